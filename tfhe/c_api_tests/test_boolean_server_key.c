@@ -324,6 +324,41 @@ bool c_xor(bool left, bool right) { return left != right; }
 
 bool c_xnor(bool left, bool right) { return !c_xor(left, right); }
 
+void test_batch_gates(BooleanClientKey *cks, BooleanServerKey *sks, bool (*c_fun)(bool, bool),
+                      BooleanGateKind gate) {
+  BooleanCiphertext *ct_lefts[4] = {NULL};
+  BooleanCiphertext *ct_rights[4] = {NULL};
+  BooleanCiphertext *ct_results[4] = {NULL};
+  bool lefts[4] = {false, false, true, true};
+  bool rights[4] = {false, true, false, true};
+
+  for (int i = 0; i < 4; ++i) {
+    int encrypt_left_ok = boolean_client_key_encrypt(cks, lefts[i], &ct_lefts[i]);
+    assert(encrypt_left_ok == 0);
+
+    int encrypt_right_ok = boolean_client_key_encrypt(cks, rights[i], &ct_rights[i]);
+    assert(encrypt_right_ok == 0);
+  }
+
+  int api_call_ok =
+      boolean_server_key_batch_gates(sks, gate, (const BooleanCiphertext *const *)ct_lefts,
+                                     (const BooleanCiphertext *const *)ct_rights, 4, ct_results);
+  assert(api_call_ok == 0);
+
+  for (int i = 0; i < 4; ++i) {
+    bool decrypted_result = false;
+
+    int decrypt_ok = boolean_client_key_decrypt(cks, ct_results[i], &decrypted_result);
+    assert(decrypt_ok == 0);
+
+    assert(decrypted_result == c_fun(lefts[i], rights[i]));
+
+    boolean_destroy_ciphertext(ct_lefts[i]);
+    boolean_destroy_ciphertext(ct_rights[i]);
+    boolean_destroy_ciphertext(ct_results[i]);
+  }
+}
+
 void test_server_key(void) {
   BooleanClientKey *cks = NULL;
   BooleanCompressedServerKey *csks = NULL;
@@ -379,6 +414,13 @@ void test_server_key(void) {
   test_not(deser_cks, deser_sks);
   test_mux(deser_cks, deser_sks);
 
+  test_batch_gates(deser_cks, deser_sks, c_and, BooleanGateKindAnd);
+  test_batch_gates(deser_cks, deser_sks, c_nand, BooleanGateKindNand);
+  test_batch_gates(deser_cks, deser_sks, c_or, BooleanGateKindOr);
+  test_batch_gates(deser_cks, deser_sks, c_nor, BooleanGateKindNor);
+  test_batch_gates(deser_cks, deser_sks, c_xor, BooleanGateKindXor);
+  test_batch_gates(deser_cks, deser_sks, c_xnor, BooleanGateKindXnor);
+
   test_binary_boolean_function_assign(deser_cks, deser_sks, c_and, boolean_server_key_and_assign);
   test_binary_boolean_function_assign(deser_cks, deser_sks, c_nand, boolean_server_key_nand_assign);
   test_binary_boolean_function_assign(deser_cks, deser_sks, c_or, boolean_server_key_or_assign);
@@ -1,7 +1,7 @@
 const crypto = require('crypto');
 const test = require('node:test');
 const assert = require('node:assert').strict;
-const { Boolean, Shortint, BooleanParameterSet } = require("../pkg");
+const { Boolean, Shortint, BooleanParameterSet, BooleanGate, BooleanCiphertextBatch } = require("../pkg");
 
 function genRandomBigIntWithBytes(byteCount) {
     return BigInt('0x' + crypto.randomBytes(byteCount).toString('hex'))
@@ -85,6 +85,40 @@ test('boolean_deterministic_keygen', (t) => {
     }
 });
 
+test('boolean_gates_and_batch', (t) => {
+    let params = Boolean.get_parameters(BooleanParameterSet.Default);
+    let cks = Boolean.new_client_key(params);
+    let compressed_sks = Boolean.new_compressed_server_key(cks);
+
+    let serialized_compressed_sks = Boolean.serialize_compressed_server_key(compressed_sks);
+    let deserialized_compressed_sks = Boolean.deserialize_compressed_server_key(serialized_compressed_sks);
+    let sks = Boolean.decompress_server_key(deserialized_compressed_sks);
+
+    let ct_true = Boolean.encrypt(cks, true);
+    let ct_false = Boolean.encrypt(cks, false);
+
+    assert.deepStrictEqual(Boolean.decrypt(cks, Boolean.and(sks, ct_true, ct_false)), false);
+    assert.deepStrictEqual(Boolean.decrypt(cks, Boolean.xor(sks, ct_true, ct_false)), true);
+    assert.deepStrictEqual(Boolean.decrypt(cks, Boolean.not(sks, ct_true)), false);
+    assert.deepStrictEqual(Boolean.decrypt(cks, Boolean.mux(sks, ct_true, ct_false, ct_true)), false);
+
+    let lefts = [false, false, true, true];
+    let rights = [false, true, false, true];
+    let lhs = new BooleanCiphertextBatch();
+    let rhs = new BooleanCiphertextBatch();
+    for (let i = 0; i < lefts.length; i++) {
+        lhs.push(Boolean.encrypt(cks, lefts[i]));
+        rhs.push(Boolean.encrypt(cks, rights[i]));
+    }
+
+    let result = Boolean.batch_gates(sks, BooleanGate.Nand, lhs, rhs);
+    assert.deepStrictEqual(result.len(), lefts.length);
+    for (let i = 0; i < lefts.length; i++) {
+        let decrypted = Boolean.decrypt(cks, result.get(i));
+        assert.deepStrictEqual(decrypted, !(lefts[i] && rights[i]));
+    }
+});
+
 
 // Shortint tests
 test('shortint_encrypt_decrypt', (t) => {
@@ -0,0 +1,478 @@
+//! Serialization of radix ciphertexts where each block is individually addressable.
+//!
+//! The usual serialization of a [RadixCiphertext](crate::integer::RadixCiphertext) is a single
+//! blob, so changing one block of a stored ciphertext means rewriting all of it. In this format
+//! the header holds the position of each block, so that a storage layer can read or overwrite a
+//! single block in place, see [BlockLayout] and [replace_block].
+//!
+//! All the blocks of a ciphertext have the same serialized size when they share the same
+//! parameters, so a block updated by a computation always fits in the place of the old one.
+//!
+//! # Format
+//!
+//! All integers are stored in little endian.
+//!
+//! | Field          | Size                 | Description                                     |
+//! |----------------|----------------------|-------------------------------------------------|
+//! | magic          | 8 bytes              | `b"TFHERDXB"`                                   |
+//! | format version | 4 bytes              | Version of this format                          |
+//! | signedness     | 1 byte               | 1 for signed ciphertexts, 0 otherwise           |
+//! | block count    | 8 bytes              | Number of blocks                                |
+//! | index          | `block count` * 16   | (offset, length) of each block, from data start |
+//! | data           | variable             | bincode serialized blocks, back to back         |
+
+use crate::integer::ciphertext::IntegerRadixCiphertext;
+use crate::shortint::Ciphertext;
+use bincode::Options;
+use std::ops::Range;
+
+const MAGIC: &[u8; 8] = b"TFHERDXB";
+const FORMAT_VERSION: u32 = 1;
+
+const VERSION_OFFSET: usize = MAGIC.len();
+const SIGNEDNESS_OFFSET: usize = VERSION_OFFSET + std::mem::size_of::<u32>();
+const BLOCK_COUNT_OFFSET: usize = SIGNEDNESS_OFFSET + 1;
+/// Size of the part of the header preceding the index, see [header_len]
+pub const FIXED_HEADER_SIZE: usize = BLOCK_COUNT_OFFSET + U64_SIZE;
+const INDEX_ENTRY_SIZE: usize = 2 * U64_SIZE;
+const U64_SIZE: usize = std::mem::size_of::<u64>();
+
+fn bincode_options() -> impl Options {
+    bincode::DefaultOptions::new().with_fixint_encoding()
+}
+
+#[derive(Debug)]
+pub enum BlockSerializationError {
+    Serialization(bincode::Error),
+    /// The bytes are not in the block format, or they are truncated
+    InvalidFormat(String),
+    /// The bytes hold a signed ciphertext and an unsigned one was requested, or the opposite
+    SignednessMismatch {
+        expected_signed: bool,
+    },
+    IndexOutOfBounds {
+        index: usize,
+        num_blocks: usize,
+    },
+    /// The new block does not have the serialized size of the block it replaces
+    BlockSizeMismatch {
+        index: usize,
+        expected: usize,
+        got: usize,
+    },
+}
+
+impl std::fmt::Display for BlockSerializationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Serialization(err) => write!(f, "Serialization error: {err}"),
+            Self::InvalidFormat(msg) => write!(f, "Invalid serialized blocks: {msg}"),
+            Self::SignednessMismatch { expected_signed } => {
+                let (expected, found) = if *expected_signed {
+                    ("signed", "unsigned")
+                } else {
+                    ("unsigned", "signed")
+                };
+                write!(
+                    f,
+                    "Expected a {expected} ciphertext, got a {found} ciphertext"
+                )
+            }
+            Self::IndexOutOfBounds { index, num_blocks } => {
+                write!(
+                    f,
+                    "Block index {index} is out of bounds for a ciphertext of {num_blocks} blocks"
+                )
+            }
+            Self::BlockSizeMismatch {
+                index,
+                expected,
+                got,
+            } => {
+                write!(
+                    f,
+                    "Block {index} takes {expected} bytes, the new block takes {got} bytes"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for BlockSerializationError {}
+
+impl From<bincode::Error> for BlockSerializationError {
+    fn from(value: bincode::Error) -> Self {
+        Self::Serialization(value)
+    }
+}
+
+fn read_u64(bytes: &[u8], offset: usize) -> Result<u64, BlockSerializationError> {
+    bytes
+        .get(offset..offset + U64_SIZE)
+        .map(|slice| u64::from_le_bytes(slice.try_into().unwrap()))
+        .ok_or_else(|| BlockSerializationError::InvalidFormat("Bytes are truncated".to_owned()))
+}
+
+fn to_usize(value: u64) -> Result<usize, BlockSerializationError> {
+    usize::try_from(value).map_err(|_| {
+        BlockSerializationError::InvalidFormat(format!("{value} does not fit in a usize"))
+    })
+}
+
+fn read_fixed_header(bytes: &[u8]) -> Result<(bool, usize), BlockSerializationError> {
+    if bytes.len() < FIXED_HEADER_SIZE || &bytes[..MAGIC.len()] != MAGIC {
+        return Err(BlockSerializationError::InvalidFormat(
+            "Bytes are not serialized blocks".to_owned(),
+        ));
+    }
+
+    let version = u32::from_le_bytes(bytes[VERSION_OFFSET..SIGNEDNESS_OFFSET].try_into().unwrap());
+    if version != FORMAT_VERSION {
+        return Err(BlockSerializationError::InvalidFormat(format!(
+            "Expected format version {FORMAT_VERSION}, got version {version}"
+        )));
+    }
+
+    let is_signed = match bytes[SIGNEDNESS_OFFSET] {
+        0 => false,
+        1 => true,
+        value => {
+            return Err(BlockSerializationError::InvalidFormat(format!(
+                "Invalid signedness {value}"
+            )))
+        }
+    };
+    let num_blocks = to_usize(read_u64(bytes, BLOCK_COUNT_OFFSET)?)?;
+
+    Ok((is_signed, num_blocks))
+}
+
+/// Returns the length of the whole header, given its first [FIXED_HEADER_SIZE] bytes
+///
+/// This allows a storage layer to read the header in two steps before calling
+/// [BlockLayout::from_header], without reading any block.
+pub fn header_len(fixed_header: &[u8]) -> Result<usize, BlockSerializationError> {
+    let (_, num_blocks) = read_fixed_header(fixed_header)?;
+    num_blocks
+        .checked_mul(INDEX_ENTRY_SIZE)
+        .and_then(|index_len| index_len.checked_add(FIXED_HEADER_SIZE))
+        .ok_or_else(|| {
+            BlockSerializationError::InvalidFormat(format!("Invalid block count {num_blocks}"))
+        })
+}
+
+/// Position of each block in serialized bytes, read from their header
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlockLayout {
+    is_signed: bool,
+    // Ranges of the blocks from the start of the bytes
+    block_ranges: Vec<Range<usize>>,
+}
+
+impl BlockLayout {
+    /// Reads the layout from the start of serialized bytes
+    ///
+    /// Only the header needs to be given (see [header_len]), the blocks may follow it.
+    pub fn from_header(bytes: &[u8]) -> Result<Self, BlockSerializationError> {
+        let (is_signed, num_blocks) = read_fixed_header(bytes)?;
+        let data_offset = header_len(bytes)?;
+
+        let mut expected_offset = 0;
+        let block_ranges = (0..num_blocks)
+            .map(|i| {
+                let entry_offset = FIXED_HEADER_SIZE + i * INDEX_ENTRY_SIZE;
+                let offset = to_usize(read_u64(bytes, entry_offset)?)?;
+                let length = to_usize(read_u64(bytes, entry_offset + U64_SIZE)?)?;
+                // Blocks are back to back, so that all the bytes are accounted for
+                if offset != expected_offset {
+                    return Err(BlockSerializationError::InvalidFormat(format!(
+                        "Block {i} starts at {offset}, expected {expected_offset}"
+                    )));
+                }
+                let start = data_offset.checked_add(offset);
+                let end = start.and_then(|start| start.checked_add(length));
+                let (Some(start), Some(end)) = (start, end) else {
+                    return Err(BlockSerializationError::InvalidFormat(format!(
+                        "Block {i} is out of range"
+                    )));
+                };
+                expected_offset += length;
+                Ok(start..end)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            is_signed,
+            block_ranges,
+        })
+    }
+
+    pub fn is_signed(&self) -> bool {
+        self.is_signed
+    }
+
+    pub fn num_blocks(&self) -> usize {
+        self.block_ranges.len()
+    }
+
+    /// Returns the length of the whole serialized ciphertext, header included
+    pub fn total_len(&self) -> usize {
+        self.block_ranges.last().map_or(
+            FIXED_HEADER_SIZE + self.block_ranges.len() * INDEX_ENTRY_SIZE,
+            |range| range.end,
+        )
+    }
+
+    /// Returns the range of the block at `index` in the serialized bytes
+    pub fn block_range(&self, index: usize) -> Result<Range<usize>, BlockSerializationError> {
+        self.block_ranges
+            .get(index)
+            .cloned()
+            .ok_or(BlockSerializationError::IndexOutOfBounds {
+                index,
+                num_blocks: self.block_ranges.len(),
+            })
+    }
+
+    fn checked_block_bytes<'a>(
+        &self,
+        bytes: &'a [u8],
+        index: usize,
+    ) -> Result<&'a [u8], BlockSerializationError> {
+        let range = self.block_range(index)?;
+        bytes
+            .get(range)
+            .ok_or_else(|| BlockSerializationError::InvalidFormat("Bytes are truncated".to_owned()))
+    }
+}
+
+/// Serializes a block on its own, as it is stored by [serialize_radix_blocks]
+pub fn serialize_block(block: &Ciphertext) -> Result<Vec<u8>, BlockSerializationError> {
+    Ok(bincode_options().serialize(block)?)
+}
+
+fn deserialize_block_bytes(bytes: &[u8]) -> Result<Ciphertext, BlockSerializationError> {
+    Ok(bincode_options()
+        .with_limit(bytes.len() as u64)
+        .deserialize(bytes)?)
+}
+
+/// Serializes a radix ciphertext so that each of its blocks can be read or replaced on its own
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::block_serialization::{
+///     deserialize_block, deserialize_radix_blocks, replace_block, serialize_radix_blocks,
+/// };
+/// use tfhe::integer::{gen_keys_radix, RadixCiphertext};
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+///
+/// let num_blocks = 4;
+/// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+///
+/// let msg = 0b1010_0110u64;
+/// let ct = cks.encrypt(msg);
+/// let mut bytes = serialize_radix_blocks(&ct).unwrap();
+///
+/// // Read the last block only
+/// let block = deserialize_block(&bytes, 3).unwrap();
+/// assert_eq!(cks.decrypt_one_block(&block), 0b10);
+///
+/// // Update the first block in place
+/// let new_block = cks.encrypt_one_block(0b11);
+/// replace_block(&mut bytes, 0, &new_block).unwrap();
+///
+/// let ct: RadixCiphertext = deserialize_radix_blocks(&bytes).unwrap();
+/// let dec: u64 = cks.decrypt(&ct);
+/// assert_eq!(dec, 0b1010_0111);
+/// # let _ = sks;
+/// ```
+pub fn serialize_radix_blocks<T>(ct: &T) -> Result<Vec<u8>, BlockSerializationError>
+where
+    T: IntegerRadixCiphertext,
+{
+    let blocks = ct
+        .blocks()
+        .iter()
+        .map(serialize_block)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let data_len = blocks.iter().map(Vec::len).sum::<usize>();
+    let mut bytes =
+        Vec::with_capacity(FIXED_HEADER_SIZE + blocks.len() * INDEX_ENTRY_SIZE + data_len);
+    bytes.extend_from_slice(MAGIC);
+    bytes.extend_from_slice(&FORMAT_VERSION.to_le_bytes());
+    bytes.push(u8::from(T::IS_SIGNED));
+    bytes.extend_from_slice(&(blocks.len() as u64).to_le_bytes());
+
+    let mut offset = 0u64;
+    for block in blocks.iter() {
+        bytes.extend_from_slice(&offset.to_le_bytes());
+        bytes.extend_from_slice(&(block.len() as u64).to_le_bytes());
+        offset += block.len() as u64;
+    }
+    for block in blocks.iter() {
+        bytes.extend_from_slice(block);
+    }
+
+    Ok(bytes)
+}
+
+/// Deserializes a radix ciphertext serialized with [serialize_radix_blocks]
+pub fn deserialize_radix_blocks<T>(bytes: &[u8]) -> Result<T, BlockSerializationError>
+where
+    T: IntegerRadixCiphertext,
+{
+    let layout = BlockLayout::from_header(bytes)?;
+    if layout.is_signed != T::IS_SIGNED {
+        return Err(BlockSerializationError::SignednessMismatch {
+            expected_signed: T::IS_SIGNED,
+        });
+    }
+    if layout.total_len() != bytes.len() {
+        return Err(BlockSerializationError::InvalidFormat(format!(
+            "Expected {} bytes, got {}",
+            layout.total_len(),
+            bytes.len()
+        )));
+    }
+
+    let blocks = (0..layout.num_blocks())
+        .map(|index| deserialize_block_bytes(layout.checked_block_bytes(bytes, index)?))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(T::from(blocks))
+}
+
+/// Deserializes the block at `index` of a radix ciphertext serialized with
+/// [serialize_radix_blocks], without deserializing the other blocks
+pub fn deserialize_block(
+    bytes: &[u8],
+    index: usize,
+) -> Result<Ciphertext, BlockSerializationError> {
+    let layout = BlockLayout::from_header(bytes)?;
+    deserialize_block_bytes(layout.checked_block_bytes(bytes, index)?)
+}
+
+/// Overwrites in place the block at `index` of a radix ciphertext serialized with
+/// [serialize_radix_blocks]
+///
+/// The new block must have the serialized size of the block it replaces, which is the case of
+/// the blocks encrypted with the same parameters. Otherwise the bytes are left untouched and an
+/// error is returned.
+pub fn replace_block(
+    bytes: &mut [u8],
+    index: usize,
+    block: &Ciphertext,
+) -> Result<(), BlockSerializationError> {
+    let layout = BlockLayout::from_header(bytes)?;
+    let range = layout.block_range(index)?;
+    if range.end > bytes.len() {
+        return Err(BlockSerializationError::InvalidFormat(
+            "Bytes are truncated".to_owned(),
+        ));
+    }
+
+    let block_bytes = serialize_block(block)?;
+    if block_bytes.len() != range.len() {
+        return Err(BlockSerializationError::BlockSizeMismatch {
+            index,
+            expected: range.len(),
+            got: block_bytes.len(),
+        });
+    }
+
+    bytes[range].copy_from_slice(&block_bytes);
+    Ok(())
+}
+
+#[cfg(test)]
+mod test_integer {
+    use super::{
+        deserialize_block, deserialize_radix_blocks, header_len, replace_block,
+        serialize_radix_blocks, BlockLayout, BlockSerializationError, FIXED_HEADER_SIZE,
+    };
+    use crate::integer::{gen_keys_radix, RadixCiphertext, SignedRadixCiphertext};
+    use crate::shortint::parameters::{
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    };
+
+    #[test]
+    fn block_serialization_round_trip() {
+        let num_blocks = 8;
+        let (cks, _sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+
+        let msg = 0xBEEFu64;
+        let ct = cks.encrypt(msg);
+        let bytes = serialize_radix_blocks(&ct).unwrap();
+
+        let layout = BlockLayout::from_header(&bytes[..header_len(&bytes).unwrap()]).unwrap();
+        assert!(!layout.is_signed());
+        assert_eq!(layout.num_blocks(), num_blocks);
+        assert_eq!(layout.total_len(), bytes.len());
+        assert_eq!(
+            header_len(&bytes[..FIXED_HEADER_SIZE]).unwrap(),
+            layout.block_range(0).unwrap().start
+        );
+
+        let deserialized: RadixCiphertext = deserialize_radix_blocks(&bytes).unwrap();
+        assert_eq!(deserialized, ct);
+
+        for i in 0..num_blocks {
+            let block = deserialize_block(&bytes, i).unwrap();
+            assert_eq!(cks.decrypt_one_block(&block), (msg >> (2 * i)) & 0b11);
+        }
+
+        assert!(matches!(
+            deserialize_block(&bytes, num_blocks),
+            Err(BlockSerializationError::IndexOutOfBounds { .. })
+        ));
+        assert!(matches!(
+            deserialize_radix_blocks::<SignedRadixCiphertext>(&bytes),
+            Err(BlockSerializationError::SignednessMismatch {
+                expected_signed: true
+            })
+        ));
+        assert!(matches!(
+            deserialize_radix_blocks::<RadixCiphertext>(&bytes[..bytes.len() - 1]),
+            Err(BlockSerializationError::InvalidFormat(_))
+        ));
+    }
+
+    #[test]
+    fn block_serialization_replace_block() {
+        let num_blocks = 4;
+        let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+
+        let ct = cks.encrypt_signed(-100i64);
+        let mut bytes = serialize_radix_blocks(&ct).unwrap();
+        let original = bytes.clone();
+
+        // Update a block the way a computation would, only its bytes must change
+        let mut updated = ct.clone();
+        sks.set_bit_parallelized(&mut updated, 5, &cks.encrypt_one_block(1));
+        replace_block(&mut bytes, 2, &updated.blocks[2]).unwrap();
+
+        let range = BlockLayout::from_header(&bytes)
+            .unwrap()
+            .block_range(2)
+            .unwrap();
+        assert_eq!(bytes[..range.start], original[..range.start]);
+        assert_eq!(bytes[range.end..], original[range.end..]);
+
+        let deserialized: SignedRadixCiphertext = deserialize_radix_blocks(&bytes).unwrap();
+        let dec: i64 = cks.decrypt_signed(&deserialized);
+        assert_eq!(dec, -100 | (1 << 5));
+
+        // A block of other parameters does not fit
+        let (other_cks, _) = gen_keys_radix(PARAM_MESSAGE_1_CARRY_1_KS_PBS, 1);
+        let other_block = other_cks.encrypt_one_block(1);
+        assert!(matches!(
+            replace_block(&mut bytes, 0, &other_block),
+            Err(BlockSerializationError::BlockSizeMismatch { index: 0, .. })
+        ));
+        let deserialized: SignedRadixCiphertext = deserialize_radix_blocks(&bytes).unwrap();
+        assert_eq!(cks.decrypt_signed::<i64>(&deserialized), -100 | (1 << 5));
+    }
+}
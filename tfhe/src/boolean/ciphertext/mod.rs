@@ -2,6 +2,9 @@
 //!
 //! This module implements the ciphertext structure containing an encryption of a Boolean message.
 
+use crate::boolean::parameters::CiphertextConformanceParams;
+use crate::conformance::ParameterSetConformant;
+use crate::content_hash::{ContentHash, ContentHasher};
 use crate::core_crypto::entities::*;
 use serde::{Deserialize, Serialize};
 
@@ -14,6 +17,37 @@ pub enum Ciphertext {
     Trivial(bool),
 }
 
+impl crate::named::Named for Ciphertext {
+    const NAME: &'static str = "boolean::Ciphertext";
+}
+
+impl ParameterSetConformant for Ciphertext {
+    type ParameterSet = CiphertextConformanceParams;
+
+    fn is_conformant(&self, params: &CiphertextConformanceParams) -> bool {
+        match self {
+            Self::Encrypted(ct) => ct.is_conformant(&params.ct_params),
+            Self::Trivial(_) => true,
+        }
+    }
+}
+
+impl ContentHash for Ciphertext {
+    fn hash_content(&self, hasher: &mut ContentHasher) {
+        hasher.write_str(<Self as crate::named::Named>::NAME);
+        match self {
+            Self::Encrypted(ct) => {
+                hasher.write_u64(0);
+                ct.hash_content(hasher);
+            }
+            Self::Trivial(value) => {
+                hasher.write_u64(1);
+                hasher.write_u64(u64::from(*value));
+            }
+        }
+    }
+}
+
 /// A structure containing a compressed ciphertext, meant to encrypt a Boolean message.
 ///
 /// It has to be decompressed before evaluating a Boolean circuit.
@@ -22,6 +56,18 @@ pub struct CompressedCiphertext {
     pub(crate) ciphertext: SeededLweCiphertext<u32>,
 }
 
+impl crate::named::Named for CompressedCiphertext {
+    const NAME: &'static str = "boolean::CompressedCiphertext";
+}
+
+impl ParameterSetConformant for CompressedCiphertext {
+    type ParameterSet = CiphertextConformanceParams;
+
+    fn is_conformant(&self, params: &CiphertextConformanceParams) -> bool {
+        self.ciphertext.is_conformant(&params.ct_params)
+    }
+}
+
 impl From<CompressedCiphertext> for Ciphertext {
     fn from(value: CompressedCiphertext) -> Self {
         Self::Encrypted(value.ciphertext.decompress_into_lwe_ciphertext())
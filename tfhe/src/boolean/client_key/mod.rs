@@ -5,7 +5,8 @@
 
 use crate::boolean::ciphertext::{Ciphertext, CompressedCiphertext};
 use crate::boolean::engine::{BooleanEngine, WithThreadLocalEngine};
-use crate::boolean::parameters::BooleanParameters;
+use crate::boolean::parameters::{BooleanParameters, ClientKeyConformanceParams};
+use crate::conformance::ParameterSetConformant;
 use crate::core_crypto::entities::*;
 use serde::{Deserialize, Serialize};
 use std::fmt::{Debug, Formatter};
@@ -33,6 +34,22 @@ impl PartialEq for ClientKey {
     }
 }
 
+impl crate::named::Named for ClientKey {
+    const NAME: &'static str = "boolean::ClientKey";
+}
+
+impl ParameterSetConformant for ClientKey {
+    type ParameterSet = ClientKeyConformanceParams;
+
+    fn is_conformant(&self, params: &ClientKeyConformanceParams) -> bool {
+        let parameters = &params.parameters;
+        self.parameters == *parameters
+            && self.lwe_secret_key.lwe_dimension() == parameters.lwe_dimension
+            && self.glwe_secret_key.glwe_dimension() == parameters.glwe_dimension
+            && self.glwe_secret_key.polynomial_size() == parameters.polynomial_size
+    }
+}
+
 impl Debug for ClientKey {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "ClientKey {{ ")?;
@@ -1,34 +1,49 @@
 use crate::boolean::ciphertext::Ciphertext;
-use crate::boolean::{ClientKey, PLAINTEXT_TRUE};
+use crate::boolean::parameters::{BooleanMultiBitParameters, ServerKeyConformanceParams};
+use crate::boolean::{plaintext_true, ClientKey};
+use crate::conformance::ParameterSetConformant;
 use crate::core_crypto::algorithms::*;
 use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
 use crate::core_crypto::commons::generators::{DeterministicSeeder, EncryptionRandomGenerator};
 use crate::core_crypto::commons::math::random::{ActivatedRandomGenerator, Seeder};
-use crate::core_crypto::commons::parameters::{CiphertextModulus, PBSOrder};
+use crate::core_crypto::commons::math::torus::UnsignedTorus;
+use crate::core_crypto::commons::numeric::{CastFrom, CastInto, UnsignedInteger};
+use crate::core_crypto::commons::parameters::{
+    CiphertextModulus, GlweSize, LweBskGroupingFactor, LweDimension, PBSOrder, PolynomialSize,
+    ThreadCount,
+};
+use crate::core_crypto::commons::traits::{Container, ContainerMut, IntoContainerOwned};
 use crate::core_crypto::entities::*;
+use crate::core_crypto::fft_impl::fft64::c64;
 use crate::core_crypto::fft_impl::fft64::math::fft::Fft;
 use serde::{Deserialize, Serialize};
 use std::error::Error;
+use std::ops::ControlFlow;
 
 /// Memory used as buffer for the bootstrap
 ///
 /// It contains contiguous chunk which is then sliced and converted
 /// into core's View types.
-#[derive(Default)]
-struct Memory {
-    buffer: Vec<u32>,
+struct Memory<Scalar> {
+    buffer: Vec<Scalar>,
 }
 
-pub struct BuffersRef<'a> {
-    pub(crate) lookup_table: GlweCiphertextMutView<'a, u32>,
+impl<Scalar> Default for Memory<Scalar> {
+    fn default() -> Self {
+        Self { buffer: Vec::new() }
+    }
+}
+
+pub struct BuffersRef<'a, Scalar: UnsignedInteger> {
+    pub(crate) lookup_table: GlweCiphertextMutView<'a, Scalar>,
     // For the intermediate keyswitch result in the case of a big ciphertext
-    pub(crate) buffer_lwe_after_ks: LweCiphertextMutView<'a, u32>,
+    pub(crate) buffer_lwe_after_ks: LweCiphertextMutView<'a, Scalar>,
     // For the intermediate PBS result in the case of a smallciphertext
-    pub(crate) buffer_lwe_after_pbs: LweCiphertextMutView<'a, u32>,
+    pub(crate) buffer_lwe_after_pbs: LweCiphertextMutView<'a, Scalar>,
 }
 
-impl Memory {
-    fn as_buffers(&mut self, server_key: &ServerKey) -> BuffersRef<'_> {
+impl<Scalar: UnsignedInteger> Memory<Scalar> {
+    fn as_buffers(&mut self, server_key: &ServerKey<Scalar>) -> BuffersRef<'_, Scalar> {
         let num_elem_in_accumulator = server_key.bootstrapping_key.glwe_size().0
             * server_key.bootstrapping_key.polynomial_size().0;
         let num_elem_in_lwe_after_ks = server_key.key_switching_key.output_lwe_size().0;
@@ -42,7 +57,7 @@ impl Memory {
             num_elem_in_accumulator + num_elem_in_lwe_after_ks + num_elem_in_lwe_after_pbs;
 
         let all_elements = if self.buffer.len() < total_elem_needed {
-            self.buffer.resize(total_elem_needed, 0u32);
+            self.buffer.resize(total_elem_needed, Scalar::ZERO);
             self.buffer.as_mut_slice()
         } else {
             &mut self.buffer[..total_elem_needed]
@@ -57,8 +72,8 @@ impl Memory {
             CiphertextModulus::new_native(),
         );
 
-        acc.get_mut_mask().as_mut().fill(0u32);
-        acc.get_mut_body().as_mut().fill(PLAINTEXT_TRUE);
+        acc.get_mut_mask().as_mut().fill(Scalar::ZERO);
+        acc.get_mut_body().as_mut().fill(plaintext_true());
 
         let (after_ks_elements, after_pbs_elements) =
             other_elements.split_at_mut(num_elem_in_lwe_after_ks);
@@ -80,6 +95,72 @@ impl Memory {
     }
 }
 
+/// The bootstrapping key of a [`ServerKey`], in the Fourier domain.
+#[derive(Clone)]
+pub(crate) enum BooleanBootstrappingKey {
+    Classic(FourierLweBootstrapKeyOwned),
+    /// A multi bit bootstrapping key, its PBS runs on `thread_count` threads
+    MultiBit {
+        fourier_bsk: FourierLweMultiBitBootstrapKeyOwned,
+        thread_count: ThreadCount,
+    },
+}
+
+impl BooleanBootstrappingKey {
+    pub(crate) fn input_lwe_dimension(&self) -> LweDimension {
+        match self {
+            Self::Classic(bsk) => bsk.input_lwe_dimension(),
+            Self::MultiBit { fourier_bsk, .. } => fourier_bsk.input_lwe_dimension(),
+        }
+    }
+
+    pub(crate) fn output_lwe_dimension(&self) -> LweDimension {
+        match self {
+            Self::Classic(bsk) => bsk.output_lwe_dimension(),
+            Self::MultiBit { fourier_bsk, .. } => fourier_bsk.output_lwe_dimension(),
+        }
+    }
+
+    pub(crate) fn glwe_size(&self) -> GlweSize {
+        match self {
+            Self::Classic(bsk) => bsk.glwe_size(),
+            Self::MultiBit { fourier_bsk, .. } => fourier_bsk.glwe_size(),
+        }
+    }
+
+    pub(crate) fn polynomial_size(&self) -> PolynomialSize {
+        match self {
+            Self::Classic(bsk) => bsk.polynomial_size(),
+            Self::MultiBit { fourier_bsk, .. } => fourier_bsk.polynomial_size(),
+        }
+    }
+
+    fn data(&self) -> &[c64] {
+        match self {
+            Self::Classic(bsk) => bsk.as_view().data(),
+            Self::MultiBit { fourier_bsk, .. } => fourier_bsk.as_view().data(),
+        }
+    }
+}
+
+/// Whether a multi bit bootstrapping key with `grouping_factor` can be generated for the
+/// `lwe_dimension`: the grouping factor must be 2 or 3 and divide the dimension.
+fn is_supported_grouping_factor(
+    grouping_factor: LweBskGroupingFactor,
+    lwe_dimension: LweDimension,
+) -> bool {
+    matches!(grouping_factor.0, 2 | 3) && lwe_dimension.0 % grouping_factor.0 == 0
+}
+
+/// The number of threads of the multi bit PBS when none is given, the optimal ones for
+/// m6i.metal.
+fn default_multi_bit_pbs_thread_count(grouping_factor: LweBskGroupingFactor) -> ThreadCount {
+    match grouping_factor.0 {
+        2 => ThreadCount(5),
+        _ => ThreadCount(7),
+    }
+}
+
 /// A structure containing the server public key.
 ///
 /// This server key data lives on the CPU.
@@ -90,20 +171,187 @@ impl Memory {
 /// In more details, it contains:
 /// * `bootstrapping_key` - a public key, used to perform the bootstrapping operation.
 /// * `key_switching_key` - a public key, used to perform the key-switching operation.
-#[derive(Clone, Serialize, Deserialize)]
-pub struct ServerKey {
-    pub(crate) bootstrapping_key: FourierLweBootstrapKeyOwned,
-    pub(crate) key_switching_key: LweKeyswitchKeyOwned<u32>,
+///
+/// The bootstrapping key is either a classic one ([`ServerKey::new`]) or a multi bit one
+/// ([`ServerKey::new_multi_bit`]).
+///
+/// The keys generated by the Boolean API work on 32 bits ciphertexts. The `Scalar` parameter only
+/// lets the boolean bootstraps run with keys built elsewhere for other ciphertext sizes, e.g. 64
+/// bits. It does not extend to shortint, whose ciphertexts and keys are always 64 bits: there are
+/// no 32 bits shortint parameter sets.
+#[derive(Clone)]
+pub struct ServerKey<Scalar: UnsignedInteger = u32> {
+    pub(crate) bootstrapping_key: BooleanBootstrappingKey,
+    pub(crate) key_switching_key: LweKeyswitchKeyOwned<Scalar>,
     pub(crate) pbs_order: PBSOrder,
 }
 
-impl ServerKey {
+// The server key is serialized through a versioned enum so that the format can evolve while old
+// keys stay readable, the borrowed variant avoids cloning the keys to serialize them.
+//
+// The thread count is a property of the machine running the PBS, it is not serialized.
+#[derive(Serialize, Deserialize)]
+#[serde(bound(deserialize = "C: IntoContainerOwned"))]
+enum SerializableBootstrappingKey<C: Container<Element = c64>> {
+    Classic(FourierLweBootstrapKey<C>),
+    MultiBit(FourierLweMultiBitBootstrapKey<C>),
+}
+
+#[derive(Serialize)]
+struct ServerKeyV0Ref<'a, K> {
+    bootstrapping_key: SerializableBootstrappingKey<&'a [c64]>,
+    key_switching_key: &'a K,
+    pbs_order: PBSOrder,
+}
+
+#[derive(Serialize)]
+enum SerializableServerKey<'a, K> {
+    V0(ServerKeyV0Ref<'a, K>),
+}
+
+#[derive(Deserialize)]
+#[serde(bound(deserialize = "C: IntoContainerOwned, K: Deserialize<'de>"))]
+struct ServerKeyV0<C: Container<Element = c64>, K> {
+    bootstrapping_key: SerializableBootstrappingKey<C>,
+    key_switching_key: K,
+    pbs_order: PBSOrder,
+}
+
+#[derive(Deserialize)]
+#[serde(bound(deserialize = "C: IntoContainerOwned, K: Deserialize<'de>"))]
+enum DeserializableServerKey<C: Container<Element = c64>, K> {
+    V0(ServerKeyV0<C, K>),
+}
+
+impl<Scalar: UnsignedInteger> Serialize for ServerKey<Scalar>
+where
+    LweKeyswitchKeyOwned<Scalar>: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let bootstrapping_key = match &self.bootstrapping_key {
+            BooleanBootstrappingKey::Classic(bsk) => {
+                SerializableBootstrappingKey::Classic(bsk.as_view())
+            }
+            BooleanBootstrappingKey::MultiBit { fourier_bsk, .. } => {
+                SerializableBootstrappingKey::MultiBit(fourier_bsk.as_view())
+            }
+        };
+
+        SerializableServerKey::V0(ServerKeyV0Ref {
+            bootstrapping_key,
+            key_switching_key: &self.key_switching_key,
+            pbs_order: self.pbs_order,
+        })
+        .serialize(serializer)
+    }
+}
+
+impl<'de, Scalar: UnsignedInteger> Deserialize<'de> for ServerKey<Scalar>
+where
+    LweKeyswitchKeyOwned<Scalar>: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        DeserializableServerKey::deserialize(deserializer).map(|versioned| match versioned {
+            DeserializableServerKey::V0(key) => {
+                let bootstrapping_key = match key.bootstrapping_key {
+                    SerializableBootstrappingKey::Classic(bsk) => {
+                        BooleanBootstrappingKey::Classic(bsk)
+                    }
+                    SerializableBootstrappingKey::MultiBit(fourier_bsk) => {
+                        let thread_count =
+                            default_multi_bit_pbs_thread_count(fourier_bsk.grouping_factor());
+                        BooleanBootstrappingKey::MultiBit {
+                            fourier_bsk,
+                            thread_count,
+                        }
+                    }
+                };
+
+                Self {
+                    bootstrapping_key,
+                    key_switching_key: key.key_switching_key,
+                    pbs_order: key.pbs_order,
+                }
+            }
+        })
+    }
+}
+
+/// A server key serialized before the server keys were versioned, it has a classic bootstrapping
+/// key.
+///
+/// These keys cannot be deserialized as a [ServerKey], deserialize them as a `LegacyServerKey`
+/// and convert them.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::boolean::server_key::{LegacyServerKey, ServerKey};
+///
+/// # fn load(legacy_bytes: &[u8]) -> bincode::Result<ServerKey> {
+/// let sks: ServerKey = bincode::deserialize::<LegacyServerKey>(legacy_bytes)?.into();
+/// # Ok(sks)
+/// # }
+/// ```
+#[derive(Deserialize)]
+pub struct LegacyServerKey {
+    bootstrapping_key: FourierLweBootstrapKeyOwned,
+    key_switching_key: LweKeyswitchKeyOwned<u32>,
+    pbs_order: PBSOrder,
+}
+
+impl From<LegacyServerKey> for ServerKey {
+    fn from(legacy: LegacyServerKey) -> Self {
+        Self {
+            bootstrapping_key: BooleanBootstrappingKey::Classic(legacy.bootstrapping_key),
+            key_switching_key: legacy.key_switching_key,
+            pbs_order: legacy.pbs_order,
+        }
+    }
+}
+
+impl<Scalar: UnsignedInteger> ServerKey<Scalar> {
     pub fn bootstrapping_key_size_elements(&self) -> usize {
-        self.bootstrapping_key.as_view().data().as_ref().len()
+        self.bootstrapping_key.data().len()
     }
 
     pub fn bootstrapping_key_size_bytes(&self) -> usize {
-        std::mem::size_of_val(self.bootstrapping_key.as_view().data())
+        std::mem::size_of_val(self.bootstrapping_key.data())
+    }
+
+    /// Return the number of threads used by the multi bit PBS, `None` for a classic
+    /// bootstrapping key.
+    pub fn multi_bit_pbs_thread_count(&self) -> Option<ThreadCount> {
+        match &self.bootstrapping_key {
+            BooleanBootstrappingKey::Classic(_) => None,
+            BooleanBootstrappingKey::MultiBit { thread_count, .. } => Some(*thread_count),
+        }
+    }
+
+    /// Set the number of threads used by the multi bit PBS.
+    ///
+    /// The multi bit PBS of a gate is split between the threads, on a server with many cores
+    /// the gates are the fastest with a few threads each, so that several gates run in parallel.
+    ///
+    /// Note: calling this function on a server key with a classic bootstrapping key is a no-op.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new_thread_count` is 0.
+    pub fn set_multi_bit_pbs_thread_count(&mut self, new_thread_count: ThreadCount) {
+        assert!(new_thread_count.0 > 0, "ThreadCount must be greater than 0");
+        match &mut self.bootstrapping_key {
+            BooleanBootstrappingKey::Classic(_) => (),
+            BooleanBootstrappingKey::MultiBit { thread_count, .. } => {
+                *thread_count = new_thread_count;
+            }
+        }
     }
 
     pub fn key_switching_key_size_elements(&self) -> usize {
@@ -115,6 +363,67 @@ impl ServerKey {
     }
 }
 
+impl crate::named::Named for ServerKey {
+    const NAME: &'static str = "boolean::ServerKey";
+}
+
+impl ParameterSetConformant for ServerKey {
+    type ParameterSet = ServerKeyConformanceParams;
+
+    fn is_conformant(&self, params: &ServerKeyConformanceParams) -> bool {
+        let glwe_size = params.glwe_dimension.to_glwe_size();
+        let big_lwe_dimension = params
+            .glwe_dimension
+            .to_equivalent_lwe_dimension(params.polynomial_size);
+
+        let ggsw_size = fourier_ggsw_ciphertext_size(
+            glwe_size,
+            params.polynomial_size.to_fourier_polynomial_size(),
+            params.pbs_level,
+        );
+        let bsk_is_conformant = match &self.bootstrapping_key {
+            BooleanBootstrappingKey::Classic(bsk) => {
+                let expected_bsk_len = params.lwe_dimension.0 * ggsw_size;
+                bsk.input_lwe_dimension() == params.lwe_dimension
+                    && bsk.glwe_size() == glwe_size
+                    && bsk.polynomial_size() == params.polynomial_size
+                    && bsk.decomposition_base_log() == params.pbs_base_log
+                    && bsk.decomposition_level_count() == params.pbs_level
+                    && bsk.as_view().data().len() == expected_bsk_len
+            }
+            BooleanBootstrappingKey::MultiBit { fourier_bsk, .. } => {
+                // The grouping factor is a property of the key, not of the parameters
+                let grouping_factor = fourier_bsk.grouping_factor();
+                is_supported_grouping_factor(grouping_factor, params.lwe_dimension)
+                    && fourier_bsk.input_lwe_dimension() == params.lwe_dimension
+                    && fourier_bsk.glwe_size() == glwe_size
+                    && fourier_bsk.polynomial_size() == params.polynomial_size
+                    && fourier_bsk.decomposition_base_log() == params.pbs_base_log
+                    && fourier_bsk.decomposition_level_count() == params.pbs_level
+                    && fourier_bsk.as_view().data().len()
+                        == params.lwe_dimension.0 / grouping_factor.0
+                            * grouping_factor.ggsw_per_multi_bit_element().0
+                            * ggsw_size
+            }
+        };
+
+        let ksk = &self.key_switching_key;
+        let expected_ksk_len = big_lwe_dimension.0
+            * lwe_keyswitch_key_input_key_element_encrypted_size(
+                params.ks_level,
+                params.lwe_dimension.to_lwe_size(),
+            );
+        let ksk_is_conformant = ksk.input_key_lwe_dimension() == big_lwe_dimension
+            && ksk.output_key_lwe_dimension() == params.lwe_dimension
+            && ksk.decomposition_base_log() == params.ks_base_log
+            && ksk.decomposition_level_count() == params.ks_level
+            && ksk.ciphertext_modulus() == CiphertextModulus::new_native()
+            && ksk.as_ref().len() == expected_ksk_len;
+
+        bsk_is_conformant && ksk_is_conformant && self.pbs_order == params.pbs_order
+    }
+}
+
 /// A structure containing the compressed server public key.
 ///
 /// This server key data lives on the CPU.
@@ -126,15 +435,56 @@ impl ServerKey {
 /// * `bootstrapping_key` - a public key, used to perform the bootstrapping operation.
 /// * `key_switching_key` - a public key, used to perform the key-switching operation.
 #[derive(Clone, Serialize, Deserialize)]
-pub struct CompressedServerKey {
-    pub(crate) bootstrapping_key: SeededLweBootstrapKeyOwned<u32>,
-    pub(crate) key_switching_key: SeededLweKeyswitchKeyOwned<u32>,
+pub struct CompressedServerKey<Scalar: UnsignedInteger = u32> {
+    pub(crate) bootstrapping_key: SeededLweBootstrapKeyOwned<Scalar>,
+    pub(crate) key_switching_key: SeededLweKeyswitchKeyOwned<Scalar>,
     pub(crate) pbs_order: PBSOrder,
 }
 
+impl crate::named::Named for CompressedServerKey {
+    const NAME: &'static str = "boolean::CompressedServerKey";
+}
+
+impl ParameterSetConformant for CompressedServerKey {
+    type ParameterSet = ServerKeyConformanceParams;
+
+    fn is_conformant(&self, params: &ServerKeyConformanceParams) -> bool {
+        let glwe_size = params.glwe_dimension.to_glwe_size();
+        let big_lwe_dimension = params
+            .glwe_dimension
+            .to_equivalent_lwe_dimension(params.polynomial_size);
+
+        let bsk = &self.bootstrapping_key;
+        let expected_bsk_len = params.lwe_dimension.0
+            * seeded_ggsw_ciphertext_size(glwe_size, params.polynomial_size, params.pbs_level);
+        let bsk_is_conformant = bsk.input_lwe_dimension() == params.lwe_dimension
+            && bsk.glwe_size() == glwe_size
+            && bsk.polynomial_size() == params.polynomial_size
+            && bsk.decomposition_base_log() == params.pbs_base_log
+            && bsk.decomposition_level_count() == params.pbs_level
+            && bsk.ciphertext_modulus() == CiphertextModulus::new_native()
+            && bsk.as_ref().len() == expected_bsk_len;
+
+        let ksk = &self.key_switching_key;
+        let expected_ksk_len = big_lwe_dimension.0
+            * seeded_lwe_keyswitch_key_input_key_element_encrypted_size(params.ks_level);
+        let ksk_is_conformant = ksk.input_key_lwe_dimension() == big_lwe_dimension
+            && ksk.output_key_lwe_dimension() == params.lwe_dimension
+            && ksk.decomposition_base_log() == params.ks_base_log
+            && ksk.decomposition_level_count() == params.ks_level
+            && ksk.ciphertext_modulus() == CiphertextModulus::new_native()
+            && ksk.as_ref().len() == expected_ksk_len;
+
+        bsk_is_conformant && ksk_is_conformant && self.pbs_order == params.pbs_order
+    }
+}
+
 /// Perform ciphertext bootstraps on the CPU
-pub(crate) struct Bootstrapper {
-    memory: Memory,
+///
+/// The bootstraps are generic over the `Scalar` of the ciphertexts, but the keys are only
+/// generated for the 32 bits ciphertexts of the Boolean API, see [ServerKey].
+pub(crate) struct Bootstrapper<Scalar = u32> {
+    memory: Memory<Scalar>,
     /// A structure containing two CSPRNGs to generate material for encryption like public masks
     /// and secret errors.
     ///
@@ -146,7 +496,7 @@ pub(crate) struct Bootstrapper {
     pub(crate) seeder: DeterministicSeeder<ActivatedRandomGenerator>,
 }
 
-impl Bootstrapper {
+impl<Scalar: UnsignedInteger> Bootstrapper<Scalar> {
     pub fn new(seeder: &mut dyn Seeder) -> Self {
         Bootstrapper {
             memory: Default::default(),
@@ -155,21 +505,49 @@ impl Bootstrapper {
             seeder: DeterministicSeeder::<_>::new(seeder.seed()),
         }
     }
+}
 
+impl Bootstrapper {
     pub(crate) fn new_server_key(
         &mut self,
         cks: &ClientKey,
     ) -> Result<ServerKey, Box<dyn std::error::Error>> {
-        let standard_bootstrapping_key: LweBootstrapKeyOwned<u32> =
-            par_allocate_and_generate_new_lwe_bootstrap_key(
-                &cks.lwe_secret_key,
-                &cks.glwe_secret_key,
-                cks.parameters.pbs_base_log,
-                cks.parameters.pbs_level,
-                cks.parameters.glwe_modular_std_dev,
-                CiphertextModulus::new_native(),
-                &mut self.encryption_generator,
-            );
+        let server_key = self.new_server_key_with_progress(cks, &|| ControlFlow::Continue(()))?;
+        // The progress callback never stops the generation
+        Ok(server_key.unwrap())
+    }
+
+    /// Generate the server key, calling `on_ggsw_encrypted` each time one of the GGSW ciphertexts
+    /// of the bootstrapping key is encrypted.
+    ///
+    /// Returns `None` if `on_ggsw_encrypted` stopped the generation of the bootstrapping key.
+    pub(crate) fn new_server_key_with_progress(
+        &mut self,
+        cks: &ClientKey,
+        on_ggsw_encrypted: &(dyn Fn() -> ControlFlow<()> + Sync),
+    ) -> Result<Option<ServerKey>, Box<dyn std::error::Error>> {
+        let mut standard_bootstrapping_key = LweBootstrapKeyOwned::new(
+            0u32,
+            cks.parameters.glwe_dimension.to_glwe_size(),
+            cks.parameters.polynomial_size,
+            cks.parameters.pbs_base_log,
+            cks.parameters.pbs_level,
+            cks.parameters.lwe_dimension,
+            CiphertextModulus::new_native(),
+        );
+
+        if par_generate_lwe_bootstrap_key_with_progress(
+            &cks.lwe_secret_key,
+            &cks.glwe_secret_key,
+            &mut standard_bootstrapping_key,
+            cks.parameters.glwe_modular_std_dev,
+            &mut self.encryption_generator,
+            on_ggsw_encrypted,
+        )
+        .is_break()
+        {
+            return Ok(None);
+        }
 
         // creation of the bootstrapping key in the Fourier domain
         let mut fourier_bsk = FourierLweBootstrapKey::new(
@@ -194,6 +572,80 @@ impl Bootstrapper {
             &mut fourier_bsk,
         );
 
+        Ok(Some(self.new_server_key_with_bootstrapping_key(
+            cks,
+            BooleanBootstrappingKey::Classic(fourier_bsk),
+        )))
+    }
+
+    /// Generate a server key whose bootstrapping key is a multi bit one, its PBS runs on
+    /// `thread_count` threads.
+    ///
+    /// The client key must have been generated with the parameters of the multi bit parameter set.
+    pub(crate) fn new_multi_bit_server_key(
+        &mut self,
+        cks: &ClientKey,
+        parameters: &BooleanMultiBitParameters,
+        thread_count: ThreadCount,
+    ) -> Result<ServerKey, Box<dyn std::error::Error>> {
+        if cks.parameters != parameters.parameters {
+            return Err("The client key parameters differ from the multi bit parameters".into());
+        }
+        let grouping_factor = parameters.grouping_factor;
+        if !is_supported_grouping_factor(grouping_factor, cks.parameters.lwe_dimension) {
+            return Err(format!(
+                "Unsupported grouping factor {} for the LWE dimension {}: the grouping factor \
+                must be 2 or 3 and divide the LWE dimension",
+                grouping_factor.0, cks.parameters.lwe_dimension.0
+            )
+            .into());
+        }
+        if thread_count.0 == 0 {
+            return Err("ThreadCount must be greater than 0".into());
+        }
+
+        let standard_bootstrapping_key: LweMultiBitBootstrapKeyOwned<u32> =
+            par_allocate_and_generate_new_lwe_multi_bit_bootstrap_key(
+                &cks.lwe_secret_key,
+                &cks.glwe_secret_key,
+                cks.parameters.pbs_base_log,
+                cks.parameters.pbs_level,
+                grouping_factor,
+                cks.parameters.glwe_modular_std_dev,
+                CiphertextModulus::new_native(),
+                &mut self.encryption_generator,
+            );
+
+        // creation of the bootstrapping key in the Fourier domain
+        let mut fourier_bsk = FourierLweMultiBitBootstrapKey::new(
+            standard_bootstrapping_key.input_lwe_dimension(),
+            standard_bootstrapping_key.glwe_size(),
+            standard_bootstrapping_key.polynomial_size(),
+            standard_bootstrapping_key.decomposition_base_log(),
+            standard_bootstrapping_key.decomposition_level_count(),
+            standard_bootstrapping_key.grouping_factor(),
+        );
+
+        // Conversion to fourier domain
+        par_convert_standard_lwe_multi_bit_bootstrap_key_to_fourier(
+            &standard_bootstrapping_key,
+            &mut fourier_bsk,
+        );
+
+        Ok(self.new_server_key_with_bootstrapping_key(
+            cks,
+            BooleanBootstrappingKey::MultiBit {
+                fourier_bsk,
+                thread_count,
+            },
+        ))
+    }
+
+    fn new_server_key_with_bootstrapping_key(
+        &mut self,
+        cks: &ClientKey,
+        bootstrapping_key: BooleanBootstrappingKey,
+    ) -> ServerKey {
         // Convert the GLWE secret key into an LWE secret key:
         let big_lwe_secret_key = cks.glwe_secret_key.clone().into_lwe_secret_key();
 
@@ -208,11 +660,11 @@ impl Bootstrapper {
             &mut self.encryption_generator,
         );
 
-        Ok(ServerKey {
-            bootstrapping_key: fourier_bsk,
+        ServerKey {
+            bootstrapping_key,
             key_switching_key: ksk,
             pbs_order: cks.parameters.encryption_key_choice.into(),
-        })
+        }
     }
 
     pub(crate) fn new_compressed_server_key(
@@ -261,40 +713,41 @@ impl Bootstrapper {
         })
     }
 
-    pub(crate) fn bootstrap(
+    pub(crate) fn apply_bootstrapping_pattern(
         &mut self,
-        input: &LweCiphertextOwned<u32>,
+        ct: LweCiphertextOwned<u32>,
         server_key: &ServerKey,
-    ) -> Result<LweCiphertextOwned<u32>, Box<dyn Error>> {
+    ) -> Result<Ciphertext, Box<dyn Error>> {
+        let ct = match server_key.pbs_order {
+            PBSOrder::KeyswitchBootstrap => self.keyswitch_bootstrap(ct, server_key),
+            PBSOrder::BootstrapKeyswitch => self.bootstrap_keyswitch(ct, server_key),
+        }?;
+        Ok(Ciphertext::Encrypted(ct))
+    }
+}
+
+impl<Scalar> Bootstrapper<Scalar>
+where
+    // CastInto required for PBS modulus switch which returns a usize
+    Scalar: UnsignedTorus + CastInto<usize> + CastFrom<usize>,
+{
+    pub(crate) fn bootstrap(
+        &mut self,
+        input: &LweCiphertextOwned<Scalar>,
+        server_key: &ServerKey<Scalar>,
+    ) -> Result<LweCiphertextOwned<Scalar>, Box<dyn Error>> {
         let BuffersRef {
             lookup_table: accumulator,
             mut buffer_lwe_after_pbs,
             ..
         } = self.memory.as_buffers(server_key);
 
-        let fourier_bsk = &server_key.bootstrapping_key;
-
-        let fft = Fft::new(fourier_bsk.polynomial_size());
-        let fft = fft.as_view();
-
-        self.computation_buffers.resize(
-            programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement::<u64>(
-                fourier_bsk.glwe_size(),
-                fourier_bsk.polynomial_size(),
-                fft,
-            )
-            .unwrap()
-            .unaligned_bytes_required(),
-        );
-        let stack = self.computation_buffers.stack();
-
-        programmable_bootstrap_lwe_ciphertext_mem_optimized(
+        programmable_bootstrap(
+            &mut self.computation_buffers,
             input,
             &mut buffer_lwe_after_pbs,
             &accumulator,
-            fourier_bsk,
-            fft,
-            stack,
+            &server_key.bootstrapping_key,
         );
 
         Ok(LweCiphertext::from_container(
@@ -305,12 +758,12 @@ impl Bootstrapper {
 
     pub(crate) fn keyswitch(
         &mut self,
-        input: &LweCiphertextOwned<u32>,
-        server_key: &ServerKey,
-    ) -> Result<LweCiphertextOwned<u32>, Box<dyn Error>> {
+        input: &LweCiphertextOwned<Scalar>,
+        server_key: &ServerKey<Scalar>,
+    ) -> Result<LweCiphertextOwned<Scalar>, Box<dyn Error>> {
         // Allocate the output of the KS
         let mut output = LweCiphertext::new(
-            0u32,
+            Scalar::ZERO,
             server_key
                 .bootstrapping_key
                 .input_lwe_dimension()
@@ -325,39 +778,22 @@ impl Bootstrapper {
 
     pub(crate) fn bootstrap_keyswitch(
         &mut self,
-        mut ciphertext: LweCiphertextOwned<u32>,
-        server_key: &ServerKey,
-    ) -> Result<Ciphertext, Box<dyn Error>> {
+        mut ciphertext: LweCiphertextOwned<Scalar>,
+        server_key: &ServerKey<Scalar>,
+    ) -> Result<LweCiphertextOwned<Scalar>, Box<dyn Error>> {
         let BuffersRef {
             lookup_table,
             mut buffer_lwe_after_pbs,
             ..
         } = self.memory.as_buffers(server_key);
 
-        let fourier_bsk = &server_key.bootstrapping_key;
-
-        let fft = Fft::new(fourier_bsk.polynomial_size());
-        let fft = fft.as_view();
-
-        self.computation_buffers.resize(
-            programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement::<u64>(
-                fourier_bsk.glwe_size(),
-                fourier_bsk.polynomial_size(),
-                fft,
-            )
-            .unwrap()
-            .unaligned_bytes_required(),
-        );
-        let stack = self.computation_buffers.stack();
-
         // Compute a bootstrap
-        programmable_bootstrap_lwe_ciphertext_mem_optimized(
+        programmable_bootstrap(
+            &mut self.computation_buffers,
             &ciphertext,
             &mut buffer_lwe_after_pbs,
             &lookup_table,
-            fourier_bsk,
-            fft,
-            stack,
+            &server_key.bootstrapping_key,
         );
 
         // Compute a key switch to get back to input key
@@ -367,36 +803,20 @@ impl Bootstrapper {
             &mut ciphertext,
         );
 
-        Ok(Ciphertext::Encrypted(ciphertext))
+        Ok(ciphertext)
     }
 
     pub(crate) fn keyswitch_bootstrap(
         &mut self,
-        mut ciphertext: LweCiphertextOwned<u32>,
-        server_key: &ServerKey,
-    ) -> Result<Ciphertext, Box<dyn Error>> {
+        mut ciphertext: LweCiphertextOwned<Scalar>,
+        server_key: &ServerKey<Scalar>,
+    ) -> Result<LweCiphertextOwned<Scalar>, Box<dyn Error>> {
         let BuffersRef {
             lookup_table,
             mut buffer_lwe_after_ks,
             ..
         } = self.memory.as_buffers(server_key);
 
-        let fourier_bsk = &server_key.bootstrapping_key;
-
-        let fft = Fft::new(fourier_bsk.polynomial_size());
-        let fft = fft.as_view();
-
-        self.computation_buffers.resize(
-            programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement::<u64>(
-                fourier_bsk.glwe_size(),
-                fourier_bsk.polynomial_size(),
-                fft,
-            )
-            .unwrap()
-            .unaligned_bytes_required(),
-        );
-        let stack = self.computation_buffers.stack();
-
         // Keyswitch from large LWE key to the small one
         keyswitch_lwe_ciphertext(
             &server_key.key_switching_key,
@@ -405,25 +825,68 @@ impl Bootstrapper {
         );
 
         // Compute a bootstrap
-        programmable_bootstrap_lwe_ciphertext_mem_optimized(
+        programmable_bootstrap(
+            &mut self.computation_buffers,
             &buffer_lwe_after_ks,
             &mut ciphertext,
             &lookup_table,
-            fourier_bsk,
-            fft,
-            stack,
+            &server_key.bootstrapping_key,
         );
 
-        Ok(Ciphertext::Encrypted(ciphertext))
+        Ok(ciphertext)
     }
-    pub(crate) fn apply_bootstrapping_pattern(
-        &mut self,
-        ct: LweCiphertextOwned<u32>,
-        server_key: &ServerKey,
-    ) -> Result<Ciphertext, Box<dyn Error>> {
-        match server_key.pbs_order {
-            PBSOrder::KeyswitchBootstrap => self.keyswitch_bootstrap(ct, server_key),
-            PBSOrder::BootstrapKeyswitch => self.bootstrap_keyswitch(ct, server_key),
+}
+
+/// Bootstrap `input` into `output` with the classic or the multi bit PBS, depending on the
+/// bootstrapping key.
+fn programmable_bootstrap<Scalar, InputCont, OutputCont, AccCont>(
+    computation_buffers: &mut ComputationBuffers,
+    input: &LweCiphertext<InputCont>,
+    output: &mut LweCiphertext<OutputCont>,
+    accumulator: &GlweCiphertext<AccCont>,
+    bootstrapping_key: &BooleanBootstrappingKey,
+) where
+    Scalar: UnsignedTorus + CastInto<usize> + CastFrom<usize>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    AccCont: Container<Element = Scalar>,
+{
+    match bootstrapping_key {
+        BooleanBootstrappingKey::Classic(fourier_bsk) => {
+            let fft = Fft::new(fourier_bsk.polynomial_size());
+            let fft = fft.as_view();
+
+            computation_buffers.resize(
+                programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement::<Scalar>(
+                    fourier_bsk.glwe_size(),
+                    fourier_bsk.polynomial_size(),
+                    fft,
+                )
+                .unwrap()
+                .unaligned_bytes_required(),
+            );
+            let stack = computation_buffers.stack();
+
+            programmable_bootstrap_lwe_ciphertext_mem_optimized(
+                input,
+                output,
+                accumulator,
+                fourier_bsk,
+                fft,
+                stack,
+            );
+        }
+        BooleanBootstrappingKey::MultiBit {
+            fourier_bsk,
+            thread_count,
+        } => {
+            multi_bit_programmable_bootstrap_lwe_ciphertext(
+                input,
+                output,
+                accumulator,
+                fourier_bsk,
+                *thread_count,
+            );
         }
     }
 }
@@ -459,7 +922,7 @@ impl From<CompressedServerKey> for ServerKey {
 
         Self {
             key_switching_key,
-            bootstrapping_key,
+            bootstrapping_key: BooleanBootstrappingKey::Classic(bootstrapping_key),
             pbs_order,
         }
     }
@@ -5,12 +5,14 @@
 
 use crate::boolean::ciphertext::{Ciphertext, CompressedCiphertext};
 use crate::boolean::parameters::{
-    BooleanKeySwitchingParameters, BooleanParameters, EncryptionKeyChoice,
+    BooleanKeySwitchingParameters, BooleanMultiBitParameters, BooleanParameters,
+    EncryptionKeyChoice,
 };
 use crate::boolean::{ClientKey, CompressedPublicKey, PublicKey, PLAINTEXT_FALSE, PLAINTEXT_TRUE};
 use crate::core_crypto::algorithms::*;
 use crate::core_crypto::entities::*;
 use std::cell::RefCell;
+use std::ops::ControlFlow;
 pub mod bootstrapping;
 use crate::boolean::engine::bootstrapping::{Bootstrapper, CompressedServerKey, ServerKey};
 use crate::core_crypto::commons::generators::{
@@ -106,6 +108,31 @@ impl BooleanEngine {
         self.bootstrapper.new_server_key(cks).unwrap()
     }
 
+    /// Generate a server key, calling `on_ggsw_encrypted` each time one of the GGSW ciphertexts
+    /// of the bootstrapping key is encrypted.
+    ///
+    /// Returns `None` if `on_ggsw_encrypted` stopped the generation.
+    pub(crate) fn create_server_key_with_progress(
+        &mut self,
+        cks: &ClientKey,
+        on_ggsw_encrypted: &(dyn Fn() -> ControlFlow<()> + Sync),
+    ) -> Option<ServerKey> {
+        self.bootstrapper
+            .new_server_key_with_progress(cks, on_ggsw_encrypted)
+            .unwrap()
+    }
+
+    pub fn create_multi_bit_server_key(
+        &mut self,
+        cks: &ClientKey,
+        parameters: &BooleanMultiBitParameters,
+        thread_count: ThreadCount,
+    ) -> ServerKey {
+        self.bootstrapper
+            .new_multi_bit_server_key(cks, parameters, thread_count)
+            .unwrap()
+    }
+
     pub fn create_compressed_server_key(&mut self, cks: &ClientKey) -> CompressedServerKey {
         self.bootstrapper.new_compressed_server_key(cks).unwrap()
     }
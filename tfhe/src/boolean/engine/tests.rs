@@ -52,3 +52,90 @@ fn test_replacing_thread_local_engine() {
     .unwrap();
     assert_eq!(third_thread_data, main_thread_data);
 }
+
+#[test]
+fn test_bootstrapper_with_64_bits_ciphertexts() {
+    use crate::boolean::engine::bootstrapping::{BooleanBootstrappingKey, Bootstrapper, ServerKey};
+    use crate::boolean::parameters::DEFAULT_PARAMETERS;
+    use crate::boolean::plaintext_true;
+    use crate::core_crypto::commons::math::random::Seeder;
+    use crate::core_crypto::prelude::*;
+
+    let params = DEFAULT_PARAMETERS;
+    let ciphertext_modulus = CiphertextModulus::new_native();
+
+    let mut seeder = new_seeder();
+    let mut bootstrapper = Bootstrapper::<u64>::new(seeder.as_mut());
+    let mut secret_generator =
+        SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+
+    let lwe_secret_key = allocate_and_generate_new_binary_lwe_secret_key(
+        params.lwe_dimension,
+        &mut secret_generator,
+    );
+    let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+        params.glwe_dimension,
+        params.polynomial_size,
+        &mut secret_generator,
+    );
+    let big_lwe_secret_key = glwe_secret_key.clone().into_lwe_secret_key();
+
+    let standard_bootstrapping_key: LweBootstrapKeyOwned<u64> =
+        par_allocate_and_generate_new_lwe_bootstrap_key(
+            &lwe_secret_key,
+            &glwe_secret_key,
+            params.pbs_base_log,
+            params.pbs_level,
+            params.glwe_modular_std_dev,
+            ciphertext_modulus,
+            &mut bootstrapper.encryption_generator,
+        );
+    let mut bootstrapping_key = FourierLweBootstrapKey::new(
+        standard_bootstrapping_key.input_lwe_dimension(),
+        standard_bootstrapping_key.glwe_size(),
+        standard_bootstrapping_key.polynomial_size(),
+        standard_bootstrapping_key.decomposition_base_log(),
+        standard_bootstrapping_key.decomposition_level_count(),
+    );
+    par_convert_standard_lwe_bootstrap_key_to_fourier(
+        &standard_bootstrapping_key,
+        &mut bootstrapping_key,
+    );
+
+    let key_switching_key = allocate_and_generate_new_lwe_keyswitch_key(
+        &big_lwe_secret_key,
+        &lwe_secret_key,
+        params.ks_base_log,
+        params.ks_level,
+        params.lwe_modular_std_dev,
+        ciphertext_modulus,
+        &mut bootstrapper.encryption_generator,
+    );
+
+    let server_key = ServerKey::<u64> {
+        bootstrapping_key: BooleanBootstrappingKey::Classic(bootstrapping_key),
+        key_switching_key,
+        pbs_order: PBSOrder::KeyswitchBootstrap,
+    };
+
+    for message in [true, false] {
+        // false is encoded as -1/8
+        let plaintext = if message {
+            plaintext_true::<u64>()
+        } else {
+            plaintext_true::<u64>().wrapping_neg()
+        };
+        let ct = allocate_and_encrypt_new_lwe_ciphertext(
+            &big_lwe_secret_key,
+            Plaintext(plaintext),
+            params.glwe_modular_std_dev,
+            ciphertext_modulus,
+            &mut bootstrapper.encryption_generator,
+        );
+
+        let ct = bootstrapper.keyswitch_bootstrap(ct, &server_key).unwrap();
+
+        let decrypted = decrypt_lwe_ciphertext(&big_lwe_secret_key, &ct);
+        assert_eq!(decrypted.0 < (1 << 63), message);
+    }
+}
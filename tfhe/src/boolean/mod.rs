@@ -56,6 +56,7 @@ use crate::boolean::client_key::ClientKey;
 use crate::boolean::parameters::DEFAULT_PARAMETERS;
 use crate::boolean::public_key::{CompressedPublicKey, PublicKey};
 use crate::boolean::server_key::ServerKey;
+use crate::core_crypto::commons::numeric::UnsignedInteger;
 #[cfg(test)]
 use rand::Rng;
 
@@ -79,6 +80,11 @@ pub(crate) const PLAINTEXT_TRUE: u32 = 1 << (32 - PLAINTEXT_LOG_SCALING_FACTOR);
 /// The plaintext associated with false: -1/8
 pub(crate) const PLAINTEXT_FALSE: u32 = 7 << (32 - PLAINTEXT_LOG_SCALING_FACTOR);
 
+/// The encoding of true for ciphertexts of any size, [PLAINTEXT_TRUE] for 32 bits ciphertexts
+pub(crate) fn plaintext_true<Scalar: UnsignedInteger>() -> Scalar {
+    Scalar::ONE << (Scalar::BITS - PLAINTEXT_LOG_SCALING_FACTOR)
+}
+
 /// tool to generate random booleans
 #[cfg(test)]
 pub(crate) fn random_boolean() -> bool {
@@ -14,15 +14,26 @@
 //! The two parameter sets offer a trade-off in terms of execution time versus error probability.
 //! The `DEFAULT_PARAMETERS` set offers better performances on homomorphic circuit evaluation
 //! with an higher probability error in comparison with the `TFHE_LIB_PARAMETERS`.
+//!
+//! The 128-bits secure sets are listed in [`BOOLEAN_PARAMETER_SETS`], from the fastest gates to
+//! the slowest, with the bound on their error probability. [`PARAMETERS_FAST`] and
+//! [`PARAMETERS_ULTRA_LOW_PFAIL`] name the two ends of this trade-off, and
+//! [`NamedBooleanParameters::fastest_with_error_probability`] picks the fastest set meeting a
+//! required error probability.
+//! The gates can also use the multi bit PBS, with the parameter sets
+//! [`DEFAULT_MULTI_BIT_GROUP_2_PARAMETERS`] and [`DEFAULT_MULTI_BIT_GROUP_3_PARAMETERS`].
+//! [`PARAMETERS_SIMULATOR`] is an insecure set to quickly test the logic of boolean circuits.
 //! Note that if you desire, you can also create your own set of parameters.
 //! Failing to properly fix the parameters will potentially result with an incorrect and/or insecure
 //! computation.
 
 pub use crate::core_crypto::commons::dispersion::StandardDev;
+use crate::core_crypto::commons::parameters::PBSOrder;
 pub use crate::core_crypto::commons::parameters::{
     DecompositionBaseLog, DecompositionLevelCount, EncryptionKeyChoice, GlweDimension,
-    LweDimension, PolynomialSize,
+    LweBskGroupingFactor, LweDimension, PolynomialSize, ThreadCount,
 };
+use crate::core_crypto::prelude::{CiphertextModulus, LweCiphertextParameters};
 
 use serde::{Deserialize, Serialize};
 
@@ -90,6 +101,71 @@ impl BooleanParameters {
     }
 }
 
+impl BooleanParameters {
+    pub fn to_ciphertext_conformance_params(&self) -> CiphertextConformanceParams {
+        let lwe_dim = match self.encryption_key_choice {
+            EncryptionKeyChoice::Big => self
+                .glwe_dimension
+                .to_equivalent_lwe_dimension(self.polynomial_size),
+            EncryptionKeyChoice::Small => self.lwe_dimension,
+        };
+
+        CiphertextConformanceParams {
+            ct_params: LweCiphertextParameters {
+                lwe_dim,
+                ct_modulus: CiphertextModulus::new_native(),
+            },
+        }
+    }
+
+    pub fn to_server_key_conformance_params(&self) -> ServerKeyConformanceParams {
+        ServerKeyConformanceParams {
+            lwe_dimension: self.lwe_dimension,
+            glwe_dimension: self.glwe_dimension,
+            polynomial_size: self.polynomial_size,
+            pbs_base_log: self.pbs_base_log,
+            pbs_level: self.pbs_level,
+            ks_base_log: self.ks_base_log,
+            ks_level: self.ks_level,
+            pbs_order: self.encryption_key_choice.into(),
+        }
+    }
+
+    pub fn to_client_key_conformance_params(&self) -> ClientKeyConformanceParams {
+        ClientKeyConformanceParams { parameters: *self }
+    }
+}
+
+/// Structure to store the expected properties of a ciphertext
+/// Can be used on a server to check if client inputs are well formed
+/// before running a computation on them
+#[derive(Copy, Clone)]
+pub struct CiphertextConformanceParams {
+    pub ct_params: LweCiphertextParameters<u32>,
+}
+
+/// Structure to store the expected properties of a server key
+/// Can be used on a server to check if a key received from a client is well formed
+/// before using it
+#[derive(Copy, Clone)]
+pub struct ServerKeyConformanceParams {
+    pub lwe_dimension: LweDimension,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub pbs_base_log: DecompositionBaseLog,
+    pub pbs_level: DecompositionLevelCount,
+    pub ks_base_log: DecompositionBaseLog,
+    pub ks_level: DecompositionLevelCount,
+    pub pbs_order: PBSOrder,
+}
+
+/// Structure to store the expected properties of a client key
+/// Can be used to check if a deserialized client key is well formed before using it
+#[derive(Copy, Clone)]
+pub struct ClientKeyConformanceParams {
+    pub parameters: BooleanParameters,
+}
+
 /// A set of cryptographic parameters for homomorphic Boolean key switching.
 #[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct BooleanKeySwitchingParameters {
@@ -194,4 +270,141 @@ pub const TFHE_LIB_PARAMETERS: BooleanParameters = BooleanParameters {
     encryption_key_choice: EncryptionKeyChoice::Small,
 };
 
+/// The parameter set with the fastest gates, with an error probability upper-bounded by
+/// $2^{-40}$, as [`DEFAULT_PARAMETERS`].
+///
+/// Its LWE dimension is smaller than the one of [`DEFAULT_PARAMETERS`], which makes the bootstrap
+/// cheaper, but its ciphertexts are encrypted under the large key.
+pub const PARAMETERS_FAST: BooleanParameters = DEFAULT_PARAMETERS_KS_PBS;
+
+/// The fastest parameter set with an error probability upper-bounded by $2^{-165}$, for
+/// applications evaluating enough gates that a failure probability of $2^{-40}$ per gate is not
+/// acceptable, e.g. any requirement between $2^{-64}$ and $2^{-165}$.
+pub const PARAMETERS_ULTRA_LOW_PFAIL: BooleanParameters =
+    PARAMETERS_ERROR_PROB_2_POW_MINUS_165_KS_PBS;
+
+/// Parameters to simulate boolean circuits, they are **not secure**.
+///
+/// They run the same algorithms as the secure sets, with LWE and GLWE dimensions small enough
+/// for a bootstrapped gate to take a fraction of a millisecond and no encryption noise. They are
+/// used for the boolean type of the simulator configuration of the high level API.
+///
+/// # Warning
+///
+/// Ciphertexts encrypted with these parameters do not protect the messages at all, they must
+/// never be used outside of tests.
+pub const PARAMETERS_SIMULATOR: BooleanParameters = BooleanParameters {
+    lwe_dimension: LweDimension(8),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(512),
+    lwe_modular_std_dev: StandardDev(0.0),
+    glwe_modular_std_dev: StandardDev(0.0),
+    pbs_base_log: DecompositionBaseLog(23),
+    pbs_level: DecompositionLevelCount(1),
+    ks_base_log: DecompositionBaseLog(8),
+    ks_level: DecompositionLevelCount(4),
+    encryption_key_choice: EncryptionKeyChoice::Big,
+};
+
+/// A set of cryptographic parameters for homomorphic Boolean circuit evaluation whose gates use
+/// the multi bit PBS, see
+/// [`ServerKey::new_multi_bit`](super::server_key::ServerKey::new_multi_bit).
+///
+/// The bits of the LWE secret key are grouped by `grouping_factor` in the bootstrapping key, which
+/// must be 2 or 3 and divide the LWE dimension.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct BooleanMultiBitParameters {
+    pub parameters: BooleanParameters,
+    pub grouping_factor: LweBskGroupingFactor,
+}
+
+/// [`DEFAULT_PARAMETERS`] with a multi bit bootstrapping key grouping the key bits by 2.
+///
+/// # Warning
+///
+/// These parameters were not optimized for the multi bit PBS, its noise was not analyzed with
+/// them: neither the probability of error of [`DEFAULT_PARAMETERS`] nor its security level are
+/// guaranteed.
+pub const DEFAULT_MULTI_BIT_GROUP_2_PARAMETERS: BooleanMultiBitParameters =
+    BooleanMultiBitParameters {
+        parameters: DEFAULT_PARAMETERS,
+        grouping_factor: LweBskGroupingFactor(2),
+    };
+
+/// [`DEFAULT_PARAMETERS`] with a multi bit bootstrapping key grouping the key bits by 3.
+///
+/// The LWE dimension is raised to 723, the next multiple of 3, with the same noise.
+///
+/// # Warning
+///
+/// These parameters were not optimized for the multi bit PBS, its noise was not analyzed with
+/// them: neither the probability of error of [`DEFAULT_PARAMETERS`] nor its security level are
+/// guaranteed.
+pub const DEFAULT_MULTI_BIT_GROUP_3_PARAMETERS: BooleanMultiBitParameters =
+    BooleanMultiBitParameters {
+        parameters: BooleanParameters {
+            lwe_dimension: LweDimension(723),
+            ..DEFAULT_PARAMETERS
+        },
+        grouping_factor: LweBskGroupingFactor(3),
+    };
+
+/// A parameter set provided by the library, with its name and its error probability.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct NamedBooleanParameters {
+    /// Name of the constant of the parameter set in this module
+    pub name: &'static str,
+    pub parameters: BooleanParameters,
+    /// Upper bound of the log2 of the probability of error of a bootstrapped gate
+    pub log2_p_error: i32,
+}
+
+/// The 128-bits secure parameter sets, ordered from the fastest gates to the slowest.
+///
+/// [`TFHE_LIB_PARAMETERS`] is not listed as its security is below 128 bits.
+pub const BOOLEAN_PARAMETER_SETS: [NamedBooleanParameters; 4] = [
+    NamedBooleanParameters {
+        name: "DEFAULT_PARAMETERS_KS_PBS",
+        parameters: DEFAULT_PARAMETERS_KS_PBS,
+        log2_p_error: -40,
+    },
+    NamedBooleanParameters {
+        name: "DEFAULT_PARAMETERS",
+        parameters: DEFAULT_PARAMETERS,
+        log2_p_error: -40,
+    },
+    NamedBooleanParameters {
+        name: "PARAMETERS_ERROR_PROB_2_POW_MINUS_165_KS_PBS",
+        parameters: PARAMETERS_ERROR_PROB_2_POW_MINUS_165_KS_PBS,
+        log2_p_error: -165,
+    },
+    NamedBooleanParameters {
+        name: "PARAMETERS_ERROR_PROB_2_POW_MINUS_165",
+        parameters: PARAMETERS_ERROR_PROB_2_POW_MINUS_165,
+        log2_p_error: -165,
+    },
+];
+
+impl NamedBooleanParameters {
+    /// Returns the fastest set of [`BOOLEAN_PARAMETER_SETS`] whose error probability is at most
+    /// $2^{max\_log2\_p\_error}$, or None if no set meets it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::boolean::client_key::ClientKey;
+    /// use tfhe::boolean::parameters::{NamedBooleanParameters, PARAMETERS_ULTRA_LOW_PFAIL};
+    ///
+    /// let set = NamedBooleanParameters::fastest_with_error_probability(-64).unwrap();
+    /// assert_eq!(set.parameters, PARAMETERS_ULTRA_LOW_PFAIL);
+    ///
+    /// let cks = ClientKey::new(&set.parameters);
+    /// ```
+    pub fn fastest_with_error_probability(max_log2_p_error: i32) -> Option<Self> {
+        BOOLEAN_PARAMETER_SETS
+            .into_iter()
+            .find(|set| set.log2_p_error <= max_log2_p_error)
+    }
+}
+
 pub const VEC_BOOLEAN_PARAM: [BooleanParameters; 2] = [DEFAULT_PARAMETERS, TFHE_LIB_PARAMETERS];
@@ -10,10 +10,14 @@ mod tests;
 
 use crate::boolean::ciphertext::Ciphertext;
 use crate::boolean::client_key::ClientKey;
-pub use crate::boolean::engine::bootstrapping::{CompressedServerKey, ServerKey};
+pub use crate::boolean::engine::bootstrapping::{CompressedServerKey, LegacyServerKey, ServerKey};
 use crate::boolean::engine::{
     BinaryGatesAssignEngine, BinaryGatesEngine, BooleanEngine, WithThreadLocalEngine,
 };
+use crate::boolean::parameters::{BooleanMultiBitParameters, ThreadCount};
+#[cfg(any(not(feature = "__wasm_api"), feature = "parallel-wasm-api"))]
+use rayon::prelude::*;
+use std::ops::ControlFlow;
 
 pub trait BinaryBooleanGates<L, R> {
     fn and(&self, ct_left: L, ct_right: R) -> Ciphertext;
@@ -24,6 +28,17 @@ pub trait BinaryBooleanGates<L, R> {
     fn xnor(&self, ct_left: L, ct_right: R) -> Ciphertext;
 }
 
+/// The binary gates that can be evaluated in batch with [ServerKey::batch_gates]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BooleanGate {
+    And,
+    Nand,
+    Nor,
+    Or,
+    Xor,
+    Xnor,
+}
+
 pub trait BinaryBooleanGatesAssign<L, R> {
     fn and_assign(&self, ct_left: L, ct_right: R);
     fn nand_assign(&self, ct_left: L, ct_right: R);
@@ -132,6 +147,60 @@ impl ServerKey {
         BooleanEngine::with_thread_local_mut(|engine| engine.create_server_key(cks))
     }
 
+    /// Generates a server key, calling `on_ggsw_encrypted` each time one of the GGSW ciphertexts
+    /// of the bootstrapping key is encrypted.
+    ///
+    /// Returns `None` if `on_ggsw_encrypted` stopped the generation.
+    pub(crate) fn new_with_progress(
+        cks: &ClientKey,
+        on_ggsw_encrypted: &(dyn Fn() -> ControlFlow<()> + Sync),
+    ) -> Option<Self> {
+        BooleanEngine::with_thread_local_mut(|engine| {
+            engine.create_server_key_with_progress(cks, on_ggsw_encrypted)
+        })
+    }
+
+    /// Generates a server key whose gates use the multi bit PBS, which groups the bits of the
+    /// LWE secret key by the grouping factor of the `parameters` and runs each bootstrap on
+    /// `thread_count` threads.
+    ///
+    /// The multi bit PBS is faster than the classic one on servers with many cores, see
+    /// [ServerKey::set_multi_bit_pbs_thread_count] to tune the number of threads.
+    ///
+    /// The client key must be generated with the parameters of the multi bit parameter set, see
+    /// the warning of the provided parameter sets about their error probability.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the client key was generated with other parameters, if the grouping factor is
+    /// not 2 or 3, if it does not divide the LWE dimension of the parameters, or if
+    /// `thread_count` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::boolean::prelude::*;
+    ///
+    /// let parameters = DEFAULT_MULTI_BIT_GROUP_3_PARAMETERS;
+    /// let cks = ClientKey::new(&parameters.parameters);
+    /// let sks = ServerKey::new_multi_bit(&cks, &parameters, ThreadCount(4));
+    ///
+    /// let ct_left = cks.encrypt(true);
+    /// let ct_right = cks.encrypt(false);
+    ///
+    /// let ct_res = sks.and(&ct_left, &ct_right);
+    /// assert!(!cks.decrypt(&ct_res));
+    /// ```
+    pub fn new_multi_bit(
+        cks: &ClientKey,
+        parameters: &BooleanMultiBitParameters,
+        thread_count: ThreadCount,
+    ) -> Self {
+        BooleanEngine::with_thread_local_mut(|engine| {
+            engine.create_multi_bit_server_key(cks, parameters, thread_count)
+        })
+    }
+
     pub fn trivial_encrypt(&self, message: bool) -> Ciphertext {
         Ciphertext::Trivial(message)
     }
@@ -156,6 +225,85 @@ impl ServerKey {
     }
 }
 
+impl ServerKey {
+    /// Evaluates a gate on each pair of ciphertexts formed by `lhs` and `rhs`
+    ///
+    /// The gates being independent from each other, they are evaluated in parallel, except in
+    /// the wasm API builds without the `parallel-wasm-api` feature.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lhs` and `rhs` do not have the same length.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::boolean::prelude::*;
+    /// use tfhe::boolean::server_key::BooleanGate;
+    ///
+    /// let (cks, sks) = gen_keys();
+    ///
+    /// let lhs = [true, true, false, false];
+    /// let rhs = [true, false, true, false];
+    ///
+    /// let ct_lhs = lhs.iter().map(|b| cks.encrypt(*b)).collect::<Vec<_>>();
+    /// let ct_rhs = rhs.iter().map(|b| cks.encrypt(*b)).collect::<Vec<_>>();
+    ///
+    /// let ct_res = sks.batch_gates(BooleanGate::Xor, &ct_lhs, &ct_rhs);
+    ///
+    /// for ((ct, l), r) in ct_res.iter().zip(lhs.iter()).zip(rhs.iter()) {
+    ///     assert_eq!(cks.decrypt(ct), l ^ r);
+    /// }
+    /// ```
+    pub fn batch_gates(
+        &self,
+        gate: BooleanGate,
+        lhs: &[Ciphertext],
+        rhs: &[Ciphertext],
+    ) -> Vec<Ciphertext> {
+        assert_eq!(
+            lhs.len(),
+            rhs.len(),
+            "lhs and rhs must have the same number of ciphertexts, got {} and {}",
+            lhs.len(),
+            rhs.len()
+        );
+
+        let evaluate_gate = |(ct_left, ct_right): (&Ciphertext, &Ciphertext)| match gate {
+            BooleanGate::And => self.and(ct_left, ct_right),
+            BooleanGate::Nand => self.nand(ct_left, ct_right),
+            BooleanGate::Nor => self.nor(ct_left, ct_right),
+            BooleanGate::Or => self.or(ct_left, ct_right),
+            BooleanGate::Xor => self.xor(ct_left, ct_right),
+            BooleanGate::Xnor => self.xnor(ct_left, ct_right),
+        };
+
+        #[cfg(any(not(feature = "__wasm_api"), feature = "parallel-wasm-api"))]
+        let result = lhs
+            .par_iter()
+            .zip(rhs.par_iter())
+            .map(evaluate_gate)
+            .collect();
+
+        #[cfg(all(feature = "__wasm_api", not(feature = "parallel-wasm-api")))]
+        let result = lhs.iter().zip(rhs.iter()).map(evaluate_gate).collect();
+
+        result
+    }
+
+    /// Evaluates the NOT gate on each of the ciphertexts, in parallel except in the same builds as
+    /// [ServerKey::batch_gates]
+    pub fn batch_not(&self, cts: &[Ciphertext]) -> Vec<Ciphertext> {
+        #[cfg(any(not(feature = "__wasm_api"), feature = "parallel-wasm-api"))]
+        let result = cts.par_iter().map(|ct| self.not(ct)).collect();
+
+        #[cfg(all(feature = "__wasm_api", not(feature = "parallel-wasm-api")))]
+        let result = cts.iter().map(|ct| self.not(ct)).collect();
+
+        result
+    }
+}
+
 impl CompressedServerKey {
     pub fn new(cks: &ClientKey) -> Self {
         BooleanEngine::with_thread_local_mut(|engine| engine.create_compressed_server_key(cks))
@@ -1,9 +1,12 @@
 use crate::boolean::ciphertext::Ciphertext;
 use crate::boolean::client_key::ClientKey;
 use crate::boolean::keycache::KEY_CACHE;
-use crate::boolean::parameters::BooleanParameters;
-use crate::boolean::server_key::{BinaryBooleanGates, BinaryBooleanGatesAssign, ServerKey};
+use crate::boolean::parameters::{BooleanMultiBitParameters, BooleanParameters, ThreadCount};
+use crate::boolean::server_key::{
+    BinaryBooleanGates, BinaryBooleanGatesAssign, BooleanGate, ServerKey,
+};
 use crate::boolean::{random_boolean, random_integer};
+use crate::conformance::ParameterSetConformant;
 
 /// Number of assert in randomized tests
 #[cfg(not(feature = "__coverage"))]
@@ -67,6 +70,10 @@ mod default_parameters_tests {
     fn test_deep_circuit_default_parameters() {
         test_deep_circuit(DEFAULT_PARAMETERS);
     }
+    #[test]
+    fn test_batch_gates_default_parameters() {
+        test_batch_gates(DEFAULT_PARAMETERS);
+    }
 }
 
 #[cfg(not(feature = "__coverage"))]
@@ -256,6 +263,85 @@ mod tfhe_lib_parameters_tests {
     }
 }
 
+#[cfg(not(feature = "__coverage"))]
+mod multi_bit_tests {
+    use super::*;
+    use crate::boolean::engine::bootstrapping::BooleanBootstrappingKey;
+    use crate::boolean::parameters::{
+        LweBskGroupingFactor, DEFAULT_MULTI_BIT_GROUP_2_PARAMETERS,
+        DEFAULT_MULTI_BIT_GROUP_3_PARAMETERS, DEFAULT_PARAMETERS,
+    };
+    use crate::boolean::server_key::LegacyServerKey;
+    use crate::core_crypto::commons::parameters::PBSOrder;
+    use crate::core_crypto::entities::{FourierLweBootstrapKeyView, LweKeyswitchKeyOwned};
+
+    #[test]
+    fn test_multi_bit_gates_grouping_factor_2() {
+        test_multi_bit_gates(DEFAULT_MULTI_BIT_GROUP_2_PARAMETERS);
+    }
+    #[test]
+    fn test_multi_bit_gates_grouping_factor_3() {
+        test_multi_bit_gates(DEFAULT_MULTI_BIT_GROUP_3_PARAMETERS);
+    }
+    #[test]
+    #[should_panic(expected = "Unsupported grouping factor")]
+    fn test_multi_bit_grouping_factor_not_dividing_lwe_dimension() {
+        // 722 is not a multiple of 3
+        let parameters = BooleanMultiBitParameters {
+            parameters: DEFAULT_PARAMETERS,
+            grouping_factor: LweBskGroupingFactor(3),
+        };
+        let keys = KEY_CACHE.get_from_param(DEFAULT_PARAMETERS);
+        let _ = ServerKey::new_multi_bit(keys.client_key(), &parameters, ThreadCount(1));
+    }
+    #[test]
+    #[should_panic(expected = "The client key parameters differ")]
+    fn test_multi_bit_client_key_with_other_parameters() {
+        let keys = KEY_CACHE.get_from_param(DEFAULT_PARAMETERS);
+        let _ = ServerKey::new_multi_bit(
+            keys.client_key(),
+            &DEFAULT_MULTI_BIT_GROUP_3_PARAMETERS,
+            ThreadCount(1),
+        );
+    }
+    #[test]
+    fn test_legacy_server_key_deserialization() {
+        // The layout of the server keys serialized before the server keys were versioned
+        #[derive(serde::Serialize)]
+        struct PreviousServerKey<'a> {
+            bootstrapping_key: FourierLweBootstrapKeyView<'a>,
+            key_switching_key: &'a LweKeyswitchKeyOwned<u32>,
+            pbs_order: PBSOrder,
+        }
+
+        let keys = KEY_CACHE.get_from_param(DEFAULT_PARAMETERS);
+        let sks = keys.server_key();
+        let bootstrapping_key = match &sks.bootstrapping_key {
+            BooleanBootstrappingKey::Classic(bsk) => bsk.as_view(),
+            BooleanBootstrappingKey::MultiBit { .. } => panic!("Expected a classic key"),
+        };
+        let previous = bincode::serialize(&PreviousServerKey {
+            bootstrapping_key,
+            key_switching_key: &sks.key_switching_key,
+            pbs_order: sks.pbs_order,
+        })
+        .unwrap();
+
+        assert!(bincode::deserialize::<ServerKey>(&previous).is_err());
+
+        let sks: ServerKey = bincode::deserialize::<LegacyServerKey>(&previous)
+            .unwrap()
+            .into();
+        assert_eq!(sks.multi_bit_pbs_thread_count(), None);
+        assert!(sks.is_conformant(&DEFAULT_PARAMETERS.to_server_key_conformance_params()));
+
+        let serialized = bincode::serialize(&sks).unwrap();
+        let sks: ServerKey = bincode::deserialize(&serialized).unwrap();
+        assert_eq!(sks.multi_bit_pbs_thread_count(), None);
+        assert!(sks.is_conformant(&DEFAULT_PARAMETERS.to_server_key_conformance_params()));
+    }
+}
+
 /// test encryption and decryption with the LWE secret key
 fn test_encrypt_decrypt_lwe_secret_key(parameters: BooleanParameters) {
     let keys = KEY_CACHE.get_from_param(parameters);
@@ -911,3 +997,112 @@ fn test_deep_circuit(parameters: BooleanParameters) {
         assert_eq!(*boolean, dec);
     }
 }
+
+fn test_batch_gates(parameters: BooleanParameters) {
+    let keys = KEY_CACHE.get_from_param(parameters);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let lhs = (0..NB_CT).map(|_| random_boolean()).collect::<Vec<_>>();
+    let rhs = (0..NB_CT).map(|_| random_boolean()).collect::<Vec<_>>();
+
+    let ct_lhs = lhs
+        .iter()
+        .map(|b| random_enum_encryption(cks, sks, *b))
+        .collect::<Vec<_>>();
+    let ct_rhs = rhs
+        .iter()
+        .map(|b| random_enum_encryption(cks, sks, *b))
+        .collect::<Vec<_>>();
+
+    let gates: [(BooleanGate, fn(bool, bool) -> bool); 6] = [
+        (BooleanGate::And, |l, r| l && r),
+        (BooleanGate::Nand, |l, r| !(l && r)),
+        (BooleanGate::Nor, |l, r| !(l || r)),
+        (BooleanGate::Or, |l, r| l || r),
+        (BooleanGate::Xor, |l, r| l ^ r),
+        (BooleanGate::Xnor, |l, r| !(l ^ r)),
+    ];
+
+    for (gate, clear_gate) in gates {
+        let ct_res = sks.batch_gates(gate, &ct_lhs, &ct_rhs);
+        assert_eq!(ct_res.len(), NB_CT);
+
+        for ((ct, l), r) in ct_res.iter().zip(lhs.iter()).zip(rhs.iter()) {
+            assert_eq!(
+                cks.decrypt(ct),
+                clear_gate(*l, *r),
+                "Invalid result for {gate:?}"
+            );
+        }
+    }
+
+    let ct_res = sks.batch_not(&ct_lhs);
+    for (ct, l) in ct_res.iter().zip(lhs.iter()) {
+        assert_eq!(cks.decrypt(ct), !l);
+    }
+}
+
+fn test_multi_bit_gates(parameters: BooleanMultiBitParameters) {
+    let cks = &ClientKey::new(&parameters.parameters);
+    let conformance_params = parameters.parameters.to_server_key_conformance_params();
+
+    let mut sks = ServerKey::new_multi_bit(cks, &parameters, ThreadCount(2));
+    assert_eq!(sks.multi_bit_pbs_thread_count(), Some(ThreadCount(2)));
+    sks.set_multi_bit_pbs_thread_count(ThreadCount(4));
+    assert_eq!(sks.multi_bit_pbs_thread_count(), Some(ThreadCount(4)));
+    assert!(sks.is_conformant(&conformance_params));
+
+    // The thread count is not serialized
+    let serialized = bincode::serialize(&sks).unwrap();
+    let sks: ServerKey = bincode::deserialize(&serialized).unwrap();
+    assert!(sks.multi_bit_pbs_thread_count().is_some());
+    assert!(sks.is_conformant(&conformance_params));
+
+    let lhs = (0..NB_CT).map(|_| random_boolean()).collect::<Vec<_>>();
+    let rhs = (0..NB_CT).map(|_| random_boolean()).collect::<Vec<_>>();
+
+    let ct_lhs = lhs
+        .iter()
+        .map(|b| random_enum_encryption(cks, &sks, *b))
+        .collect::<Vec<_>>();
+    let ct_rhs = rhs
+        .iter()
+        .map(|b| random_enum_encryption(cks, &sks, *b))
+        .collect::<Vec<_>>();
+
+    let gates: [(BooleanGate, fn(bool, bool) -> bool); 6] = [
+        (BooleanGate::And, |l, r| l && r),
+        (BooleanGate::Nand, |l, r| !(l && r)),
+        (BooleanGate::Nor, |l, r| !(l || r)),
+        (BooleanGate::Or, |l, r| l || r),
+        (BooleanGate::Xor, |l, r| l ^ r),
+        (BooleanGate::Xnor, |l, r| !(l ^ r)),
+    ];
+
+    for (gate, clear_gate) in gates {
+        let ct_res = sks.batch_gates(gate, &ct_lhs, &ct_rhs);
+
+        for ((ct, l), r) in ct_res.iter().zip(lhs.iter()).zip(rhs.iter()) {
+            assert_eq!(
+                cks.decrypt(ct),
+                clear_gate(*l, *r),
+                "Invalid result for {gate:?}"
+            );
+        }
+    }
+
+    for ((ct_condition, ct_then), ct_else) in
+        ct_lhs.iter().zip(ct_rhs.iter()).zip(ct_lhs.iter().rev())
+    {
+        let expected_result = if cks.decrypt(ct_condition) {
+            cks.decrypt(ct_then)
+        } else {
+            cks.decrypt(ct_else)
+        };
+        let ct_res = sks.mux(ct_condition, ct_then, ct_else);
+        assert_eq!(cks.decrypt(&ct_res), expected_result);
+
+        let ct_res = sks.not(ct_condition);
+        assert_eq!(cks.decrypt(&ct_res), !cks.decrypt(ct_condition));
+    }
+}
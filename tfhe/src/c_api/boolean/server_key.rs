@@ -7,6 +7,30 @@ use crate::boolean::server_key::{BinaryBooleanGates, BinaryBooleanGatesAssign};
 
 use super::BooleanCiphertext;
 
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub enum BooleanGateKind {
+    BooleanGateKindAnd,
+    BooleanGateKindNand,
+    BooleanGateKindNor,
+    BooleanGateKindOr,
+    BooleanGateKindXor,
+    BooleanGateKindXnor,
+}
+
+impl From<BooleanGateKind> for boolean::server_key::BooleanGate {
+    fn from(value: BooleanGateKind) -> Self {
+        match value {
+            BooleanGateKind::BooleanGateKindAnd => Self::And,
+            BooleanGateKind::BooleanGateKindNand => Self::Nand,
+            BooleanGateKind::BooleanGateKindNor => Self::Nor,
+            BooleanGateKind::BooleanGateKindOr => Self::Or,
+            BooleanGateKind::BooleanGateKindXor => Self::Xor,
+            BooleanGateKind::BooleanGateKindXnor => Self::Xnor,
+        }
+    }
+}
+
 pub struct BooleanServerKey(pub(in crate::c_api) boolean::server_key::ServerKey);
 pub struct BooleanCompressedServerKey(
     pub(in crate::c_api) boolean::server_key::CompressedServerKey,
@@ -333,6 +357,55 @@ pub unsafe extern "C" fn boolean_server_key_not_assign(
     })
 }
 
+/// Evaluates `gate` on each of the `count` pairs of ciphertexts of `ct_lefts` and `ct_rights`.
+///
+/// The C API being a native build, the gates are evaluated in parallel on the threads of the
+/// library, only the wasm API builds without the `parallel-wasm-api` feature evaluate them one
+/// after the other.
+///
+/// `results` must point to an array of `count` ciphertext pointers, each of them is filled
+/// with a newly allocated ciphertext that must be destroyed by the caller.
+#[no_mangle]
+pub unsafe extern "C" fn boolean_server_key_batch_gates(
+    server_key: *const BooleanServerKey,
+    gate: BooleanGateKind,
+    ct_lefts: *const *const BooleanCiphertext,
+    ct_rights: *const *const BooleanCiphertext,
+    count: usize,
+    results: *mut *mut BooleanCiphertext,
+) -> c_int {
+    catch_panic(|| {
+        check_ptr_is_non_null_and_aligned(results).unwrap();
+
+        let results = std::slice::from_raw_parts_mut(results, count);
+
+        // First fill the results with null ptrs so that if we fail and the return code is not
+        // checked, then any access to the result pointers will segfault (mimics malloc on failure)
+        for result in results.iter_mut() {
+            *result = std::ptr::null_mut();
+        }
+
+        let server_key = get_ref_checked(server_key).unwrap();
+
+        check_ptr_is_non_null_and_aligned(ct_lefts).unwrap();
+        check_ptr_is_non_null_and_aligned(ct_rights).unwrap();
+        let ct_lefts = std::slice::from_raw_parts(ct_lefts, count)
+            .iter()
+            .map(|ct| get_ref_checked(*ct).unwrap().0.clone())
+            .collect::<Vec<_>>();
+        let ct_rights = std::slice::from_raw_parts(ct_rights, count)
+            .iter()
+            .map(|ct| get_ref_checked(*ct).unwrap().0.clone())
+            .collect::<Vec<_>>();
+
+        let cts = server_key.0.batch_gates(gate.into(), &ct_lefts, &ct_rights);
+
+        for (result, ct) in results.iter_mut().zip(cts) {
+            *result = Box::into_raw(Box::new(BooleanCiphertext(ct)));
+        }
+    })
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn boolean_server_key_mux(
     server_key: *const BooleanServerKey,
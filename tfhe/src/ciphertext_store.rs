@@ -0,0 +1,439 @@
+//! Persistent, indexed storage of large collections of ciphertexts.
+//!
+//! A [CiphertextStoreWriter] appends objects (ciphertexts, compressed ciphertexts, or any type
+//! implementing [Named]) one at a time to a file, and a [CiphertextStore] memory-maps such a file
+//! to give random access to each entry. Entries are only deserialized when they are accessed, so
+//! opening a store holding millions of ciphertexts does not require reading it entirely.
+//!
+//! # File format
+//!
+//! All integers are stored in little endian.
+//!
+//! | Field          | Size                 | Description                              |
+//! |----------------|----------------------|------------------------------------------|
+//! | magic          | 8 bytes              | `b"TFHECTST"`                            |
+//! | format version | 4 bytes              | Version of this file format              |
+//! | entry count    | 8 bytes              | Number of entries in the store           |
+//! | index offset   | 8 bytes              | Offset of the index from file start      |
+//! | name length    | 8 bytes              | Length of the stored type name           |
+//! | name           | `name length` bytes  | [Named::NAME] of the stored type         |
+//! | data           | variable             | bincode serialized entries, back to back |
+//! | index          | `entry count` * 16   | (offset, length) of each entry           |
+
+use crate::named::Named;
+use bincode::Options;
+use memmap2::Mmap;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fs::File;
+use std::io::{BufWriter, Seek, SeekFrom, Write};
+use std::marker::PhantomData;
+use std::path::Path;
+
+const MAGIC: &[u8; 8] = b"TFHECTST";
+const FORMAT_VERSION: u32 = 1;
+
+// Offset of the entry count in the header, the index offset directly follows it
+const ENTRY_COUNT_OFFSET: u64 = (MAGIC.len() + std::mem::size_of::<u32>()) as u64;
+const FIXED_HEADER_SIZE: usize = MAGIC.len() + std::mem::size_of::<u32>() + 3 * U64_SIZE;
+const INDEX_ENTRY_SIZE: usize = 2 * U64_SIZE;
+const U64_SIZE: usize = std::mem::size_of::<u64>();
+
+// Same limit as the type name in safe_deserialization
+const TYPE_NAME_LENGTH_LIMIT: u64 = 1000;
+
+fn bincode_options() -> impl Options {
+    bincode::DefaultOptions::new().with_fixint_encoding()
+}
+
+#[derive(Debug)]
+pub enum CiphertextStoreError {
+    Io(std::io::Error),
+    Serialization(bincode::Error),
+    /// The file is not a valid store, e.g. it was not finished or it is truncated
+    InvalidFormat(String),
+    /// The store holds another type than the one requested
+    TypeMismatch {
+        expected: &'static str,
+        found: String,
+    },
+    IndexOutOfBounds {
+        index: usize,
+        len: usize,
+    },
+}
+
+impl std::fmt::Display for CiphertextStoreError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "I/O error: {err}"),
+            Self::Serialization(err) => write!(f, "Serialization error: {err}"),
+            Self::InvalidFormat(msg) => write!(f, "Invalid ciphertext store: {msg}"),
+            Self::TypeMismatch { expected, found } => {
+                write!(
+                    f,
+                    "Expected a store of type {expected}, got a store of type {found}"
+                )
+            }
+            Self::IndexOutOfBounds { index, len } => {
+                write!(
+                    f,
+                    "Index {index} is out of bounds for a store of {len} entries"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for CiphertextStoreError {}
+
+impl From<std::io::Error> for CiphertextStoreError {
+    fn from(value: std::io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+impl From<bincode::Error> for CiphertextStoreError {
+    fn from(value: bincode::Error) -> Self {
+        Self::Serialization(value)
+    }
+}
+
+/// Writes entries to a new store file
+///
+/// The store is only readable once [CiphertextStoreWriter::finish] has been called.
+pub struct CiphertextStoreWriter<T> {
+    writer: BufWriter<File>,
+    // Current position in the file
+    position: u64,
+    // (offset, length) of each entry written so far
+    index: Vec<(u64, u64)>,
+    _marker: PhantomData<fn(&T)>,
+}
+
+impl<T: Serialize + Named> CiphertextStoreWriter<T> {
+    /// Creates the store file at `path`, truncating it if it already exists
+    pub fn create(path: impl AsRef<Path>) -> Result<Self, CiphertextStoreError> {
+        let mut writer = BufWriter::new(File::create(path)?);
+
+        writer.write_all(MAGIC)?;
+        writer.write_all(&FORMAT_VERSION.to_le_bytes())?;
+        // Entry count and index offset, written for real in finish
+        writer.write_all(&0u64.to_le_bytes())?;
+        writer.write_all(&0u64.to_le_bytes())?;
+        writer.write_all(&(T::NAME.len() as u64).to_le_bytes())?;
+        writer.write_all(T::NAME.as_bytes())?;
+
+        Ok(Self {
+            writer,
+            position: (FIXED_HEADER_SIZE + T::NAME.len()) as u64,
+            index: Vec::new(),
+            _marker: PhantomData,
+        })
+    }
+
+    /// Appends an entry to the store and returns its index
+    pub fn push(&mut self, object: &T) -> Result<usize, CiphertextStoreError> {
+        let options = bincode_options();
+        let length = options.serialized_size(object)?;
+        options.serialize_into(&mut self.writer, object)?;
+
+        self.index.push((self.position, length));
+        self.position += length;
+
+        Ok(self.index.len() - 1)
+    }
+
+    /// Appends all the entries of the iterator to the store
+    pub fn extend<'a>(
+        &mut self,
+        objects: impl IntoIterator<Item = &'a T>,
+    ) -> Result<(), CiphertextStoreError>
+    where
+        T: 'a,
+    {
+        for object in objects {
+            self.push(object)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the number of entries written so far
+    pub fn len(&self) -> usize {
+        self.index.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.index.is_empty()
+    }
+
+    /// Writes the index and flushes the store to disk
+    pub fn finish(mut self) -> Result<(), CiphertextStoreError> {
+        let index_offset = self.position;
+        for (offset, length) in self.index.iter() {
+            self.writer.write_all(&offset.to_le_bytes())?;
+            self.writer.write_all(&length.to_le_bytes())?;
+        }
+
+        self.writer.seek(SeekFrom::Start(ENTRY_COUNT_OFFSET))?;
+        self.writer
+            .write_all(&(self.index.len() as u64).to_le_bytes())?;
+        self.writer.write_all(&index_offset.to_le_bytes())?;
+
+        let file = self
+            .writer
+            .into_inner()
+            .map_err(|err| CiphertextStoreError::Io(err.into_error()))?;
+        file.sync_all()?;
+
+        Ok(())
+    }
+}
+
+/// A read-only, memory-mapped store of entries of type `T`
+///
+/// Entries are deserialized lazily, each time they are accessed.
+pub struct CiphertextStore<T> {
+    mmap: Mmap,
+    len: usize,
+    index_offset: usize,
+    _marker: PhantomData<fn() -> T>,
+}
+
+fn read_u64(bytes: &[u8], offset: usize) -> Result<u64, CiphertextStoreError> {
+    bytes
+        .get(offset..offset + U64_SIZE)
+        .map(|slice| u64::from_le_bytes(slice.try_into().unwrap()))
+        .ok_or_else(|| CiphertextStoreError::InvalidFormat("File is truncated".to_owned()))
+}
+
+fn to_usize(value: u64) -> Result<usize, CiphertextStoreError> {
+    usize::try_from(value).map_err(|_| {
+        CiphertextStoreError::InvalidFormat(format!("{value} does not fit in a usize"))
+    })
+}
+
+impl<T: DeserializeOwned + Named> CiphertextStore<T> {
+    /// Opens and memory-maps the store at `path`, checking its header and index
+    ///
+    /// # Note
+    ///
+    /// The file must not be modified while the store is open, as the content of a memory-mapped
+    /// file that is modified by another process is undefined.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, CiphertextStoreError> {
+        let file = File::open(path)?;
+        // SAFETY: the file is opened read-only, and the documentation of this function requires
+        // that the file is not modified while mapped
+        let mmap = unsafe { Mmap::map(&file)? };
+
+        let bytes = &mmap[..];
+        if bytes.len() < FIXED_HEADER_SIZE || &bytes[..MAGIC.len()] != MAGIC {
+            return Err(CiphertextStoreError::InvalidFormat(
+                "File is not a ciphertext store".to_owned(),
+            ));
+        }
+
+        let version = u32::from_le_bytes(
+            bytes[MAGIC.len()..ENTRY_COUNT_OFFSET as usize]
+                .try_into()
+                .unwrap(),
+        );
+        if version != FORMAT_VERSION {
+            return Err(CiphertextStoreError::InvalidFormat(format!(
+                "Expected format version {FORMAT_VERSION}, got version {version}"
+            )));
+        }
+
+        let entry_count_offset = ENTRY_COUNT_OFFSET as usize;
+        let len = to_usize(read_u64(bytes, entry_count_offset)?)?;
+        let index_offset = to_usize(read_u64(bytes, entry_count_offset + U64_SIZE)?)?;
+        let name_length = read_u64(bytes, entry_count_offset + 2 * U64_SIZE)?;
+
+        if name_length > TYPE_NAME_LENGTH_LIMIT {
+            return Err(CiphertextStoreError::InvalidFormat(format!(
+                "Type name length {name_length} exceeds the limit of {TYPE_NAME_LENGTH_LIMIT}"
+            )));
+        }
+        let data_offset = FIXED_HEADER_SIZE + name_length as usize;
+        let name = bytes
+            .get(FIXED_HEADER_SIZE..data_offset)
+            .ok_or_else(|| CiphertextStoreError::InvalidFormat("File is truncated".to_owned()))?;
+        if name != T::NAME.as_bytes() {
+            return Err(CiphertextStoreError::TypeMismatch {
+                expected: T::NAME,
+                found: String::from_utf8_lossy(name).into_owned(),
+            });
+        }
+
+        // A writer that was not finished leaves an index offset of 0
+        let expected_file_len = len
+            .checked_mul(INDEX_ENTRY_SIZE)
+            .and_then(|index_len| index_len.checked_add(index_offset));
+        if index_offset < data_offset || expected_file_len != Some(bytes.len()) {
+            return Err(CiphertextStoreError::InvalidFormat(
+                "Index is missing or corrupted, was the store finished ?".to_owned(),
+            ));
+        }
+
+        let store = Self {
+            mmap,
+            len,
+            index_offset,
+            _marker: PhantomData,
+        };
+
+        for i in 0..len {
+            let (offset, length) = store.index_entry(i)?;
+            let in_data_section = offset
+                .checked_add(length)
+                .map_or(false, |end| offset >= data_offset && end <= index_offset);
+            if !in_data_section {
+                return Err(CiphertextStoreError::InvalidFormat(format!(
+                    "Entry {i} is out of the data section"
+                )));
+            }
+        }
+
+        Ok(store)
+    }
+
+    /// Returns the number of entries in the store
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn index_entry(&self, index: usize) -> Result<(usize, usize), CiphertextStoreError> {
+        let entry_offset = self.index_offset + index * INDEX_ENTRY_SIZE;
+        let offset = read_u64(&self.mmap, entry_offset)?;
+        let length = read_u64(&self.mmap, entry_offset + U64_SIZE)?;
+        Ok((to_usize(offset)?, to_usize(length)?))
+    }
+
+    /// Returns the serialized bytes of the entry at `index`, without deserializing it
+    pub fn get_raw(&self, index: usize) -> Result<&[u8], CiphertextStoreError> {
+        if index >= self.len {
+            return Err(CiphertextStoreError::IndexOutOfBounds {
+                index,
+                len: self.len,
+            });
+        }
+
+        // Bounds were checked on open
+        let (offset, length) = self.index_entry(index)?;
+        Ok(&self.mmap[offset..offset + length])
+    }
+
+    /// Deserializes the entry at `index`
+    pub fn get(&self, index: usize) -> Result<T, CiphertextStoreError> {
+        let bytes = self.get_raw(index)?;
+        let object = bincode_options()
+            .with_limit(bytes.len() as u64)
+            .deserialize(bytes)?;
+        Ok(object)
+    }
+
+    /// Returns an iterator deserializing the entries in order
+    pub fn iter(&self) -> impl Iterator<Item = Result<T, CiphertextStoreError>> + '_ {
+        (0..self.len).map(move |index| self.get(index))
+    }
+}
+
+#[cfg(all(test, feature = "shortint"))]
+mod test_shortint {
+    use super::{CiphertextStore, CiphertextStoreError, CiphertextStoreWriter};
+    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    use crate::shortint::{gen_keys, Ciphertext, CompressedCiphertext};
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!(
+            "tfhe_ciphertext_store_{name}_{}",
+            std::process::id()
+        ))
+    }
+
+    #[test]
+    fn ciphertext_store_round_trip() {
+        let (ck, _sk) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+        let path = temp_path("round_trip");
+
+        let msgs = [0u64, 1, 2, 3, 2, 1];
+
+        let mut writer = CiphertextStoreWriter::<Ciphertext>::create(&path).unwrap();
+        for msg in msgs {
+            writer.push(&ck.encrypt(msg)).unwrap();
+        }
+        assert_eq!(writer.len(), msgs.len());
+        writer.finish().unwrap();
+
+        let store = CiphertextStore::<Ciphertext>::open(&path).unwrap();
+        assert_eq!(store.len(), msgs.len());
+
+        for (i, msg) in msgs.iter().enumerate().rev() {
+            let ct = store.get(i).unwrap();
+            assert_eq!(ck.decrypt(&ct), *msg);
+        }
+
+        let decrypted = store
+            .iter()
+            .map(|ct| ck.decrypt(&ct.unwrap()))
+            .collect::<Vec<_>>();
+        assert_eq!(decrypted, msgs);
+
+        assert!(matches!(
+            store.get(msgs.len()),
+            Err(CiphertextStoreError::IndexOutOfBounds { .. })
+        ));
+
+        // Opening with the wrong type must fail
+        assert!(matches!(
+            CiphertextStore::<CompressedCiphertext>::open(&path),
+            Err(CiphertextStoreError::TypeMismatch { .. })
+        ));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn ciphertext_store_compressed() {
+        let (ck, _sk) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+        let path = temp_path("compressed");
+
+        let mut writer = CiphertextStoreWriter::<CompressedCiphertext>::create(&path).unwrap();
+        let cts = (0..4u64)
+            .map(|msg| ck.encrypt_compressed(msg))
+            .collect::<Vec<_>>();
+        writer.extend(cts.iter()).unwrap();
+        writer.finish().unwrap();
+
+        let store = CiphertextStore::<CompressedCiphertext>::open(&path).unwrap();
+        for (i, compressed) in store.iter().enumerate() {
+            let ct: Ciphertext = compressed.unwrap().decompress();
+            assert_eq!(ck.decrypt(&ct), i as u64);
+        }
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn ciphertext_store_unfinished() {
+        let (ck, _sk) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+        let path = temp_path("unfinished");
+
+        {
+            let mut writer = CiphertextStoreWriter::<Ciphertext>::create(&path).unwrap();
+            writer.push(&ck.encrypt(1)).unwrap();
+            // Dropped without calling finish
+        }
+
+        assert!(matches!(
+            CiphertextStore::<Ciphertext>::open(&path),
+            Err(CiphertextStoreError::InvalidFormat(_))
+        ));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,212 @@
+//! Stable hashing of the content of ciphertexts.
+//!
+//! The [ContentHash] trait computes a 128 bits hash of a ciphertext directly from its LWE masks
+//! and bodies and its metadata, without serializing it. Two ciphertexts with the same content
+//! always have the same hash, which allows services to deduplicate identical submitted
+//! ciphertexts, or caches to index results by their inputs.
+//!
+//! The hash is stable: it does not depend on the platform, the process or the version of the
+//! Rust compiler, so it can be stored and compared across machines. It only changes when
+//! [CONTENT_HASH_VERSION] is bumped.
+//!
+//! The hash function is fast but not cryptographic: collisions are very unlikely between honestly
+//! generated ciphertexts, but they can be crafted. When the inputs come from untrusted parties,
+//! contents with the same hash must be compared before being considered identical.
+
+/// Version of the hash function and of the hashed content of the types
+///
+/// The hashes computed with different versions are not comparable.
+pub const CONTENT_HASH_VERSION: u64 = 1;
+
+const DEFAULT_SEED: u64 = 0x7466_6865_2d72_7321;
+
+const PRIME_1: u64 = 0x9e37_79b1_85eb_ca87;
+const PRIME_2: u64 = 0xc2b2_ae3d_27d4_eb4f;
+const PRIME_3: u64 = 0x1656_67b1_9e37_79f9;
+
+/// Final mixing of a 64 bits word, from MurmurHash3
+fn fmix64(mut x: u64) -> u64 {
+    x ^= x >> 33;
+    x = x.wrapping_mul(0xff51_afd7_ed55_8ccd);
+    x ^= x >> 33;
+    x = x.wrapping_mul(0xc4ce_b9fe_1a85_ec53);
+    x ^= x >> 33;
+    x
+}
+
+/// Incremental hasher used by [ContentHash]
+///
+/// Words are fed to two independent 64 bits lanes, which are mixed together when the hash is
+/// finished. The hasher can be seeded, so that hashes computed with different seeds cannot be
+/// related.
+#[derive(Clone, Debug)]
+pub struct ContentHasher {
+    lanes: [u64; 2],
+    word_count: u64,
+}
+
+impl Default for ContentHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ContentHasher {
+    /// Creates a hasher with the default seed
+    pub fn new() -> Self {
+        Self::with_seed(DEFAULT_SEED)
+    }
+
+    pub fn with_seed(seed: u64) -> Self {
+        let seed = fmix64(seed ^ CONTENT_HASH_VERSION.wrapping_mul(PRIME_3));
+        Self {
+            lanes: [seed ^ PRIME_1, seed.rotate_left(32) ^ PRIME_2],
+            word_count: 0,
+        }
+    }
+
+    pub fn write_u64(&mut self, word: u64) {
+        self.lanes[0] = (self.lanes[0] ^ word).wrapping_mul(PRIME_1).rotate_left(31);
+        self.lanes[1] = (self.lanes[1] ^ word.rotate_left(29))
+            .wrapping_mul(PRIME_2)
+            .rotate_left(27)
+            .wrapping_add(PRIME_3);
+        self.word_count += 1;
+    }
+
+    pub fn write_u128(&mut self, word: u128) {
+        self.write_u64(word as u64);
+        self.write_u64((word >> 64) as u64);
+    }
+
+    /// Hashes a length or an index, as a 64 bits word on all platforms
+    pub fn write_usize(&mut self, word: usize) {
+        self.write_u64(word as u64);
+    }
+
+    /// Hashes bytes, prefixed by their length
+    pub fn write_bytes(&mut self, bytes: &[u8]) {
+        self.write_usize(bytes.len());
+        for chunk in bytes.chunks(8) {
+            let mut word = [0u8; 8];
+            word[..chunk.len()].copy_from_slice(chunk);
+            self.write_u64(u64::from_le_bytes(word));
+        }
+    }
+
+    /// Hashes a string, prefixed by its length
+    pub fn write_str(&mut self, string: &str) {
+        self.write_bytes(string.as_bytes());
+    }
+
+    /// Returns the hash of the words written so far
+    ///
+    /// The hasher is not consumed, more words can be written to it afterwards.
+    pub fn finish(&self) -> u128 {
+        let low = fmix64(self.lanes[0] ^ self.word_count);
+        let high = fmix64(self.lanes[1] ^ low.wrapping_mul(PRIME_3));
+        let low = fmix64(low ^ high);
+
+        (u128::from(high) << 64) | u128::from(low)
+    }
+}
+
+/// Computes a stable hash of the content of an object, see the [module documentation](self)
+///
+/// This trait is implemented for the ciphertexts of the crate, and for slices and vectors of
+/// them.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::content_hash::ContentHash;
+/// use tfhe::shortint::gen_keys;
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+///
+/// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+///
+/// let ct = cks.encrypt(3);
+/// let copy = ct.clone();
+/// // Another encryption of the same message uses another mask
+/// let other = cks.encrypt(3);
+///
+/// assert_eq!(ct.content_hash(), copy.content_hash());
+/// assert_ne!(ct.content_hash(), other.content_hash());
+/// ```
+pub trait ContentHash {
+    /// Feeds the content of the object to the hasher
+    fn hash_content(&self, hasher: &mut ContentHasher);
+
+    /// Returns the hash of the content of the object, computed with the default seed
+    fn content_hash(&self) -> u128 {
+        let mut hasher = ContentHasher::new();
+        self.hash_content(&mut hasher);
+        hasher.finish()
+    }
+}
+
+impl<T: ContentHash> ContentHash for [T] {
+    fn hash_content(&self, hasher: &mut ContentHasher) {
+        hasher.write_usize(self.len());
+        for item in self {
+            item.hash_content(hasher);
+        }
+    }
+}
+
+impl<T: ContentHash> ContentHash for Vec<T> {
+    fn hash_content(&self, hasher: &mut ContentHasher) {
+        self.as_slice().hash_content(hasher);
+    }
+}
+
+impl<T: ContentHash + ?Sized> ContentHash for &T {
+    fn hash_content(&self, hasher: &mut ContentHasher) {
+        (**self).hash_content(hasher);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ContentHash, ContentHasher};
+
+    struct Words(Vec<u64>);
+
+    impl ContentHash for Words {
+        fn hash_content(&self, hasher: &mut ContentHasher) {
+            for word in &self.0 {
+                hasher.write_u64(*word);
+            }
+        }
+    }
+
+    #[test]
+    fn content_hash_is_stable() {
+        // Changing this value requires bumping CONTENT_HASH_VERSION
+        let expected = 0x533f_c829_a8cc_2c48_ab67_abe8_e478_e034;
+        assert_eq!(Words(vec![0, 1, u64::MAX]).content_hash(), expected);
+
+        let mut hasher = ContentHasher::new();
+        assert_eq!(hasher.finish(), ContentHasher::new().finish());
+        hasher.write_u64(0);
+        assert_ne!(hasher.finish(), ContentHasher::new().finish());
+
+        // Order, length and seed all matter
+        assert_ne!(
+            Words(vec![1, 0]).content_hash(),
+            Words(vec![0, 1]).content_hash()
+        );
+        assert_ne!(
+            Words(vec![0]).content_hash(),
+            Words(vec![0, 0]).content_hash()
+        );
+        let mut seeded = ContentHasher::with_seed(1);
+        Words(vec![0, 1, u64::MAX]).hash_content(&mut seeded);
+        assert_ne!(seeded.finish(), expected);
+
+        assert_ne!(
+            vec![Words(vec![1]), Words(vec![2])].content_hash(),
+            vec![Words(vec![1, 2])].content_hash()
+        );
+    }
+}
@@ -11,6 +11,7 @@ use crate::core_crypto::fft_impl::fft64::crypto::bootstrap::{
     fill_with_forward_fourier_scratch, FourierLweBootstrapKey,
 };
 use crate::core_crypto::fft_impl::fft64::math::fft::{Fft, FftView};
+use crate::core_crypto::fft_impl::ntt64::math::ntt::Ntt;
 use concrete_fft::c64;
 use dyn_stack::{PodStack, SizeOverflow, StackReq};
 
@@ -212,3 +213,114 @@ pub fn convert_standard_lwe_bootstrap_key_to_fourier_128<Scalar, InputCont, Outp
 
     output_bsk.fill_with_forward_fourier(input_bsk, fft);
 }
+
+/// Convert an [`LWE bootstrap key`](`LweBootstrapKey`) with standard coefficients to the NTT
+/// domain.
+///
+/// See [`programmable_bootstrap_ntt64_lwe_ciphertext`](`crate::core_crypto::algorithms::programmable_bootstrap_ntt64_lwe_ciphertext`) for usage.
+pub fn convert_standard_lwe_bootstrap_key_to_ntt64<InputCont, OutputCont>(
+    input_bsk: &LweBootstrapKey<InputCont>,
+    output_bsk: &mut NttLweBootstrapKey<OutputCont>,
+) where
+    InputCont: Container<Element = u64>,
+    OutputCont: ContainerMut<Element = u64>,
+{
+    assert_eq!(
+        input_bsk.polynomial_size(),
+        output_bsk.polynomial_size(),
+        "Mismatched PolynomialSize between input_bsk {:?} and output_bsk {:?}",
+        input_bsk.polynomial_size(),
+        output_bsk.polynomial_size(),
+    );
+
+    assert_eq!(
+        input_bsk.glwe_size(),
+        output_bsk.glwe_size(),
+        "Mismatched GlweSize"
+    );
+
+    assert_eq!(
+        input_bsk.decomposition_base_log(),
+        output_bsk.decomposition_base_log(),
+        "Mismatched DecompositionBaseLog between input_bsk {:?} and output_bsk {:?}",
+        input_bsk.decomposition_base_log(),
+        output_bsk.decomposition_base_log(),
+    );
+
+    assert_eq!(
+        input_bsk.decomposition_level_count(),
+        output_bsk.decomposition_level_count(),
+        "Mismatched DecompositionLevelCount between input_bsk {:?} and output_bsk {:?}",
+        input_bsk.decomposition_level_count(),
+        output_bsk.decomposition_level_count(),
+    );
+
+    assert_eq!(
+        input_bsk.input_lwe_dimension(),
+        output_bsk.input_lwe_dimension(),
+        "Mismatched input LweDimension between input_bsk {:?} and output_bsk {:?}",
+        input_bsk.input_lwe_dimension(),
+        output_bsk.input_lwe_dimension(),
+    );
+
+    let ntt = Ntt::new(output_bsk.polynomial_size());
+    let ntt = ntt.as_view();
+
+    output_bsk
+        .as_mut_view()
+        .fill_with_forward_ntt(input_bsk.as_view(), ntt);
+}
+
+/// Parallel variant of [`convert_standard_lwe_bootstrap_key_to_ntt64`].
+pub fn par_convert_standard_lwe_bootstrap_key_to_ntt64<InputCont, OutputCont>(
+    input_bsk: &LweBootstrapKey<InputCont>,
+    output_bsk: &mut NttLweBootstrapKey<OutputCont>,
+) where
+    InputCont: Container<Element = u64>,
+    OutputCont: ContainerMut<Element = u64>,
+{
+    assert_eq!(
+        input_bsk.polynomial_size(),
+        output_bsk.polynomial_size(),
+        "Mismatched PolynomialSize between input_bsk {:?} and output_bsk {:?}",
+        input_bsk.polynomial_size(),
+        output_bsk.polynomial_size(),
+    );
+
+    assert_eq!(
+        input_bsk.glwe_size(),
+        output_bsk.glwe_size(),
+        "Mismatched GlweSize"
+    );
+
+    assert_eq!(
+        input_bsk.decomposition_base_log(),
+        output_bsk.decomposition_base_log(),
+        "Mismatched DecompositionBaseLog between input_bsk {:?} and output_bsk {:?}",
+        input_bsk.decomposition_base_log(),
+        output_bsk.decomposition_base_log(),
+    );
+
+    assert_eq!(
+        input_bsk.decomposition_level_count(),
+        output_bsk.decomposition_level_count(),
+        "Mismatched DecompositionLevelCount between input_bsk {:?} and output_bsk {:?}",
+        input_bsk.decomposition_level_count(),
+        output_bsk.decomposition_level_count(),
+    );
+
+    assert_eq!(
+        input_bsk.input_lwe_dimension(),
+        output_bsk.input_lwe_dimension(),
+        "Mismatched input LweDimension between input_bsk {:?} and output_bsk {:?}",
+        input_bsk.input_lwe_dimension(),
+        output_bsk.input_lwe_dimension(),
+    );
+
+    let ntt = Ntt::new(output_bsk.polynomial_size());
+    let ntt = ntt.as_view();
+
+    output_bsk
+        .as_mut_view()
+        .par_fill_with_forward_ntt(input_bsk.as_view(), ntt);
+}
@@ -10,6 +10,8 @@ use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
 use rayon::prelude::*;
+use std::ops::ControlFlow;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 /// Fill an [`LWE bootstrap key`](`LweBootstrapKey`) with an actual bootstrapping key constructed
 /// from an input key [`LWE secret key`](`LweSecretKey`) and an output key
@@ -246,6 +248,109 @@ pub fn par_generate_lwe_bootstrap_key<Scalar, InputKeyCont, OutputKeyCont, Outpu
     OutputKeyCont: Container<Element = Scalar> + Sync,
     OutputCont: ContainerMut<Element = Scalar>,
     Gen: ParallelByteRandomGenerator,
+{
+    // The progress callback never stops the generation, the key is always complete
+    let _ = par_generate_lwe_bootstrap_key_with_progress(
+        input_lwe_secret_key,
+        output_glwe_secret_key,
+        output,
+        noise_parameters,
+        generator,
+        || ControlFlow::Continue(()),
+    );
+}
+
+/// Variant of [`par_generate_lwe_bootstrap_key`] calling `on_ggsw_encrypted` each time one of the
+/// GGSW ciphertexts of the key is encrypted, the key having one GGSW ciphertext per element of the
+/// input LWE secret key.
+///
+/// Once `on_ggsw_encrypted` returns [`ControlFlow::Break`], the GGSW ciphertexts not yet being
+/// encrypted are skipped and [`ControlFlow::Break`] is returned, the key is then incomplete and
+/// must be discarded. As the GGSW ciphertexts are encrypted in parallel, the callback can be
+/// called from several threads at once.
+///
+/// ```
+/// use std::ops::ControlFlow;
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// let input_lwe_dimension = LweDimension(742);
+/// let decomp_base_log = DecompositionBaseLog(3);
+/// let decomp_level_count = DecompositionLevelCount(5);
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// let input_lwe_secret_key =
+///     allocate_and_generate_new_binary_lwe_secret_key(input_lwe_dimension, &mut secret_generator);
+/// let output_glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_dimension,
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// let mut bsk = LweBootstrapKey::new(
+///     0u64,
+///     glwe_dimension.to_glwe_size(),
+///     polynomial_size,
+///     decomp_base_log,
+///     decomp_level_count,
+///     input_lwe_dimension,
+///     ciphertext_modulus,
+/// );
+///
+/// // Stop the generation after 10 GGSW ciphertexts
+/// let encrypted_ggsw_count = AtomicUsize::new(0);
+/// let result = par_generate_lwe_bootstrap_key_with_progress(
+///     &input_lwe_secret_key,
+///     &output_glwe_secret_key,
+///     &mut bsk,
+///     glwe_modular_std_dev,
+///     &mut encryption_generator,
+///     || {
+///         if encrypted_ggsw_count.fetch_add(1, Ordering::Relaxed) + 1 >= 10 {
+///             ControlFlow::Break(())
+///         } else {
+///             ControlFlow::Continue(())
+///         }
+///     },
+/// );
+///
+/// assert!(result.is_break());
+/// assert!(encrypted_ggsw_count.load(Ordering::Relaxed) < input_lwe_dimension.0);
+/// ```
+pub fn par_generate_lwe_bootstrap_key_with_progress<
+    Scalar,
+    InputKeyCont,
+    OutputKeyCont,
+    OutputCont,
+    Gen,
+    F,
+>(
+    input_lwe_secret_key: &LweSecretKey<InputKeyCont>,
+    output_glwe_secret_key: &GlweSecretKey<OutputKeyCont>,
+    output: &mut LweBootstrapKey<OutputCont>,
+    noise_parameters: impl DispersionParameter + Sync,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+    on_ggsw_encrypted: F,
+) -> ControlFlow<()>
+where
+    Scalar: UnsignedTorus + Sync + Send,
+    InputKeyCont: Container<Element = Scalar>,
+    OutputKeyCont: Container<Element = Scalar> + Sync,
+    OutputCont: ContainerMut<Element = Scalar>,
+    Gen: ParallelByteRandomGenerator,
+    F: Fn() -> ControlFlow<()> + Sync,
 {
     assert!(
         output.input_lwe_dimension() == input_lwe_secret_key.lwe_dimension(),
@@ -280,11 +385,17 @@ pub fn par_generate_lwe_bootstrap_key<Scalar, InputKeyCont, OutputKeyCont, Outpu
         )
         .unwrap();
 
+    let stopped = AtomicBool::new(false);
+
     output
         .par_iter_mut()
         .zip(input_lwe_secret_key.as_ref().par_iter())
         .zip(gen_iter)
         .for_each(|((mut ggsw, &input_key_element), mut generator)| {
+            if stopped.load(Ordering::Relaxed) {
+                return;
+            }
+
             par_encrypt_constant_ggsw_ciphertext(
                 output_glwe_secret_key,
                 &mut ggsw,
@@ -292,7 +403,17 @@ pub fn par_generate_lwe_bootstrap_key<Scalar, InputKeyCont, OutputKeyCont, Outpu
                 noise_parameters,
                 &mut generator,
             );
+
+            if on_ggsw_encrypted().is_break() {
+                stopped.store(true, Ordering::Relaxed);
+            }
         });
+
+    if stopped.into_inner() {
+        ControlFlow::Break(())
+    } else {
+        ControlFlow::Continue(())
+    }
 }
 
 /// Parallel variant of [`allocate_and_generate_new_lwe_bootstrap_key`], it is recommended to use
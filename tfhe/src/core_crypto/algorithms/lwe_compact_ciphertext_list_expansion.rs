@@ -108,3 +108,42 @@ pub fn par_expand_lwe_compact_ciphertext_list<Scalar, InputCont, OutputCont>(
                 });
         });
 }
+
+/// Expand the ciphertext at position `index` of an [`LweCompactCiphertextList`] into an
+/// [`LweCiphertext`], without expanding the other ciphertexts of the list.
+pub fn expand_lwe_compact_ciphertext_list_element<Scalar, InputCont, OutputCont>(
+    output_lwe_ciphertext: &mut LweCiphertext<OutputCont>,
+    input_lwe_compact_ciphertext_list: &LweCompactCiphertextList<InputCont>,
+    index: usize,
+) where
+    Scalar: UnsignedInteger,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    assert!(index < input_lwe_compact_ciphertext_list.lwe_ciphertext_count().0);
+
+    assert!(output_lwe_ciphertext.lwe_size() == input_lwe_compact_ciphertext_list.lwe_size());
+
+    let (input_mask_list, input_body_list) =
+        input_lwe_compact_ciphertext_list.get_mask_and_body_list();
+
+    let lwe_dimension = input_mask_list.lwe_dimension();
+    let max_ciphertext_per_bin = lwe_dimension.0;
+
+    let input_mask = input_mask_list.get(index / max_ciphertext_per_bin);
+    let input_body = input_body_list.get(index);
+
+    let (mut out_mask, out_body) = output_lwe_ciphertext.get_mut_mask_and_body();
+    out_mask.as_mut().copy_from_slice(input_mask.as_ref());
+
+    let mut out_mask_as_polynomial = Polynomial::from_container(out_mask.as_mut());
+
+    // This is the Psi_jl from the paper, it's equivalent to a multiplication in the X^N + 1 ring
+    // for our choice of i == n
+    polynomial_wrapping_monic_monomial_mul_assign(
+        &mut out_mask_as_polynomial,
+        MonomialDegree(lwe_dimension.0 - (index % max_ciphertext_per_bin + 1)),
+    );
+
+    *out_body.data = *input_body.data;
+}
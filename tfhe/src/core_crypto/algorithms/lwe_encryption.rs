@@ -2039,6 +2039,201 @@ pub fn par_encrypt_lwe_compact_ciphertext_list_with_compact_public_key<
         );
 }
 
+/// Convenience function to share the core logic of the seeded LWE compact ciphertext list
+/// encryption between all functions needing it.
+pub fn encrypt_seeded_lwe_compact_ciphertext_list_with_existing_generator<
+    Scalar,
+    KeyCont,
+    OutputCont,
+    InputCont,
+    Gen,
+>(
+    lwe_secret_key: &LweSecretKey<KeyCont>,
+    output: &mut SeededLweCompactCiphertextList<OutputCont>,
+    encoded: &PlaintextList<InputCont>,
+    noise_parameters: impl DispersionParameter,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    assert!(
+        output.lwe_size().to_lwe_dimension() == lwe_secret_key.lwe_dimension(),
+        "Mismatch between LweDimension of output ciphertext and input secret key. \
+        Got {:?} in output, and {:?} in secret key.",
+        output.lwe_size().to_lwe_dimension(),
+        lwe_secret_key.lwe_dimension()
+    );
+
+    assert!(
+        output.lwe_ciphertext_count().0 == encoded.plaintext_count().0,
+        "Mismatch between LweCiphertextCount of output ciphertext and \
+        PlaintextCount of input list. Got {:?} in output, and {:?} in input plaintext list.",
+        output.lwe_ciphertext_count(),
+        encoded.plaintext_count()
+    );
+
+    assert!(
+        output.ciphertext_modulus().is_native_modulus(),
+        "This operation only supports native moduli"
+    );
+
+    let lwe_mask_count = output.lwe_mask_count();
+    let lwe_dimension = output.lwe_size().to_lwe_dimension();
+
+    let max_ciphertext_per_bin = lwe_dimension.0;
+
+    // Generator forking must match the SeededLweCompactCiphertextList decompression algorithm
+    let gen_iter = generator
+        .fork_lwe_compact_ciphertext_list_to_bin::<Scalar>(lwe_mask_count, lwe_dimension)
+        .expect("Failed to split generator into lwe compact ciphertext bins");
+
+    // The mask of a bin is only needed to compute the bodies, it is regenerated from the seed
+    // during decompression
+    let mut bin_mask = vec![Scalar::ZERO; lwe_dimension.0];
+    let mut bin_mask_convolved = vec![Scalar::ZERO; lwe_dimension.0];
+
+    // Loop over the ciphertext "bins"
+    for ((output_body_chunk, input_plaintext_chunk), mut loop_generator) in output
+        .as_mut()
+        .chunks_mut(max_ciphertext_per_bin)
+        .zip(encoded.chunks(max_ciphertext_per_bin))
+        .zip(gen_iter)
+    {
+        loop_generator.fill_slice_with_random_mask(&mut bin_mask);
+
+        // Same relation between the masks and the bodies as the one obtained when encrypting with
+        // an LweCompactPublicKey, so that the decompressed list expands the same way
+        slice_semi_reverse_negacyclic_convolution(
+            &mut bin_mask_convolved,
+            &bin_mask,
+            lwe_secret_key.as_ref(),
+        );
+
+        output_body_chunk
+            .iter_mut()
+            .zip(bin_mask_convolved.iter().zip(input_plaintext_chunk.iter()))
+            .for_each(|(dst, (&src, plaintext))| {
+                *dst = src
+                    .wrapping_add(loop_generator.random_noise(noise_parameters))
+                    .wrapping_add(*plaintext.0)
+            });
+    }
+}
+
+/// Encrypt a [`PlaintextList`] in a
+/// [`compressed/seeded LWE compact ciphertext list`](`SeededLweCompactCiphertextList`).
+///
+/// Only the bodies of the ciphertexts are stored, the masks of the
+/// [`LweCompactCiphertextList`] obtained by decompression are generated from the seed of the
+/// output.
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for LweCiphertext creation
+/// let lwe_dimension = LweDimension(1024);
+/// let lwe_ciphertext_count = LweCiphertextCount(lwe_dimension.0 + 3);
+/// let lwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the LweSecretKey
+/// let lwe_secret_key =
+///     allocate_and_generate_new_binary_lwe_secret_key(lwe_dimension, &mut secret_generator);
+///
+/// let mut input_plaintext_list = PlaintextList::new(0u64, PlaintextCount(lwe_ciphertext_count.0));
+/// input_plaintext_list
+///     .iter_mut()
+///     .enumerate()
+///     .for_each(|(idx, x)| {
+///         *x.0 = (idx as u64 % 16) << 60;
+///     });
+///
+/// // Create a new SeededLweCompactCiphertextList
+/// let mut seeded_compact_list = SeededLweCompactCiphertextList::new(
+///     0u64,
+///     lwe_dimension.to_lwe_size(),
+///     lwe_ciphertext_count,
+///     seeder.seed().into(),
+///     ciphertext_modulus,
+/// );
+///
+/// encrypt_seeded_lwe_compact_ciphertext_list(
+///     &lwe_secret_key,
+///     &mut seeded_compact_list,
+///     &input_plaintext_list,
+///     lwe_modular_std_dev,
+///     seeder,
+/// );
+///
+/// let compact_list = seeded_compact_list.decompress_into_lwe_compact_ciphertext_list();
+/// let lwe_ciphertext_list = compact_list.expand_into_lwe_ciphertext_list();
+///
+/// let mut output_plaintext_list = input_plaintext_list.clone();
+/// output_plaintext_list.as_mut().fill(0u64);
+///
+/// decrypt_lwe_ciphertext_list(
+///     &lwe_secret_key,
+///     &lwe_ciphertext_list,
+///     &mut output_plaintext_list,
+/// );
+///
+/// let signed_decomposer =
+///     SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+///
+/// // Round the plaintexts
+/// output_plaintext_list
+///     .iter_mut()
+///     .for_each(|x| *x.0 = signed_decomposer.closest_representable(*x.0));
+///
+/// // Check we recovered the original messages
+/// assert_eq!(input_plaintext_list, output_plaintext_list);
+/// ```
+pub fn encrypt_seeded_lwe_compact_ciphertext_list<
+    Scalar,
+    KeyCont,
+    OutputCont,
+    InputCont,
+    NoiseSeeder,
+>(
+    lwe_secret_key: &LweSecretKey<KeyCont>,
+    output: &mut SeededLweCompactCiphertextList<OutputCont>,
+    encoded: &PlaintextList<InputCont>,
+    noise_parameters: impl DispersionParameter,
+    noise_seeder: &mut NoiseSeeder,
+) where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    // Maybe Sized allows to pass Box<dyn Seeder>.
+    NoiseSeeder: Seeder + ?Sized,
+{
+    let mut generator = EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(
+        output.compression_seed().seed,
+        noise_seeder,
+    );
+
+    encrypt_seeded_lwe_compact_ciphertext_list_with_existing_generator(
+        lwe_secret_key,
+        output,
+        encoded,
+        noise_parameters,
+        &mut generator,
+    );
+}
+
 #[cfg(test)]
 mod test {
     use crate::core_crypto::commons::test_tools;
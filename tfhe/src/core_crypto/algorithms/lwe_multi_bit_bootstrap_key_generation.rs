@@ -9,6 +9,8 @@ use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
 use rayon::prelude::*;
+use std::ops::ControlFlow;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 /// ```
 /// use tfhe::core_crypto::prelude::*;
@@ -317,6 +319,47 @@ pub fn par_generate_lwe_multi_bit_bootstrap_key<
     OutputKeyCont: Container<Element = Scalar> + Sync,
     OutputCont: ContainerMut<Element = Scalar>,
     Gen: ParallelByteRandomGenerator,
+{
+    // The progress callback never stops the generation, the key is always complete
+    let _ = par_generate_lwe_multi_bit_bootstrap_key_with_progress(
+        input_lwe_secret_key,
+        output_glwe_secret_key,
+        output,
+        noise_parameters,
+        generator,
+        || ControlFlow::Continue(()),
+    );
+}
+
+/// Variant of [`par_generate_lwe_multi_bit_bootstrap_key`] calling `on_ggsw_encrypted` each time
+/// one of the GGSW ciphertexts of the key is encrypted.
+///
+/// Once `on_ggsw_encrypted` returns [`ControlFlow::Break`], the GGSW ciphertexts not yet being
+/// encrypted are skipped and [`ControlFlow::Break`] is returned, the key is then incomplete and
+/// must be discarded. As the GGSW ciphertexts are encrypted in parallel, the callback can be
+/// called from several threads at once.
+pub fn par_generate_lwe_multi_bit_bootstrap_key_with_progress<
+    Scalar,
+    InputKeyCont,
+    OutputKeyCont,
+    OutputCont,
+    Gen,
+    F,
+>(
+    input_lwe_secret_key: &LweSecretKey<InputKeyCont>,
+    output_glwe_secret_key: &GlweSecretKey<OutputKeyCont>,
+    output: &mut LweMultiBitBootstrapKey<OutputCont>,
+    noise_parameters: impl DispersionParameter + Sync,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+    on_ggsw_encrypted: F,
+) -> ControlFlow<()>
+where
+    Scalar: UnsignedTorus + CastFrom<usize> + Sync + Send,
+    InputKeyCont: Container<Element = Scalar>,
+    OutputKeyCont: Container<Element = Scalar> + Sync,
+    OutputCont: ContainerMut<Element = Scalar>,
+    Gen: ParallelByteRandomGenerator,
+    F: Fn() -> ControlFlow<()> + Sync,
 {
     assert!(
         output.input_lwe_dimension() == input_lwe_secret_key.lwe_dimension(),
@@ -358,6 +401,8 @@ pub fn par_generate_lwe_multi_bit_bootstrap_key<
     let output_grouping_factor = output.grouping_factor();
     let ggsw_per_multi_bit_element = output_grouping_factor.ggsw_per_multi_bit_element();
 
+    let stopped = AtomicBool::new(false);
+
     output
         .par_iter_mut()
         .chunks(ggsw_per_multi_bit_element.0)
@@ -382,6 +427,10 @@ pub fn par_generate_lwe_multi_bit_bootstrap_key<
                     .enumerate()
                     .zip(gen_iter)
                     .for_each(|((bit_inversion_idx, ggsw), mut inner_loop_generator)| {
+                        if stopped.load(Ordering::Relaxed) {
+                            return;
+                        }
+
                         // Use the index of the ggsw as a way to know which bit to invert
                         let key_bits_plaintext =
                             combine_key_bits(bit_inversion_idx, input_key_elements);
@@ -393,9 +442,19 @@ pub fn par_generate_lwe_multi_bit_bootstrap_key<
                             noise_parameters,
                             &mut inner_loop_generator,
                         );
+
+                        if on_ggsw_encrypted().is_break() {
+                            stopped.store(true, Ordering::Relaxed);
+                        }
                     });
             },
         );
+
+    if stopped.into_inner() {
+        ControlFlow::Break(())
+    } else {
+        ControlFlow::Continue(())
+    }
 }
 
 fn combine_key_bits<Scalar>(bit_selector: usize, input_key_elements: &[Scalar]) -> Scalar
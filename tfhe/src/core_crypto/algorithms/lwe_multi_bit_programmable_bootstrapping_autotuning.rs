@@ -0,0 +1,330 @@
+//! Module containing an autotuner for the [`ThreadCount`] used by the [`multi bit programmable
+//! bootstrap`](`multi_bit_programmable_bootstrap_lwe_ciphertext`).
+//!
+//! The best number of threads for a multi bit PBS depends on the parameters and on the machine.
+//! The autotuner benchmarks the candidate thread counts the first time a set of parameters is
+//! used, and caches the fastest one for the lifetime of the process. A thread count can also be
+//! pinned for a set of parameters, in which case no benchmark is run for these parameters.
+//!
+//! The cache can be persisted to a file with [`save_multi_bit_pbs_thread_count_cache`] and
+//! restored with [`load_multi_bit_pbs_thread_count_cache`], so that the benchmarks only run once
+//! per machine. The file records the available parallelism of the machine which tuned it, the
+//! tuning is not loaded on a machine with another one.
+
+use crate::core_crypto::algorithms::multi_bit_programmable_bootstrap_lwe_ciphertext;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+use concrete_fft::c64;
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+
+// Number of timed runs per candidate, the best run is kept to filter out scheduling noise
+const RUNS_PER_CANDIDATE: usize = 3;
+
+// First line of the cache files, followed by the available parallelism of the machine
+const CACHE_FILE_HEADER: &str = "tfhe-rs multi bit pbs thread counts v0";
+
+static THREAD_COUNT_CACHE: RwLock<Vec<(MultiBitPbsThreadCountKey, ThreadCount)>> =
+    RwLock::new(Vec::new());
+
+/// The parameters of a multi bit PBS which determine its best [`ThreadCount`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
+pub struct MultiBitPbsThreadCountKey {
+    pub input_lwe_dimension: LweDimension,
+    pub glwe_dimension: GlweDimension,
+    pub polynomial_size: PolynomialSize,
+    pub decomposition_base_log: DecompositionBaseLog,
+    pub decomposition_level_count: DecompositionLevelCount,
+    pub grouping_factor: LweBskGroupingFactor,
+}
+
+impl MultiBitPbsThreadCountKey {
+    pub fn from_bootstrap_key<C: Container<Element = c64>>(
+        multi_bit_bsk: &FourierLweMultiBitBootstrapKey<C>,
+    ) -> Self {
+        Self {
+            input_lwe_dimension: multi_bit_bsk.input_lwe_dimension(),
+            glwe_dimension: multi_bit_bsk.glwe_size().to_glwe_dimension(),
+            polynomial_size: multi_bit_bsk.polynomial_size(),
+            decomposition_base_log: multi_bit_bsk.decomposition_base_log(),
+            decomposition_level_count: multi_bit_bsk.decomposition_level_count(),
+            grouping_factor: multi_bit_bsk.grouping_factor(),
+        }
+    }
+}
+
+/// Return the [`ThreadCount`] pinned or previously tuned for the given parameters, if any.
+pub fn cached_multi_bit_pbs_thread_count(key: MultiBitPbsThreadCountKey) -> Option<ThreadCount> {
+    THREAD_COUNT_CACHE
+        .read()
+        .unwrap()
+        .iter()
+        .find(|(cached_key, _)| *cached_key == key)
+        .map(|(_, thread_count)| *thread_count)
+}
+
+/// Pin the [`ThreadCount`] to use for the given parameters, replacing any previously tuned or
+/// pinned value.
+///
+/// # Panics
+///
+/// Panics if `thread_count` is 0.
+pub fn pin_multi_bit_pbs_thread_count(key: MultiBitPbsThreadCountKey, thread_count: ThreadCount) {
+    assert!(thread_count.0 > 0, "ThreadCount must be greater than 0");
+
+    let mut cache = THREAD_COUNT_CACHE.write().unwrap();
+    match cache.iter_mut().find(|(cached_key, _)| *cached_key == key) {
+        Some((_, cached_thread_count)) => *cached_thread_count = thread_count,
+        None => cache.push((key, thread_count)),
+    }
+}
+
+/// Remove the pinned or tuned [`ThreadCount`] of the given parameters, the next call to
+/// [`autotune_multi_bit_pbs_thread_count`] for these parameters runs the benchmark again.
+pub fn unpin_multi_bit_pbs_thread_count(key: MultiBitPbsThreadCountKey) {
+    THREAD_COUNT_CACHE
+        .write()
+        .unwrap()
+        .retain(|(cached_key, _)| *cached_key != key);
+}
+
+/// Return all the pinned and tuned thread counts of the process.
+pub fn multi_bit_pbs_thread_count_cache() -> Vec<(MultiBitPbsThreadCountKey, ThreadCount)> {
+    THREAD_COUNT_CACHE.read().unwrap().clone()
+}
+
+fn available_parallelism() -> usize {
+    std::thread::available_parallelism().map_or(1, |count| count.get())
+}
+
+/// Write all the pinned and tuned thread counts of the process to a file, see
+/// [`load_multi_bit_pbs_thread_count_cache`].
+///
+/// The file is a text file: a header with the available parallelism of the machine, then one line
+/// per set of parameters, the fields of the [`MultiBitPbsThreadCountKey`] followed by the
+/// [`ThreadCount`].
+pub fn save_multi_bit_pbs_thread_count_cache(path: impl AsRef<Path>) -> std::io::Result<()> {
+    let cache = multi_bit_pbs_thread_count_cache();
+
+    let mut writer = BufWriter::new(File::create(path)?);
+    writeln!(writer, "{CACHE_FILE_HEADER} {}", available_parallelism())?;
+    for (key, thread_count) in cache {
+        writeln!(
+            writer,
+            "{} {} {} {} {} {} {}",
+            key.input_lwe_dimension.0,
+            key.glwe_dimension.0,
+            key.polynomial_size.0,
+            key.decomposition_base_log.0,
+            key.decomposition_level_count.0,
+            key.grouping_factor.0,
+            thread_count.0,
+        )?;
+    }
+    writer.flush()
+}
+
+/// Load the thread counts written by [`save_multi_bit_pbs_thread_count_cache`], returns the
+/// number of loaded entries.
+///
+/// The values already pinned or tuned in the process are kept. Nothing is loaded if the file was
+/// written on a machine with another available parallelism, as its tuning does not apply to this
+/// machine.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::core_crypto::prelude::*;
+///
+/// let key = MultiBitPbsThreadCountKey {
+///     input_lwe_dimension: LweDimension(18),
+///     glwe_dimension: GlweDimension(1),
+///     polynomial_size: PolynomialSize(256),
+///     decomposition_base_log: DecompositionBaseLog(15),
+///     decomposition_level_count: DecompositionLevelCount(2),
+///     grouping_factor: LweBskGroupingFactor(3),
+/// };
+/// let path = std::env::temp_dir().join("tfhe_multi_bit_pbs_thread_counts_doctest");
+///
+/// let thread_count = autotune_multi_bit_pbs_thread_count(key);
+/// save_multi_bit_pbs_thread_count_cache(&path).unwrap();
+///
+/// // In another run on the same machine, the benchmark is not run again
+/// unpin_multi_bit_pbs_thread_count(key);
+/// assert!(load_multi_bit_pbs_thread_count_cache(&path).unwrap() >= 1);
+/// assert_eq!(cached_multi_bit_pbs_thread_count(key), Some(thread_count));
+/// # std::fs::remove_file(&path).unwrap();
+/// ```
+pub fn load_multi_bit_pbs_thread_count_cache(path: impl AsRef<Path>) -> std::io::Result<usize> {
+    let invalid_data =
+        |message: &str| std::io::Error::new(std::io::ErrorKind::InvalidData, message.to_owned());
+
+    let mut lines = BufReader::new(File::open(path)?).lines();
+    let header = lines
+        .next()
+        .transpose()?
+        .ok_or_else(|| invalid_data("Empty multi bit PBS thread count file"))?;
+    let parallelism = header
+        .strip_prefix(CACHE_FILE_HEADER)
+        .and_then(|parallelism| parallelism.trim().parse::<usize>().ok())
+        .ok_or_else(|| invalid_data("Not a multi bit PBS thread count file"))?;
+    if parallelism != available_parallelism() {
+        return Ok(0);
+    }
+
+    let mut entries = Vec::new();
+    for line in lines {
+        let line = line?;
+        let fields = line
+            .split_whitespace()
+            .map(str::parse::<usize>)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| invalid_data("Invalid multi bit PBS thread count entry"))?;
+        let [lwe_dim, glwe_dim, poly_size, base_log, level_count, grouping_factor, thread_count] =
+            fields[..]
+        else {
+            return Err(invalid_data("Invalid multi bit PBS thread count entry"));
+        };
+        if thread_count == 0 {
+            return Err(invalid_data("Invalid multi bit PBS thread count entry"));
+        }
+
+        let key = MultiBitPbsThreadCountKey {
+            input_lwe_dimension: LweDimension(lwe_dim),
+            glwe_dimension: GlweDimension(glwe_dim),
+            polynomial_size: PolynomialSize(poly_size),
+            decomposition_base_log: DecompositionBaseLog(base_log),
+            decomposition_level_count: DecompositionLevelCount(level_count),
+            grouping_factor: LweBskGroupingFactor(grouping_factor),
+        };
+        entries.push((key, ThreadCount(thread_count)));
+    }
+
+    // The file is fully parsed before the cache is modified, an invalid file loads nothing
+    let mut cache = THREAD_COUNT_CACHE.write().unwrap();
+    let mut num_loaded = 0;
+    for (key, thread_count) in entries {
+        if cache.iter().all(|(cached_key, _)| *cached_key != key) {
+            cache.push((key, thread_count));
+            num_loaded += 1;
+        }
+    }
+    Ok(num_loaded)
+}
+
+/// Return the fastest [`ThreadCount`] for a multi bit PBS with the given parameters on this
+/// machine.
+///
+/// The first call for a set of parameters benchmarks a multi bit PBS with every thread count from
+/// 1 to the available parallelism of the machine, which takes a few seconds, subsequent calls
+/// return the cached result. If a value was pinned with [`pin_multi_bit_pbs_thread_count`] it is
+/// returned directly.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::core_crypto::prelude::*;
+///
+/// let key = MultiBitPbsThreadCountKey {
+///     input_lwe_dimension: LweDimension(16),
+///     glwe_dimension: GlweDimension(1),
+///     polynomial_size: PolynomialSize(256),
+///     decomposition_base_log: DecompositionBaseLog(15),
+///     decomposition_level_count: DecompositionLevelCount(2),
+///     grouping_factor: LweBskGroupingFactor(2),
+/// };
+///
+/// let thread_count = autotune_multi_bit_pbs_thread_count(key);
+/// assert!(thread_count.0 >= 1);
+/// assert_eq!(cached_multi_bit_pbs_thread_count(key), Some(thread_count));
+///
+/// // A pinned value takes precedence over the tuned one
+/// pin_multi_bit_pbs_thread_count(key, ThreadCount(3));
+/// assert_eq!(autotune_multi_bit_pbs_thread_count(key), ThreadCount(3));
+/// ```
+pub fn autotune_multi_bit_pbs_thread_count(key: MultiBitPbsThreadCountKey) -> ThreadCount {
+    if let Some(thread_count) = cached_multi_bit_pbs_thread_count(key) {
+        return thread_count;
+    }
+
+    let thread_count = benchmark_multi_bit_pbs_thread_counts(key);
+
+    // Another thread may have tuned or pinned the same parameters in the meantime, keep its value
+    let mut cache = THREAD_COUNT_CACHE.write().unwrap();
+    match cache.iter().find(|(cached_key, _)| *cached_key == key) {
+        Some((_, cached_thread_count)) => *cached_thread_count,
+        None => {
+            cache.push((key, thread_count));
+            thread_count
+        }
+    }
+}
+
+fn benchmark_multi_bit_pbs_thread_counts(key: MultiBitPbsThreadCountKey) -> ThreadCount {
+    let max_thread_count = available_parallelism();
+
+    // The run time of a PBS does not depend on the values it computes on, so trivial keys and
+    // ciphertexts are enough to benchmark it
+    let ciphertext_modulus = CiphertextModulus::<u64>::new_native();
+    let glwe_size = key.glwe_dimension.to_glwe_size();
+
+    let multi_bit_bsk = FourierLweMultiBitBootstrapKeyOwned::new(
+        key.input_lwe_dimension,
+        glwe_size,
+        key.polynomial_size,
+        key.decomposition_base_log,
+        key.decomposition_level_count,
+        key.grouping_factor,
+    );
+    let accumulator = GlweCiphertext::new(0u64, glwe_size, key.polynomial_size, ciphertext_modulus);
+    let input = LweCiphertext::new(
+        0u64,
+        key.input_lwe_dimension.to_lwe_size(),
+        ciphertext_modulus,
+    );
+    let mut output = LweCiphertext::new(
+        0u64,
+        key.glwe_dimension
+            .to_equivalent_lwe_dimension(key.polynomial_size)
+            .to_lwe_size(),
+        ciphertext_modulus,
+    );
+
+    let mut best = (ThreadCount(1), Duration::MAX);
+    for thread_count in (1..=max_thread_count).map(ThreadCount) {
+        // Warm up run, so that buffers allocation and FFT plans are not part of the measure
+        multi_bit_programmable_bootstrap_lwe_ciphertext(
+            &input,
+            &mut output,
+            &accumulator,
+            &multi_bit_bsk,
+            thread_count,
+        );
+
+        let elapsed = (0..RUNS_PER_CANDIDATE)
+            .map(|_| {
+                let start = Instant::now();
+                multi_bit_programmable_bootstrap_lwe_ciphertext(
+                    &input,
+                    &mut output,
+                    &accumulator,
+                    &multi_bit_bsk,
+                    thread_count,
+                );
+                start.elapsed()
+            })
+            .min()
+            .unwrap();
+
+        if elapsed < best.1 {
+            best = (thread_count, elapsed);
+        }
+    }
+
+    best.0
+}
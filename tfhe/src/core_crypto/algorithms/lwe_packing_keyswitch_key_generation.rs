@@ -8,7 +8,9 @@ use crate::core_crypto::algorithms::{
 use crate::core_crypto::commons::dispersion::DispersionParameter;
 use crate::core_crypto::commons::generators::EncryptionRandomGenerator;
 use crate::core_crypto::commons::math::decomposition::{DecompositionLevel, DecompositionTerm};
-use crate::core_crypto::commons::math::random::{ActivatedRandomGenerator, ByteRandomGenerator};
+use crate::core_crypto::commons::math::random::{
+    ActivatedRandomGenerator, ByteRandomGenerator, ParallelByteRandomGenerator,
+};
 use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::{
@@ -196,6 +198,200 @@ where
     new_lwe_packing_keyswitch_key
 }
 
+/// Parallel variant of [`generate_lwe_packing_keyswitch_key`], it is recommended to use this
+/// function for better key generation times as LWE packing keyswitching keys can be quite large.
+///
+/// The key elements are encrypted with generators forked from `generator`, so the result differs
+/// from the one of [`generate_lwe_packing_keyswitch_key`] for the same seeds.
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for LwePackingKeyswitchKey creation
+/// let input_lwe_dimension = LweDimension(742);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let output_glwe_dimension = GlweDimension(1);
+/// let output_polynomial_size = PolynomialSize(2048);
+/// let decomp_base_log = DecompositionBaseLog(23);
+/// let decomp_level_count = DecompositionLevelCount(1);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the LweSecretKey
+/// let input_lwe_secret_key =
+///     allocate_and_generate_new_binary_lwe_secret_key(input_lwe_dimension, &mut secret_generator);
+/// let output_glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     output_glwe_dimension,
+///     output_polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// let mut pksk = LwePackingKeyswitchKey::new(
+///     0u64,
+///     decomp_base_log,
+///     decomp_level_count,
+///     input_lwe_dimension,
+///     output_glwe_dimension,
+///     output_polynomial_size,
+///     ciphertext_modulus,
+/// );
+///
+/// par_generate_lwe_packing_keyswitch_key(
+///     &input_lwe_secret_key,
+///     &output_glwe_secret_key,
+///     &mut pksk,
+///     glwe_modular_std_dev,
+///     &mut encryption_generator,
+/// );
+///
+/// assert!(pksk.as_ref().iter().all(|&x| x == 0) == false);
+/// ```
+pub fn par_generate_lwe_packing_keyswitch_key<Scalar, InputKeyCont, OutputKeyCont, KSKeyCont, Gen>(
+    input_lwe_sk: &LweSecretKey<InputKeyCont>,
+    output_glwe_sk: &GlweSecretKey<OutputKeyCont>,
+    lwe_packing_keyswitch_key: &mut LwePackingKeyswitchKey<KSKeyCont>,
+    noise_parameters: impl DispersionParameter + Sync,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) where
+    Scalar: UnsignedTorus + Sync + Send,
+    InputKeyCont: Container<Element = Scalar>,
+    OutputKeyCont: Container<Element = Scalar> + Sync,
+    KSKeyCont: ContainerMut<Element = Scalar>,
+    Gen: ParallelByteRandomGenerator,
+{
+    assert!(
+        lwe_packing_keyswitch_key.input_key_lwe_dimension() == input_lwe_sk.lwe_dimension(),
+        "The destination LwePackingKeyswitchKey input LweDimension is not equal \
+    to the input LweSecretKey LweDimension. Destination: {:?}, input: {:?}",
+        lwe_packing_keyswitch_key.input_key_lwe_dimension(),
+        input_lwe_sk.lwe_dimension()
+    );
+    assert!(
+        lwe_packing_keyswitch_key.output_key_glwe_dimension() == output_glwe_sk.glwe_dimension(),
+        "The destination LwePackingKeyswitchKey output LweDimension is not equal \
+    to the output GlweSecretKey GlweDimension. Destination: {:?}, output: {:?}",
+        lwe_packing_keyswitch_key.output_key_glwe_dimension(),
+        output_glwe_sk.glwe_dimension()
+    );
+    assert!(
+        lwe_packing_keyswitch_key.output_key_polynomial_size() == output_glwe_sk.polynomial_size(),
+        "The destination LwePackingKeyswitchKey output PolynomialSize is not equal \
+        to the output GlweSecretKey PolynomialSize. Destination: {:?}, output: {:?}",
+        lwe_packing_keyswitch_key.output_key_polynomial_size(),
+        output_glwe_sk.polynomial_size()
+    );
+
+    let decomp_base_log = lwe_packing_keyswitch_key.decomposition_base_log();
+    let decomp_level_count = lwe_packing_keyswitch_key.decomposition_level_count();
+    let polynomial_size = lwe_packing_keyswitch_key.output_polynomial_size();
+    let ciphertext_modulus = lwe_packing_keyswitch_key.ciphertext_modulus();
+    assert!(ciphertext_modulus.is_compatible_with_native_modulus());
+
+    let gen_iter = generator
+        .par_fork_pksk_to_pksk_blocks::<Scalar>(
+            decomp_level_count,
+            lwe_packing_keyswitch_key
+                .output_key_glwe_dimension()
+                .to_glwe_size(),
+            polynomial_size,
+            lwe_packing_keyswitch_key.input_key_lwe_dimension(),
+        )
+        .unwrap();
+
+    // Iterate over the input key elements and the destination lwe_packing_keyswitch_key memory
+    lwe_packing_keyswitch_key
+        .par_iter_mut()
+        .zip(input_lwe_sk.as_ref().par_iter())
+        .zip(gen_iter)
+        .for_each(
+            |((mut packing_keyswitch_key_block, &input_key_element), mut generator)| {
+                // The plaintexts used to encrypt a key element will be stored in this buffer
+                let mut decomposition_plaintexts_buffer = PlaintextListOwned::new(
+                    Scalar::ZERO,
+                    PlaintextCount(decomp_level_count.0 * polynomial_size.0),
+                );
+
+                // We fill the buffer with the powers of the key elements
+                for (level, mut messages) in (1..=decomp_level_count.0)
+                    .rev()
+                    .map(DecompositionLevel)
+                    .zip(decomposition_plaintexts_buffer.chunks_exact_mut(polynomial_size.0))
+                {
+                    // Here  we take the decomposition term from the native torus, bring it to
+                    // the torus we are working with by dividing by the scaling factor and the
+                    // encryption will take care of mapping that back to the native torus
+                    *messages.get_mut(0).0 =
+                        DecompositionTerm::new(level, decomp_base_log, input_key_element)
+                            .to_recomposition_summand()
+                            .wrapping_div(
+                                ciphertext_modulus.get_power_of_two_scaling_to_native_torus(),
+                            );
+                }
+
+                encrypt_glwe_ciphertext_list(
+                    output_glwe_sk,
+                    &mut packing_keyswitch_key_block,
+                    &decomposition_plaintexts_buffer,
+                    noise_parameters,
+                    &mut generator,
+                );
+            },
+        );
+}
+
+/// Parallel variant of [`allocate_and_generate_new_lwe_packing_keyswitch_key`], it is recommended
+/// to use this function for better key generation times as LWE packing keyswitching keys can be
+/// quite large.
+pub fn par_allocate_and_generate_new_lwe_packing_keyswitch_key<
+    Scalar,
+    InputKeyCont,
+    OutputKeyCont,
+    Gen,
+>(
+    input_lwe_sk: &LweSecretKey<InputKeyCont>,
+    output_glwe_sk: &GlweSecretKey<OutputKeyCont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    noise_parameters: impl DispersionParameter + Sync,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) -> LwePackingKeyswitchKeyOwned<Scalar>
+where
+    Scalar: UnsignedTorus + Sync + Send,
+    InputKeyCont: Container<Element = Scalar>,
+    OutputKeyCont: Container<Element = Scalar> + Sync,
+    Gen: ParallelByteRandomGenerator,
+{
+    let mut new_lwe_packing_keyswitch_key = LwePackingKeyswitchKeyOwned::new(
+        Scalar::ZERO,
+        decomp_base_log,
+        decomp_level_count,
+        input_lwe_sk.lwe_dimension(),
+        output_glwe_sk.glwe_dimension(),
+        output_glwe_sk.polynomial_size(),
+        ciphertext_modulus,
+    );
+
+    par_generate_lwe_packing_keyswitch_key(
+        input_lwe_sk,
+        output_glwe_sk,
+        &mut new_lwe_packing_keyswitch_key,
+        noise_parameters,
+        generator,
+    );
+
+    new_lwe_packing_keyswitch_key
+}
+
 /// Fill an [`LWE keyswitch key`](`SeededLwePackingKeyswitchKey`) with an actual keyswitching key
 /// constructed from an input [`LWE secret key`](`LweSecretKey`) and an output
 /// [`GLWE secret key`](`GlweSecretKey`).
@@ -387,3 +583,160 @@ where
 
     new_lwe_packing_keyswitch_key
 }
+
+/// Parallel variant of [`generate_seeded_lwe_packing_keyswitch_key`], it is recommended to use
+/// this function for better key generation times as LWE packing keyswitching keys can be quite
+/// large.
+///
+/// The mask is the same as the one of [`generate_seeded_lwe_packing_keyswitch_key`] for the same
+/// compression seed, the key decompresses to the same masks.
+pub fn par_generate_seeded_lwe_packing_keyswitch_key<
+    Scalar,
+    InputKeyCont,
+    OutputKeyCont,
+    KSKeyCont,
+    NoiseSeeder,
+>(
+    input_lwe_sk: &LweSecretKey<InputKeyCont>,
+    output_glwe_sk: &GlweSecretKey<OutputKeyCont>,
+    lwe_packing_keyswitch_key: &mut SeededLwePackingKeyswitchKey<KSKeyCont>,
+    noise_parameters: impl DispersionParameter + Sync,
+    noise_seeder: &mut NoiseSeeder,
+) where
+    Scalar: UnsignedTorus + Sync + Send,
+    InputKeyCont: Container<Element = Scalar>,
+    OutputKeyCont: Container<Element = Scalar> + Sync,
+    KSKeyCont: ContainerMut<Element = Scalar>,
+    // Maybe Sized allows to pass Box<dyn Seeder>.
+    NoiseSeeder: Seeder + ?Sized,
+{
+    assert!(
+        lwe_packing_keyswitch_key.input_key_lwe_dimension() == input_lwe_sk.lwe_dimension(),
+        "The destination LwePackingKeyswitchKey input LweDimension is not equal \
+    to the input LweSecretKey LweDimension. Destination: {:?}, input: {:?}",
+        lwe_packing_keyswitch_key.input_key_lwe_dimension(),
+        input_lwe_sk.lwe_dimension()
+    );
+    assert!(
+        lwe_packing_keyswitch_key.output_key_glwe_dimension() == output_glwe_sk.glwe_dimension(),
+        "The destination LwePackingKeyswitchKey output LweDimension is not equal \
+    to the output GlweSecretKey GlweDimension. Destination: {:?}, output: {:?}",
+        lwe_packing_keyswitch_key.output_key_glwe_dimension(),
+        output_glwe_sk.glwe_dimension()
+    );
+    assert!(
+        lwe_packing_keyswitch_key.output_key_polynomial_size() == output_glwe_sk.polynomial_size(),
+        "The destination LwePackingKeyswitchKey output PolynomialSize is not equal \
+        to the output GlweSecretKey PolynomialSize. Destination: {:?}, output: {:?}",
+        lwe_packing_keyswitch_key.output_key_polynomial_size(),
+        output_glwe_sk.polynomial_size()
+    );
+
+    let decomp_base_log = lwe_packing_keyswitch_key.decomposition_base_log();
+    let decomp_level_count = lwe_packing_keyswitch_key.decomposition_level_count();
+    let polynomial_size = lwe_packing_keyswitch_key.output_polynomial_size();
+    let ciphertext_modulus = lwe_packing_keyswitch_key.ciphertext_modulus();
+    assert!(ciphertext_modulus.is_compatible_with_native_modulus());
+
+    let mut generator = EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(
+        lwe_packing_keyswitch_key.compression_seed().seed,
+        noise_seeder,
+    );
+
+    let gen_iter = generator
+        .par_fork_pksk_to_pksk_blocks::<Scalar>(
+            decomp_level_count,
+            lwe_packing_keyswitch_key
+                .output_key_glwe_dimension()
+                .to_glwe_size(),
+            polynomial_size,
+            lwe_packing_keyswitch_key.input_key_lwe_dimension(),
+        )
+        .unwrap();
+
+    // Iterate over the input key elements and the destination lwe_packing_keyswitch_key memory
+    lwe_packing_keyswitch_key
+        .par_iter_mut()
+        .zip(input_lwe_sk.as_ref().par_iter())
+        .zip(gen_iter)
+        .for_each(
+            |((mut packing_keyswitch_key_block, &input_key_element), mut generator)| {
+                // The plaintexts used to encrypt a key element will be stored in this buffer
+                let mut decomposition_plaintexts_buffer = PlaintextListOwned::new(
+                    Scalar::ZERO,
+                    PlaintextCount(decomp_level_count.0 * polynomial_size.0),
+                );
+
+                // We fill the buffer with the powers of the key elements
+                for (level, mut messages) in (1..=decomp_level_count.0)
+                    .rev()
+                    .map(DecompositionLevel)
+                    .zip(decomposition_plaintexts_buffer.chunks_exact_mut(polynomial_size.0))
+                {
+                    // Here  we take the decomposition term from the native torus, bring it to
+                    // the torus we are working with by dividing by the scaling factor and the
+                    // encryption will take care of mapping that back to the native torus
+                    *messages.get_mut(0).0 =
+                        DecompositionTerm::new(level, decomp_base_log, input_key_element)
+                            .to_recomposition_summand()
+                            .wrapping_div(
+                                ciphertext_modulus.get_power_of_two_scaling_to_native_torus(),
+                            );
+                }
+
+                encrypt_seeded_glwe_ciphertext_list_with_existing_generator(
+                    output_glwe_sk,
+                    &mut packing_keyswitch_key_block,
+                    &decomposition_plaintexts_buffer,
+                    noise_parameters,
+                    &mut generator,
+                );
+            },
+        );
+}
+
+/// Parallel variant of [`allocate_and_generate_new_seeded_lwe_packing_keyswitch_key`], it is
+/// recommended to use this function for better key generation times as LWE packing keyswitching
+/// keys can be quite large.
+pub fn par_allocate_and_generate_new_seeded_lwe_packing_keyswitch_key<
+    Scalar,
+    InputKeyCont,
+    OutputKeyCont,
+    NoiseSeeder,
+>(
+    input_lwe_sk: &LweSecretKey<InputKeyCont>,
+    output_glwe_sk: &GlweSecretKey<OutputKeyCont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    noise_parameters: impl DispersionParameter + Sync,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    noise_seeder: &mut NoiseSeeder,
+) -> SeededLwePackingKeyswitchKeyOwned<Scalar>
+where
+    Scalar: UnsignedTorus + Sync + Send,
+    InputKeyCont: Container<Element = Scalar>,
+    OutputKeyCont: Container<Element = Scalar> + Sync,
+    // Maybe Sized allows to pass Box<dyn Seeder>.
+    NoiseSeeder: Seeder + ?Sized,
+{
+    let mut new_lwe_packing_keyswitch_key = SeededLwePackingKeyswitchKeyOwned::new(
+        Scalar::ZERO,
+        decomp_base_log,
+        decomp_level_count,
+        input_lwe_sk.lwe_dimension(),
+        output_glwe_sk.glwe_dimension(),
+        output_glwe_sk.polynomial_size(),
+        noise_seeder.seed().into(),
+        ciphertext_modulus,
+    );
+
+    par_generate_seeded_lwe_packing_keyswitch_key(
+        input_lwe_sk,
+        output_glwe_sk,
+        &mut new_lwe_packing_keyswitch_key,
+        noise_parameters,
+        noise_seeder,
+    );
+
+    new_lwe_packing_keyswitch_key
+}
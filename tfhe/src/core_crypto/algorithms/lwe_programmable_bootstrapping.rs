@@ -1,6 +1,7 @@
 //! Module containing primitives pertaining to the [`LWE programmable
 //! bootstrap`](`LweBootstrapKey#programmable-bootstrapping`).
 
+use crate::core_crypto::algorithms::allocate_and_trivially_encrypt_new_glwe_ciphertext;
 use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
 use crate::core_crypto::commons::math::decomposition::SignedDecomposer;
 use crate::core_crypto::commons::parameters::*;
@@ -20,9 +21,112 @@ use crate::core_crypto::fft_impl::fft64::crypto::ggsw::{
 };
 use crate::core_crypto::fft_impl::fft64::crypto::wop_pbs::blind_rotate_assign_scratch;
 use crate::core_crypto::fft_impl::fft64::math::fft::{Fft, FftView};
+use crate::core_crypto::fft_impl::ntt64::crypto::bootstrap::{
+    blind_rotate_scratch as blind_rotate_scratch_ntt64,
+    bootstrap_scratch as bootstrap_scratch_ntt64, NttLweBootstrapKey,
+};
+use crate::core_crypto::fft_impl::ntt64::math::ntt::{Ntt, NttView};
 use concrete_fft::c64;
 use dyn_stack::{PodStack, SizeOverflow, StackReq};
 
+/// Allocate a new look-up table for a programmable bootstrap, trivially encrypted in a
+/// [`GLWE ciphertext`](`GlweCiphertext`), mapping an input message `m` to `f(m) * delta`.
+///
+/// The input messages are in `[0, message_modulus)` and encoded with a bit of padding, the
+/// polynomial is split in `message_modulus` boxes of coefficients, the box `m` holding the
+/// encoded `f(m)`. The boxes are shifted by half a box, so that an input with a small noise
+/// still rotates the polynomial to the right box, the coefficients wrapping around being negated
+/// to account for the negacyclicity.
+///
+/// The look-up table can be used with [`programmable_bootstrap_lwe_ciphertext`], or modified
+/// before being passed to [`blind_rotate_assign`] to implement custom bootstrapping variants.
+///
+/// # Panics
+///
+/// Panics if `message_modulus` does not divide the polynomial size.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_size = GlweSize(2);
+/// let message_modulus = 1u64 << 4;
+/// // Encode 4 bits of message with a bit of padding on u64
+/// let delta = (1_u64 << 63) / message_modulus;
+///
+/// let lut = generate_programmable_bootstrap_glwe_lut(
+///     polynomial_size,
+///     glwe_size,
+///     message_modulus as usize,
+///     CiphertextModulus::new_native(),
+///     delta,
+///     |x: u64| (x + 1) % message_modulus,
+/// );
+///
+/// // The polynomial starts with the second half of the box of f(0), followed by the box of f(1),
+/// // the first half of the box of f(0) is negated at the end of the polynomial
+/// let half_box_size = polynomial_size.0 / message_modulus as usize / 2;
+/// let body = lut.get_body();
+/// assert_eq!(body.as_ref()[0], delta);
+/// assert_eq!(body.as_ref()[half_box_size], 2 * delta);
+/// assert_eq!(body.as_ref()[polynomial_size.0 - 1], delta.wrapping_neg());
+/// ```
+pub fn generate_programmable_bootstrap_glwe_lut<F, Scalar>(
+    polynomial_size: PolynomialSize,
+    glwe_size: GlweSize,
+    message_modulus: usize,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    delta: Scalar,
+    f: F,
+) -> GlweCiphertextOwned<Scalar>
+where
+    Scalar: UnsignedTorus + CastFrom<usize>,
+    F: Fn(Scalar) -> Scalar,
+{
+    assert_eq!(
+        polynomial_size.0 % message_modulus,
+        0,
+        "The message modulus {message_modulus} does not divide the polynomial size {}",
+        polynomial_size.0
+    );
+
+    // N/(p/2) = size of each block, to correct noise from the input we introduce the
+    // notion of box, which manages redundancy to yield a denoised value
+    // for several noisy values around a true input value.
+    let box_size = polynomial_size.0 / message_modulus;
+
+    // Create the accumulator
+    let mut accumulator_scalar = vec![Scalar::ZERO; polynomial_size.0];
+
+    // Fill each box with the encoded denoised value
+    for i in 0..message_modulus {
+        let index = i * box_size;
+        accumulator_scalar[index..index + box_size]
+            .iter_mut()
+            .for_each(|a| *a = f(Scalar::cast_from(i)).wrapping_mul(delta));
+    }
+
+    let half_box_size = box_size / 2;
+
+    // Negate the first half_box_size coefficients to manage negacyclicity and rotate
+    for a_i in accumulator_scalar[0..half_box_size].iter_mut() {
+        *a_i = (*a_i).wrapping_neg();
+    }
+
+    // Rotate the accumulator
+    accumulator_scalar.rotate_left(half_box_size);
+
+    let accumulator_plaintext = PlaintextList::from_container(accumulator_scalar);
+
+    allocate_and_trivially_encrypt_new_glwe_ciphertext(
+        glwe_size,
+        &accumulator_plaintext,
+        ciphertext_modulus,
+    )
+}
+
 /// Perform a blind rotation given an input [`LWE ciphertext`](`LweCiphertext`), modifying a look-up
 /// table passed as a [`GLWE ciphertext`](`GlweCiphertext`) and an [`LWE bootstrap
 /// key`](`LweBootstrapKey`) in the fourier domain see [`fourier LWE bootstrap
@@ -816,6 +920,12 @@ pub fn cmux_assign_mem_optimized_requirement<Scalar>(
 /// key`](`FourierLweBootstrapKey`). The result is written in the provided output
 /// [`LWE ciphertext`](`LweCiphertext`).
 ///
+/// The ciphertexts can use `u32`, `u64` or `u128` scalars. The external products are computed
+/// with `f64` values, so only about 53 MSBs of the result carry information: with `u128` scalars
+/// the larger ciphertext modulus gives more room to the message and the carries, but the noise of
+/// the PBS output is similar to the `u64` case. When the parameters require more precision, use
+/// [`programmable_bootstrap_f128_lwe_ciphertext`].
+///
 /// If you want to manage the computation memory manually you can use
 /// [`programmable_bootstrap_lwe_ciphertext_mem_optimized`].
 ///
@@ -1406,3 +1516,264 @@ pub fn programmable_bootstrap_f128_lwe_ciphertext_mem_optimized_requirement<Scal
 ) -> Result<StackReq, SizeOverflow> {
     bootstrap_scratch_f128::<Scalar>(glwe_size, polynomial_size, fft)
 }
+
+/// Perform a programmable bootstrap given an input [`LWE ciphertext`](`LweCiphertext`), a
+/// look-up table passed as a [`GLWE ciphertext`](`GlweCiphertext`) and an [`LWE bootstrap
+/// key`](`LweBootstrapKey`) in the NTT domain see [`NTT LWE bootstrap
+/// key`](`NttLweBootstrapKey`). The result is written in the provided output
+/// [`LWE ciphertext`](`LweCiphertext`).
+///
+/// The external products are computed with integer arithmetic only, so for the same inputs and
+/// key the output is bit-exact on all platforms, which is not guaranteed by the floating point
+/// FFT of [`programmable_bootstrap_lwe_ciphertext`]. The native modulus and the power of two
+/// moduli are supported, the products on the latter being exact as well.
+///
+/// If you want to manage the computation memory manually you can use
+/// [`programmable_bootstrap_ntt64_lwe_ciphertext_mem_optimized`].
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define the parameters for a 4 bits message able to hold the doubled 2 bits message
+/// let small_lwe_dimension = LweDimension(742);
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(2048);
+/// let lwe_modular_std_dev = StandardDev(0.000007069849454709433);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let pbs_base_log = DecompositionBaseLog(23);
+/// let pbs_level = DecompositionLevelCount(1);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Request the best seeder possible, starting with hardware entropy sources and falling back to
+/// // /dev/random on Unix systems if enabled via cargo features
+/// let mut boxed_seeder = new_seeder();
+/// // Get a mutable reference to the seeder as a trait object from the Box returned by new_seeder
+/// let seeder = boxed_seeder.as_mut();
+///
+/// // Create a generator which uses a CSPRNG to generate secret keys
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create a generator which uses two CSPRNGs to generate public masks and secret encryption
+/// // noise
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+///
+/// // Generate an LweSecretKey with binary coefficients
+/// let small_lwe_sk =
+///     LweSecretKey::generate_new_binary(small_lwe_dimension, &mut secret_generator);
+///
+/// // Generate a GlweSecretKey with binary coefficients
+/// let glwe_sk =
+///     GlweSecretKey::generate_new_binary(glwe_dimension, polynomial_size, &mut secret_generator);
+///
+/// // Create a copy of the GlweSecretKey re-interpreted as an LweSecretKey
+/// let big_lwe_sk = glwe_sk.clone().into_lwe_secret_key();
+///
+/// let std_bootstrapping_key = par_allocate_and_generate_new_lwe_bootstrap_key(
+///     &small_lwe_sk,
+///     &glwe_sk,
+///     pbs_base_log,
+///     pbs_level,
+///     glwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // Create the empty bootstrapping key in the NTT domain
+/// let mut ntt_bsk = NttLweBootstrapKey::new(
+///     std_bootstrapping_key.input_lwe_dimension(),
+///     std_bootstrapping_key.glwe_size(),
+///     std_bootstrapping_key.polynomial_size(),
+///     std_bootstrapping_key.decomposition_base_log(),
+///     std_bootstrapping_key.decomposition_level_count(),
+/// );
+///
+/// // Convert the standard bootstrapping key to the NTT domain
+/// par_convert_standard_lwe_bootstrap_key_to_ntt64(&std_bootstrapping_key, &mut ntt_bsk);
+/// // We don't need the standard bootstrapping key anymore
+/// drop(std_bootstrapping_key);
+///
+/// // Our 4 bits message space
+/// let message_modulus = 1u64 << 4;
+///
+/// // Our input message
+/// let input_message = 3u64;
+///
+/// // Delta used to encode 4 bits of message + a bit of padding on u64
+/// let delta = (1_u64 << 63) / message_modulus;
+///
+/// // Allocate a new LweCiphertext and encrypt our plaintext
+/// let lwe_ciphertext_in: LweCiphertextOwned<u64> = allocate_and_encrypt_new_lwe_ciphertext(
+///     &small_lwe_sk,
+///     Plaintext(input_message * delta),
+///     lwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // Generate the accumulator of a multiplication by 2
+/// let accumulator = generate_programmable_bootstrap_glwe_lut(
+///     polynomial_size,
+///     glwe_dimension.to_glwe_size(),
+///     message_modulus as usize,
+///     ciphertext_modulus,
+///     delta,
+///     |x: u64| 2 * x,
+/// );
+///
+/// // Allocate the LweCiphertext to store the result of the PBS
+/// let mut pbs_multiplication_ct = LweCiphertext::new(
+///     0u64,
+///     big_lwe_sk.lwe_dimension().to_lwe_size(),
+///     ciphertext_modulus,
+/// );
+/// programmable_bootstrap_ntt64_lwe_ciphertext(
+///     &lwe_ciphertext_in,
+///     &mut pbs_multiplication_ct,
+///     &accumulator,
+///     &ntt_bsk,
+/// );
+///
+/// // Decrypt the PBS multiplication result
+/// let pbs_multiplication_plaintext: Plaintext<u64> =
+///     decrypt_lwe_ciphertext(&big_lwe_sk, &pbs_multiplication_ct);
+///
+/// // Round the 5 MSB, 1 bit of padding plus our 4 bits of message, and remove our encoding
+/// let signed_decomposer =
+///     SignedDecomposer::new(DecompositionBaseLog(5), DecompositionLevelCount(1));
+/// let pbs_multiplication_result: u64 =
+///     signed_decomposer.closest_representable(pbs_multiplication_plaintext.0) / delta;
+///
+/// assert_eq!(6, pbs_multiplication_result);
+/// ```
+pub fn programmable_bootstrap_ntt64_lwe_ciphertext<InputCont, OutputCont, AccCont, KeyCont>(
+    input: &LweCiphertext<InputCont>,
+    output: &mut LweCiphertext<OutputCont>,
+    accumulator: &GlweCiphertext<AccCont>,
+    ntt_bsk: &NttLweBootstrapKey<KeyCont>,
+) where
+    InputCont: Container<Element = u64>,
+    OutputCont: ContainerMut<Element = u64>,
+    AccCont: Container<Element = u64>,
+    KeyCont: Container<Element = u64>,
+{
+    let mut buffers = ComputationBuffers::new();
+
+    let ntt = Ntt::new(ntt_bsk.polynomial_size());
+    let ntt = ntt.as_view();
+
+    buffers.resize(
+        programmable_bootstrap_ntt64_lwe_ciphertext_mem_optimized_requirement(
+            ntt_bsk.glwe_size(),
+            ntt_bsk.polynomial_size(),
+        )
+        .unwrap()
+        .unaligned_bytes_required(),
+    );
+
+    let stack = buffers.stack();
+
+    programmable_bootstrap_ntt64_lwe_ciphertext_mem_optimized(
+        input,
+        output,
+        accumulator,
+        ntt_bsk,
+        ntt,
+        stack,
+    )
+}
+
+/// Memory optimized version of [`programmable_bootstrap_ntt64_lwe_ciphertext`], the caller must
+/// provide a properly configured [`NttView`] object and a `PodStack` used as a memory buffer
+/// having a capacity at least as large as the result of
+/// [`programmable_bootstrap_ntt64_lwe_ciphertext_mem_optimized_requirement`].
+pub fn programmable_bootstrap_ntt64_lwe_ciphertext_mem_optimized<
+    InputCont,
+    OutputCont,
+    AccCont,
+    KeyCont,
+>(
+    input: &LweCiphertext<InputCont>,
+    output: &mut LweCiphertext<OutputCont>,
+    accumulator: &GlweCiphertext<AccCont>,
+    ntt_bsk: &NttLweBootstrapKey<KeyCont>,
+    ntt: NttView<'_>,
+    stack: PodStack<'_>,
+) where
+    InputCont: Container<Element = u64>,
+    OutputCont: ContainerMut<Element = u64>,
+    AccCont: Container<Element = u64>,
+    KeyCont: Container<Element = u64>,
+{
+    assert_eq!(
+        input.ciphertext_modulus(),
+        output.ciphertext_modulus(),
+        "Mismatched moduli between input ({:?}) and output ({:?})",
+        input.ciphertext_modulus(),
+        output.ciphertext_modulus()
+    );
+
+    assert_eq!(
+        accumulator.ciphertext_modulus(),
+        output.ciphertext_modulus(),
+        "Mismatched moduli between accumulator ({:?}) and output ({:?})",
+        accumulator.ciphertext_modulus(),
+        output.ciphertext_modulus()
+    );
+
+    ntt_bsk.as_view().bootstrap(
+        output.as_mut_view(),
+        input.as_view(),
+        accumulator.as_view(),
+        ntt,
+        stack,
+    );
+}
+
+/// Return the required memory for [`programmable_bootstrap_ntt64_lwe_ciphertext_mem_optimized`].
+pub fn programmable_bootstrap_ntt64_lwe_ciphertext_mem_optimized_requirement(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+) -> Result<StackReq, SizeOverflow> {
+    bootstrap_scratch_ntt64(glwe_size, polynomial_size)
+}
+
+/// Perform a blind rotation given an input [`LWE ciphertext`](`LweCiphertext`), modifying a
+/// look-up table passed as a [`GLWE ciphertext`](`GlweCiphertext`) and an [`LWE bootstrap
+/// key`](`LweBootstrapKey`) in the NTT domain see [`NTT LWE bootstrap
+/// key`](`NttLweBootstrapKey`).
+///
+/// This is the NTT counterpart of [`blind_rotate_assign_mem_optimized`], the caller must provide
+/// a properly configured [`NttView`] object and a `PodStack` used as a memory buffer having a
+/// capacity at least as large as the result of
+/// [`blind_rotate_ntt64_assign_mem_optimized_requirement`].
+pub fn blind_rotate_ntt64_assign_mem_optimized<InputCont, OutputCont, KeyCont>(
+    input: &LweCiphertext<InputCont>,
+    lut: &mut GlweCiphertext<OutputCont>,
+    ntt_bsk: &NttLweBootstrapKey<KeyCont>,
+    ntt: NttView<'_>,
+    stack: PodStack<'_>,
+) where
+    InputCont: Container<Element = u64>,
+    OutputCont: ContainerMut<Element = u64>,
+    KeyCont: Container<Element = u64>,
+{
+    assert_eq!(input.ciphertext_modulus(), lut.ciphertext_modulus());
+
+    ntt_bsk
+        .as_view()
+        .blind_rotate_assign(lut.as_mut_view(), input.as_ref(), ntt, stack);
+}
+
+/// Return the required memory for [`blind_rotate_ntt64_assign_mem_optimized`].
+pub fn blind_rotate_ntt64_assign_mem_optimized_requirement(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+) -> Result<StackReq, SizeOverflow> {
+    blind_rotate_scratch_ntt64(glwe_size, polynomial_size)
+}
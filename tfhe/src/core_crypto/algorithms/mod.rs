@@ -19,6 +19,7 @@ pub mod lwe_linear_algebra;
 pub mod lwe_multi_bit_bootstrap_key_conversion;
 pub mod lwe_multi_bit_bootstrap_key_generation;
 pub mod lwe_multi_bit_programmable_bootstrapping;
+pub mod lwe_multi_bit_programmable_bootstrapping_autotuning;
 pub mod lwe_packing_keyswitch;
 pub mod lwe_packing_keyswitch_key_generation;
 pub mod lwe_private_functional_packing_keyswitch;
@@ -36,6 +37,7 @@ pub mod seeded_glwe_ciphertext_list_decompression;
 pub mod seeded_lwe_bootstrap_key_decompression;
 pub mod seeded_lwe_ciphertext_decompression;
 pub mod seeded_lwe_ciphertext_list_decompression;
+pub mod seeded_lwe_compact_ciphertext_list_decompression;
 pub mod seeded_lwe_compact_public_key_decompression;
 pub mod seeded_lwe_keyswitch_key_decompression;
 pub mod seeded_lwe_multi_bit_bootstrap_key_decompression;
@@ -65,6 +67,7 @@ pub use lwe_linear_algebra::*;
 pub use lwe_multi_bit_bootstrap_key_conversion::*;
 pub use lwe_multi_bit_bootstrap_key_generation::*;
 pub use lwe_multi_bit_programmable_bootstrapping::*;
+pub use lwe_multi_bit_programmable_bootstrapping_autotuning::*;
 pub use lwe_packing_keyswitch::*;
 pub use lwe_packing_keyswitch_key_generation::*;
 pub use lwe_private_functional_packing_keyswitch::*;
@@ -80,6 +83,7 @@ pub use seeded_glwe_ciphertext_list_decompression::*;
 pub use seeded_lwe_bootstrap_key_decompression::*;
 pub use seeded_lwe_ciphertext_decompression::*;
 pub use seeded_lwe_ciphertext_list_decompression::*;
+pub use seeded_lwe_compact_ciphertext_list_decompression::*;
 pub use seeded_lwe_compact_public_key_decompression::*;
 pub use seeded_lwe_keyswitch_key_decompression::*;
 pub use seeded_lwe_multi_bit_bootstrap_key_decompression::*;
@@ -0,0 +1,93 @@
+//! Module with primitives pertaining to [`SeededLweCompactCiphertextList`] decompression.
+
+use crate::core_crypto::commons::generators::MaskRandomGenerator;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+
+/// Convenience function to share the core logic of the decompression algorithm for
+/// [`SeededLweCompactCiphertextList`] between all functions needing it.
+pub fn decompress_seeded_lwe_compact_ciphertext_list_with_existing_generator<
+    Scalar,
+    InputCont,
+    OutputCont,
+    Gen,
+>(
+    output_list: &mut LweCompactCiphertextList<OutputCont>,
+    input_seeded_list: &SeededLweCompactCiphertextList<InputCont>,
+    generator: &mut MaskRandomGenerator<Gen>,
+) where
+    Scalar: UnsignedTorus,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    assert_eq!(
+        output_list.ciphertext_modulus(),
+        input_seeded_list.ciphertext_modulus(),
+        "Mismatched CiphertextModulus \
+    between input SeededLweCompactCiphertextList ({:?}) and output LweCompactCiphertextList ({:?})",
+        input_seeded_list.ciphertext_modulus(),
+        output_list.ciphertext_modulus(),
+    );
+
+    assert_eq!(
+        output_list.lwe_size(),
+        input_seeded_list.lwe_size(),
+        "Mismatched LweSize \
+    between input SeededLweCompactCiphertextList ({:?}) and output LweCompactCiphertextList ({:?})",
+        input_seeded_list.lwe_size(),
+        output_list.lwe_size(),
+    );
+
+    assert_eq!(
+        output_list.lwe_ciphertext_count(),
+        input_seeded_list.lwe_ciphertext_count(),
+        "Mismatched LweCiphertextCount \
+    between input SeededLweCompactCiphertextList ({:?}) and output LweCompactCiphertextList ({:?})",
+        input_seeded_list.lwe_ciphertext_count(),
+        output_list.lwe_ciphertext_count(),
+    );
+
+    assert!(
+        output_list.ciphertext_modulus().is_native_modulus(),
+        "This operation only supports native moduli"
+    );
+
+    let (mut output_mask_list, mut output_body_list) = output_list.get_mut_mask_and_body_list();
+
+    let lwe_mask_count = output_mask_list.lwe_mask_count();
+    let lwe_dimension = output_mask_list.lwe_dimension();
+
+    // Generator forking and decompression computations must match the
+    // SeededLweCompactCiphertextList encryption algorithm
+    let gen_iter = generator
+        .fork_lwe_compact_ciphertext_list_to_bin::<Scalar>(lwe_mask_count, lwe_dimension)
+        .expect("Error while forking generator for SeededLweCompactCiphertextList decompression.");
+
+    for (mut output_mask, mut loop_generator) in output_mask_list.iter_mut().zip(gen_iter) {
+        loop_generator.fill_slice_with_random_mask(output_mask.as_mut());
+    }
+
+    output_body_list
+        .as_mut()
+        .copy_from_slice(input_seeded_list.as_ref());
+}
+
+/// Decompress a [`SeededLweCompactCiphertextList`], without consuming it, into a standard
+/// [`LweCompactCiphertextList`].
+pub fn decompress_seeded_lwe_compact_ciphertext_list<Scalar, InputCont, OutputCont, Gen>(
+    output_list: &mut LweCompactCiphertextList<OutputCont>,
+    input_seeded_list: &SeededLweCompactCiphertextList<InputCont>,
+) where
+    Scalar: UnsignedTorus,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    let mut generator = MaskRandomGenerator::<Gen>::new(input_seeded_list.compression_seed().seed);
+    decompress_seeded_lwe_compact_ciphertext_list_with_existing_generator::<_, _, _, Gen>(
+        output_list,
+        input_seeded_list,
+        &mut generator,
+    )
+}
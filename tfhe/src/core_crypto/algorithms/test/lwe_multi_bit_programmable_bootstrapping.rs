@@ -45,7 +45,7 @@ fn lwe_encrypt_multi_bit_pbs_decrypt_custom_mod<
     let mut msg = msg_modulus;
     const NB_TESTS: usize = 10;
 
-    let accumulator = generate_accumulator(
+    let accumulator = generate_programmable_bootstrap_glwe_lut(
         polynomial_size,
         glwe_dimension.to_glwe_size(),
         msg_modulus.cast_into(),
@@ -178,7 +178,7 @@ fn lwe_encrypt_multi_bit_deterministic_pbs_decrypt_custom_mod<
     // Divided by two compared to other tests, we are running the algorithm twice for determinism
     const NB_TESTS: usize = 5;
 
-    let accumulator = generate_accumulator(
+    let accumulator = generate_programmable_bootstrap_glwe_lut(
         polynomial_size,
         glwe_dimension.to_glwe_size(),
         msg_modulus.cast_into(),
@@ -334,7 +334,7 @@ fn lwe_encrypt_std_multi_bit_pbs_decrypt_custom_mod<
     let mut msg = msg_modulus;
     const NB_TESTS: usize = 10;
 
-    let accumulator = generate_accumulator(
+    let accumulator = generate_programmable_bootstrap_glwe_lut(
         polynomial_size,
         glwe_dimension.to_glwe_size(),
         msg_modulus.cast_into(),
@@ -454,7 +454,7 @@ fn std_lwe_encrypt_multi_bit_deterministic_pbs_decrypt_custom_mod<
     // Divided by two compared to other tests, we are running the algorithm twice for determinism
     const NB_TESTS: usize = 5;
 
-    let accumulator = generate_accumulator(
+    let accumulator = generate_programmable_bootstrap_glwe_lut(
         polynomial_size,
         glwe_dimension.to_glwe_size(),
         msg_modulus.cast_into(),
@@ -739,3 +739,82 @@ pub fn std_test_lwe_encrypt_multi_bit_deterministic_pbs_decrypt_factor_3_thread_
         ..MULTI_BIT_2_2_3_PARAMS
     });
 }
+
+#[test]
+pub fn test_multi_bit_pbs_thread_count_autotuning() {
+    // Small dimensions only used by this test, so that other tests do not share its cache entry
+    let key = MultiBitPbsThreadCountKey {
+        input_lwe_dimension: LweDimension(12),
+        glwe_dimension: GlweDimension(1),
+        polynomial_size: PolynomialSize(128),
+        decomposition_base_log: DecompositionBaseLog(10),
+        decomposition_level_count: DecompositionLevelCount(3),
+        grouping_factor: LweBskGroupingFactor(3),
+    };
+    let max_thread_count = std::thread::available_parallelism().map_or(1, |count| count.get());
+
+    assert_eq!(cached_multi_bit_pbs_thread_count(key), None);
+
+    let thread_count = autotune_multi_bit_pbs_thread_count(key);
+    assert!((1..=max_thread_count).contains(&thread_count.0));
+    assert_eq!(cached_multi_bit_pbs_thread_count(key), Some(thread_count));
+    assert!(multi_bit_pbs_thread_count_cache().contains(&(key, thread_count)));
+
+    pin_multi_bit_pbs_thread_count(key, ThreadCount(max_thread_count + 1));
+    assert_eq!(
+        autotune_multi_bit_pbs_thread_count(key),
+        ThreadCount(max_thread_count + 1)
+    );
+
+    unpin_multi_bit_pbs_thread_count(key);
+    assert_eq!(cached_multi_bit_pbs_thread_count(key), None);
+}
+
+#[test]
+pub fn test_multi_bit_pbs_thread_count_cache_file() {
+    // Parameters only used by this test, so that other tests do not share its cache entry
+    let key = MultiBitPbsThreadCountKey {
+        input_lwe_dimension: LweDimension(15),
+        glwe_dimension: GlweDimension(1),
+        polynomial_size: PolynomialSize(128),
+        decomposition_base_log: DecompositionBaseLog(10),
+        decomposition_level_count: DecompositionLevelCount(3),
+        grouping_factor: LweBskGroupingFactor(3),
+    };
+    let max_thread_count = std::thread::available_parallelism().map_or(1, |count| count.get());
+    let path = std::env::temp_dir().join(format!(
+        "tfhe_multi_bit_pbs_thread_counts_{}",
+        std::process::id()
+    ));
+
+    pin_multi_bit_pbs_thread_count(key, ThreadCount(2));
+    save_multi_bit_pbs_thread_count_cache(&path).unwrap();
+    unpin_multi_bit_pbs_thread_count(key);
+
+    assert!(load_multi_bit_pbs_thread_count_cache(&path).unwrap() >= 1);
+    assert_eq!(cached_multi_bit_pbs_thread_count(key), Some(ThreadCount(2)));
+
+    // The values of the process take precedence over the loaded ones
+    pin_multi_bit_pbs_thread_count(key, ThreadCount(3));
+    load_multi_bit_pbs_thread_count_cache(&path).unwrap();
+    assert_eq!(cached_multi_bit_pbs_thread_count(key), Some(ThreadCount(3)));
+    unpin_multi_bit_pbs_thread_count(key);
+
+    // A tuning from a machine with another available parallelism is not loaded
+    let content = std::fs::read_to_string(&path).unwrap();
+    let (header, entries) = content.split_once('\n').unwrap();
+    let (header_prefix, _) = header.rsplit_once(' ').unwrap();
+    let other_header = format!("{header_prefix} {}", max_thread_count + 1);
+    std::fs::write(&path, format!("{other_header}\n{entries}")).unwrap();
+    assert_eq!(load_multi_bit_pbs_thread_count_cache(&path).unwrap(), 0);
+    assert_eq!(cached_multi_bit_pbs_thread_count(key), None);
+
+    // An invalid file loads nothing
+    std::fs::write(&path, format!("{header}\n15 1 128 10 3 3 0\n")).unwrap();
+    assert!(load_multi_bit_pbs_thread_count_cache(&path).is_err());
+    std::fs::write(&path, "not a cache file\n").unwrap();
+    assert!(load_multi_bit_pbs_thread_count_cache(&path).is_err());
+    assert_eq!(cached_multi_bit_pbs_thread_count(key), None);
+
+    std::fs::remove_file(&path).unwrap();
+}
@@ -29,7 +29,7 @@ fn lwe_encrypt_pbs_decrypt_custom_mod<
     let mut msg = msg_modulus;
     const NB_TESTS: usize = 10;
 
-    let accumulator = generate_accumulator(
+    let accumulator = generate_programmable_bootstrap_glwe_lut(
         polynomial_size,
         glwe_dimension.to_glwe_size(),
         msg_modulus.cast_into(),
@@ -126,7 +126,141 @@ fn lwe_encrypt_pbs_decrypt_custom_mod<
     }
 }
 
-create_parametrized_test!(lwe_encrypt_pbs_decrypt_custom_mod);
+create_parametrized_test!(lwe_encrypt_pbs_decrypt_custom_mod {
+    TEST_PARAMS_4_BITS_NATIVE_U64,
+    TEST_PARAMS_3_BITS_63_U64,
+    TEST_PARAMS_4_BITS_NATIVE_U128,
+    TEST_PARAMS_3_BITS_127_U128
+});
+
+// A PBS rebuilt from the public blind rotation and sample extraction must match the PBS
+fn lwe_encrypt_blind_rotate_extract_decrypt_custom_mod<
+    Scalar: UnsignedTorus + Sync + Send + CastFrom<usize> + CastInto<usize>,
+>(
+    params: TestParams<Scalar>,
+) {
+    let input_lwe_dimension = params.lwe_dimension;
+    let lwe_modular_std_dev = params.lwe_modular_std_dev;
+    let glwe_modular_std_dev = params.glwe_modular_std_dev;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+
+    let mut rsc = TestResources::new();
+
+    let f = |x: Scalar| x.wrapping_add(Scalar::ONE).wrapping_rem(msg_modulus);
+
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+
+    let accumulator = generate_programmable_bootstrap_glwe_lut(
+        polynomial_size,
+        glwe_dimension.to_glwe_size(),
+        msg_modulus.cast_into(),
+        ciphertext_modulus,
+        delta,
+        f,
+    );
+
+    let input_lwe_secret_key = allocate_and_generate_new_binary_lwe_secret_key(
+        input_lwe_dimension,
+        &mut rsc.secret_random_generator,
+    );
+    let output_glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+        glwe_dimension,
+        polynomial_size,
+        &mut rsc.secret_random_generator,
+    );
+    let output_lwe_secret_key = output_glwe_secret_key.clone().into_lwe_secret_key();
+
+    let bsk = par_allocate_and_generate_new_lwe_bootstrap_key(
+        &input_lwe_secret_key,
+        &output_glwe_secret_key,
+        params.pbs_base_log,
+        params.pbs_level,
+        glwe_modular_std_dev,
+        ciphertext_modulus,
+        &mut rsc.encryption_random_generator,
+    );
+
+    let mut fbsk = FourierLweBootstrapKey::new(
+        input_lwe_dimension,
+        glwe_dimension.to_glwe_size(),
+        polynomial_size,
+        params.pbs_base_log,
+        params.pbs_level,
+    );
+    par_convert_standard_lwe_bootstrap_key_to_fourier(&bsk, &mut fbsk);
+    drop(bsk);
+
+    let fft = Fft::new(polynomial_size);
+    let fft = fft.as_view();
+    let mut buffers = ComputationBuffers::new();
+    buffers.resize(
+        blind_rotate_assign_mem_optimized_requirement::<Scalar>(
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            fft,
+        )
+        .unwrap()
+        .unaligned_bytes_required(),
+    );
+
+    let mut msg = msg_modulus;
+    while msg != Scalar::ZERO {
+        msg = msg.wrapping_sub(Scalar::ONE);
+
+        let lwe_ciphertext_in = allocate_and_encrypt_new_lwe_ciphertext(
+            &input_lwe_secret_key,
+            Plaintext(msg * delta),
+            lwe_modular_std_dev,
+            ciphertext_modulus,
+            &mut rsc.encryption_random_generator,
+        );
+
+        let mut rotated_lut = accumulator.clone();
+        blind_rotate_assign_mem_optimized(
+            &lwe_ciphertext_in,
+            &mut rotated_lut,
+            &fbsk,
+            fft,
+            buffers.stack(),
+        );
+
+        let mut extracted_ct = LweCiphertext::new(
+            Scalar::ZERO,
+            output_lwe_secret_key.lwe_dimension().to_lwe_size(),
+            ciphertext_modulus,
+        );
+        extract_lwe_sample_from_glwe_ciphertext(&rotated_lut, &mut extracted_ct, MonomialDegree(0));
+
+        let mut out_pbs_ct = LweCiphertext::new(
+            Scalar::ZERO,
+            output_lwe_secret_key.lwe_dimension().to_lwe_size(),
+            ciphertext_modulus,
+        );
+        programmable_bootstrap_lwe_ciphertext(
+            &lwe_ciphertext_in,
+            &mut out_pbs_ct,
+            &accumulator,
+            &fbsk,
+        );
+
+        assert_eq!(extracted_ct, out_pbs_ct);
+        assert!(check_content_respects_mod(
+            &extracted_ct,
+            ciphertext_modulus
+        ));
+
+        let decrypted = decrypt_lwe_ciphertext(&output_lwe_secret_key, &extracted_ct);
+        let decoded = round_decode(decrypted.0, delta) % msg_modulus;
+        assert_eq!(decoded, f(msg));
+    }
+}
+
+create_parametrized_test!(lwe_encrypt_blind_rotate_extract_decrypt_custom_mod);
 
 // DISCLAIMER: all parameters here are not guaranteed to be secure or yield correct computations
 pub const TEST_PARAMS_4_BITS_NATIVE_U128: TestParams<u128> = TestParams {
@@ -196,7 +330,7 @@ fn lwe_encrypt_pbs_f128_decrypt_custom_mod<
     let mut msg = msg_modulus;
     const NB_TESTS: usize = 10;
 
-    let accumulator = generate_accumulator(
+    let accumulator = generate_programmable_bootstrap_glwe_lut(
         polynomial_size,
         glwe_dimension.to_glwe_size(),
         msg_modulus.cast_into(),
@@ -301,3 +435,131 @@ fn lwe_encrypt_pbs_f128_decrypt_custom_mod_test_params_4_bits_native_u128() {
 fn lwe_encrypt_pbs_f128_decrypt_custom_mod_test_params_3_bits_127_u128() {
     lwe_encrypt_pbs_f128_decrypt_custom_mod(TEST_PARAMS_3_BITS_127_U128)
 }
+
+fn lwe_encrypt_pbs_ntt64_decrypt_custom_mod(params: TestParams<u64>) {
+    let input_lwe_dimension = params.lwe_dimension;
+    let lwe_modular_std_dev = params.lwe_modular_std_dev;
+    let glwe_modular_std_dev = params.glwe_modular_std_dev;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let msg_modulus = 1u64 << message_modulus_log.0;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let decomp_base_log = params.pbs_base_log;
+    let decomp_level_count = params.pbs_level;
+
+    let mut rsc = TestResources::new();
+
+    let f = |x: u64| x.wrapping_mul(2).wrapping_sub(1).wrapping_rem(msg_modulus);
+
+    let delta = encoding_with_padding / msg_modulus;
+    let mut msg = msg_modulus;
+    const NB_TESTS: usize = 10;
+
+    let accumulator = generate_programmable_bootstrap_glwe_lut(
+        polynomial_size,
+        glwe_dimension.to_glwe_size(),
+        msg_modulus as usize,
+        ciphertext_modulus,
+        delta,
+        f,
+    );
+
+    assert!(check_content_respects_mod(&accumulator, ciphertext_modulus));
+
+    while msg != 0 {
+        msg -= 1;
+        // Create the LweSecretKey
+        let input_lwe_secret_key = allocate_and_generate_new_binary_lwe_secret_key(
+            input_lwe_dimension,
+            &mut rsc.secret_random_generator,
+        );
+        let output_glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+            glwe_dimension,
+            polynomial_size,
+            &mut rsc.secret_random_generator,
+        );
+        let output_lwe_secret_key = output_glwe_secret_key.clone().into_lwe_secret_key();
+
+        let mut bsk = LweBootstrapKey::new(
+            0u64,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            decomp_base_log,
+            decomp_level_count,
+            input_lwe_dimension,
+            ciphertext_modulus,
+        );
+
+        par_generate_lwe_bootstrap_key(
+            &input_lwe_secret_key,
+            &output_glwe_secret_key,
+            &mut bsk,
+            glwe_modular_std_dev,
+            &mut rsc.encryption_random_generator,
+        );
+
+        assert!(check_content_respects_mod(&*bsk, ciphertext_modulus));
+
+        let mut ntt_bsk = NttLweBootstrapKey::new(
+            input_lwe_dimension,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            decomp_base_log,
+            decomp_level_count,
+        );
+
+        par_convert_standard_lwe_bootstrap_key_to_ntt64(&bsk, &mut ntt_bsk);
+
+        drop(bsk);
+
+        for _ in 0..NB_TESTS {
+            let plaintext = Plaintext(msg * delta);
+
+            let lwe_ciphertext_in = allocate_and_encrypt_new_lwe_ciphertext(
+                &input_lwe_secret_key,
+                plaintext,
+                lwe_modular_std_dev,
+                ciphertext_modulus,
+                &mut rsc.encryption_random_generator,
+            );
+
+            assert!(check_content_respects_mod(
+                &lwe_ciphertext_in,
+                ciphertext_modulus
+            ));
+
+            let mut out_pbs_ct = LweCiphertext::new(
+                0u64,
+                output_lwe_secret_key.lwe_dimension().to_lwe_size(),
+                ciphertext_modulus,
+            );
+
+            programmable_bootstrap_ntt64_lwe_ciphertext(
+                &lwe_ciphertext_in,
+                &mut out_pbs_ct,
+                &accumulator,
+                &ntt_bsk,
+            );
+
+            // The products being exact, the output respects the modulus without any rounding
+            assert!(check_content_respects_mod(&out_pbs_ct, ciphertext_modulus));
+
+            let decrypted = decrypt_lwe_ciphertext(&output_lwe_secret_key, &out_pbs_ct);
+
+            let decoded = round_decode(decrypted.0, delta) % msg_modulus;
+
+            assert_eq!(decoded, f(msg));
+        }
+    }
+}
+
+#[test]
+fn lwe_encrypt_pbs_ntt64_decrypt_custom_mod_test_params_4_bits_native_u64() {
+    lwe_encrypt_pbs_ntt64_decrypt_custom_mod(TEST_PARAMS_4_BITS_NATIVE_U64)
+}
+#[test]
+fn lwe_encrypt_pbs_ntt64_decrypt_custom_mod_test_params_3_bits_63_u64() {
+    lwe_encrypt_pbs_ntt64_decrypt_custom_mod(TEST_PARAMS_3_BITS_63_U64)
+}
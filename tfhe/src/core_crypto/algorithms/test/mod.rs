@@ -157,53 +157,6 @@ pub fn round_decode<Scalar: UnsignedInteger>(decrypted: Scalar, delta: Scalar) -
     (decrypted.wrapping_add(rounding_margin)).wrapping_div(delta)
 }
 
-// Here we will define a helper function to generate an accumulator for a PBS
-fn generate_accumulator<F, Scalar: UnsignedTorus + CastFrom<usize>>(
-    polynomial_size: PolynomialSize,
-    glwe_size: GlweSize,
-    message_modulus: usize,
-    ciphertext_modulus: CiphertextModulus<Scalar>,
-    delta: Scalar,
-    f: F,
-) -> GlweCiphertextOwned<Scalar>
-where
-    F: Fn(Scalar) -> Scalar,
-{
-    // N/(p/2) = size of each block, to correct noise from the input we introduce the
-    // notion of box, which manages redundancy to yield a denoised value
-    // for several noisy values around a true input value.
-    let box_size = polynomial_size.0 / message_modulus;
-
-    // Create the accumulator
-    let mut accumulator_scalar = vec![Scalar::ZERO; polynomial_size.0];
-
-    // Fill each box with the encoded denoised value
-    for i in 0..message_modulus {
-        let index = i * box_size;
-        accumulator_scalar[index..index + box_size]
-            .iter_mut()
-            .for_each(|a| *a = f(Scalar::cast_from(i)) * delta);
-    }
-
-    let half_box_size = box_size / 2;
-
-    // Negate the first half_box_size coefficients to manage negacyclicity and rotate
-    for a_i in accumulator_scalar[0..half_box_size].iter_mut() {
-        *a_i = (*a_i).wrapping_neg();
-    }
-
-    // Rotate the accumulator
-    accumulator_scalar.rotate_left(half_box_size);
-
-    let accumulator_plaintext = PlaintextList::from_container(accumulator_scalar);
-
-    allocate_and_trivially_encrypt_new_glwe_ciphertext(
-        glwe_size,
-        &accumulator_plaintext,
-        ciphertext_modulus,
-    )
-}
-
 // Macro to generate tests for all parameter sets
 macro_rules! create_parametrized_test{
     ($name:ident { $($param:ident),* }) => {
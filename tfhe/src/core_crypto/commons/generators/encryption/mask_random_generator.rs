@@ -321,6 +321,18 @@ impl<G: ParallelByteRandomGenerator> MaskRandomGenerator<G> {
         self.par_try_fork(lwe_size.0, mask_bytes)
     }
 
+    // Forks the generator, when splitting a packing keyswitch key into blocks
+    pub(crate) fn par_fork_pksk_to_pksk_blocks<T: UnsignedInteger>(
+        &mut self,
+        level: DecompositionLevelCount,
+        glwe_size: GlweSize,
+        poly_size: PolynomialSize,
+        lwe_dimension: LweDimension,
+    ) -> Result<impl IndexedParallelIterator<Item = MaskRandomGenerator<G>>, ForkError> {
+        let mask_bytes = mask_bytes_per_pksk_block::<T>(level, glwe_size, poly_size);
+        self.par_try_fork(lwe_dimension.0, mask_bytes)
+    }
+
     pub(crate) fn par_fork_lwe_compact_ciphertext_list_to_bin<T: UnsignedInteger>(
         &mut self,
         lwe_mask_count: LweMaskCount,
@@ -409,6 +421,14 @@ fn mask_bytes_per_pfpksk<T: UnsignedInteger>(
     lwe_size.0 * mask_bytes_per_pfpksk_chunk::<T>(level, glwe_size, poly_size)
 }
 
+fn mask_bytes_per_pksk_block<T: UnsignedInteger>(
+    level: DecompositionLevelCount,
+    glwe_size: GlweSize,
+    poly_size: PolynomialSize,
+) -> usize {
+    level.0 * mask_bytes_per_glwe::<T>(glwe_size.to_glwe_dimension(), poly_size)
+}
+
 fn mask_bytes_per_lwe_compact_ciphertext_bin<T: UnsignedInteger>(
     lwe_dimension: LweDimension,
 ) -> usize {
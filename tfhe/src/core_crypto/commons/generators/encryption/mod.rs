@@ -518,6 +518,26 @@ impl<G: ParallelByteRandomGenerator> EncryptionRandomGenerator<G> {
         self.par_map_to_encryption_generator(mask_iter, noise_iter)
     }
 
+    // Forks the generator, when splitting a packing keyswitch key into blocks
+    pub(crate) fn par_fork_pksk_to_pksk_blocks<T: UnsignedInteger>(
+        &mut self,
+        level: DecompositionLevelCount,
+        glwe_size: GlweSize,
+        poly_size: PolynomialSize,
+        lwe_dimension: LweDimension,
+    ) -> Result<impl IndexedParallelIterator<Item = EncryptionRandomGenerator<G>>, ForkError> {
+        let mask_iter = self.mask.par_fork_pksk_to_pksk_blocks::<T>(
+            level,
+            glwe_size,
+            poly_size,
+            lwe_dimension,
+        )?;
+        let noise_iter =
+            self.noise
+                .par_fork_pksk_to_pksk_blocks(level, poly_size, lwe_dimension)?;
+        self.par_map_to_encryption_generator(mask_iter, noise_iter)
+    }
+
     pub(crate) fn par_fork_lwe_compact_ciphertext_list_to_bin<T: UnsignedInteger>(
         &mut self,
         lwe_mask_count: LweMaskCount,
@@ -401,6 +401,17 @@ impl<G: ParallelByteRandomGenerator> NoiseRandomGenerator<G> {
         self.par_try_fork(lwe_size.0, noise_bytes)
     }
 
+    // Forks the generator, when splitting a packing keyswitch key into blocks
+    pub(crate) fn par_fork_pksk_to_pksk_blocks(
+        &mut self,
+        level: DecompositionLevelCount,
+        poly_size: PolynomialSize,
+        lwe_dimension: LweDimension,
+    ) -> Result<impl IndexedParallelIterator<Item = NoiseRandomGenerator<G>>, ForkError> {
+        let noise_bytes = noise_bytes_per_pksk_block(level, poly_size);
+        self.par_try_fork(lwe_dimension.0, noise_bytes)
+    }
+
     pub(crate) fn par_fork_lwe_compact_ciphertext_list_to_bin(
         &mut self,
         lwe_mask_count: LweMaskCount,
@@ -483,6 +494,10 @@ fn noise_bytes_per_pfpksk(
     lwe_size.0 * noise_bytes_per_pfpksk_chunk(level, poly_size)
 }
 
+fn noise_bytes_per_pksk_block(level: DecompositionLevelCount, poly_size: PolynomialSize) -> usize {
+    level.0 * noise_bytes_per_glwe(poly_size)
+}
+
 fn noise_bytes_per_lwe_compact_ciphertext_bin(lwe_dimension: LweDimension) -> usize {
     lwe_dimension.0 * noise_bytes_per_coef()
 }
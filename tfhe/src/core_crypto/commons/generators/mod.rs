@@ -11,3 +11,6 @@ pub use secret::SecretRandomGenerator;
 
 mod seeder;
 pub use seeder::DeterministicSeeder;
+
+mod randomness_service;
+pub use randomness_service::{ForkRecord, RandomnessService};
@@ -0,0 +1,151 @@
+//! Module containing a service deriving the randomness of several engines from a single seed.
+
+use crate::core_crypto::commons::generators::DeterministicSeeder;
+use crate::core_crypto::commons::math::random::{ActivatedRandomGenerator, RandomGenerator, Seed};
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+
+// Each fork only draws the 128 bits seed of its own seeder from its child generator
+const BYTES_PER_FORK: usize = 16;
+
+/// A fork handed out by a [`RandomnessService`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ForkRecord {
+    pub index: u64,
+    /// Free form description of the user of the fork, e.g. the thread or the engine
+    pub label: String,
+}
+
+/// Derives independent deterministic seeders from a single master [`Seed`].
+///
+/// The seeder of a fork only depends on the master seed and on the index of the fork, not on the
+/// order in which forks are requested. Engines created from forks on several threads are thus
+/// reproducible, whatever the scheduling of the threads.
+///
+/// Each index can be forked once, as two engines using the same randomness would reuse noise and
+/// masks. The forks handed out are recorded, see [`RandomnessService::transcript`], so that a run
+/// can be audited or replayed from the master seed and the transcript.
+///
+/// # Warning
+///
+/// Everything generated from the forks, secret keys included, can be recomputed from the master
+/// seed: it must be drawn from an entropy source and kept as secret as the keys themselves.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::core_crypto::commons::generators::RandomnessService;
+/// use tfhe::core_crypto::commons::math::random::Seed;
+/// use tfhe::shortint::engine::ShortintEngine;
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+/// use tfhe::shortint::ClientKey;
+///
+/// let service = RandomnessService::new(Seed(42));
+///
+/// let keys = std::thread::scope(|s| {
+///     let handles = (0..2u64)
+///         .map(|index| {
+///             let service = &service;
+///             s.spawn(move || {
+///                 let mut seeder = service.fork(index, &format!("thread {index}"));
+///                 let engine = ShortintEngine::new_from_seeder(&mut seeder);
+///                 ShortintEngine::replace_thread_local(engine);
+///                 // Keys and ciphertexts of this thread now come from its fork
+///                 ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS)
+///             })
+///         })
+///         .collect::<Vec<_>>();
+///     handles
+///         .into_iter()
+///         .map(|handle| handle.join().unwrap())
+///         .collect::<Vec<_>>()
+/// });
+///
+/// // The same fork of another service with the same master seed gives the same key
+/// let replay = RandomnessService::new(Seed(42));
+/// let mut seeder = replay.fork(1, "replay of thread 1");
+/// ShortintEngine::replace_thread_local(ShortintEngine::new_from_seeder(&mut seeder));
+/// let key = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+/// assert_eq!(key, keys[1]);
+/// assert_ne!(keys[0], keys[1]);
+///
+/// let mut transcript = service.transcript();
+/// transcript.sort_by_key(|record| record.index);
+/// assert_eq!(transcript[0].label, "thread 0");
+/// ```
+pub struct RandomnessService {
+    master_seed: Seed,
+    transcript: Mutex<Vec<ForkRecord>>,
+}
+
+impl RandomnessService {
+    pub fn new(master_seed: Seed) -> Self {
+        Self {
+            master_seed,
+            transcript: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Returns the seeder of the fork at `index`, to be given to an engine constructor such as
+    /// `ShortintEngine::new_from_seeder`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the fork at `index` was already handed out by this service.
+    pub fn fork(&self, index: u64, label: &str) -> DeterministicSeeder<ActivatedRandomGenerator> {
+        {
+            let mut transcript = self.transcript.lock().unwrap();
+            assert!(
+                transcript.iter().all(|record| record.index != index),
+                "The fork {index} was already handed out"
+            );
+            transcript.push(ForkRecord {
+                index,
+                label: label.to_owned(),
+            });
+        }
+
+        DeterministicSeeder::new(self.fork_seed(index))
+    }
+
+    /// Returns the seeder of the first fork index not handed out yet, and this index.
+    ///
+    /// When called concurrently, which caller gets which index depends on the scheduling, the
+    /// transcript records the index given to each label.
+    pub fn fork_next(&self, label: &str) -> (u64, DeterministicSeeder<ActivatedRandomGenerator>) {
+        let index = {
+            let mut transcript = self.transcript.lock().unwrap();
+            let index = transcript
+                .iter()
+                .map(|record| record.index + 1)
+                .max()
+                .unwrap_or(0);
+            transcript.push(ForkRecord {
+                index,
+                label: label.to_owned(),
+            });
+            index
+        };
+
+        (index, DeterministicSeeder::new(self.fork_seed(index)))
+    }
+
+    /// Returns the forks handed out so far, in the order they were requested
+    pub fn transcript(&self) -> Vec<ForkRecord> {
+        self.transcript.lock().unwrap().clone()
+    }
+
+    fn fork_seed(&self, index: u64) -> Seed {
+        let index = usize::try_from(index).expect("The fork index does not fit in a usize");
+
+        // Children of a fork are disjoint slices of the master generator stream, the child at
+        // `index` does not depend on the other ones
+        let mut child = RandomGenerator::<ActivatedRandomGenerator>::new(self.master_seed)
+            .try_fork(index + 1, BYTES_PER_FORK)
+            .expect("Failed to fork the master generator")
+            .nth(index)
+            .unwrap();
+
+        Seed(child.random_uniform::<u128>())
+    }
+}
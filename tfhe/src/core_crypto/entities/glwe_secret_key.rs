@@ -36,6 +36,14 @@ impl<T, C: ContainerMut<Element = T>> AsMut<[T]> for GlweSecretKey<C> {
     }
 }
 
+impl<T: zeroize::DefaultIsZeroes, C: ContainerMut<Element = T>> zeroize::Zeroize
+    for GlweSecretKey<C>
+{
+    fn zeroize(&mut self) {
+        zeroize::Zeroize::zeroize(self.data.as_mut());
+    }
+}
+
 impl<Scalar, C: Container<Element = Scalar>> GlweSecretKey<C> {
     /// Create a [`GlweSecretKey`] from an existing container.
     ///
@@ -1,6 +1,7 @@
 //! Module containing the definition of the [`LweCiphertext`].
 
 use crate::conformance::ParameterSetConformant;
+use crate::content_hash::{ContentHash, ContentHasher};
 use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::prelude::misc::check_content_respects_mod;
@@ -712,6 +713,33 @@ where
     }
 }
 
+impl<C: Container> ContentHash for LweCiphertext<C>
+where
+    C::Element: UnsignedInteger,
+{
+    fn hash_content(&self, hasher: &mut ContentHasher) {
+        let ciphertext_modulus = self.ciphertext_modulus();
+        if ciphertext_modulus.is_native_modulus() {
+            hasher.write_u128(0);
+        } else {
+            hasher.write_u128(ciphertext_modulus.get_custom_modulus());
+        }
+
+        let data = self.as_ref();
+        hasher.write_usize(data.len());
+        if C::Element::BITS <= 64 {
+            for &element in data {
+                let element: u128 = element.cast_into();
+                hasher.write_u64(element as u64);
+            }
+        } else {
+            for &element in data {
+                hasher.write_u128(element.cast_into());
+            }
+        }
+    }
+}
+
 impl<Scalar: UnsignedInteger> LweCiphertextOwned<Scalar> {
     /// Allocate memory and create a new owned [`LweCiphertext`].
     ///
@@ -2,6 +2,7 @@
 //! encryption of a list of LWE ciphertexts.
 
 use crate::conformance::{ListSizeConstraint, ParameterSetConformant};
+use crate::core_crypto::algorithms::polynomial_algorithms;
 use crate::core_crypto::algorithms::{
     expand_lwe_compact_ciphertext_list, par_expand_lwe_compact_ciphertext_list,
 };
@@ -242,6 +243,89 @@ impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> LweCompactCipherte
         lwe_ciphertext_list
     }
 
+    /// Split the [`LweCompactCiphertextList`] in two at `index` without expanding it, the first
+    /// list contains the ciphertexts before `index`, the second one the others.
+    ///
+    /// The ciphertexts are stored in bins of [`LweDimension`] ciphertexts sharing a mask, so the
+    /// ciphertexts of a bin of the second list must come from a single bin of this list. Returns
+    /// `None` if `index` is not a multiple of the [`LweDimension`] and the ciphertexts after it
+    /// span several bins, or if `index` is greater than the ciphertext count.
+    ///
+    /// ```
+    /// use tfhe::core_crypto::prelude::*;
+    ///
+    /// let lwe_size = LweSize(1025);
+    /// let ciphertext_modulus = CiphertextModulus::new_native();
+    ///
+    /// let lwe_ciphertext_count = LweCiphertextCount(5);
+    /// let lwe_compact_list =
+    ///     LweCompactCiphertextList::new(0u64, lwe_size, lwe_ciphertext_count, ciphertext_modulus);
+    ///
+    /// let (first, second) = lwe_compact_list.split_at(2).unwrap();
+    /// assert_eq!(first.lwe_ciphertext_count(), LweCiphertextCount(2));
+    /// assert_eq!(second.lwe_ciphertext_count(), LweCiphertextCount(3));
+    ///
+    /// let merged = LweCompactCiphertextListOwned::concat(&[first, second]);
+    /// // The first list does not fill its bin, the second one cannot be appended to it
+    /// assert!(merged.is_none());
+    /// ```
+    pub fn split_at(
+        &self,
+        index: usize,
+    ) -> Option<(
+        LweCompactCiphertextListOwned<Scalar>,
+        LweCompactCiphertextListOwned<Scalar>,
+    )> {
+        let lwe_ciphertext_count = self.lwe_ciphertext_count().0;
+        let lwe_dimension = self.lwe_size().to_lwe_dimension();
+        let bin_size = lwe_dimension.0;
+        let offset_in_bin = index % bin_size;
+        let second_count = lwe_ciphertext_count.checked_sub(index)?;
+        if offset_in_bin != 0 && second_count > bin_size - offset_in_bin {
+            return None;
+        }
+
+        let (mask_list, body_list) = self.get_mask_and_body_list();
+        let (masks, bodies) = (mask_list.as_ref(), body_list.as_ref());
+
+        let first_mask_count =
+            lwe_compact_ciphertext_list_mask_count(lwe_dimension, LweCiphertextCount(index));
+        let mut first_data = masks[..first_mask_count.0 * bin_size].to_vec();
+        first_data.extend_from_slice(&bodies[..index]);
+
+        let second_mask_count =
+            lwe_compact_ciphertext_list_mask_count(lwe_dimension, LweCiphertextCount(second_count));
+        let second_masks_start = index / bin_size * bin_size;
+        let mut second_data =
+            masks[second_masks_start..second_masks_start + second_mask_count.0 * bin_size].to_vec();
+        if offset_in_bin != 0 && second_mask_count.0 != 0 {
+            // The mask of the ciphertext at position i in a bin is the mask of the bin multiplied
+            // by X^(N - i - 1), it is divided by X^offset so that the first ciphertext of the
+            // second list is at position 0
+            let mut mask = Polynomial::from_container(&mut second_data[..bin_size]);
+            polynomial_algorithms::polynomial_wrapping_monic_monomial_div_assign(
+                &mut mask,
+                MonomialDegree(offset_in_bin),
+            );
+        }
+        second_data.extend_from_slice(&bodies[index..]);
+
+        Some((
+            LweCompactCiphertextListOwned::from_container(
+                first_data,
+                self.lwe_size(),
+                LweCiphertextCount(index),
+                self.ciphertext_modulus(),
+            ),
+            LweCompactCiphertextListOwned::from_container(
+                second_data,
+                self.lwe_size(),
+                LweCiphertextCount(second_count),
+                self.ciphertext_modulus(),
+            ),
+        ))
+    }
+
     pub fn size_elements(&self) -> usize {
         self.data.container_len()
     }
@@ -381,4 +465,50 @@ impl<Scalar: UnsignedInteger> LweCompactCiphertextListOwned<Scalar> {
             ciphertext_modulus,
         )
     }
+
+    /// Concatenate [`LweCompactCiphertextList`]s without expanding them.
+    ///
+    /// As the ciphertexts of a bin share a mask, the ciphertexts of a list cannot be appended to
+    /// a bin of another list: every list but the last must have a ciphertext count that is a
+    /// multiple of the [`LweDimension`]. Returns `None` if it is not the case, if the lists do not
+    /// have the same [`LweSize`] and [`CiphertextModulus`], or if `lists` is empty.
+    ///
+    /// See [`LweCompactCiphertextList::split_at`] for usage.
+    pub fn concat<C: Container<Element = Scalar>>(
+        lists: &[LweCompactCiphertextList<C>],
+    ) -> Option<Self> {
+        let (first, others) = lists.split_first()?;
+        let lwe_size = first.lwe_size();
+        let ciphertext_modulus = first.ciphertext_modulus();
+        let bin_size = lwe_size.to_lwe_dimension().0;
+
+        let lists_are_compatible = others.iter().all(|list| {
+            list.lwe_size() == lwe_size && list.ciphertext_modulus() == ciphertext_modulus
+        });
+        let bins_are_full = lists[..lists.len() - 1]
+            .iter()
+            .all(|list| list.lwe_ciphertext_count().0 % bin_size == 0);
+        if !lists_are_compatible || !bins_are_full {
+            return None;
+        }
+
+        let lwe_ciphertext_count = lists.iter().map(|list| list.lwe_ciphertext_count().0).sum();
+        let mut data = Vec::with_capacity(lwe_compact_ciphertext_list_size(
+            lwe_size.to_lwe_dimension(),
+            LweCiphertextCount(lwe_ciphertext_count),
+        ));
+        for list in lists {
+            data.extend_from_slice(list.get_mask_list().as_ref());
+        }
+        for list in lists {
+            data.extend_from_slice(list.get_body_list().as_ref());
+        }
+
+        Some(Self::from_container(
+            data,
+            lwe_size,
+            LweCiphertextCount(lwe_ciphertext_count),
+            ciphertext_modulus,
+        ))
+    }
 }
@@ -33,6 +33,14 @@ impl<T, C: ContainerMut<Element = T>> AsMut<[T]> for LweSecretKey<C> {
     }
 }
 
+impl<T: zeroize::DefaultIsZeroes, C: ContainerMut<Element = T>> zeroize::Zeroize
+    for LweSecretKey<C>
+{
+    fn zeroize(&mut self) {
+        zeroize::Zeroize::zeroize(self.data.as_mut());
+    }
+}
+
 impl<Scalar, C: Container<Element = Scalar>> LweSecretKey<C> {
     /// Create an [`LweSecretKey`] from an existing container.
     ///
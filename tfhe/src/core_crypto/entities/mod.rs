@@ -33,6 +33,7 @@ pub mod seeded_glwe_ciphertext_list;
 pub mod seeded_lwe_bootstrap_key;
 pub mod seeded_lwe_ciphertext;
 pub mod seeded_lwe_ciphertext_list;
+pub mod seeded_lwe_compact_ciphertext_list;
 pub mod seeded_lwe_compact_public_key;
 pub mod seeded_lwe_keyswitch_key;
 pub mod seeded_lwe_multi_bit_bootstrap_key;
@@ -52,6 +53,12 @@ pub use crate::core_crypto::fft_impl::fft64::crypto::ggsw::{
     FourierGgswCiphertext, FourierGgswCiphertextList, FourierGgswLevelMatrix, FourierGgswLevelRow,
 };
 pub use crate::core_crypto::fft_impl::fft64::math::polynomial::FourierPolynomial;
+pub use crate::core_crypto::fft_impl::ntt64::crypto::bootstrap::{
+    NttLweBootstrapKey, NttLweBootstrapKeyOwned,
+};
+pub use crate::core_crypto::fft_impl::ntt64::crypto::ggsw::{
+    NttGgswCiphertext, NttGgswLevelMatrix, NttGgswLevelRow,
+};
 pub use cleartext::*;
 pub use ggsw_ciphertext::*;
 pub use ggsw_ciphertext_list::*;
@@ -82,6 +89,7 @@ pub use seeded_glwe_ciphertext_list::*;
 pub use seeded_lwe_bootstrap_key::*;
 pub use seeded_lwe_ciphertext::*;
 pub use seeded_lwe_ciphertext_list::*;
+pub use seeded_lwe_compact_ciphertext_list::*;
 pub use seeded_lwe_compact_public_key::*;
 pub use seeded_lwe_keyswitch_key::*;
 pub use seeded_lwe_multi_bit_bootstrap_key::*;
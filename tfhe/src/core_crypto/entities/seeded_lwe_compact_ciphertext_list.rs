@@ -0,0 +1,275 @@
+//! Module containing the definition of the [`SeededLweCompactCiphertextList`].
+
+use crate::conformance::ParameterSetConformant;
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::commons::math::random::{ActivatedRandomGenerator, CompressionSeed};
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+use crate::core_crypto::prelude::misc::check_content_respects_mod;
+
+/// A seeded [`compact list of LWE ciphertexts`](`LweCompactCiphertextList`), only the bodies are
+/// stored, the mask of each bin being generated from the [`CompressionSeed`] when the list is
+/// decompressed.
+///
+/// As the masks are derived from a seed, this list can only be obtained through encryption with
+/// an [`LWE secret key`](`LweSecretKey`).
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SeededLweCompactCiphertextList<C: Container>
+where
+    C::Element: UnsignedInteger,
+{
+    data: C,
+    lwe_size: LweSize,
+    compression_seed: CompressionSeed,
+    ciphertext_modulus: CiphertextModulus<C::Element>,
+}
+
+impl<T: UnsignedInteger, C: Container<Element = T>> AsRef<[T]>
+    for SeededLweCompactCiphertextList<C>
+{
+    fn as_ref(&self) -> &[T] {
+        self.data.as_ref()
+    }
+}
+
+impl<T: UnsignedInteger, C: ContainerMut<Element = T>> AsMut<[T]>
+    for SeededLweCompactCiphertextList<C>
+{
+    fn as_mut(&mut self) -> &mut [T] {
+        self.data.as_mut()
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> SeededLweCompactCiphertextList<C> {
+    /// Create a [`SeededLweCompactCiphertextList`] from an existing container of bodies.
+    ///
+    /// # Note
+    ///
+    /// This function only wraps a container in the appropriate type. If you want to encrypt data
+    /// you need to use
+    /// [`crate::core_crypto::algorithms::encrypt_seeded_lwe_compact_ciphertext_list`] using this
+    /// list as output.
+    ///
+    /// This docstring exhibits [`SeededLweCompactCiphertextList`] primitives usage.
+    ///
+    /// ```
+    /// use tfhe::core_crypto::prelude::*;
+    ///
+    /// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+    /// // computations
+    /// // Define parameters for SeededLweCompactCiphertextList creation
+    /// let lwe_size = LweSize(1025);
+    /// let lwe_ciphertext_count = LweCiphertextCount(5);
+    /// let ciphertext_modulus = CiphertextModulus::new_native();
+    ///
+    /// // Get a seeder
+    /// let mut seeder = new_seeder();
+    /// let seeder = seeder.as_mut();
+    ///
+    /// // Create a new SeededLweCompactCiphertextList
+    /// let seeded_compact_list = SeededLweCompactCiphertextList::new(
+    ///     0u64,
+    ///     lwe_size,
+    ///     lwe_ciphertext_count,
+    ///     seeder.seed().into(),
+    ///     ciphertext_modulus,
+    /// );
+    ///
+    /// assert_eq!(seeded_compact_list.lwe_size(), lwe_size);
+    /// assert_eq!(
+    ///     seeded_compact_list.lwe_ciphertext_count(),
+    ///     lwe_ciphertext_count
+    /// );
+    /// assert_eq!(seeded_compact_list.ciphertext_modulus(), ciphertext_modulus);
+    ///
+    /// let compression_seed = seeded_compact_list.compression_seed();
+    ///
+    /// // Demonstrate how to recover the allocated container
+    /// let underlying_container: Vec<u64> = seeded_compact_list.into_container();
+    ///
+    /// // Recreate a list using from_container
+    /// let seeded_compact_list = SeededLweCompactCiphertextList::from_container(
+    ///     underlying_container,
+    ///     lwe_size,
+    ///     compression_seed,
+    ///     ciphertext_modulus,
+    /// );
+    ///
+    /// assert_eq!(seeded_compact_list.lwe_size(), lwe_size);
+    /// assert_eq!(
+    ///     seeded_compact_list.lwe_ciphertext_count(),
+    ///     lwe_ciphertext_count
+    /// );
+    /// assert_eq!(seeded_compact_list.ciphertext_modulus(), ciphertext_modulus);
+    ///
+    /// // Decompress the list
+    /// let compact_list = seeded_compact_list.decompress_into_lwe_compact_ciphertext_list();
+    ///
+    /// assert_eq!(compact_list.lwe_size(), lwe_size);
+    /// assert_eq!(compact_list.lwe_ciphertext_count(), lwe_ciphertext_count);
+    /// assert_eq!(compact_list.ciphertext_modulus(), ciphertext_modulus);
+    /// ```
+    pub fn from_container(
+        container: C,
+        lwe_size: LweSize,
+        compression_seed: CompressionSeed,
+        ciphertext_modulus: CiphertextModulus<Scalar>,
+    ) -> Self {
+        Self {
+            data: container,
+            lwe_size,
+            compression_seed,
+            ciphertext_modulus,
+        }
+    }
+
+    /// Return the [`LweSize`] of the compressed [`LweCompactCiphertextList`].
+    ///
+    /// See [`SeededLweCompactCiphertextList::from_container`] for usage.
+    pub fn lwe_size(&self) -> LweSize {
+        self.lwe_size
+    }
+
+    /// Return the [`CompressionSeed`] of the [`SeededLweCompactCiphertextList`].
+    ///
+    /// See [`SeededLweCompactCiphertextList::from_container`] for usage.
+    pub fn compression_seed(&self) -> CompressionSeed {
+        self.compression_seed
+    }
+
+    /// Return the [`LweCiphertextCount`] of the [`SeededLweCompactCiphertextList`].
+    ///
+    /// See [`SeededLweCompactCiphertextList::from_container`] for usage.
+    pub fn lwe_ciphertext_count(&self) -> LweCiphertextCount {
+        LweCiphertextCount(self.data.container_len())
+    }
+
+    /// Return the [`LweMaskCount`] of the decompressed [`LweCompactCiphertextList`].
+    pub fn lwe_mask_count(&self) -> LweMaskCount {
+        lwe_compact_ciphertext_list_mask_count(
+            self.lwe_size().to_lwe_dimension(),
+            self.lwe_ciphertext_count(),
+        )
+    }
+
+    /// Return the [`CiphertextModulus`] of the [`SeededLweCompactCiphertextList`].
+    ///
+    /// See [`SeededLweCompactCiphertextList::from_container`] for usage.
+    pub fn ciphertext_modulus(&self) -> CiphertextModulus<Scalar> {
+        self.ciphertext_modulus
+    }
+
+    /// Consume the entity and return its underlying container.
+    ///
+    /// See [`SeededLweCompactCiphertextList::from_container`] for usage.
+    pub fn into_container(self) -> C {
+        self.data
+    }
+
+    /// Consume the [`SeededLweCompactCiphertextList`] and decompress it into a standard
+    /// [`LweCompactCiphertextList`].
+    ///
+    /// See [`SeededLweCompactCiphertextList::from_container`] for usage.
+    pub fn decompress_into_lwe_compact_ciphertext_list(
+        self,
+    ) -> LweCompactCiphertextListOwned<Scalar>
+    where
+        Scalar: UnsignedTorus,
+    {
+        let mut decompressed_list = LweCompactCiphertextList::new(
+            Scalar::ZERO,
+            self.lwe_size(),
+            self.lwe_ciphertext_count(),
+            self.ciphertext_modulus(),
+        );
+        decompress_seeded_lwe_compact_ciphertext_list::<_, _, _, ActivatedRandomGenerator>(
+            &mut decompressed_list,
+            &self,
+        );
+        decompressed_list
+    }
+
+    /// Return a view of the [`SeededLweCompactCiphertextList`]. This is useful if an algorithm
+    /// takes a view by value.
+    pub fn as_view(&self) -> SeededLweCompactCiphertextList<&'_ [Scalar]> {
+        SeededLweCompactCiphertextList::from_container(
+            self.as_ref(),
+            self.lwe_size(),
+            self.compression_seed(),
+            self.ciphertext_modulus(),
+        )
+    }
+
+    pub fn size_elements(&self) -> usize {
+        self.data.container_len()
+    }
+
+    pub fn size_bytes(&self) -> usize {
+        std::mem::size_of_val(self.data.as_ref())
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> SeededLweCompactCiphertextList<C> {
+    /// Mutable variant of [`SeededLweCompactCiphertextList::as_view`].
+    pub fn as_mut_view(&mut self) -> SeededLweCompactCiphertextList<&'_ mut [Scalar]> {
+        let lwe_size = self.lwe_size();
+        let compression_seed = self.compression_seed();
+        let ciphertext_modulus = self.ciphertext_modulus();
+        SeededLweCompactCiphertextList::from_container(
+            self.as_mut(),
+            lwe_size,
+            compression_seed,
+            ciphertext_modulus,
+        )
+    }
+}
+
+/// A [`SeededLweCompactCiphertextList`] owning the memory for its own storage.
+pub type SeededLweCompactCiphertextListOwned<Scalar> = SeededLweCompactCiphertextList<Vec<Scalar>>;
+/// A [`SeededLweCompactCiphertextList`] immutably borrowing memory for its own storage.
+pub type SeededLweCompactCiphertextListView<'data, Scalar> =
+    SeededLweCompactCiphertextList<&'data [Scalar]>;
+/// A [`SeededLweCompactCiphertextList`] mutably borrowing memory for its own storage.
+pub type SeededLweCompactCiphertextListMutView<'data, Scalar> =
+    SeededLweCompactCiphertextList<&'data mut [Scalar]>;
+
+impl<Scalar: UnsignedInteger> SeededLweCompactCiphertextListOwned<Scalar> {
+    /// Allocate memory and create a new owned [`SeededLweCompactCiphertextList`].
+    ///
+    /// # Note
+    ///
+    /// This function allocates a vector of the appropriate size and wraps it in the appropriate
+    /// type. If you want to encrypt data you need to use
+    /// [`crate::core_crypto::algorithms::encrypt_seeded_lwe_compact_ciphertext_list`] using this
+    /// list as output.
+    ///
+    /// See [`SeededLweCompactCiphertextList::from_container`] for usage.
+    pub fn new(
+        fill_with: Scalar,
+        lwe_size: LweSize,
+        lwe_ciphertext_count: LweCiphertextCount,
+        compression_seed: CompressionSeed,
+        ciphertext_modulus: CiphertextModulus<Scalar>,
+    ) -> Self {
+        Self::from_container(
+            vec![fill_with; lwe_ciphertext_count.0],
+            lwe_size,
+            compression_seed,
+            ciphertext_modulus,
+        )
+    }
+}
+
+impl<T: UnsignedInteger> ParameterSetConformant for SeededLweCompactCiphertextListOwned<T> {
+    type ParameterSet = LweCiphertextListParameters<T>;
+
+    fn is_conformant(&self, param: &LweCiphertextListParameters<T>) -> bool {
+        param
+            .lwe_ciphertext_count_constraint
+            .is_valid(self.lwe_ciphertext_count().0)
+            && check_content_respects_mod(self, param.ct_modulus)
+            && self.lwe_size == param.lwe_dim.to_lwe_size()
+            && self.ciphertext_modulus == param.ct_modulus
+    }
+}
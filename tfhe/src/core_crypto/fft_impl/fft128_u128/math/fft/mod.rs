@@ -1,5 +1,6 @@
 use crate::core_crypto::commons::utils::izip;
 pub use crate::core_crypto::fft_impl::fft128::math::fft::{Fft128, Fft128View};
+use crate::core_crypto::fft_impl::fft64::math::fft::{fft_backend, FftBackend};
 use concrete_fft::fft128::f128;
 use dyn_stack::PodStack;
 
@@ -960,19 +961,50 @@ pub fn convert_forward_integer(
     in_re_hi: &[u64],
     in_im_lo: &[u64],
     in_im_hi: &[u64],
+) {
+    convert_forward_integer_with_backend(
+        fft_backend(),
+        out_re0,
+        out_re1,
+        out_im0,
+        out_im1,
+        in_re_lo,
+        in_re_hi,
+        in_im_lo,
+        in_im_hi,
+    )
+}
+
+/// Converts with the implementation of `backend`, falling back to the portable one if the CPU
+/// does not support it
+#[allow(unused_variables)]
+fn convert_forward_integer_with_backend(
+    backend: FftBackend,
+    out_re0: &mut [f64],
+    out_re1: &mut [f64],
+    out_im0: &mut [f64],
+    out_im1: &mut [f64],
+    in_re_lo: &[u64],
+    in_re_hi: &[u64],
+    in_im_lo: &[u64],
+    in_im_hi: &[u64],
 ) {
     #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
     #[cfg(feature = "nightly-avx512")]
-    if let Some(simd) = V4::try_new() {
-        return convert_forward_integer_avx512(
-            simd, out_re0, out_re1, out_im0, out_im1, in_re_lo, in_re_hi, in_im_lo, in_im_hi,
-        );
+    if backend == FftBackend::X86Avx512 {
+        if let Some(simd) = V4::try_new() {
+            return convert_forward_integer_avx512(
+                simd, out_re0, out_re1, out_im0, out_im1, in_re_lo, in_re_hi, in_im_lo, in_im_hi,
+            );
+        }
     }
     #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-    if let Some(simd) = V3::try_new() {
-        return convert_forward_integer_avx2(
-            simd, out_re0, out_re1, out_im0, out_im1, in_re_lo, in_re_hi, in_im_lo, in_im_hi,
-        );
+    if backend != FftBackend::Portable {
+        if let Some(simd) = V3::try_new() {
+            return convert_forward_integer_avx2(
+                simd, out_re0, out_re1, out_im0, out_im1, in_re_lo, in_re_hi, in_im_lo, in_im_hi,
+            );
+        }
     }
     convert_forward_integer_scalar(
         out_re0, out_re1, out_im0, out_im1, in_re_lo, in_re_hi, in_im_lo, in_im_hi,
@@ -1200,19 +1232,50 @@ pub fn convert_add_backward_torus(
     in_re1: &[f64],
     in_im0: &[f64],
     in_im1: &[f64],
+) {
+    convert_add_backward_torus_with_backend(
+        fft_backend(),
+        out_re_lo,
+        out_re_hi,
+        out_im_lo,
+        out_im_hi,
+        in_re0,
+        in_re1,
+        in_im0,
+        in_im1,
+    )
+}
+
+/// Converts with the implementation of `backend`, falling back to the portable one if the CPU
+/// does not support it
+#[allow(unused_variables)]
+fn convert_add_backward_torus_with_backend(
+    backend: FftBackend,
+    out_re_lo: &mut [u64],
+    out_re_hi: &mut [u64],
+    out_im_lo: &mut [u64],
+    out_im_hi: &mut [u64],
+    in_re0: &[f64],
+    in_re1: &[f64],
+    in_im0: &[f64],
+    in_im1: &[f64],
 ) {
     #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
     #[cfg(feature = "nightly-avx512")]
-    if let Some(simd) = V4::try_new() {
-        return convert_add_backward_torus_avx512(
-            simd, out_re_lo, out_re_hi, out_im_lo, out_im_hi, in_re0, in_re1, in_im0, in_im1,
-        );
+    if backend == FftBackend::X86Avx512 {
+        if let Some(simd) = V4::try_new() {
+            return convert_add_backward_torus_avx512(
+                simd, out_re_lo, out_re_hi, out_im_lo, out_im_hi, in_re0, in_re1, in_im0, in_im1,
+            );
+        }
     }
     #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-    if let Some(simd) = V3::try_new() {
-        return convert_add_backward_torus_avx2(
-            simd, out_re_lo, out_re_hi, out_im_lo, out_im_hi, in_re0, in_re1, in_im0, in_im1,
-        );
+    if backend != FftBackend::Portable {
+        if let Some(simd) = V3::try_new() {
+            return convert_add_backward_torus_avx2(
+                simd, out_re_lo, out_re_hi, out_im_lo, out_im_hi, in_re0, in_re1, in_im0, in_im1,
+            );
+        }
     }
     convert_add_backward_torus_scalar(
         out_re_lo, out_re_hi, out_im_lo, out_im_hi, in_re0, in_re1, in_im0, in_im1,
@@ -1347,6 +1410,7 @@ impl<'a> Fft128View<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core_crypto::commons::test_tools::{modular_distance, new_random_generator};
 
     // copied from the standard library
     fn next_up(this: f64) -> f64 {
@@ -1382,4 +1446,78 @@ mod tests {
 
         assert!(b.1.abs() <= 0.5 * ulp(b.0))
     }
+
+    #[test]
+    fn test_backends_match_portable() {
+        let mut generator = new_random_generator();
+        let n = 512;
+
+        let mut random_u64 =
+            || -> Vec<u64> { (0..n).map(|_| generator.random_uniform()).collect() };
+        let (in_re_lo, in_re_hi, in_im_lo, in_im_hi) =
+            (random_u64(), random_u64(), random_u64(), random_u64());
+
+        let forward = |backend| {
+            let mut out = [vec![0.0; n], vec![0.0; n], vec![0.0; n], vec![0.0; n]];
+            let [out_re0, out_re1, out_im0, out_im1] = &mut out;
+            convert_forward_integer_with_backend(
+                backend, out_re0, out_re1, out_im0, out_im1, &in_re_lo, &in_re_hi, &in_im_lo,
+                &in_im_hi,
+            );
+            out
+        };
+        let backward = |backend, fourier: &[Vec<f64>; 4]| {
+            let mut out = [vec![0; n], vec![0; n], vec![0; n], vec![0; n]];
+            let [out_re_lo, out_re_hi, out_im_lo, out_im_hi] = &mut out;
+            convert_add_backward_torus_with_backend(
+                backend,
+                out_re_lo,
+                out_re_hi,
+                out_im_lo,
+                out_im_hi,
+                &fourier[0],
+                &fourier[1],
+                &fourier[2],
+                &fourier[3],
+            );
+            out
+        };
+
+        let expected_fourier = forward(FftBackend::Portable);
+        let expected_standard = backward(FftBackend::Portable, &expected_fourier);
+
+        for backend in FftBackend::all() {
+            if !backend.is_supported() {
+                continue;
+            }
+
+            let fourier = forward(backend);
+            for (expected, actual) in [(0, 1), (2, 3)].into_iter().flat_map(|(hi, lo)| {
+                izip!(
+                    &expected_fourier[hi],
+                    &expected_fourier[lo],
+                    &fourier[hi],
+                    &fourier[lo]
+                )
+                .map(|(e0, e1, a0, a1)| (f128(*e0, *e1), f128(*a0, *a1)))
+            }) {
+                let diff = f128::sub_estimate_f128_f128(expected, actual);
+                assert!((diff.0 + diff.1).abs() <= 2.0f64.powi(-100) * expected.0.abs().max(1.0));
+            }
+
+            let standard = backward(backend, &expected_fourier);
+            for (lo, hi) in [(0, 1), (2, 3)] {
+                for (e_lo, e_hi, a_lo, a_hi) in izip!(
+                    &expected_standard[lo],
+                    &expected_standard[hi],
+                    &standard[lo],
+                    &standard[hi]
+                ) {
+                    let expected = (*e_lo as u128) | ((*e_hi as u128) << 64);
+                    let actual = (*a_lo as u128) | ((*a_hi as u128) << 64);
+                    assert!(modular_distance(expected, actual) < (1 << 40));
+                }
+            }
+        }
+    }
 }
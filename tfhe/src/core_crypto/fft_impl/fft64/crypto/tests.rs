@@ -17,3 +17,11 @@ fn test_bootstrap_u32() {
         StandardDev(0.00000000000000029403601535432533),
     );
 }
+
+#[test]
+fn test_bootstrap_u128() {
+    test_bootstrap_generic::<u128, FourierLweBootstrapKeyOwned>(
+        StandardDev(0.000007069849454709433),
+        StandardDev(0.00000000000000029403601535432533),
+    );
+}
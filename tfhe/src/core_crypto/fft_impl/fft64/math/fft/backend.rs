@@ -0,0 +1,220 @@
+//! Runtime selection of the conversions between the standard and the Fourier domains.
+//!
+//! The conversions done around the FFT have a portable implementation, and SIMD implementations
+//! for x86 (AVX2, and AVX512 with the `nightly-avx512` feature). The best one supported by the CPU
+//! is selected the first time an FFT is computed, so a single binary runs on all the CPUs of its
+//! target architecture. A backend can also be chosen explicitly at startup with
+//! [set_fft_backend], e.g. to run the same conversion code on all the machines of a
+//! heterogeneous fleet. The selection applies to the conversions of the 32 and 64 bits FFT and of
+//! the SIMD implementation of the 128 bits one.
+//!
+//! The FFT itself is computed by `concrete-fft`, which keeps dispatching at runtime on the
+//! instruction sets of the CPU whatever the selected backend. The results are therefore not bit
+//! identical across machines: they differ by floating point rounding errors, far below the noise
+//! of the ciphertexts, so decryptions are the same.
+//!
+//! There is no dedicated aarch64 backend yet, on aarch64 and the other architectures the portable
+//! implementation is used and vectorized by the compiler, NEON being part of the aarch64 baseline.
+//! [FftBackend] is non exhaustive so that such a backend can be added later.
+
+use super::TwistiesView;
+use concrete_fft::c64;
+use std::sync::OnceLock;
+
+/// The conversions done before the forward FFT and after the backward FFT
+pub(super) trait ConversionBackend: Send + Sync {
+    fn convert_forward_integer_u32(
+        &self,
+        out: &mut [c64],
+        in_re: &[u32],
+        in_im: &[u32],
+        twisties: TwistiesView<'_>,
+    );
+
+    fn convert_forward_integer_u64(
+        &self,
+        out: &mut [c64],
+        in_re: &[u64],
+        in_im: &[u64],
+        twisties: TwistiesView<'_>,
+    );
+
+    fn convert_add_backward_torus_u32(
+        &self,
+        out_re: &mut [u32],
+        out_im: &mut [u32],
+        inp: &[c64],
+        twisties: TwistiesView<'_>,
+    );
+
+    fn convert_add_backward_torus_u64(
+        &self,
+        out_re: &mut [u64],
+        out_im: &mut [u64],
+        inp: &[c64],
+        twisties: TwistiesView<'_>,
+    );
+}
+
+struct Portable;
+
+impl ConversionBackend for Portable {
+    fn convert_forward_integer_u32(
+        &self,
+        out: &mut [c64],
+        in_re: &[u32],
+        in_im: &[u32],
+        twisties: TwistiesView<'_>,
+    ) {
+        super::convert_forward_integer_scalar::<u32>(out, in_re, in_im, twisties);
+    }
+
+    fn convert_forward_integer_u64(
+        &self,
+        out: &mut [c64],
+        in_re: &[u64],
+        in_im: &[u64],
+        twisties: TwistiesView<'_>,
+    ) {
+        super::convert_forward_integer_scalar::<u64>(out, in_re, in_im, twisties);
+    }
+
+    fn convert_add_backward_torus_u32(
+        &self,
+        out_re: &mut [u32],
+        out_im: &mut [u32],
+        inp: &[c64],
+        twisties: TwistiesView<'_>,
+    ) {
+        super::convert_add_backward_torus_scalar::<u32>(out_re, out_im, inp, twisties);
+    }
+
+    fn convert_add_backward_torus_u64(
+        &self,
+        out_re: &mut [u64],
+        out_im: &mut [u64],
+        inp: &[c64],
+        twisties: TwistiesView<'_>,
+    ) {
+        super::convert_add_backward_torus_scalar::<u64>(out_re, out_im, inp, twisties);
+    }
+}
+
+/// The implementations of the conversions of the FFT
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FftBackend {
+    /// Supported on all CPUs
+    Portable,
+    /// Requires an x86 CPU with AVX2 and FMA
+    X86Avx2,
+    /// Requires an x86 CPU with AVX512F and AVX512DQ, and the `nightly-avx512` feature
+    X86Avx512,
+}
+
+impl FftBackend {
+    /// Returns the backends from the fastest to the slowest
+    pub fn all() -> [Self; 3] {
+        [Self::X86Avx512, Self::X86Avx2, Self::Portable]
+    }
+
+    /// Returns whether the backend can be used on the current CPU
+    pub fn is_supported(self) -> bool {
+        self.instantiate().is_some()
+    }
+
+    /// Returns the fastest backend supported by the current CPU
+    pub fn detect() -> Self {
+        Self::all()
+            .into_iter()
+            .find(|backend| backend.is_supported())
+            .unwrap_or(Self::Portable)
+    }
+
+    pub(super) fn instantiate(self) -> Option<Box<dyn ConversionBackend>> {
+        match self {
+            Self::Portable => Some(Box::new(Portable)),
+            #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+            Self::X86Avx2 => pulp::x86::V3::try_new()
+                .map(|simd| Box::new(super::x86::Avx2(simd)) as Box<dyn ConversionBackend>),
+            #[cfg(all(
+                any(target_arch = "x86_64", target_arch = "x86"),
+                feature = "nightly-avx512"
+            ))]
+            Self::X86Avx512 => pulp::x86::V4::try_new()
+                .map(|simd| Box::new(super::x86::Avx512(simd)) as Box<dyn ConversionBackend>),
+            #[allow(unreachable_patterns)]
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FftBackendError {
+    /// The backend cannot be used on the current CPU, see [FftBackend::is_supported]
+    Unsupported(FftBackend),
+    /// A backend was already selected, either explicitly or by a previous FFT
+    AlreadySelected(FftBackend),
+}
+
+impl std::fmt::Display for FftBackendError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unsupported(backend) => {
+                write!(
+                    f,
+                    "The FFT backend {backend:?} is not supported by this CPU"
+                )
+            }
+            Self::AlreadySelected(backend) => {
+                write!(f, "The FFT backend {backend:?} is already selected")
+            }
+        }
+    }
+}
+
+impl std::error::Error for FftBackendError {}
+
+static SELECTED_BACKEND: OnceLock<(FftBackend, Box<dyn ConversionBackend>)> = OnceLock::new();
+
+fn selected_backend() -> &'static (FftBackend, Box<dyn ConversionBackend>) {
+    SELECTED_BACKEND.get_or_init(|| {
+        let backend = FftBackend::detect();
+        // detect only returns supported backends
+        (backend, backend.instantiate().unwrap())
+    })
+}
+
+/// Returns the backend used by the FFTs, selecting the fastest one if none was selected yet
+pub fn fft_backend() -> FftBackend {
+    selected_backend().0
+}
+
+/// Selects the backend used by all the FFTs of the process
+///
+/// This must be called before any FFT is computed, as the backend cannot be changed once
+/// selected.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::core_crypto::fft_impl::fft64::math::fft::{fft_backend, set_fft_backend, FftBackend};
+///
+/// // Use the same conversion implementation on all CPUs
+/// set_fft_backend(FftBackend::Portable).unwrap();
+/// assert_eq!(fft_backend(), FftBackend::Portable);
+///
+/// assert!(set_fft_backend(FftBackend::detect()).is_err());
+/// ```
+pub fn set_fft_backend(backend: FftBackend) -> Result<(), FftBackendError> {
+    let instance = backend
+        .instantiate()
+        .ok_or(FftBackendError::Unsupported(backend))?;
+    SELECTED_BACKEND
+        .set((backend, instance))
+        .map_err(|_| FftBackendError::AlreadySelected(fft_backend()))
+}
+
+pub(super) fn conversion_backend() -> &'static dyn ConversionBackend {
+    selected_backend().1.as_ref()
+}
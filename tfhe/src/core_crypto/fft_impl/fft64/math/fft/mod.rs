@@ -18,9 +18,12 @@ use std::sync::{Arc, OnceLock, RwLock};
 #[cfg(not(feature = "experimental-force_fft_algo_dif4"))]
 use std::time::Duration;
 
+mod backend;
 #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
 mod x86;
 
+pub use backend::{fft_backend, set_fft_backend, FftBackend, FftBackendError};
+
 /// Twisting factors from the paper:
 /// [Fast and Error-Free Negacyclic Integer Convolution using Extended Fourier Transform][paper]
 ///
@@ -110,7 +113,6 @@ fn plans() -> &'static PlanMap {
 ///
 /// Panics if `From` and `To` are not the same type
 #[inline]
-#[allow(dead_code)]
 fn id_mut<From: 'static, To: 'static>(slice: &mut [From]) -> &mut [To] {
     assert_eq!(size_of::<From>(), size_of::<To>());
     assert_eq!(align_of::<From>(), align_of::<To>());
@@ -130,7 +132,6 @@ fn id_mut<From: 'static, To: 'static>(slice: &mut [From]) -> &mut [To] {
 ///
 /// Panics if `From` and `To` are not the same type
 #[inline]
-#[allow(dead_code)]
 fn id<From: 'static, To: 'static>(slice: &[From]) -> &[To] {
     assert_eq!(size_of::<From>(), size_of::<To>());
     assert_eq!(align_of::<From>(), align_of::<To>());
@@ -245,19 +246,18 @@ fn convert_forward_integer<Scalar: UnsignedTorus>(
     in_im: &[Scalar],
     twisties: TwistiesView<'_>,
 ) {
-    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
-    {
-        if Scalar::BITS == 32 {
-            x86::convert_forward_integer_u32(out, id(in_re), id(in_im), twisties);
-        } else if Scalar::BITS == 64 {
-            x86::convert_forward_integer_u64(out, id(in_re), id(in_im), twisties);
-        } else {
-            unreachable!();
-        }
+    let backend = backend::conversion_backend();
+    if Scalar::BITS == 32 {
+        backend.convert_forward_integer_u32(out, id(in_re), id(in_im), twisties);
+    } else if Scalar::BITS == 64 {
+        backend.convert_forward_integer_u64(out, id(in_re), id(in_im), twisties);
+    } else if Scalar::BITS == 128 {
+        // There is no vectorized conversion of 128 bits integers, and the decomposed values
+        // converted here are small enough to be exactly represented by an f64
+        convert_forward_integer_scalar::<Scalar>(out, in_re, in_im, twisties);
+    } else {
+        unreachable!();
     }
-
-    #[cfg(not(any(target_arch = "x86_64", target_arch = "x86")))]
-    convert_forward_integer_scalar::<Scalar>(out, in_re, in_im, twisties)
 }
 
 #[cfg_attr(__profiling, inline(never))]
@@ -310,19 +310,17 @@ fn convert_add_backward_torus<Scalar: UnsignedTorus>(
     inp: &[c64],
     twisties: TwistiesView<'_>,
 ) {
-    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
-    {
-        if Scalar::BITS == 32 {
-            x86::convert_add_backward_torus_u32(id_mut(out_re), id_mut(out_im), inp, twisties);
-        } else if Scalar::BITS == 64 {
-            x86::convert_add_backward_torus_u64(id_mut(out_re), id_mut(out_im), inp, twisties);
-        } else {
-            unreachable!();
-        }
+    let backend = backend::conversion_backend();
+    if Scalar::BITS == 32 {
+        backend.convert_add_backward_torus_u32(id_mut(out_re), id_mut(out_im), inp, twisties);
+    } else if Scalar::BITS == 64 {
+        backend.convert_add_backward_torus_u64(id_mut(out_re), id_mut(out_im), inp, twisties);
+    } else if Scalar::BITS == 128 {
+        // Only the 53 MSBs of the torus values are recovered from an f64, the LSBs are zero
+        convert_add_backward_torus_scalar::<Scalar>(out_re, out_im, inp, twisties);
+    } else {
+        unreachable!();
     }
-
-    #[cfg(not(any(target_arch = "x86_64", target_arch = "x86")))]
-    convert_add_backward_torus_scalar::<Scalar>(out_re, out_im, inp, twisties);
 }
 
 impl<'a> FftView<'a> {
@@ -41,7 +41,9 @@ fn test_roundtrip<Scalar: UnsignedTorus>() {
             if Scalar::BITS == 32 {
                 assert!(modular_distance(*expected, *actual) == Scalar::ZERO);
             } else {
-                assert!(modular_distance(*expected, *actual) < (Scalar::ONE << (64 - 50)));
+                assert!(
+                    modular_distance(*expected, *actual) < (Scalar::ONE << (Scalar::BITS - 50))
+                );
             }
         }
 
@@ -55,7 +57,9 @@ fn test_roundtrip<Scalar: UnsignedTorus>() {
             if Scalar::BITS == 32 {
                 assert!(modular_distance(*expected, *actual) == Scalar::ZERO);
             } else {
-                assert!(modular_distance(*expected, *actual) < (Scalar::ONE << (64 - 50)));
+                assert!(
+                    modular_distance(*expected, *actual) < (Scalar::ONE << (Scalar::BITS - 50))
+                );
             }
         }
 
@@ -73,7 +77,9 @@ fn test_roundtrip<Scalar: UnsignedTorus>() {
             if Scalar::BITS == 32 {
                 assert!(modular_distance(*expected, *actual) == Scalar::ZERO);
             } else {
-                assert!(modular_distance(*expected, *actual) < (Scalar::ONE << (64 - 50)));
+                assert!(
+                    modular_distance(*expected, *actual) < (Scalar::ONE << (Scalar::BITS - 50))
+                );
             }
         }
     }
@@ -231,6 +237,11 @@ fn test_product_u64() {
     test_product::<u64>();
 }
 
+#[test]
+fn test_product_u128() {
+    test_product::<u128>();
+}
+
 #[test]
 fn test_roundtrip_u32() {
     test_roundtrip::<u32>();
@@ -239,6 +250,62 @@ fn test_roundtrip_u32() {
 fn test_roundtrip_u64() {
     test_roundtrip::<u64>();
 }
+#[test]
+fn test_roundtrip_u128() {
+    test_roundtrip::<u128>();
+}
+
+#[test]
+fn test_backends_match_portable() {
+    assert!(FftBackend::Portable.is_supported());
+    assert!(FftBackend::detect().is_supported());
+
+    let mut generator = new_random_generator();
+    let portable = FftBackend::Portable.instantiate().unwrap();
+    let n = 1024;
+    let twisties = Twisties::new(n);
+
+    let in_re: Vec<u64> = (0..n).map(|_| generator.random_uniform()).collect();
+    let in_im: Vec<u64> = (0..n).map(|_| generator.random_uniform()).collect();
+    let mut expected_fourier = vec![c64::default(); n];
+    portable.convert_forward_integer_u64(&mut expected_fourier, &in_re, &in_im, twisties.as_view());
+
+    let mut expected_re = vec![0_u64; n];
+    let mut expected_im = vec![0_u64; n];
+    portable.convert_add_backward_torus_u64(
+        &mut expected_re,
+        &mut expected_im,
+        &expected_fourier,
+        twisties.as_view(),
+    );
+
+    for backend in FftBackend::all() {
+        let Some(backend) = backend.instantiate() else {
+            continue;
+        };
+
+        let mut fourier = vec![c64::default(); n];
+        backend.convert_forward_integer_u64(&mut fourier, &in_re, &in_im, twisties.as_view());
+        for (expected, actual) in izip!(&expected_fourier, &fourier) {
+            assert!((expected - actual).norm() <= 1e-6 * expected.norm());
+        }
+
+        let mut out_re = vec![0_u64; n];
+        let mut out_im = vec![0_u64; n];
+        backend.convert_add_backward_torus_u64(
+            &mut out_re,
+            &mut out_im,
+            &expected_fourier,
+            twisties.as_view(),
+        );
+        for (expected, actual) in izip!(
+            expected_re.iter().chain(&expected_im),
+            out_re.iter().chain(&out_im)
+        ) {
+            assert!(modular_distance(*expected, *actual) < (1 << 38));
+        }
+    }
+}
 
 #[test]
 fn f64_to_i64_bit_twiddles() {
@@ -1,7 +1,7 @@
 //! For documentation on the various intrinsics used here, refer to Intel's intrinsics guide.
 //! <https://www.intel.com/content/www/us/en/docs/intrinsics-guide/index.html>
 //!
-//! currently we dispatch based on the availability of
+//! The backend is selected at runtime, see the `backend` module, based on the availability of
 //!  - avx+avx2(advanced vector extensions for 256 intrinsics)+fma(fused multiply add for complex
 //!  multiplication, usually comes with avx+avx2),
 //!  - or the availability of avx512f[+avx512dq(doubleword/quadword intrinsics for conversion of f64
@@ -15,6 +15,7 @@ use core::arch::x86::*;
 use core::arch::x86_64::*;
 
 use super::super::super::c64;
+use super::backend::ConversionBackend;
 use super::TwistiesView;
 use crate::core_crypto::commons::utils::izip;
 
@@ -1043,68 +1044,96 @@ pub fn convert_add_backward_torus_u64_v3(
     })
 }
 
-pub fn convert_forward_integer_u32(
-    out: &mut [c64],
-    in_re: &[u32],
-    in_im: &[u32],
-    twisties: TwistiesView<'_>,
-) {
-    #[cfg(feature = "nightly-avx512")]
-    if let Some(simd) = V4::try_new() {
-        return convert_forward_integer_u32_v4(simd, out, in_re, in_im, twisties);
+/// The conversions using AVX2 and FMA
+pub struct Avx2(pub V3);
+
+impl ConversionBackend for Avx2 {
+    fn convert_forward_integer_u32(
+        &self,
+        out: &mut [c64],
+        in_re: &[u32],
+        in_im: &[u32],
+        twisties: TwistiesView<'_>,
+    ) {
+        convert_forward_integer_u32_v3(self.0, out, in_re, in_im, twisties);
     }
-    if let Some(simd) = V3::try_new() {
-        return convert_forward_integer_u32_v3(simd, out, in_re, in_im, twisties);
+
+    fn convert_forward_integer_u64(
+        &self,
+        out: &mut [c64],
+        in_re: &[u64],
+        in_im: &[u64],
+        twisties: TwistiesView<'_>,
+    ) {
+        convert_forward_integer_u64_avx2_v3(self.0, out, in_re, in_im, twisties);
     }
-    super::convert_forward_integer_scalar::<u32>(out, in_re, in_im, twisties)
-}
 
-pub fn convert_forward_integer_u64(
-    out: &mut [c64],
-    in_re: &[u64],
-    in_im: &[u64],
-    twisties: TwistiesView<'_>,
-) {
-    #[cfg(feature = "nightly-avx512")]
-    if let Some(simd) = V4::try_new() {
-        return convert_forward_integer_u64_v4(simd, out, in_re, in_im, twisties);
+    fn convert_add_backward_torus_u32(
+        &self,
+        out_re: &mut [u32],
+        out_im: &mut [u32],
+        inp: &[c64],
+        twisties: TwistiesView<'_>,
+    ) {
+        convert_add_backward_torus_u32_v3(self.0, out_re, out_im, inp, twisties);
     }
-    if let Some(simd) = V3::try_new() {
-        return convert_forward_integer_u64_avx2_v3(simd, out, in_re, in_im, twisties);
+
+    fn convert_add_backward_torus_u64(
+        &self,
+        out_re: &mut [u64],
+        out_im: &mut [u64],
+        inp: &[c64],
+        twisties: TwistiesView<'_>,
+    ) {
+        convert_add_backward_torus_u64_v3(self.0, out_re, out_im, inp, twisties);
     }
-    super::convert_forward_integer_scalar::<u64>(out, in_re, in_im, twisties)
 }
 
-pub fn convert_add_backward_torus_u32(
-    out_re: &mut [u32],
-    out_im: &mut [u32],
-    inp: &[c64],
-    twisties: TwistiesView<'_>,
-) {
-    #[cfg(feature = "nightly-avx512")]
-    if let Some(simd) = V4::try_new() {
-        return convert_add_backward_torus_u32_v4(simd, out_re, out_im, inp, twisties);
+/// The conversions using AVX512F and AVX512DQ
+#[cfg(feature = "nightly-avx512")]
+pub struct Avx512(pub V4);
+
+#[cfg(feature = "nightly-avx512")]
+impl ConversionBackend for Avx512 {
+    fn convert_forward_integer_u32(
+        &self,
+        out: &mut [c64],
+        in_re: &[u32],
+        in_im: &[u32],
+        twisties: TwistiesView<'_>,
+    ) {
+        convert_forward_integer_u32_v4(self.0, out, in_re, in_im, twisties);
     }
-    if let Some(simd) = V3::try_new() {
-        return convert_add_backward_torus_u32_v3(simd, out_re, out_im, inp, twisties);
+
+    fn convert_forward_integer_u64(
+        &self,
+        out: &mut [c64],
+        in_re: &[u64],
+        in_im: &[u64],
+        twisties: TwistiesView<'_>,
+    ) {
+        convert_forward_integer_u64_v4(self.0, out, in_re, in_im, twisties);
     }
-    super::convert_add_backward_torus_scalar::<u32>(out_re, out_im, inp, twisties)
-}
 
-pub fn convert_add_backward_torus_u64(
-    out_re: &mut [u64],
-    out_im: &mut [u64],
-    inp: &[c64],
-    twisties: TwistiesView<'_>,
-) {
-    #[cfg(feature = "nightly-avx512")]
-    if let Some(simd) = V4::try_new() {
-        return convert_add_backward_torus_u64_v4(simd, out_re, out_im, inp, twisties);
+    fn convert_add_backward_torus_u32(
+        &self,
+        out_re: &mut [u32],
+        out_im: &mut [u32],
+        inp: &[c64],
+        twisties: TwistiesView<'_>,
+    ) {
+        convert_add_backward_torus_u32_v4(self.0, out_re, out_im, inp, twisties);
     }
-    if let Some(simd) = V3::try_new() {
-        return convert_add_backward_torus_u64_v3(simd, out_re, out_im, inp, twisties);
+
+    fn convert_add_backward_torus_u64(
+        &self,
+        out_re: &mut [u64],
+        out_im: &mut [u64],
+        inp: &[c64],
+        twisties: TwistiesView<'_>,
+    ) {
+        convert_add_backward_torus_u64_v4(self.0, out_re, out_im, inp, twisties);
     }
-    super::convert_add_backward_torus_scalar::<u64>(out_re, out_im, inp, twisties)
 }
 
 #[cfg(test)]
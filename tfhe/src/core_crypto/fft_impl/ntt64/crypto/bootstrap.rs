@@ -0,0 +1,401 @@
+use super::super::math::ntt::{Ntt, NttView, RECOVERED_BITS};
+use super::ggsw::{
+    add_external_product_assign, add_external_product_assign_scratch, NttGgswCiphertext,
+};
+use crate::core_crypto::algorithms::extract_lwe_sample_from_glwe_ciphertext;
+use crate::core_crypto::algorithms::polynomial_algorithms::*;
+use crate::core_crypto::commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweSize, LutCountLog, LweDimension,
+    ModulusSwitchOffset, MonomialDegree, PolynomialSize,
+};
+use crate::core_crypto::commons::traits::{
+    Container, ContainerMut, ContiguousEntityContainer, ContiguousEntityContainerMut, Split,
+};
+use crate::core_crypto::commons::utils::izip;
+use crate::core_crypto::entities::*;
+use crate::core_crypto::fft_impl::common::{fast_pbs_modulus_switch, FourierBootstrapKey};
+use aligned_vec::{avec, ABox, CACHELINE_ALIGN};
+use dyn_stack::{PodStack, ReborrowMut, SizeOverflow, StackReq};
+use rayon::prelude::*;
+
+/// An LWE bootstrapping key in the NTT domain.
+///
+/// Unlike the bootstrapping keys in the Fourier domain, the programmable bootstrapping computed
+/// with this key gives bit-exact results on all platforms, at the cost of a slower external
+/// product.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct NttLweBootstrapKey<C: Container<Element = u64>> {
+    data: C,
+    polynomial_size: PolynomialSize,
+    input_lwe_dimension: LweDimension,
+    glwe_size: GlweSize,
+    decomposition_base_log: DecompositionBaseLog,
+    decomposition_level_count: DecompositionLevelCount,
+}
+
+pub type NttLweBootstrapKeyView<'a> = NttLweBootstrapKey<&'a [u64]>;
+pub type NttLweBootstrapKeyMutView<'a> = NttLweBootstrapKey<&'a mut [u64]>;
+
+impl<C: Container<Element = u64>> NttLweBootstrapKey<C> {
+    /// # Panics
+    ///
+    /// Panics if the coefficients of the external products computed with the key could exceed the
+    /// [`RECOVERED_BITS`] bits recovered exactly by the NTT.
+    pub fn from_container(
+        data: C,
+        input_lwe_dimension: LweDimension,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> Self {
+        assert_eq!(
+            data.container_len(),
+            input_lwe_dimension.0
+                * 2
+                * polynomial_size.0
+                * decomposition_level_count.0
+                * glwe_size.0
+                * glwe_size.0
+        );
+
+        // Each coefficient of an external product is a sum of glwe_size * level_count *
+        // polynomial_size products of a decomposition term of at most 2^(base_log - 1) by a
+        // signed 64 bits key coefficient
+        let summed_products = glwe_size.0 * decomposition_level_count.0 * polynomial_size.0;
+        let max_bits =
+            summed_products.next_power_of_two().ilog2() as usize + decomposition_base_log.0 + 62;
+        assert!(
+            max_bits < RECOVERED_BITS,
+            "The external products with {decomposition_base_log:?}, {decomposition_level_count:?}, \
+            {glwe_size:?} and {polynomial_size:?} can exceed the precision of the NTT"
+        );
+
+        Self {
+            data,
+            polynomial_size,
+            input_lwe_dimension,
+            glwe_size,
+            decomposition_base_log,
+            decomposition_level_count,
+        }
+    }
+
+    /// Return an iterator over the GGSW ciphertexts composing the key.
+    pub fn into_ggsw_iter(self) -> impl DoubleEndedIterator<Item = NttGgswCiphertext<C>>
+    where
+        C: Split,
+    {
+        self.data
+            .split_into(self.input_lwe_dimension.0)
+            .map(move |slice| {
+                NttGgswCiphertext::from_container(
+                    slice,
+                    self.polynomial_size,
+                    self.glwe_size,
+                    self.decomposition_base_log,
+                    self.decomposition_level_count,
+                )
+            })
+    }
+
+    pub fn input_lwe_dimension(&self) -> LweDimension {
+        self.input_lwe_dimension
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    pub fn glwe_size(&self) -> GlweSize {
+        self.glwe_size
+    }
+
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+
+    pub fn output_lwe_dimension(&self) -> LweDimension {
+        LweDimension((self.glwe_size.0 - 1) * self.polynomial_size().0)
+    }
+
+    pub fn data(self) -> C {
+        self.data
+    }
+
+    pub fn as_view(&self) -> NttLweBootstrapKeyView<'_> {
+        NttLweBootstrapKeyView {
+            data: self.data.as_ref(),
+            polynomial_size: self.polynomial_size,
+            input_lwe_dimension: self.input_lwe_dimension,
+            glwe_size: self.glwe_size,
+            decomposition_base_log: self.decomposition_base_log,
+            decomposition_level_count: self.decomposition_level_count,
+        }
+    }
+
+    pub fn as_mut_view(&mut self) -> NttLweBootstrapKeyMutView<'_>
+    where
+        C: AsMut<[u64]>,
+    {
+        NttLweBootstrapKeyMutView {
+            data: self.data.as_mut(),
+            polynomial_size: self.polynomial_size,
+            input_lwe_dimension: self.input_lwe_dimension,
+            glwe_size: self.glwe_size,
+            decomposition_base_log: self.decomposition_base_log,
+            decomposition_level_count: self.decomposition_level_count,
+        }
+    }
+}
+
+pub type NttLweBootstrapKeyOwned = NttLweBootstrapKey<ABox<[u64]>>;
+
+impl NttLweBootstrapKey<ABox<[u64]>> {
+    pub fn new(
+        input_lwe_dimension: LweDimension,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> NttLweBootstrapKey<ABox<[u64]>> {
+        let boxed = avec![
+            0u64;
+            2 * polynomial_size.0
+                * input_lwe_dimension.0
+                * decomposition_level_count.0
+                * glwe_size.0
+                * glwe_size.0
+        ]
+        .into_boxed_slice();
+
+        NttLweBootstrapKey::from_container(
+            boxed,
+            input_lwe_dimension,
+            glwe_size,
+            polynomial_size,
+            decomposition_base_log,
+            decomposition_level_count,
+        )
+    }
+}
+
+impl<'a> NttLweBootstrapKeyMutView<'a> {
+    /// Fill a bootstrapping key with the NTT of a bootstrapping key in the standard domain.
+    pub fn fill_with_forward_ntt(self, coef_bsk: LweBootstrapKey<&'_ [u64]>, ntt: NttView<'_>) {
+        let poly_size = self.polynomial_size.0;
+        for (ntt_poly, coef_poly) in izip!(
+            self.data.chunks_exact_mut(2 * poly_size),
+            coef_bsk.as_ref().chunks_exact(poly_size)
+        ) {
+            ntt.forward_as_integer(ntt_poly, coef_poly);
+        }
+    }
+
+    /// Fill a bootstrapping key with the NTT of a bootstrapping key in the standard domain, the
+    /// polynomials being transformed in parallel.
+    pub fn par_fill_with_forward_ntt(self, coef_bsk: LweBootstrapKey<&'_ [u64]>, ntt: NttView<'_>) {
+        let poly_size = self.polynomial_size.0;
+        self.data
+            .par_chunks_exact_mut(2 * poly_size)
+            .zip(coef_bsk.as_ref().par_chunks_exact(poly_size))
+            .for_each(|(ntt_poly, coef_poly)| ntt.forward_as_integer(ntt_poly, coef_poly));
+    }
+}
+
+/// Return the required memory for [`NttLweBootstrapKeyView::blind_rotate_assign`].
+pub fn blind_rotate_scratch(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+) -> Result<StackReq, SizeOverflow> {
+    StackReq::try_all_of([
+        // ct1 allocation
+        StackReq::try_new_aligned::<u64>(glwe_size.0 * polynomial_size.0, CACHELINE_ALIGN)?,
+        // external product
+        add_external_product_assign_scratch(glwe_size, polynomial_size)?,
+    ])
+}
+
+/// Return the required memory for [`NttLweBootstrapKeyView::bootstrap`].
+pub fn bootstrap_scratch(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+) -> Result<StackReq, SizeOverflow> {
+    blind_rotate_scratch(glwe_size, polynomial_size)?.try_and(StackReq::try_new_aligned::<u64>(
+        glwe_size.0 * polynomial_size.0,
+        CACHELINE_ALIGN,
+    )?)
+}
+
+impl<'a> NttLweBootstrapKeyView<'a> {
+    pub fn blind_rotate_assign(
+        self,
+        mut lut: GlweCiphertextMutView<'_, u64>,
+        lwe: &[u64],
+        ntt: NttView<'_>,
+        stack: PodStack<'_>,
+    ) {
+        let (lwe_body, lwe_mask) = lwe.split_last().unwrap();
+
+        let lut_poly_size = lut.polynomial_size();
+        let ciphertext_modulus = lut.ciphertext_modulus();
+        // The power of two moduli are stored in the MSBs, the LSBs of the results of the exact
+        // external products stay at zero, so unlike the FFT no rounding is needed afterwards
+        assert!(
+            ciphertext_modulus.is_compatible_with_native_modulus(),
+            "The NTT bootstrap only supports the native and power of two moduli"
+        );
+        let monomial_degree = MonomialDegree(fast_pbs_modulus_switch(
+            *lwe_body,
+            lut_poly_size,
+            ModulusSwitchOffset(0),
+            LutCountLog(0),
+        ));
+
+        lut.as_mut_polynomial_list()
+            .iter_mut()
+            .for_each(|mut poly| {
+                polynomial_wrapping_monic_monomial_div_assign(&mut poly, monomial_degree)
+            });
+
+        // We initialize the ct_0 used for the successive cmuxes
+        let mut ct0 = lut;
+        let (mut ct1, mut stack) = stack.make_aligned_raw(ct0.as_ref().len(), CACHELINE_ALIGN);
+        let mut ct1 =
+            GlweCiphertextMutView::from_container(&mut *ct1, lut_poly_size, ciphertext_modulus);
+
+        for (lwe_mask_element, bootstrap_key_ggsw) in izip!(lwe_mask.iter(), self.into_ggsw_iter())
+        {
+            if *lwe_mask_element != 0 {
+                let monomial_degree = MonomialDegree(fast_pbs_modulus_switch(
+                    *lwe_mask_element,
+                    lut_poly_size,
+                    ModulusSwitchOffset(0),
+                    LutCountLog(0),
+                ));
+
+                // We rotate ct_1 and subtract ct_0 (first step of cmux) by performing
+                // ct_1 <- (ct_0 * X^{a_hat}) - ct_0
+                for (mut ct1_poly, ct0_poly) in izip!(
+                    ct1.as_mut_polynomial_list().iter_mut(),
+                    ct0.as_polynomial_list().iter(),
+                ) {
+                    polynomial_wrapping_monic_monomial_mul_and_subtract(
+                        &mut ct1_poly,
+                        &ct0_poly,
+                        monomial_degree,
+                    );
+                }
+
+                // second step of cmux
+                add_external_product_assign(
+                    &mut ct0,
+                    &bootstrap_key_ggsw,
+                    &ct1,
+                    ntt,
+                    stack.rb_mut(),
+                );
+            }
+        }
+    }
+
+    pub fn bootstrap(
+        self,
+        mut lwe_out: LweCiphertextMutView<'_, u64>,
+        lwe_in: LweCiphertextView<'_, u64>,
+        accumulator: GlweCiphertextView<'_, u64>,
+        ntt: NttView<'_>,
+        stack: PodStack<'_>,
+    ) {
+        let (mut local_accumulator_data, stack) =
+            stack.collect_aligned(CACHELINE_ALIGN, accumulator.as_ref().iter().copied());
+        let mut local_accumulator = GlweCiphertextMutView::from_container(
+            &mut *local_accumulator_data,
+            accumulator.polynomial_size(),
+            accumulator.ciphertext_modulus(),
+        );
+        self.blind_rotate_assign(local_accumulator.as_mut_view(), lwe_in.as_ref(), ntt, stack);
+
+        extract_lwe_sample_from_glwe_ciphertext(
+            &local_accumulator,
+            &mut lwe_out,
+            MonomialDegree(0),
+        );
+    }
+}
+
+impl FourierBootstrapKey<u64> for NttLweBootstrapKeyOwned {
+    type Fft = Ntt;
+
+    fn new_fft(polynomial_size: PolynomialSize) -> Self::Fft {
+        Ntt::new(polynomial_size)
+    }
+
+    fn new(
+        input_lwe_dimension: LweDimension,
+        polynomial_size: PolynomialSize,
+        glwe_size: GlweSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> Self {
+        Self::new(
+            input_lwe_dimension,
+            glwe_size,
+            polynomial_size,
+            decomposition_base_log,
+            decomposition_level_count,
+        )
+    }
+
+    fn fill_with_forward_fourier<ContBsk>(
+        &mut self,
+        coef_bsk: &LweBootstrapKey<ContBsk>,
+        fft: &Self::Fft,
+        stack: PodStack<'_>,
+    ) where
+        ContBsk: Container<Element = u64>,
+    {
+        let _ = stack;
+        self.as_mut_view()
+            .fill_with_forward_ntt(coef_bsk.as_view(), fft.as_view());
+    }
+
+    fn bootstrap_scratch(
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        fft: &Self::Fft,
+    ) -> Result<StackReq, SizeOverflow> {
+        let _ = fft;
+        bootstrap_scratch(glwe_size, polynomial_size)
+    }
+
+    fn bootstrap<ContLweOut, ContLweIn, ContAcc>(
+        &self,
+        lwe_out: &mut LweCiphertext<ContLweOut>,
+        lwe_in: &LweCiphertext<ContLweIn>,
+        accumulator: &GlweCiphertext<ContAcc>,
+        fft: &Self::Fft,
+        stack: PodStack<'_>,
+    ) where
+        ContLweOut: ContainerMut<Element = u64>,
+        ContLweIn: Container<Element = u64>,
+        ContAcc: Container<Element = u64>,
+    {
+        self.as_view().bootstrap(
+            lwe_out.as_mut_view(),
+            lwe_in.as_view(),
+            accumulator.as_view(),
+            fft.as_view(),
+            stack,
+        )
+    }
+
+    fn fill_with_forward_fourier_scratch(fft: &Self::Fft) -> Result<StackReq, SizeOverflow> {
+        let _ = fft;
+        Ok(StackReq::empty())
+    }
+}
@@ -0,0 +1,412 @@
+use super::super::math::ntt::NttView;
+use crate::core_crypto::commons::math::decomposition::{DecompositionLevel, SignedDecomposer};
+use crate::core_crypto::commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+};
+use crate::core_crypto::commons::traits::{
+    Container, ContainerMut, ContiguousEntityContainerMut, Split,
+};
+use crate::core_crypto::commons::utils::izip;
+use crate::core_crypto::entities::*;
+use crate::core_crypto::fft_impl::fft64::math::decomposition::TensorSignedDecompositionLendingIter;
+use aligned_vec::CACHELINE_ALIGN;
+use dyn_stack::{PodStack, ReborrowMut, SizeOverflow, StackReq};
+
+/// A GGSW ciphertext in the NTT domain.
+///
+/// Each polynomial takes `2N` coefficients, see [`Ntt`](super::super::math::ntt::Ntt).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct NttGgswCiphertext<C: Container<Element = u64>> {
+    data: C,
+
+    polynomial_size: PolynomialSize,
+    glwe_size: GlweSize,
+    decomposition_base_log: DecompositionBaseLog,
+    decomposition_level_count: DecompositionLevelCount,
+}
+
+/// A matrix containing a single level of gadget decomposition, in the NTT domain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NttGgswLevelMatrix<C: Container<Element = u64>> {
+    data: C,
+
+    polynomial_size: PolynomialSize,
+    glwe_size: GlweSize,
+    row_count: usize,
+    decomposition_level: DecompositionLevel,
+}
+
+/// A row of a GGSW level matrix, in the NTT domain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NttGgswLevelRow<C: Container<Element = u64>> {
+    data: C,
+
+    polynomial_size: PolynomialSize,
+    glwe_size: GlweSize,
+    decomposition_level: DecompositionLevel,
+}
+
+impl<C: Container<Element = u64>> NttGgswCiphertext<C> {
+    pub fn from_container(
+        data: C,
+        polynomial_size: PolynomialSize,
+        glwe_size: GlweSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+    ) -> Self {
+        assert_eq!(
+            data.container_len(),
+            2 * polynomial_size.0 * glwe_size.0 * glwe_size.0 * decomposition_level_count.0
+        );
+
+        Self {
+            data,
+            polynomial_size,
+            glwe_size,
+            decomposition_base_log,
+            decomposition_level_count,
+        }
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    pub fn glwe_size(&self) -> GlweSize {
+        self.glwe_size
+    }
+
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+
+    pub fn data(self) -> C {
+        self.data
+    }
+
+    pub fn as_view(&self) -> NttGgswCiphertext<&[u64]> {
+        NttGgswCiphertext {
+            data: self.data.as_ref(),
+            polynomial_size: self.polynomial_size,
+            glwe_size: self.glwe_size,
+            decomposition_base_log: self.decomposition_base_log,
+            decomposition_level_count: self.decomposition_level_count,
+        }
+    }
+
+    pub fn as_mut_view(&mut self) -> NttGgswCiphertext<&mut [u64]>
+    where
+        C: AsMut<[u64]>,
+    {
+        NttGgswCiphertext {
+            data: self.data.as_mut(),
+            polynomial_size: self.polynomial_size,
+            glwe_size: self.glwe_size,
+            decomposition_base_log: self.decomposition_base_log,
+            decomposition_level_count: self.decomposition_level_count,
+        }
+    }
+
+    /// Return an iterator over the level matrices.
+    pub fn into_levels(self) -> impl DoubleEndedIterator<Item = NttGgswLevelMatrix<C>>
+    where
+        C: Split,
+    {
+        self.data
+            .split_into(self.decomposition_level_count.0)
+            .enumerate()
+            .map(move |(i, data)| {
+                NttGgswLevelMatrix::from_container(
+                    data,
+                    self.polynomial_size,
+                    self.glwe_size,
+                    self.glwe_size.0,
+                    DecompositionLevel(i + 1),
+                )
+            })
+    }
+}
+
+impl<C: Container<Element = u64>> NttGgswLevelMatrix<C> {
+    pub fn from_container(
+        data: C,
+        polynomial_size: PolynomialSize,
+        glwe_size: GlweSize,
+        row_count: usize,
+        decomposition_level: DecompositionLevel,
+    ) -> Self {
+        assert_eq!(
+            data.container_len(),
+            2 * polynomial_size.0 * glwe_size.0 * row_count
+        );
+
+        Self {
+            data,
+            polynomial_size,
+            glwe_size,
+            row_count,
+            decomposition_level,
+        }
+    }
+
+    /// Return an iterator over the rows of the level matrices.
+    pub fn into_rows(self) -> impl DoubleEndedIterator<Item = NttGgswLevelRow<C>>
+    where
+        C: Split,
+    {
+        self.data
+            .split_into(self.row_count)
+            .map(move |data| NttGgswLevelRow {
+                data,
+                polynomial_size: self.polynomial_size,
+                glwe_size: self.glwe_size,
+                decomposition_level: self.decomposition_level,
+            })
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    pub fn glwe_size(&self) -> GlweSize {
+        self.glwe_size
+    }
+
+    pub fn row_count(&self) -> usize {
+        self.row_count
+    }
+
+    pub fn decomposition_level(&self) -> DecompositionLevel {
+        self.decomposition_level
+    }
+
+    pub fn data(self) -> C {
+        self.data
+    }
+}
+
+impl<C: Container<Element = u64>> NttGgswLevelRow<C> {
+    pub fn from_container(
+        data: C,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        decomposition_level: DecompositionLevel,
+    ) -> Self {
+        assert_eq!(data.container_len(), 2 * polynomial_size.0 * glwe_size.0);
+
+        Self {
+            data,
+            polynomial_size,
+            glwe_size,
+            decomposition_level,
+        }
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    pub fn glwe_size(&self) -> GlweSize {
+        self.glwe_size
+    }
+
+    pub fn decomposition_level(&self) -> DecompositionLevel {
+        self.decomposition_level
+    }
+
+    pub fn data(self) -> C {
+        self.data
+    }
+}
+
+impl<Cont> NttGgswCiphertext<Cont>
+where
+    Cont: ContainerMut<Element = u64>,
+{
+    /// Fill a GGSW ciphertext with the NTT of a GGSW ciphertext in the standard domain.
+    pub fn fill_with_forward_ntt<ContGgsw>(
+        &mut self,
+        coef_ggsw: &GgswCiphertext<ContGgsw>,
+        ntt: NttView<'_>,
+    ) where
+        ContGgsw: Container<Element = u64>,
+    {
+        debug_assert_eq!(coef_ggsw.polynomial_size(), self.polynomial_size());
+        let poly_size = coef_ggsw.polynomial_size().0;
+
+        for (ntt_poly, coef_poly) in izip!(
+            self.data.as_mut().chunks_exact_mut(2 * poly_size),
+            coef_ggsw.as_ref().chunks_exact(poly_size)
+        ) {
+            ntt.forward_as_integer(ntt_poly, coef_poly);
+        }
+    }
+}
+
+/// Return the required memory for [`add_external_product_assign`].
+pub fn add_external_product_assign_scratch(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+) -> Result<StackReq, SizeOverflow> {
+    let align = CACHELINE_ALIGN;
+    let standard_scratch =
+        StackReq::try_new_aligned::<u64>(glwe_size.0 * polynomial_size.0, align)?;
+    let ntt_scratch = StackReq::try_new_aligned::<u64>(glwe_size.0 * 2 * polynomial_size.0, align)?;
+    let ntt_scratch_single = StackReq::try_new_aligned::<u64>(2 * polynomial_size.0, align)?;
+
+    let substack1 = ntt_scratch_single.try_and(standard_scratch)?;
+    let substack0 = substack1.try_and(standard_scratch)?;
+    substack0.try_and(ntt_scratch)
+}
+
+/// Compute the external product of `ggsw` and `glwe`, and add the result to `out`.
+///
+/// All the computations are done on integers, the result is the same on all platforms.
+pub fn add_external_product_assign<ContOut, ContGgsw, ContGlwe>(
+    out: &mut GlweCiphertext<ContOut>,
+    ggsw: &NttGgswCiphertext<ContGgsw>,
+    glwe: &GlweCiphertext<ContGlwe>,
+    ntt: NttView<'_>,
+    stack: PodStack<'_>,
+) where
+    ContOut: ContainerMut<Element = u64>,
+    ContGgsw: Container<Element = u64>,
+    ContGlwe: Container<Element = u64>,
+{
+    fn implementation(
+        mut out: GlweCiphertext<&mut [u64]>,
+        ggsw: NttGgswCiphertext<&[u64]>,
+        glwe: GlweCiphertext<&[u64]>,
+        ntt: NttView<'_>,
+        stack: PodStack<'_>,
+    ) {
+        // we check that the polynomial sizes match
+        debug_assert_eq!(ggsw.polynomial_size(), glwe.polynomial_size());
+        debug_assert_eq!(ggsw.polynomial_size(), out.polynomial_size());
+        // we check that the glwe sizes match
+        debug_assert_eq!(ggsw.glwe_size(), glwe.glwe_size());
+        debug_assert_eq!(ggsw.glwe_size(), out.glwe_size());
+
+        debug_assert_eq!(glwe.ciphertext_modulus(), out.ciphertext_modulus());
+
+        let align = CACHELINE_ALIGN;
+        let poly_size = ggsw.polynomial_size().0;
+        let ntt_poly_size = 2 * poly_size;
+
+        // we round the input mask and body
+        let decomposer = SignedDecomposer::<u64>::new(
+            ggsw.decomposition_base_log(),
+            ggsw.decomposition_level_count(),
+        );
+
+        let (mut output_ntt_buffer, mut substack0) =
+            stack.make_aligned_raw::<u64>(ntt_poly_size * ggsw.glwe_size().0, align);
+        output_ntt_buffer.fill(0);
+
+        {
+            // ------------------------------------------------------ EXTERNAL PRODUCT IN NTT DOMAIN
+            // In this section, we perform the external product in the NTT domain, and accumulate
+            // the result in the output_ntt_buffer variable.
+            let (mut decomposition, mut substack1) = TensorSignedDecompositionLendingIter::new(
+                glwe.as_ref()
+                    .iter()
+                    .map(|s| decomposer.closest_representable(*s)),
+                DecompositionBaseLog(decomposer.base_log),
+                DecompositionLevelCount(decomposer.level_count),
+                substack0.rb_mut(),
+            );
+
+            // We loop through the levels (we reverse to match the order of the decomposition
+            // iterator.)
+            for ggsw_decomp_matrix in ggsw.into_levels().rev() {
+                // We retrieve the decomposition of this level.
+                let (glwe_level, glwe_decomp_term, mut substack2) =
+                    collect_next_term(&mut decomposition, &mut substack1, align);
+                debug_assert_eq!(ggsw_decomp_matrix.decomposition_level(), glwe_level);
+
+                // For each level we add the product between every row of the level matrix, and
+                // the corresponding polynomial of the glwe decomposition, to the output.
+                for (ggsw_row, glwe_poly) in izip!(
+                    ggsw_decomp_matrix.into_rows(),
+                    glwe_decomp_term.chunks_exact(poly_size)
+                ) {
+                    let (mut ntt_poly, _) = substack2
+                        .rb_mut()
+                        .make_aligned_raw::<u64>(ntt_poly_size, align);
+                    // We perform the forward ntt for the glwe polynomial
+                    ntt.forward_as_integer(&mut ntt_poly, glwe_poly);
+                    // Now we loop through the polynomials of the output, and add the
+                    // corresponding product of polynomials.
+                    for (output_ntt, ggsw_poly) in izip!(
+                        output_ntt_buffer.chunks_exact_mut(ntt_poly_size),
+                        ggsw_row.data.chunks_exact(ntt_poly_size)
+                    ) {
+                        ntt.add_mul_assign(output_ntt, ggsw_poly, &ntt_poly);
+                    }
+                }
+            }
+        }
+
+        // --------------------------------------------  TRANSFORMATION OF RESULT TO STANDARD DOMAIN
+        // In this section, we bring the result from the NTT domain, back to the standard domain,
+        // and add it to the output.
+        for (mut out, output_ntt) in izip!(
+            out.as_mut_polynomial_list().iter_mut(),
+            output_ntt_buffer.chunks_exact_mut(ntt_poly_size),
+        ) {
+            ntt.add_backward_as_integer(out.as_mut(), output_ntt);
+        }
+    }
+
+    implementation(
+        out.as_mut_view(),
+        ggsw.as_view(),
+        glwe.as_view(),
+        ntt,
+        stack,
+    )
+}
+
+fn collect_next_term<'a>(
+    decomposition: &mut TensorSignedDecompositionLendingIter<'_, u64>,
+    substack1: &'a mut PodStack,
+    align: usize,
+) -> (
+    DecompositionLevel,
+    dyn_stack::DynArray<'a, u64>,
+    PodStack<'a>,
+) {
+    let (glwe_level, _, glwe_decomp_term) = decomposition.next_term().unwrap();
+    let (glwe_decomp_term, substack2) = substack1.rb_mut().collect_aligned(align, glwe_decomp_term);
+    (glwe_level, glwe_decomp_term, substack2)
+}
+
+/// Return the required memory for [`cmux`].
+pub fn cmux_scratch(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+) -> Result<StackReq, SizeOverflow> {
+    add_external_product_assign_scratch(glwe_size, polynomial_size)
+}
+
+/// This cmux mutates both ct1 and ct0. The result is in ct0 after the method was called.
+pub fn cmux<ContCt0, ContCt1, ContGgsw>(
+    ct0: &mut GlweCiphertext<ContCt0>,
+    ct1: &mut GlweCiphertext<ContCt1>,
+    ggsw: &NttGgswCiphertext<ContGgsw>,
+    ntt: NttView<'_>,
+    stack: PodStack<'_>,
+) where
+    ContCt0: ContainerMut<Element = u64>,
+    ContCt1: ContainerMut<Element = u64>,
+    ContGgsw: Container<Element = u64>,
+{
+    for (c1, c0) in izip!(ct1.as_mut(), ct0.as_ref()) {
+        *c1 = c1.wrapping_sub(*c0);
+    }
+    add_external_product_assign(ct0, ggsw, ct1, ntt, stack);
+}
@@ -0,0 +1,5 @@
+pub mod bootstrap;
+pub mod ggsw;
+
+#[cfg(test)]
+pub mod tests;
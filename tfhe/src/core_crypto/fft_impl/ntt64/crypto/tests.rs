@@ -0,0 +1,11 @@
+use crate::core_crypto::fft_impl::common::tests::test_bootstrap_generic;
+use crate::core_crypto::fft_impl::ntt64::crypto::bootstrap::NttLweBootstrapKeyOwned;
+use crate::core_crypto::prelude::*;
+
+#[test]
+fn test_bootstrap_u64() {
+    test_bootstrap_generic::<u64, NttLweBootstrapKeyOwned>(
+        StandardDev(0.000007069849454709433),
+        StandardDev(0.00000000000000029403601535432533),
+    );
+}
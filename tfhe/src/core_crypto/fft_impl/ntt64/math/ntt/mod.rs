@@ -0,0 +1,349 @@
+use crate::core_crypto::commons::parameters::PolynomialSize;
+use crate::core_crypto::commons::utils::izip;
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock, RwLock};
+
+#[cfg(test)]
+mod tests;
+
+/// The primes of the NTT.
+///
+/// They are smaller than `2^62`, and congruent to `1` modulo `2^17`, so that they have the `2N`-th
+/// roots of unity needed by the negacyclic transform of all the polynomial sizes up to
+/// [`MAX_POLYNOMIAL_SIZE`].
+pub const PRIMES: [u64; 2] = [0x3fff_ffff_ffe8_0001, 0x3fff_ffff_ffbe_0001];
+
+/// The largest polynomial size supported by the NTT.
+pub const MAX_POLYNOMIAL_SIZE: PolynomialSize = PolynomialSize(1 << 16);
+
+/// The number of bits of the largest integer which can be recovered from its residues modulo the
+/// [`PRIMES`], as a signed value.
+///
+/// The products of polynomials are exact as long as the absolute values of their coefficients
+/// stay below `2^RECOVERED_BITS`.
+pub const RECOVERED_BITS: usize = 122;
+
+// A quadratic non-residue modulo both primes, the 2N-th roots of unity are obtained by raising it
+// to the power (p - 1) / 2N
+const NON_RESIDUE: u64 = 3;
+
+/// Arithmetic modulo one of the [`PRIMES`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct PrimeModulus {
+    p: u64,
+    // floor(2^124 / p), for the Barrett reduction of the products
+    barrett: u64,
+}
+
+impl PrimeModulus {
+    fn new(p: u64) -> Self {
+        debug_assert!(p > 1 << 61 && p < 1 << 62);
+        Self {
+            p,
+            barrett: ((1u128 << 124) / p as u128) as u64,
+        }
+    }
+
+    #[inline(always)]
+    fn add(self, lhs: u64, rhs: u64) -> u64 {
+        let sum = lhs + rhs;
+        if sum >= self.p {
+            sum - self.p
+        } else {
+            sum
+        }
+    }
+
+    #[inline(always)]
+    fn sub(self, lhs: u64, rhs: u64) -> u64 {
+        if lhs >= rhs {
+            lhs - rhs
+        } else {
+            lhs + self.p - rhs
+        }
+    }
+
+    #[inline(always)]
+    fn mul(self, lhs: u64, rhs: u64) -> u64 {
+        let product = lhs as u128 * rhs as u128;
+        // The estimated quotient is at most 2 below the true one
+        let quotient = (((product >> 60) * self.barrett as u128) >> 64) as u64;
+        let mut remainder = (product as u64).wrapping_sub(quotient.wrapping_mul(self.p));
+        if remainder >= self.p {
+            remainder -= self.p;
+        }
+        if remainder >= self.p {
+            remainder -= self.p;
+        }
+        remainder
+    }
+
+    fn pow(self, mut base: u64, mut exponent: u64) -> u64 {
+        let mut result = 1;
+        while exponent != 0 {
+            if exponent & 1 == 1 {
+                result = self.mul(result, base);
+            }
+            base = self.mul(base, base);
+            exponent >>= 1;
+        }
+        result
+    }
+
+    fn inv(self, value: u64) -> u64 {
+        self.pow(value, self.p - 2)
+    }
+
+    /// Return the residue of an integer interpreted as a signed value.
+    #[inline(always)]
+    fn reduce_signed(self, value: u64) -> u64 {
+        let value = value as i64;
+        let residue = value.unsigned_abs() % self.p;
+        if value < 0 && residue != 0 {
+            self.p - residue
+        } else {
+            residue
+        }
+    }
+}
+
+/// The negacyclic NTT modulo one prime.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct PrimePlan {
+    modulus: PrimeModulus,
+    // Powers of the 2N-th root of unity, in bit reversed order
+    twiddles: Box<[u64]>,
+    // Powers of the inverse of the 2N-th root of unity, in bit reversed order
+    inv_twiddles: Box<[u64]>,
+    // Inverse of N
+    n_inv: u64,
+}
+
+fn bit_reverse(value: usize, log_n: u32) -> usize {
+    if log_n == 0 {
+        0
+    } else {
+        value.reverse_bits() >> (usize::BITS - log_n)
+    }
+}
+
+impl PrimePlan {
+    fn new(p: u64, n: usize) -> Self {
+        let modulus = PrimeModulus::new(p);
+        let root = modulus.pow(NON_RESIDUE, (p - 1) / (2 * n as u64));
+        debug_assert_eq!(modulus.pow(root, n as u64), p - 1);
+        let inv_root = modulus.inv(root);
+
+        let log_n = n.ilog2();
+        let mut twiddles = vec![0; n].into_boxed_slice();
+        let mut inv_twiddles = vec![0; n].into_boxed_slice();
+        let (mut power, mut inv_power) = (1, 1);
+        for i in 0..n {
+            let index = bit_reverse(i, log_n);
+            twiddles[index] = power;
+            inv_twiddles[index] = inv_power;
+            power = modulus.mul(power, root);
+            inv_power = modulus.mul(inv_power, inv_root);
+        }
+
+        Self {
+            modulus,
+            twiddles,
+            inv_twiddles,
+            n_inv: modulus.inv(n as u64),
+        }
+    }
+
+    /// Cooley-Tukey transform with the twisting by the powers of the 2N-th root merged in the
+    /// butterflies, the output is in bit reversed order.
+    fn forward(&self, data: &mut [u64]) {
+        let modulus = self.modulus;
+        let n = data.len();
+        let mut t = n;
+        let mut m = 1;
+        while m < n {
+            t /= 2;
+            for (i, chunk) in data.chunks_exact_mut(2 * t).enumerate() {
+                let twiddle = self.twiddles[m + i];
+                let (lo, hi) = chunk.split_at_mut(t);
+                for (lo, hi) in izip!(lo, hi) {
+                    let u = *lo;
+                    let v = modulus.mul(*hi, twiddle);
+                    *lo = modulus.add(u, v);
+                    *hi = modulus.sub(u, v);
+                }
+            }
+            m *= 2;
+        }
+    }
+
+    /// Gentleman-Sande transform, inverse of [`Self::forward`].
+    fn backward(&self, data: &mut [u64]) {
+        let modulus = self.modulus;
+        let n = data.len();
+        let mut t = 1;
+        let mut m = n;
+        while m > 1 {
+            let h = m / 2;
+            for (i, chunk) in data.chunks_exact_mut(2 * t).enumerate() {
+                let twiddle = self.inv_twiddles[h + i];
+                let (lo, hi) = chunk.split_at_mut(t);
+                for (lo, hi) in izip!(lo, hi) {
+                    let u = *lo;
+                    let v = *hi;
+                    *lo = modulus.add(u, v);
+                    *hi = modulus.mul(modulus.sub(u, v), twiddle);
+                }
+            }
+            t *= 2;
+            m = h;
+        }
+        for value in data.iter_mut() {
+            *value = modulus.mul(*value, self.n_inv);
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Plan {
+    primes: [PrimePlan; 2],
+    // Inverse of the first prime modulo the second one, for the recombination
+    p0_inv: u64,
+}
+
+/// Negacyclic Number Theoretic Transform. See [`NttView`] for transform functions.
+///
+/// The polynomial products are computed modulo two primes of 62 bits, and recombined with the
+/// Chinese remainder theorem. All the computations are done on integers, so unlike the floating
+/// point [`Fft`](crate::core_crypto::fft_impl::fft64::math::fft::Fft), the results are exact and
+/// do not depend on the CPU the code runs on.
+///
+/// A polynomial of size `N` in the NTT domain is made of `2N` coefficients: its `N` residues
+/// modulo the first prime, followed by its `N` residues modulo the second one.
+#[derive(Clone, Debug)]
+pub struct Ntt {
+    plan: Arc<Plan>,
+}
+
+/// View type for [`Ntt`].
+#[derive(Clone, Copy, Debug)]
+pub struct NttView<'a> {
+    plan: &'a Plan,
+}
+
+type PlanMap = RwLock<HashMap<usize, Arc<Plan>>>;
+static PLANS: OnceLock<PlanMap> = OnceLock::new();
+
+impl Ntt {
+    /// Polynomial of size `size`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is not a power of two, or is larger than [`MAX_POLYNOMIAL_SIZE`].
+    pub fn new(size: PolynomialSize) -> Self {
+        let n = size.0;
+        assert!(
+            n.is_power_of_two() && n <= MAX_POLYNOMIAL_SIZE.0,
+            "The NTT requires a power of two polynomial size smaller than {}, got {n}",
+            MAX_POLYNOMIAL_SIZE.0
+        );
+
+        let plans = PLANS.get_or_init(|| RwLock::new(HashMap::new()));
+        if let Some(plan) = plans.read().unwrap().get(&n) {
+            return Self { plan: plan.clone() };
+        }
+
+        let plan = plans
+            .write()
+            .unwrap()
+            .entry(n)
+            .or_insert_with(|| {
+                let primes = PRIMES.map(|p| PrimePlan::new(p, n));
+                let p0_inv = primes[1].modulus.inv(PRIMES[0] % PRIMES[1]);
+                Arc::new(Plan { primes, p0_inv })
+            })
+            .clone();
+        Self { plan }
+    }
+
+    #[inline]
+    pub fn as_view(&self) -> NttView<'_> {
+        NttView { plan: &self.plan }
+    }
+}
+
+impl NttView<'_> {
+    pub fn polynomial_size(self) -> PolynomialSize {
+        PolynomialSize(self.plan.primes[0].twiddles.len())
+    }
+
+    /// Perform a forward negacyclic NTT of a polynomial, its coefficients being interpreted as
+    /// signed integers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ntt` is not twice as long as `standard`, or if `standard` does not have the
+    /// polynomial size of the NTT.
+    pub fn forward_as_integer(self, ntt: &mut [u64], standard: &[u64]) {
+        let n = self.polynomial_size().0;
+        assert_eq!(standard.len(), n);
+        assert_eq!(ntt.len(), 2 * n);
+
+        for (prime, ntt) in izip!(self.plan.primes.iter(), ntt.chunks_exact_mut(n)) {
+            for (out, input) in izip!(ntt.iter_mut(), standard) {
+                *out = prime.modulus.reduce_signed(*input);
+            }
+            prime.forward(ntt);
+        }
+    }
+
+    /// Perform an inverse negacyclic NTT, and add the result to `standard`, wrapping around
+    /// `2^64`.
+    ///
+    /// `ntt` is used as a buffer and left in an unspecified state. The result is exact as long as
+    /// the coefficients of the polynomial fit in [`RECOVERED_BITS`] bits as signed integers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ntt` is not twice as long as `standard`, or if `standard` does not have the
+    /// polynomial size of the NTT.
+    pub fn add_backward_as_integer(self, standard: &mut [u64], ntt: &mut [u64]) {
+        let n = self.polynomial_size().0;
+        assert_eq!(standard.len(), n);
+        assert_eq!(ntt.len(), 2 * n);
+
+        let (ntt0, ntt1) = ntt.split_at_mut(n);
+        self.plan.primes[0].backward(ntt0);
+        self.plan.primes[1].backward(ntt1);
+
+        let [p0, p1] = PRIMES;
+        let modulus1 = self.plan.primes[1].modulus;
+        for (out, r0, r1) in izip!(standard.iter_mut(), ntt0.iter(), ntt1.iter()) {
+            // Garner's recombination: x = r0 + p0 * v1, with v1 centered around 0 so that the
+            // negative values are recovered
+            let v1 = modulus1.mul(modulus1.sub(*r1, *r0 % p1), self.plan.p0_inv);
+            let v1 = if v1 > p1 / 2 { v1.wrapping_sub(p1) } else { v1 };
+            *out = out.wrapping_add(r0.wrapping_add(v1.wrapping_mul(p0)));
+        }
+    }
+
+    /// Add the pointwise product of two polynomials in the NTT domain to `out`.
+    pub fn add_mul_assign(self, out: &mut [u64], lhs: &[u64], rhs: &[u64]) {
+        let n = self.polynomial_size().0;
+        assert_eq!(out.len(), 2 * n);
+        assert_eq!(lhs.len(), 2 * n);
+        assert_eq!(rhs.len(), 2 * n);
+
+        for (prime, out, lhs, rhs) in izip!(
+            self.plan.primes.iter(),
+            out.chunks_exact_mut(n),
+            lhs.chunks_exact(n),
+            rhs.chunks_exact(n),
+        ) {
+            let modulus = prime.modulus;
+            for (out, lhs, rhs) in izip!(out.iter_mut(), lhs, rhs) {
+                *out = modulus.add(*out, modulus.mul(*lhs, *rhs));
+            }
+        }
+    }
+}
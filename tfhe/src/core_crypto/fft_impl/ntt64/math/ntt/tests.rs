@@ -0,0 +1,69 @@
+use super::*;
+use crate::core_crypto::algorithms::polynomial_algorithms::polynomial_wrapping_mul;
+use crate::core_crypto::commons::test_tools::new_random_generator;
+use crate::core_crypto::entities::Polynomial;
+
+#[test]
+fn test_roundtrip() {
+    let mut generator = new_random_generator();
+    for size_log in 0..=14 {
+        let size = 1_usize << size_log;
+
+        let ntt = Ntt::new(PolynomialSize(size));
+        let ntt = ntt.as_view();
+
+        let mut poly = vec![0u64; size];
+        let mut roundtrip = vec![0u64; size];
+        let mut ntt_poly = vec![0u64; 2 * size];
+
+        for x in poly.iter_mut() {
+            *x = generator.random_uniform();
+        }
+
+        ntt.forward_as_integer(&mut ntt_poly, &poly);
+        ntt.add_backward_as_integer(&mut roundtrip, &mut ntt_poly);
+
+        assert_eq!(poly, roundtrip);
+    }
+}
+
+#[test]
+fn test_product() {
+    let mut generator = new_random_generator();
+    for size_log in 0..=11 {
+        for _ in 0..10 {
+            let size = 1_usize << size_log;
+
+            let ntt = Ntt::new(PolynomialSize(size));
+            let ntt = ntt.as_view();
+
+            let mut poly0 = Polynomial::new(0u64, PolynomialSize(size));
+            let mut poly1 = Polynomial::new(0u64, PolynomialSize(size));
+
+            // The second polynomial has small signed coefficients, like the decomposition of a
+            // GLWE ciphertext in an external product
+            let integer_magnitude = 24;
+            for (x, y) in izip!(poly0.as_mut().iter_mut(), poly1.as_mut().iter_mut()) {
+                *x = generator.random_uniform();
+                let y_unsigned: u64 = generator.random_uniform();
+                *y = ((y_unsigned as i64) >> (64 - integer_magnitude)) as u64;
+            }
+
+            let mut ntt0 = vec![0u64; 2 * size];
+            let mut ntt1 = vec![0u64; 2 * size];
+            let mut ntt_product = vec![0u64; 2 * size];
+
+            ntt.forward_as_integer(&mut ntt0, poly0.as_ref());
+            ntt.forward_as_integer(&mut ntt1, poly1.as_ref());
+            ntt.add_mul_assign(&mut ntt_product, &ntt0, &ntt1);
+
+            let mut convolution_from_ntt = Polynomial::new(0u64, PolynomialSize(size));
+            ntt.add_backward_as_integer(convolution_from_ntt.as_mut(), &mut ntt_product);
+
+            let mut convolution_from_naive = Polynomial::new(0u64, PolynomialSize(size));
+            polynomial_wrapping_mul(&mut convolution_from_naive, &poly0, &poly1);
+
+            assert_eq!(convolution_from_naive, convolution_from_ntt);
+        }
+    }
+}
@@ -0,0 +1,15 @@
+//! Exact negacyclic products of polynomials with 64 bits coefficients, with a Number Theoretic
+//! Transform.
+//!
+//! The Fourier transforms of [`fft64`](super::fft64) are computed with floating point numbers, so
+//! their results can differ in the least significant bits between two CPUs, e.g. an x86 CPU using
+//! FMA instructions and an aarch64 one. The NTT computes the same products with integer
+//! arithmetic only, so the bootstraps computed with an
+//! [`NttLweBootstrapKey`](crypto::bootstrap::NttLweBootstrapKey) are bit-exact on all platforms.
+//!
+//! As the products are exact, the NTT also supports the non-native power of two ciphertext moduli
+//! without losing precision: the FFT only computes the 53 most significant bits of the products,
+//! and needs to round its results to the modulus afterwards.
+
+pub mod crypto;
+pub mod math;
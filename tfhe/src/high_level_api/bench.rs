@@ -0,0 +1,160 @@
+//! This module measures the performance of the server keys on the current machine
+//!
+//! - [run] measures the keys set with [set_server_key](crate::set_server_key) on the current
+//!   thread, [run_with_key] measures the given [ServerKey].
+//! - The measures are returned as a [BenchReport], which deployments can use to calibrate their
+//!   costs (e.g. gas prices or scheduler weights) at startup.
+//!
+//! As the cost of the operations does not depend on the encrypted values, the measures are done
+//! on trivial ciphertexts (for the boolean gates, on ciphertexts that are not flagged as trivial,
+//! so that they are actually bootstrapped), no client key is needed.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::boolean::ciphertext::Ciphertext as BooleanCiphertext;
+use crate::boolean::server_key::BooleanGate;
+use crate::core_crypto::prelude::{CiphertextModulus, LweCiphertextOwned, PBSOrder};
+use crate::high_level_api::global_state::with_internal_keys;
+use crate::high_level_api::keys::ServerKey;
+use crate::integer::RadixCiphertext;
+
+/// Configuration of the measures done by [run] and [run_with_key].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Number of timed runs of each latency measure, the median is reported
+    pub samples: usize,
+    /// Number of gates evaluated in parallel to measure the gate throughput
+    pub gate_batch_size: usize,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            samples: 10,
+            gate_batch_size: 4 * rayon::current_num_threads(),
+        }
+    }
+}
+
+/// Performance of the server keys on the current machine.
+///
+/// A measure is `None` when the key it needs is not part of the [ServerKey].
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::bench::{run, BenchConfig};
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder};
+///
+/// let config = ConfigBuilder::all_disabled()
+///     .enable_default_integers()
+///     .build();
+///
+/// let (_client_key, server_key) = generate_keys(config);
+/// set_server_key(server_key);
+///
+/// let report = run(&BenchConfig {
+///     samples: 1,
+///     gate_batch_size: 1,
+/// });
+///
+/// assert!(report.pbs_latency.is_some());
+/// assert!(report.add_64_latency.is_some());
+/// // Booleans are not enabled in the config
+/// assert!(report.gate_throughput.is_none());
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct BenchReport {
+    /// Median latency of a programmable bootstrapping of the integer key
+    pub pbs_latency: Option<Duration>,
+    /// Number of boolean gates evaluated per second, using all the threads
+    pub gate_throughput: Option<f64>,
+    /// Median latency of an addition of two 64 bits integers
+    pub add_64_latency: Option<Duration>,
+    /// Median latency of a multiplication of two 64 bits integers
+    pub mul_64_latency: Option<Duration>,
+    /// Number of threads available to the parallelized operations
+    pub num_threads: usize,
+}
+
+/// Measures the server key set on the current thread.
+///
+/// See [BenchReport] for usage.
+pub fn run(config: &BenchConfig) -> BenchReport {
+    with_internal_keys(|server_key| run_with_key(server_key, config))
+}
+
+/// Measures the given server key.
+///
+/// See [BenchReport] for usage.
+pub fn run_with_key(server_key: &ServerKey, config: &BenchConfig) -> BenchReport {
+    let mut report = BenchReport {
+        pbs_latency: None,
+        gate_throughput: None,
+        add_64_latency: None,
+        mul_64_latency: None,
+        num_threads: rayon::current_num_threads(),
+    };
+
+    if let Some(integer_key) = server_key.integer_key.key.as_ref() {
+        let shortint_key = &integer_key.key;
+        let lut = shortint_key.generate_lookup_table(|x| x);
+        let ct = shortint_key.create_trivial(0);
+        report.pbs_latency = Some(median_latency(config.samples, || {
+            black_box(shortint_key.apply_lookup_table(&ct, &lut));
+        }));
+
+        let num_blocks = 64 / shortint_key.message_modulus.0.ilog2() as usize;
+        let lhs: RadixCiphertext = integer_key.create_trivial_radix(0u64, num_blocks);
+        let rhs: RadixCiphertext = integer_key.create_trivial_radix(0u64, num_blocks);
+        report.add_64_latency = Some(median_latency(config.samples, || {
+            black_box(integer_key.add_parallelized(&lhs, &rhs));
+        }));
+        report.mul_64_latency = Some(median_latency(config.samples, || {
+            black_box(integer_key.mul_parallelized(&lhs, &rhs));
+        }));
+    }
+
+    if let Some(gate_key) = server_key.boolean_key.gate_key() {
+        let lwe_size = match gate_key.pbs_order {
+            PBSOrder::KeyswitchBootstrap => gate_key
+                .key_switching_key
+                .input_key_lwe_dimension()
+                .to_lwe_size(),
+            PBSOrder::BootstrapKeyswitch => gate_key
+                .bootstrapping_key
+                .input_lwe_dimension()
+                .to_lwe_size(),
+        };
+        let ct = BooleanCiphertext::Encrypted(LweCiphertextOwned::new(
+            0u32,
+            lwe_size,
+            CiphertextModulus::new_native(),
+        ));
+        let batch_size = config.gate_batch_size.max(1);
+        let cts = vec![ct; batch_size];
+        let latency = median_latency(config.samples, || {
+            black_box(gate_key.batch_gates(BooleanGate::And, &cts, &cts));
+        });
+        report.gate_throughput = Some(batch_size as f64 / latency.as_secs_f64());
+    }
+
+    report
+}
+
+/// Runs `func` once to warm up, then returns the median of `samples` timed runs
+fn median_latency<F: FnMut()>(samples: usize, mut func: F) -> Duration {
+    func();
+
+    let mut latencies = (0..samples.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            func();
+            start.elapsed()
+        })
+        .collect::<Vec<_>>();
+    latencies.sort_unstable();
+
+    latencies[latencies.len() / 2]
+}
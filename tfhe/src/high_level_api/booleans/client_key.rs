@@ -21,6 +21,11 @@ impl FheBoolClientKey {
 
         Self { key }
     }
+
+    /// The number of GGSW ciphertexts of the bootstrapping key of the server key
+    pub(crate) fn bootstrapping_key_ggsw_count(&self) -> usize {
+        self.key.parameters.lwe_dimension.0
+    }
 }
 
 impl From<FheBoolParameters> for FheBoolClientKey {
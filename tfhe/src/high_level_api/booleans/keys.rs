@@ -3,3 +3,12 @@ define_key_structs! {
         bool: FheBool,
     }
 }
+
+#[cfg(feature = "bench")]
+impl BooleanServerKey {
+    pub(in crate::high_level_api) fn gate_key(
+        &self,
+    ) -> Option<&crate::boolean::server_key::ServerKey> {
+        self.bool_key.as_ref().map(|bool_key| &bool_key.key)
+    }
+}
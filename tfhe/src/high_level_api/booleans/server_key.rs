@@ -1,6 +1,8 @@
 use super::client_key::FheBoolClientKey;
 use super::types::FheBool;
-use crate::boolean::server_key::{BinaryBooleanGates, CompressedServerKey, ServerKey};
+use crate::boolean::ciphertext::Ciphertext;
+use crate::boolean::server_key::{BinaryBooleanGates, BooleanGate, CompressedServerKey, ServerKey};
+use std::ops::ControlFlow;
 
 #[cfg_attr(all(doc, not(doctest)), cfg(feature = "boolean"))]
 #[derive(Clone, serde::Serialize, serde::Deserialize)]
@@ -15,6 +17,20 @@ impl FheBoolServerKey {
         }
     }
 
+    /// Generates the key, calling `on_ggsw_encrypted` each time one of the
+    /// [bootstrapping_key_ggsw_count](FheBoolClientKey::bootstrapping_key_ggsw_count) GGSW
+    /// ciphertexts of the bootstrapping key is encrypted.
+    ///
+    /// Returns `None` if `on_ggsw_encrypted` stopped the generation.
+    pub(crate) fn new_with_progress(
+        key: &FheBoolClientKey,
+        on_ggsw_encrypted: &(dyn Fn() -> ControlFlow<()> + Sync),
+    ) -> Option<Self> {
+        Some(Self {
+            key: ServerKey::new_with_progress(&key.key, on_ggsw_encrypted)?,
+        })
+    }
+
     pub(in crate::high_level_api::booleans) fn and(&self, lhs: &FheBool, rhs: &FheBool) -> FheBool {
         let ciphertext = self.key.and(&lhs.ciphertext, &rhs.ciphertext);
         FheBool::new(ciphertext)
@@ -53,6 +69,29 @@ impl FheBoolServerKey {
         FheBool::new(ciphertext)
     }
 
+    /// Reduces the ciphertexts with the gate, as a balanced tree where each level is evaluated
+    /// in parallel.
+    ///
+    /// The gate must be associative and commutative.
+    pub(in crate::high_level_api::booleans) fn tree_reduce(
+        &self,
+        gate: BooleanGate,
+        mut ciphertexts: Vec<Ciphertext>,
+    ) -> Option<Ciphertext> {
+        while ciphertexts.len() > 1 {
+            let remainder = if ciphertexts.len() % 2 == 1 {
+                ciphertexts.pop()
+            } else {
+                None
+            };
+            let rhs = ciphertexts.split_off(ciphertexts.len() / 2);
+            ciphertexts = self.key.batch_gates(gate, &ciphertexts, &rhs);
+            ciphertexts.extend(remainder);
+        }
+
+        ciphertexts.pop()
+    }
+
     #[allow(dead_code)]
     pub(in crate::high_level_api::booleans) fn mux(
         &self,
@@ -3,6 +3,7 @@
 // if they are correct
 #![allow(clippy::eq_op)]
 #![allow(clippy::bool_assert_comparison)]
+use rand::Rng;
 use std::ops::{BitAnd, BitOr, BitXor, Not};
 
 use crate::high_level_api::prelude::*;
@@ -227,3 +228,52 @@ fn test_decompressed_public_key_encrypt() {
     let clear: bool = a.decrypt(&client_key);
     assert_eq!(clear, true);
 }
+
+#[test]
+fn test_all_any() {
+    let keys = setup_static_default();
+
+    let mut rng = rand::thread_rng();
+
+    for num_values in [0, 1, 2, 5, 8] {
+        let clears: Vec<bool> = (0..num_values).map(|_| rng.gen()).collect();
+        let values: Vec<FheBool> = clears
+            .iter()
+            .map(|clear| FheBool::encrypt(*clear, &keys))
+            .collect();
+
+        let all = FheBool::all(&values);
+        assert_eq!(all.decrypt(&keys), clears.iter().all(|clear| *clear));
+
+        let any = FheBool::any(&values);
+        assert_eq!(any.decrypt(&keys), clears.iter().any(|clear| *clear));
+    }
+
+    // Trivial values short circuit the reduction
+    let encrypted = FheBool::encrypt(true, &keys);
+    let values = [encrypted.clone(), FheBool::encrypt_trivial(false)];
+    assert_eq!(FheBool::all(&values).decrypt(&keys), false);
+    assert_eq!(FheBool::any(&values).decrypt(&keys), true);
+
+    let values = [FheBool::encrypt_trivial(true), encrypted];
+    assert_eq!(FheBool::all(&values).decrypt(&keys), true);
+    assert_eq!(FheBool::any(&values).decrypt(&keys), true);
+}
+
+#[test]
+fn test_assign_operations() {
+    let keys = setup_static_default();
+
+    let ttrue = FheBool::encrypt(true, &keys);
+    let ffalse = FheBool::encrypt(false, &keys);
+
+    let mut a = ttrue.clone();
+    a &= &ffalse;
+    assert_eq!(a.decrypt(&keys), false);
+    a |= ttrue.clone();
+    assert_eq!(a.decrypt(&keys), true);
+    a ^= &ttrue;
+    assert_eq!(a.decrypt(&keys), false);
+    a ^= ttrue;
+    assert_eq!(a.decrypt(&keys), true);
+}
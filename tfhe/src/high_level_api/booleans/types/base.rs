@@ -1,7 +1,9 @@
 use std::borrow::Borrow;
-use std::ops::{BitAnd, BitOr, BitXor};
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign};
 
 use crate::boolean::ciphertext::{Ciphertext, CompressedCiphertext};
+use crate::boolean::server_key::BooleanGate;
+use crate::content_hash::{ContentHash, ContentHasher};
 use crate::errors::{Type, UnwrapResultExt};
 use crate::CompressedPublicKey;
 use serde::{Deserialize, Serialize};
@@ -78,6 +80,12 @@ pub struct FheBool {
     id: FheBoolId,
 }
 
+impl ContentHash for FheBool {
+    fn hash_content(&self, hasher: &mut ContentHasher) {
+        self.ciphertext.hash_content(hasher);
+    }
+}
+
 #[derive(Clone, Serialize, Deserialize)]
 pub struct CompressedFheBool {
     pub(in crate::high_level_api::booleans) ciphertext: CompressedCiphertext,
@@ -94,6 +102,88 @@ impl FheBool {
     pub fn nand(&self, rhs: &Self) -> Self {
         self.id.with_unwrapped_global(|key| key.nand(self, rhs))
     }
+
+    /// Returns an encryption of `true` if all the values are `true`.
+    ///
+    /// The values are combined as a balanced tree of `AND` gates, so the depth is logarithmic in
+    /// the number of values. Trivial values do not require any gate evaluation: trivial `true`
+    /// values are skipped and a trivial `false` makes the result a trivial `false`.
+    ///
+    /// An empty slice gives a trivial `true`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheBool};
+    ///
+    /// let config = ConfigBuilder::all_disabled().enable_default_bool().build();
+    /// let (client_key, server_key) = generate_keys(config);
+    /// set_server_key(server_key);
+    ///
+    /// let values = [true, true, false, true]
+    ///     .map(|value| FheBool::encrypt(value, &client_key));
+    ///
+    /// let result = FheBool::all(&values);
+    /// assert_eq!(result.decrypt(&client_key), false);
+    ///
+    /// let result = FheBool::all(&values[..2]);
+    /// assert_eq!(result.decrypt(&client_key), true);
+    /// ```
+    pub fn all(values: &[Self]) -> Self {
+        Self::reduce_with_gate(values, BooleanGate::And, true)
+    }
+
+    /// Returns an encryption of `true` if any of the values is `true`.
+    ///
+    /// The values are combined as a balanced tree of `OR` gates, so the depth is logarithmic in
+    /// the number of values. Trivial values do not require any gate evaluation: trivial `false`
+    /// values are skipped and a trivial `true` makes the result a trivial `true`.
+    ///
+    /// An empty slice gives a trivial `false`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheBool};
+    ///
+    /// let config = ConfigBuilder::all_disabled().enable_default_bool().build();
+    /// let (client_key, server_key) = generate_keys(config);
+    /// set_server_key(server_key);
+    ///
+    /// let values = [false, false, true, false]
+    ///     .map(|value| FheBool::encrypt(value, &client_key));
+    ///
+    /// let result = FheBool::any(&values);
+    /// assert_eq!(result.decrypt(&client_key), true);
+    ///
+    /// let result = FheBool::any(&values[..2]);
+    /// assert_eq!(result.decrypt(&client_key), false);
+    /// ```
+    pub fn any(values: &[Self]) -> Self {
+        Self::reduce_with_gate(values, BooleanGate::Or, false)
+    }
+
+    /// `identity` is the neutral element of the gate, its negation is absorbing
+    fn reduce_with_gate(values: &[Self], gate: BooleanGate, identity: bool) -> Self {
+        let mut encrypted = Vec::with_capacity(values.len());
+        for value in values {
+            match &value.ciphertext {
+                Ciphertext::Trivial(clear) if *clear == identity => {}
+                Ciphertext::Trivial(_) => return Self::new(Ciphertext::Trivial(!identity)),
+                Ciphertext::Encrypted(_) => encrypted.push(value.ciphertext.clone()),
+            }
+        }
+
+        if encrypted.len() <= 1 {
+            let ciphertext = encrypted.pop().unwrap_or(Ciphertext::Trivial(identity));
+            return Self::new(ciphertext);
+        }
+
+        let ciphertext = FheBoolId
+            .with_unwrapped_global(|key| key.tree_reduce(gate, encrypted))
+            .unwrap();
+        Self::new(ciphertext)
+    }
 }
 
 impl<B> FheEq<B> for FheBool
@@ -248,6 +338,24 @@ fhe_bool_impl_operation!(BitAnd(bitand) => and);
 fhe_bool_impl_operation!(BitOr(bitor) => or);
 fhe_bool_impl_operation!(BitXor(bitxor) => xor);
 
+macro_rules! fhe_bool_impl_operation_assign(
+    ($trait_name:ident($trait_method:ident) => $key_method:ident) => {
+        impl<B> $trait_name<B> for FheBool
+        where B: Borrow<FheBool>,
+        {
+            fn $trait_method(&mut self, rhs: B) {
+                *self = self.id.with_unwrapped_global(|key| {
+                  key.$key_method(self, rhs.borrow())
+                });
+            }
+        }
+    };
+);
+
+fhe_bool_impl_operation_assign!(BitAndAssign(bitand_assign) => and);
+fhe_bool_impl_operation_assign!(BitOrAssign(bitor_assign) => or);
+fhe_bool_impl_operation_assign!(BitXorAssign(bitxor_assign) => xor);
+
 impl ::std::ops::Not for FheBool {
     type Output = Self;
 
@@ -0,0 +1,162 @@
+//! This module defines CompressionReport
+//!
+//! - [CompressionReport] gives the serialized sizes of the keys and ciphertexts of a [Config], in
+//!   their original, compressed and compact forms.
+
+use crate::high_level_api::config::Config;
+use crate::high_level_api::keys::{
+    ClientKey, CompactPublicKey, CompressedPublicKey, CompressedServerKey, PublicKey,
+};
+use crate::serialized_size::SerializedSizeHint;
+
+#[cfg(feature = "boolean")]
+use crate::high_level_api::booleans::{CompressedFheBool, FheBool};
+#[cfg(feature = "integer")]
+use crate::high_level_api::integers::{
+    CompactFheUint16, CompactFheUint32, CompactFheUint64, CompactFheUint8, CompressedFheUint16,
+    CompressedFheUint32, CompressedFheUint64, CompressedFheUint8, FheUint16, FheUint32, FheUint64,
+    FheUint8,
+};
+#[cfg(any(feature = "boolean", feature = "integer"))]
+use crate::high_level_api::prelude::*;
+
+/// The serialized sizes, in bytes, of the different forms of an object.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SerializedSizes {
+    pub original: u64,
+    pub compressed: u64,
+    /// `None` when the object has no compact form, or when the parameters do not support it
+    pub compact: Option<u64>,
+}
+
+impl SerializedSizes {
+    /// Returns how many times the compressed form is smaller than the original one
+    pub fn compression_ratio(&self) -> f64 {
+        self.original as f64 / self.compressed as f64
+    }
+
+    /// Returns how many times the compact form is smaller than the original one
+    pub fn compact_ratio(&self) -> Option<f64> {
+        self.compact
+            .map(|compact| self.original as f64 / compact as f64)
+    }
+}
+
+/// Serialized sizes of the keys and ciphertexts of a [Config].
+///
+/// - `server_key` compares the [ServerKey](crate::ServerKey) to the [CompressedServerKey].
+/// - `public_key` compares the [PublicKey] to the [CompressedPublicKey], the compact size is the
+///   one of the [CompactPublicKey].
+/// - `ciphertexts` gives, for each type enabled in the config, the sizes of a ciphertext, of its
+///   compressed variant and of its compact variant.
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "integer")]
+/// # {
+/// use tfhe::{CompressionReport, ConfigBuilder};
+///
+/// let config = ConfigBuilder::all_disabled()
+///     .enable_default_integers_small()
+///     .build();
+///
+/// let report = CompressionReport::new(config);
+///
+/// assert!(report.server_key.compression_ratio() > 1.0);
+///
+/// let (name, sizes) = report.ciphertexts[0];
+/// assert_eq!(name, "FheUint8");
+/// assert!(sizes.compressed < sizes.original);
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct CompressionReport {
+    pub server_key: SerializedSizes,
+    pub public_key: SerializedSizes,
+    pub ciphertexts: Vec<(&'static str, SerializedSizes)>,
+}
+
+impl CompressionReport {
+    /// Generates the keys of the config, and a ciphertext of each enabled type, to measure them.
+    ///
+    /// This generates all the public keys of the config, so it takes about as much time and
+    /// memory as generating these keys.
+    pub fn new<C: Into<Config>>(config: C) -> Self {
+        let config = config.into();
+        let client_key = &ClientKey::generate(config.clone());
+
+        let server_key = SerializedSizes {
+            original: client_key.generate_server_key().serialized_size_hint(),
+            compressed: CompressedServerKey::new(client_key).serialized_size_hint(),
+            compact: None,
+        };
+
+        let compact_public_key = CompactPublicKey::try_new(client_key);
+        let public_key = SerializedSizes {
+            original: PublicKey::new(client_key).serialized_size_hint(),
+            compressed: CompressedPublicKey::new(client_key).serialized_size_hint(),
+            compact: compact_public_key
+                .as_ref()
+                .map(SerializedSizeHint::serialized_size_hint),
+        };
+
+        #[allow(unused_mut)]
+        let mut ciphertexts = Vec::new();
+
+        #[cfg(feature = "boolean")]
+        if config.boolean_config.bool_params.is_some() {
+            ciphertexts.push((
+                "FheBool",
+                SerializedSizes {
+                    original: FheBool::encrypt(false, client_key).serialized_size_hint(),
+                    compressed: CompressedFheBool::encrypt(false, client_key)
+                        .serialized_size_hint(),
+                    compact: None,
+                },
+            ));
+        }
+
+        #[cfg(feature = "integer")]
+        if config.integer_config.block_parameters.is_some() {
+            macro_rules! measure_integer_type {
+                ($($name:ident, $compressed:ident, $compact:ident, $clear:ty);* $(;)?) => {
+                    $(
+                        ciphertexts.push((
+                            stringify!($name),
+                            SerializedSizes {
+                                original: $name::encrypt(<$clear>::default(), client_key)
+                                    .serialized_size_hint(),
+                                compressed: $compressed::encrypt(
+                                    <$clear>::default(),
+                                    client_key,
+                                )
+                                .serialized_size_hint(),
+                                compact: compact_public_key.as_ref().map(|pk| {
+                                    $compact::encrypt(<$clear>::default(), pk)
+                                        .serialized_size_hint()
+                                }),
+                            },
+                        ));
+                    )*
+                };
+            }
+
+            measure_integer_type!(
+                FheUint8, CompressedFheUint8, CompactFheUint8, u8;
+                FheUint16, CompressedFheUint16, CompactFheUint16, u16;
+                FheUint32, CompressedFheUint32, CompactFheUint32, u32;
+                FheUint64, CompressedFheUint64, CompactFheUint64, u64;
+            );
+        }
+
+        #[cfg(not(any(feature = "boolean", feature = "integer")))]
+        let _ = config;
+
+        Self {
+            server_key,
+            public_key,
+            ciphertexts,
+        }
+    }
+}
@@ -12,6 +12,53 @@ pub struct Config {
     pub(crate) integer_config: IntegerConfig,
 }
 
+impl Config {
+    /// Returns a config simulating the computations on integers, for fast tests of applications.
+    ///
+    /// The integers use insecure
+    /// [simulation parameters](crate::shortint::parameters::parameters_simulator): all the
+    /// operations go through the same code paths as with the default parameters, with much
+    /// faster programmable bootstraps. The number of programmable bootstraps computed can be
+    /// read with [crate::shortint::engine::programmable_bootstrap_count].
+    ///
+    /// When the `boolean` feature is enabled, the boolean type uses the insecure
+    /// [simulation parameters](crate::boolean::parameters::PARAMETERS_SIMULATOR) as well.
+    ///
+    /// # Warning
+    ///
+    /// The keys generated from this config are **not secure**, they must only be used in tests.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, Config, FheUint32};
+    ///
+    /// let (client_key, server_key) = generate_keys(Config::simulator());
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint32::encrypt(u32::MAX, &client_key);
+    /// let b = FheUint32::encrypt(2u32, &client_key);
+    ///
+    /// let c = &a + &b;
+    /// let decrypted: u32 = c.decrypt(&client_key);
+    /// assert_eq!(decrypted, 1);
+    /// ```
+    #[cfg(feature = "integer")]
+    pub fn simulator() -> Self {
+        let builder = ConfigBuilder::all_disabled().enable_custom_integers(
+            crate::shortint::parameters::PARAM_SIMULATOR_MESSAGE_2_CARRY_2_KS_PBS,
+            None,
+        );
+
+        #[cfg(feature = "boolean")]
+        let builder =
+            builder.enable_custom_bool(crate::boolean::parameters::PARAMETERS_SIMULATOR.into());
+
+        builder.build()
+    }
+}
+
 /// The builder to create your config
 ///
 /// This struct is what you will to use to build your
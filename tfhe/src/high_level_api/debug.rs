@@ -0,0 +1,143 @@
+//! Shadow decryption of intermediate values, to debug computations during development.
+//!
+//! A [DebugContext] registers a [ClientKey] for the current thread. While it is alive,
+//! [debug_assert_decrypts_to](crate::debug_assert_decrypts_to) decrypts values and checks them
+//! against the expected clear values, and [DebugContext::report] describes the blocks of a value:
+//! message, carry, degree and noise.
+//!
+//! # Warning
+//!
+//! The side doing the computations must never have the client key in production, only use this
+//! with keys generated for development.
+
+use std::cell::RefCell;
+use std::fmt::{Debug, Write};
+
+use crate::high_level_api::keys::ClientKey;
+use crate::high_level_api::traits::FheDecrypt;
+
+thread_local! {
+    static DEBUG_CLIENT_KEY: RefCell<Option<ClientKey>> = RefCell::new(None);
+}
+
+/// Values that can be inspected by a [DebugContext]
+pub trait DebugDecrypt {
+    /// Returns a description of the blocks of the value, decrypted with `key`
+    fn debug_report(&self, key: &ClientKey) -> String;
+}
+
+/// Registers a development [ClientKey] for the current thread, until it is dropped.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{
+///     debug_assert_decrypts_to, generate_keys, set_server_key, ConfigBuilder, DebugContext,
+///     FheUint8,
+/// };
+///
+/// let config = ConfigBuilder::all_disabled()
+///     .enable_default_integers()
+///     .build();
+/// let (client_key, server_key) = generate_keys(config);
+/// set_server_key(server_key);
+///
+/// let _debug_context = DebugContext::new(client_key.clone());
+///
+/// let a = FheUint8::encrypt(27u8, &client_key);
+/// let b = FheUint8::encrypt(100u8, &client_key);
+///
+/// let c = &a + &b;
+/// debug_assert_decrypts_to!(c, 127u8);
+///
+/// let report = DebugContext::report(&c).unwrap();
+/// assert!(report.contains("degree"));
+/// ```
+pub struct DebugContext {
+    previous_key: Option<ClientKey>,
+}
+
+impl DebugContext {
+    pub fn new(client_key: ClientKey) -> Self {
+        let previous_key = DEBUG_CLIENT_KEY.with(|key| key.replace(Some(client_key)));
+        Self { previous_key }
+    }
+
+    /// Returns whether a debug key is registered for the current thread
+    pub fn is_active() -> bool {
+        DEBUG_CLIENT_KEY.with(|key| key.borrow().is_some())
+    }
+
+    /// Returns a description of the blocks of `value`, or None if no debug key is registered
+    pub fn report<T: DebugDecrypt>(value: &T) -> Option<String> {
+        DEBUG_CLIENT_KEY.with(|key| key.borrow().as_ref().map(|key| value.debug_report(key)))
+    }
+
+    /// Implementation of [debug_assert_decrypts_to](crate::debug_assert_decrypts_to)
+    #[doc(hidden)]
+    #[track_caller]
+    pub fn assert_decrypts_to<T, Clear>(value: &T, expected: Clear, expression: &str)
+    where
+        T: FheDecrypt<Clear> + DebugDecrypt,
+        Clear: PartialEq + Debug,
+    {
+        DEBUG_CLIENT_KEY.with(|key| {
+            let key = key.borrow();
+            let Some(key) = key.as_ref() else {
+                return;
+            };
+
+            let decrypted = value.decrypt(key);
+            assert!(
+                decrypted == expected,
+                "`{expression}` decrypts to {decrypted:?}, expected {expected:?}\n{}",
+                value.debug_report(key)
+            );
+        });
+    }
+}
+
+impl Drop for DebugContext {
+    fn drop(&mut self) {
+        let previous_key = self.previous_key.take();
+        DEBUG_CLIENT_KEY.with(|key| key.replace(previous_key));
+    }
+}
+
+/// Checks that an encrypted value decrypts to the expected clear value, using the key of the
+/// current [DebugContext].
+///
+/// On mismatch, it panics with the description of the blocks of the value, see
+/// [DebugContext::report]. It does nothing if no [DebugContext] is alive on the current thread,
+/// or in builds without debug assertions.
+#[macro_export]
+macro_rules! debug_assert_decrypts_to {
+    ($value:expr, $expected:expr $(,)?) => {
+        if cfg!(debug_assertions) {
+            $crate::DebugContext::assert_decrypts_to(&$value, $expected, stringify!($value));
+        }
+    };
+}
+
+pub(crate) fn integer_blocks_report(
+    key: &crate::integer::ClientKey,
+    blocks: &[crate::shortint::Ciphertext],
+) -> String {
+    let mut report = String::new();
+    for (i, block) in blocks.iter().enumerate() {
+        let noise = key.key.analyze_noise(block);
+
+        // Writing to a String cannot fail
+        let _ = writeln!(
+            report,
+            "block {i}: message {}, carry {}, degree {}, noise 2^{:.1} (fails above 2^{:.1})",
+            noise.message,
+            noise.carry,
+            noise.degree.0,
+            noise.log2_noise(),
+            (noise.decision_boundary as f64).log2(),
+        );
+    }
+    report
+}
@@ -76,6 +76,16 @@ macro_rules! define_key_structs {
                         )*
                     }
                 }
+
+                /// The number of GGSW ciphertexts of the bootstrapping keys of the server key
+                pub(crate) fn bootstrapping_key_ggsw_count(&self) -> usize {
+                    0 $(
+                        + self
+                            .[<$name _key>]
+                            .as_ref()
+                            .map_or(0, <[<$base_ty_name ClientKey>]>::bootstrapping_key_ggsw_count)
+                    )*
+                }
             }
 
             impl From<[<$base_struct_name Config>]> for [<$base_struct_name ClientKey>] {
@@ -162,6 +172,27 @@ macro_rules! define_key_structs {
                         )*
                     }
                 }
+
+                /// Generates the key, calling `on_ggsw_encrypted` each time one of the GGSW
+                /// ciphertexts of the bootstrapping keys is encrypted.
+                ///
+                /// Returns `None` if `on_ggsw_encrypted` stopped the generation.
+                pub(crate) fn new_with_progress(
+                    client_key: &[<$base_struct_name ClientKey>],
+                    on_ggsw_encrypted: &(dyn Fn() -> ::std::ops::ControlFlow<()> + Sync),
+                ) -> Option<Self> {
+                    Some(Self {
+                        $(
+                            [<$name _key>]: match client_key.[<$name _key>].as_ref() {
+                                Some(key) => Some(<[<$base_ty_name ServerKey>]>::new_with_progress(
+                                    key,
+                                    on_ggsw_encrypted,
+                                )?),
+                                None => None,
+                            },
+                        )*
+                    })
+                }
             }
 
             impl Default for [<$base_struct_name ServerKey>] {
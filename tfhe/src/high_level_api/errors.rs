@@ -152,6 +152,19 @@ impl Display for OutOfRangeError {
 
 impl std::error::Error for OutOfRangeError {}
 
+/// Error returned when a key generation is cancelled with a
+/// [CancellationToken](crate::high_level_api::CancellationToken)
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct KeygenCancelled;
+
+impl Display for KeygenCancelled {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "The key generation was cancelled")
+    }
+}
+
+impl std::error::Error for KeygenCancelled {}
+
 #[non_exhaustive]
 #[derive(Debug, Eq, PartialEq)]
 pub enum Error {
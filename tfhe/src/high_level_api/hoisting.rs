@@ -0,0 +1,270 @@
+//! Evaluation in the clear of the subexpressions whose inputs are all clear.
+//!
+//! The values known by the server, e.g. the constants of an application, are used in the
+//! computations as trivial ciphertexts, see [FheTrivialEncrypt](crate::prelude::FheTrivialEncrypt).
+//! They are not encrypted, but the homomorphic operations on them cost as much as on encrypted
+//! values. While a [ClearHoisting] is active on the current thread, the operations whose inputs
+//! are all trivial are evaluated natively on the clear values instead, and their result is
+//! trivially encrypted: only the values at the boundary with the encrypted computations are
+//! turned into ciphertexts, and the whole clear subexpression is hoisted out of the homomorphic
+//! computation.
+//!
+//! Combined with an [OperationRecorder](crate::OperationRecorder), the hoisted operations appear in
+//! the [ComputationGraph](crate::ComputationGraph) without any PBS.
+//!
+//! # Note
+//!
+//! - Only the operators which give the same result on signed and unsigned integers are evaluated
+//!   natively: additions, subtractions, multiplications, negations and bitwise operations. The
+//!   divisions, remainders, shifts and rotations on clear inputs are listed in
+//!   [HoistingReport::unsupported_operations], and evaluated homomorphically. The other
+//!   operations, e.g. the comparisons, are never hoisted.
+//! - As for the [OperationRecorder](crate::OperationRecorder), only the operations done on the
+//!   current thread are hoisted.
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::integer::block_decomposition::{BlockDecomposer, BlockRecomposer, DecomposableInto};
+use crate::integer::ciphertext::IntegerRadixCiphertext;
+use crate::integer::U256;
+use crate::shortint::Ciphertext;
+
+thread_local! {
+    static HOISTING: RefCell<Option<HoistingReport>> = RefCell::new(None);
+}
+
+/// The homomorphic work eliminated by a [ClearHoisting]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HoistingReport {
+    /// Number of operations evaluated natively, by operation name, e.g. `add` or `scalar_mul`
+    pub hoisted_operations: BTreeMap<String, usize>,
+    /// Total number of blocks of the results evaluated natively
+    pub hoisted_blocks: usize,
+    /// Number of operations on clear inputs only which have no native evaluation, by operation
+    /// name
+    pub unsupported_operations: BTreeMap<String, usize>,
+    /// Number of clear inputs of the operations evaluated homomorphically because another of
+    /// their inputs is encrypted
+    pub boundary_inputs: usize,
+}
+
+impl HoistingReport {
+    pub fn hoisted_operation_count(&self) -> usize {
+        self.hoisted_operations.values().sum()
+    }
+}
+
+/// Evaluates natively the operations on clear inputs done on the current thread, until it is
+/// finished or dropped.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ClearHoisting, ConfigBuilder, FheUint8};
+///
+/// let config = ConfigBuilder::all_disabled()
+///     .enable_default_integers()
+///     .build();
+/// let (client_key, server_key) = generate_keys(config);
+/// set_server_key(server_key);
+///
+/// let a = FheUint8::encrypt(27u8, &client_key);
+/// let rate = FheUint8::encrypt_trivial(3u8);
+/// let offset = FheUint8::encrypt_trivial(10u8);
+///
+/// let hoisting = ClearHoisting::start();
+/// // Only known by the server, evaluated natively
+/// let factor = &rate * &rate + &offset;
+/// // The boundary with the encrypted computation
+/// let result = &a * &factor;
+/// let report = hoisting.finish();
+///
+/// assert_eq!(report.hoisted_operation_count(), 2);
+/// assert_eq!(report.boundary_inputs, 1);
+///
+/// let decrypted: u8 = result.decrypt(&client_key);
+/// assert_eq!(decrypted, 27u8.wrapping_mul(19));
+/// ```
+pub struct ClearHoisting {
+    // The report of an outer hoisting, restored when this one is finished or dropped
+    previous: Option<HoistingReport>,
+    finished: bool,
+}
+
+impl ClearHoisting {
+    pub fn start() -> Self {
+        let previous = HOISTING.with(|report| report.replace(Some(HoistingReport::default())));
+        Self {
+            previous,
+            finished: false,
+        }
+    }
+
+    /// Returns whether the operations on clear inputs are evaluated natively on the current
+    /// thread
+    pub fn is_active() -> bool {
+        HOISTING.with(|report| report.borrow().is_some())
+    }
+
+    /// Stops the hoisting, and returns the report of the homomorphic work eliminated
+    pub fn finish(mut self) -> HoistingReport {
+        // The drop must not restore the previous report a second time
+        self.finished = true;
+        let previous = self.previous.take();
+        HOISTING
+            .with(|report| report.replace(previous))
+            .unwrap_or_default()
+    }
+}
+
+impl Drop for ClearHoisting {
+    fn drop(&mut self) {
+        if self.finished {
+            return;
+        }
+        let previous = self.previous.take();
+        HOISTING.with(|report| report.replace(previous));
+    }
+}
+
+fn update_report(update: impl FnOnce(&mut HoistingReport)) {
+    HOISTING.with(|report| {
+        if let Some(report) = report.borrow_mut().as_mut() {
+            update(report);
+        }
+    });
+}
+
+/// Evaluates natively the operation `operation` if a [ClearHoisting] is active and its `inputs`
+/// are all trivial, returns `None` if it must be evaluated homomorphically.
+pub(crate) fn hoist_operation<C>(
+    key: &crate::integer::ServerKey,
+    operation: &str,
+    inputs: &[&[Ciphertext]],
+) -> Option<C>
+where
+    C: IntegerRadixCiphertext,
+{
+    if !ClearHoisting::is_active() {
+        return None;
+    }
+    hoist(key, operation, inputs, None)
+}
+
+/// Same as [hoist_operation] for the operations between a ciphertext and a scalar.
+pub(crate) fn hoist_scalar_operation<C, S>(
+    key: &crate::integer::ServerKey,
+    operation: &str,
+    input: &[Ciphertext],
+    scalar: S,
+) -> Option<C>
+where
+    C: IntegerRadixCiphertext,
+    S: DecomposableInto<u64>,
+{
+    if !ClearHoisting::is_active() || input.is_empty() {
+        return None;
+    }
+
+    // The scalar is truncated to the bits of the ciphertext, as in the homomorphic operations
+    let bits_in_block = input[0].message_modulus.0.ilog2();
+    let mut recomposer = BlockRecomposer::<U256>::new(bits_in_block);
+    for block in BlockDecomposer::new(scalar, bits_in_block)
+        .iter_as::<u64>()
+        .take(input.len())
+    {
+        recomposer.add_unmasked(block);
+    }
+
+    hoist(key, operation, &[input], Some(recomposer.value()))
+}
+
+fn hoist<C>(
+    key: &crate::integer::ServerKey,
+    operation: &str,
+    inputs: &[&[Ciphertext]],
+    scalar: Option<U256>,
+) -> Option<C>
+where
+    C: IntegerRadixCiphertext,
+{
+    let operation = operation
+        .trim_end_matches("_parallelized")
+        .trim_end_matches("_assign");
+
+    let clear_input_count = inputs
+        .iter()
+        .filter(|blocks| blocks.iter().all(Ciphertext::is_trivial))
+        .count();
+    if clear_input_count < inputs.len() {
+        update_report(|report| report.boundary_inputs += clear_input_count);
+        return None;
+    }
+
+    let num_blocks = inputs[0].len();
+    let bits_in_block = inputs[0].first()?.message_modulus.0.ilog2();
+    let num_bits = num_blocks as u32 * bits_in_block;
+
+    let values = inputs
+        .iter()
+        .map(|blocks| clear_value(blocks, bits_in_block))
+        .chain(scalar)
+        .collect::<Vec<_>>();
+
+    let result = (num_bits <= U256::BITS)
+        .then(|| evaluate(operation, &values))
+        .flatten();
+    let Some(result) = result else {
+        update_report(|report| {
+            *report
+                .unsupported_operations
+                .entry(operation.to_owned())
+                .or_default() += 1;
+        });
+        return None;
+    };
+
+    update_report(|report| {
+        *report
+            .hoisted_operations
+            .entry(operation.to_owned())
+            .or_default() += 1;
+        report.hoisted_blocks += num_blocks;
+    });
+
+    // The result is reduced modulo 2^num_bits when decomposed in blocks
+    Some(key.create_trivial_radix(result, num_blocks))
+}
+
+fn clear_value(blocks: &[Ciphertext], bits_in_block: u32) -> U256 {
+    let mut recomposer = BlockRecomposer::<U256>::new(bits_in_block);
+    for block in blocks {
+        // The blocks may have carries, which are added to the next blocks
+        let value = block.decrypt_trivial_message_and_carry().unwrap();
+        if !recomposer.add_unmasked(value) {
+            break;
+        }
+    }
+    recomposer.value()
+}
+
+// The operations modulo 2^256 give the same result as modulo 2^num_bits on the low bits, for
+// signed and unsigned integers alike
+fn evaluate(operation: &str, values: &[U256]) -> Option<U256> {
+    let result = match (operation, values) {
+        ("add" | "scalar_add", &[lhs, rhs]) => lhs + rhs,
+        ("sub" | "scalar_sub", &[lhs, rhs]) => lhs - rhs,
+        ("mul" | "scalar_mul", &[lhs, rhs]) => lhs * rhs,
+        ("bitand" | "scalar_bitand", &[lhs, rhs]) => lhs & rhs,
+        ("bitor" | "scalar_bitor", &[lhs, rhs]) => lhs | rhs,
+        ("bitxor" | "scalar_bitxor", &[lhs, rhs]) => lhs ^ rhs,
+        ("neg", &[value]) => U256::ZERO - value,
+        ("bitnot", &[value]) => !value,
+        _ => return None,
+    };
+    Some(result)
+}
@@ -1,5 +1,6 @@
 use concrete_csprng::seeders::Seed;
 use serde::{Deserialize, Serialize};
+use std::ops::ControlFlow;
 
 use crate::core_crypto::commons::generators::DeterministicSeeder;
 use crate::core_crypto::prelude::ActivatedRandomGenerator;
@@ -90,6 +91,21 @@ impl IntegerClientKey {
     pub(crate) fn block_parameters(&self) -> Option<crate::shortint::parameters::PBSParameters> {
         self.key.as_ref().map(|key| key.parameters())
     }
+
+    /// The number of GGSW ciphertexts of the bootstrapping key of the server key, 0 if integers
+    /// are disabled
+    pub(in crate::high_level_api) fn bootstrapping_key_ggsw_count(&self) -> usize {
+        let Some(cks) = &self.key else {
+            return 0;
+        };
+        match cks.parameters() {
+            crate::shortint::PBSParameters::PBS(params) => params.lwe_dimension.0,
+            crate::shortint::PBSParameters::MultiBitPBS(params) => {
+                params.lwe_dimension.0 / params.grouping_factor.0
+                    * params.grouping_factor.ggsw_per_multi_bit_element().0
+            }
+        }
+    }
 }
 
 impl From<IntegerConfig> for IntegerClientKey {
@@ -116,25 +132,40 @@ pub struct IntegerServerKey {
 
 impl IntegerServerKey {
     pub(in crate::high_level_api) fn new(client_key: &IntegerClientKey) -> Self {
+        // The progress callback never stops the generation
+        Self::new_with_progress(client_key, &|| ControlFlow::Continue(())).unwrap()
+    }
+
+    /// Generates the key, calling `on_ggsw_encrypted` each time one of the
+    /// [bootstrapping_key_ggsw_count](IntegerClientKey::bootstrapping_key_ggsw_count) GGSW
+    /// ciphertexts of the bootstrapping key is encrypted.
+    ///
+    /// Returns `None` if `on_ggsw_encrypted` stopped the generation. The generation of the wopbs
+    /// key, if any, does not call `on_ggsw_encrypted`.
+    pub(in crate::high_level_api) fn new_with_progress(
+        client_key: &IntegerClientKey,
+        on_ggsw_encrypted: &(dyn Fn() -> ControlFlow<()> + Sync),
+    ) -> Option<Self> {
         let Some(cks) = &client_key.key else {
-            return Self::default();
+            return Some(Self::default());
         };
         assert_eq!(
             cks.parameters().message_modulus().0,
             4,
             "This API only supports integers with 2 bits per block (MessageModulus(4))",
         );
-        let base_integer_key = crate::integer::ServerKey::new(cks);
+        let base_integer_key =
+            crate::integer::ServerKey::new_with_progress(cks, on_ggsw_encrypted)?;
         let wopbs_key = client_key
             .wopbs_block_parameters
             .as_ref()
             .map(|wopbs_params| {
                 crate::integer::wopbs::WopbsKey::new_wopbs_key(cks, &base_integer_key, wopbs_params)
             });
-        Self {
+        Some(Self {
             key: Some(base_integer_key),
             wopbs_key,
-        }
+        })
     }
 
     pub(in crate::high_level_api::integers) fn pbs_key(&self) -> &crate::integer::ServerKey {
@@ -210,6 +241,14 @@ impl IntegerCompactPublicKey {
         let ct = key.encrypt_slice_radix_compact(values, num_blocks);
         Some(ct)
     }
+
+    pub(in crate::high_level_api::integers) fn try_rerandomize_compact_list(
+        &self,
+        list: &CompactCiphertextList,
+    ) -> Option<CompactCiphertextList> {
+        let key = self.key.as_ref()?;
+        Some(key.rerandomize_compact_list(list))
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
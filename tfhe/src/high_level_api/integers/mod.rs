@@ -4,6 +4,7 @@ expand_pub_use_fhe_type!(
         FheUint256, FheInt8, FheInt16, FheInt32, FheInt64, FheInt128, FheInt256
     };
 );
+pub use types::{FheMap, FheOrdering, FheUintVector, SparseEncryptedVector};
 
 pub(in crate::high_level_api) use keys::{
     IntegerClientKey, IntegerCompactPublicKey, IntegerCompressedCompactPublicKey,
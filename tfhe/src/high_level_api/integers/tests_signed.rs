@@ -472,6 +472,37 @@ fn test_integer_casting() {
     }
 }
 
+#[test]
+fn test_integer_narrowing_casts() {
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .build();
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let mut rng = rand::thread_rng();
+
+    for clear in [
+        rng.gen_range(i16::MIN..i8::MIN as i16),
+        rng.gen_range(i8::MIN as i16..0),
+        rng.gen_range(0..=i8::MAX as i16),
+        rng.gen_range(i8::MAX as i16 + 1..=i16::MAX),
+    ] {
+        let a = FheInt16::encrypt(clear, &client_key);
+
+        let (narrowed, overflowed): (FheInt8, FheInt8) = a.cast_narrow_checked();
+        let da: i8 = narrowed.decrypt(&client_key);
+        assert_eq!(da, clear as i8);
+        let overflowed: i8 = overflowed.decrypt(&client_key);
+        assert_eq!(overflowed, i8::from(i8::try_from(clear).is_err()));
+
+        let saturated: FheInt8 = a.cast_narrow_saturating();
+        let da: i8 = saturated.decrypt(&client_key);
+        assert_eq!(da, clear.clamp(i8::MIN as i16, i8::MAX as i16) as i8);
+    }
+}
+
 #[test]
 fn test_if_then_else() {
     let config = ConfigBuilder::all_disabled()
@@ -525,6 +556,77 @@ fn test_abs() {
     }
 }
 
+#[test]
+fn test_signum_and_copysign() {
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let mut rng = rand::thread_rng();
+
+    let clears = [
+        rng.gen_range(i16::MIN + 1..0),
+        0,
+        rng.gen_range(1..=i16::MAX),
+    ];
+    for clear_a in clears {
+        let a = FheInt16::encrypt(clear_a, &client_key);
+
+        let signum = a.signum();
+        let decrypted_result: i16 = signum.decrypt(&client_key);
+        assert_eq!(decrypted_result, clear_a.signum());
+
+        for clear_b in clears {
+            let b = FheInt16::encrypt(clear_b, &client_key);
+
+            let result = a.copysign(&b);
+            let decrypted_result: i16 = result.decrypt(&client_key);
+            let expected = if clear_b < 0 {
+                -clear_a.abs()
+            } else {
+                clear_a.abs()
+            };
+            assert_eq!(decrypted_result, expected);
+        }
+    }
+}
+
+#[test]
+fn test_saturating_ops() {
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let mut rng = rand::thread_rng();
+
+    for (clear_a, clear_b) in [
+        (i8::MAX, 1),
+        (i8::MIN, 1),
+        (-100, 50),
+        (rng.gen(), rng.gen()),
+    ] {
+        let a = FheInt8::encrypt(clear_a, &client_key);
+        let b = FheInt8::encrypt(clear_b, &client_key);
+
+        let result: i8 = a.saturating_add(&b).decrypt(&client_key);
+        assert_eq!(result, clear_a.saturating_add(clear_b));
+
+        let result: i8 = a.saturating_sub(&b).decrypt(&client_key);
+        assert_eq!(result, clear_a.saturating_sub(clear_b));
+
+        let result: i8 = a.saturating_mul(&b).decrypt(&client_key);
+        assert_eq!(result, clear_a.saturating_mul(clear_b));
+    }
+}
+
 #[test]
 fn test_trivial_fhe_int8() {
     let config = ConfigBuilder::all_disabled()
@@ -648,3 +750,62 @@ fn test_compact_public_key_list(config: Config) {
     let decrypted: i32 = a.decrypt(&client_key);
     assert_eq!(clear_xs[0], decrypted);
 }
+
+#[test]
+fn test_widening_ops() {
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .build();
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let a = FheInt8::encrypt(i8::MIN, &client_key);
+    let b = FheInt8::encrypt(-100i8, &client_key);
+
+    let product: FheInt16 = a.widening_mul(&a);
+    let decrypted: i16 = product.decrypt(&client_key);
+    assert_eq!(decrypted, i8::MIN as i16 * i8::MIN as i16);
+
+    let product: FheInt16 = a.widening_mul(&FheInt8::encrypt(i8::MAX, &client_key));
+    let decrypted: i16 = product.decrypt(&client_key);
+    assert_eq!(decrypted, i8::MIN as i16 * i8::MAX as i16);
+
+    let sum: FheInt16 = a.widening_add(&b);
+    let decrypted: i16 = sum.decrypt(&client_key);
+    assert_eq!(decrypted, i8::MIN as i16 - 100);
+}
+
+#[test]
+fn test_cmp() {
+    use std::cmp::Ordering;
+
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .build();
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let if_less = FheInt8::encrypt(-1i8, &client_key);
+    let if_equal = FheInt8::encrypt(0i8, &client_key);
+    let if_greater = FheInt8::encrypt(1i8, &client_key);
+
+    for (clear_a, clear_b) in [(-100i8, 27i8), (-5i8, -5i8), (27i8, -100i8)] {
+        let a = FheInt8::encrypt(clear_a, &client_key);
+        let b = FheInt8::encrypt(clear_b, &client_key);
+
+        let expected = clear_a.cmp(&clear_b);
+        for ordering in [a.cmp(&b), a.cmp(clear_b)] {
+            let decrypted: Ordering = ordering.decrypt(&client_key);
+            assert_eq!(decrypted, expected);
+
+            let decrypted: i8 = ordering.is_le().decrypt(&client_key);
+            assert_eq!(decrypted, i8::from(expected.is_le()));
+
+            let selected = ordering.select(&if_less, &if_equal, &if_greater);
+            let decrypted: i8 = selected.decrypt(&client_key);
+            assert_eq!(decrypted, expected as i8);
+        }
+    }
+}
@@ -6,7 +6,7 @@ use crate::integer::U256;
 use crate::{
     CompactFheUint32, CompactFheUint32List, CompactPublicKey, CompressedFheUint16,
     CompressedFheUint256, CompressedPublicKey, Config, FheInt32, FheInt8, FheUint128, FheUint16,
-    FheUint256, FheUint32, FheUint64,
+    FheUint256, FheUint32, FheUint64, RoundingMode,
 };
 
 #[test]
@@ -270,6 +270,90 @@ fn fhe_uint32_shift(config: Config) {
     }
 }
 
+#[test]
+fn test_uint8_saturating_ops() {
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let mut rng = rand::thread_rng();
+
+    for (clear_a, clear_b) in [(u8::MAX, 1), (0, 1), (17, 15), (rng.gen(), rng.gen())] {
+        let a = FheUint8::encrypt(clear_a, &client_key);
+        let b = FheUint8::encrypt(clear_b, &client_key);
+
+        let result: u8 = a.saturating_add(&b).decrypt(&client_key);
+        assert_eq!(result, clear_a.saturating_add(clear_b));
+
+        let result: u8 = a.saturating_sub(&b).decrypt(&client_key);
+        assert_eq!(result, clear_a.saturating_sub(clear_b));
+
+        let result: u8 = a.saturating_mul(&b).decrypt(&client_key);
+        assert_eq!(result, clear_a.saturating_mul(clear_b));
+    }
+}
+
+#[test]
+fn test_uint32_operand_combinations() {
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let mut rng = rand::thread_rng();
+    let clear_a = rng.gen::<u32>();
+    let clear_b = rng.gen::<u32>();
+
+    let a = FheUint32::encrypt(clear_a, &client_key);
+    let b = FheUint32::encrypt(clear_b, &client_key);
+
+    let expected = clear_a.wrapping_add(clear_b);
+    let results = [
+        &a + &b,
+        &a + b.clone(),
+        a.clone() + &b,
+        a.clone() + b.clone(),
+        &a + clear_b,
+        a.clone() + clear_b,
+        clear_b + &a,
+        clear_b + a.clone(),
+    ];
+    for result in results {
+        let decrypted: u32 = result.decrypt(&client_key);
+        assert_eq!(decrypted, expected);
+    }
+
+    let mut c = a.clone();
+    c += &b;
+    c -= b;
+    c *= clear_b;
+    let decrypted: u32 = c.decrypt(&client_key);
+    assert_eq!(decrypted, clear_a.wrapping_mul(clear_b));
+
+    // Operations with the scalar on the left side
+    let decrypted: u32 = (clear_b - &a).decrypt(&client_key);
+    assert_eq!(decrypted, clear_b.wrapping_sub(clear_a));
+
+    let decrypted: u32 = (clear_b * &a).decrypt(&client_key);
+    assert_eq!(decrypted, clear_b.wrapping_mul(clear_a));
+
+    let decrypted: u32 = (clear_b & &a).decrypt(&client_key);
+    assert_eq!(decrypted, clear_b & clear_a);
+
+    let decrypted: u32 = (clear_b | a.clone()).decrypt(&client_key);
+    assert_eq!(decrypted, clear_b | clear_a);
+
+    let decrypted: u32 = (clear_b ^ a).decrypt(&client_key);
+    assert_eq!(decrypted, clear_b ^ clear_a);
+}
+
 #[test]
 fn test_uint32_bitwise() {
     let config = ConfigBuilder::all_disabled()
@@ -475,6 +559,23 @@ fn fhe_uint32_div_rem(config: Config) {
         assert_eq!(decrypted_q, clear_a / clear_b);
         assert_eq!(decrypted_r, clear_a % clear_b);
 
+        let (q, r, divisor_is_zero) = (&a).checked_div_rem(&b);
+        let decrypted_q: u32 = q.decrypt(&cks);
+        let decrypted_r: u32 = r.decrypt(&cks);
+        let decrypted_divisor_is_zero: u32 = divisor_is_zero.decrypt(&cks);
+        assert_eq!(decrypted_q, clear_a / clear_b);
+        assert_eq!(decrypted_r, clear_a % clear_b);
+        assert_eq!(decrypted_divisor_is_zero, 0);
+
+        let zero = FheUint32::try_encrypt(0u32, &cks).unwrap();
+        let (q, r, divisor_is_zero) = (&a).checked_div_rem(&zero);
+        let decrypted_q: u32 = q.decrypt(&cks);
+        let decrypted_r: u32 = r.decrypt(&cks);
+        let decrypted_divisor_is_zero: u32 = divisor_is_zero.decrypt(&cks);
+        assert_eq!(decrypted_q, 0);
+        assert_eq!(decrypted_r, clear_a);
+        assert_eq!(decrypted_divisor_is_zero, 1);
+
         let mut c = a.clone();
         c /= &b;
         let decrypted: u32 = c.decrypt(&cks);
@@ -558,6 +659,35 @@ fn test_uint64() {
     assert_eq!(decrypted, clear_a.wrapping_add(clear_b));
 }
 
+#[test]
+fn test_decrypt_batch() {
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .build();
+
+    let (cks, _) = generate_keys(config);
+
+    let mut rng = rand::thread_rng();
+
+    let clears: Vec<u64> = (0..10).map(|_| rng.gen()).collect();
+    let values: Vec<FheUint64> = clears
+        .iter()
+        .map(|clear| FheUint64::encrypt(*clear, &cks))
+        .collect();
+    let decrypted: Vec<u64> = cks.decrypt_batch(&values);
+    assert_eq!(decrypted, clears);
+
+    let clears: Vec<i8> = (0..10).map(|_| rng.gen()).collect();
+    let values: Vec<FheInt8> = clears
+        .iter()
+        .map(|clear| FheInt8::encrypt(*clear, &cks))
+        .collect();
+    let decrypted: Vec<i8> = cks.decrypt_batch(&values);
+    assert_eq!(decrypted, clears);
+
+    assert!(cks.decrypt_batch::<FheUint64, u64>(&[]).is_empty());
+}
+
 #[test]
 fn test_small_uint128() {
     let config = ConfigBuilder::all_disabled()
@@ -819,6 +949,62 @@ fn test_integer_casting() {
     }
 }
 
+#[test]
+fn test_widening_ops() {
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .build();
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let a = FheUint8::encrypt(u8::MAX, &client_key);
+    let b = FheUint8::encrypt(200u8, &client_key);
+
+    let product: FheUint16 = a.widening_mul(&a);
+    let decrypted: u16 = product.decrypt(&client_key);
+    assert_eq!(decrypted, u8::MAX as u16 * u8::MAX as u16);
+
+    let sum: FheUint16 = a.widening_add(&b);
+    let decrypted: u16 = sum.decrypt(&client_key);
+    assert_eq!(decrypted, u8::MAX as u16 + 200);
+
+    // The wrapping operations are left unchanged
+    let decrypted: u8 = (&a * &b).decrypt(&client_key);
+    assert_eq!(decrypted, u8::MAX.wrapping_mul(200));
+}
+
+#[test]
+fn test_rounded_div_and_shr() {
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .build();
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let a = FheUint16::encrypt(1050u16, &client_key);
+
+    for (mode, expected) in [
+        (RoundingMode::Floor, 10u16),
+        (RoundingMode::Ceil, 11),
+        (RoundingMode::HalfEven, 10),
+    ] {
+        let decrypted: u16 = a.div_rounded(100u16, mode).decrypt(&client_key);
+        assert_eq!(decrypted, expected, "Invalid division with {mode:?}");
+    }
+
+    // 1050 / 16 = 65.625
+    for (mode, expected) in [
+        (RoundingMode::Floor, 65u16),
+        (RoundingMode::Ceil, 66),
+        (RoundingMode::HalfEven, 66),
+    ] {
+        let decrypted: u16 = a.shr_rounded(4, mode).decrypt(&client_key);
+        assert_eq!(decrypted, expected, "Invalid shift with {mode:?}");
+    }
+}
+
 #[test]
 fn test_if_then_else() {
     let config = ConfigBuilder::all_disabled()
@@ -849,3 +1035,254 @@ fn test_if_then_else() {
         if clear_a <= clear_b { clear_b } else { clear_a }
     );
 }
+
+#[test]
+fn test_cmp() {
+    use std::cmp::Ordering;
+
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let if_less = FheUint16::encrypt(1u16, &client_key);
+    let if_equal = FheUint16::encrypt(2u16, &client_key);
+    let if_greater = FheUint16::encrypt(3u16, &client_key);
+
+    for (clear_a, clear_b) in [(27u8, 128u8), (128u8, 128u8), (200u8, 128u8)] {
+        let a = FheUint8::encrypt(clear_a, &client_key);
+        let b = FheUint8::encrypt(clear_b, &client_key);
+
+        let expected = clear_a.cmp(&clear_b);
+        for ordering in [a.cmp(&b), a.cmp(clear_b)] {
+            let decrypted: Ordering = ordering.decrypt(&client_key);
+            assert_eq!(decrypted, expected);
+
+            let comparisons = [
+                (ordering.is_lt(), expected.is_lt()),
+                (ordering.is_le(), expected.is_le()),
+                (ordering.is_eq(), expected.is_eq()),
+                (ordering.is_ne(), expected.is_ne()),
+                (ordering.is_gt(), expected.is_gt()),
+                (ordering.is_ge(), expected.is_ge()),
+            ];
+            for (result, expected_result) in comparisons {
+                let decrypted: u8 = result.decrypt(&client_key);
+                assert_eq!(decrypted, u8::from(expected_result));
+            }
+
+            let selected = ordering.select(&if_less, &if_equal, &if_greater);
+            let decrypted: u16 = selected.decrypt(&client_key);
+            let expected_selected = match expected {
+                Ordering::Less => 1,
+                Ordering::Equal => 2,
+                Ordering::Greater => 3,
+            };
+            assert_eq!(decrypted, expected_selected);
+        }
+    }
+}
+
+#[test]
+fn test_fhe_map() {
+    use crate::high_level_api::FheMap;
+
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let clear_entries = [(3u16, 1000u32), (17, 25), (200, u32::MAX)];
+
+    let mut map = clear_entries
+        .iter()
+        .map(|&(key, value)| (key, FheUint32::encrypt(value, &client_key)))
+        .collect::<FheMap<_, _>>();
+    assert_eq!(map.len(), clear_entries.len());
+
+    // Oblivious access, with a key type smaller than the values
+    for (key, value) in clear_entries {
+        let encrypted_key = FheUint8::encrypt(key as u8, &client_key);
+
+        let decrypted: u32 = map.get(&encrypted_key).decrypt(&client_key);
+        assert_eq!(decrypted, value);
+
+        let decrypted: u8 = map.contains_key(&encrypted_key).decrypt(&client_key);
+        assert_eq!(decrypted, 1);
+    }
+
+    let encrypted_key = FheUint8::encrypt(4u8, &client_key);
+    let decrypted: u32 = map.get(&encrypted_key).decrypt(&client_key);
+    assert_eq!(decrypted, 0);
+    let decrypted: u8 = map.contains_key(&encrypted_key).decrypt(&client_key);
+    assert_eq!(decrypted, 0);
+
+    // Non oblivious access
+    let previous = map.insert(17, FheUint32::encrypt(26u32, &client_key));
+    let decrypted: u32 = previous.unwrap().decrypt(&client_key);
+    assert_eq!(decrypted, 25);
+
+    *map.get_clear_mut(&3).unwrap() += 1u32;
+    let decrypted: u32 = map.get_clear(&3).unwrap().decrypt(&client_key);
+    assert_eq!(decrypted, 1001);
+
+    let encrypted_key = FheUint8::encrypt(3u8, &client_key);
+    let decrypted: u32 = map.get(&encrypted_key).decrypt(&client_key);
+    assert_eq!(decrypted, 1001);
+
+    assert!(map.remove(&200).is_some());
+    assert!(!map.contains_clear_key(&200));
+
+    let empty = FheMap::<u16, FheUint32>::new();
+    let decrypted: u32 = empty.get(&encrypted_key).decrypt(&client_key);
+    assert_eq!(decrypted, 0);
+}
+
+#[test]
+fn test_fhe_uint_vector_dot_clear_and_mul_add_clear() {
+    use crate::high_level_api::FheUintVector;
+
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let clear_values = [17u64, u64::MAX, 0, 1 << 40];
+    let weights = [0u64, 3, 12, (1 << 33) + 5];
+
+    let mut values = clear_values
+        .iter()
+        .map(|&value| FheUint64::encrypt(value, &client_key))
+        .collect::<FheUintVector<_>>();
+    values.push(FheUint64::encrypt(3u64, &client_key));
+    assert_eq!(values.len(), clear_values.len() + 1);
+
+    let mut all_weights = weights.to_vec();
+    all_weights.push(7);
+    let expected = clear_values
+        .iter()
+        .chain(std::iter::once(&3))
+        .zip(all_weights.iter())
+        .fold(0u64, |acc, (v, w)| acc.wrapping_add(v.wrapping_mul(*w)));
+
+    let decrypted: u64 = values.dot_clear(&all_weights).decrypt(&client_key);
+    assert_eq!(decrypted, expected);
+
+    let empty = FheUintVector::<FheUint64>::new();
+    let decrypted: u64 = empty.dot_clear(&[]).decrypt(&client_key);
+    assert_eq!(decrypted, 0);
+
+    let a = FheUint64::encrypt(u64::MAX - 2, &client_key);
+    for (mul, add) in [(0u64, 5u64), (1, 0), (8, 3), (1234567, u64::MAX)] {
+        let decrypted: u64 = a.mul_add_clear(mul, add).decrypt(&client_key);
+        assert_eq!(
+            decrypted,
+            (u64::MAX - 2).wrapping_mul(mul).wrapping_add(add)
+        );
+    }
+}
+
+#[test]
+fn test_sparse_encrypted_vector() {
+    use crate::high_level_api::SparseEncryptedVector;
+
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let decrypt_all = |vector: &SparseEncryptedVector<FheUint8>| {
+        vector
+            .iter()
+            .map(|(index, value)| {
+                let value: u8 = value.decrypt(&client_key);
+                (index, value)
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let mut a = SparseEncryptedVector::new(1 << 20);
+    assert_eq!(a.len(), 1 << 20);
+    assert!(a.insert(5, FheUint8::encrypt(0u8, &client_key)).is_none());
+    a.insert(42, FheUint8::encrypt(200u8, &client_key));
+    a.insert(1000, FheUint8::encrypt(7u8, &client_key));
+    assert!(a.get_clear(6).is_none());
+
+    let yes = FheUint8::encrypt(1u8, &client_key);
+    let no = FheUint8::encrypt(0u8, &client_key);
+    let value = FheUint8::encrypt(9u8, &client_key);
+    a.masked_update(42, &no, &value);
+    a.masked_update(1000, &yes, &value);
+    a.masked_update(3, &no, &value);
+    a.masked_update(77, &yes, &value);
+    assert_eq!(a.stored_len(), 5);
+    assert_eq!(
+        decrypt_all(&a),
+        [(3, 0), (5, 0), (42, 200), (77, 9), (1000, 9)]
+    );
+
+    let mut b = SparseEncryptedVector::new(1 << 20);
+    b.insert(42, FheUint8::encrypt(100u8, &client_key));
+    b.insert(500, FheUint8::encrypt(1u8, &client_key));
+    let merged = a.merge(&b);
+    assert_eq!(
+        decrypt_all(&merged),
+        [(3, 0), (5, 0), (42, 44), (77, 9), (500, 1), (1000, 9)]
+    );
+
+    let compacted = merged.compact();
+    let decrypted = compacted
+        .iter()
+        .map(|value| {
+            let value: u8 = value.decrypt(&client_key);
+            value
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(decrypted, [44, 9, 1, 9, 0, 0]);
+
+    assert!(SparseEncryptedVector::<FheUint8>::new(10)
+        .compact()
+        .is_empty());
+}
+
+#[test]
+fn test_content_hash() {
+    use crate::integer::{IntegerCiphertext, RadixCiphertext};
+
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .build();
+
+    let (client_key, _server_key) = generate_keys(config);
+
+    let a = FheUint8::encrypt(17u8, &client_key);
+    let b = FheUint8::encrypt(17u8, &client_key);
+    assert_eq!(a.content_hash(), a.clone().content_hash());
+    assert_ne!(a.content_hash(), b.content_hash());
+
+    // The hash does not depend on the serialization
+    let serialized = bincode::serialize(&a).unwrap();
+    let deserialized: FheUint8 = bincode::deserialize(&serialized).unwrap();
+    assert_eq!(a.content_hash(), deserialized.content_hash());
+
+    // Signed and unsigned integers with the same blocks do not collide
+    let signed = FheInt8::encrypt(17i8, &client_key);
+    let signed_blocks = RadixCiphertext::from(signed.ciphertext.blocks().to_vec());
+    assert_ne!(signed.content_hash(), signed_blocks.content_hash());
+
+    let values = vec![a.clone(), b];
+    assert_eq!(values.content_hash(), values.clone().content_hash());
+    assert_ne!(values.content_hash(), vec![a].content_hash());
+}
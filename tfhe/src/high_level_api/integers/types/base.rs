@@ -4,25 +4,38 @@ use std::ops::{
     Mul, MulAssign, Neg, Not, Rem, RemAssign, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
 };
 
+#[cfg(feature = "block-serialization")]
+use crate::block_serialization::{
+    deserialize_radix_blocks, serialize_radix_blocks, BlockSerializationError,
+};
 use crate::conformance::ParameterSetConformant;
+use crate::content_hash::{ContentHash, ContentHasher};
 use crate::errors::{
     UninitializedClientKey, UninitializedCompressedPublicKey, UninitializedPublicKey,
     UnwrapResultExt,
 };
+use crate::high_level_api::debug::{integer_blocks_report, DebugDecrypt};
 use crate::high_level_api::global_state::WithGlobalKey;
+use crate::high_level_api::hoisting::{hoist_operation, hoist_scalar_operation};
 use crate::high_level_api::integers::parameters::IntegerParameter;
+use crate::high_level_api::integers::types::ordering::FheOrdering;
 use crate::high_level_api::integers::IntegerServerKey;
 use crate::high_level_api::internal_traits::{DecryptionKey, EncryptionKey, TypeIdentifier};
 use crate::high_level_api::keys::{CompressedPublicKey, RefKeyFromKeyChain};
+use crate::high_level_api::provenance::{value_hash, Provenance, ProvenanceChain, ProvenanceHash};
+use crate::high_level_api::recorder::{
+    record_block_operation, record_operation, record_operation_assign,
+};
 use crate::high_level_api::traits::{
-    DivRem, FheBootstrap, FheDecrypt, FheEq, FheMax, FheMin, FheOrd, FheTrivialEncrypt,
-    FheTryEncrypt, FheTryTrivialEncrypt, RotateLeft, RotateLeftAssign, RotateRight,
-    RotateRightAssign,
+    CheckedDivRem, DivRem, FheBootstrap, FheCmp, FheDecrypt, FheEq, FheMax, FheMin, FheOrd,
+    FheTrivialEncrypt, FheTryEncrypt, FheTryTrivialEncrypt, RotateLeft, RotateLeftAssign,
+    RotateRight, RotateRightAssign,
 };
 use crate::high_level_api::{ClientKey, PublicKey};
 use crate::integer::block_decomposition::DecomposableInto;
 use crate::integer::ciphertext::{IntegerRadixCiphertext, RadixCiphertext};
 use crate::integer::parameters::RadixCiphertextConformanceParams;
+use crate::integer::server_key::{Reciprocable, RoundingMode, ScalarMultiplier};
 use crate::integer::{IntegerCiphertext, SignedRadixCiphertext, I256, U256};
 use crate::named::Named;
 use crate::CompactPublicKey;
@@ -99,6 +112,8 @@ impl std::fmt::Display for GenericIntegerBlockError {
 pub struct GenericInteger<P: IntegerParameter> {
     pub(in crate::high_level_api::integers) ciphertext: P::InnerCiphertext,
     pub(in crate::high_level_api::integers) id: P::Id,
+    #[serde(skip)]
+    pub(in crate::high_level_api::integers) provenance: Option<Provenance>,
 }
 
 impl<P: IntegerParameter> ParameterSetConformant for GenericInteger<P>
@@ -115,6 +130,65 @@ impl<P: IntegerParameter> Named for GenericInteger<P> {
     const NAME: &'static str = "high_level_api::GenericInteger";
 }
 
+impl<P> ContentHash for GenericInteger<P>
+where
+    P: IntegerParameter,
+    P::InnerCiphertext: ContentHash,
+{
+    fn hash_content(&self, hasher: &mut ContentHasher) {
+        self.ciphertext.hash_content(hasher);
+    }
+}
+
+#[cfg(feature = "block-serialization")]
+impl<P> GenericInteger<P>
+where
+    P: IntegerParameter,
+{
+    /// Serializes the value so that each of its blocks can be read or replaced on its own
+    ///
+    /// See [crate::block_serialization].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let config = ConfigBuilder::all_disabled()
+    ///     .enable_default_integers()
+    ///     .build();
+    /// let (client_key, server_key) = generate_keys(config);
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint8::encrypt(27u8, &client_key);
+    /// let bytes = a.serialize_blocks().unwrap();
+    ///
+    /// let b = FheUint8::deserialize_blocks(&bytes).unwrap();
+    /// let decrypted: u8 = b.decrypt(&client_key);
+    /// assert_eq!(decrypted, 27);
+    /// ```
+    pub fn serialize_blocks(&self) -> Result<Vec<u8>, BlockSerializationError> {
+        serialize_radix_blocks(&self.ciphertext)
+    }
+
+    /// Deserializes a value serialized with [Self::serialize_blocks]
+    pub fn deserialize_blocks(bytes: &[u8]) -> Result<Self, BlockSerializationError>
+    where
+        P::Id: Default,
+    {
+        let ciphertext: P::InnerCiphertext = deserialize_radix_blocks(bytes)?;
+        let num_blocks = ciphertext.blocks().len();
+        if num_blocks != P::num_blocks() {
+            return Err(BlockSerializationError::InvalidFormat(format!(
+                "Expected {} blocks, got {num_blocks}",
+                P::num_blocks()
+            )));
+        }
+        Ok(Self::new(ciphertext, P::Id::default()))
+    }
+}
+
 impl<P> GenericInteger<P>
 where
     P: IntegerParameter,
@@ -123,7 +197,73 @@ where
         ciphertext: P::InnerCiphertext,
         id: P::Id,
     ) -> Self {
-        Self { ciphertext, id }
+        Self {
+            ciphertext,
+            id,
+            provenance: None,
+        }
+    }
+
+    pub(in crate::high_level_api::integers) fn with_provenance(
+        mut self,
+        provenance: Option<Provenance>,
+    ) -> Self {
+        self.provenance = provenance;
+        self
+    }
+
+    /// Starts attaching to the results of the operations on this value the
+    /// [provenance](crate::high_level_api::ProvenanceChain) of the results
+    ///
+    /// The provenance is not serialized with the value, and has no effect on the computations.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let config = ConfigBuilder::all_disabled()
+    ///     .enable_default_integers()
+    ///     .build();
+    /// let (client_key, server_key) = generate_keys(config);
+    /// set_server_key(server_key);
+    ///
+    /// let mut a = FheUint8::encrypt(27u8, &client_key);
+    /// let b = FheUint8::encrypt(100u8, &client_key);
+    /// a.enable_provenance();
+    ///
+    /// let c = &a + &b;
+    /// let d = &c * 2u8;
+    ///
+    /// let chain = d.provenance().unwrap();
+    /// assert!(chain.verify());
+    /// assert_eq!(chain.records.len(), 2);
+    /// assert_eq!(chain.records[0].operation, "add");
+    /// assert_eq!(chain.records[0].inputs, [a.provenance_hash(), b.provenance_hash()]);
+    /// assert_eq!(chain.records[1].output, d.provenance_hash());
+    /// // b was not derived from a
+    /// assert!(b.provenance().is_none());
+    /// ```
+    pub fn enable_provenance(&mut self) {
+        self.provenance.get_or_insert_with(Provenance::default);
+    }
+
+    /// Returns the records of the operations this value was derived from, if its provenance or
+    /// the one of a value it was derived from was [enabled](Self::enable_provenance)
+    ///
+    /// The records are attached by the operators, the comparisons and the other operations
+    /// recorded by an [OperationRecorder](crate::high_level_api::OperationRecorder), the
+    /// provenance is lost by the other operations.
+    pub fn provenance(&self) -> Option<ProvenanceChain> {
+        self.provenance.as_ref().map(Provenance::chain)
+    }
+
+    /// Returns the hash identifying the value in a [ProvenanceChain]
+    ///
+    /// It is the SHA-256 digest of the blocks of the value.
+    pub fn provenance_hash(&self) -> ProvenanceHash {
+        value_hash(self.ciphertext.blocks())
     }
 
     pub fn cast_from<P2>(other: GenericInteger<P2>) -> Self
@@ -193,6 +333,120 @@ where
         })
     }
 
+    /// Casts to a type with at most as many bits, and returns an encryption of 1 if the value
+    /// does not fit in the target type (0 otherwise)
+    ///
+    /// The value is truncated as with [Self::cast_into]. The overflow flag is encrypted in the
+    /// target type, as the result of a comparison would be.
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the target type has more bits than `Self`
+    /// - Panics if the target type does not have the same signedness as `Self`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16, FheUint8};
+    ///
+    /// let config = ConfigBuilder::all_disabled()
+    ///     .enable_default_integers()
+    ///     .build();
+    /// let (client_key, server_key) = generate_keys(config);
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::encrypt(300u16, &client_key);
+    ///
+    /// let (narrowed, overflowed): (FheUint8, FheUint8) = a.cast_narrow_checked();
+    /// let decrypted: u8 = narrowed.decrypt(&client_key);
+    /// assert_eq!(decrypted, 300u16 as u8);
+    /// let decrypted: u8 = overflowed.decrypt(&client_key);
+    /// assert_eq!(decrypted, 1);
+    ///
+    /// let saturated: FheUint8 = a.cast_narrow_saturating();
+    /// let decrypted: u8 = saturated.decrypt(&client_key);
+    /// assert_eq!(decrypted, u8::MAX);
+    /// ```
+    pub fn cast_narrow_checked<P2>(&self) -> (GenericInteger<P2>, GenericInteger<P2>)
+    where
+        P2: IntegerParameter,
+        P2::Id: Default,
+    {
+        let num_blocks_to_remove = Self::num_blocks_to_remove_when_narrowing::<P2>();
+        crate::high_level_api::global_state::with_internal_keys(|keys| {
+            let integer_key = keys.integer_key.pbs_key();
+            let (ciphertext, overflowed) = integer_key
+                .overflowing_trim_radix_blocks_msb_parallelized(
+                    &self.ciphertext,
+                    num_blocks_to_remove,
+                );
+
+            let mut overflowed_blocks = vec![overflowed];
+            overflowed_blocks.resize_with(P2::num_blocks(), || integer_key.key.create_trivial(0));
+            (
+                GenericInteger::<P2>::new(
+                    P2::InnerCiphertext::from_blocks(ciphertext.into_blocks()),
+                    P2::Id::default(),
+                ),
+                GenericInteger::<P2>::new(
+                    P2::InnerCiphertext::from_blocks(overflowed_blocks),
+                    P2::Id::default(),
+                ),
+            )
+        })
+    }
+
+    /// Casts to a type with at most as many bits, saturating at the numeric bounds of the target
+    /// type instead of truncating the value
+    ///
+    /// See [Self::cast_narrow_checked].
+    ///
+    /// # Panics
+    ///
+    /// - Panics if the target type has more bits than `Self`
+    /// - Panics if the target type does not have the same signedness as `Self`
+    pub fn cast_narrow_saturating<P2>(&self) -> GenericInteger<P2>
+    where
+        P2: IntegerParameter,
+        P2::Id: Default,
+    {
+        let num_blocks_to_remove = Self::num_blocks_to_remove_when_narrowing::<P2>();
+        let ciphertext = crate::high_level_api::global_state::with_internal_keys(|keys| {
+            keys.integer_key
+                .pbs_key()
+                .saturating_trim_radix_blocks_msb_parallelized(
+                    &self.ciphertext,
+                    num_blocks_to_remove,
+                )
+        });
+
+        GenericInteger::<P2>::new(
+            P2::InnerCiphertext::from_blocks(ciphertext.into_blocks()),
+            P2::Id::default(),
+        )
+    }
+
+    fn num_blocks_to_remove_when_narrowing<P2>() -> usize
+    where
+        P2: IntegerParameter,
+    {
+        assert_eq!(
+            P::InnerCiphertext::IS_SIGNED,
+            P2::InnerCiphertext::IS_SIGNED,
+            "Narrowing casts require types of the same signedness"
+        );
+        P::num_blocks()
+            .checked_sub(P2::num_blocks())
+            .unwrap_or_else(|| {
+                panic!(
+                    "Cannot narrow a type of {} blocks to a type of {} blocks",
+                    P::num_blocks(),
+                    P2::num_blocks()
+                )
+            })
+    }
+
     pub fn abs(&self) -> Self {
         let ciphertext = crate::high_level_api::global_state::with_internal_keys(|keys| {
             keys.integer_key
@@ -202,6 +456,217 @@ where
 
         Self::new(ciphertext, self.id)
     }
+
+    /// Returns a number representing the sign of `self`
+    ///
+    /// - 0 if the number is zero
+    /// - 1 if the number is positive
+    /// - -1 if the number is negative
+    pub fn signum(&self) -> Self {
+        let ciphertext = crate::high_level_api::global_state::with_internal_keys(|keys| {
+            keys.integer_key
+                .pbs_key()
+                .signum_parallelized(&self.ciphertext)
+        });
+
+        Self::new(ciphertext, self.id)
+    }
+
+    /// Returns a number with the magnitude of `self` and the sign of `sign`
+    ///
+    /// As with [Self::abs], the magnitude of the minimum value cannot be represented,
+    /// so it is returned unchanged.
+    pub fn copysign(&self, sign: &Self) -> Self {
+        let ciphertext = crate::high_level_api::global_state::with_internal_keys(|keys| {
+            keys.integer_key
+                .pbs_key()
+                .copysign_parallelized(&self.ciphertext, &sign.ciphertext)
+        });
+
+        Self::new(ciphertext, self.id)
+    }
+
+    /// Saturating addition
+    ///
+    /// Computes `self + rhs`, saturating at the numeric bounds
+    /// instead of overflowing.
+    pub fn saturating_add(&self, rhs: &Self) -> Self {
+        let ciphertext = crate::high_level_api::global_state::with_internal_keys(|keys| {
+            keys.integer_key
+                .pbs_key()
+                .saturating_add_parallelized(&self.ciphertext, &rhs.ciphertext)
+        });
+
+        Self::new(ciphertext, self.id)
+    }
+
+    /// Saturating subtraction
+    ///
+    /// Computes `self - rhs`, saturating at the numeric bounds
+    /// instead of overflowing.
+    pub fn saturating_sub(&self, rhs: &Self) -> Self {
+        let ciphertext = crate::high_level_api::global_state::with_internal_keys(|keys| {
+            keys.integer_key
+                .pbs_key()
+                .saturating_sub_parallelized(&self.ciphertext, &rhs.ciphertext)
+        });
+
+        Self::new(ciphertext, self.id)
+    }
+
+    /// Returns an encryption of the bit at position `index`, i.e. 0 or 1
+    ///
+    /// The bit 0 is the least significant bit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater or equal to the number of bits of the type
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let config = ConfigBuilder::all_disabled()
+    ///     .enable_default_integers()
+    ///     .build();
+    /// let (client_key, server_key) = generate_keys(config);
+    /// set_server_key(server_key);
+    ///
+    /// let mut a = FheUint8::encrypt(0b0110_1001u8, &client_key);
+    ///
+    /// let bit = a.get_bit(3);
+    /// let decrypted: u8 = bit.decrypt(&client_key);
+    /// assert_eq!(decrypted, 1);
+    ///
+    /// a.set_bit(7, &bit);
+    /// let decrypted: u8 = a.decrypt(&client_key);
+    /// assert_eq!(decrypted, 0b1110_1001);
+    ///
+    /// let mut bits = a.to_bits();
+    /// bits.reverse();
+    /// let reversed = FheUint8::from_bits(&bits);
+    /// let decrypted: u8 = reversed.decrypt(&client_key);
+    /// assert_eq!(decrypted, 0b1001_0111);
+    /// ```
+    pub fn get_bit(&self, index: usize) -> Self {
+        let ciphertext = crate::high_level_api::global_state::with_internal_keys(|keys| {
+            let integer_key = keys.integer_key.pbs_key();
+            let bit = integer_key.get_bit_parallelized(&self.ciphertext, index);
+
+            let mut blocks = vec![bit];
+            blocks.resize_with(P::num_blocks(), || integer_key.key.create_trivial(0));
+            P::InnerCiphertext::from_blocks(blocks)
+        });
+
+        Self::new(ciphertext, self.id)
+    }
+
+    /// Sets the bit at position `index` to the value of `bit`, which must encrypt 0 or 1
+    ///
+    /// See [Self::get_bit].
+    pub fn set_bit(&mut self, index: usize, bit: &Self) {
+        crate::high_level_api::global_state::with_internal_keys(|keys| {
+            keys.integer_key.pbs_key().set_bit_parallelized(
+                &mut self.ciphertext,
+                index,
+                &bit.ciphertext.blocks()[0],
+            )
+        });
+    }
+
+    /// Returns encryptions of the bits, least significant bit first, each being 0 or 1
+    ///
+    /// See [Self::get_bit].
+    pub fn to_bits(&self) -> Vec<Self> {
+        crate::high_level_api::global_state::with_internal_keys(|keys| {
+            let integer_key = keys.integer_key.pbs_key();
+            integer_key
+                .to_bits_parallelized(&self.ciphertext)
+                .into_iter()
+                .map(|bit| {
+                    let mut blocks = vec![bit];
+                    blocks.resize_with(P::num_blocks(), || integer_key.key.create_trivial(0));
+                    Self::new(P::InnerCiphertext::from_blocks(blocks), self.id)
+                })
+                .collect()
+        })
+    }
+
+    /// Creates a value from encryptions of its bits, least significant bit first, each of them
+    /// must encrypt 0 or 1
+    ///
+    /// Missing upper bits are zeros, extra bits are ignored. See [Self::get_bit].
+    pub fn from_bits(bits: &[Self]) -> Self
+    where
+        P::Id: Default,
+    {
+        let ciphertext = crate::high_level_api::global_state::with_internal_keys(|keys| {
+            let integer_key = keys.integer_key.pbs_key();
+            let num_bits = P::num_blocks() * integer_key.key.message_modulus.0.ilog2() as usize;
+            let mut bit_blocks = bits
+                .iter()
+                .take(num_bits)
+                .map(|bit| bit.ciphertext.blocks()[0].clone())
+                .collect::<Vec<_>>();
+            bit_blocks.resize_with(num_bits, || integer_key.key.create_trivial(0));
+
+            integer_key.from_bits_parallelized(&bit_blocks)
+        });
+
+        Self::new(ciphertext, P::Id::default())
+    }
+
+    /// Saturating multiplication
+    ///
+    /// Computes `self * rhs`, saturating at the numeric bounds
+    /// instead of overflowing.
+    pub fn saturating_mul(&self, rhs: &Self) -> Self {
+        let ciphertext = crate::high_level_api::global_state::with_internal_keys(|keys| {
+            keys.integer_key
+                .pbs_key()
+                .saturating_mul_parallelized(&self.ciphertext, &rhs.ciphertext)
+        });
+
+        Self::new(ciphertext, self.id)
+    }
+
+    /// Computes `self * mul + add`, wrapping around on overflow
+    ///
+    /// The multiplication and the addition share a single carry propagation, which makes it
+    /// cheaper than `self * mul + add`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint64};
+    ///
+    /// let config = ConfigBuilder::all_disabled()
+    ///     .enable_default_integers()
+    ///     .build();
+    /// let (client_key, server_key) = generate_keys(config);
+    /// set_server_key(server_key);
+    ///
+    /// let score = FheUint64::encrypt(1_250u64, &client_key);
+    /// let scaled = score.mul_add_clear(3u64, 40u64);
+    ///
+    /// let decrypted: u64 = scaled.decrypt(&client_key);
+    /// assert_eq!(decrypted, 1_250 * 3 + 40);
+    /// ```
+    pub fn mul_add_clear<Clear>(&self, mul: Clear, add: Clear) -> Self
+    where
+        Clear: ScalarMultiplier + DecomposableInto<u8> + DecomposableInto<u64>,
+    {
+        let ciphertext = crate::high_level_api::global_state::with_internal_keys(|keys| {
+            keys.integer_key
+                .pbs_key()
+                .scalar_mul_add_parallelized(&self.ciphertext, mul, add)
+        });
+
+        Self::new(ciphertext, self.id)
+    }
 }
 
 impl<P> GenericInteger<P>
@@ -219,15 +684,31 @@ where
     /// - if `self` is false (0), the output will have the value of `ct_else`
     pub fn if_then_else(&self, ct_then: &Self, ct_else: &Self) -> GenericInteger<P> {
         let ct_condition = self;
-        let new_ct = ct_condition.id.with_unwrapped_global(|integer_key| {
-            integer_key.pbs_key().if_then_else_parallelized(
-                &ct_condition.ciphertext,
-                &ct_then.ciphertext,
-                &ct_else.ciphertext,
-            )
-        });
+        let inputs = [
+            ct_condition.ciphertext.blocks(),
+            ct_then.ciphertext.blocks(),
+            ct_else.ciphertext.blocks(),
+        ];
+        let (new_ct, provenance) = record_operation(
+            "if_then_else_parallelized",
+            &inputs,
+            &[
+                &ct_condition.provenance,
+                &ct_then.provenance,
+                &ct_else.provenance,
+            ],
+            || {
+                ct_condition.id.with_unwrapped_global(|integer_key| {
+                    integer_key.pbs_key().if_then_else_parallelized(
+                        &ct_condition.ciphertext,
+                        &ct_then.ciphertext,
+                        &ct_else.ciphertext,
+                    )
+                })
+            },
+        );
 
-        GenericInteger::new(new_ct, ct_condition.id)
+        GenericInteger::new(new_ct, ct_condition.id).with_provenance(provenance)
     }
 
     /// Conditional selection.
@@ -238,6 +719,91 @@ where
     }
 }
 
+impl<P> GenericInteger<P>
+where
+    P: IntegerParameter<InnerCiphertext = RadixCiphertext>,
+    P::Id: WithGlobalKey<Key = IntegerServerKey>,
+{
+    /// Divides by a clear divisor, rounding the quotient with the given mode
+    ///
+    /// The `/` operator rounds down, i.e. truncates the quotient.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint32, RoundingMode};
+    ///
+    /// let config = ConfigBuilder::all_disabled()
+    ///     .enable_default_integers()
+    ///     .build();
+    /// let (client_key, server_key) = generate_keys(config);
+    /// set_server_key(server_key);
+    ///
+    /// // An amount in cents, converted to dollars
+    /// let amount = FheUint32::encrypt(1250u32, &client_key);
+    ///
+    /// let dollars = amount.div_rounded(100u32, RoundingMode::HalfEven);
+    /// let decrypted: u32 = dollars.decrypt(&client_key);
+    /// assert_eq!(decrypted, 12);
+    ///
+    /// let dollars = amount.div_rounded(100u32, RoundingMode::Ceil);
+    /// let decrypted: u32 = dollars.decrypt(&client_key);
+    /// assert_eq!(decrypted, 13);
+    /// ```
+    pub fn div_rounded<T>(&self, divisor: T, mode: RoundingMode) -> Self
+    where
+        T: Reciprocable + ScalarMultiplier + DecomposableInto<u8> + DecomposableInto<u64>,
+    {
+        let provenances = [&self.provenance];
+        let (ciphertext, provenance) = record_operation(
+            "div_rounded",
+            &[self.ciphertext.blocks()],
+            &provenances,
+            || {
+                self.id.with_unwrapped_global(|integer_key| {
+                    integer_key.pbs_key().scalar_div_rounded_parallelized(
+                        &self.ciphertext,
+                        divisor,
+                        mode,
+                    )
+                })
+            },
+        );
+
+        Self::new(ciphertext, self.id).with_provenance(provenance)
+    }
+
+    /// Shifts to the right by a clear amount, rounding the result with the given mode
+    ///
+    /// This is the division by `2^shift`, see [Self::div_rounded].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `shift` is greater or equal to the number of bits of the type.
+    pub fn shr_rounded(&self, shift: u32, mode: RoundingMode) -> Self {
+        let provenances = [&self.provenance];
+        let (ciphertext, provenance) = record_operation(
+            "shr_rounded",
+            &[self.ciphertext.blocks()],
+            &provenances,
+            || {
+                self.id.with_unwrapped_global(|integer_key| {
+                    integer_key
+                        .pbs_key()
+                        .scalar_right_shift_rounded_parallelized(&self.ciphertext, shift, mode)
+                })
+            },
+        );
+
+        Self::new(ciphertext, self.id).with_provenance(provenance)
+    }
+}
+
 impl<P> TryFrom<RadixCiphertext> for GenericInteger<P>
 where
     P: IntegerParameter<InnerCiphertext = RadixCiphertext>,
@@ -308,6 +874,17 @@ where
     }
 }
 
+impl<P> DebugDecrypt for GenericInteger<P>
+where
+    P: IntegerParameter,
+    P::Id: RefKeyFromKeyChain<Key = crate::integer::ClientKey>,
+{
+    fn debug_report(&self, key: &ClientKey) -> String {
+        let key = self.id.unwrapped_ref_key(key);
+        integer_blocks_report(key, self.ciphertext.blocks())
+    }
+}
+
 impl<P, T> FheTryEncrypt<T, ClientKey> for GenericInteger<P>
 where
     P: IntegerParameter,
@@ -444,12 +1021,17 @@ where
     type Output = Self;
 
     fn max(&self, rhs: &Self) -> Self::Output {
-        let inner_result = self.id.with_unwrapped_global(|integer_key| {
-            integer_key
-                .pbs_key()
-                .max_parallelized(&self.ciphertext, &rhs.ciphertext)
-        });
-        GenericInteger::new(inner_result, self.id)
+        let inputs = [self.ciphertext.blocks(), rhs.ciphertext.blocks()];
+        let provenances = [&self.provenance, &rhs.provenance];
+        let (inner_result, provenance) =
+            record_operation("max_parallelized", &inputs, &provenances, || {
+                self.id.with_unwrapped_global(|integer_key| {
+                    integer_key
+                        .pbs_key()
+                        .max_parallelized(&self.ciphertext, &rhs.ciphertext)
+                })
+            });
+        GenericInteger::new(inner_result, self.id).with_provenance(provenance)
     }
 }
 
@@ -463,12 +1045,17 @@ where
     type Output = Self;
 
     fn max(&self, rhs: Clear) -> Self::Output {
-        let inner_result = self.id.with_unwrapped_global(|integer_key| {
-            integer_key
-                .pbs_key()
-                .scalar_max_parallelized(&self.ciphertext, rhs)
-        });
-        GenericInteger::new(inner_result, self.id)
+        let inputs = [self.ciphertext.blocks()];
+        let provenances = [&self.provenance];
+        let (inner_result, provenance) =
+            record_operation("scalar_max_parallelized", &inputs, &provenances, || {
+                self.id.with_unwrapped_global(|integer_key| {
+                    integer_key
+                        .pbs_key()
+                        .scalar_max_parallelized(&self.ciphertext, rhs)
+                })
+            });
+        GenericInteger::new(inner_result, self.id).with_provenance(provenance)
     }
 }
 
@@ -481,12 +1068,17 @@ where
     type Output = Self;
 
     fn min(&self, rhs: &Self) -> Self::Output {
-        let inner_result = self.id.with_unwrapped_global(|integer_key| {
-            integer_key
-                .pbs_key()
-                .min_parallelized(&self.ciphertext, &rhs.ciphertext)
-        });
-        GenericInteger::new(inner_result, self.id)
+        let inputs = [self.ciphertext.blocks(), rhs.ciphertext.blocks()];
+        let provenances = [&self.provenance, &rhs.provenance];
+        let (inner_result, provenance) =
+            record_operation("min_parallelized", &inputs, &provenances, || {
+                self.id.with_unwrapped_global(|integer_key| {
+                    integer_key
+                        .pbs_key()
+                        .min_parallelized(&self.ciphertext, &rhs.ciphertext)
+                })
+            });
+        GenericInteger::new(inner_result, self.id).with_provenance(provenance)
     }
 }
 
@@ -500,12 +1092,17 @@ where
     type Output = Self;
 
     fn min(&self, rhs: Clear) -> Self::Output {
-        let inner_result = self.id.with_unwrapped_global(|integer_key| {
-            integer_key
-                .pbs_key()
-                .scalar_min_parallelized(&self.ciphertext, rhs)
-        });
-        GenericInteger::new(inner_result, self.id)
+        let inputs = [self.ciphertext.blocks()];
+        let provenances = [&self.provenance];
+        let (inner_result, provenance) =
+            record_operation("scalar_min_parallelized", &inputs, &provenances, || {
+                self.id.with_unwrapped_global(|integer_key| {
+                    integer_key
+                        .pbs_key()
+                        .scalar_min_parallelized(&self.ciphertext, rhs)
+                })
+            });
+        GenericInteger::new(inner_result, self.id).with_provenance(provenance)
     }
 }
 
@@ -518,21 +1115,31 @@ where
     type Output = Self;
 
     fn eq(&self, rhs: Self) -> Self::Output {
-        let inner_result = self.id.with_unwrapped_global(|integer_key| {
-            integer_key
-                .pbs_key()
-                .eq_parallelized(&self.ciphertext, &rhs.ciphertext)
-        });
-        GenericInteger::new(inner_result, self.id)
+        let inputs = [self.ciphertext.blocks(), rhs.ciphertext.blocks()];
+        let provenances = [&self.provenance, &rhs.provenance];
+        let (inner_result, provenance) =
+            record_operation("eq_parallelized", &inputs, &provenances, || {
+                self.id.with_unwrapped_global(|integer_key| {
+                    integer_key
+                        .pbs_key()
+                        .eq_parallelized(&self.ciphertext, &rhs.ciphertext)
+                })
+            });
+        GenericInteger::new(inner_result, self.id).with_provenance(provenance)
     }
 
     fn ne(&self, rhs: Self) -> Self::Output {
-        let inner_result = self.id.with_unwrapped_global(|integer_key| {
-            integer_key
-                .pbs_key()
-                .ne_parallelized(&self.ciphertext, &rhs.ciphertext)
-        });
-        GenericInteger::new(inner_result, self.id)
+        let inputs = [self.ciphertext.blocks(), rhs.ciphertext.blocks()];
+        let provenances = [&self.provenance, &rhs.provenance];
+        let (inner_result, provenance) =
+            record_operation("ne_parallelized", &inputs, &provenances, || {
+                self.id.with_unwrapped_global(|integer_key| {
+                    integer_key
+                        .pbs_key()
+                        .ne_parallelized(&self.ciphertext, &rhs.ciphertext)
+                })
+            });
+        GenericInteger::new(inner_result, self.id).with_provenance(provenance)
     }
 }
 
@@ -545,21 +1152,31 @@ where
     type Output = Self;
 
     fn eq(&self, rhs: &Self) -> Self::Output {
-        let inner_result = self.id.with_unwrapped_global(|integer_key| {
-            integer_key
-                .pbs_key()
-                .eq_parallelized(&self.ciphertext, &rhs.ciphertext)
-        });
-        GenericInteger::new(inner_result, self.id)
+        let inputs = [self.ciphertext.blocks(), rhs.ciphertext.blocks()];
+        let provenances = [&self.provenance, &rhs.provenance];
+        let (inner_result, provenance) =
+            record_operation("eq_parallelized", &inputs, &provenances, || {
+                self.id.with_unwrapped_global(|integer_key| {
+                    integer_key
+                        .pbs_key()
+                        .eq_parallelized(&self.ciphertext, &rhs.ciphertext)
+                })
+            });
+        GenericInteger::new(inner_result, self.id).with_provenance(provenance)
     }
 
     fn ne(&self, rhs: &Self) -> Self::Output {
-        let inner_result = self.id.with_unwrapped_global(|integer_key| {
-            integer_key
-                .pbs_key()
-                .ne_parallelized(&self.ciphertext, &rhs.ciphertext)
-        });
-        GenericInteger::new(inner_result, self.id)
+        let inputs = [self.ciphertext.blocks(), rhs.ciphertext.blocks()];
+        let provenances = [&self.provenance, &rhs.provenance];
+        let (inner_result, provenance) =
+            record_operation("ne_parallelized", &inputs, &provenances, || {
+                self.id.with_unwrapped_global(|integer_key| {
+                    integer_key
+                        .pbs_key()
+                        .ne_parallelized(&self.ciphertext, &rhs.ciphertext)
+                })
+            });
+        GenericInteger::new(inner_result, self.id).with_provenance(provenance)
     }
 }
 
@@ -573,21 +1190,31 @@ where
     type Output = Self;
 
     fn eq(&self, rhs: Clear) -> Self::Output {
-        let inner_result = self.id.with_unwrapped_global(|integer_key| {
-            integer_key
-                .pbs_key()
-                .scalar_eq_parallelized(&self.ciphertext, rhs)
-        });
-        GenericInteger::new(inner_result, self.id)
+        let inputs = [self.ciphertext.blocks()];
+        let provenances = [&self.provenance];
+        let (inner_result, provenance) =
+            record_operation("scalar_eq_parallelized", &inputs, &provenances, || {
+                self.id.with_unwrapped_global(|integer_key| {
+                    integer_key
+                        .pbs_key()
+                        .scalar_eq_parallelized(&self.ciphertext, rhs)
+                })
+            });
+        GenericInteger::new(inner_result, self.id).with_provenance(provenance)
     }
 
     fn ne(&self, rhs: Clear) -> Self::Output {
-        let inner_result = self.id.with_unwrapped_global(|integer_key| {
-            integer_key
-                .pbs_key()
-                .scalar_ne_parallelized(&self.ciphertext, rhs)
-        });
-        GenericInteger::new(inner_result, self.id)
+        let inputs = [self.ciphertext.blocks()];
+        let provenances = [&self.provenance];
+        let (inner_result, provenance) =
+            record_operation("scalar_ne_parallelized", &inputs, &provenances, || {
+                self.id.with_unwrapped_global(|integer_key| {
+                    integer_key
+                        .pbs_key()
+                        .scalar_ne_parallelized(&self.ciphertext, rhs)
+                })
+            });
+        GenericInteger::new(inner_result, self.id).with_provenance(provenance)
     }
 }
 
@@ -600,39 +1227,59 @@ where
     type Output = Self;
 
     fn lt(&self, rhs: Self) -> Self::Output {
-        let inner_result = self.id.with_unwrapped_global(|integer_key| {
-            integer_key
-                .pbs_key()
-                .lt_parallelized(&self.ciphertext, &rhs.ciphertext)
-        });
-        GenericInteger::new(inner_result, self.id)
+        let inputs = [self.ciphertext.blocks(), rhs.ciphertext.blocks()];
+        let provenances = [&self.provenance, &rhs.provenance];
+        let (inner_result, provenance) =
+            record_operation("lt_parallelized", &inputs, &provenances, || {
+                self.id.with_unwrapped_global(|integer_key| {
+                    integer_key
+                        .pbs_key()
+                        .lt_parallelized(&self.ciphertext, &rhs.ciphertext)
+                })
+            });
+        GenericInteger::new(inner_result, self.id).with_provenance(provenance)
     }
 
     fn le(&self, rhs: Self) -> Self::Output {
-        let inner_result = self.id.with_unwrapped_global(|integer_key| {
-            integer_key
-                .pbs_key()
-                .le_parallelized(&self.ciphertext, &rhs.ciphertext)
-        });
-        GenericInteger::new(inner_result, self.id)
+        let inputs = [self.ciphertext.blocks(), rhs.ciphertext.blocks()];
+        let provenances = [&self.provenance, &rhs.provenance];
+        let (inner_result, provenance) =
+            record_operation("le_parallelized", &inputs, &provenances, || {
+                self.id.with_unwrapped_global(|integer_key| {
+                    integer_key
+                        .pbs_key()
+                        .le_parallelized(&self.ciphertext, &rhs.ciphertext)
+                })
+            });
+        GenericInteger::new(inner_result, self.id).with_provenance(provenance)
     }
 
     fn gt(&self, rhs: Self) -> Self::Output {
-        let inner_result = self.id.with_unwrapped_global(|integer_key| {
-            integer_key
-                .pbs_key()
-                .gt_parallelized(&self.ciphertext, &rhs.ciphertext)
-        });
-        GenericInteger::new(inner_result, self.id)
+        let inputs = [self.ciphertext.blocks(), rhs.ciphertext.blocks()];
+        let provenances = [&self.provenance, &rhs.provenance];
+        let (inner_result, provenance) =
+            record_operation("gt_parallelized", &inputs, &provenances, || {
+                self.id.with_unwrapped_global(|integer_key| {
+                    integer_key
+                        .pbs_key()
+                        .gt_parallelized(&self.ciphertext, &rhs.ciphertext)
+                })
+            });
+        GenericInteger::new(inner_result, self.id).with_provenance(provenance)
     }
 
     fn ge(&self, rhs: Self) -> Self::Output {
-        let inner_result = self.id.with_unwrapped_global(|integer_key| {
-            integer_key
-                .pbs_key()
-                .ge_parallelized(&self.ciphertext, &rhs.ciphertext)
-        });
-        GenericInteger::new(inner_result, self.id)
+        let inputs = [self.ciphertext.blocks(), rhs.ciphertext.blocks()];
+        let provenances = [&self.provenance, &rhs.provenance];
+        let (inner_result, provenance) =
+            record_operation("ge_parallelized", &inputs, &provenances, || {
+                self.id.with_unwrapped_global(|integer_key| {
+                    integer_key
+                        .pbs_key()
+                        .ge_parallelized(&self.ciphertext, &rhs.ciphertext)
+                })
+            });
+        GenericInteger::new(inner_result, self.id).with_provenance(provenance)
     }
 }
 
@@ -645,39 +1292,59 @@ where
     type Output = Self;
 
     fn lt(&self, rhs: &Self) -> Self::Output {
-        let inner_result = self.id.with_unwrapped_global(|integer_key| {
-            integer_key
-                .pbs_key()
-                .lt_parallelized(&self.ciphertext, &rhs.ciphertext)
-        });
-        GenericInteger::new(inner_result, self.id)
+        let inputs = [self.ciphertext.blocks(), rhs.ciphertext.blocks()];
+        let provenances = [&self.provenance, &rhs.provenance];
+        let (inner_result, provenance) =
+            record_operation("lt_parallelized", &inputs, &provenances, || {
+                self.id.with_unwrapped_global(|integer_key| {
+                    integer_key
+                        .pbs_key()
+                        .lt_parallelized(&self.ciphertext, &rhs.ciphertext)
+                })
+            });
+        GenericInteger::new(inner_result, self.id).with_provenance(provenance)
     }
 
     fn le(&self, rhs: &Self) -> Self::Output {
-        let inner_result = self.id.with_unwrapped_global(|integer_key| {
-            integer_key
-                .pbs_key()
-                .le_parallelized(&self.ciphertext, &rhs.ciphertext)
-        });
-        GenericInteger::new(inner_result, self.id)
+        let inputs = [self.ciphertext.blocks(), rhs.ciphertext.blocks()];
+        let provenances = [&self.provenance, &rhs.provenance];
+        let (inner_result, provenance) =
+            record_operation("le_parallelized", &inputs, &provenances, || {
+                self.id.with_unwrapped_global(|integer_key| {
+                    integer_key
+                        .pbs_key()
+                        .le_parallelized(&self.ciphertext, &rhs.ciphertext)
+                })
+            });
+        GenericInteger::new(inner_result, self.id).with_provenance(provenance)
     }
 
     fn gt(&self, rhs: &Self) -> Self::Output {
-        let inner_result = self.id.with_unwrapped_global(|integer_key| {
-            integer_key
-                .pbs_key()
-                .gt_parallelized(&self.ciphertext, &rhs.ciphertext)
-        });
-        GenericInteger::new(inner_result, self.id)
+        let inputs = [self.ciphertext.blocks(), rhs.ciphertext.blocks()];
+        let provenances = [&self.provenance, &rhs.provenance];
+        let (inner_result, provenance) =
+            record_operation("gt_parallelized", &inputs, &provenances, || {
+                self.id.with_unwrapped_global(|integer_key| {
+                    integer_key
+                        .pbs_key()
+                        .gt_parallelized(&self.ciphertext, &rhs.ciphertext)
+                })
+            });
+        GenericInteger::new(inner_result, self.id).with_provenance(provenance)
     }
 
     fn ge(&self, rhs: &Self) -> Self::Output {
-        let inner_result = self.id.with_unwrapped_global(|integer_key| {
-            integer_key
-                .pbs_key()
-                .ge_parallelized(&self.ciphertext, &rhs.ciphertext)
-        });
-        GenericInteger::new(inner_result, self.id)
+        let inputs = [self.ciphertext.blocks(), rhs.ciphertext.blocks()];
+        let provenances = [&self.provenance, &rhs.provenance];
+        let (inner_result, provenance) =
+            record_operation("ge_parallelized", &inputs, &provenances, || {
+                self.id.with_unwrapped_global(|integer_key| {
+                    integer_key
+                        .pbs_key()
+                        .ge_parallelized(&self.ciphertext, &rhs.ciphertext)
+                })
+            });
+        GenericInteger::new(inner_result, self.id).with_provenance(provenance)
     }
 }
 
@@ -691,39 +1358,116 @@ where
     type Output = Self;
 
     fn lt(&self, rhs: Clear) -> Self::Output {
-        let inner_result = self.id.with_unwrapped_global(|integer_key| {
-            integer_key
-                .pbs_key()
-                .scalar_lt_parallelized(&self.ciphertext, rhs)
-        });
-        GenericInteger::new(inner_result, self.id)
+        let inputs = [self.ciphertext.blocks()];
+        let provenances = [&self.provenance];
+        let (inner_result, provenance) =
+            record_operation("scalar_lt_parallelized", &inputs, &provenances, || {
+                self.id.with_unwrapped_global(|integer_key| {
+                    integer_key
+                        .pbs_key()
+                        .scalar_lt_parallelized(&self.ciphertext, rhs)
+                })
+            });
+        GenericInteger::new(inner_result, self.id).with_provenance(provenance)
     }
 
     fn le(&self, rhs: Clear) -> Self::Output {
-        let inner_result = self.id.with_unwrapped_global(|integer_key| {
-            integer_key
-                .pbs_key()
-                .scalar_le_parallelized(&self.ciphertext, rhs)
-        });
-        GenericInteger::new(inner_result, self.id)
+        let inputs = [self.ciphertext.blocks()];
+        let provenances = [&self.provenance];
+        let (inner_result, provenance) =
+            record_operation("scalar_le_parallelized", &inputs, &provenances, || {
+                self.id.with_unwrapped_global(|integer_key| {
+                    integer_key
+                        .pbs_key()
+                        .scalar_le_parallelized(&self.ciphertext, rhs)
+                })
+            });
+        GenericInteger::new(inner_result, self.id).with_provenance(provenance)
+    }
+
+    fn gt(&self, rhs: Clear) -> Self::Output {
+        let inputs = [self.ciphertext.blocks()];
+        let provenances = [&self.provenance];
+        let (inner_result, provenance) =
+            record_operation("scalar_gt_parallelized", &inputs, &provenances, || {
+                self.id.with_unwrapped_global(|integer_key| {
+                    integer_key
+                        .pbs_key()
+                        .scalar_gt_parallelized(&self.ciphertext, rhs)
+                })
+            });
+        GenericInteger::new(inner_result, self.id).with_provenance(provenance)
+    }
+
+    fn ge(&self, rhs: Clear) -> Self::Output {
+        let inputs = [self.ciphertext.blocks()];
+        let provenances = [&self.provenance];
+        let (inner_result, provenance) =
+            record_operation("scalar_ge_parallelized", &inputs, &provenances, || {
+                self.id.with_unwrapped_global(|integer_key| {
+                    integer_key
+                        .pbs_key()
+                        .scalar_ge_parallelized(&self.ciphertext, rhs)
+                })
+            });
+        GenericInteger::new(inner_result, self.id).with_provenance(provenance)
+    }
+}
+
+impl<P> FheCmp<Self> for GenericInteger<P>
+where
+    P: IntegerParameter,
+    P::Id: WithGlobalKey<Key = IntegerServerKey>,
+{
+    type Output = FheOrdering<P>;
+
+    fn cmp(&self, rhs: Self) -> Self::Output {
+        self.cmp(&rhs)
+    }
+}
+
+impl<P> FheCmp<&Self> for GenericInteger<P>
+where
+    P: IntegerParameter,
+    P::Id: WithGlobalKey<Key = IntegerServerKey>,
+{
+    type Output = FheOrdering<P>;
+
+    fn cmp(&self, rhs: &Self) -> Self::Output {
+        let inputs = [self.ciphertext.blocks(), rhs.ciphertext.blocks()];
+        let provenances = [&self.provenance, &rhs.provenance];
+        let (ordering, provenance) =
+            record_block_operation("cmp_parallelized", &inputs, &provenances, || {
+                self.id.with_unwrapped_global(|integer_key| {
+                    integer_key
+                        .pbs_key()
+                        .cmp_parallelized(&self.ciphertext, &rhs.ciphertext)
+                })
+            });
+        FheOrdering::new(ordering, self.id, provenance)
     }
+}
 
-    fn gt(&self, rhs: Clear) -> Self::Output {
-        let inner_result = self.id.with_unwrapped_global(|integer_key| {
-            integer_key
-                .pbs_key()
-                .scalar_gt_parallelized(&self.ciphertext, rhs)
-        });
-        GenericInteger::new(inner_result, self.id)
-    }
+impl<P, Clear> FheCmp<Clear> for GenericInteger<P>
+where
+    Clear: DecomposableInto<u64>,
+    P: IntegerParameter,
+    P::Id: WithGlobalKey<Key = IntegerServerKey>,
+{
+    type Output = FheOrdering<P>;
 
-    fn ge(&self, rhs: Clear) -> Self::Output {
-        let inner_result = self.id.with_unwrapped_global(|integer_key| {
-            integer_key
-                .pbs_key()
-                .scalar_ge_parallelized(&self.ciphertext, rhs)
-        });
-        GenericInteger::new(inner_result, self.id)
+    fn cmp(&self, rhs: Clear) -> Self::Output {
+        let inputs = [self.ciphertext.blocks()];
+        let provenances = [&self.provenance];
+        let (ordering, provenance) =
+            record_block_operation("scalar_cmp_parallelized", &inputs, &provenances, || {
+                self.id.with_unwrapped_global(|integer_key| {
+                    integer_key
+                        .pbs_key()
+                        .scalar_cmp_parallelized(&self.ciphertext, rhs)
+                })
+            });
+        FheOrdering::new(ordering, self.id, provenance)
     }
 }
 
@@ -783,6 +1527,91 @@ where
     }
 }
 
+impl<P> CheckedDivRem<GenericInteger<P>> for GenericInteger<P>
+where
+    P: IntegerParameter,
+    P::Id: WithGlobalKey<Key = IntegerServerKey>,
+{
+    type Output = (GenericInteger<P>, GenericInteger<P>, GenericInteger<P>);
+
+    fn checked_div_rem(self, rhs: GenericInteger<P>) -> Self::Output {
+        <&Self as CheckedDivRem<&GenericInteger<P>>>::checked_div_rem(&self, &rhs)
+    }
+}
+
+impl<P> CheckedDivRem<&GenericInteger<P>> for GenericInteger<P>
+where
+    P: IntegerParameter,
+    P::Id: WithGlobalKey<Key = IntegerServerKey>,
+{
+    type Output = (GenericInteger<P>, GenericInteger<P>, GenericInteger<P>);
+
+    fn checked_div_rem(self, rhs: &GenericInteger<P>) -> Self::Output {
+        <&Self as CheckedDivRem<&GenericInteger<P>>>::checked_div_rem(&self, rhs)
+    }
+}
+
+impl<P> CheckedDivRem<GenericInteger<P>> for &GenericInteger<P>
+where
+    P: IntegerParameter,
+    P::Id: WithGlobalKey<Key = IntegerServerKey>,
+{
+    type Output = (GenericInteger<P>, GenericInteger<P>, GenericInteger<P>);
+
+    fn checked_div_rem(self, rhs: GenericInteger<P>) -> Self::Output {
+        <Self as CheckedDivRem<&GenericInteger<P>>>::checked_div_rem(self, &rhs)
+    }
+}
+
+/// Returns `(quotient, remainder, divisor_is_zero)`.
+///
+/// `divisor_is_zero` encrypts 1 if the divisor is zero and 0 otherwise,
+/// in which case the quotient is 0 and the remainder is the numerator.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+///
+/// let config = ConfigBuilder::all_disabled()
+///     .enable_default_integers()
+///     .build();
+/// let (client_key, server_key) = generate_keys(config);
+/// set_server_key(server_key);
+///
+/// let a = FheUint8::encrypt(37u8, &client_key);
+/// let b = FheUint8::encrypt(0u8, &client_key);
+///
+/// let (q, r, divisor_is_zero) = (&a).checked_div_rem(&b);
+/// let q: u8 = q.decrypt(&client_key);
+/// let r: u8 = r.decrypt(&client_key);
+/// let divisor_is_zero: u8 = divisor_is_zero.decrypt(&client_key);
+/// assert_eq!(q, 0);
+/// assert_eq!(r, 37);
+/// assert_eq!(divisor_is_zero, 1);
+/// ```
+impl<P> CheckedDivRem<&GenericInteger<P>> for &GenericInteger<P>
+where
+    P: IntegerParameter,
+    P::Id: WithGlobalKey<Key = IntegerServerKey>,
+{
+    type Output = (GenericInteger<P>, GenericInteger<P>, GenericInteger<P>);
+
+    fn checked_div_rem(self, rhs: &GenericInteger<P>) -> Self::Output {
+        let (q, r, divisor_is_zero) = self.id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .checked_div_rem_parallelized(&self.ciphertext, &rhs.ciphertext)
+        });
+        (
+            GenericInteger::<P>::new(q, self.id),
+            GenericInteger::<P>::new(r, self.id),
+            GenericInteger::<P>::new(divisor_is_zero, self.id),
+        )
+    }
+}
+
 impl<P> DivRem<GenericInteger<P>> for GenericInteger<P>
 where
     P: IntegerParameter,
@@ -900,12 +1729,18 @@ macro_rules! generic_integer_impl_shift_rotate (
             type Output = GenericInteger<P>;
 
             fn $rust_trait_method(self, rhs: &GenericInteger<P2>) -> Self::Output {
-                let ciphertext = self.id.with_unwrapped_global(|integer_key| {
-                    integer_key
-                        .pbs_key()
-                        .$key_method(&self.ciphertext, &rhs.ciphertext)
-                });
-                GenericInteger::<P>::new(ciphertext, self.id)
+                let inputs = [self.ciphertext.blocks(), rhs.ciphertext.blocks()];
+                let provenances = [&self.provenance, &rhs.provenance];
+                let (ciphertext, provenance) =
+                    record_operation(stringify!($key_method), &inputs, &provenances, || {
+                        self.id.with_unwrapped_global(|integer_key| {
+                            let key = integer_key.pbs_key();
+                            hoist_operation(key, stringify!($key_method), &inputs).unwrap_or_else(
+                                || key.$key_method(&self.ciphertext, &rhs.ciphertext),
+                            )
+                        })
+                    });
+                GenericInteger::<P>::new(ciphertext, self.id).with_provenance(provenance)
             }
         }
     }
@@ -933,11 +1768,27 @@ macro_rules! generic_integer_impl_shift_rotate_assign(
             P2: IntegerParameter<InnerCiphertext=RadixCiphertext>,
         {
             fn $rust_trait_method(&mut self, rhs: &GenericInteger<P2>) {
-                self.id.with_unwrapped_global(|integer_key| {
-                    integer_key
-                        .pbs_key()
-                        .$key_method(&mut self.ciphertext, &rhs.ciphertext)
-                })
+                let id = self.id;
+                let inputs = [rhs.ciphertext.blocks()];
+                let lhs = &mut self.ciphertext;
+                let lhs_provenance = &mut self.provenance;
+                record_operation_assign(
+                    stringify!($key_method),
+                    lhs,
+                    lhs_provenance,
+                    &inputs,
+                    &[&rhs.provenance],
+                    |ciphertext| {
+                        id.with_unwrapped_global(|integer_key| {
+                            let key = integer_key.pbs_key();
+                            let hoist_inputs = [ciphertext.blocks(), rhs.ciphertext.blocks()];
+                            match hoist_operation(key, stringify!($key_method), &hoist_inputs) {
+                                Some(result) => *ciphertext = result,
+                                None => key.$key_method(ciphertext, &rhs.ciphertext),
+                            }
+                        })
+                    },
+                )
             }
         }
     }
@@ -971,13 +1822,19 @@ macro_rules! generic_integer_impl_operation (
             type Output = GenericInteger<P>;
 
             fn $rust_trait_method(self, rhs: B) -> Self::Output {
-                let ciphertext = self.id.with_unwrapped_global(|integer_key| {
-                    let borrowed = rhs.borrow();
-                    integer_key
-                        .pbs_key()
-                        .$key_method(&self.ciphertext, &borrowed.ciphertext)
-                });
-                GenericInteger::<P>::new(ciphertext, self.id)
+                let borrowed = rhs.borrow();
+                let inputs = [self.ciphertext.blocks(), borrowed.ciphertext.blocks()];
+                let provenances = [&self.provenance, &borrowed.provenance];
+                let (ciphertext, provenance) =
+                    record_operation(stringify!($key_method), &inputs, &provenances, || {
+                        self.id.with_unwrapped_global(|integer_key| {
+                            let key = integer_key.pbs_key();
+                            hoist_operation(key, stringify!($key_method), &inputs).unwrap_or_else(
+                                || key.$key_method(&self.ciphertext, &borrowed.ciphertext),
+                            )
+                        })
+                    });
+                GenericInteger::<P>::new(ciphertext, self.id).with_provenance(provenance)
             }
         }
     }
@@ -992,11 +1849,28 @@ macro_rules! generic_integer_impl_operation_assign (
             I: Borrow<Self>,
         {
             fn $rust_trait_method(&mut self, rhs: I) {
-                self.id.with_unwrapped_global(|integer_key| {
-                    integer_key
-                        .pbs_key()
-                        .$key_method(&mut self.ciphertext, &rhs.borrow().ciphertext)
-                })
+                let id = self.id;
+                let borrowed = rhs.borrow();
+                let inputs = [borrowed.ciphertext.blocks()];
+                let lhs = &mut self.ciphertext;
+                let lhs_provenance = &mut self.provenance;
+                record_operation_assign(
+                    stringify!($key_method),
+                    lhs,
+                    lhs_provenance,
+                    &inputs,
+                    &[&borrowed.provenance],
+                    |ciphertext| {
+                        id.with_unwrapped_global(|integer_key| {
+                            let key = integer_key.pbs_key();
+                            let hoist_inputs = [ciphertext.blocks(), borrowed.ciphertext.blocks()];
+                            match hoist_operation(key, stringify!($key_method), &hoist_inputs) {
+                                Some(result) => *ciphertext = result,
+                                None => key.$key_method(ciphertext, &borrowed.ciphertext),
+                            }
+                        })
+                    },
+                )
             }
         }
     }
@@ -1095,12 +1969,23 @@ macro_rules! generic_integer_impl_scalar_operation {
                     type Output = $concrete_type;
 
                     fn $rust_trait_method(self, rhs: $scalar_type) -> Self::Output {
-                        let ciphertext =
-                            self.id.with_unwrapped_global(|integer_key| {
-                                integer_key.pbs_key().$key_method(&self.ciphertext, rhs)
+                        let inputs = [self.ciphertext.blocks()];
+                        let provenances = [&self.provenance];
+                        let (ciphertext, provenance) =
+                            record_operation(stringify!($key_method), &inputs, &provenances, || {
+                                self.id.with_unwrapped_global(|integer_key| {
+                                    let key = integer_key.pbs_key();
+                                    hoist_scalar_operation(
+                                        key,
+                                        stringify!($key_method),
+                                        self.ciphertext.blocks(),
+                                        rhs,
+                                    )
+                                    .unwrap_or_else(|| key.$key_method(&self.ciphertext, rhs))
+                                })
                             });
 
-                        <$concrete_type>::new(ciphertext, self.id)
+                        <$concrete_type>::new(ciphertext, self.id).with_provenance(provenance)
                     }
                 }
             )* // Closing second repeating pattern
@@ -1124,9 +2009,30 @@ macro_rules! generic_integer_impl_scalar_operation_assign {
                 impl $rust_trait_name<$scalar_type> for $concrete_type
                 {
                     fn $rust_trait_method(&mut self, rhs: $scalar_type) {
-                        self.id.with_unwrapped_global(|integer_key| {
-                            integer_key.pbs_key().$key_method(&mut self.ciphertext, rhs);
-                        })
+                        let id = self.id;
+                        let lhs = &mut self.ciphertext;
+                        let lhs_provenance = &mut self.provenance;
+                        record_operation_assign(
+                            stringify!($key_method),
+                            lhs,
+                            lhs_provenance,
+                            &[],
+                            &[],
+                            |ciphertext| {
+                                id.with_unwrapped_global(|integer_key| {
+                                    let key = integer_key.pbs_key();
+                                    match hoist_scalar_operation(
+                                        key,
+                                        stringify!($key_method),
+                                        ciphertext.blocks(),
+                                        rhs,
+                                    ) {
+                                        Some(result) => *ciphertext = result,
+                                        None => key.$key_method(ciphertext, rhs),
+                                    }
+                                })
+                            },
+                        )
                     }
                 }
             )*
@@ -1134,6 +2040,83 @@ macro_rules! generic_integer_impl_scalar_operation_assign {
     }
 }
 
+// Operations with the scalar on the left side, e.g `u32 + FheUint32`
+//
+// As the operation is commutative, `scalar op ct` is computed as `ct op scalar`
+macro_rules! generic_integer_impl_scalar_left_commutative_operation {
+    (
+        rust_trait: $rust_trait_name:ident($rust_trait_method:ident),
+        // A 'list' of tuple, where the first element is the concrete Fhe type
+        // e.g (FheUint8 and the rest is scalar types (u8, u16, etc)
+        fhe_and_scalar_type: $(
+            ($concrete_type:ty, $($scalar_type:ty),*)
+        ),*
+        $(,)?
+    ) => {
+        $(
+            $(
+                impl $rust_trait_name<$concrete_type> for $scalar_type
+                {
+                    type Output = $concrete_type;
+
+                    fn $rust_trait_method(self, rhs: $concrete_type) -> Self::Output {
+                        <&$concrete_type as $rust_trait_name<$scalar_type>>::$rust_trait_method(
+                            &rhs,
+                            self,
+                        )
+                    }
+                }
+
+                impl $rust_trait_name<&$concrete_type> for $scalar_type
+                {
+                    type Output = $concrete_type;
+
+                    fn $rust_trait_method(self, rhs: &$concrete_type) -> Self::Output {
+                        <&$concrete_type as $rust_trait_name<$scalar_type>>::$rust_trait_method(
+                            rhs,
+                            self,
+                        )
+                    }
+                }
+            )*
+        )*
+    };
+}
+
+// `scalar - ct` is computed as `-ct + scalar`
+macro_rules! generic_integer_impl_scalar_left_sub {
+    (
+        fhe_and_scalar_type: $(
+            ($concrete_type:ty, $($scalar_type:ty),*)
+        ),*
+        $(,)?
+    ) => {
+        $(
+            $(
+                impl Sub<$concrete_type> for $scalar_type
+                {
+                    type Output = $concrete_type;
+
+                    fn sub(self, rhs: $concrete_type) -> Self::Output {
+                        <$scalar_type as Sub<&$concrete_type>>::sub(self, &rhs)
+                    }
+                }
+
+                impl Sub<&$concrete_type> for $scalar_type
+                {
+                    type Output = $concrete_type;
+
+                    fn sub(self, rhs: &$concrete_type) -> Self::Output {
+                        let mut result = <&$concrete_type as Neg>::neg(rhs);
+                        <$concrete_type as AddAssign<$scalar_type>>::add_assign(&mut result, self);
+                        result
+                    }
+                }
+            )*
+        )*
+    };
+}
+
 generic_integer_impl_operation!(Add(add) => add_parallelized);
 generic_integer_impl_operation!(Sub(sub) => sub_parallelized);
 generic_integer_impl_operation!(Mul(mul) => mul_parallelized);
@@ -1410,6 +2393,120 @@ generic_integer_impl_scalar_operation!(
         (super::FheInt128, i128),
         (super::FheInt256, I256),
 );
+// Scalar on the left side operations
+generic_integer_impl_scalar_left_commutative_operation!(
+    rust_trait: Add(add),
+    fhe_and_scalar_type:
+        (super::FheUint8, u8),
+        (super::FheUint10, u16),
+        (super::FheUint12, u16),
+        (super::FheUint14, u16),
+        (super::FheUint16, u16),
+        (super::FheUint32, u32),
+        (super::FheUint64, u64),
+        (super::FheUint128, u128),
+        (super::FheUint256, U256),
+        (super::FheInt8, i8),
+        (super::FheInt16, i16),
+        (super::FheInt32, i32),
+        (super::FheInt64, i64),
+        (super::FheInt128, i128),
+        (super::FheInt256, I256),
+);
+generic_integer_impl_scalar_left_commutative_operation!(
+    rust_trait: Mul(mul),
+    fhe_and_scalar_type:
+        (super::FheUint8, u8),
+        (super::FheUint10, u16),
+        (super::FheUint12, u16),
+        (super::FheUint14, u16),
+        (super::FheUint16, u16),
+        (super::FheUint32, u32),
+        (super::FheUint64, u64),
+        (super::FheUint128, u128),
+        (super::FheUint256, U256),
+        (super::FheInt8, i8),
+        (super::FheInt16, i16),
+        (super::FheInt32, i32),
+        (super::FheInt64, i64),
+        (super::FheInt128, i128),
+        (super::FheInt256, I256),
+);
+generic_integer_impl_scalar_left_commutative_operation!(
+    rust_trait: BitAnd(bitand),
+    fhe_and_scalar_type:
+        (super::FheUint8, u8),
+        (super::FheUint10, u16),
+        (super::FheUint12, u16),
+        (super::FheUint14, u16),
+        (super::FheUint16, u16),
+        (super::FheUint32, u32),
+        (super::FheUint64, u64),
+        (super::FheUint128, u128),
+        (super::FheUint256, U256),
+        (super::FheInt8, i8),
+        (super::FheInt16, i16),
+        (super::FheInt32, i32),
+        (super::FheInt64, i64),
+        (super::FheInt128, i128),
+        (super::FheInt256, I256),
+);
+generic_integer_impl_scalar_left_commutative_operation!(
+    rust_trait: BitOr(bitor),
+    fhe_and_scalar_type:
+        (super::FheUint8, u8),
+        (super::FheUint10, u16),
+        (super::FheUint12, u16),
+        (super::FheUint14, u16),
+        (super::FheUint16, u16),
+        (super::FheUint32, u32),
+        (super::FheUint64, u64),
+        (super::FheUint128, u128),
+        (super::FheUint256, U256),
+        (super::FheInt8, i8),
+        (super::FheInt16, i16),
+        (super::FheInt32, i32),
+        (super::FheInt64, i64),
+        (super::FheInt128, i128),
+        (super::FheInt256, I256),
+);
+generic_integer_impl_scalar_left_commutative_operation!(
+    rust_trait: BitXor(bitxor),
+    fhe_and_scalar_type:
+        (super::FheUint8, u8),
+        (super::FheUint10, u16),
+        (super::FheUint12, u16),
+        (super::FheUint14, u16),
+        (super::FheUint16, u16),
+        (super::FheUint32, u32),
+        (super::FheUint64, u64),
+        (super::FheUint128, u128),
+        (super::FheUint256, U256),
+        (super::FheInt8, i8),
+        (super::FheInt16, i16),
+        (super::FheInt32, i32),
+        (super::FheInt64, i64),
+        (super::FheInt128, i128),
+        (super::FheInt256, I256),
+);
+generic_integer_impl_scalar_left_sub!(
+    fhe_and_scalar_type:
+        (super::FheUint8, u8),
+        (super::FheUint10, u16),
+        (super::FheUint12, u16),
+        (super::FheUint14, u16),
+        (super::FheUint16, u16),
+        (super::FheUint32, u32),
+        (super::FheUint64, u64),
+        (super::FheUint128, u128),
+        (super::FheUint256, U256),
+        (super::FheInt8, i8),
+        (super::FheInt16, i16),
+        (super::FheInt32, i32),
+        (super::FheInt64, i64),
+        (super::FheInt128, i128),
+        (super::FheInt256, I256),
+);
 // Scalar assign ops
 generic_integer_impl_scalar_operation_assign!(
     rust_trait: AddAssign(add_assign),
@@ -1682,10 +2779,16 @@ where
     type Output = GenericInteger<P>;
 
     fn neg(self) -> Self::Output {
-        let ciphertext = self.id.with_unwrapped_global(|integer_key| {
-            integer_key.pbs_key().neg_parallelized(&self.ciphertext)
-        });
-        GenericInteger::<P>::new(ciphertext, self.id)
+        let inputs = [self.ciphertext.blocks()];
+        let (ciphertext, provenance) =
+            record_operation("neg_parallelized", &inputs, &[&self.provenance], || {
+                self.id.with_unwrapped_global(|integer_key| {
+                    let key = integer_key.pbs_key();
+                    hoist_operation(key, "neg_parallelized", &inputs)
+                        .unwrap_or_else(|| key.neg_parallelized(&self.ciphertext))
+                })
+            });
+        GenericInteger::<P>::new(ciphertext, self.id).with_provenance(provenance)
     }
 }
 
@@ -1709,9 +2812,15 @@ where
     type Output = GenericInteger<P>;
 
     fn not(self) -> Self::Output {
-        let ciphertext = self.id.with_unwrapped_global(|integer_key| {
-            integer_key.pbs_key().bitnot_parallelized(&self.ciphertext)
-        });
-        GenericInteger::<P>::new(ciphertext, self.id)
+        let inputs = [self.ciphertext.blocks()];
+        let (ciphertext, provenance) =
+            record_operation("bitnot_parallelized", &inputs, &[&self.provenance], || {
+                self.id.with_unwrapped_global(|integer_key| {
+                    let key = integer_key.pbs_key();
+                    hoist_operation(key, "bitnot_parallelized", &inputs)
+                        .unwrap_or_else(|| key.bitnot_parallelized(&self.ciphertext))
+                })
+            });
+        GenericInteger::<P>::new(ciphertext, self.id).with_provenance(provenance)
     }
 }
@@ -33,6 +33,19 @@ where
         let ct = self.list.expand_one();
         GenericInteger::new(ct, self.id)
     }
+
+    /// Re-randomizes the value, see [GenericCompactIntegerList::rerandomize]
+    pub fn rerandomize(&self, key: &CompactPublicKey) -> Self
+    where
+        P::Id: TypeIdentifier,
+    {
+        let list = key
+            .integer_key
+            .try_rerandomize_compact_list(&self.list)
+            .ok_or(UninitializedPublicKey(self.id.type_variant()))
+            .unwrap_display();
+        Self { list, id: self.id }
+    }
 }
 
 impl<P> GenericCompactIntegerList<P>
@@ -50,6 +63,50 @@ where
             .map(|ct| GenericInteger::new(ct, self.id))
             .collect::<Vec<_>>()
     }
+
+    /// Re-randomizes the values, by adding a fresh encryption of 0 to each of them
+    ///
+    /// The returned list encrypts the same values but cannot be linked to `self` without the
+    /// secret key, so a relayer can re-post the values it received without revealing which ones
+    /// they are. `key` must be the key the list was encrypted with.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, CompactFheUint8List, CompactPublicKey, ConfigBuilder};
+    ///
+    /// let config = ConfigBuilder::all_disabled()
+    ///     .enable_default_integers()
+    ///     .build();
+    /// let (client_key, _server_key) = generate_keys(config);
+    /// let public_key = CompactPublicKey::new(&client_key);
+    ///
+    /// let ballots = [1u8, 0, 2];
+    /// let list = CompactFheUint8List::try_encrypt(ballots.as_slice(), &public_key).unwrap();
+    /// let reposted = list.rerandomize(&public_key);
+    ///
+    /// let decrypted = reposted
+    ///     .expand()
+    ///     .iter()
+    ///     .map(|ct| {
+    ///         let value: u8 = ct.decrypt(&client_key);
+    ///         value
+    ///     })
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(decrypted, ballots);
+    /// ```
+    pub fn rerandomize(&self, key: &CompactPublicKey) -> Self
+    where
+        P::Id: TypeIdentifier,
+    {
+        let list = key
+            .integer_key
+            .try_rerandomize_compact_list(&self.list)
+            .ok_or(UninitializedPublicKey(self.id.type_variant()))
+            .unwrap_display();
+        Self { list, id: self.id }
+    }
 }
 
 impl<P, T> FheTryEncrypt<T, CompactPublicKey> for GenericCompactInteger<P>
@@ -0,0 +1,205 @@
+use std::collections::BTreeMap;
+
+use rayon::prelude::*;
+
+use crate::high_level_api::integers::parameters::IntegerParameter;
+use crate::high_level_api::integers::types::base::GenericInteger;
+use crate::integer::block_decomposition::DecomposableInto;
+use crate::integer::ciphertext::IntegerRadixCiphertext;
+
+/// A map from clear keys to encrypted values.
+///
+/// The keys of the map are known to the server, while the values are encrypted integers. The map
+/// supports two access modes:
+///
+/// - non-oblivious access with a clear key ([get_clear](Self::get_clear),
+///   [insert](Self::insert), ...), which costs nothing but reveals which entry is accessed,
+/// - oblivious access with an encrypted key ([get](Self::get),
+///   [contains_key](Self::contains_key)), which does not reveal which entry is accessed but
+///   costs a comparison and a selection per entry of the map.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheMap, FheUint32, FheUint64};
+///
+/// let config = ConfigBuilder::all_disabled()
+///     .enable_default_integers()
+///     .build();
+/// let (client_key, server_key) = generate_keys(config);
+/// set_server_key(server_key);
+///
+/// let mut balances = FheMap::new();
+/// balances.insert(12u32, FheUint64::encrypt(100u64, &client_key));
+/// balances.insert(57u32, FheUint64::encrypt(2500u64, &client_key));
+///
+/// // The server does not learn which account is read
+/// let account = FheUint32::encrypt(57u32, &client_key);
+/// let balance = balances.get(&account);
+/// let decrypted: u64 = balance.decrypt(&client_key);
+/// assert_eq!(decrypted, 2500);
+///
+/// // Unknown keys give an encryption of 0
+/// let account = FheUint32::encrypt(3u32, &client_key);
+/// let decrypted: u32 = balances.contains_key(&account).decrypt(&client_key);
+/// assert_eq!(decrypted, 0);
+/// let decrypted: u64 = balances.get(&account).decrypt(&client_key);
+/// assert_eq!(decrypted, 0);
+/// ```
+#[cfg_attr(all(doc, not(doctest)), doc(cfg(feature = "integer")))]
+#[derive(Clone, serde::Deserialize, serde::Serialize)]
+pub struct FheMap<K: Ord, V> {
+    entries: BTreeMap<K, V>,
+}
+
+impl<K: Ord, V> Default for FheMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord, V> FheMap<K, V> {
+    pub fn new() -> Self {
+        Self {
+            entries: BTreeMap::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Inserts a value, returns the previous value of the key if any
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.entries.insert(key, value)
+    }
+
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        self.entries.remove(key)
+    }
+
+    /// Returns the value of a clear key, the accessed entry is not hidden
+    pub fn get_clear(&self, key: &K) -> Option<&V> {
+        self.entries.get(key)
+    }
+
+    /// Returns the value of a clear key, the accessed entry is not hidden
+    pub fn get_clear_mut(&mut self, key: &K) -> Option<&mut V> {
+        self.entries.get_mut(key)
+    }
+
+    pub fn contains_clear_key(&self, key: &K) -> bool {
+        self.entries.contains_key(key)
+    }
+
+    /// Iterates over the entries, in the order of the keys
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.entries.iter()
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.entries.keys()
+    }
+}
+
+impl<K: Ord, V> FromIterator<(K, V)> for FheMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Self {
+            entries: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<K, P> FheMap<K, GenericInteger<P>>
+where
+    K: Ord + DecomposableInto<u64>,
+    P: IntegerParameter,
+    P::Id: Default + Sync,
+{
+    /// Returns the value of the encrypted key, or an encryption of 0 if the key is not in the
+    /// map.
+    ///
+    /// The accessed entry is not revealed: the encrypted key is compared to every key of the map.
+    pub fn get<PK>(&self, encrypted_key: &GenericInteger<PK>) -> GenericInteger<P>
+    where
+        PK: IntegerParameter,
+        PK::Id: Sync,
+    {
+        let ciphertext = crate::high_level_api::global_state::with_internal_keys(|keys| {
+            let integer_key = keys.integer_key.pbs_key();
+
+            // The encrypted key is propagated once, rather than by each of the comparisons
+            let mut propagated_key;
+            let encrypted_key = if encrypted_key.ciphertext.block_carries_are_empty() {
+                &encrypted_key.ciphertext
+            } else {
+                propagated_key = encrypted_key.ciphertext.clone();
+                integer_key.full_propagate_parallelized(&mut propagated_key);
+                &propagated_key
+            };
+
+            let selected = self
+                .entries
+                .par_iter()
+                .map(|(key, value)| {
+                    let is_key = integer_key.unchecked_scalar_eq_parallelized(encrypted_key, *key);
+
+                    let mut value = value.ciphertext.clone();
+                    if !value.block_carries_are_empty() {
+                        integer_key.full_propagate_parallelized(&mut value);
+                    }
+                    integer_key.zero_out_if_condition_is_false(&mut value, &is_key.blocks()[0]);
+                    value
+                })
+                .collect::<Vec<_>>();
+
+            // At most one of the selected values is not 0, so the sum cannot overflow
+            integer_key
+                .unchecked_sum_ciphertexts_vec_parallelized(selected)
+                .unwrap_or_else(|| integer_key.create_trivial_zero_radix(P::num_blocks()))
+        });
+
+        GenericInteger::new(ciphertext, P::Id::default())
+    }
+
+    /// Returns an encryption of 1 if the encrypted key is in the map, 0 otherwise.
+    ///
+    /// As for [get](Self::get), the encrypted key is compared to every key of the map.
+    pub fn contains_key<PK>(&self, encrypted_key: &GenericInteger<PK>) -> GenericInteger<PK>
+    where
+        PK: IntegerParameter,
+        PK::Id: Default + Sync,
+    {
+        let ciphertext = crate::high_level_api::global_state::with_internal_keys(|keys| {
+            let integer_key = keys.integer_key.pbs_key();
+
+            // The encrypted key is propagated once, rather than by each of the comparisons
+            let mut propagated_key;
+            let encrypted_key = if encrypted_key.ciphertext.block_carries_are_empty() {
+                &encrypted_key.ciphertext
+            } else {
+                propagated_key = encrypted_key.ciphertext.clone();
+                integer_key.full_propagate_parallelized(&mut propagated_key);
+                &propagated_key
+            };
+
+            let is_keys = self
+                .entries
+                .par_iter()
+                .map(|(key, _)| integer_key.unchecked_scalar_eq_parallelized(encrypted_key, *key))
+                .collect::<Vec<_>>();
+
+            // The keys are distinct, at most one comparison is true
+            integer_key
+                .unchecked_sum_ciphertexts_vec_parallelized(is_keys)
+                .unwrap_or_else(|| integer_key.create_trivial_zero_radix(PK::num_blocks()))
+        });
+
+        GenericInteger::new(ciphertext, PK::Id::default())
+    }
+}
@@ -1,4 +1,8 @@
 pub use base::GenericInteger;
+pub use map::FheMap;
+pub use ordering::FheOrdering;
+pub use sparse::SparseEncryptedVector;
+pub use vector::FheUintVector;
 
 expand_pub_use_fhe_type!(
     pub use static_{
@@ -10,4 +14,8 @@ expand_pub_use_fhe_type!(
 pub(super) mod base;
 pub(super) mod compact;
 pub(super) mod compressed;
+pub(super) mod map;
+pub(super) mod ordering;
+pub(super) mod sparse;
 pub(super) mod static_;
+pub(super) mod vector;
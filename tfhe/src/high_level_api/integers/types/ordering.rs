@@ -0,0 +1,188 @@
+use std::cmp::Ordering;
+
+use crate::high_level_api::global_state::WithGlobalKey;
+use crate::high_level_api::integers::parameters::IntegerParameter;
+use crate::high_level_api::integers::types::base::GenericInteger;
+use crate::high_level_api::integers::IntegerServerKey;
+use crate::high_level_api::keys::RefKeyFromKeyChain;
+use crate::high_level_api::provenance::Provenance;
+use crate::high_level_api::recorder::record_operation;
+use crate::high_level_api::traits::FheDecrypt;
+use crate::high_level_api::ClientKey;
+use crate::integer::ciphertext::IntegerCiphertext;
+use crate::integer::server_key::comparator::Comparator;
+
+/// The encrypted ordering of two integers, the result of [FheCmp::cmp](crate::prelude::FheCmp).
+///
+/// The comparison circuit is only run by [FheCmp::cmp](crate::prelude::FheCmp), the methods of
+/// the ordering cost a single PBS per comparison ([is_lt](Self::is_lt), [is_eq](Self::is_eq),
+/// ...), or one CMUX per value to select between ([select](Self::select)).
+///
+/// As for the comparisons of [FheOrd](crate::prelude::FheOrd), the comparisons return an integer
+/// encrypting 1 if the comparison holds, 0 otherwise.
+///
+/// # Example
+///
+/// ```rust
+/// use std::cmp::Ordering;
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16, FheUint8};
+///
+/// let config = ConfigBuilder::all_disabled()
+///     .enable_default_integers()
+///     .build();
+/// let (client_key, server_key) = generate_keys(config);
+/// set_server_key(server_key);
+///
+/// let a = FheUint8::encrypt(27u8, &client_key);
+/// let b = FheUint8::encrypt(128u8, &client_key);
+///
+/// let ordering = a.cmp(&b);
+///
+/// let is_le = ordering.is_le();
+/// let is_eq = ordering.is_eq();
+/// let decrypted: u8 = is_le.decrypt(&client_key);
+/// assert_eq!(decrypted, 1);
+/// let decrypted: u8 = is_eq.decrypt(&client_key);
+/// assert_eq!(decrypted, 0);
+///
+/// let fees = [100u16, 200, 300].map(|fee| FheUint16::encrypt(fee, &client_key));
+/// let fee = ordering.select(&fees[0], &fees[1], &fees[2]);
+/// let decrypted: u16 = fee.decrypt(&client_key);
+/// assert_eq!(decrypted, 100);
+///
+/// let decrypted: Ordering = ordering.decrypt(&client_key);
+/// assert_eq!(decrypted, Ordering::Less);
+/// ```
+#[cfg_attr(all(doc, not(doctest)), doc(cfg(feature = "integer")))]
+#[derive(Clone, serde::Deserialize, serde::Serialize)]
+pub struct FheOrdering<P: IntegerParameter> {
+    ordering: crate::shortint::Ciphertext,
+    id: P::Id,
+    #[serde(skip)]
+    provenance: Option<Provenance>,
+}
+
+impl<P> FheOrdering<P>
+where
+    P: IntegerParameter,
+{
+    pub(in crate::high_level_api::integers) fn new(
+        ordering: crate::shortint::Ciphertext,
+        id: P::Id,
+        provenance: Option<Provenance>,
+    ) -> Self {
+        Self {
+            ordering,
+            id,
+            provenance,
+        }
+    }
+}
+
+impl<P> FheOrdering<P>
+where
+    P: IntegerParameter,
+    P::Id: WithGlobalKey<Key = IntegerServerKey>,
+{
+    fn matches(&self, operation: &str, predicate: fn(Ordering) -> bool) -> GenericInteger<P> {
+        let inputs = [std::slice::from_ref(&self.ordering)];
+        let (inner_result, provenance) =
+            record_operation(operation, &inputs, &[&self.provenance], || {
+                self.id.with_unwrapped_global(|integer_key| {
+                    integer_key.pbs_key().ordering_matches(
+                        &self.ordering,
+                        predicate,
+                        P::num_blocks(),
+                    )
+                })
+            });
+        GenericInteger::new(inner_result, self.id).with_provenance(provenance)
+    }
+
+    /// Returns whether the left-hand side of the comparison was less than the right-hand side
+    pub fn is_lt(&self) -> GenericInteger<P> {
+        self.matches("ordering_is_lt", Ordering::is_lt)
+    }
+
+    /// Returns whether the left-hand side of the comparison was less than or equal to the
+    /// right-hand side
+    pub fn is_le(&self) -> GenericInteger<P> {
+        self.matches("ordering_is_le", Ordering::is_le)
+    }
+
+    /// Returns whether the two sides of the comparison were equal
+    pub fn is_eq(&self) -> GenericInteger<P> {
+        self.matches("ordering_is_eq", Ordering::is_eq)
+    }
+
+    /// Returns whether the two sides of the comparison were different
+    pub fn is_ne(&self) -> GenericInteger<P> {
+        self.matches("ordering_is_ne", Ordering::is_ne)
+    }
+
+    /// Returns whether the left-hand side of the comparison was greater than the right-hand side
+    pub fn is_gt(&self) -> GenericInteger<P> {
+        self.matches("ordering_is_gt", Ordering::is_gt)
+    }
+
+    /// Returns whether the left-hand side of the comparison was greater than or equal to the
+    /// right-hand side
+    pub fn is_ge(&self) -> GenericInteger<P> {
+        self.matches("ordering_is_ge", Ordering::is_ge)
+    }
+
+    /// Returns an integer encrypting the same value as `if_less`, `if_equal` or `if_greater`,
+    /// depending on the ordering.
+    ///
+    /// The values may be of another integer type than the compared ones.
+    pub fn select<Q>(
+        &self,
+        if_less: &GenericInteger<Q>,
+        if_equal: &GenericInteger<Q>,
+        if_greater: &GenericInteger<Q>,
+    ) -> GenericInteger<Q>
+    where
+        Q: IntegerParameter,
+    {
+        let inputs = [
+            std::slice::from_ref(&self.ordering),
+            if_less.ciphertext.blocks(),
+            if_equal.ciphertext.blocks(),
+            if_greater.ciphertext.blocks(),
+        ];
+        let provenances = [
+            &self.provenance,
+            &if_less.provenance,
+            &if_equal.provenance,
+            &if_greater.provenance,
+        ];
+        let (inner_result, provenance) = record_operation(
+            "ordering_select_parallelized",
+            &inputs,
+            &provenances,
+            || {
+                self.id.with_unwrapped_global(|integer_key| {
+                    integer_key.pbs_key().ordering_select_parallelized(
+                        &self.ordering,
+                        &if_less.ciphertext,
+                        &if_equal.ciphertext,
+                        &if_greater.ciphertext,
+                    )
+                })
+            },
+        );
+        GenericInteger::new(inner_result, if_less.id).with_provenance(provenance)
+    }
+}
+
+impl<P> FheDecrypt<Ordering> for FheOrdering<P>
+where
+    P: IntegerParameter,
+    P::Id: RefKeyFromKeyChain<Key = crate::integer::ClientKey>,
+{
+    fn decrypt(&self, key: &ClientKey) -> Ordering {
+        let key = self.id.unwrapped_ref_key(key);
+        Comparator::sign_to_ordering(key.decrypt_one_block(&self.ordering))
+    }
+}
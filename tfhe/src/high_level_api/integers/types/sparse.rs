@@ -0,0 +1,305 @@
+use std::collections::BTreeMap;
+
+use rayon::prelude::*;
+
+use crate::high_level_api::integers::parameters::IntegerParameter;
+use crate::high_level_api::integers::types::base::GenericInteger;
+use crate::high_level_api::integers::types::vector::FheUintVector;
+use crate::integer::ciphertext::{IntegerCiphertext, IntegerRadixCiphertext};
+use crate::integer::RadixCiphertext;
+
+/// A vector of encrypted integers, where only the non-default entries are stored.
+///
+/// The vector has a logical length, and stores (clear index, encrypted value) pairs. Entries
+/// which are not stored are encryptions of 0, which do not take any space. This is meant for
+/// large arrays of which only a small fraction is non-zero, where storing every encrypted zero
+/// would waste a lot of memory.
+///
+/// The stored indices are known to the server, but the stored values are not. In particular the
+/// server does not know which stored values are zeros, for instance after a
+/// [masked_update](Self::masked_update) with a false condition.
+/// [compact](Self::compact) moves the non-zero values to the front without revealing which of
+/// them are zeros.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16, SparseEncryptedVector};
+///
+/// let config = ConfigBuilder::all_disabled()
+///     .enable_default_integers()
+///     .build();
+/// let (client_key, server_key) = generate_keys(config);
+/// set_server_key(server_key);
+///
+/// let mut vector = SparseEncryptedVector::new(1_000_000);
+/// vector.insert(10, FheUint16::encrypt(3u16, &client_key));
+/// vector.insert(500_000, FheUint16::encrypt(0u16, &client_key));
+/// vector.insert(999_999, FheUint16::encrypt(7u16, &client_key));
+/// assert_eq!(vector.stored_len(), 3);
+///
+/// // The server does not learn which update happens
+/// let condition = FheUint16::encrypt(1u16, &client_key);
+/// vector.masked_update(123, &condition, &FheUint16::encrypt(9u16, &client_key));
+///
+/// let compacted = vector.compact();
+/// let decrypted: Vec<u16> = compacted
+///     .iter()
+///     .map(|value| value.decrypt(&client_key))
+///     .collect();
+/// assert_eq!(decrypted, [3, 9, 7, 0]);
+/// ```
+#[cfg_attr(all(doc, not(doctest)), doc(cfg(feature = "integer")))]
+#[derive(Clone, serde::Deserialize, serde::Serialize)]
+pub struct SparseEncryptedVector<V> {
+    len: usize,
+    entries: BTreeMap<usize, V>,
+}
+
+impl<V> SparseEncryptedVector<V> {
+    /// Creates a vector of `len` encryptions of 0, none of which is stored
+    pub fn new(len: usize) -> Self {
+        Self {
+            len,
+            entries: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the logical length of the vector
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of stored entries
+    pub fn stored_len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Stores a value, returns the previously stored value of the index if any
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn insert(&mut self, index: usize, value: V) -> Option<V> {
+        self.assert_in_bounds(index);
+        self.entries.insert(index, value)
+    }
+
+    /// Removes a stored value, the entry becomes an encryption of 0 again
+    pub fn remove(&mut self, index: usize) -> Option<V> {
+        self.entries.remove(&index)
+    }
+
+    /// Returns the stored value of an index, `None` means that the entry is an encryption of 0
+    pub fn get_clear(&self, index: usize) -> Option<&V> {
+        self.entries.get(&index)
+    }
+
+    /// Iterates over the stored entries, in the order of the indices
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &V)> {
+        self.entries.iter().map(|(index, value)| (*index, value))
+    }
+
+    fn assert_in_bounds(&self, index: usize) {
+        assert!(
+            index < self.len,
+            "Index {index} is out of bounds for a vector of length {}",
+            self.len
+        );
+    }
+}
+
+impl<P> SparseEncryptedVector<GenericInteger<P>>
+where
+    P: IntegerParameter,
+    P::Id: Default + Sync,
+{
+    /// Sets the entry at `index` to `value` if `condition` encrypts 1, and leaves it unchanged
+    /// if `condition` encrypts 0.
+    ///
+    /// `condition` has to encrypt 0 or 1. The entry is stored afterwards, whatever the value of
+    /// the condition, so that the server does not learn whether the update happened.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn masked_update(
+        &mut self,
+        index: usize,
+        condition: &GenericInteger<P>,
+        value: &GenericInteger<P>,
+    ) {
+        self.assert_in_bounds(index);
+
+        let ciphertext = crate::high_level_api::global_state::with_internal_keys(|keys| {
+            let integer_key = keys.integer_key.pbs_key();
+
+            match self.entries.get(&index) {
+                Some(current) => integer_key.if_then_else_parallelized(
+                    &condition.ciphertext,
+                    &value.ciphertext,
+                    &current.ciphertext,
+                ),
+                None => {
+                    let mut value = value.ciphertext.clone();
+                    if !value.block_carries_are_empty() {
+                        integer_key.full_propagate_parallelized(&mut value);
+                    }
+                    let mut condition = condition.ciphertext.clone();
+                    if !condition.block_carries_are_empty() {
+                        integer_key.full_propagate_parallelized(&mut condition);
+                    }
+                    integer_key.zero_out_if_condition_is_false(&mut value, &condition.blocks()[0]);
+                    value
+                }
+            }
+        });
+
+        self.entries
+            .insert(index, GenericInteger::new(ciphertext, P::Id::default()));
+    }
+
+    /// Returns the element-wise sum of the two vectors, wrapping around on overflow
+    ///
+    /// The stored indices of the result are the union of the stored indices of the inputs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vectors do not have the same length.
+    pub fn merge(&self, other: &Self) -> Self {
+        assert_eq!(
+            self.len, other.len,
+            "Cannot merge vectors of different lengths"
+        );
+
+        let entries = crate::high_level_api::global_state::with_internal_keys(|keys| {
+            let integer_key = keys.integer_key.pbs_key();
+
+            let mut indices = self.entries.keys().collect::<Vec<_>>();
+            indices.extend(
+                other
+                    .entries
+                    .keys()
+                    .filter(|index| !self.entries.contains_key(index)),
+            );
+
+            indices
+                .into_par_iter()
+                .map(|index| {
+                    let ciphertext = match (self.entries.get(index), other.entries.get(index)) {
+                        (Some(lhs), Some(rhs)) => {
+                            integer_key.add_parallelized(&lhs.ciphertext, &rhs.ciphertext)
+                        }
+                        (Some(value), None) | (None, Some(value)) => value.ciphertext.clone(),
+                        (None, None) => unreachable!(),
+                    };
+                    (*index, ciphertext)
+                })
+                .collect::<Vec<_>>()
+        });
+
+        Self {
+            len: self.len,
+            entries: entries
+                .into_iter()
+                .map(|(index, ciphertext)| {
+                    (index, GenericInteger::new(ciphertext, P::Id::default()))
+                })
+                .collect(),
+        }
+    }
+
+    /// Moves the non-zero stored values to the front, keeping their order.
+    ///
+    /// The result has one value per stored entry: the non-zero values, in the order of their
+    /// indices, followed by encryptions of 0. The server does not learn which stored values are
+    /// zeros, which costs a number of comparisons quadratic in the number of stored entries.
+    pub fn compact(&self) -> FheUintVector<GenericInteger<P>> {
+        let values = crate::high_level_api::global_state::with_internal_keys(|keys| {
+            let integer_key = keys.integer_key.pbs_key();
+
+            let values = self
+                .entries
+                .values()
+                .collect::<Vec<_>>()
+                .into_par_iter()
+                .map(|value| {
+                    let mut value = value.ciphertext.clone();
+                    if !value.block_carries_are_empty() {
+                        integer_key.full_propagate_parallelized(&mut value);
+                    }
+                    value
+                })
+                .collect::<Vec<_>>();
+
+            // The counts go up to the number of stored values, which may not fit in the values
+            let message_modulus = integer_key.key.message_modulus.0;
+            let mut count_num_blocks = 1;
+            let mut count_capacity = message_modulus;
+            while count_capacity <= values.len() {
+                count_capacity *= message_modulus;
+                count_num_blocks += 1;
+            }
+
+            let is_non_zero = values
+                .par_iter()
+                .map(|value| {
+                    let is_non_zero = integer_key.scalar_ne_parallelized(value, 0u64);
+                    let is_non_zero = RadixCiphertext::from(vec![is_non_zero.blocks()[0].clone()]);
+                    integer_key.extend_radix_with_trivial_zero_blocks_msb(
+                        &is_non_zero,
+                        count_num_blocks - 1,
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            // counts[i] is the number of non-zero values up to the i-th one included, so a
+            // non-zero value goes to position counts[i] - 1. Zero values share the count of the
+            // previous non-zero value, but selecting them adds nothing.
+            let mut counts = Vec::with_capacity(is_non_zero.len());
+            for is_non_zero in is_non_zero {
+                let count = match counts.last() {
+                    Some(previous) => integer_key.add_parallelized(previous, &is_non_zero),
+                    None => is_non_zero,
+                };
+                counts.push(count);
+            }
+
+            (0..values.len())
+                .into_par_iter()
+                .map(|position| {
+                    // Only the values from the position onward can be moved to it
+                    let selected = values[position..]
+                        .par_iter()
+                        .zip(counts[position..].par_iter())
+                        .map(|(value, count)| {
+                            let is_position =
+                                integer_key.scalar_eq_parallelized(count, position as u64 + 1);
+                            let mut value = value.clone();
+                            integer_key.zero_out_if_condition_is_false(
+                                &mut value,
+                                &is_position.blocks()[0],
+                            );
+                            value
+                        })
+                        .collect::<Vec<_>>();
+
+                    // At most one of the selected values is not 0, so the sum cannot overflow
+                    integer_key
+                        .unchecked_sum_ciphertexts_vec_parallelized(selected)
+                        .unwrap_or_else(|| integer_key.create_trivial_zero_radix(P::num_blocks()))
+                })
+                .collect::<Vec<_>>()
+        });
+
+        values
+            .into_iter()
+            .map(|ciphertext| GenericInteger::new(ciphertext, P::Id::default()))
+            .collect()
+    }
+}
@@ -368,3 +368,42 @@ static_int_type! {
         },
     }
 }
+
+// The output type of each type is the smallest one holding the product of two values
+macro_rules! impl_widening_ops {
+    ($($name:ident => $wide_name:ident),* $(,)?) => {
+        $(
+            impl crate::high_level_api::traits::WideningOps for $name {
+                type Output = $wide_name;
+
+                fn widening_add(&self, rhs: &Self) -> Self::Output {
+                    let lhs: $wide_name = self.clone().cast_into();
+                    let rhs: $wide_name = rhs.clone().cast_into();
+                    lhs + rhs
+                }
+
+                fn widening_mul(&self, rhs: &Self) -> Self::Output {
+                    let lhs: $wide_name = self.clone().cast_into();
+                    let rhs: $wide_name = rhs.clone().cast_into();
+                    lhs * rhs
+                }
+            }
+        )*
+    };
+}
+
+impl_widening_ops!(
+    FheUint8 => FheUint16,
+    FheUint10 => FheUint32,
+    FheUint12 => FheUint32,
+    FheUint14 => FheUint32,
+    FheUint16 => FheUint32,
+    FheUint32 => FheUint64,
+    FheUint64 => FheUint128,
+    FheUint128 => FheUint256,
+    FheInt8 => FheInt16,
+    FheInt16 => FheInt32,
+    FheInt32 => FheInt64,
+    FheInt64 => FheInt128,
+    FheInt128 => FheInt256,
+);
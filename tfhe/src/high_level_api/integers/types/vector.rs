@@ -0,0 +1,123 @@
+use crate::high_level_api::integers::parameters::IntegerParameter;
+use crate::high_level_api::integers::types::base::GenericInteger;
+
+/// A vector of encrypted integers.
+///
+/// Besides storing the values, the vector computes linear combinations of them with clear
+/// weights ([dot_clear](Self::dot_clear)). The products are not propagated one by one, all of
+/// them are summed before a single carry propagation, which makes the dot product much cheaper
+/// than the equivalent sequence of multiplications and additions. This is what scoring and
+/// weighted ranking workloads need.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint32, FheUintVector};
+///
+/// let config = ConfigBuilder::all_disabled()
+///     .enable_default_integers()
+///     .build();
+/// let (client_key, server_key) = generate_keys(config);
+/// set_server_key(server_key);
+///
+/// let features = [12u32, 0, 7, 250];
+/// let encrypted = features
+///     .iter()
+///     .map(|&feature| FheUint32::encrypt(feature, &client_key))
+///     .collect::<FheUintVector<_>>();
+///
+/// let score = encrypted.dot_clear(&[3, 100, 8, 1]);
+/// let decrypted: u32 = score.decrypt(&client_key);
+/// assert_eq!(decrypted, 12 * 3 + 7 * 8 + 250);
+/// ```
+#[cfg_attr(all(doc, not(doctest)), doc(cfg(feature = "integer")))]
+#[derive(Clone, serde::Deserialize, serde::Serialize)]
+pub struct FheUintVector<T> {
+    values: Vec<T>,
+}
+
+impl<T> Default for FheUintVector<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FheUintVector<T> {
+    pub fn new() -> Self {
+        Self { values: Vec::new() }
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn push(&mut self, value: T) {
+        self.values.push(value);
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.values.get(index)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.values.iter()
+    }
+}
+
+impl<T> From<Vec<T>> for FheUintVector<T> {
+    fn from(values: Vec<T>) -> Self {
+        Self { values }
+    }
+}
+
+impl<T> FromIterator<T> for FheUintVector<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self {
+            values: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<P> FheUintVector<GenericInteger<P>>
+where
+    P: IntegerParameter,
+    P::Id: Default,
+{
+    /// Returns the sum of the values multiplied by their weight, wrapping around on overflow
+    ///
+    /// An empty vector gives an encryption of 0.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is not exactly one weight per value.
+    pub fn dot_clear(&self, weights: &[u64]) -> GenericInteger<P> {
+        assert_eq!(
+            self.values.len(),
+            weights.len(),
+            "Expected one weight per value, got {} weights for {} values",
+            weights.len(),
+            self.values.len()
+        );
+
+        let ciphertext = crate::high_level_api::global_state::with_internal_keys(|keys| {
+            let integer_key = keys.integer_key.pbs_key();
+
+            let ciphertexts = self
+                .values
+                .iter()
+                .map(|value| value.ciphertext.clone())
+                .collect::<Vec<_>>();
+
+            integer_key
+                .scalar_dot_product_parallelized(&ciphertexts, weights)
+                .unwrap_or_else(|| integer_key.create_trivial_zero_radix(P::num_blocks()))
+        });
+
+        GenericInteger::new(ciphertext, P::Id::default())
+    }
+}
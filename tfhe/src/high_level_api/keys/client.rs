@@ -3,6 +3,7 @@
 //! - [ClientKey] aggregates the keys used to encrypt/decrypt between normal and homomorphic types.
 
 use concrete_csprng::seeders::Seed;
+use rayon::prelude::*;
 
 #[cfg(feature = "boolean")]
 use crate::high_level_api::booleans::BooleanClientKey;
@@ -10,6 +11,7 @@ use crate::high_level_api::config::Config;
 use crate::high_level_api::errors::{UninitializedClientKey, UnwrapResultExt};
 #[cfg(feature = "integer")]
 use crate::high_level_api::integers::IntegerClientKey;
+use crate::high_level_api::traits::FheDecrypt;
 
 use super::{CompressedServerKey, ServerKey};
 
@@ -63,6 +65,120 @@ impl ClientKey {
     pub fn generate_compressed_server_key(&self) -> CompressedServerKey {
         CompressedServerKey::new(self)
     }
+
+    /// Decrypts a batch of values in parallel
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, ConfigBuilder, FheUint64};
+    ///
+    /// let config = ConfigBuilder::all_disabled()
+    ///     .enable_default_integers()
+    ///     .build();
+    /// let (client_key, _) = generate_keys(config);
+    ///
+    /// let clears = [0u64, 1, u64::MAX, 1 << 40];
+    /// let values = clears.map(|clear| FheUint64::encrypt(clear, &client_key));
+    ///
+    /// let decrypted: Vec<u64> = client_key.decrypt_batch(&values);
+    /// assert_eq!(decrypted, clears);
+    /// ```
+    pub fn decrypt_batch<T, ClearType>(&self, values: &[T]) -> Vec<ClearType>
+    where
+        T: FheDecrypt<ClearType> + Sync,
+        ClearType: Send,
+    {
+        values.par_iter().map(|value| value.decrypt(self)).collect()
+    }
+
+    /// Creates a ClientKey from an integer client key, e.g. one built from secret keys generated
+    /// outside of this crate with [`crate::integer::ClientKey::from_raw_parts`].
+    ///
+    /// The boolean part of the key is disabled.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the integer key does not use 2 bits of message per block, or if the wopbs
+    /// parameters do not have the same message and carry moduli as the integer key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{ClientKey, ConfigBuilder, FheUint8};
+    ///
+    /// let config = ConfigBuilder::all_disabled()
+    ///     .enable_default_integers()
+    ///     .build();
+    /// let client_key = ClientKey::generate(config);
+    /// let a = FheUint8::encrypt(27u8, &client_key);
+    ///
+    /// let (integer_key, wopbs_block_parameters) = client_key.into_raw_parts();
+    /// let (glwe_secret_key, lwe_secret_key, parameters) = integer_key.unwrap().into_raw_parts();
+    ///
+    /// let integer_key =
+    ///     tfhe::integer::ClientKey::from_raw_parts(glwe_secret_key, lwe_secret_key, parameters);
+    /// let client_key = ClientKey::from_raw_parts(integer_key, wopbs_block_parameters);
+    ///
+    /// let decrypted: u8 = a.decrypt(&client_key);
+    /// assert_eq!(decrypted, 27);
+    /// ```
+    #[cfg(all(feature = "secret-key-raw-parts", feature = "integer"))]
+    pub fn from_raw_parts(
+        integer_key: crate::integer::ClientKey,
+        wopbs_block_parameters: Option<crate::shortint::WopbsParameters>,
+    ) -> Self {
+        let block_parameters = integer_key.parameters();
+        assert_eq!(
+            block_parameters.message_modulus().0,
+            4,
+            "This API only supports integers with 2 bits per block (MessageModulus(4))",
+        );
+        if let Some(wopbs_parameters) = wopbs_block_parameters.as_ref() {
+            assert_eq!(
+                wopbs_parameters.message_modulus,
+                block_parameters.message_modulus(),
+                "Mismatch between the MessageModulus of the wopbs parameters and the integer key"
+            );
+            assert_eq!(
+                wopbs_parameters.carry_modulus,
+                block_parameters.carry_modulus(),
+                "Mismatch between the CarryModulus of the wopbs parameters and the integer key"
+            );
+        }
+
+        ClientKey {
+            #[cfg(feature = "boolean")]
+            boolean_key: BooleanClientKey::from(
+                crate::high_level_api::booleans::BooleanConfig::all_none(),
+            ),
+            integer_key: IntegerClientKey {
+                key: Some(integer_key),
+                wopbs_block_parameters,
+            },
+        }
+    }
+
+    /// Deconstructs a ClientKey into its integer client key, if integers are enabled, and its
+    /// wopbs parameters, see [`Self::from_raw_parts`].
+    ///
+    /// The boolean part of the key, if any, is dropped.
+    #[cfg(all(feature = "secret-key-raw-parts", feature = "integer"))]
+    pub fn into_raw_parts(
+        self,
+    ) -> (
+        Option<crate::integer::ClientKey>,
+        Option<crate::shortint::WopbsParameters>,
+    ) {
+        let IntegerClientKey {
+            key,
+            wopbs_block_parameters,
+        } = self.integer_key;
+
+        (key, wopbs_block_parameters)
+    }
 }
 
 #[cfg(feature = "integer")]
@@ -2,15 +2,19 @@
 mod client;
 #[macro_use]
 mod public;
+pub(crate) mod progress;
 mod server;
 
 use crate::high_level_api::config::Config;
+use crate::high_level_api::errors::KeygenCancelled;
 pub use client::{ClientKey, RefKeyFromKeyChain};
+pub use progress::{CancellationToken, KeygenProgress, KeygenStep};
+use progress::{KeygenMonitor, SERVER_KEY_STEPS};
 pub use public::{
     CompactPublicKey, CompressedCompactPublicKey, CompressedPublicKey, PublicKey,
     RefKeyFromCompressedPublicKeyChain, RefKeyFromPublicKeyChain,
 };
-pub use server::{CompressedServerKey, ServerKey};
+pub use server::{CompressedServerKey, LegacyCompressedServerKey, ServerKey};
 
 /// Generates keys using the provided config.
 ///
@@ -33,3 +37,61 @@ pub fn generate_keys<C: Into<Config>>(config: C) -> (ClientKey, ServerKey) {
 
     (client_kc, server_kc)
 }
+
+/// Generates keys using the provided config, reporting the progress and stopping if
+/// `cancellation` is cancelled.
+///
+/// Generating the keys of big parameter sets takes tens of seconds to minutes. `progress` is
+/// called before each step, a step being the generation of one of the keys, and, while the
+/// bootstrapping key of a server key is generated, each time one of its GGSW ciphertexts is
+/// encrypted. As those are encrypted in parallel, `progress` must be [Send], the calls being
+/// serialized.
+///
+/// The cancellation is checked between steps, after the last one and between the GGSW ciphertexts
+/// of the bootstrapping keys, and a [KeygenCancelled] error is returned if the token was
+/// cancelled. The other parts of a step, like the generation of the key switching keys, cannot
+/// be interrupted.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "integer")]
+/// # {
+/// use tfhe::{generate_keys_with_progress, CancellationToken, ConfigBuilder};
+///
+/// let config = ConfigBuilder::all_disabled()
+///     .enable_default_integers()
+///     .build();
+///
+/// let cancellation = CancellationToken::new();
+/// // The token can be cancelled from another thread, for instance by a UI
+/// let (client_key, server_key) = generate_keys_with_progress(
+///     config,
+///     |progress| {
+///         println!(
+///             "{:?} ({}/{}), {}/{} GGSW",
+///             progress.step,
+///             progress.completed_steps,
+///             progress.total_steps,
+///             progress.step_completed_ggsw,
+///             progress.step_total_ggsw,
+///         )
+///     },
+///     &cancellation,
+/// )
+/// .unwrap();
+/// # }
+/// ```
+pub fn generate_keys_with_progress<C: Into<Config>>(
+    config: C,
+    mut progress: impl FnMut(KeygenProgress) + Send,
+    cancellation: &CancellationToken,
+) -> Result<(ClientKey, ServerKey), KeygenCancelled> {
+    let mut monitor = KeygenMonitor::new(&mut progress, cancellation, 1 + SERVER_KEY_STEPS);
+
+    let client_kc = monitor.step(KeygenStep::ClientKey, || ClientKey::generate(config))?;
+    let server_kc = ServerKey::new_with_monitor(&client_kc, &mut monitor)?;
+    monitor.check_cancellation()?;
+
+    Ok((client_kc, server_kc))
+}
@@ -0,0 +1,141 @@
+//! Progress reporting and cancellation of the key generation.
+
+use std::ops::ControlFlow;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+use crate::high_level_api::errors::KeygenCancelled;
+
+/// A step of a key generation or decompression
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum KeygenStep {
+    ClientKey,
+    #[cfg(feature = "boolean")]
+    BooleanServerKey,
+    #[cfg(feature = "integer")]
+    IntegerServerKey,
+}
+
+/// The progress reported to the callback, before each step and, for the steps generating
+/// bootstrapping keys, each time one of their GGSW ciphertexts is encrypted
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct KeygenProgress {
+    /// The current step
+    pub step: KeygenStep,
+    /// The number of completed steps
+    pub completed_steps: usize,
+    pub total_steps: usize,
+    /// The number of GGSW ciphertexts of the bootstrapping keys of the step already encrypted
+    pub step_completed_ggsw: usize,
+    /// The number of GGSW ciphertexts of the bootstrapping keys of the step, 0 if the step does
+    /// not generate bootstrapping keys
+    pub step_total_ggsw: usize,
+}
+
+/// A token to cancel a key generation from another thread
+///
+/// Clones of a token share the same state, cancelling one cancels all of them.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+/// The number of steps of the generation or decompression of a server key
+pub(crate) const SERVER_KEY_STEPS: usize =
+    cfg!(feature = "boolean") as usize + cfg!(feature = "integer") as usize;
+
+/// Runs the steps of a key generation, reporting the progress and checking the cancellation
+pub(crate) struct KeygenMonitor<'a> {
+    progress: &'a mut (dyn FnMut(KeygenProgress) + Send),
+    cancellation: &'a CancellationToken,
+    completed_steps: usize,
+    total_steps: usize,
+}
+
+impl<'a> KeygenMonitor<'a> {
+    pub(crate) fn new(
+        progress: &'a mut (dyn FnMut(KeygenProgress) + Send),
+        cancellation: &'a CancellationToken,
+        total_steps: usize,
+    ) -> Self {
+        Self {
+            progress,
+            cancellation,
+            completed_steps: 0,
+            total_steps,
+        }
+    }
+
+    pub(crate) fn step<R>(
+        &mut self,
+        step: KeygenStep,
+        f: impl FnOnce() -> R,
+    ) -> Result<R, KeygenCancelled> {
+        self.step_with_progress(step, 0, |_| Some(f()))
+    }
+
+    /// Runs a step generating bootstrapping keys of `total_ggsw` GGSW ciphertexts
+    ///
+    /// `f` must call the callback it is given each time one of the GGSW ciphertexts is encrypted
+    /// and return `None` if the callback stopped the generation. The callback reports the progress
+    /// and stops the generation once the token is cancelled.
+    pub(crate) fn step_with_progress<R>(
+        &mut self,
+        step: KeygenStep,
+        total_ggsw: usize,
+        f: impl FnOnce(&(dyn Fn() -> ControlFlow<()> + Sync)) -> Option<R>,
+    ) -> Result<R, KeygenCancelled> {
+        self.check_cancellation()?;
+        let completed_steps = self.completed_steps;
+        let total_steps = self.total_steps;
+        let report = |step_completed_ggsw| KeygenProgress {
+            step,
+            completed_steps,
+            total_steps,
+            step_completed_ggsw,
+            step_total_ggsw: total_ggsw,
+        };
+        (self.progress)(report(0));
+
+        // The GGSW ciphertexts are encrypted in parallel, the lock serializes the calls to the
+        // progress callback and keeps the reported counts increasing
+        let cancellation = self.cancellation;
+        let state = Mutex::new((&mut *self.progress, 0));
+        let on_ggsw_encrypted = || {
+            if cancellation.is_cancelled() {
+                return ControlFlow::Break(());
+            }
+            let mut state = state.lock().unwrap();
+            let (progress, completed_ggsw) = &mut *state;
+            *completed_ggsw += 1;
+            progress(report(*completed_ggsw));
+            ControlFlow::Continue(())
+        };
+        let result = f(&on_ggsw_encrypted).ok_or(KeygenCancelled)?;
+
+        self.completed_steps += 1;
+        Ok(result)
+    }
+
+    pub(crate) fn check_cancellation(&self) -> Result<(), KeygenCancelled> {
+        if self.cancellation.is_cancelled() {
+            Err(KeygenCancelled)
+        } else {
+            Ok(())
+        }
+    }
+}
@@ -2,11 +2,19 @@
 use crate::high_level_api::booleans::{BooleanCompressedServerKey, BooleanServerKey};
 #[cfg(feature = "integer")]
 use crate::high_level_api::integers::{IntegerCompressedServerKey, IntegerServerKey};
+#[cfg(feature = "integer")]
+use crate::integer::key_switching_key::{CompressedKeySwitchingKey, KeySwitchingKey};
 
 #[cfg(any(feature = "boolean", feature = "integer"))]
 use std::sync::Arc;
 
+#[cfg(any(feature = "boolean", feature = "integer"))]
+use super::progress::KeygenStep;
+use super::progress::{CancellationToken, KeygenMonitor, KeygenProgress, SERVER_KEY_STEPS};
 use super::ClientKey;
+#[cfg(feature = "integer")]
+use super::{CompactPublicKey, CompressedCompactPublicKey};
+use crate::high_level_api::errors::KeygenCancelled;
 
 /// Key of the server
 ///
@@ -35,6 +43,33 @@ impl ServerKey {
             integer_key: Arc::new(IntegerServerKey::new(&keys.integer_key)),
         }
     }
+
+    #[allow(unused_variables)]
+    pub(crate) fn new_with_monitor(
+        keys: &ClientKey,
+        monitor: &mut KeygenMonitor,
+    ) -> Result<Self, KeygenCancelled> {
+        Ok(Self {
+            #[cfg(feature = "boolean")]
+            boolean_key: monitor.step_with_progress(
+                KeygenStep::BooleanServerKey,
+                keys.boolean_key.bootstrapping_key_ggsw_count(),
+                |on_ggsw_encrypted| {
+                    BooleanServerKey::new_with_progress(&keys.boolean_key, on_ggsw_encrypted)
+                        .map(Arc::new)
+                },
+            )?,
+            #[cfg(feature = "integer")]
+            integer_key: monitor.step_with_progress(
+                KeygenStep::IntegerServerKey,
+                keys.integer_key.bootstrapping_key_ggsw_count(),
+                |on_ggsw_encrypted| {
+                    IntegerServerKey::new_with_progress(&keys.integer_key, on_ggsw_encrypted)
+                        .map(Arc::new)
+                },
+            )?,
+        })
+    }
 }
 
 #[cfg(feature = "integer")]
@@ -102,12 +137,56 @@ impl<'de> serde::Deserialize<'de> for ServerKey {
     }
 }
 
-#[derive(Clone, serde::Serialize, serde::Deserialize)]
+/// Compressed version of the [ServerKey]
+///
+/// With the integers enabled, the key can also carry the other public keys of a deployment in
+/// their compressed form: a compact public key and casting keys. A whole deployment keyset then
+/// ships as a single artifact, and each key is decompressed on its own, so that a server only
+/// pays the decompression of the keys it actually uses. [Self::decompress] only decompresses the
+/// server key.
+///
+/// The serialized form of the key is versioned.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{ClientKey, CompressedCompactPublicKey, CompressedServerKey, ConfigBuilder};
+/// use tfhe::CompactFheUint8;
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS;
+///
+/// let config = ConfigBuilder::all_disabled()
+///     .enable_custom_integers(PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS, None)
+///     .build();
+/// let client_key = ClientKey::generate(config);
+///
+/// let compressed_server_key = CompressedServerKey::new(&client_key)
+///     .with_compact_public_key(CompressedCompactPublicKey::new(&client_key));
+/// let serialized = bincode::serialize(&compressed_server_key).unwrap();
+///
+/// // On the gateway, only the compact public key is decompressed
+/// let keys: CompressedServerKey = bincode::deserialize(&serialized).unwrap();
+/// let public_key = keys.decompress_compact_public_key().unwrap();
+/// let compact = CompactFheUint8::encrypt(3u8, &public_key);
+///
+/// // On the server
+/// let keys: CompressedServerKey = bincode::deserialize(&serialized).unwrap();
+/// tfhe::set_server_key(keys.decompress());
+/// let result = compact.expand() + 2u8;
+///
+/// let decrypted: u8 = result.decrypt(&client_key);
+/// assert_eq!(decrypted, 5);
+/// ```
+#[derive(Clone)]
 pub struct CompressedServerKey {
     #[cfg(feature = "boolean")]
     pub(crate) boolean_key: BooleanCompressedServerKey,
     #[cfg(feature = "integer")]
     pub(crate) integer_key: IntegerCompressedServerKey,
+    #[cfg(feature = "integer")]
+    pub(crate) compact_public_key: Option<CompressedCompactPublicKey>,
+    #[cfg(feature = "integer")]
+    pub(crate) casting_keys: Vec<CompressedKeySwitchingKey>,
 }
 
 impl CompressedServerKey {
@@ -117,9 +196,17 @@ impl CompressedServerKey {
             boolean_key: BooleanCompressedServerKey::new(&keys.boolean_key),
             #[cfg(feature = "integer")]
             integer_key: IntegerCompressedServerKey::new(&keys.integer_key),
+            #[cfg(feature = "integer")]
+            compact_public_key: None,
+            #[cfg(feature = "integer")]
+            casting_keys: Vec::new(),
         }
     }
 
+    /// Decompresses the server key
+    ///
+    /// The compact public key and the casting keys are not decompressed, see
+    /// [Self::decompress_compact_public_key] and [Self::decompress_casting_key].
     pub fn decompress(self) -> ServerKey {
         ServerKey {
             #[cfg(feature = "boolean")]
@@ -128,6 +215,197 @@ impl CompressedServerKey {
             integer_key: Arc::new(self.integer_key.decompress()),
         }
     }
+
+    /// Decompresses the key, reporting the progress and stopping if `cancellation` is cancelled
+    ///
+    /// The steps are the decompression of each key, see
+    /// [generate_keys_with_progress](crate::high_level_api::generate_keys_with_progress). A
+    /// decompression cannot be interrupted and reports no GGSW ciphertext progress.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::{CancellationToken, ClientKey, ConfigBuilder};
+    ///
+    /// let config = ConfigBuilder::all_disabled()
+    ///     .enable_default_integers()
+    ///     .build();
+    /// let client_key = ClientKey::generate(config);
+    /// let compressed_server_key = client_key.generate_compressed_server_key();
+    ///
+    /// let cancellation = CancellationToken::new();
+    /// let server_key = compressed_server_key
+    ///     .decompress_with_progress(
+    ///         |progress| println!("{}/{}", progress.completed_steps, progress.total_steps),
+    ///         &cancellation,
+    ///     )
+    ///     .unwrap();
+    /// ```
+    pub fn decompress_with_progress(
+        self,
+        mut progress: impl FnMut(KeygenProgress) + Send,
+        cancellation: &CancellationToken,
+    ) -> Result<ServerKey, KeygenCancelled> {
+        let mut monitor = KeygenMonitor::new(&mut progress, cancellation, SERVER_KEY_STEPS);
+        let server_key = ServerKey {
+            #[cfg(feature = "boolean")]
+            boolean_key: monitor.step(KeygenStep::BooleanServerKey, || {
+                Arc::new(self.boolean_key.decompress())
+            })?,
+            #[cfg(feature = "integer")]
+            integer_key: monitor.step(KeygenStep::IntegerServerKey, || {
+                Arc::new(self.integer_key.decompress())
+            })?,
+        };
+        monitor.check_cancellation()?;
+        Ok(server_key)
+    }
+}
+
+#[cfg(feature = "integer")]
+impl CompressedServerKey {
+    /// Adds a compact public key to the key, replacing the previous one if any
+    pub fn with_compact_public_key(
+        mut self,
+        compact_public_key: CompressedCompactPublicKey,
+    ) -> Self {
+        self.compact_public_key = Some(compact_public_key);
+        self
+    }
+
+    /// Adds a casting key to the key, casting keys are indexed in insertion order
+    pub fn with_casting_key(mut self, casting_key: CompressedKeySwitchingKey) -> Self {
+        self.casting_keys.push(casting_key);
+        self
+    }
+
+    pub fn compact_public_key(&self) -> Option<&CompressedCompactPublicKey> {
+        self.compact_public_key.as_ref()
+    }
+
+    pub fn casting_keys(&self) -> &[CompressedKeySwitchingKey] {
+        &self.casting_keys
+    }
+
+    /// Returns `None` if the key does not carry a compact public key
+    pub fn decompress_compact_public_key(&self) -> Option<CompactPublicKey> {
+        self.compact_public_key
+            .clone()
+            .map(CompressedCompactPublicKey::decompress)
+    }
+
+    /// Returns `None` if there is no casting key at this index
+    pub fn decompress_casting_key(&self, index: usize) -> Option<KeySwitchingKey> {
+        self.casting_keys
+            .get(index)
+            .cloned()
+            .map(CompressedKeySwitchingKey::decompress)
+    }
+}
+
+// The key is serialized through a versioned enum so that the format can evolve while old keys
+// stay readable, the borrowed variant avoids cloning the keys to serialize them.
+#[derive(serde::Serialize)]
+struct CompressedServerKeyV0Ref<'a> {
+    #[cfg(feature = "boolean")]
+    boolean_key: &'a BooleanCompressedServerKey,
+    #[cfg(feature = "integer")]
+    integer_key: &'a IntegerCompressedServerKey,
+    #[cfg(feature = "integer")]
+    compact_public_key: &'a Option<CompressedCompactPublicKey>,
+    #[cfg(feature = "integer")]
+    casting_keys: &'a [CompressedKeySwitchingKey],
+}
+
+#[derive(serde::Serialize)]
+enum SerializableCompressedServerKey<'a> {
+    V0(CompressedServerKeyV0Ref<'a>),
+}
+
+#[derive(serde::Deserialize)]
+struct CompressedServerKeyV0 {
+    #[cfg(feature = "boolean")]
+    boolean_key: BooleanCompressedServerKey,
+    #[cfg(feature = "integer")]
+    integer_key: IntegerCompressedServerKey,
+    #[cfg(feature = "integer")]
+    compact_public_key: Option<CompressedCompactPublicKey>,
+    #[cfg(feature = "integer")]
+    casting_keys: Vec<CompressedKeySwitchingKey>,
+}
+
+#[derive(serde::Deserialize)]
+enum DeserializableCompressedServerKey {
+    V0(CompressedServerKeyV0),
+}
+
+impl serde::Serialize for CompressedServerKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        SerializableCompressedServerKey::V0(CompressedServerKeyV0Ref {
+            #[cfg(feature = "boolean")]
+            boolean_key: &self.boolean_key,
+            #[cfg(feature = "integer")]
+            integer_key: &self.integer_key,
+            #[cfg(feature = "integer")]
+            compact_public_key: &self.compact_public_key,
+            #[cfg(feature = "integer")]
+            casting_keys: &self.casting_keys,
+        })
+        .serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for CompressedServerKey {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        DeserializableCompressedServerKey::deserialize(deserializer).map(
+            |versioned| match versioned {
+                DeserializableCompressedServerKey::V0(key) => Self {
+                    #[cfg(feature = "boolean")]
+                    boolean_key: key.boolean_key,
+                    #[cfg(feature = "integer")]
+                    integer_key: key.integer_key,
+                    #[cfg(feature = "integer")]
+                    compact_public_key: key.compact_public_key,
+                    #[cfg(feature = "integer")]
+                    casting_keys: key.casting_keys,
+                },
+            },
+        )
+    }
+}
+
+/// A compressed server key serialized before the compressed server keys were versioned, it
+/// carries no compact public key nor casting key.
+///
+/// These keys cannot be deserialized as a [CompressedServerKey], deserialize them as a
+/// `LegacyCompressedServerKey` and convert them.
+#[derive(serde::Deserialize)]
+pub struct LegacyCompressedServerKey {
+    #[cfg(feature = "boolean")]
+    boolean_key: BooleanCompressedServerKey,
+    #[cfg(feature = "integer")]
+    integer_key: IntegerCompressedServerKey,
+}
+
+impl From<LegacyCompressedServerKey> for CompressedServerKey {
+    fn from(legacy: LegacyCompressedServerKey) -> Self {
+        Self {
+            #[cfg(feature = "boolean")]
+            boolean_key: legacy.boolean_key,
+            #[cfg(feature = "integer")]
+            integer_key: legacy.integer_key,
+            #[cfg(feature = "integer")]
+            compact_public_key: None,
+            #[cfg(feature = "integer")]
+            casting_keys: Vec::new(),
+        }
+    }
 }
 
 impl From<CompressedServerKey> for ServerKey {
@@ -26,11 +26,12 @@ macro_rules! expand_pub_use_fhe_type(
 
 pub use crate::core_crypto::commons::math::random::Seed;
 pub use config::{Config, ConfigBuilder};
-pub use errors::{Error, OutOfRangeError};
+pub use errors::{Error, KeygenCancelled, OutOfRangeError};
 pub use global_state::{set_server_key, unset_server_key, with_server_key_as_context};
 pub use keys::{
-    generate_keys, ClientKey, CompactPublicKey, CompressedCompactPublicKey, CompressedPublicKey,
-    CompressedServerKey, PublicKey, ServerKey,
+    generate_keys, generate_keys_with_progress, CancellationToken, ClientKey, CompactPublicKey,
+    CompressedCompactPublicKey, CompressedPublicKey, CompressedServerKey, KeygenProgress,
+    KeygenStep, LegacyCompressedServerKey, PublicKey, ServerKey,
 };
 
 #[cfg(test)]
@@ -39,6 +40,8 @@ mod tests;
 #[cfg(feature = "boolean")]
 pub use crate::high_level_api::booleans::{CompressedFheBool, FheBool, FheBoolParameters};
 #[cfg(feature = "integer")]
+pub use crate::integer::server_key::RoundingMode;
+#[cfg(feature = "integer")]
 expand_pub_use_fhe_type!(
     pub use crate::high_level_api::integers{
         FheUint8, FheUint10, FheUint12, FheUint14, FheUint16, FheUint32, FheUint64, FheUint128,
@@ -46,12 +49,28 @@ expand_pub_use_fhe_type!(
     };
 );
 #[cfg(feature = "integer")]
+pub use crate::high_level_api::integers::{
+    FheMap, FheOrdering, FheUintVector, SparseEncryptedVector,
+};
+#[cfg(feature = "serialized-size")]
+pub use compression_report::{CompressionReport, SerializedSizes};
+#[cfg(feature = "integer")]
+pub use debug::{DebugContext, DebugDecrypt};
+#[cfg(feature = "integer")]
+pub use hoisting::{ClearHoisting, HoistingReport};
+#[cfg(feature = "integer")]
 #[cfg(feature = "safe-deserialization")]
 pub use integers::safe_serialize::{
     safe_deserialize_conformant, safe_deserialize_conformant_compact_integer,
     safe_deserialize_conformant_compressed_integer, safe_deserialize_conformant_integer,
     safe_serialize,
 };
+#[cfg(feature = "integer")]
+pub use provenance::{ProvenanceChain, ProvenanceHash, ProvenanceRecord};
+#[cfg(feature = "integer")]
+pub use recorder::{ComputationGraph, ComputationNode, OperationRecorder};
+#[cfg(feature = "integer")]
+pub use time::{FheDuration, FheTimestamp};
 
 #[macro_use]
 mod details;
@@ -63,11 +82,27 @@ mod config;
 mod internal_traits;
 mod traits;
 
+#[cfg(feature = "bench")]
+pub mod bench;
 #[cfg(feature = "boolean")]
 mod booleans;
+#[cfg(feature = "serialized-size")]
+mod compression_report;
+#[cfg(feature = "integer")]
+mod debug;
 pub mod errors;
 #[cfg(feature = "integer")]
+mod hoisting;
+#[cfg(feature = "integer")]
 mod integers;
 /// The tfhe prelude.
 pub mod prelude;
+#[cfg(feature = "integer")]
+mod provenance;
+#[cfg(feature = "integer")]
+mod recorder;
+#[cfg(feature = "integer")]
+mod time;
+#[cfg(feature = "integer")]
+pub mod typed;
 pub mod parameters {}
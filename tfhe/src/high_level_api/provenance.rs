@@ -0,0 +1,245 @@
+//! Hash-linked provenance records attached to encrypted integers.
+//!
+//! Once [enabled](crate::high_level_api::FheUint8::enable_provenance) on an encrypted integer,
+//! each operation taking it as input attaches to its result a [ProvenanceRecord], with the name
+//! of the operation and the hashes of its inputs and of its output. The records are kept beside
+//! the ciphertexts: they are not serialized with them, and have no effect on the computations.
+//!
+//! Each record contains the hashes of the records which produced its inputs, so a record cannot be
+//! modified, removed or reordered without changing the hashes of all the records derived from it.
+//! A server can publish the hash of the [last record](ProvenanceChain::head) of an output, and
+//! later [export](crate::high_level_api::FheUint8::provenance) its chain for an auditor, who
+//! [verifies](ProvenanceChain::verify) it and reconstructs how the output was
+//! [derived](ProvenanceChain::derivation_of).
+//!
+//! All the hashes are SHA-256 digests.
+//!
+//! # Note
+//!
+//! Values are identified by the hash of their blocks, so two equal trivial ciphertexts are the
+//! same value. The chain records what the server claims to have computed, it does not prove that
+//! the outputs are the results of the operations.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::shortint::Ciphertext;
+
+/// A SHA-256 digest identifying a value or a record of a [ProvenanceChain]
+pub type ProvenanceHash = [u8; 32];
+
+/// An operation of a [ProvenanceChain]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProvenanceRecord {
+    /// Name of the operation, e.g. `add` or `scalar_mul`
+    pub operation: String,
+    /// Hashes of the encrypted inputs of the operation
+    pub inputs: Vec<ProvenanceHash>,
+    /// Hash of the result of the operation
+    pub output: ProvenanceHash,
+    /// Hashes of the records which produced the inputs, empty if the inputs were not produced by
+    /// a recorded operation
+    pub previous: Vec<ProvenanceHash>,
+    /// Hash of this record, computed from all the other fields
+    pub hash: ProvenanceHash,
+}
+
+impl ProvenanceRecord {
+    fn new(
+        operation: String,
+        inputs: Vec<ProvenanceHash>,
+        output: ProvenanceHash,
+        previous: Vec<ProvenanceHash>,
+    ) -> Self {
+        let hash = Self::compute_hash(&operation, &inputs, &output, &previous);
+        Self {
+            operation,
+            inputs,
+            output,
+            previous,
+            hash,
+        }
+    }
+
+    fn compute_hash(
+        operation: &str,
+        inputs: &[ProvenanceHash],
+        output: &ProvenanceHash,
+        previous: &[ProvenanceHash],
+    ) -> ProvenanceHash {
+        let mut hasher = Sha256::new();
+        hasher.update(b"tfhe-rs ProvenanceRecord");
+        hasher.update((previous.len() as u64).to_le_bytes());
+        for record in previous {
+            hasher.update(record);
+        }
+        hasher.update((operation.len() as u64).to_le_bytes());
+        hasher.update(operation.as_bytes());
+        hasher.update((inputs.len() as u64).to_le_bytes());
+        for input in inputs {
+            hasher.update(input);
+        }
+        hasher.update(output);
+        hasher.finalize().into()
+    }
+}
+
+/// The records of the operations an encrypted integer was derived from
+///
+/// The records are in the order they were done, so the records producing the inputs of an
+/// operation always come before it, and the last one produces the value.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProvenanceChain {
+    pub records: Vec<ProvenanceRecord>,
+}
+
+impl ProvenanceChain {
+    /// Returns the hash of the last record, which depends on all the records of the chain
+    pub fn head(&self) -> Option<ProvenanceHash> {
+        self.records.last().map(|record| record.hash)
+    }
+
+    /// Returns whether the hash of each record is valid, and links to records before it
+    pub fn verify(&self) -> bool {
+        let mut seen = HashSet::new();
+        for record in &self.records {
+            let hash = ProvenanceRecord::compute_hash(
+                &record.operation,
+                &record.inputs,
+                &record.output,
+                &record.previous,
+            );
+            if record.hash != hash || !record.previous.iter().all(|link| seen.contains(link)) {
+                return false;
+            }
+            seen.insert(record.hash);
+        }
+        true
+    }
+
+    /// Returns the records of the operations the value was derived from, in the order they were
+    /// done, the last one being the operation producing the value.
+    ///
+    /// The value is identified by its
+    /// [provenance_hash](crate::high_level_api::FheUint8::provenance_hash). The result is empty
+    /// if the value was not produced by a recorded operation, e.g. if it is an input of the
+    /// computation.
+    pub fn derivation_of(&self, value_hash: &ProvenanceHash) -> Vec<&ProvenanceRecord> {
+        let mut needed = HashSet::from([*value_hash]);
+        let mut derivation = Vec::new();
+        // A value may be produced several times, the last production before its use is the one
+        // that matters, so the records are visited backward
+        for record in self.records.iter().rev() {
+            if needed.remove(&record.output) {
+                needed.extend(record.inputs.iter().copied());
+                derivation.push(record);
+            }
+        }
+        derivation.reverse();
+        derivation
+    }
+}
+
+struct ProvenanceNode {
+    record: ProvenanceRecord,
+    parents: Vec<Arc<ProvenanceNode>>,
+}
+
+impl Drop for ProvenanceNode {
+    // The default drop would recurse as deep as the chain is long
+    fn drop(&mut self) {
+        let mut parents = std::mem::take(&mut self.parents);
+        while let Some(parent) = parents.pop() {
+            if let Ok(mut parent) = Arc::try_unwrap(parent) {
+                parents.append(&mut parent.parents);
+            }
+        }
+    }
+}
+
+/// The provenance attached to an encrypted integer for which it is enabled
+///
+/// The records are shared with the values the integer was derived from, so attaching a record to
+/// the result of an operation does not copy the records of its inputs.
+#[derive(Clone, Default)]
+pub(crate) struct Provenance {
+    head: Option<Arc<ProvenanceNode>>,
+}
+
+impl Provenance {
+    /// Returns the records of the chain, the ones producing the inputs of a record before it
+    pub(crate) fn chain(&self) -> ProvenanceChain {
+        let mut records = Vec::new();
+        let mut visited = HashSet::new();
+        // Iterative depth-first post-order traversal: (node, whether its parents were pushed)
+        let mut stack: Vec<(&ProvenanceNode, bool)> = self
+            .head
+            .iter()
+            .map(|head| (head.as_ref(), false))
+            .collect();
+        while let Some((node, parents_pushed)) = stack.pop() {
+            if parents_pushed {
+                if visited.insert(node.record.hash) {
+                    records.push(node.record.clone());
+                }
+                continue;
+            }
+            if visited.contains(&node.record.hash) {
+                continue;
+            }
+            stack.push((node, true));
+            stack.extend(
+                node.parents
+                    .iter()
+                    .rev()
+                    .map(|parent| (parent.as_ref(), false)),
+            );
+        }
+        ProvenanceChain { records }
+    }
+}
+
+/// Returns the hash identifying a value in a [ProvenanceChain]
+pub(crate) fn value_hash(blocks: &[Ciphertext]) -> ProvenanceHash {
+    let mut hasher = Sha256::new();
+    hasher.update(b"tfhe-rs ProvenanceValue");
+    hasher.update((blocks.len() as u64).to_le_bytes());
+    for block in blocks {
+        let data = block.ct.as_ref();
+        hasher.update((data.len() as u64).to_le_bytes());
+        for word in data {
+            hasher.update(word.to_le_bytes());
+        }
+    }
+    hasher.finalize().into()
+}
+
+/// Returns the provenance of the result of an operation, with a record of the operation, if the
+/// provenance of one of the inputs is enabled
+pub(crate) fn derive_provenance(
+    operation: &str,
+    inputs: &[&[Ciphertext]],
+    provenances: &[&Option<Provenance>],
+    output: &[Ciphertext],
+) -> Option<Provenance> {
+    if provenances.iter().all(|provenance| provenance.is_none()) {
+        return None;
+    }
+
+    let parents = provenances
+        .iter()
+        .filter_map(|provenance| provenance.as_ref()?.head.clone())
+        .collect::<Vec<_>>();
+    let record = ProvenanceRecord::new(
+        operation.to_owned(),
+        inputs.iter().map(|blocks| value_hash(blocks)).collect(),
+        value_hash(output),
+        parents.iter().map(|parent| parent.record.hash).collect(),
+    );
+    Some(Provenance {
+        head: Some(Arc::new(ProvenanceNode { record, parents })),
+    })
+}
@@ -0,0 +1,394 @@
+//! Recording of the graph of the operations done on encrypted integers.
+//!
+//! An [OperationRecorder] records the operations done on the current thread, until it is
+//! finished. The resulting [ComputationGraph] links each operation to the ones that produced its
+//! inputs, with the number of blocks and of programmable bootstraps (PBS) of each operation.
+//! It can be exported to [DOT](ComputationGraph::to_dot) to be rendered with graphviz, or to any
+//! serde format, e.g. JSON.
+//!
+//! The PBS dominate the cost of the computations: the [critical
+//! path](ComputationGraph::critical_path) of the graph is the chain of dependent operations with
+//! the most PBS, a bound on the latency whatever the parallelism. When it is close to the
+//! [total](ComputationGraph::total_pbs_count), the computation is mostly sequential.
+//!
+//! # Note
+//!
+//! - Values are identified by a hash of their blocks, so two equal trivial ciphertexts are
+//!   recorded as the same value.
+//! - The PBS of an operation are counted with
+//!   [programmable_bootstrap_count](crate::shortint::engine::programmable_bootstrap_count),
+//!   which is shared by all threads: the counts are only exact if no other thread computes on
+//!   ciphertexts while recording.
+
+use std::cell::RefCell;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::hash::{Hash, Hasher};
+
+use serde::{Deserialize, Serialize};
+
+use crate::high_level_api::provenance::{derive_provenance, Provenance};
+use crate::shortint::engine::programmable_bootstrap_count;
+use crate::shortint::Ciphertext;
+
+thread_local! {
+    static RECORDING: RefCell<Option<Recording>> = RefCell::new(None);
+}
+
+/// An operation of a [ComputationGraph]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComputationNode {
+    /// Index of the node in [ComputationGraph::nodes]
+    pub id: usize,
+    /// Name of the operation, e.g. `add` or `scalar_mul`, [Self::INPUT_OPERATION] for the values
+    /// which were not computed while recording
+    pub operation: String,
+    /// Number of blocks of the result
+    pub num_blocks: usize,
+    /// Number of programmable bootstraps computed by the operation
+    pub pbs_count: u64,
+    /// Ids of the nodes producing the encrypted inputs of the operation
+    pub inputs: Vec<usize>,
+}
+
+impl ComputationNode {
+    /// The operation of the nodes of the values which were not computed while recording
+    pub const INPUT_OPERATION: &'static str = "input";
+}
+
+/// The graph of the operations recorded by an [OperationRecorder]
+///
+/// The nodes are in the order the operations were done, so the inputs of a node always come
+/// before it.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComputationGraph {
+    pub nodes: Vec<ComputationNode>,
+}
+
+impl ComputationGraph {
+    pub fn total_pbs_count(&self) -> u64 {
+        self.nodes.iter().map(|node| node.pbs_count).sum()
+    }
+
+    /// Returns the ids of the nodes of the chain of dependent operations with the most PBS, from
+    /// the first operation to the last.
+    pub fn critical_path(&self) -> Vec<usize> {
+        // (PBS of the heaviest chain ending at the node, previous node of this chain)
+        let mut heaviest = Vec::<(u64, Option<usize>)>::with_capacity(self.nodes.len());
+        for node in &self.nodes {
+            let previous = node
+                .inputs
+                .iter()
+                .copied()
+                .max_by_key(|&input| heaviest[input].0);
+            let pbs_count = previous.map_or(0, |input| heaviest[input].0) + node.pbs_count;
+            heaviest.push((pbs_count, previous));
+        }
+
+        let mut path = Vec::new();
+        let mut current = (0..self.nodes.len()).max_by_key(|&id| heaviest[id].0);
+        while let Some(id) = current {
+            path.push(id);
+            current = heaviest[id].1;
+        }
+        path.reverse();
+        path
+    }
+
+    pub fn critical_path_pbs_count(&self) -> u64 {
+        self.critical_path()
+            .into_iter()
+            .map(|id| self.nodes[id].pbs_count)
+            .sum()
+    }
+
+    /// Returns the graph in the DOT format of graphviz, the nodes of the
+    /// [critical path](Self::critical_path) are drawn in red.
+    pub fn to_dot(&self) -> String {
+        let critical_path = self.critical_path();
+
+        let mut dot = String::from("digraph computation {\n    node [shape=box];\n");
+        // Writing to a String cannot fail
+        for node in &self.nodes {
+            let label = if node.operation == ComputationNode::INPUT_OPERATION {
+                format!("{}\\n{} blocks", node.operation, node.num_blocks)
+            } else {
+                format!(
+                    "{}\\n{} blocks, {} PBS",
+                    node.operation, node.num_blocks, node.pbs_count
+                )
+            };
+            let color = if critical_path.contains(&node.id) {
+                ", color=red"
+            } else {
+                ""
+            };
+            let _ = writeln!(dot, "    n{} [label=\"{label}\"{color}];", node.id);
+        }
+        for node in &self.nodes {
+            for input in &node.inputs {
+                let _ = writeln!(dot, "    n{input} -> n{};", node.id);
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+#[derive(Default)]
+struct Recording {
+    graph: ComputationGraph,
+    // Hash of the blocks of a value -> id of the node which produced it
+    producers: HashMap<u64, usize>,
+}
+
+impl Recording {
+    fn push_node(
+        &mut self,
+        operation: String,
+        num_blocks: usize,
+        pbs_count: u64,
+        inputs: Vec<usize>,
+    ) -> usize {
+        let id = self.graph.nodes.len();
+        self.graph.nodes.push(ComputationNode {
+            id,
+            operation,
+            num_blocks,
+            pbs_count,
+            inputs,
+        });
+        id
+    }
+
+    fn node_of(&mut self, blocks: &[Ciphertext]) -> usize {
+        let fingerprint = fingerprint(blocks);
+        if let Some(&id) = self.producers.get(&fingerprint) {
+            return id;
+        }
+        let id = self.push_node(
+            ComputationNode::INPUT_OPERATION.to_owned(),
+            blocks.len(),
+            0,
+            Vec::new(),
+        );
+        self.producers.insert(fingerprint, id);
+        id
+    }
+}
+
+fn fingerprint(blocks: &[Ciphertext]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for block in blocks {
+        block.ct.as_ref().hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+/// Records the operations done on encrypted integers on the current thread, until it is finished
+/// or dropped.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8, OperationRecorder};
+///
+/// let config = ConfigBuilder::all_disabled()
+///     .enable_default_integers()
+///     .build();
+/// let (client_key, server_key) = generate_keys(config);
+/// set_server_key(server_key);
+///
+/// let a = FheUint8::encrypt(27u8, &client_key);
+/// let b = FheUint8::encrypt(100u8, &client_key);
+///
+/// let recorder = OperationRecorder::start();
+/// let c = &a + &b;
+/// let d = &c * 2u8;
+/// let graph = recorder.finish();
+///
+/// // a, b, add, scalar_mul
+/// assert_eq!(graph.nodes.len(), 4);
+/// assert_eq!(graph.nodes[3].inputs, vec![2]);
+/// assert_eq!(graph.critical_path().last(), Some(&3));
+/// assert_eq!(graph.critical_path_pbs_count(), graph.total_pbs_count());
+///
+/// let dot = graph.to_dot();
+/// assert!(dot.starts_with("digraph"));
+/// # let _ = d;
+/// ```
+pub struct OperationRecorder {
+    // The recording of an outer recorder, restored when this one is finished or dropped
+    previous: Option<Recording>,
+    finished: bool,
+}
+
+impl OperationRecorder {
+    pub fn start() -> Self {
+        let previous = RECORDING.with(|recording| recording.replace(Some(Recording::default())));
+        Self {
+            previous,
+            finished: false,
+        }
+    }
+
+    /// Returns whether operations are being recorded on the current thread
+    pub fn is_recording() -> bool {
+        RECORDING.with(|recording| recording.borrow().is_some())
+    }
+
+    /// Stops the recording, and returns the graph of the operations recorded
+    pub fn finish(mut self) -> ComputationGraph {
+        // The drop must not restore the previous recording a second time
+        self.finished = true;
+        let previous = self.previous.take();
+        RECORDING
+            .with(|recording| recording.replace(previous))
+            .map(|recording| recording.graph)
+            .unwrap_or_default()
+    }
+}
+
+impl Drop for OperationRecorder {
+    fn drop(&mut self) {
+        if self.finished {
+            return;
+        }
+        let previous = self.previous.take();
+        RECORDING.with(|recording| recording.replace(previous));
+    }
+}
+
+fn record<R>(
+    operation: &str,
+    inputs: &[&[Ciphertext]],
+    provenances: &[&Option<Provenance>],
+    compute: impl FnOnce() -> R,
+    result_blocks: impl FnOnce(&R) -> &[Ciphertext],
+) -> (R, Option<Provenance>) {
+    let start_count = programmable_bootstrap_count();
+    let result = compute();
+    // The count may have been reset meanwhile
+    let pbs_count = programmable_bootstrap_count().saturating_sub(start_count);
+
+    let operation = operation.trim_end_matches("_parallelized");
+    let blocks = result_blocks(&result);
+    let provenance = derive_provenance(operation, inputs, provenances, blocks);
+
+    RECORDING.with(|recording| {
+        let mut recording = recording.borrow_mut();
+        let Some(recording) = recording.as_mut() else {
+            return;
+        };
+        let inputs = inputs
+            .iter()
+            .map(|blocks| recording.node_of(blocks))
+            .collect();
+        let id = recording.push_node(operation.to_owned(), blocks.len(), pbs_count, inputs);
+        recording.producers.insert(fingerprint(blocks), id);
+    });
+    (result, provenance)
+}
+
+/// Returns whether the operation has to be recorded, either by an [OperationRecorder] or in the
+/// provenance of its result
+fn is_recorded(provenances: &[&Option<Provenance>]) -> bool {
+    OperationRecorder::is_recording() || provenances.iter().any(|provenance| provenance.is_some())
+}
+
+/// Computes `compute`, recording it as the operation `operation` on `inputs` if an
+/// [OperationRecorder] is active on the current thread.
+///
+/// `provenances` are the provenances of the inputs, the provenance of the result is returned
+/// with it.
+pub(crate) fn record_operation<T>(
+    operation: &str,
+    inputs: &[&[Ciphertext]],
+    provenances: &[&Option<Provenance>],
+    compute: impl FnOnce() -> T,
+) -> (T, Option<Provenance>)
+where
+    T: crate::integer::IntegerCiphertext,
+{
+    #[cfg(feature = "tracing")]
+    let _span =
+        tracing::debug_span!("fhe_operation", operation, num_inputs = inputs.len()).entered();
+
+    if !is_recorded(provenances) {
+        return (compute(), None);
+    }
+    record(operation, inputs, provenances, compute, |result| {
+        result.blocks()
+    })
+}
+
+/// Same as [record_operation] for the operations updating `lhs` in place, `lhs` is the first
+/// input of the operation, and its provenance is updated in place too.
+pub(crate) fn record_operation_assign<T>(
+    operation: &str,
+    lhs: &mut T,
+    lhs_provenance: &mut Option<Provenance>,
+    other_inputs: &[&[Ciphertext]],
+    other_provenances: &[&Option<Provenance>],
+    compute: impl FnOnce(&mut T),
+) where
+    T: crate::integer::IntegerCiphertext,
+{
+    #[cfg(feature = "tracing")]
+    let _span = tracing::debug_span!(
+        "fhe_operation",
+        operation,
+        num_inputs = other_inputs.len() + 1
+    )
+    .entered();
+
+    let provenances = std::iter::once(&*lhs_provenance)
+        .chain(other_provenances.iter().copied())
+        .collect::<Vec<_>>();
+    if !is_recorded(&provenances) {
+        compute(lhs);
+        return;
+    }
+    // The input must be kept to be identified once it is overwritten
+    let lhs_blocks = lhs.blocks().to_vec();
+    let inputs = std::iter::once(lhs_blocks.as_slice())
+        .chain(other_inputs.iter().copied())
+        .collect::<Vec<_>>();
+    let (_, provenance) = record(
+        operation,
+        &inputs,
+        &provenances,
+        || {
+            compute(lhs);
+            lhs
+        },
+        |lhs| lhs.blocks(),
+    );
+    *lhs_provenance = provenance;
+}
+
+/// Same as [record_operation] for the operations whose result is a single block, e.g. the
+/// ordering of two integers.
+pub(crate) fn record_block_operation(
+    operation: &str,
+    inputs: &[&[Ciphertext]],
+    provenances: &[&Option<Provenance>],
+    compute: impl FnOnce() -> Ciphertext,
+) -> (Ciphertext, Option<Provenance>) {
+    #[cfg(feature = "tracing")]
+    let _span =
+        tracing::debug_span!("fhe_operation", operation, num_inputs = inputs.len()).entered();
+
+    if !is_recorded(provenances) {
+        return (compute(), None);
+    }
+    record(
+        operation,
+        inputs,
+        provenances,
+        compute,
+        std::slice::from_ref,
+    )
+}
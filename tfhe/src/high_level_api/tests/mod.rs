@@ -112,6 +112,165 @@ fn test_server_key_decompression() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[cfg(feature = "integer")]
+#[test]
+fn test_compressed_server_key_with_public_and_casting_keys() {
+    use crate::integer::key_switching_key::CompressedKeySwitchingKey;
+    use crate::shortint::parameters::{
+        ShortintKeySwitchingParameters, PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS,
+    };
+    use crate::{CompactFheUint8, CompressedCompactPublicKey, LegacyCompressedServerKey};
+
+    let config = ConfigBuilder::all_disabled()
+        .enable_custom_integers(PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS, None)
+        .build();
+    let cks = ClientKey::generate(config.clone());
+    let other_cks = ClientKey::generate(config);
+
+    let integer_cks: &crate::integer::ClientKey = cks.as_ref();
+    let ksk_params = ShortintKeySwitchingParameters::new(
+        integer_cks.parameters().ks_base_log(),
+        integer_cks.parameters().ks_level(),
+    );
+    let other_integer_cks: &crate::integer::ClientKey = other_cks.as_ref();
+    let casting_key = CompressedKeySwitchingKey::new(
+        (
+            other_integer_cks,
+            &crate::integer::CompressedServerKey::new(other_integer_cks),
+        ),
+        (
+            integer_cks,
+            &crate::integer::CompressedServerKey::new(integer_cks),
+        ),
+        ksk_params,
+    );
+
+    let compressed_sks = CompressedServerKey::new(&cks)
+        .with_compact_public_key(CompressedCompactPublicKey::new(&cks))
+        .with_casting_key(casting_key);
+    let serialized = bincode::serialize(&compressed_sks).unwrap();
+    let compressed_sks: CompressedServerKey = bincode::deserialize(&serialized).unwrap();
+    assert_eq!(compressed_sks.casting_keys().len(), 1);
+
+    let public_key = compressed_sks.decompress_compact_public_key().unwrap();
+    let ksk = compressed_sks.decompress_casting_key(0).unwrap();
+    assert!(compressed_sks.decompress_casting_key(1).is_none());
+    set_server_key(compressed_sks.decompress());
+
+    let a = CompactFheUint8::encrypt(12u8, &public_key).expand();
+    let b = ksk.cast(&other_integer_cks.encrypt_radix(30u8, 4));
+    let b = FheUint8::try_from(b).unwrap();
+    let decrypted: u8 = (a + b).decrypt(&cks);
+    assert_eq!(decrypted, 42);
+
+    // The keys serialized before the versioning carry no other key
+    #[derive(serde::Serialize)]
+    struct PreviousCompressedServerKey {
+        #[cfg(feature = "boolean")]
+        boolean_key: crate::high_level_api::booleans::BooleanCompressedServerKey,
+        integer_key: crate::high_level_api::integers::IntegerCompressedServerKey,
+    }
+    let CompressedServerKey {
+        #[cfg(feature = "boolean")]
+        boolean_key,
+        integer_key,
+        ..
+    } = CompressedServerKey::new(&cks);
+    let legacy = bincode::serialize(&PreviousCompressedServerKey {
+        #[cfg(feature = "boolean")]
+        boolean_key,
+        integer_key,
+    })
+    .unwrap();
+    let compressed_sks: CompressedServerKey =
+        bincode::deserialize::<LegacyCompressedServerKey>(&legacy)
+            .unwrap()
+            .into();
+    assert!(compressed_sks.compact_public_key().is_none());
+    assert!(compressed_sks.casting_keys().is_empty());
+}
+
+#[cfg(feature = "integer")]
+#[test]
+fn test_keygen_progress_and_cancellation() {
+    use crate::{generate_keys_with_progress, CancellationToken, KeygenCancelled, KeygenStep};
+
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .build();
+
+    let cancellation = CancellationToken::new();
+    let mut reports = vec![];
+    let (cks, _sks) =
+        generate_keys_with_progress(config.clone(), |p| reports.push(p), &cancellation).unwrap();
+    assert_eq!(reports[0].step, KeygenStep::ClientKey);
+    assert_eq!(reports[0].step_total_ggsw, 0);
+    let step_starts = reports
+        .iter()
+        .filter(|report| report.step_completed_ggsw == 0)
+        .collect::<Vec<_>>();
+    assert_eq!(step_starts.len(), 2);
+    for (i, report) in step_starts.iter().enumerate() {
+        assert_eq!(report.completed_steps, i);
+        assert_eq!(report.total_steps, 2);
+    }
+
+    // The bootstrapping key generation reports each of its GGSW ciphertexts
+    let total_ggsw = crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS
+        .lwe_dimension
+        .0;
+    let server_key_reports = reports
+        .iter()
+        .filter(|report| report.step == KeygenStep::IntegerServerKey)
+        .collect::<Vec<_>>();
+    assert_eq!(server_key_reports.len(), total_ggsw + 1);
+    for (i, report) in server_key_reports.iter().enumerate() {
+        assert_eq!(report.completed_steps, 1);
+        assert_eq!(report.step_completed_ggsw, i);
+        assert_eq!(report.step_total_ggsw, total_ggsw);
+    }
+
+    // Cancelling during the bootstrapping key generation stops it
+    let cancellation = CancellationToken::new();
+    let mut num_ggsw_reports = 0;
+    let result = generate_keys_with_progress(
+        config.clone(),
+        |p| {
+            if p.step_completed_ggsw > 0 {
+                num_ggsw_reports += 1;
+            }
+            if p.step_completed_ggsw == 10 {
+                cancellation.cancel();
+            }
+        },
+        &cancellation,
+    );
+    assert_eq!(result.err(), Some(KeygenCancelled));
+    assert!(num_ggsw_reports >= 10);
+    assert!(num_ggsw_reports < total_ggsw);
+
+    // Cancelling during a step stops before the next one
+    let cancellation = CancellationToken::new();
+    let mut num_reports = 0;
+    let result = generate_keys_with_progress(
+        config,
+        |_| {
+            num_reports += 1;
+            cancellation.cancel();
+        },
+        &cancellation,
+    );
+    assert_eq!(result.err(), Some(KeygenCancelled));
+    assert_eq!(num_reports, 1);
+
+    let compressed_sks = CompressedServerKey::new(&cks);
+    let cancellation = CancellationToken::new();
+    cancellation.clone().cancel();
+    let result =
+        compressed_sks.decompress_with_progress(|_| panic!("not cancelled"), &cancellation);
+    assert!(result.is_err());
+}
+
 #[test]
 fn test_with_seed() -> Result<(), Box<dyn std::error::Error>> {
     use crate::Seed;
@@ -195,3 +354,464 @@ fn test_serialize_deserialize_are_implemented() {
     can_be_deserialized(&csks);
     can_be_deserialized(&pksz);
 }
+
+#[cfg(all(feature = "integer", feature = "serialized-size"))]
+#[test]
+fn test_compression_report() {
+    use crate::high_level_api::CompressionReport;
+
+    let config = ConfigBuilder::all_disabled()
+        .enable_custom_integers(
+            crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS,
+            None,
+        )
+        .build();
+
+    let report = CompressionReport::new(config);
+
+    assert!(report.server_key.compressed < report.server_key.original);
+    assert!(report.server_key.compact.is_none());
+    assert!(report.public_key.compressed < report.public_key.original);
+    assert!(report.public_key.compact.unwrap() < report.public_key.compressed);
+
+    let names = report
+        .ciphertexts
+        .iter()
+        .map(|(name, _)| *name)
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["FheUint8", "FheUint16", "FheUint32", "FheUint64"]);
+
+    for (_, sizes) in &report.ciphertexts {
+        assert!(sizes.compression_ratio() > 1.0);
+        assert!(sizes.compact_ratio().unwrap() > 1.0);
+    }
+
+    // Bigger types have more blocks
+    let original_sizes = report
+        .ciphertexts
+        .iter()
+        .map(|(_, sizes)| sizes.original)
+        .collect::<Vec<_>>();
+    assert!(original_sizes.windows(2).all(|w| w[0] < w[1]));
+}
+
+#[cfg(feature = "integer")]
+#[test]
+fn test_typed_parameter_sets() {
+    use crate::high_level_api::typed::{
+        FheUint16, ParamMessage2Carry2PbsKs, ParameterSet, TypedClientKey,
+    };
+
+    let client_key = TypedClientKey::<ParamMessage2Carry2PbsKs>::generate();
+    let integer_key: &crate::integer::ClientKey = client_key.as_inner().as_ref();
+    assert_eq!(
+        integer_key.parameters(),
+        ParamMessage2Carry2PbsKs::block_parameters()
+    );
+    client_key.generate_server_key().set();
+
+    let a = FheUint16::encrypt(1000u16, &client_key);
+    let b = FheUint16::encrypt(24u16, &client_key);
+
+    let c = &a * &b - b.clone();
+    let decrypted: u16 = c.decrypt(&client_key);
+    assert_eq!(decrypted, 23976);
+
+    let decrypted: u16 = a.gt(&b).decrypt(&client_key);
+    assert_eq!(decrypted, 1);
+    let decrypted: u16 = a.eq(&b).decrypt(&client_key);
+    assert_eq!(decrypted, 0);
+
+    let serialized = bincode::serialize(&c).unwrap();
+    let c: FheUint16<ParamMessage2Carry2PbsKs> = bincode::deserialize(&serialized).unwrap();
+    let decrypted: u16 = (c ^ &a).decrypt(&client_key);
+    assert_eq!(decrypted, 23976 ^ 1000);
+}
+
+#[cfg(feature = "integer")]
+#[test]
+fn test_timestamp_window() {
+    use crate::high_level_api::{set_server_key, FheDuration, FheTimestamp};
+
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .build();
+    let (client_key, server_key) = generate_keys(config);
+    set_server_key(server_key);
+
+    let start = FheTimestamp::encrypt(1_000u64, &client_key);
+    let end = FheTimestamp::encrypt(2_000u64, &client_key);
+
+    for (clear, expected) in [
+        (999u64, 0u64),
+        (1_000, 1),
+        (1_500, 1),
+        (2_000, 1),
+        (2_001, 0),
+    ] {
+        let timestamp = FheTimestamp::encrypt(clear, &client_key);
+        let decrypted: u64 = timestamp
+            .is_within_window(&start, &end)
+            .decrypt(&client_key);
+        assert_eq!(decrypted, expected, "{clear} in [1000, 2000]");
+        let decrypted: u64 = timestamp
+            .is_within_clear_window(1_000, 2_000)
+            .decrypt(&client_key);
+        assert_eq!(decrypted, expected, "{clear} in [1000, 2000]");
+    }
+
+    // The window wraps around when end < start
+    let timestamp = FheTimestamp::encrypt(500u64, &client_key);
+    let decrypted: u64 = timestamp
+        .is_within_window(&end, &start)
+        .decrypt(&client_key);
+    assert_eq!(decrypted, 1);
+
+    let delay = FheDuration::encrypt(300u64, &client_key);
+    let later = &start + &delay;
+    let decrypted: u64 = (&later - &start).decrypt(&client_key);
+    assert_eq!(decrypted, 300);
+    let decrypted: u64 = (later - 100u64).decrypt(&client_key);
+    assert_eq!(decrypted, 1_200);
+    let decrypted: u64 = start.lt(&end).decrypt(&client_key);
+    assert_eq!(decrypted, 1);
+}
+
+#[cfg(feature = "integer")]
+#[test]
+fn test_debug_context() {
+    use crate::high_level_api::{set_server_key, DebugContext};
+
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .build();
+    let (client_key, server_key) = generate_keys(config);
+    set_server_key(server_key);
+
+    let a = FheUint8::encrypt(27u8, &client_key);
+    let b = FheUint8::encrypt(100u8, &client_key);
+    let c = &a + &b;
+
+    // Without a registered key, nothing is checked
+    assert!(!DebugContext::is_active());
+    crate::debug_assert_decrypts_to!(c, 0u8);
+    assert!(DebugContext::report(&c).is_none());
+
+    let debug_context = DebugContext::new(client_key);
+    crate::debug_assert_decrypts_to!(c, 127u8);
+    let report = DebugContext::report(&c).unwrap();
+    assert_eq!(report.lines().count(), 4);
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        crate::debug_assert_decrypts_to!(c, 128u8);
+    }));
+    if cfg!(debug_assertions) {
+        let message = result.unwrap_err();
+        let message = message.downcast_ref::<String>().unwrap();
+        assert!(message.starts_with("`c` decrypts to 127, expected 128"));
+    }
+
+    drop(debug_context);
+    assert!(!DebugContext::is_active());
+}
+
+#[cfg(feature = "integer")]
+#[test]
+fn test_simulator_config() {
+    use crate::high_level_api::{set_server_key, Config, FheUint32};
+    use crate::shortint::engine::programmable_bootstrap_count;
+    use rand::Rng;
+
+    let (client_key, server_key) = generate_keys(Config::simulator());
+    set_server_key(server_key);
+
+    let mut rng = rand::thread_rng();
+    for _ in 0..5 {
+        let clear_a = rng.gen::<u32>();
+        let clear_b = rng.gen::<u32>();
+
+        let a = FheUint32::encrypt(clear_a, &client_key);
+        let b = FheUint32::encrypt(clear_b, &client_key);
+
+        let count_before = programmable_bootstrap_count();
+        let c = &a * &b + &a;
+        // Other tests may run in parallel, the count can only be checked to increase
+        assert!(programmable_bootstrap_count() > count_before);
+
+        let decrypted: u32 = c.decrypt(&client_key);
+        assert_eq!(
+            decrypted,
+            clear_a.wrapping_mul(clear_b).wrapping_add(clear_a)
+        );
+
+        let decrypted: u32 = a.lt(&b).decrypt(&client_key);
+        assert_eq!(decrypted, u32::from(clear_a < clear_b));
+    }
+
+    // The boolean type is simulated as well
+    #[cfg(feature = "boolean")]
+    for _ in 0..5 {
+        let clear_a = rng.gen::<bool>();
+        let clear_b = rng.gen::<bool>();
+
+        let a = FheBool::encrypt(clear_a, &client_key);
+        let b = FheBool::encrypt(clear_b, &client_key);
+
+        let c = &a & &b;
+        let d = !(&a | &b);
+        assert_eq!(c.decrypt(&client_key), clear_a && clear_b);
+        assert_eq!(d.decrypt(&client_key), !(clear_a || clear_b));
+    }
+}
+
+#[cfg(feature = "integer")]
+#[test]
+fn test_operation_recorder() {
+    use crate::high_level_api::{set_server_key, Config, OperationRecorder};
+
+    let (client_key, server_key) = generate_keys(Config::simulator());
+    set_server_key(server_key);
+
+    let a = FheUint8::encrypt(27u8, &client_key);
+    let b = FheUint8::encrypt(100u8, &client_key);
+
+    let recorder = OperationRecorder::start();
+    assert!(OperationRecorder::is_recording());
+    // Two independent branches, joined by the xor
+    let sum = &a + &b;
+    let product = &a * &b;
+    let mut result = sum ^ product;
+    result += 1u8;
+    let graph = recorder.finish();
+    assert!(!OperationRecorder::is_recording());
+
+    let operations = graph
+        .nodes
+        .iter()
+        .map(|node| node.operation.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        operations,
+        [
+            "input",
+            "input",
+            "add",
+            "mul",
+            "bitxor",
+            "scalar_add_assign"
+        ]
+    );
+    assert_eq!(graph.nodes[2].inputs, [0, 1]);
+    assert_eq!(graph.nodes[3].inputs, [0, 1]);
+    assert_eq!(graph.nodes[4].inputs, [2, 3]);
+    assert_eq!(graph.nodes[5].inputs, [4]);
+    assert!(graph.nodes.iter().all(|node| node.num_blocks == 4));
+
+    // The critical path goes through one of the branches only
+    let critical_path = graph.critical_path();
+    assert_eq!(critical_path.len(), 4);
+    assert_eq!(&critical_path[2..], [4, 5]);
+    assert!(graph.critical_path_pbs_count() <= graph.total_pbs_count());
+
+    let dot = graph.to_dot();
+    assert!(dot.contains("n2 -> n4;"));
+    assert!(dot.contains("n3 -> n4;"));
+
+    let decrypted: u8 = result.decrypt(&client_key);
+    assert_eq!(
+        decrypted,
+        (27u8.wrapping_add(100) ^ 27u8.wrapping_mul(100)) + 1
+    );
+}
+
+#[cfg(feature = "integer")]
+#[test]
+fn test_nested_operation_recorders() {
+    use crate::high_level_api::{set_server_key, Config, OperationRecorder};
+
+    let (client_key, server_key) = generate_keys(Config::simulator());
+    set_server_key(server_key);
+
+    let a = FheUint8::encrypt(27u8, &client_key);
+    let b = FheUint8::encrypt(100u8, &client_key);
+
+    let outer = OperationRecorder::start();
+    let sum = &a + &b;
+
+    let inner = OperationRecorder::start();
+    let product = &a * &b;
+    let inner_graph = inner.finish();
+    // The outer recorder is active again
+    assert!(OperationRecorder::is_recording());
+
+    {
+        // A dropped recorder restores the outer one too
+        let _dropped = OperationRecorder::start();
+        let _ = &a - &b;
+    }
+    assert!(OperationRecorder::is_recording());
+
+    let result = sum ^ product;
+    let outer_graph = outer.finish();
+    assert!(!OperationRecorder::is_recording());
+
+    let operations = |graph: &crate::high_level_api::ComputationGraph| {
+        graph
+            .nodes
+            .iter()
+            .map(|node| node.operation.clone())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(operations(&inner_graph), ["input", "input", "mul"]);
+    // The product was not recorded by the outer recorder, it is an input of the xor
+    assert_eq!(
+        operations(&outer_graph),
+        ["input", "input", "add", "input", "bitxor"]
+    );
+    assert_eq!(outer_graph.nodes[4].inputs, [2, 3]);
+
+    let decrypted: u8 = result.decrypt(&client_key);
+    assert_eq!(decrypted, 27u8.wrapping_add(100) ^ 27u8.wrapping_mul(100));
+}
+
+#[cfg(feature = "integer")]
+#[test]
+fn test_clear_hoisting() {
+    use crate::high_level_api::{set_server_key, ClearHoisting, Config, OperationRecorder};
+
+    let (client_key, server_key) = generate_keys(Config::simulator());
+    set_server_key(server_key);
+
+    let a = FheUint8::encrypt(27u8, &client_key);
+    let b = FheUint8::encrypt_trivial(100u8);
+    let c = FheUint8::encrypt_trivial(7u8);
+
+    let recorder = OperationRecorder::start();
+    let hoisting = ClearHoisting::start();
+    assert!(ClearHoisting::is_active());
+    let mut clear = &b * &c;
+    clear -= 3u8;
+    let clear = !(clear ^ &c);
+    let clear_neg = -&clear;
+    // The boundary with the encrypted value
+    let result = &a + &clear_neg;
+    // Not evaluated natively
+    let quotient = &b / &c;
+    let report = hoisting.finish();
+    let graph = recorder.finish();
+    assert!(!ClearHoisting::is_active());
+
+    assert_eq!(report.hoisted_operation_count(), 5);
+    for operation in ["mul", "scalar_sub", "bitxor", "bitnot", "neg"] {
+        assert_eq!(report.hoisted_operations[operation], 1);
+    }
+    assert_eq!(report.hoisted_blocks, 5 * 4);
+    assert_eq!(report.boundary_inputs, 1);
+    assert_eq!(report.unsupported_operations["div"], 1);
+
+    for node in graph.nodes.iter() {
+        if report.hoisted_operations.contains_key(&node.operation) {
+            assert_eq!(node.pbs_count, 0);
+        }
+    }
+
+    let expected_clear = !((100u8.wrapping_mul(7) - 3) ^ 7);
+    let decrypted: u8 = clear.decrypt(&client_key);
+    assert_eq!(decrypted, expected_clear);
+    let decrypted: u8 = result.decrypt(&client_key);
+    assert_eq!(decrypted, 27u8.wrapping_sub(expected_clear));
+    let decrypted: u8 = quotient.decrypt(&client_key);
+    assert_eq!(decrypted, 100 / 7);
+
+    // Without hoisting, the same operations are evaluated homomorphically
+    let product = &b * &c;
+    let decrypted: u8 = product.decrypt(&client_key);
+    assert_eq!(decrypted, 100u8.wrapping_mul(7));
+
+    // A finished inner hoisting gives back the outer one
+    let outer = ClearHoisting::start();
+    let inner = ClearHoisting::start();
+    let _ = &b * &c;
+    let inner_report = inner.finish();
+    assert!(ClearHoisting::is_active());
+    let _ = &b + &c;
+    let outer_report = outer.finish();
+    assert!(!ClearHoisting::is_active());
+    assert_eq!(inner_report.hoisted_operations["mul"], 1);
+    assert_eq!(outer_report.hoisted_operation_count(), 1);
+    assert_eq!(outer_report.hoisted_operations["add"], 1);
+}
+
+#[cfg(feature = "integer")]
+#[test]
+fn test_provenance() {
+    use crate::high_level_api::{set_server_key, Config, ProvenanceChain};
+
+    let (client_key, server_key) = generate_keys(Config::simulator());
+    set_server_key(server_key);
+
+    let mut a = FheUint8::encrypt(27u8, &client_key);
+    let b = FheUint8::encrypt(100u8, &client_key);
+    a.enable_provenance();
+    assert_eq!(a.provenance(), Some(ProvenanceChain::default()));
+
+    let sum = &a + &b;
+    let unrelated = &b * &b;
+    let mut result = &sum ^ &b;
+    result += 1u8;
+    assert!(b.provenance().is_none());
+    assert!(unrelated.provenance().is_none());
+
+    let chain = result.provenance().unwrap();
+    assert_eq!(chain.records.len(), 3);
+    assert!(chain.verify());
+    assert_eq!(chain.head(), Some(chain.records[2].hash));
+    assert_eq!(chain.records[2].output, result.provenance_hash());
+    assert_eq!(
+        chain.records[1].inputs,
+        [sum.provenance_hash(), b.provenance_hash()]
+    );
+    assert_eq!(chain.records[1].previous, [chain.records[0].hash]);
+
+    let derivation = chain
+        .derivation_of(&result.provenance_hash())
+        .into_iter()
+        .map(|record| record.operation.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(derivation, ["add", "bitxor", "scalar_add_assign"]);
+    assert!(chain.derivation_of(&a.provenance_hash()).is_empty());
+
+    // The records shared by the inputs are only exported once
+    let doubled = &a * 2u8;
+    let merged = &doubled + &sum;
+    let chain = merged.provenance().unwrap();
+    assert!(chain.verify());
+    let operations = chain
+        .records
+        .iter()
+        .map(|record| record.operation.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(operations, ["scalar_mul", "add", "add"]);
+    assert_eq!(
+        chain.records[2].previous,
+        [chain.records[0].hash, chain.records[1].hash]
+    );
+
+    // The provenance is not serialized with the ciphertext
+    let serialized = bincode::serialize(&merged).unwrap();
+    let deserialized: FheUint8 = bincode::deserialize(&serialized).unwrap();
+    assert!(deserialized.provenance().is_none());
+
+    // Any change breaks the chain
+    let mut tampered = chain.clone();
+    tampered.records[1].operation = "sub".to_owned();
+    assert!(!tampered.verify());
+    let mut tampered = chain.clone();
+    tampered.records.remove(1);
+    assert!(!tampered.verify());
+
+    let decrypted: u8 = result.decrypt(&client_key);
+    assert_eq!(decrypted, (27u8.wrapping_add(100) ^ 100) + 1);
+    let decrypted: u8 = merged.decrypt(&client_key);
+    assert_eq!(decrypted, 27u8.wrapping_mul(2).wrapping_add(127));
+}
@@ -0,0 +1,238 @@
+//! Encrypted points in time and durations.
+//!
+//! [FheTimestamp] and [FheDuration] are backed by a [FheUint64], counting a unit chosen by the
+//! application, e.g. seconds since the Unix epoch for timestamps and seconds for durations.
+//! The types only allow the operations that make sense between points in time and durations:
+//!
+//! - `FheTimestamp + FheDuration -> FheTimestamp`
+//! - `FheTimestamp - FheDuration -> FheTimestamp`
+//! - `FheTimestamp - FheTimestamp -> FheDuration`
+//! - `FheDuration ± FheDuration -> FheDuration`
+//!
+//! Clear `u64` durations can be used in place of the encrypted ones.
+//!
+//! As for the integers, arithmetic wraps around on overflow, and comparisons return a
+//! [FheUint64] encrypting 1 if the comparison holds, 0 otherwise.
+
+use serde::{Deserialize, Serialize};
+use std::ops::{Add, Sub};
+
+use crate::high_level_api::traits::{FheDecrypt, FheEq, FheOrd, FheTryEncrypt};
+use crate::high_level_api::{ClientKey, FheUint64};
+
+/// An encrypted point in time.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheDuration, FheTimestamp};
+///
+/// let config = ConfigBuilder::all_disabled()
+///     .enable_default_integers()
+///     .build();
+/// let (client_key, server_key) = generate_keys(config);
+/// set_server_key(server_key);
+///
+/// let issued_at = FheTimestamp::encrypt(1_700_000_000u64, &client_key);
+/// let validity = FheDuration::encrypt(3600u64, &client_key);
+/// let now = FheTimestamp::encrypt(1_700_001_000u64, &client_key);
+///
+/// let expires_at = &issued_at + &validity;
+/// let is_valid = now.is_within_window(&issued_at, &expires_at);
+/// let decrypted: u64 = is_valid.decrypt(&client_key);
+/// assert_eq!(decrypted, 1);
+///
+/// let elapsed: FheDuration = &now - &issued_at;
+/// let decrypted: u64 = elapsed.decrypt(&client_key);
+/// assert_eq!(decrypted, 1000);
+/// ```
+#[derive(Clone, Serialize, Deserialize)]
+pub struct FheTimestamp(FheUint64);
+
+/// An encrypted duration, the difference between two [FheTimestamp].
+#[derive(Clone, Serialize, Deserialize)]
+pub struct FheDuration(FheUint64);
+
+macro_rules! time_impl_common (
+    ($name:ident) => {
+        impl $name {
+            pub fn from_inner(inner: FheUint64) -> Self {
+                Self(inner)
+            }
+
+            pub fn as_inner(&self) -> &FheUint64 {
+                &self.0
+            }
+
+            pub fn into_inner(self) -> FheUint64 {
+                self.0
+            }
+        }
+
+        impl<Key> FheTryEncrypt<u64, Key> for $name
+        where
+            FheUint64: FheTryEncrypt<u64, Key>,
+        {
+            type Error = <FheUint64 as FheTryEncrypt<u64, Key>>::Error;
+
+            fn try_encrypt(value: u64, key: &Key) -> Result<Self, Self::Error> {
+                <FheUint64 as FheTryEncrypt<u64, Key>>::try_encrypt(value, key).map(Self)
+            }
+        }
+
+        impl FheDecrypt<u64> for $name {
+            fn decrypt(&self, key: &ClientKey) -> u64 {
+                self.0.decrypt(key)
+            }
+        }
+
+        impl FheEq<&Self> for $name {
+            type Output = FheUint64;
+
+            fn eq(&self, other: &Self) -> Self::Output {
+                self.0.eq(&other.0)
+            }
+
+            fn ne(&self, other: &Self) -> Self::Output {
+                self.0.ne(&other.0)
+            }
+        }
+
+        impl FheEq<u64> for $name {
+            type Output = FheUint64;
+
+            fn eq(&self, other: u64) -> Self::Output {
+                self.0.eq(other)
+            }
+
+            fn ne(&self, other: u64) -> Self::Output {
+                self.0.ne(other)
+            }
+        }
+
+        impl FheOrd<&Self> for $name {
+            type Output = FheUint64;
+
+            fn lt(&self, other: &Self) -> Self::Output {
+                self.0.lt(&other.0)
+            }
+
+            fn le(&self, other: &Self) -> Self::Output {
+                self.0.le(&other.0)
+            }
+
+            fn gt(&self, other: &Self) -> Self::Output {
+                self.0.gt(&other.0)
+            }
+
+            fn ge(&self, other: &Self) -> Self::Output {
+                self.0.ge(&other.0)
+            }
+        }
+
+        impl FheOrd<u64> for $name {
+            type Output = FheUint64;
+
+            fn lt(&self, other: u64) -> Self::Output {
+                self.0.lt(other)
+            }
+
+            fn le(&self, other: u64) -> Self::Output {
+                self.0.le(other)
+            }
+
+            fn gt(&self, other: u64) -> Self::Output {
+                self.0.gt(other)
+            }
+
+            fn ge(&self, other: u64) -> Self::Output {
+                self.0.ge(other)
+            }
+        }
+    }
+);
+
+time_impl_common!(FheTimestamp);
+time_impl_common!(FheDuration);
+
+macro_rules! time_impl_operation (
+    ($rust_trait_name:ident($rust_trait_method:ident): $lhs:ident, $rhs:ident => $output:ident) => {
+        impl $rust_trait_name<$rhs> for $lhs {
+            type Output = $output;
+
+            fn $rust_trait_method(self, rhs: $rhs) -> Self::Output {
+                $output(self.0.$rust_trait_method(&rhs.0))
+            }
+        }
+
+        impl $rust_trait_name<&$rhs> for $lhs {
+            type Output = $output;
+
+            fn $rust_trait_method(self, rhs: &$rhs) -> Self::Output {
+                $output(self.0.$rust_trait_method(&rhs.0))
+            }
+        }
+
+        impl $rust_trait_name<&$rhs> for &$lhs {
+            type Output = $output;
+
+            fn $rust_trait_method(self, rhs: &$rhs) -> Self::Output {
+                $output((&self.0).$rust_trait_method(&rhs.0))
+            }
+        }
+    }
+);
+
+time_impl_operation!(Add(add): FheTimestamp, FheDuration => FheTimestamp);
+time_impl_operation!(Sub(sub): FheTimestamp, FheDuration => FheTimestamp);
+time_impl_operation!(Sub(sub): FheTimestamp, FheTimestamp => FheDuration);
+time_impl_operation!(Add(add): FheDuration, FheDuration => FheDuration);
+time_impl_operation!(Sub(sub): FheDuration, FheDuration => FheDuration);
+
+// Clear durations
+macro_rules! time_impl_scalar_operation (
+    ($rust_trait_name:ident($rust_trait_method:ident): $lhs:ident) => {
+        impl $rust_trait_name<u64> for $lhs {
+            type Output = $lhs;
+
+            fn $rust_trait_method(self, rhs: u64) -> Self::Output {
+                $lhs(self.0.$rust_trait_method(rhs))
+            }
+        }
+
+        impl $rust_trait_name<u64> for &$lhs {
+            type Output = $lhs;
+
+            fn $rust_trait_method(self, rhs: u64) -> Self::Output {
+                $lhs((&self.0).$rust_trait_method(rhs))
+            }
+        }
+    }
+);
+
+time_impl_scalar_operation!(Add(add): FheTimestamp);
+time_impl_scalar_operation!(Sub(sub): FheTimestamp);
+time_impl_scalar_operation!(Add(add): FheDuration);
+time_impl_scalar_operation!(Sub(sub): FheDuration);
+
+impl FheTimestamp {
+    /// Returns an encryption of 1 if `start <= self <= end`, 0 otherwise.
+    ///
+    /// Instead of two comparisons and a bitand, this uses the fact that, with wrapping
+    /// arithmetic, `start <= self <= end` is equivalent to `self - start <= end - start`.
+    ///
+    /// If `end < start` the window wraps around, i.e. the result is 1 if `self >= start` or
+    /// `self <= end`.
+    pub fn is_within_window(&self, start: &Self, end: &Self) -> FheUint64 {
+        let offset = &self.0 - &start.0;
+        let window_length = &end.0 - &start.0;
+        offset.le(&window_length)
+    }
+
+    /// Same as [Self::is_within_window] with clear bounds.
+    pub fn is_within_clear_window(&self, start: u64, end: u64) -> FheUint64 {
+        let offset = &self.0 - start;
+        offset.le(end.wrapping_sub(start))
+    }
+}
@@ -111,6 +111,17 @@ pub trait FheOrd<Rhs = Self> {
     fn ge(&self, other: Rhs) -> Self::Output;
 }
 
+/// Trait for fully homomorphic three-way comparisons.
+///
+/// The comparison circuit is run once, and its encrypted result can then be converted to the
+/// result of any of the comparisons of [FheOrd] and [FheEq] for a single PBS, so that code
+/// needing e.g. both "less" and "equal" does not run two full comparisons.
+pub trait FheCmp<Rhs = Self> {
+    type Output;
+
+    fn cmp(&self, other: Rhs) -> Self::Output;
+}
+
 pub trait FheMin<Rhs = Self> {
     type Output;
 
@@ -170,3 +181,25 @@ pub trait DivRem<Rhs = Self> {
 
     fn div_rem(self, amount: Rhs) -> Self::Output;
 }
+
+/// Division and remainder that also returns whether the divisor was zero.
+///
+/// When the divisor is zero, the quotient is 0 and the remainder is the numerator.
+pub trait CheckedDivRem<Rhs = Self> {
+    type Output;
+
+    fn checked_div_rem(self, amount: Rhs) -> Self::Output;
+}
+
+/// Additions and multiplications computed in a wider type, so that they cannot overflow.
+///
+/// The operands are extended, with their sign for signed types, to the [Output](Self::Output)
+/// type before the operation. The output type is large enough to hold the exact result of both
+/// operations.
+pub trait WideningOps<Rhs = Self> {
+    type Output;
+
+    fn widening_add(&self, rhs: &Rhs) -> Self::Output;
+
+    fn widening_mul(&self, rhs: &Rhs) -> Self::Output;
+}
@@ -0,0 +1,272 @@
+//! Parameter sets as part of the types.
+//!
+//! The types of this module carry the parameter set of their keys as a type parameter, e.g.
+//! `typed::FheUint32<ParamMessage2Carry2>`. Ciphertexts encrypted under different parameter sets
+//! have different types, so mixing them is a compile time error instead of a wrong result.
+//!
+//! The parameter set is a marker type implementing [ParameterSet], applications can define their
+//! own markers for custom parameters.
+//!
+//! # Note
+//!
+//! The server key is still the one set with [TypedServerKey::set], which is not checked against
+//! the type parameter of the ciphertexts. An application juggling several configurations should
+//! set the server key matching the parameter set of the values it computes on.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::typed::{FheUint32, ParamMessage2Carry2, TypedClientKey};
+//!
+//! let client_key = TypedClientKey::<ParamMessage2Carry2>::generate();
+//! client_key.generate_server_key().set();
+//!
+//! let a = FheUint32::encrypt(20u32, &client_key);
+//! let b = FheUint32::encrypt(22u32, &client_key);
+//!
+//! let c = &a + &b;
+//! let decrypted: u32 = c.decrypt(&client_key);
+//! assert_eq!(decrypted, 42);
+//! ```
+//!
+//! ```compile_fail
+//! use tfhe::typed::{FheUint32, ParamMessage2Carry2, ParamMessage2Carry2PbsKs, TypedClientKey};
+//!
+//! let client_key = TypedClientKey::<ParamMessage2Carry2>::generate();
+//! let other_client_key = TypedClientKey::<ParamMessage2Carry2PbsKs>::generate();
+//!
+//! let a = FheUint32::encrypt(20u32, &client_key);
+//! let b = FheUint32::encrypt(22u32, &other_client_key);
+//!
+//! // Ciphertexts of different parameter sets cannot be added
+//! let c = a + b;
+//! ```
+
+use std::marker::PhantomData;
+use std::ops::{Add, BitAnd, BitOr, BitXor, Mul, Sub};
+
+use serde::{Deserialize, Serialize};
+
+use crate::high_level_api::config::ConfigBuilder;
+use crate::high_level_api::keys::{ClientKey, ServerKey};
+use crate::high_level_api::traits::{FheDecrypt, FheEncrypt, FheEq, FheOrd};
+
+/// A parameter set usable as a type parameter of the types of this module.
+pub trait ParameterSet: Copy + Send + Sync + 'static {
+    /// The parameters of the blocks of the integers
+    ///
+    /// The high level API only supports parameters with 2 bits of message.
+    fn block_parameters() -> crate::shortint::PBSParameters;
+}
+
+/// [PARAM_MESSAGE_2_CARRY_2_KS_PBS](crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS),
+/// the parameters of [ConfigBuilder::enable_default_integers].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ParamMessage2Carry2;
+
+impl ParameterSet for ParamMessage2Carry2 {
+    fn block_parameters() -> crate::shortint::PBSParameters {
+        crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS.into()
+    }
+}
+
+/// [PARAM_MESSAGE_2_CARRY_2_PBS_KS](crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_PBS_KS),
+/// the parameters of [ConfigBuilder::enable_default_integers_small].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ParamMessage2Carry2PbsKs;
+
+impl ParameterSet for ParamMessage2Carry2PbsKs {
+    fn block_parameters() -> crate::shortint::PBSParameters {
+        crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_PBS_KS.into()
+    }
+}
+
+/// A [ClientKey] generated for the parameter set `M`.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct TypedClientKey<M> {
+    key: ClientKey,
+    _marker: PhantomData<M>,
+}
+
+impl<M: ParameterSet> TypedClientKey<M> {
+    pub fn generate() -> Self {
+        let config = ConfigBuilder::all_disabled()
+            .enable_custom_integers(M::block_parameters(), None)
+            .build();
+
+        Self {
+            key: ClientKey::generate(config),
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn generate_server_key(&self) -> TypedServerKey<M> {
+        TypedServerKey {
+            key: self.key.generate_server_key(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<M> TypedClientKey<M> {
+    pub fn as_inner(&self) -> &ClientKey {
+        &self.key
+    }
+
+    pub fn into_inner(self) -> ClientKey {
+        self.key
+    }
+}
+
+/// A [ServerKey] generated for the parameter set `M`.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct TypedServerKey<M> {
+    key: ServerKey,
+    _marker: PhantomData<M>,
+}
+
+impl<M> TypedServerKey<M> {
+    /// Sets the key as the server key of the current thread, see
+    /// [set_server_key](crate::high_level_api::set_server_key)
+    pub fn set(self) {
+        crate::high_level_api::set_server_key(self.key);
+    }
+
+    pub fn into_inner(self) -> ServerKey {
+        self.key
+    }
+}
+
+/// A value of the FHE type `T` encrypted under the parameter set `M`.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))]
+pub struct Typed<T, M> {
+    inner: T,
+    _marker: PhantomData<M>,
+}
+
+impl<T, M> Typed<T, M> {
+    fn new(inner: T) -> Self {
+        Self {
+            inner,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn encrypt<Clear>(value: Clear, key: &TypedClientKey<M>) -> Self
+    where
+        T: FheEncrypt<Clear, ClientKey>,
+    {
+        Self::new(T::encrypt(value, &key.key))
+    }
+
+    pub fn decrypt<Clear>(&self, key: &TypedClientKey<M>) -> Clear
+    where
+        T: FheDecrypt<Clear>,
+    {
+        self.inner.decrypt(&key.key)
+    }
+
+    pub fn as_inner(&self) -> &T {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+macro_rules! typed_impl_operation (
+    ($rust_trait_name:ident($rust_trait_method:ident)) => {
+        impl<T, M> $rust_trait_name<Typed<T, M>> for Typed<T, M>
+        where
+            T: for<'a> $rust_trait_name<&'a T, Output = T>,
+        {
+            type Output = Self;
+
+            fn $rust_trait_method(self, rhs: Typed<T, M>) -> Self::Output {
+                Typed::new(self.inner.$rust_trait_method(&rhs.inner))
+            }
+        }
+
+        impl<'a, T, M> $rust_trait_name<&'a Typed<T, M>> for Typed<T, M>
+        where
+            T: $rust_trait_name<&'a T, Output = T>,
+        {
+            type Output = Self;
+
+            fn $rust_trait_method(self, rhs: &'a Typed<T, M>) -> Self::Output {
+                Typed::new(self.inner.$rust_trait_method(&rhs.inner))
+            }
+        }
+
+        impl<'a, 'b, T, M> $rust_trait_name<&'b Typed<T, M>> for &'a Typed<T, M>
+        where
+            &'a T: $rust_trait_name<&'b T, Output = T>,
+        {
+            type Output = Typed<T, M>;
+
+            fn $rust_trait_method(self, rhs: &'b Typed<T, M>) -> Self::Output {
+                Typed::new((&self.inner).$rust_trait_method(&rhs.inner))
+            }
+        }
+    }
+);
+
+typed_impl_operation!(Add(add));
+typed_impl_operation!(Sub(sub));
+typed_impl_operation!(Mul(mul));
+typed_impl_operation!(BitAnd(bitand));
+typed_impl_operation!(BitOr(bitor));
+typed_impl_operation!(BitXor(bitxor));
+
+impl<'a, T, M> FheEq<&'a Typed<T, M>> for Typed<T, M>
+where
+    T: FheEq<&'a T, Output = T>,
+{
+    type Output = Self;
+
+    fn eq(&self, other: &'a Typed<T, M>) -> Self::Output {
+        Typed::new(self.inner.eq(&other.inner))
+    }
+
+    fn ne(&self, other: &'a Typed<T, M>) -> Self::Output {
+        Typed::new(self.inner.ne(&other.inner))
+    }
+}
+
+impl<'a, T, M> FheOrd<&'a Typed<T, M>> for Typed<T, M>
+where
+    T: FheOrd<&'a T, Output = T>,
+{
+    type Output = Self;
+
+    fn lt(&self, other: &'a Typed<T, M>) -> Self::Output {
+        Typed::new(self.inner.lt(&other.inner))
+    }
+
+    fn le(&self, other: &'a Typed<T, M>) -> Self::Output {
+        Typed::new(self.inner.le(&other.inner))
+    }
+
+    fn gt(&self, other: &'a Typed<T, M>) -> Self::Output {
+        Typed::new(self.inner.gt(&other.inner))
+    }
+
+    fn ge(&self, other: &'a Typed<T, M>) -> Self::Output {
+        Typed::new(self.inner.ge(&other.inner))
+    }
+}
+
+pub type FheUint8<M> = Typed<crate::high_level_api::FheUint8, M>;
+pub type FheUint16<M> = Typed<crate::high_level_api::FheUint16, M>;
+pub type FheUint32<M> = Typed<crate::high_level_api::FheUint32, M>;
+pub type FheUint64<M> = Typed<crate::high_level_api::FheUint64, M>;
+pub type FheUint128<M> = Typed<crate::high_level_api::FheUint128, M>;
+pub type FheInt8<M> = Typed<crate::high_level_api::FheInt8, M>;
+pub type FheInt16<M> = Typed<crate::high_level_api::FheInt16, M>;
+pub type FheInt32<M> = Typed<crate::high_level_api::FheInt32, M>;
+pub type FheInt64<M> = Typed<crate::high_level_api::FheInt64, M>;
+pub type FheInt128<M> = Typed<crate::high_level_api::FheInt128, M>;
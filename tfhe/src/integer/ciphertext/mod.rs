@@ -3,8 +3,15 @@ use super::parameters::{
     RadixCiphertextConformanceParams, RadixCompactCiphertextListConformanceParams,
 };
 use crate::conformance::ParameterSetConformant;
+use crate::content_hash::{ContentHash, ContentHasher};
+use crate::integer::key_switching_key::KeySwitchingKey;
+use crate::integer::ServerKey;
+pub use crate::shortint::ciphertext::{CompactListError, ExpandError};
+use crate::shortint::parameters::CiphertextListConformanceParams;
 use crate::shortint::{Ciphertext, CompressedCiphertext};
 use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+use std::ops::Range;
 
 /// Structure containing a ciphertext in radix decomposition
 /// holding an unsigned value.
@@ -23,6 +30,13 @@ impl<Block> From<Vec<Block>> for BaseRadixCiphertext<Block> {
 // Type alias to save some typing in implementation parts
 pub type RadixCiphertext = BaseRadixCiphertext<Ciphertext>;
 
+impl ContentHash for RadixCiphertext {
+    fn hash_content(&self, hasher: &mut ContentHasher) {
+        hasher.write_str("integer::RadixCiphertext");
+        self.blocks.hash_content(hasher);
+    }
+}
+
 impl ParameterSetConformant for RadixCiphertext {
     type ParameterSet = RadixCiphertextConformanceParams;
 
@@ -94,7 +108,293 @@ impl CompactCiphertextList {
     }
 
     pub fn expand<T: IntegerRadixCiphertext>(&self) -> Vec<T> {
-        let mut all_block_iter = self.ct_list.expand().into_iter();
+        self.group_blocks(self.ct_list.expand())
+    }
+
+    /// Expand the list, applying the operations configured in `config` to each expanded
+    /// block in a single pass.
+    ///
+    /// See [`crate::shortint::ciphertext::CompactCiphertextList::expand_with`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::conformance::ListSizeConstraint;
+    /// use tfhe::integer::ciphertext::{ExpandConfig, RadixCiphertext};
+    /// use tfhe::integer::parameters::RadixCiphertextConformanceParams;
+    /// use tfhe::integer::public_key::CompactPublicKey;
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS);
+    /// let sks = ServerKey::new(&cks);
+    /// let pk = CompactPublicKey::new(&cks);
+    ///
+    /// let msgs = vec![17u64, 213];
+    /// let list = pk.encrypt_slice_radix_compact(&msgs, num_blocks);
+    ///
+    /// let conformance_params = RadixCiphertextConformanceParams::from_pbs_parameters(
+    ///     PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS,
+    ///     num_blocks,
+    /// )
+    /// .to_ct_list_conformance_parameters(ListSizeConstraint::exact_size(msgs.len()));
+    ///
+    /// let config = ExpandConfig::new()
+    ///     .with_conformance_params(&conformance_params)
+    ///     .with_sanitizing_key(&sks);
+    ///
+    /// let expanded: Vec<RadixCiphertext> = list.expand_with(config).unwrap();
+    /// for (ct, msg) in expanded.iter().zip(msgs.iter()) {
+    ///     let dec: u64 = cks.decrypt_radix(ct);
+    ///     assert_eq!(dec, *msg);
+    /// }
+    /// ```
+    pub fn expand_with<T: IntegerRadixCiphertext>(
+        &self,
+        config: ExpandConfig<'_>,
+    ) -> Result<Vec<T>, ExpandError> {
+        let shortint_conformance_params = self.shortint_conformance_params(&config)?;
+
+        let blocks = self
+            .ct_list
+            .expand_with(config.shortint_config(shortint_conformance_params.as_ref()))?;
+        Ok(self.group_blocks(blocks))
+    }
+
+    /// Expand a list of booleans, as encrypted by
+    /// [`crate::integer::public_key::CompactPublicKey::encrypt_slice_bool_compact`].
+    ///
+    /// The sanitizing key of `config` is required, it is used to map each block to a block
+    /// encrypting 0 or 1 with a degree of 1, so that a malicious client cannot submit a value
+    /// outside of the boolean range.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::ciphertext::ExpandConfig;
+    /// use tfhe::integer::public_key::CompactPublicKey;
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::ciphertext::Degree;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS);
+    /// let sks = ServerKey::new(&cks);
+    /// let pk = CompactPublicKey::new(&cks);
+    ///
+    /// let msgs = vec![true, false, true];
+    /// let list = pk.encrypt_slice_bool_compact(&msgs);
+    ///
+    /// let config = ExpandConfig::new().with_sanitizing_key(&sks);
+    /// let expanded = list.expand_booleans_with(config).unwrap();
+    /// for (ct, msg) in expanded.iter().zip(msgs.iter()) {
+    ///     assert_eq!(ct.degree, Degree(1));
+    ///     assert_eq!(cks.decrypt_one_block(ct), u64::from(*msg));
+    /// }
+    /// ```
+    pub fn expand_booleans_with(
+        &self,
+        config: ExpandConfig<'_>,
+    ) -> Result<Vec<Ciphertext>, ExpandError> {
+        if self.num_blocks_per_integer != 1 {
+            return Err(ExpandError::NonConformantList);
+        }
+
+        let shortint_conformance_params = self.shortint_conformance_params(&config)?;
+
+        self.ct_list
+            .expand_booleans_with(config.shortint_config(shortint_conformance_params.as_ref()))
+    }
+
+    /// Checks the integer part of the conformance and returns the parameters to check the
+    /// blocks with, so that they are checked while being expanded
+    fn shortint_conformance_params(
+        &self,
+        config: &ExpandConfig<'_>,
+    ) -> Result<Option<CiphertextListConformanceParams>, ExpandError> {
+        match config.conformance_params {
+            Some(params) if params.num_blocks_per_integer != self.num_blocks_per_integer => {
+                Err(ExpandError::NonConformantList)
+            }
+            Some(params) => Ok(Some(params.to_shortint_ct_list_conformance_parameters())),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns an iterator expanding the integers of the list one at a time.
+    ///
+    /// See [`crate::shortint::ciphertext::CompactCiphertextList::iter_expand`], only the blocks
+    /// of the integer being yielded are in memory.
+    pub fn iter_expand<T: IntegerRadixCiphertext>(
+        &self,
+    ) -> CompactCiphertextListExpandIter<'_, '_, T> {
+        CompactCiphertextListExpandIter {
+            blocks: self.ct_list.iter_expand(),
+            num_blocks_per_integer: self.num_blocks_per_integer,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns an iterator expanding the integers of the list one at a time, applying the
+    /// operations configured in `config` to each block, see [`Self::expand_with`] and
+    /// [`Self::iter_expand`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::ciphertext::{ExpandConfig, RadixCiphertext};
+    /// use tfhe::integer::public_key::CompactPublicKey;
+    /// use tfhe::integer::{ClientKey, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS);
+    /// let sks = ServerKey::new(&cks);
+    /// let pk = CompactPublicKey::new(&cks);
+    ///
+    /// let msgs = vec![17u64, 213, 42];
+    /// let list = pk.encrypt_slice_radix_compact(&msgs, num_blocks);
+    ///
+    /// let config = ExpandConfig::new().with_sanitizing_key(&sks);
+    /// let mut sum = 0u64;
+    /// for ct in list.iter_expand_with::<RadixCiphertext>(config).unwrap() {
+    ///     let dec: u64 = cks.decrypt_radix(&ct);
+    ///     sum += dec;
+    /// }
+    /// assert_eq!(sum, 272);
+    /// ```
+    pub fn iter_expand_with<'list, 'key, T: IntegerRadixCiphertext>(
+        &'list self,
+        config: ExpandConfig<'key>,
+    ) -> Result<CompactCiphertextListExpandIter<'list, 'key, T>, ExpandError> {
+        if let Some(params) = config.conformance_params {
+            if !self.is_conformant(params) {
+                return Err(ExpandError::NonConformantList);
+            }
+        }
+
+        Ok(CompactCiphertextListExpandIter {
+            blocks: self
+                .ct_list
+                .iter_expand_with(config.shortint_config(None))?,
+            num_blocks_per_integer: self.num_blocks_per_integer,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Expands the integers of the list whose indices are in `range`.
+    ///
+    /// See [`crate::shortint::ciphertext::CompactCiphertextList::expand_range`], only the
+    /// blocks of the integers of the range are allocated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of the bounds of the list.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::public_key::CompactPublicKey;
+    /// use tfhe::integer::{ClientKey, RadixCiphertext};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS);
+    /// let pk = CompactPublicKey::new(&cks);
+    ///
+    /// let msgs = vec![17u64, 213, 42];
+    /// let list = pk.encrypt_slice_radix_compact(&msgs, num_blocks);
+    ///
+    /// let expanded: Vec<RadixCiphertext> = list.expand_range(1..3);
+    /// assert_eq!(expanded.len(), 2);
+    /// let dec: u64 = cks.decrypt_radix(&expanded[1]);
+    /// assert_eq!(dec, 42);
+    /// ```
+    pub fn expand_range<T: IntegerRadixCiphertext>(&self, range: Range<usize>) -> Vec<T> {
+        let ciphertext_count = self.ciphertext_count();
+        assert!(
+            range.start <= range.end && range.end <= ciphertext_count,
+            "The range {range:?} is out of the bounds of a list of {ciphertext_count} integers"
+        );
+
+        let mut blocks = self
+            .ct_list
+            .expand_range(
+                range.start * self.num_blocks_per_integer..range.end * self.num_blocks_per_integer,
+            )
+            .into_iter();
+        range
+            .map(|_| T::from(blocks.by_ref().take(self.num_blocks_per_integer).collect()))
+            .collect()
+    }
+
+    /// Splits the list in two without expanding it, the first list contains the integers
+    /// before `index`, the second one the others.
+    ///
+    /// See [`crate::shortint::ciphertext::CompactCiphertextList::split_at`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::public_key::CompactPublicKey;
+    /// use tfhe::integer::{ClientKey, RadixCiphertext};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS);
+    /// let pk = CompactPublicKey::new(&cks);
+    ///
+    /// let msgs = vec![17u64, 213, 42];
+    /// let list = pk.encrypt_slice_radix_compact(&msgs, num_blocks);
+    ///
+    /// let (first, second) = list.split_at(2).unwrap();
+    /// assert_eq!(first.ciphertext_count(), 2);
+    /// assert_eq!(second.ciphertext_count(), 1);
+    ///
+    /// let expanded: Vec<RadixCiphertext> = second.expand();
+    /// let dec: u64 = cks.decrypt_radix(&expanded[0]);
+    /// assert_eq!(dec, 42);
+    /// ```
+    pub fn split_at(&self, index: usize) -> Result<(Self, Self), CompactListError> {
+        if index > self.ciphertext_count() {
+            return Err(CompactListError::IndexOutOfBounds);
+        }
+
+        let (first, second) = self.ct_list.split_at(index * self.num_blocks_per_integer)?;
+        let with_ct_list = |ct_list| Self {
+            ct_list,
+            num_blocks_per_integer: self.num_blocks_per_integer,
+        };
+        Ok((with_ct_list(first), with_ct_list(second)))
+    }
+
+    /// Concatenates lists of integers with the same number of blocks without expanding them.
+    ///
+    /// See [`crate::shortint::ciphertext::CompactCiphertextList::concat`].
+    pub fn concat(lists: &[Self]) -> Result<Self, CompactListError> {
+        let Some(first) = lists.first() else {
+            return Err(CompactListError::EmptyConcat);
+        };
+        let num_blocks_per_integer = first.num_blocks_per_integer;
+        if lists
+            .iter()
+            .any(|list| list.num_blocks_per_integer != num_blocks_per_integer)
+        {
+            return Err(CompactListError::IncompatibleLists);
+        }
+
+        let ct_lists = lists
+            .iter()
+            .map(|list| list.ct_list.clone())
+            .collect::<Vec<_>>();
+        Ok(Self {
+            ct_list: crate::shortint::ciphertext::CompactCiphertextList::concat(&ct_lists)?,
+            num_blocks_per_integer,
+        })
+    }
+
+    fn group_blocks<T: IntegerRadixCiphertext>(&self, blocks: Vec<Ciphertext>) -> Vec<T> {
+        let mut all_block_iter = blocks.into_iter();
         let num_ct = self.ciphertext_count();
         let mut ciphertexts = Vec::with_capacity(num_ct);
 
@@ -122,6 +422,89 @@ impl CompactCiphertextList {
     }
 }
 
+/// Iterator expanding the integers of a [`CompactCiphertextList`] one at a time, see
+/// [`CompactCiphertextList::iter_expand`].
+pub struct CompactCiphertextListExpandIter<'list, 'key, T> {
+    blocks: crate::shortint::ciphertext::CompactCiphertextListExpandIter<'list, 'key>,
+    num_blocks_per_integer: usize,
+    _marker: PhantomData<T>,
+}
+
+impl<'list, 'key, T: IntegerRadixCiphertext> Iterator
+    for CompactCiphertextListExpandIter<'list, 'key, T>
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let blocks = self
+            .blocks
+            .by_ref()
+            .take(self.num_blocks_per_integer)
+            .collect::<Vec<_>>();
+        (blocks.len() == self.num_blocks_per_integer).then(|| T::from(blocks))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.blocks.len() / self.num_blocks_per_integer;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'list, 'key, T: IntegerRadixCiphertext> ExactSizeIterator
+    for CompactCiphertextListExpandIter<'list, 'key, T>
+{
+}
+
+/// Operations to apply to each block when expanding a [`CompactCiphertextList`] with
+/// [`CompactCiphertextList::expand_with`].
+#[derive(Copy, Clone, Default)]
+pub struct ExpandConfig<'key> {
+    pub conformance_params: Option<&'key RadixCompactCiphertextListConformanceParams>,
+    pub casting_key: Option<&'key KeySwitchingKey>,
+    pub sanitizing_key: Option<&'key ServerKey>,
+}
+
+impl<'key> ExpandConfig<'key> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check that the list is conformant to the given parameters before expanding it.
+    pub fn with_conformance_params(
+        mut self,
+        params: &'key RadixCompactCiphertextListConformanceParams,
+    ) -> Self {
+        self.conformance_params = Some(params);
+        self
+    }
+
+    /// Keyswitch each expanded block to the destination parameters of the casting key.
+    pub fn with_casting_key(mut self, casting_key: &'key KeySwitchingKey) -> Self {
+        self.casting_key = Some(casting_key);
+        self
+    }
+
+    /// Apply a PBS to each expanded block, so that it has a canonical noise and degree.
+    pub fn with_sanitizing_key(mut self, sanitizing_key: &'key ServerKey) -> Self {
+        self.sanitizing_key = Some(sanitizing_key);
+        self
+    }
+
+    fn shortint_config<'params>(
+        self,
+        conformance_params: Option<&'params CiphertextListConformanceParams>,
+    ) -> crate::shortint::ciphertext::ExpandConfig<'params>
+    where
+        'key: 'params,
+    {
+        crate::shortint::ciphertext::ExpandConfig {
+            conformance_params,
+            casting_key: self.casting_key.map(|key| &key.key),
+            sanitizing_key: self.sanitizing_key.map(|key| &key.key),
+        }
+    }
+}
+
 impl RadixCiphertext {
     pub fn block_carries_are_empty(&self) -> bool {
         self.blocks.iter().all(|block| block.carry_is_empty())
@@ -145,6 +528,13 @@ impl<Block> From<Vec<Block>> for BaseSignedRadixCiphertext<Block> {
 // Type alias to save some typing in implementation parts
 pub type SignedRadixCiphertext = BaseSignedRadixCiphertext<Ciphertext>;
 
+impl ContentHash for SignedRadixCiphertext {
+    fn hash_content(&self, hasher: &mut ContentHasher) {
+        hasher.write_str("integer::SignedRadixCiphertext");
+        self.blocks.hash_content(hasher);
+    }
+}
+
 impl ParameterSetConformant for SignedRadixCiphertext {
     type ParameterSet = RadixCiphertextConformanceParams;
 
@@ -200,6 +590,12 @@ pub trait IntegerCiphertext: Clone {
             .map(|x| x.message_modulus.0 as u64)
             .collect()
     }
+
+    /// Returns whether all the blocks are trivial encryptions, see
+    /// [Ciphertext::is_trivial](crate::shortint::Ciphertext::is_trivial).
+    fn is_trivial(&self) -> bool {
+        self.blocks().iter().all(|block| block.is_trivial())
+    }
 }
 
 pub trait IntegerRadixCiphertext: IntegerCiphertext + Sync + Send + From<Vec<Ciphertext>> {
@@ -292,6 +688,17 @@ pub struct BaseCrtCiphertext<Block> {
 /// Structure containing a ciphertext in CRT decomposition.
 pub type CrtCiphertext = BaseCrtCiphertext<Ciphertext>;
 
+impl ContentHash for CrtCiphertext {
+    fn hash_content(&self, hasher: &mut ContentHasher) {
+        hasher.write_str("integer::CrtCiphertext");
+        self.blocks.hash_content(hasher);
+        hasher.write_usize(self.moduli.len());
+        for modulus in &self.moduli {
+            hasher.write_u64(*modulus);
+        }
+    }
+}
+
 /// Structure containing a **compressed** ciphertext in CRT decomposition.
 pub type CompressedCrtCiphertext = BaseCrtCiphertext<CompressedCiphertext>;
 
@@ -312,3 +719,42 @@ impl From<CompressedCrtCiphertext> for CrtCiphertext {
         Self::from((blocks, moduli))
     }
 }
+
+/// Structure containing a ciphertext in one-hot encoding holding an unsigned value.
+///
+/// The value is decomposed in digits of base `message_modulus`, and each digit is encrypted by
+/// `message_modulus` blocks: the block at the index of the digit encrypts 1, the others encrypt 0.
+///
+/// This takes `message_modulus` times more blocks than a [RadixCiphertext], but equality
+/// comparisons with a clear value are done without evaluating any lookup table on the blocks,
+/// see [ServerKey::one_hot_scalar_eq_parallelized].
+#[derive(Serialize, Clone, Deserialize, PartialEq, Eq, Debug)]
+pub struct OneHotCiphertext {
+    /// The blocks of the digits, from the least significant digit to the most significant one
+    pub(crate) blocks: Vec<Ciphertext>,
+}
+
+impl OneHotCiphertext {
+    /// Returns the number of digits, i.e. the number of blocks of the equivalent radix ciphertext
+    pub fn num_digits(&self) -> usize {
+        self.blocks
+            .first()
+            .map_or(0, |block| self.blocks.len() / block.message_modulus.0)
+    }
+
+    /// Returns the blocks encrypting each digit, from the least significant one
+    pub(crate) fn digits(&self) -> std::slice::ChunksExact<'_, Ciphertext> {
+        let message_modulus = self
+            .blocks
+            .first()
+            .map_or(1, |block| block.message_modulus.0);
+        self.blocks.chunks_exact(message_modulus)
+    }
+}
+
+impl ContentHash for OneHotCiphertext {
+    fn hash_content(&self, hasher: &mut ContentHasher) {
+        hasher.write_str("integer::OneHotCiphertext");
+        self.blocks.hash_content(hasher);
+    }
+}
@@ -11,11 +11,16 @@ use crate::core_crypto::prelude::{CastFrom, SignedNumeric, UnsignedNumeric};
 use crate::integer::block_decomposition::BlockRecomposer;
 use crate::integer::ciphertext::{CompressedCrtCiphertext, CrtCiphertext};
 use crate::integer::client_key::utils::i_crt;
-use crate::integer::encryption::{encrypt_crt, encrypt_words_radix_impl};
+use crate::integer::encryption::{
+    create_clear_radix_block_iterator, encrypt_crt, encrypt_words_radix_impl,
+};
+use crate::shortint::ciphertext::Degree;
 use crate::shortint::parameters::MessageModulus;
 use crate::shortint::{
-    Ciphertext, ClientKey as ShortintClientKey, ShortintParameterSet as ShortintParameters,
+    BlockNoiseReport, Ciphertext, ClientKey as ShortintClientKey,
+    ShortintParameterSet as ShortintParameters,
 };
+use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 pub use utils::radix_decomposition;
 
@@ -25,8 +30,8 @@ pub use radix::RadixClientKey;
 
 use super::block_decomposition::{DecomposableInto, RecomposableFrom};
 use super::ciphertext::{
-    CompressedRadixCiphertext, CompressedSignedRadixCiphertext, RadixCiphertext,
-    SignedRadixCiphertext,
+    CompressedRadixCiphertext, CompressedSignedRadixCiphertext, IntegerCiphertext,
+    OneHotCiphertext, RadixCiphertext, SignedRadixCiphertext,
 };
 
 pub trait RecomposableSignedInteger:
@@ -103,6 +108,65 @@ impl ClientKey {
         }
     }
 
+    /// Creates a Client Key from its secret keys, see
+    /// [`crate::shortint::ClientKey::from_raw_parts`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parameters are not PBS parameters, or if the secret keys do not match them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::ClientKey;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// let ct = cks.encrypt_radix(200u8, 4);
+    ///
+    /// let (glwe_secret_key, lwe_secret_key, parameters) = cks.into_raw_parts();
+    /// let cks = ClientKey::from_raw_parts(glwe_secret_key, lwe_secret_key, parameters);
+    ///
+    /// let dec: u8 = cks.decrypt_radix(&ct);
+    /// assert_eq!(dec, 200);
+    /// ```
+    #[cfg(feature = "secret-key-raw-parts")]
+    pub fn from_raw_parts<P>(
+        glwe_secret_key: crate::core_crypto::entities::GlweSecretKeyOwned<u64>,
+        lwe_secret_key: crate::core_crypto::entities::LweSecretKeyOwned<u64>,
+        parameter_set: P,
+    ) -> Self
+    where
+        P: TryInto<ShortintParameters>,
+        <P as TryInto<ShortintParameters>>::Error: std::fmt::Debug,
+    {
+        let key = ShortintClientKey::from_raw_parts(glwe_secret_key, lwe_secret_key, parameter_set);
+        assert!(
+            key.parameters.pbs_parameters().is_some(),
+            "Integer client keys require PBS parameters"
+        );
+
+        Self { key }
+    }
+
+    /// Deconstructs a Client Key into its secret keys and its parameters, see
+    /// [`crate::shortint::ClientKey::into_raw_parts`].
+    #[cfg(feature = "secret-key-raw-parts")]
+    pub fn into_raw_parts(
+        self,
+    ) -> (
+        crate::core_crypto::entities::GlweSecretKeyOwned<u64>,
+        crate::core_crypto::entities::LweSecretKeyOwned<u64>,
+        crate::shortint::PBSParameters,
+    ) {
+        let (glwe_secret_key, lwe_secret_key, parameters) = self.key.into_raw_parts();
+        (
+            glwe_secret_key,
+            lwe_secret_key,
+            parameters.pbs_parameters().unwrap(),
+        )
+    }
+
     pub fn parameters(&self) -> crate::shortint::PBSParameters {
         self.key.parameters.pbs_parameters().unwrap()
     }
@@ -282,15 +346,29 @@ impl ClientKey {
         T: RecomposableFrom<u64>,
         F: Fn(&crate::shortint::ClientKey, &crate::shortint::Ciphertext) -> u64,
     {
-        if blocks.is_empty() {
+        self.recompose_radix_impl(
+            blocks
+                .iter()
+                .map(|encrypted_block| decrypt_block(&self.key, encrypted_block)),
+        )
+    }
+
+    /// Recomposes the decrypted blocks of a radix decomposition
+    ///
+    /// The blocks are assumed to be in little endian order.
+    fn recompose_radix_impl<T>(&self, decrypted_blocks: impl Iterator<Item = u64>) -> T
+    where
+        T: RecomposableFrom<u64>,
+    {
+        let mut decrypted_blocks = decrypted_blocks.peekable();
+        if decrypted_blocks.peek().is_none() {
             return T::ZERO;
         }
 
         let bits_in_block = self.key.parameters.message_modulus().0.ilog2();
         let mut recomposer = BlockRecomposer::<T>::new(bits_in_block);
 
-        for encrypted_block in blocks {
-            let decrypted_block = decrypt_block(&self.key, encrypted_block);
+        for decrypted_block in decrypted_blocks {
             if !recomposer.add_unmasked(decrypted_block) {
                 // End of T::BITS reached no need to try more
                 // recomposition
@@ -301,6 +379,137 @@ impl ClientKey {
         recomposer.value()
     }
 
+    /// Decrypts a batch of ciphertexts encrypting radix integers
+    ///
+    /// The ciphertexts, and the blocks within each ciphertext, are decrypted in parallel.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::ClientKey;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// let num_block = 4;
+    ///
+    /// let msgs = [191_u64, 0, 42, 255];
+    ///
+    /// // Encryption
+    /// let cts: Vec<_> = msgs
+    ///     .iter()
+    ///     .map(|msg| cks.encrypt_radix(*msg, num_block))
+    ///     .collect();
+    ///
+    /// // Decryption
+    /// let decs: Vec<u64> = cks.decrypt_radix_batch(&cts);
+    /// assert_eq!(decs, msgs);
+    /// ```
+    pub fn decrypt_radix_batch<T>(&self, ctxts: &[RadixCiphertext]) -> Vec<T>
+    where
+        T: RecomposableFrom<u64> + UnsignedNumeric + Send,
+    {
+        ctxts
+            .par_iter()
+            .map(|ctxt| {
+                let decrypted_blocks = self.key.decrypt_message_and_carry_batch(&ctxt.blocks);
+                self.recompose_radix_impl(decrypted_blocks.into_iter())
+            })
+            .collect()
+    }
+
+    /// Encrypts an integer in one-hot encoding, see [OneHotCiphertext]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::ClientKey;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// // 4 digits of 2 bits, encrypted by 4 * 4 blocks
+    /// let num_digits = 4;
+    ///
+    /// let msg = 167_u64;
+    ///
+    /// let ct = cks.encrypt_one_hot(msg, num_digits);
+    /// assert_eq!(ct.num_digits(), num_digits);
+    ///
+    /// let dec: u64 = cks.decrypt_one_hot(&ct);
+    /// assert_eq!(msg, dec);
+    /// ```
+    pub fn encrypt_one_hot<T>(&self, message: T, num_digits: usize) -> OneHotCiphertext
+    where
+        T: DecomposableInto<u64> + UnsignedNumeric,
+    {
+        let message_modulus = self.key.parameters.message_modulus();
+        let blocks = create_clear_radix_block_iterator(message, message_modulus, num_digits)
+            .flat_map(|digit| {
+                (0..message_modulus.0 as u64).map(move |value| u64::from(value == digit))
+            })
+            .map(|bit| {
+                let mut block = self.key.encrypt(bit);
+                // The blocks only encrypt 0 or 1
+                block.degree = Degree(1);
+                block
+            })
+            .collect();
+
+        OneHotCiphertext { blocks }
+    }
+
+    /// Decrypts a ciphertext encrypting an integer in one-hot encoding
+    ///
+    /// A digit whose blocks do not encrypt a single 1 decrypts to the index of the first block
+    /// encrypting 1, or to 0 if there is none.
+    pub fn decrypt_one_hot<T>(&self, ctxt: &OneHotCiphertext) -> T
+    where
+        T: RecomposableFrom<u64> + UnsignedNumeric,
+    {
+        let digits = ctxt.digits().map(|blocks| {
+            blocks
+                .iter()
+                .position(|block| self.key.decrypt_message_and_carry(block) == 1)
+                .map_or(0, |digit| digit as u64)
+        });
+        self.recompose_radix_impl(digits)
+    }
+
+    /// Measures the noise of each block of a ciphertext, in parallel
+    ///
+    /// The reports are ordered as the blocks, from the least significant one. A block whose
+    /// [margin](BlockNoiseReport::margin_bits) is close to 0 is close to a decryption failure,
+    /// see [ShortintClientKey::analyze_noise].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let ct = cks.encrypt(200u64);
+    /// let sum = sks.unchecked_add(&ct, &ct);
+    ///
+    /// let reports = cks.as_ref().analyze_noise(&sum);
+    /// assert_eq!(reports.len(), num_blocks);
+    ///
+    /// // The first block holds 0 + 0, the last one 3 + 3 with no carry propagation
+    /// assert_eq!((reports[0].message, reports[0].carry), (0, 0));
+    /// assert_eq!((reports[3].message, reports[3].carry), (2, 1));
+    /// assert!(reports.iter().all(|report| report.margin_bits() > 0.0));
+    /// ```
+    pub fn analyze_noise<T>(&self, ctxt: &T) -> Vec<BlockNoiseReport>
+    where
+        T: IntegerCiphertext,
+    {
+        ctxt.blocks()
+            .par_iter()
+            .map(|block| self.key.analyze_noise(block))
+            .collect()
+    }
+
     pub fn encrypt_signed_radix<T>(&self, message: T, num_blocks: usize) -> SignedRadixCiphertext
     where
         T: DecomposableInto<u64> + SignedNumeric,
@@ -372,6 +581,54 @@ impl ClientKey {
     where
         T: RecomposableSignedInteger,
         F: Fn(&crate::shortint::ClientKey, &crate::shortint::Ciphertext) -> u64,
+    {
+        let unpadded_value = self.decrypt_radix_impl(&ctxt.blocks, decrypt_block);
+        self.sign_extend_radix_value(unpadded_value, ctxt.blocks.len())
+    }
+
+    /// Decrypts a batch of ciphertexts encrypting signed radix integers
+    ///
+    /// The ciphertexts, and the blocks within each ciphertext, are decrypted in parallel.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::ClientKey;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// let num_block = 4;
+    ///
+    /// let msgs = [-128_i64, -1, 0, 127];
+    ///
+    /// // Encryption
+    /// let cts: Vec<_> = msgs
+    ///     .iter()
+    ///     .map(|msg| cks.encrypt_signed_radix(*msg, num_block))
+    ///     .collect();
+    ///
+    /// // Decryption
+    /// let decs: Vec<i64> = cks.decrypt_signed_radix_batch(&cts);
+    /// assert_eq!(decs, msgs);
+    /// ```
+    pub fn decrypt_signed_radix_batch<T>(&self, ctxts: &[SignedRadixCiphertext]) -> Vec<T>
+    where
+        T: RecomposableSignedInteger + Send,
+    {
+        ctxts
+            .par_iter()
+            .map(|ctxt| {
+                let decrypted_blocks = self.key.decrypt_message_and_carry_batch(&ctxt.blocks);
+                let unpadded_value = self.recompose_radix_impl(decrypted_blocks.into_iter());
+                self.sign_extend_radix_value(unpadded_value, ctxt.blocks.len())
+            })
+            .collect()
+    }
+
+    /// Extends the sign of a value recomposed from `num_blocks` blocks to all the bits of `T`
+    fn sign_extend_radix_value<T>(&self, unpadded_value: T, num_blocks: usize) -> T
+    where
+        T: RecomposableSignedInteger,
     {
         let message_modulus = self.parameters().message_modulus().0;
         assert!(message_modulus.is_power_of_two());
@@ -380,10 +637,8 @@ impl ClientKey {
         // but, in the signed case,
         // we have to take care of the case when the clear type T has more bits
         // than what the ciphertext encrypts.
-        let unpadded_value = self.decrypt_radix_impl(&ctxt.blocks, decrypt_block);
-
         let num_bits_in_message = message_modulus.ilog2();
-        let num_bits_in_ctxt = num_bits_in_message * ctxt.blocks.len() as u32;
+        let num_bits_in_ctxt = num_bits_in_message * num_blocks as u32;
         if num_bits_in_ctxt >= T::BITS as u32 {
             return unpadded_value;
         }
@@ -596,3 +851,9 @@ impl ClientKey {
         encrypt_crt(&self.key, message, base_vec, encrypt_block)
     }
 }
+
+impl zeroize::Zeroize for ClientKey {
+    fn zeroize(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.key);
+    }
+}
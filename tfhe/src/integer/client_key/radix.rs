@@ -81,6 +81,22 @@ impl RadixClientKey {
         self.key.decrypt_signed_radix(ciphertext)
     }
 
+    /// Decrypts the ciphertexts in parallel, see [ClientKey::decrypt_radix_batch]
+    pub fn decrypt_batch<T>(&self, ciphertexts: &[RadixCiphertext]) -> Vec<T>
+    where
+        T: RecomposableFrom<u64> + UnsignedNumeric + Send,
+    {
+        self.key.decrypt_radix_batch(ciphertexts)
+    }
+
+    /// Decrypts the ciphertexts in parallel, see [ClientKey::decrypt_signed_radix_batch]
+    pub fn decrypt_signed_batch<T>(&self, ciphertexts: &[SignedRadixCiphertext]) -> Vec<T>
+    where
+        T: RecomposableSignedInteger + Send,
+    {
+        self.key.decrypt_signed_radix_batch(ciphertexts)
+    }
+
     /// Returns the parameters used by the client key.
     pub fn parameters(&self) -> ShortintParameters {
         self.key.parameters()
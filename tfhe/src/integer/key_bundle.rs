@@ -0,0 +1,231 @@
+//! This module defines CompressedKeyBundle
+//!
+//! - [CompressedKeyBundle] packs all the public keys of a deployment, in their compressed form, in
+//!   a single serializable artifact.
+
+use crate::integer::key_switching_key::{CompressedKeySwitchingKey, KeySwitchingKey};
+use crate::integer::public_key::CompactPublicKey;
+use crate::integer::{CompressedCompactPublicKey, CompressedServerKey, ServerKey};
+use serde::{Deserialize, Serialize};
+
+/// A bundle of compressed public keys.
+///
+/// The bundle always contains a server key, and may contain a compact public key and casting keys.
+/// All the keys are stored in their seeded form, and each one of them can be decompressed on its
+/// own, so that a server only pays the decompression of the keys it actually uses.
+///
+/// The serialized form of the bundle is versioned.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::integer::{
+///     ClientKey, CompressedCompactPublicKey, CompressedKeyBundle, CompressedServerKey,
+/// };
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS;
+///
+/// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS);
+///
+/// let bundle = CompressedKeyBundle::new(CompressedServerKey::new(&cks))
+///     .with_compact_public_key(CompressedCompactPublicKey::new(&cks));
+///
+/// let serialized = bincode::serialize(&bundle).unwrap();
+/// let bundle: CompressedKeyBundle = bincode::deserialize(&serialized).unwrap();
+///
+/// let sks = bundle.decompress_server_key();
+/// let pk = bundle.decompress_compact_public_key().unwrap();
+///
+/// let ct = pk.encrypt_radix(3u8, 4);
+/// let ct = sks.scalar_add_parallelized(&ct, 2u8);
+/// let dec: u8 = cks.decrypt_radix(&ct);
+/// assert_eq!(dec, 5);
+/// ```
+#[derive(Clone)]
+pub struct CompressedKeyBundle {
+    server_key: CompressedServerKey,
+    compact_public_key: Option<CompressedCompactPublicKey>,
+    casting_keys: Vec<CompressedKeySwitchingKey>,
+}
+
+impl CompressedKeyBundle {
+    pub fn new(server_key: CompressedServerKey) -> Self {
+        Self {
+            server_key,
+            compact_public_key: None,
+            casting_keys: Vec::new(),
+        }
+    }
+
+    /// Adds the compact public key to the bundle, replacing the previous one if any
+    pub fn with_compact_public_key(
+        mut self,
+        compact_public_key: CompressedCompactPublicKey,
+    ) -> Self {
+        self.compact_public_key = Some(compact_public_key);
+        self
+    }
+
+    /// Adds a casting key to the bundle, casting keys are indexed in insertion order
+    pub fn with_casting_key(mut self, casting_key: CompressedKeySwitchingKey) -> Self {
+        self.casting_keys.push(casting_key);
+        self
+    }
+
+    pub fn server_key(&self) -> &CompressedServerKey {
+        &self.server_key
+    }
+
+    pub fn compact_public_key(&self) -> Option<&CompressedCompactPublicKey> {
+        self.compact_public_key.as_ref()
+    }
+
+    pub fn casting_keys(&self) -> &[CompressedKeySwitchingKey] {
+        &self.casting_keys
+    }
+
+    pub fn decompress_server_key(&self) -> ServerKey {
+        ServerKey::from(self.server_key.clone())
+    }
+
+    /// Returns `None` if the bundle does not contain a compact public key
+    pub fn decompress_compact_public_key(&self) -> Option<CompactPublicKey> {
+        self.compact_public_key
+            .clone()
+            .map(CompressedCompactPublicKey::decompress)
+    }
+
+    /// Returns `None` if there is no casting key at this index
+    pub fn decompress_casting_key(&self, index: usize) -> Option<KeySwitchingKey> {
+        self.casting_keys
+            .get(index)
+            .cloned()
+            .map(CompressedKeySwitchingKey::decompress)
+    }
+
+    /// Splits the bundle into its compressed keys
+    pub fn into_raw_parts(
+        self,
+    ) -> (
+        CompressedServerKey,
+        Option<CompressedCompactPublicKey>,
+        Vec<CompressedKeySwitchingKey>,
+    ) {
+        let Self {
+            server_key,
+            compact_public_key,
+            casting_keys,
+        } = self;
+        (server_key, compact_public_key, casting_keys)
+    }
+}
+
+// The bundle is serialized through a versioned enum so that the format can evolve while old
+// bundles stay readable, the borrowed variant avoids cloning keys to serialize them.
+#[derive(Serialize)]
+struct CompressedKeyBundleV0Ref<'a> {
+    server_key: &'a CompressedServerKey,
+    compact_public_key: &'a Option<CompressedCompactPublicKey>,
+    casting_keys: &'a [CompressedKeySwitchingKey],
+}
+
+#[derive(Serialize)]
+enum SerializableCompressedKeyBundle<'a> {
+    V0(CompressedKeyBundleV0Ref<'a>),
+}
+
+#[derive(Deserialize)]
+struct CompressedKeyBundleV0 {
+    server_key: CompressedServerKey,
+    compact_public_key: Option<CompressedCompactPublicKey>,
+    casting_keys: Vec<CompressedKeySwitchingKey>,
+}
+
+#[derive(Deserialize)]
+enum DeserializableCompressedKeyBundle {
+    V0(CompressedKeyBundleV0),
+}
+
+impl Serialize for CompressedKeyBundle {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        SerializableCompressedKeyBundle::V0(CompressedKeyBundleV0Ref {
+            server_key: &self.server_key,
+            compact_public_key: &self.compact_public_key,
+            casting_keys: &self.casting_keys,
+        })
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for CompressedKeyBundle {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        DeserializableCompressedKeyBundle::deserialize(deserializer).map(
+            |versioned| match versioned {
+                DeserializableCompressedKeyBundle::V0(bundle) => Self {
+                    server_key: bundle.server_key,
+                    compact_public_key: bundle.compact_public_key,
+                    casting_keys: bundle.casting_keys,
+                },
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::integer::ClientKey;
+    use crate::shortint::parameters::{
+        ShortintKeySwitchingParameters, PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS,
+    };
+
+    #[test]
+    fn test_compressed_key_bundle_serialization_and_decompression() {
+        let cks_1 = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS);
+        let cks_2 = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS);
+        let compressed_sks_1 = CompressedServerKey::new(&cks_1);
+        let compressed_sks_2 = CompressedServerKey::new(&cks_2);
+
+        let ksk_params = ShortintKeySwitchingParameters::new(
+            cks_2.parameters().ks_base_log(),
+            cks_2.parameters().ks_level(),
+        );
+        let casting_key = CompressedKeySwitchingKey::new(
+            (&cks_1, &compressed_sks_1),
+            (&cks_2, &compressed_sks_2),
+            ksk_params,
+        );
+
+        let bundle = CompressedKeyBundle::new(compressed_sks_2)
+            .with_compact_public_key(CompressedCompactPublicKey::new(&cks_2))
+            .with_casting_key(casting_key);
+
+        let serialized = bincode::serialize(&bundle).unwrap();
+        let bundle: CompressedKeyBundle = bincode::deserialize(&serialized).unwrap();
+
+        assert_eq!(bundle.casting_keys().len(), 1);
+        assert!(bundle.decompress_casting_key(1).is_none());
+
+        let ksk = bundle.decompress_casting_key(0).unwrap();
+        let ct = cks_1.encrypt_radix(200u8, 4);
+        let ct = ksk.cast(&ct);
+        let dec: u8 = cks_2.decrypt_radix(&ct);
+        assert_eq!(dec, 200);
+
+        let sks = bundle.decompress_server_key();
+        let pk = bundle.decompress_compact_public_key().unwrap();
+        let ct = pk.encrypt_radix(17u8, 4);
+        let ct = sks.scalar_add_parallelized(&ct, 25u8);
+        let dec: u8 = cks_2.decrypt_radix(&ct);
+        assert_eq!(dec, 42);
+
+        let bundle = CompressedKeyBundle::new(CompressedServerKey::new(&cks_1));
+        assert!(bundle.decompress_compact_public_key().is_none());
+        assert!(bundle.decompress_casting_key(0).is_none());
+    }
+}
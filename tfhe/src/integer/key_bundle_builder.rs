@@ -0,0 +1,564 @@
+//! This module defines KeyBundleBuilder
+//!
+//! - [KeyBundleBuilder] generates, from a client key, the artifacts needed by each role of a
+//!   deployment. Each role gets a single bundle, serialized in a versioned format:
+//!   - [ClientBundle]: the client key, to encrypt inputs and decrypt results,
+//!   - [GatewayBundle]: the compact public key and the casting keys, to encrypt and forward
+//!     inputs,
+//!   - [ComputeBundle]: the server key, to run the computations,
+//!   - [AuditBundle]: the parameters and the fingerprints of the public keys, to check inputs and
+//!     the keys deployed on the other roles.
+
+use crate::core_crypto::commons::parameters::EncryptionKeyChoice;
+use crate::integer::key_switching_key::{CompressedKeySwitchingKey, KeySwitchingKey};
+use crate::integer::parameters::RadixCiphertextConformanceParams;
+use crate::integer::public_key::CompactPublicKey;
+use crate::integer::{ClientKey, CompressedCompactPublicKey, CompressedServerKey, ServerKey};
+use crate::shortint::PBSParameters;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyBundleError {
+    /// The parameters of the client key do not allow to create a compact public key, the
+    /// dimension of the encryption key must be a power of two
+    CompactPublicKeyNotSupported,
+}
+
+impl std::fmt::Display for KeyBundleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::CompactPublicKeyNotSupported => write!(
+                f,
+                "The parameters do not support compact public keys, \
+                the dimension of the encryption key must be a power of two"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for KeyBundleError {}
+
+/// Generates the bundles of each deployment role from a client key
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::integer::key_bundle_builder::{ComputeBundle, GatewayBundle, KeyBundleBuilder};
+/// use tfhe::integer::ClientKey;
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS;
+///
+/// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS);
+///
+/// let bundles = KeyBundleBuilder::new(cks).with_gateway().build().unwrap();
+///
+/// // Each bundle is sent to its role
+/// let gateway = bincode::serialize(bundles.gateway.as_ref().unwrap()).unwrap();
+/// let compute = bincode::serialize(&bundles.compute).unwrap();
+///
+/// // On the gateway
+/// let gateway: GatewayBundle = bincode::deserialize(&gateway).unwrap();
+/// let pk = gateway.decompress_compact_public_key();
+/// let ct = pk.encrypt_radix(3u8, 4);
+///
+/// // On the compute side
+/// let compute: ComputeBundle = bincode::deserialize(&compute).unwrap();
+/// assert!(bundles.audit.verify_compute_bundle(&compute));
+/// let sks = compute.decompress_server_key();
+/// let ct = sks.scalar_add_parallelized(&ct, 2u8);
+///
+/// // On the client
+/// let dec: u8 = bundles.client.client_key().decrypt_radix(&ct);
+/// assert_eq!(dec, 5);
+/// ```
+pub struct KeyBundleBuilder {
+    client_key: ClientKey,
+    with_gateway: bool,
+    casting_keys: Vec<CompressedKeySwitchingKey>,
+}
+
+impl KeyBundleBuilder {
+    pub fn new(client_key: ClientKey) -> Self {
+        Self {
+            client_key,
+            with_gateway: false,
+            casting_keys: Vec::new(),
+        }
+    }
+
+    /// Also builds a [GatewayBundle], with a compact public key generated from the client key
+    pub fn with_gateway(mut self) -> Self {
+        self.with_gateway = true;
+        self
+    }
+
+    /// Adds a casting key to the [GatewayBundle], casting keys are indexed in insertion order
+    ///
+    /// This implies [with_gateway](Self::with_gateway).
+    pub fn with_casting_key(mut self, casting_key: CompressedKeySwitchingKey) -> Self {
+        self.with_gateway = true;
+        self.casting_keys.push(casting_key);
+        self
+    }
+
+    /// Generates the public keys and assembles the bundles
+    pub fn build(self) -> Result<KeyBundles, KeyBundleError> {
+        let Self {
+            client_key,
+            with_gateway,
+            casting_keys,
+        } = self;
+        let parameters = client_key.parameters();
+
+        let gateway = if with_gateway {
+            let encryption_lwe_dimension = match parameters.encryption_key_choice() {
+                EncryptionKeyChoice::Big => parameters
+                    .glwe_dimension()
+                    .to_equivalent_lwe_dimension(parameters.polynomial_size()),
+                EncryptionKeyChoice::Small => parameters.lwe_dimension(),
+            };
+            if !encryption_lwe_dimension.0.is_power_of_two() {
+                return Err(KeyBundleError::CompactPublicKeyNotSupported);
+            }
+
+            Some(GatewayBundle {
+                compact_public_key: CompressedCompactPublicKey::new(&client_key),
+                casting_keys,
+            })
+        } else {
+            None
+        };
+
+        let compute = ComputeBundle {
+            server_key: CompressedServerKey::new(&client_key),
+        };
+
+        let fingerprints = KeyFingerprints {
+            server_key: compute.server_key_fingerprint(),
+            compact_public_key: gateway
+                .as_ref()
+                .map(GatewayBundle::compact_public_key_fingerprint),
+            casting_keys: gateway
+                .as_ref()
+                .map(GatewayBundle::casting_key_fingerprints)
+                .unwrap_or_default(),
+        };
+
+        Ok(KeyBundles {
+            client: ClientBundle { client_key },
+            gateway,
+            compute,
+            audit: AuditBundle {
+                parameters,
+                fingerprints,
+            },
+        })
+    }
+}
+
+/// The bundles of each deployment role, see [KeyBundleBuilder]
+pub struct KeyBundles {
+    pub client: ClientBundle,
+    /// Only built if requested with [KeyBundleBuilder::with_gateway]
+    pub gateway: Option<GatewayBundle>,
+    pub compute: ComputeBundle,
+    pub audit: AuditBundle,
+}
+
+/// Fingerprint of a key, the SHA-256 digest of its serialized form
+pub type KeyFingerprint = [u8; 32];
+
+fn fingerprint<T: Serialize>(key: &T) -> KeyFingerprint {
+    let serialized = bincode::serialize(key).expect("Failed to serialize the key");
+    Sha256::digest(serialized).into()
+}
+
+/// The bundle of the client, it must be kept secret
+#[derive(Clone)]
+pub struct ClientBundle {
+    client_key: ClientKey,
+}
+
+impl ClientBundle {
+    pub fn client_key(&self) -> &ClientKey {
+        &self.client_key
+    }
+
+    pub fn into_client_key(self) -> ClientKey {
+        self.client_key
+    }
+}
+
+/// The bundle of the gateways, which encrypt inputs and cast them to the computation parameters
+#[derive(Clone)]
+pub struct GatewayBundle {
+    compact_public_key: CompressedCompactPublicKey,
+    casting_keys: Vec<CompressedKeySwitchingKey>,
+}
+
+impl GatewayBundle {
+    pub fn compact_public_key(&self) -> &CompressedCompactPublicKey {
+        &self.compact_public_key
+    }
+
+    pub fn casting_keys(&self) -> &[CompressedKeySwitchingKey] {
+        &self.casting_keys
+    }
+
+    pub fn decompress_compact_public_key(&self) -> CompactPublicKey {
+        self.compact_public_key.clone().decompress()
+    }
+
+    /// Returns `None` if there is no casting key at this index
+    pub fn decompress_casting_key(&self, index: usize) -> Option<KeySwitchingKey> {
+        self.casting_keys
+            .get(index)
+            .cloned()
+            .map(CompressedKeySwitchingKey::decompress)
+    }
+
+    pub(crate) fn compact_public_key_fingerprint(&self) -> KeyFingerprint {
+        fingerprint(&self.compact_public_key)
+    }
+
+    pub(crate) fn casting_key_fingerprints(&self) -> Vec<KeyFingerprint> {
+        self.casting_keys.iter().map(fingerprint).collect()
+    }
+}
+
+/// The bundle of the servers running the computations
+///
+/// There is no GPU backend in this crate, the server key is always a CPU [ServerKey].
+#[derive(Clone)]
+pub struct ComputeBundle {
+    server_key: CompressedServerKey,
+}
+
+impl ComputeBundle {
+    pub fn server_key(&self) -> &CompressedServerKey {
+        &self.server_key
+    }
+
+    pub fn decompress_server_key(&self) -> ServerKey {
+        ServerKey::from(self.server_key.clone())
+    }
+
+    pub(crate) fn server_key_fingerprint(&self) -> KeyFingerprint {
+        fingerprint(&self.server_key)
+    }
+}
+
+/// Fingerprints of the public keys of a deployment
+///
+/// A fingerprint is a cryptographic hash of the serialized key, it identifies a key without
+/// having to store it: another key with the same fingerprint cannot be crafted.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyFingerprints {
+    pub server_key: KeyFingerprint,
+    pub compact_public_key: Option<KeyFingerprint>,
+    pub casting_keys: Vec<KeyFingerprint>,
+}
+
+/// The bundle of the auditors, it does not contain any key
+#[derive(Clone)]
+pub struct AuditBundle {
+    parameters: PBSParameters,
+    fingerprints: KeyFingerprints,
+}
+
+impl AuditBundle {
+    pub fn parameters(&self) -> PBSParameters {
+        self.parameters
+    }
+
+    pub fn fingerprints(&self) -> &KeyFingerprints {
+        &self.fingerprints
+    }
+
+    /// Returns the parameters that the radix ciphertexts of the deployment conform to
+    pub fn conformance_params(
+        &self,
+        num_blocks_per_integer: usize,
+    ) -> RadixCiphertextConformanceParams {
+        RadixCiphertextConformanceParams::from_pbs_parameters(
+            self.parameters,
+            num_blocks_per_integer,
+        )
+    }
+
+    /// Returns whether the bundle holds the server key of the deployment
+    pub fn verify_compute_bundle(&self, bundle: &ComputeBundle) -> bool {
+        bundle.server_key_fingerprint() == self.fingerprints.server_key
+    }
+
+    /// Returns whether the bundle holds the compact public key and the casting keys of the
+    /// deployment
+    pub fn verify_gateway_bundle(&self, bundle: &GatewayBundle) -> bool {
+        Some(bundle.compact_public_key_fingerprint()) == self.fingerprints.compact_public_key
+            && bundle.casting_key_fingerprints() == self.fingerprints.casting_keys
+    }
+}
+
+// Each bundle is serialized through a versioned enum so that the format can evolve while old
+// bundles stay readable, see CompressedKeyBundle.
+#[derive(Serialize)]
+struct ClientBundleV0Ref<'a> {
+    client_key: &'a ClientKey,
+}
+
+#[derive(Serialize)]
+enum SerializableClientBundle<'a> {
+    V0(ClientBundleV0Ref<'a>),
+}
+
+#[derive(Deserialize)]
+struct ClientBundleV0 {
+    client_key: ClientKey,
+}
+
+#[derive(Deserialize)]
+enum DeserializableClientBundle {
+    V0(ClientBundleV0),
+}
+
+impl Serialize for ClientBundle {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        SerializableClientBundle::V0(ClientBundleV0Ref {
+            client_key: &self.client_key,
+        })
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ClientBundle {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        DeserializableClientBundle::deserialize(deserializer).map(|versioned| match versioned {
+            DeserializableClientBundle::V0(bundle) => Self {
+                client_key: bundle.client_key,
+            },
+        })
+    }
+}
+
+#[derive(Serialize)]
+struct GatewayBundleV0Ref<'a> {
+    compact_public_key: &'a CompressedCompactPublicKey,
+    casting_keys: &'a [CompressedKeySwitchingKey],
+}
+
+#[derive(Serialize)]
+enum SerializableGatewayBundle<'a> {
+    V0(GatewayBundleV0Ref<'a>),
+}
+
+#[derive(Deserialize)]
+struct GatewayBundleV0 {
+    compact_public_key: CompressedCompactPublicKey,
+    casting_keys: Vec<CompressedKeySwitchingKey>,
+}
+
+#[derive(Deserialize)]
+enum DeserializableGatewayBundle {
+    V0(GatewayBundleV0),
+}
+
+impl Serialize for GatewayBundle {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        SerializableGatewayBundle::V0(GatewayBundleV0Ref {
+            compact_public_key: &self.compact_public_key,
+            casting_keys: &self.casting_keys,
+        })
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for GatewayBundle {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        DeserializableGatewayBundle::deserialize(deserializer).map(|versioned| match versioned {
+            DeserializableGatewayBundle::V0(bundle) => Self {
+                compact_public_key: bundle.compact_public_key,
+                casting_keys: bundle.casting_keys,
+            },
+        })
+    }
+}
+
+#[derive(Serialize)]
+struct ComputeBundleV0Ref<'a> {
+    server_key: &'a CompressedServerKey,
+}
+
+#[derive(Serialize)]
+enum SerializableComputeBundle<'a> {
+    V0(ComputeBundleV0Ref<'a>),
+}
+
+#[derive(Deserialize)]
+struct ComputeBundleV0 {
+    server_key: CompressedServerKey,
+}
+
+#[derive(Deserialize)]
+enum DeserializableComputeBundle {
+    V0(ComputeBundleV0),
+}
+
+impl Serialize for ComputeBundle {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        SerializableComputeBundle::V0(ComputeBundleV0Ref {
+            server_key: &self.server_key,
+        })
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ComputeBundle {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        DeserializableComputeBundle::deserialize(deserializer).map(|versioned| match versioned {
+            DeserializableComputeBundle::V0(bundle) => Self {
+                server_key: bundle.server_key,
+            },
+        })
+    }
+}
+
+#[derive(Serialize)]
+struct AuditBundleV0Ref<'a> {
+    parameters: &'a PBSParameters,
+    fingerprints: &'a KeyFingerprints,
+}
+
+#[derive(Serialize)]
+enum SerializableAuditBundle<'a> {
+    V0(AuditBundleV0Ref<'a>),
+}
+
+#[derive(Deserialize)]
+struct AuditBundleV0 {
+    parameters: PBSParameters,
+    fingerprints: KeyFingerprints,
+}
+
+#[derive(Deserialize)]
+enum DeserializableAuditBundle {
+    V0(AuditBundleV0),
+}
+
+impl Serialize for AuditBundle {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        SerializableAuditBundle::V0(AuditBundleV0Ref {
+            parameters: &self.parameters,
+            fingerprints: &self.fingerprints,
+        })
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for AuditBundle {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        DeserializableAuditBundle::deserialize(deserializer).map(|versioned| match versioned {
+            DeserializableAuditBundle::V0(bundle) => Self {
+                parameters: bundle.parameters,
+                fingerprints: bundle.fingerprints,
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::conformance::ParameterSetConformant;
+    use crate::shortint::parameters::{
+        ShortintKeySwitchingParameters, PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS,
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    };
+
+    #[test]
+    fn test_key_bundle_builder() {
+        let cks_1 = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS);
+        let cks_2 = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS);
+
+        let ksk_params = ShortintKeySwitchingParameters::new(
+            cks_2.parameters().ks_base_log(),
+            cks_2.parameters().ks_level(),
+        );
+        let casting_key = CompressedKeySwitchingKey::new(
+            (&cks_1, &CompressedServerKey::new(&cks_1)),
+            (&cks_2, &CompressedServerKey::new(&cks_2)),
+            ksk_params,
+        );
+
+        let bundles = KeyBundleBuilder::new(cks_2)
+            .with_casting_key(casting_key)
+            .build()
+            .unwrap();
+
+        let client: ClientBundle =
+            bincode::deserialize(&bincode::serialize(&bundles.client).unwrap()).unwrap();
+        let gateway: GatewayBundle =
+            bincode::deserialize(&bincode::serialize(bundles.gateway.as_ref().unwrap()).unwrap())
+                .unwrap();
+        let compute: ComputeBundle =
+            bincode::deserialize(&bincode::serialize(&bundles.compute).unwrap()).unwrap();
+        let audit: AuditBundle =
+            bincode::deserialize(&bincode::serialize(&bundles.audit).unwrap()).unwrap();
+
+        assert_eq!(audit.fingerprints(), bundles.audit.fingerprints());
+        assert!(audit.verify_compute_bundle(&compute));
+        assert!(audit.verify_gateway_bundle(&gateway));
+        assert_eq!(audit.fingerprints().casting_keys.len(), 1);
+
+        let cks = client.into_client_key();
+
+        // Casting from the first key to the deployment key
+        let ksk = gateway.decompress_casting_key(0).unwrap();
+        assert!(gateway.decompress_casting_key(1).is_none());
+        let ct = ksk.cast(&cks_1.encrypt_radix(200u8, 4));
+        assert!(ct.is_conformant(&audit.conformance_params(4)));
+        let dec: u8 = cks.decrypt_radix(&ct);
+        assert_eq!(dec, 200);
+
+        let pk = gateway.decompress_compact_public_key();
+        let sks = compute.decompress_server_key();
+        let ct = sks.scalar_add_parallelized(&pk.encrypt_radix(17u8, 4), 25u8);
+        let dec: u8 = cks.decrypt_radix(&ct);
+        assert_eq!(dec, 42);
+
+        // The keys of another deployment are detected
+        let other = KeyBundleBuilder::new(cks_1).with_gateway().build().unwrap();
+        assert!(!audit.verify_compute_bundle(&other.compute));
+        assert!(!audit.verify_gateway_bundle(other.gateway.as_ref().unwrap()));
+        assert!(other.audit.fingerprints().casting_keys.is_empty());
+
+        // No gateway unless requested
+        let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+        let bundles = KeyBundleBuilder::new(cks).build().unwrap();
+        assert!(bundles.gateway.is_none());
+        assert!(bundles.audit.fingerprints().compact_public_key.is_none());
+    }
+}
@@ -0,0 +1,420 @@
+//! This module defines KeyManifest
+//!
+//! A [KeyManifest] lists the public keys of a deployment: the fingerprint and the role of each
+//! key, the parameters they were generated with and the time the manifest was created. A network
+//! signs it with an Ed25519 key it already publishes, and the clients check the
+//! [SignedKeyManifest] against this key, then the keys they downloaded against the manifest,
+//! before encrypting anything.
+//!
+//! The Ed25519 keys are not managed by this crate, they are the [SigningKey] and [VerifyingKey]
+//! of `ed25519-dalek`.
+//!
+//! The signature covers the serialized manifest, which the [SignedKeyManifest] keeps as it was
+//! signed: the signatures stay valid when the serialization of the types of the crate evolves.
+
+use crate::integer::key_bundle_builder::{
+    AuditBundle, ComputeBundle, GatewayBundle, KeyFingerprint,
+};
+use crate::shortint::PBSParameters;
+use ed25519_dalek::{Signature, Signer, SigningKey, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// Prepended to the signed bytes, so that a manifest signature cannot be mistaken for the
+// signature of another message by the same key
+const SIGNATURE_CONTEXT: &[u8] = b"tfhe-rs key manifest";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyManifestError {
+    /// The manifest was signed by another key than the expected one
+    UnexpectedSigner,
+    /// The signature does not match the manifest
+    InvalidSignature,
+}
+
+impl std::fmt::Display for KeyManifestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnexpectedSigner => {
+                write!(f, "The manifest was not signed by the expected key")
+            }
+            Self::InvalidSignature => {
+                write!(f, "The signature of the manifest is not valid")
+            }
+        }
+    }
+}
+
+impl std::error::Error for KeyManifestError {}
+
+/// The role of a key in a deployment
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum KeyRole {
+    /// The server key, used to run the computations
+    Server,
+    /// The compact public key, used to encrypt inputs
+    CompactPublic,
+    /// A casting key, at this index in the [GatewayBundle]
+    Casting(usize),
+}
+
+/// A public key listed in a [KeyManifest]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyManifestEntry {
+    pub role: KeyRole,
+    /// Fingerprint of the key, see [KeyFingerprints](super::key_bundle_builder::KeyFingerprints)
+    pub fingerprint: KeyFingerprint,
+}
+
+/// The list of the public keys of a deployment
+///
+/// # Example
+///
+/// ```rust
+/// use ed25519_dalek::SigningKey;
+/// use tfhe::integer::key_bundle_builder::KeyBundleBuilder;
+/// use tfhe::integer::{ClientKey, KeyManifest, SignedKeyManifest};
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS;
+///
+/// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS);
+/// let bundles = KeyBundleBuilder::new(cks).with_gateway().build().unwrap();
+///
+/// // The network signs the manifest of its keys with its own Ed25519 key
+/// let network_key = SigningKey::from_bytes(&[42u8; 32]);
+/// let manifest = KeyManifest::from_audit_bundle(&bundles.audit).sign(&network_key);
+/// let published = bincode::serialize(&manifest).unwrap();
+///
+/// // On the client, which knows the verifying key of the network
+/// let manifest: SignedKeyManifest = bincode::deserialize(&published).unwrap();
+/// let manifest = manifest.verify(&network_key.verifying_key()).unwrap();
+/// assert!(manifest.verify_gateway_bundle(bundles.gateway.as_ref().unwrap()));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyManifest {
+    parameters: PBSParameters,
+    entries: Vec<KeyManifestEntry>,
+    // Seconds since the unix epoch
+    created_at: u64,
+}
+
+impl KeyManifest {
+    /// Lists the keys of the deployment of the bundle, created now
+    pub fn from_audit_bundle(bundle: &AuditBundle) -> Self {
+        Self::from_audit_bundle_with_creation_time(bundle, SystemTime::now())
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `created_at` is before the unix epoch.
+    pub fn from_audit_bundle_with_creation_time(
+        bundle: &AuditBundle,
+        created_at: SystemTime,
+    ) -> Self {
+        let fingerprints = bundle.fingerprints();
+
+        let mut entries = vec![KeyManifestEntry {
+            role: KeyRole::Server,
+            fingerprint: fingerprints.server_key,
+        }];
+        entries.extend(
+            fingerprints
+                .compact_public_key
+                .map(|fingerprint| KeyManifestEntry {
+                    role: KeyRole::CompactPublic,
+                    fingerprint,
+                }),
+        );
+        entries.extend(fingerprints.casting_keys.iter().enumerate().map(
+            |(index, &fingerprint)| KeyManifestEntry {
+                role: KeyRole::Casting(index),
+                fingerprint,
+            },
+        ));
+
+        let created_at = created_at
+            .duration_since(UNIX_EPOCH)
+            .expect("The creation time of the manifest is before the unix epoch")
+            .as_secs();
+
+        Self {
+            parameters: bundle.parameters(),
+            entries,
+            created_at,
+        }
+    }
+
+    pub fn parameters(&self) -> PBSParameters {
+        self.parameters
+    }
+
+    pub fn entries(&self) -> &[KeyManifestEntry] {
+        &self.entries
+    }
+
+    /// Returns the creation time, truncated to the second
+    pub fn creation_time(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(self.created_at)
+    }
+
+    /// Returns the fingerprint of the key with this role, if it is listed
+    pub fn fingerprint(&self, role: KeyRole) -> Option<KeyFingerprint> {
+        self.entries
+            .iter()
+            .find(|entry| entry.role == role)
+            .map(|entry| entry.fingerprint)
+    }
+
+    /// Returns whether the bundle holds the server key listed in the manifest
+    pub fn verify_compute_bundle(&self, bundle: &ComputeBundle) -> bool {
+        self.fingerprint(KeyRole::Server) == Some(bundle.server_key_fingerprint())
+    }
+
+    /// Returns whether the bundle holds the compact public key and exactly the casting keys listed
+    /// in the manifest
+    pub fn verify_gateway_bundle(&self, bundle: &GatewayBundle) -> bool {
+        let casting_keys = self
+            .entries
+            .iter()
+            .filter_map(|entry| match entry.role {
+                KeyRole::Casting(_) => Some(entry.fingerprint),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        self.fingerprint(KeyRole::CompactPublic) == Some(bundle.compact_public_key_fingerprint())
+            && casting_keys == bundle.casting_key_fingerprints()
+    }
+
+    /// Signs the manifest, the signature covers all its content
+    pub fn sign(self, signing_key: &SigningKey) -> SignedKeyManifest {
+        let manifest_bytes = bincode::serialize(&self).expect("Failed to serialize the manifest");
+        let signature = signing_key.sign(&signed_bytes(&manifest_bytes));
+        SignedKeyManifest {
+            manifest: self,
+            manifest_bytes,
+            signature,
+            signer: signing_key.verifying_key(),
+        }
+    }
+}
+
+fn signed_bytes(manifest_bytes: &[u8]) -> Vec<u8> {
+    [SIGNATURE_CONTEXT, manifest_bytes].concat()
+}
+
+/// A [KeyManifest] and its Ed25519 signature
+///
+/// The manifest should only be used once its signature is verified, see [Self::verify].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SignedKeyManifest {
+    // Decoded from `manifest_bytes`
+    manifest: KeyManifest,
+    // The serialized manifest, exactly as signed
+    manifest_bytes: Vec<u8>,
+    signature: Signature,
+    signer: VerifyingKey,
+}
+
+impl SignedKeyManifest {
+    /// The key which signed the manifest, it is not trusted until compared to a known key
+    pub fn signer(&self) -> &VerifyingKey {
+        &self.signer
+    }
+
+    /// Returns the manifest if it was signed by `trusted_key` and was not modified since
+    pub fn verify(&self, trusted_key: &VerifyingKey) -> Result<&KeyManifest, KeyManifestError> {
+        if self.signer != *trusted_key {
+            return Err(KeyManifestError::UnexpectedSigner);
+        }
+
+        trusted_key
+            .verify_strict(&signed_bytes(&self.manifest_bytes), &self.signature)
+            .map_err(|_| KeyManifestError::InvalidSignature)?;
+
+        Ok(&self.manifest)
+    }
+
+    /// Returns the manifest without checking its signature
+    pub fn into_unverified_manifest(self) -> KeyManifest {
+        self.manifest
+    }
+}
+
+// The manifests are serialized through a versioned enum, see AuditBundle.
+#[derive(Serialize)]
+struct KeyManifestV0Ref<'a> {
+    parameters: &'a PBSParameters,
+    entries: &'a [KeyManifestEntry],
+    created_at: u64,
+}
+
+#[derive(Serialize)]
+enum SerializableKeyManifest<'a> {
+    V0(KeyManifestV0Ref<'a>),
+}
+
+#[derive(Deserialize)]
+struct KeyManifestV0 {
+    parameters: PBSParameters,
+    entries: Vec<KeyManifestEntry>,
+    created_at: u64,
+}
+
+#[derive(Deserialize)]
+enum DeserializableKeyManifest {
+    V0(KeyManifestV0),
+}
+
+impl Serialize for KeyManifest {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        SerializableKeyManifest::V0(KeyManifestV0Ref {
+            parameters: &self.parameters,
+            entries: &self.entries,
+            created_at: self.created_at,
+        })
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for KeyManifest {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        DeserializableKeyManifest::deserialize(deserializer).map(|versioned| match versioned {
+            DeserializableKeyManifest::V0(manifest) => Self {
+                parameters: manifest.parameters,
+                entries: manifest.entries,
+                created_at: manifest.created_at,
+            },
+        })
+    }
+}
+
+// The signed manifests keep the signed bytes of the manifest, the manifest is decoded from them
+#[derive(Serialize)]
+struct SignedKeyManifestV0Ref<'a> {
+    manifest_bytes: &'a [u8],
+    signature: &'a Signature,
+    signer: &'a VerifyingKey,
+}
+
+#[derive(Serialize)]
+enum SerializableSignedKeyManifest<'a> {
+    V0(SignedKeyManifestV0Ref<'a>),
+}
+
+#[derive(Deserialize)]
+struct SignedKeyManifestV0 {
+    manifest_bytes: Vec<u8>,
+    signature: Signature,
+    signer: VerifyingKey,
+}
+
+#[derive(Deserialize)]
+enum DeserializableSignedKeyManifest {
+    V0(SignedKeyManifestV0),
+}
+
+impl Serialize for SignedKeyManifest {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        SerializableSignedKeyManifest::V0(SignedKeyManifestV0Ref {
+            manifest_bytes: &self.manifest_bytes,
+            signature: &self.signature,
+            signer: &self.signer,
+        })
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for SignedKeyManifest {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        match DeserializableSignedKeyManifest::deserialize(deserializer)? {
+            DeserializableSignedKeyManifest::V0(signed) => {
+                let manifest = bincode::deserialize(&signed.manifest_bytes)
+                    .map_err(serde::de::Error::custom)?;
+                Ok(Self {
+                    manifest,
+                    manifest_bytes: signed.manifest_bytes,
+                    signature: signed.signature,
+                    signer: signed.signer,
+                })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::integer::key_bundle_builder::KeyBundleBuilder;
+    use crate::integer::ClientKey;
+    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS;
+
+    #[test]
+    fn test_key_manifest() {
+        let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS);
+        let bundles = KeyBundleBuilder::new(cks).with_gateway().build().unwrap();
+
+        let created_at = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let manifest =
+            KeyManifest::from_audit_bundle_with_creation_time(&bundles.audit, created_at);
+        assert_eq!(manifest.creation_time(), created_at);
+        assert_eq!(manifest.parameters(), bundles.audit.parameters());
+        assert_eq!(
+            manifest.fingerprint(KeyRole::Server),
+            Some(bundles.audit.fingerprints().server_key)
+        );
+        assert_eq!(manifest.fingerprint(KeyRole::Casting(0)), None);
+
+        let network_key = SigningKey::from_bytes(&[7u8; 32]);
+        let signed = manifest.clone().sign(&network_key);
+
+        let serialized = bincode::serialize(&signed).unwrap();
+        let signed: SignedKeyManifest = bincode::deserialize(&serialized).unwrap();
+        assert_eq!(signed.signer(), &network_key.verifying_key());
+
+        let verified = signed.verify(&network_key.verifying_key()).unwrap();
+        assert_eq!(verified, &manifest);
+        assert!(verified.verify_compute_bundle(&bundles.compute));
+        assert!(verified.verify_gateway_bundle(bundles.gateway.as_ref().unwrap()));
+
+        // Signed by another key
+        let other_key = SigningKey::from_bytes(&[8u8; 32]);
+        assert_eq!(
+            signed.verify(&other_key.verifying_key()),
+            Err(KeyManifestError::UnexpectedSigner)
+        );
+
+        // A manifest modified after being signed
+        let mut tampered = signed.clone();
+        *tampered.manifest_bytes.last_mut().unwrap() ^= 1;
+        assert_eq!(
+            tampered.verify(&network_key.verifying_key()),
+            Err(KeyManifestError::InvalidSignature)
+        );
+
+        // A signature by another key, presented as the network one
+        let mut forged = manifest.sign(&other_key);
+        forged.signer = network_key.verifying_key();
+        assert_eq!(
+            forged.verify(&network_key.verifying_key()),
+            Err(KeyManifestError::InvalidSignature)
+        );
+
+        // The keys of another deployment are detected
+        let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS);
+        let other = KeyBundleBuilder::new(cks).with_gateway().build().unwrap();
+        assert!(!verified.verify_compute_bundle(&other.compute));
+        assert!(!verified.verify_gateway_bundle(other.gateway.as_ref().unwrap()));
+    }
+}
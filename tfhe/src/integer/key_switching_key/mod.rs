@@ -1,4 +1,4 @@
-use super::{ClientKey, ServerKey};
+use super::{ClientKey, CompressedServerKey, ServerKey};
 
 use crate::integer::IntegerCiphertext;
 use crate::shortint::parameters::ShortintKeySwitchingParameters;
@@ -11,7 +11,7 @@ mod test;
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct KeySwitchingKey {
-    key: crate::shortint::KeySwitchingKey,
+    pub(crate) key: crate::shortint::KeySwitchingKey,
 }
 
 impl KeySwitchingKey {
@@ -64,3 +64,83 @@ impl KeySwitchingKey {
         )
     }
 }
+
+/// A compressed casting key between two integer key pairs
+///
+/// It bundles everything the [KeySwitchingKey] it decompresses into needs: the seeded key
+/// switching key, and the compressed server keys of both key pairs, which the casting uses to
+/// clean the blocks. It is thus about as large as two compressed server keys.
+///
+/// Its serialized form is not versioned on its own, the bundles storing casting keys are:
+/// [CompressedKeyBundle](crate::integer::CompressedKeyBundle),
+/// [GatewayBundle](crate::integer::key_bundle_builder::GatewayBundle) and the high level
+/// [CompressedServerKey](crate::CompressedServerKey).
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::integer::key_switching_key::CompressedKeySwitchingKey;
+/// use tfhe::integer::{ClientKey, CompressedServerKey};
+/// use tfhe::shortint::parameters::{
+///     ShortintKeySwitchingParameters, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+/// };
+///
+/// let cks_1 = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+/// let cks_2 = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+///
+/// let ksk_params = ShortintKeySwitchingParameters::new(
+///     cks_2.parameters().ks_base_log(),
+///     cks_2.parameters().ks_level(),
+/// );
+/// let compressed_ksk = CompressedKeySwitchingKey::new(
+///     (&cks_1, &CompressedServerKey::new(&cks_1)),
+///     (&cks_2, &CompressedServerKey::new(&cks_2)),
+///     ksk_params,
+/// );
+///
+/// let ksk = compressed_ksk.decompress();
+/// let ct = ksk.cast(&cks_1.encrypt_radix(200u8, 4));
+/// let dec: u8 = cks_2.decrypt_radix(&ct);
+/// assert_eq!(dec, 200);
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CompressedKeySwitchingKey {
+    pub(crate) key: crate::shortint::CompressedKeySwitchingKey,
+}
+
+impl CompressedKeySwitchingKey {
+    pub fn new<ClientKeyType>(
+        key_pair_1: (&ClientKeyType, &CompressedServerKey),
+        key_pair_2: (&ClientKeyType, &CompressedServerKey),
+        params: ShortintKeySwitchingParameters,
+    ) -> Self
+    where
+        ClientKeyType: AsRef<ClientKey>,
+    {
+        let ret = Self {
+            key: crate::shortint::CompressedKeySwitchingKey::new(
+                (&key_pair_1.0.as_ref().key, &key_pair_1.1.key),
+                (&key_pair_2.0.as_ref().key, &key_pair_2.1.key),
+                params,
+            ),
+        };
+
+        if ret.key.cast_rshift != 0 {
+            panic!("Attempt to build a CompressedKeySwitchingKey between integer key pairs with different message modulus and carry");
+        }
+
+        ret
+    }
+
+    pub fn decompress(self) -> KeySwitchingKey {
+        KeySwitchingKey {
+            key: self.key.decompress(),
+        }
+    }
+}
+
+impl From<CompressedKeySwitchingKey> for KeySwitchingKey {
+    fn from(value: CompressedKeySwitchingKey) -> Self {
+        value.decompress()
+    }
+}
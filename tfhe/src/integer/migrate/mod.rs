@@ -0,0 +1,275 @@
+//! This module defines the tools to migrate ciphertexts from one parameter set to another.
+//!
+//! - [CiphertextMigrator] converts ciphertexts encrypted under an old set of keys into
+//!   ciphertexts encrypted under a new set of keys, using a [KeySwitchingKey] between the two.
+//! - [MigrationIter] converts an archive of ciphertexts in batches, keeping track of its progress
+//!   in a [MigrationCheckpoint] so that an interrupted migration can be resumed.
+
+use crate::integer::key_switching_key::KeySwitchingKey;
+use crate::integer::IntegerCiphertext;
+use crate::shortint::ciphertext::NoiseLevel;
+
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+#[cfg(test)]
+mod test;
+
+const DEFAULT_BATCH_SIZE: usize = 64;
+
+/// Progress of a migration.
+///
+/// The checkpoint is meant to be persisted alongside the migrated archive, and given back to
+/// [CiphertextMigrator::migrate_iter] to resume the migration where it stopped.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MigrationCheckpoint {
+    /// Number of ciphertexts of the archive that have already been migrated
+    pub processed: usize,
+}
+
+impl MigrationCheckpoint {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Converts ciphertexts from the source parameters of a [KeySwitchingKey] to its destination
+/// parameters.
+///
+/// Each block is only key switched to the new key: migrated ciphertexts are noisier than fresh
+/// encryptions under the new parameters, their next PBS brings them back to a nominal noise level.
+pub struct CiphertextMigrator<'key> {
+    key: &'key KeySwitchingKey,
+    batch_size: usize,
+}
+
+impl<'key> CiphertextMigrator<'key> {
+    /// Creates a migrator using the given migration key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::integer::key_switching_key::KeySwitchingKey;
+    /// use tfhe::integer::migrate::CiphertextMigrator;
+    /// use tfhe::shortint::parameters::{
+    ///     ShortintKeySwitchingParameters, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    ///
+    /// let num_blocks = 4;
+    /// let (old_cks, old_sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    /// let (new_cks, new_sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let ksk_params = ShortintKeySwitchingParameters::new(
+    ///     new_cks.parameters().ks_base_log(),
+    ///     new_cks.parameters().ks_level(),
+    /// );
+    /// let ksk = KeySwitchingKey::new((&old_cks, &old_sks), (&new_cks, &new_sks), ksk_params);
+    ///
+    /// let migrator = CiphertextMigrator::new(&ksk);
+    ///
+    /// let ct = old_cks.encrypt(42u8);
+    /// let migrated = migrator.migrate(&ct);
+    ///
+    /// let clear: u8 = new_cks.decrypt(&migrated);
+    /// assert_eq!(clear, 42);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the source and destination parameters of the key do not have the same message
+    /// and carry moduli: migrated blocks keep the degree and moduli of the input blocks.
+    pub fn new(key: &'key KeySwitchingKey) -> Self {
+        let src_server_key = &key.key.src_server_key;
+        let dest_server_key = &key.key.dest_server_key;
+        assert_eq!(
+            key.key.cast_rshift, 0,
+            "Cannot migrate ciphertexts to parameters with a different message space"
+        );
+        assert!(
+            src_server_key.message_modulus == dest_server_key.message_modulus
+                && src_server_key.carry_modulus == dest_server_key.carry_modulus,
+            "Cannot migrate ciphertexts to parameters with different message and carry moduli, \
+            got {:?} and {:?} as source, {:?} and {:?} as destination",
+            src_server_key.message_modulus,
+            src_server_key.carry_modulus,
+            dest_server_key.message_modulus,
+            dest_server_key.carry_modulus,
+        );
+
+        Self {
+            key,
+            batch_size: DEFAULT_BATCH_SIZE,
+        }
+    }
+
+    /// Sets the number of ciphertexts migrated in parallel by [MigrationIter].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `batch_size` is 0.
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        assert!(batch_size > 0, "The batch size must be greater than 0");
+        self.batch_size = batch_size;
+        self
+    }
+
+    pub fn batch_size(&self) -> usize {
+        self.batch_size
+    }
+
+    /// Migrates a single ciphertext to the destination parameters.
+    ///
+    /// Carries of the input blocks are preserved, as well as their degree. The noise level of
+    /// each block is the one of the input block increased by the key switch.
+    pub fn migrate<Int>(&self, ct: &Int) -> Int
+    where
+        Int: IntegerCiphertext,
+    {
+        let dest_server_key = &self.key.key.dest_server_key;
+
+        Int::from_blocks(
+            ct.blocks()
+                .par_iter()
+                .map(|block| {
+                    let mut migrated = dest_server_key.create_trivial(0);
+                    self.key.key.cast_into(block, &mut migrated);
+
+                    // Both sides of the key switching key have the same message and carry moduli
+                    // (checked in new), so the degree of the input block is still valid, Crt
+                    // blocks keep their own message modulus
+                    migrated.message_modulus = block.message_modulus;
+                    migrated.carry_modulus = dest_server_key.carry_modulus;
+                    migrated.degree = block.degree;
+                    // The key switch adds its own noise to the one of the input block
+                    migrated.noise_level = block.noise_level() + NoiseLevel::NOMINAL;
+
+                    migrated
+                })
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    /// Migrates an archive of ciphertexts, batch by batch.
+    ///
+    /// The first `checkpoint.processed` elements of the archive are skipped, they are
+    /// considered as already migrated by a previous run.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::integer::key_switching_key::KeySwitchingKey;
+    /// use tfhe::integer::migrate::{CiphertextMigrator, MigrationCheckpoint};
+    /// use tfhe::integer::RadixCiphertext;
+    /// use tfhe::shortint::parameters::{
+    ///     ShortintKeySwitchingParameters, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    ///
+    /// let num_blocks = 4;
+    /// let (old_cks, old_sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    /// let (new_cks, new_sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let ksk_params = ShortintKeySwitchingParameters::new(
+    ///     new_cks.parameters().ks_base_log(),
+    ///     new_cks.parameters().ks_level(),
+    /// );
+    /// let ksk = KeySwitchingKey::new((&old_cks, &old_sks), (&new_cks, &new_sks), ksk_params);
+    ///
+    /// let migrator = CiphertextMigrator::new(&ksk).with_batch_size(2);
+    ///
+    /// let clears = [1u8, 2, 3, 4, 5];
+    /// let archive = clears
+    ///     .iter()
+    ///     .map(|clear| old_cks.encrypt(*clear))
+    ///     .collect::<Vec<RadixCiphertext>>();
+    ///
+    /// // Migrate the first 3 ciphertexts, then stop
+    /// let mut migration =
+    ///     migrator.migrate_iter(archive.iter().cloned(), MigrationCheckpoint::new());
+    /// let mut migrated = migration.by_ref().take(3).collect::<Vec<_>>();
+    /// let checkpoint = migration.checkpoint();
+    /// assert_eq!(checkpoint.processed, 3);
+    ///
+    /// // Resume the migration
+    /// migrated.extend(migrator.migrate_iter(archive.iter().cloned(), checkpoint));
+    ///
+    /// for (ct, clear) in migrated.iter().zip(clears.iter()) {
+    ///     let decrypted: u8 = new_cks.decrypt(ct);
+    ///     assert_eq!(decrypted, *clear);
+    /// }
+    /// ```
+    pub fn migrate_iter<I, Int>(
+        &self,
+        archive: I,
+        checkpoint: MigrationCheckpoint,
+    ) -> MigrationIter<'_, 'key, I::IntoIter, Int>
+    where
+        I: IntoIterator<Item = Int>,
+        Int: IntegerCiphertext + Send + Sync,
+    {
+        MigrationIter {
+            migrator: self,
+            archive: archive.into_iter(),
+            pending: VecDeque::with_capacity(self.batch_size),
+            to_skip: checkpoint.processed,
+            checkpoint,
+        }
+    }
+}
+
+/// Iterator migrating the ciphertexts of an archive, see [CiphertextMigrator::migrate_iter].
+///
+/// Ciphertexts are pulled from the archive and migrated in parallel, one batch at a time.
+pub struct MigrationIter<'migrator, 'key, I, Int> {
+    migrator: &'migrator CiphertextMigrator<'key>,
+    archive: I,
+    pending: VecDeque<Int>,
+    to_skip: usize,
+    checkpoint: MigrationCheckpoint,
+}
+
+impl<'migrator, 'key, I, Int> MigrationIter<'migrator, 'key, I, Int> {
+    /// Returns the progress of the migration.
+    ///
+    /// Only the ciphertexts already returned by the iterator are accounted as processed,
+    /// ciphertexts migrated in the current batch but not yet returned will be migrated again
+    /// when resuming.
+    pub fn checkpoint(&self) -> MigrationCheckpoint {
+        self.checkpoint
+    }
+}
+
+impl<'migrator, 'key, I, Int> Iterator for MigrationIter<'migrator, 'key, I, Int>
+where
+    I: Iterator<Item = Int>,
+    Int: IntegerCiphertext + Send + Sync,
+{
+    type Item = Int;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.to_skip != 0 {
+            self.archive.by_ref().take(self.to_skip).for_each(drop);
+            self.to_skip = 0;
+        }
+
+        if self.pending.is_empty() {
+            let batch = self
+                .archive
+                .by_ref()
+                .take(self.migrator.batch_size)
+                .collect::<Vec<_>>();
+
+            let migrated = batch
+                .par_iter()
+                .map(|ct| self.migrator.migrate(ct))
+                .collect::<Vec<_>>();
+            self.pending.extend(migrated);
+        }
+
+        let ct = self.pending.pop_front()?;
+        self.checkpoint.processed += 1;
+        Some(ct)
+    }
+}
@@ -0,0 +1,165 @@
+use crate::integer::key_switching_key::KeySwitchingKey;
+use crate::integer::migrate::{CiphertextMigrator, MigrationCheckpoint};
+use crate::shortint::ciphertext::NoiseLevel;
+use crate::shortint::parameters::ShortintKeySwitchingParameters;
+use crate::shortint::parameters::{
+    PARAM_MESSAGE_1_CARRY_3_KS_PBS, PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS,
+};
+use crate::shortint::prelude::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+
+#[test]
+fn migrate_radix_archive_with_resume() {
+    let num_block = 4;
+
+    let (client_key_1, server_key_1) =
+        crate::integer::gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_block);
+    let (client_key_2, server_key_2) =
+        crate::integer::gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_block);
+
+    let ksk_params = ShortintKeySwitchingParameters::new(
+        client_key_2.parameters().ks_base_log(),
+        client_key_2.parameters().ks_level(),
+    );
+    let ksk = KeySwitchingKey::new(
+        (&client_key_1, &server_key_1),
+        (&client_key_2, &server_key_2),
+        ksk_params,
+    );
+
+    let migrator = CiphertextMigrator::new(&ksk).with_batch_size(3);
+
+    let clears = (0..10u8).map(|i| i * 25).collect::<Vec<_>>();
+    let archive = clears
+        .iter()
+        .map(|clear| client_key_1.encrypt(*clear))
+        .collect::<Vec<_>>();
+
+    // Interrupt the migration in the middle of a batch
+    let mut migration = migrator.migrate_iter(archive.iter().cloned(), MigrationCheckpoint::new());
+    let mut migrated = migration.by_ref().take(4).collect::<Vec<_>>();
+    let checkpoint = migration.checkpoint();
+    drop(migration);
+    assert_eq!(checkpoint.processed, 4);
+
+    // The checkpoint survives a round trip through serialization
+    let serialized = bincode::serialize(&checkpoint).unwrap();
+    let checkpoint: MigrationCheckpoint = bincode::deserialize(&serialized).unwrap();
+
+    let mut migration = migrator.migrate_iter(archive.iter().cloned(), checkpoint);
+    migrated.extend(migration.by_ref());
+    assert_eq!(migration.checkpoint().processed, clears.len());
+    assert_eq!(migrated.len(), clears.len());
+
+    for (ct, clear) in migrated.iter().zip(clears.iter()) {
+        let decrypted: u8 = client_key_2.decrypt(ct);
+        assert_eq!(decrypted, *clear);
+
+        // Migrated ciphertexts are usable with the new server key
+        let doubled = server_key_2.add_parallelized(ct, ct);
+        let decrypted: u8 = client_key_2.decrypt(&doubled);
+        assert_eq!(decrypted, clear.wrapping_mul(2));
+    }
+}
+
+#[test]
+fn migrate_keeps_block_metadata() {
+    let num_block = 4;
+
+    let (client_key_1, server_key_1) =
+        crate::integer::gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_block);
+    let (client_key_2, server_key_2) =
+        crate::integer::gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_block);
+
+    let ksk_params = ShortintKeySwitchingParameters::new(
+        client_key_2.parameters().ks_base_log(),
+        client_key_2.parameters().ks_level(),
+    );
+    let ksk = KeySwitchingKey::new(
+        (&client_key_1, &server_key_1),
+        (&client_key_2, &server_key_2),
+        ksk_params,
+    );
+
+    let migrator = CiphertextMigrator::new(&ksk);
+
+    // Fill the carries so that the degree of the blocks is above the message modulus
+    let ct = client_key_1.encrypt(200u8);
+    let ct = server_key_1.unchecked_add(&ct, &ct);
+
+    let migrated = migrator.migrate(&ct);
+
+    for (block, migrated_block) in ct.blocks.iter().zip(migrated.blocks.iter()) {
+        assert_eq!(migrated_block.degree, block.degree);
+        assert_eq!(migrated_block.message_modulus, block.message_modulus);
+        assert_eq!(migrated_block.carry_modulus, block.carry_modulus);
+        // The block was only key switched, it must not be taken for the output of a PBS
+        assert!(migrated_block.noise_level() > block.noise_level());
+        assert!(migrated_block.noise_level() > NoiseLevel::NOMINAL);
+    }
+
+    let decrypted: u8 = client_key_2.decrypt(&migrated);
+    assert_eq!(decrypted, 200u8.wrapping_mul(2));
+}
+
+#[test]
+fn migrate_across_parameter_sets() {
+    let num_block = 4;
+
+    let (client_key_1, server_key_1) =
+        crate::integer::gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_block);
+    let (client_key_2, server_key_2) =
+        crate::integer::gen_keys_radix(PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS, num_block);
+
+    let ksk_params = ShortintKeySwitchingParameters::new(
+        client_key_2.parameters().ks_base_log(),
+        client_key_2.parameters().ks_level(),
+    );
+    let ksk = KeySwitchingKey::new(
+        (&client_key_1, &server_key_1),
+        (&client_key_2, &server_key_2),
+        ksk_params,
+    );
+
+    let migrator = CiphertextMigrator::new(&ksk);
+
+    let ct = client_key_1.encrypt(123u8);
+    let migrated = migrator.migrate(&ct);
+
+    // The migrated blocks have the shape of fresh encryptions under the new parameters
+    let fresh_block = client_key_2.encrypt_one_block(0);
+    for block in migrated.blocks.iter() {
+        assert_eq!(block.ct.lwe_size(), fresh_block.ct.lwe_size());
+        assert_eq!(block.pbs_order, fresh_block.pbs_order);
+    }
+
+    let decrypted: u8 = client_key_2.decrypt(&migrated);
+    assert_eq!(decrypted, 123);
+
+    let tripled = server_key_2.scalar_mul_parallelized(&migrated, 3u8);
+    let decrypted: u8 = client_key_2.decrypt(&tripled);
+    assert_eq!(decrypted, 123u8.wrapping_mul(3));
+}
+
+#[test]
+#[should_panic(expected = "different message and carry moduli")]
+fn migrate_to_different_moduli_panics() {
+    let num_block = 4;
+
+    let (client_key_1, server_key_1) =
+        crate::integer::gen_keys_radix(PARAM_MESSAGE_1_CARRY_3_KS_PBS, num_block);
+    let (client_key_2, server_key_2) =
+        crate::integer::gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_block);
+
+    let ksk_params = ShortintKeySwitchingParameters::new(
+        client_key_2.parameters().ks_base_log(),
+        client_key_2.parameters().ks_level(),
+    );
+    // Both sides have 4 bits of message space, so the key switching key itself can be built
+    let ksk = KeySwitchingKey::new(
+        (&client_key_1, &server_key_1),
+        (&client_key_2, &server_key_2),
+        ksk_params,
+    );
+
+    let _ = CiphertextMigrator::new(&ksk);
+}
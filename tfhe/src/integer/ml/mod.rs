@@ -0,0 +1,5 @@
+//! Private inference of clear machine learning models on encrypted inputs
+//!
+//! - [tree] evaluates decision trees and random forests on encrypted feature vectors.
+
+pub mod tree;
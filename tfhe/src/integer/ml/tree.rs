@@ -0,0 +1,406 @@
+//! This module defines DecisionTree and RandomForest
+//!
+//! - [DecisionTree] evaluates a clear decision tree on an encrypted feature vector, returning the
+//!   encrypted value of the reached leaf.
+//! - [RandomForest] sums the values returned by several decision trees.
+//!
+//! The model is clear, only the features and the result are encrypted: which leaf has been
+//! reached is not revealed. To do so, all the splits of the tree are evaluated, in parallel, with
+//! one encrypted comparison each, then the leaf values are selected level by level, from the
+//! deepest one up to the root, with one encrypted cmux per split.
+
+use crate::integer::{RadixCiphertext, ServerKey};
+use serde::{Deserialize, Serialize};
+
+use rayon::prelude::*;
+
+/// A node of a [DecisionTree].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DecisionNode {
+    /// Continues to `left` if the feature at index `feature` is smaller than or equal to
+    /// `threshold`, to `right` otherwise
+    Split {
+        feature: usize,
+        threshold: u64,
+        left: Box<DecisionNode>,
+        right: Box<DecisionNode>,
+    },
+    /// The class or score returned when reaching this node
+    Leaf(u64),
+}
+
+impl DecisionNode {
+    pub fn split(feature: usize, threshold: u64, left: Self, right: Self) -> Self {
+        Self::Split {
+            feature,
+            threshold,
+            left: Box::new(left),
+            right: Box::new(right),
+        }
+    }
+
+    pub fn leaf(value: u64) -> Self {
+        Self::Leaf(value)
+    }
+}
+
+/// A node of a [DecisionTree], with its children given as indices in the next level
+enum LevelNode {
+    Split {
+        condition_index: usize,
+        left: usize,
+        right: usize,
+    },
+    Leaf(u64),
+}
+
+/// A clear decision tree, evaluated on encrypted features.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::integer::gen_keys_radix;
+/// use tfhe::integer::ml::tree::{DecisionNode, DecisionTree};
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+///
+/// // We have 4 * 2 = 8 bits of message
+/// let num_blocks = 4;
+/// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+///
+/// // if age <= 30 { 0 } else if income <= 100 { 1 } else { 2 }
+/// let tree = DecisionTree::new(DecisionNode::split(
+///     0,
+///     30,
+///     DecisionNode::leaf(0),
+///     DecisionNode::split(1, 100, DecisionNode::leaf(1), DecisionNode::leaf(2)),
+/// ));
+///
+/// let clear_features = [42u64, 150u64];
+/// let features = clear_features
+///     .iter()
+///     .map(|feature| cks.encrypt(*feature))
+///     .collect::<Vec<_>>();
+///
+/// let ct_res = tree.evaluate(&sks, &features, num_blocks);
+///
+/// let dec: u64 = cks.decrypt(&ct_res);
+/// assert_eq!(dec, 2);
+/// assert_eq!(dec, tree.evaluate_clear(&clear_features));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DecisionTree {
+    root: DecisionNode,
+}
+
+impl DecisionTree {
+    pub fn new(root: DecisionNode) -> Self {
+        Self { root }
+    }
+
+    pub fn root(&self) -> &DecisionNode {
+        &self.root
+    }
+
+    /// Returns the number of splits on the longest path from the root to a leaf
+    pub fn depth(&self) -> usize {
+        self.levels().1.len() - 1
+    }
+
+    /// Returns the minimum number of features the tree must be evaluated on
+    pub fn num_features(&self) -> usize {
+        self.levels()
+            .0
+            .iter()
+            .map(|(feature, _)| feature + 1)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Evaluates the tree on clear features
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are less features than [DecisionTree::num_features]
+    pub fn evaluate_clear(&self, features: &[u64]) -> u64 {
+        let mut node = &self.root;
+        loop {
+            match node {
+                DecisionNode::Split {
+                    feature,
+                    threshold,
+                    left,
+                    right,
+                } => {
+                    node = if features[*feature] <= *threshold {
+                        left
+                    } else {
+                        right
+                    };
+                }
+                DecisionNode::Leaf(value) => return *value,
+            }
+        }
+    }
+
+    /// Evaluates the tree on encrypted features, and returns the encrypted value of the reached
+    /// leaf, as a ciphertext with `num_blocks` blocks.
+    ///
+    /// The features must have empty carries. See [DecisionTree] for usage.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are less features than [DecisionTree::num_features]
+    pub fn evaluate(
+        &self,
+        sks: &ServerKey,
+        features: &[RadixCiphertext],
+        num_blocks: usize,
+    ) -> RadixCiphertext {
+        let (splits, levels) = self.levels();
+        assert!(
+            splits.iter().all(|(feature, _)| *feature < features.len()),
+            "The tree needs {} features, got {}",
+            self.num_features(),
+            features.len()
+        );
+
+        // The comparisons of all the splits are independent
+        let conditions = splits
+            .par_iter()
+            .map(|(feature, threshold)| sks.scalar_gt_parallelized(&features[*feature], *threshold))
+            .collect::<Vec<_>>();
+
+        // The children of the nodes of a level are in the level below, so the levels are reduced
+        // from the deepest one, each node of a level being computed in parallel
+        let mut values: Vec<RadixCiphertext> = Vec::new();
+        for level in levels.iter().rev() {
+            values = level
+                .par_iter()
+                .map(|node| match node {
+                    LevelNode::Split {
+                        condition_index,
+                        left,
+                        right,
+                    } => {
+                        // The condition encrypts 1 if the feature is greater than the threshold
+                        let condition_block = &conditions[*condition_index].blocks[0];
+                        sks.unchecked_programmable_if_then_else_parallelized(
+                            condition_block,
+                            &values[*right],
+                            &values[*left],
+                            |x| x == 1,
+                            true,
+                        )
+                    }
+                    LevelNode::Leaf(value) => sks.create_trivial_radix(*value, num_blocks),
+                })
+                .collect();
+        }
+
+        assert_eq!(values.len(), 1);
+        values.pop().unwrap()
+    }
+
+    /// Returns the `(feature, threshold)` of all the splits, and the nodes of the tree level by
+    /// level, the first level being the root
+    fn levels(&self) -> (Vec<(usize, u64)>, Vec<Vec<LevelNode>>) {
+        let mut splits = Vec::new();
+        let mut levels = Vec::new();
+
+        let mut current_level = vec![&self.root];
+        while !current_level.is_empty() {
+            let mut next_level = Vec::new();
+            let mut level = Vec::with_capacity(current_level.len());
+
+            for node in current_level {
+                match node {
+                    DecisionNode::Split {
+                        feature,
+                        threshold,
+                        left,
+                        right,
+                    } => {
+                        splits.push((*feature, *threshold));
+                        next_level.push(left.as_ref());
+                        next_level.push(right.as_ref());
+                        level.push(LevelNode::Split {
+                            condition_index: splits.len() - 1,
+                            left: next_level.len() - 2,
+                            right: next_level.len() - 1,
+                        });
+                    }
+                    DecisionNode::Leaf(value) => level.push(LevelNode::Leaf(*value)),
+                }
+            }
+
+            levels.push(level);
+            current_level = next_level;
+        }
+
+        (splits, levels)
+    }
+}
+
+/// A clear random forest, evaluated on encrypted features.
+///
+/// The result is the sum of the values returned by each tree, e.g. the number of trees voting for
+/// a class when the leaves encrypt 0 or 1, or the score of a boosted ensemble.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::integer::gen_keys_radix;
+/// use tfhe::integer::ml::tree::{DecisionNode, DecisionTree, RandomForest};
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+///
+/// // We have 4 * 2 = 8 bits of message
+/// let num_blocks = 4;
+/// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+///
+/// let forest = RandomForest::new(vec![
+///     DecisionTree::new(DecisionNode::split(
+///         0,
+///         10,
+///         DecisionNode::leaf(0),
+///         DecisionNode::leaf(1),
+///     )),
+///     DecisionTree::new(DecisionNode::split(
+///         1,
+///         20,
+///         DecisionNode::leaf(1),
+///         DecisionNode::leaf(0),
+///     )),
+/// ]);
+///
+/// let clear_features = [15u64, 5u64];
+/// let features = clear_features
+///     .iter()
+///     .map(|feature| cks.encrypt(*feature))
+///     .collect::<Vec<_>>();
+///
+/// let ct_res = forest.evaluate(&sks, &features, num_blocks);
+///
+/// let dec: u64 = cks.decrypt(&ct_res);
+/// assert_eq!(dec, 2);
+/// assert_eq!(dec, forest.evaluate_clear(&clear_features));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RandomForest {
+    trees: Vec<DecisionTree>,
+}
+
+impl RandomForest {
+    /// # Panics
+    ///
+    /// Panics if there is no tree
+    pub fn new(trees: Vec<DecisionTree>) -> Self {
+        assert!(!trees.is_empty(), "A random forest needs at least one tree");
+        Self { trees }
+    }
+
+    pub fn trees(&self) -> &[DecisionTree] {
+        &self.trees
+    }
+
+    /// Returns the minimum number of features the forest must be evaluated on
+    pub fn num_features(&self) -> usize {
+        self.trees
+            .iter()
+            .map(DecisionTree::num_features)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Evaluates the forest on clear features, the sum wraps around `u64::MAX`
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are less features than [RandomForest::num_features]
+    pub fn evaluate_clear(&self, features: &[u64]) -> u64 {
+        self.trees
+            .iter()
+            .map(|tree| tree.evaluate_clear(features))
+            .fold(0, u64::wrapping_add)
+    }
+
+    /// Evaluates all the trees in parallel on encrypted features, and returns the encrypted sum
+    /// of their values, as a ciphertext with `num_blocks` blocks.
+    ///
+    /// The features must have empty carries. See [RandomForest] for usage.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are less features than [RandomForest::num_features]
+    pub fn evaluate(
+        &self,
+        sks: &ServerKey,
+        features: &[RadixCiphertext],
+        num_blocks: usize,
+    ) -> RadixCiphertext {
+        let values = self
+            .trees
+            .par_iter()
+            .map(|tree| tree.evaluate(sks, features, num_blocks))
+            .collect::<Vec<_>>();
+
+        sks.unchecked_sum_ciphertexts_vec_parallelized(values)
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integer::keycache::KEY_CACHE;
+    use crate::integer::RadixClientKey;
+    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    use rand::Rng;
+
+    const NB_CTXT: usize = 4;
+
+    fn random_node(rng: &mut impl Rng, depth: usize, num_features: usize) -> DecisionNode {
+        if depth == 0 || rng.gen_bool(0.2) {
+            return DecisionNode::leaf(rng.gen_range(0..16));
+        }
+        DecisionNode::split(
+            rng.gen_range(0..num_features),
+            rng.gen_range(0..256),
+            random_node(rng, depth - 1, num_features),
+            random_node(rng, depth - 1, num_features),
+        )
+    }
+
+    #[test]
+    fn test_decision_tree_and_random_forest_evaluation() {
+        let (cks, sks) = KEY_CACHE.get_from_params(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+        let cks = RadixClientKey::from((cks, NB_CTXT));
+
+        let mut rng = rand::thread_rng();
+        let num_features = 3;
+
+        let forest = RandomForest::new(
+            (0..3)
+                .map(|_| DecisionTree::new(random_node(&mut rng, 3, num_features)))
+                .collect(),
+        );
+
+        for _ in 0..2 {
+            let clear_features = (0..num_features)
+                .map(|_| rng.gen_range(0..256))
+                .collect::<Vec<u64>>();
+            let features = clear_features
+                .iter()
+                .map(|feature| cks.encrypt(*feature))
+                .collect::<Vec<_>>();
+
+            let tree = &forest.trees()[0];
+            let ct_res = tree.evaluate(&sks, &features, NB_CTXT);
+            let dec: u64 = cks.decrypt(&ct_res);
+            assert_eq!(dec, tree.evaluate_clear(&clear_features));
+
+            let ct_res = forest.evaluate(&sks, &features, NB_CTXT);
+            let dec: u64 = cks.decrypt(&ct_res);
+            assert_eq!(dec, forest.evaluate_clear(&clear_features) % 256);
+        }
+    }
+}
@@ -55,12 +55,21 @@ pub(crate) mod encryption;
 pub mod bigint;
 pub mod ciphertext;
 pub mod client_key;
+pub mod key_bundle;
+#[cfg(feature = "key-bundle-builder")]
+pub mod key_bundle_builder;
+#[cfg(feature = "key-manifest")]
+pub mod key_manifest;
 pub mod key_switching_key;
 #[cfg(any(test, feature = "internal-keycache"))]
 pub mod keycache;
+pub mod migrate;
+pub mod ml;
 pub mod parameters;
 pub mod public_key;
 pub mod server_key;
+#[cfg(any(test, feature = "test-utils"))]
+pub mod test_utils;
 pub mod wopbs;
 
 pub use bigint::i256::I256;
@@ -68,12 +77,19 @@ pub use bigint::i512::I512;
 pub use bigint::u256::U256;
 pub use bigint::u512::U512;
 pub use ciphertext::{
-    CrtCiphertext, IntegerCiphertext, IntegerRadixCiphertext, RadixCiphertext,
+    CrtCiphertext, IntegerCiphertext, IntegerRadixCiphertext, OneHotCiphertext, RadixCiphertext,
     SignedRadixCiphertext,
 };
 pub use client_key::{ClientKey, CrtClientKey, RadixClientKey};
+pub use key_bundle::CompressedKeyBundle;
+#[cfg(feature = "key-bundle-builder")]
+pub use key_bundle_builder::{KeyBundleBuilder, KeyBundles};
+#[cfg(feature = "key-manifest")]
+pub use key_manifest::{KeyManifest, SignedKeyManifest};
 pub use public_key::{CompressedCompactPublicKey, CompressedPublicKey, PublicKey};
-pub use server_key::{CheckError, CompressedServerKey, ServerKey};
+pub use server_key::{
+    CheckError, CompressedServerKey, EncryptedHeap, MulAlgorithm, PartialSum, ServerKey,
+};
 
 /// Unless you know what you are doing you are likely looking for [`gen_keys_radix`] or
 /// [`gen_keys_crt`].
@@ -123,6 +123,56 @@ impl CompactPublicKey {
         }
     }
 
+    /// Encrypts booleans in a compact list, using a single block per boolean
+    ///
+    /// The list is meant to be expanded with
+    /// [CompactCiphertextList::expand_booleans_with], which guarantees that the expanded
+    /// blocks encrypt either 0 or 1.
+    pub fn encrypt_slice_bool_compact(&self, messages: &[bool]) -> CompactCiphertextList {
+        let ct_list = self
+            .key
+            .encrypt_iter(messages.iter().copied().map(u64::from));
+        CompactCiphertextList {
+            ct_list,
+            num_blocks_per_integer: 1,
+        }
+    }
+
+    pub fn encrypt_bool_compact(&self, message: bool) -> CompactCiphertextList {
+        self.encrypt_slice_bool_compact(&[message])
+    }
+
+    /// Re-randomizes a list encrypted under this key, see
+    /// [ShortintCompactPublicKey::rerandomize_compact_list]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{ClientKey, CompactPublicKey, RadixCiphertext};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS);
+    /// let pk = CompactPublicKey::new(&cks);
+    ///
+    /// let ballots = [1u8, 0, 3];
+    /// let list = pk.encrypt_slice_radix_compact(&ballots, 4);
+    ///
+    /// let reposted = pk.rerandomize_compact_list(&list);
+    ///
+    /// let expanded: Vec<RadixCiphertext> = reposted.expand();
+    /// let decrypted = expanded
+    ///     .iter()
+    ///     .map(|ct| cks.decrypt_radix::<u8>(ct))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(decrypted, ballots);
+    /// ```
+    pub fn rerandomize_compact_list(&self, list: &CompactCiphertextList) -> CompactCiphertextList {
+        CompactCiphertextList {
+            ct_list: self.key.rerandomize_compact_list(&list.ct_list),
+            num_blocks_per_integer: list.num_blocks_per_integer,
+        }
+    }
+
     pub fn size_elements(&self) -> usize {
         self.key.size_elements()
     }
@@ -1,6 +1,6 @@
 use rand::Rng;
 
-use crate::integer::{gen_keys, CompressedPublicKey, PublicKey};
+use crate::integer::{gen_keys, CompressedPublicKey, PublicKey, RadixCiphertext};
 use crate::shortint::parameters::*;
 use crate::shortint::ClassicPBSParameters;
 
@@ -27,6 +27,15 @@ create_parametrized_test!(small_radix_encrypt_decrypt_compact_128_bits_list {
     PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_PBS_KS
 });
 
+create_parametrized_test!(split_and_concat_compact_list {
+    PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS
+});
+
+create_parametrized_test!(bool_encrypt_expand_compact_list {
+    PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS,
+    PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_PBS_KS
+});
+
 /// Test that the public key can encrypt a 128 bit number
 /// in radix decomposition, and that the client key can decrypt it
 fn big_radix_encrypt_decrypt_128_bits(param: ClassicPBSParameters) {
@@ -103,5 +112,112 @@ fn radix_encrypt_decrypt_compact_128_bits_list(params: ClassicPBSParameters) {
             let decrypted: u128 = cks.decrypt_radix(ciphertext);
             assert_eq!(decrypted, clear);
         }
+
+        let iter = compact_encrypted_list.iter_expand::<RadixCiphertext>();
+        assert_eq!(iter.len(), num_ct_for_this_iter);
+        for (ciphertext, clear) in iter.zip(clear_vec.iter().copied()) {
+            let decrypted: u128 = cks.decrypt_radix(&ciphertext);
+            assert_eq!(decrypted, clear);
+        }
+
+        let start = rng.gen_range(0..=num_ct_for_this_iter);
+        let end = rng.gen_range(start..=num_ct_for_this_iter);
+        let ciphertext_vec: Vec<RadixCiphertext> = compact_encrypted_list.expand_range(start..end);
+        assert_eq!(ciphertext_vec.len(), end - start);
+        for (ciphertext, clear) in ciphertext_vec
+            .iter()
+            .zip(clear_vec[start..end].iter().copied())
+        {
+            let decrypted: u128 = cks.decrypt_radix(ciphertext);
+            assert_eq!(decrypted, clear);
+        }
+    }
+}
+
+fn bool_encrypt_expand_compact_list(params: ClassicPBSParameters) {
+    use crate::integer::ciphertext::{ExpandConfig, ExpandError};
+    use crate::shortint::ciphertext::Degree;
+
+    let (cks, sks) = KEY_CACHE.get_from_params(params);
+    let pk = crate::integer::public_key::CompactPublicKey::new(&cks);
+
+    let mut rng = rand::thread_rng();
+
+    const MAX_CT: usize = 20;
+
+    for _ in 0..5 {
+        let num_ct_for_this_iter = rng.gen_range(1..=MAX_CT);
+        let clear_vec = (0..num_ct_for_this_iter)
+            .map(|_| rng.gen::<bool>())
+            .collect::<Vec<_>>();
+
+        let compact_encrypted_list = pk.encrypt_slice_bool_compact(&clear_vec);
+
+        let config = ExpandConfig::new().with_sanitizing_key(&sks);
+        let ciphertext_vec = compact_encrypted_list.expand_booleans_with(config).unwrap();
+        assert_eq!(ciphertext_vec.len(), num_ct_for_this_iter);
+
+        for (ciphertext, clear) in ciphertext_vec.iter().zip(clear_vec.iter().copied()) {
+            assert_eq!(ciphertext.degree, Degree(1));
+            assert_eq!(cks.decrypt_one_block(ciphertext), u64::from(clear));
+        }
     }
+
+    // A list of multi-block integers cannot be expanded as booleans
+    let radix_list = pk.encrypt_slice_radix_compact(&[1u8, 0], 2);
+    let config = ExpandConfig::new().with_sanitizing_key(&sks);
+    assert!(matches!(
+        radix_list.expand_booleans_with(config),
+        Err(ExpandError::NonConformantList)
+    ));
+}
+
+fn split_and_concat_compact_list(params: ClassicPBSParameters) {
+    use crate::integer::ciphertext::{CompactCiphertextList, CompactListError};
+
+    let (cks, _) = KEY_CACHE.get_from_params(params);
+    let pk = crate::integer::public_key::CompactPublicKey::new(&cks);
+
+    let mut rng = rand::thread_rng();
+    let modulus = params.message_modulus.0 as u64;
+
+    let decrypt_list = |list: &CompactCiphertextList| {
+        list.expand::<RadixCiphertext>()
+            .iter()
+            .map(|ct| cks.decrypt_radix::<u64>(ct))
+            .collect::<Vec<_>>()
+    };
+
+    // With one block per integer, a bin of the list holds lwe_dimension integers
+    let bin_size = pk.key.key.lwe_dimension().0;
+    let clear_vec = (0..bin_size + 3)
+        .map(|_| rng.gen::<u64>() % modulus)
+        .collect::<Vec<_>>();
+    let list = pk.encrypt_slice_radix_compact(&clear_vec, 1);
+
+    // Splitting at the end of a bin
+    let (first, second) = list.split_at(bin_size).unwrap();
+    assert_eq!(decrypt_list(&first), clear_vec[..bin_size]);
+    assert_eq!(decrypt_list(&second), clear_vec[bin_size..]);
+
+    let merged = CompactCiphertextList::concat(&[first, second]).unwrap();
+    assert_eq!(decrypt_list(&merged), clear_vec);
+
+    // Splitting inside the last bin
+    let (first, second) = list.split_at(bin_size + 1).unwrap();
+    assert_eq!(first.ciphertext_count(), bin_size + 1);
+    assert_eq!(decrypt_list(&second), clear_vec[bin_size + 1..]);
+
+    // The integers after the index span two bins
+    assert_eq!(list.split_at(1).unwrap_err(), CompactListError::SplitBin);
+    assert_eq!(
+        list.split_at(clear_vec.len() + 1).unwrap_err(),
+        CompactListError::IndexOutOfBounds
+    );
+
+    // The first list does not fill its last bin
+    assert_eq!(
+        CompactCiphertextList::concat(&[first, second]).unwrap_err(),
+        CompactListError::SplitBin
+    );
 }
@@ -0,0 +1,146 @@
+//! Traits of the server side integer operations, independent of the backend doing them.
+//!
+//! Code written against these traits, rather than against the methods of a [ServerKey], can be
+//! instantiated with any backend implementing them. The [ServerKey] implements them for
+//! [RadixCiphertext](crate::integer::RadixCiphertext) and
+//! [SignedRadixCiphertext](crate::integer::SignedRadixCiphertext) with its parallelized
+//! operations.
+//!
+//! # Example
+//!
+//! ```rust
+//! use tfhe::integer::gen_keys_radix;
+//! use tfhe::integer::server_key::FheSelect;
+//! use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+//!
+//! // Written once for all the backends
+//! fn clamp<Sk, Ct>(sks: &Sk, value: &Ct, low: &Ct, high: &Ct) -> Ct
+//! where
+//!     Sk: FheSelect<Ct>,
+//! {
+//!     let is_too_low = sks.lt(value, low);
+//!     let value = sks.select(&is_too_low, low, value);
+//!     sks.min(&value, high)
+//! }
+//!
+//! // We have 4 * 2 = 8 bits of message
+//! let num_blocks = 4;
+//! let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+//!
+//! let value = cks.encrypt(230u8);
+//! let low = cks.encrypt(10u8);
+//! let high = cks.encrypt(200u8);
+//!
+//! let ct_res = clamp(&sks, &value, &low, &high);
+//! let dec: u8 = cks.decrypt(&ct_res);
+//! assert_eq!(dec, 200);
+//! ```
+
+use super::ServerKey;
+use crate::integer::ciphertext::IntegerRadixCiphertext;
+
+/// Additive operations on encrypted integers
+pub trait FheAdd<Ct> {
+    fn add(&self, lhs: &Ct, rhs: &Ct) -> Ct;
+
+    fn sub(&self, lhs: &Ct, rhs: &Ct) -> Ct;
+
+    fn neg(&self, ct: &Ct) -> Ct;
+}
+
+/// Multiplication of encrypted integers
+pub trait FheMul<Ct> {
+    fn mul(&self, lhs: &Ct, rhs: &Ct) -> Ct;
+}
+
+/// Comparisons of encrypted integers
+pub trait FheCompare<Ct> {
+    /// The encrypted result of a comparison
+    type Boolean;
+
+    fn eq(&self, lhs: &Ct, rhs: &Ct) -> Self::Boolean;
+
+    fn ne(&self, lhs: &Ct, rhs: &Ct) -> Self::Boolean;
+
+    fn lt(&self, lhs: &Ct, rhs: &Ct) -> Self::Boolean;
+
+    fn le(&self, lhs: &Ct, rhs: &Ct) -> Self::Boolean;
+
+    fn gt(&self, lhs: &Ct, rhs: &Ct) -> Self::Boolean;
+
+    fn ge(&self, lhs: &Ct, rhs: &Ct) -> Self::Boolean;
+
+    fn min(&self, lhs: &Ct, rhs: &Ct) -> Ct;
+
+    fn max(&self, lhs: &Ct, rhs: &Ct) -> Ct;
+}
+
+/// Selection between encrypted integers, with the result of a comparison as condition
+pub trait FheSelect<Ct>: FheCompare<Ct> {
+    /// Returns `if_true` if the condition encrypts true, `if_false` otherwise
+    fn select(&self, condition: &Self::Boolean, if_true: &Ct, if_false: &Ct) -> Ct;
+}
+
+impl<T: IntegerRadixCiphertext> FheAdd<T> for ServerKey {
+    fn add(&self, lhs: &T, rhs: &T) -> T {
+        self.add_parallelized(lhs, rhs)
+    }
+
+    fn sub(&self, lhs: &T, rhs: &T) -> T {
+        self.sub_parallelized(lhs, rhs)
+    }
+
+    fn neg(&self, ct: &T) -> T {
+        self.neg_parallelized(ct)
+    }
+}
+
+impl<T: IntegerRadixCiphertext> FheMul<T> for ServerKey {
+    fn mul(&self, lhs: &T, rhs: &T) -> T {
+        self.mul_parallelized(lhs, rhs)
+    }
+}
+
+impl<T: IntegerRadixCiphertext> FheCompare<T> for ServerKey {
+    /// The comparisons of the [ServerKey] return a ciphertext of the same type as their inputs,
+    /// encrypting 0 or 1
+    type Boolean = T;
+
+    fn eq(&self, lhs: &T, rhs: &T) -> T {
+        self.eq_parallelized(lhs, rhs)
+    }
+
+    fn ne(&self, lhs: &T, rhs: &T) -> T {
+        self.ne_parallelized(lhs, rhs)
+    }
+
+    fn lt(&self, lhs: &T, rhs: &T) -> T {
+        self.lt_parallelized(lhs, rhs)
+    }
+
+    fn le(&self, lhs: &T, rhs: &T) -> T {
+        self.le_parallelized(lhs, rhs)
+    }
+
+    fn gt(&self, lhs: &T, rhs: &T) -> T {
+        self.gt_parallelized(lhs, rhs)
+    }
+
+    fn ge(&self, lhs: &T, rhs: &T) -> T {
+        self.ge_parallelized(lhs, rhs)
+    }
+
+    fn min(&self, lhs: &T, rhs: &T) -> T {
+        self.min_parallelized(lhs, rhs)
+    }
+
+    fn max(&self, lhs: &T, rhs: &T) -> T {
+        self.max_parallelized(lhs, rhs)
+    }
+}
+
+impl<T: IntegerRadixCiphertext> FheSelect<T> for ServerKey {
+    fn select(&self, condition: &T, if_true: &T, if_false: &T) -> T {
+        self.if_then_else_parallelized(condition, if_true, if_false)
+    }
+}
@@ -37,9 +37,9 @@ pub struct Comparator<'a> {
 }
 
 impl<'a> Comparator<'a> {
-    const IS_INFERIOR: u64 = 0;
-    const IS_EQUAL: u64 = 1;
-    const IS_SUPERIOR: u64 = 2;
+    pub(crate) const IS_INFERIOR: u64 = 0;
+    pub(crate) const IS_EQUAL: u64 = 1;
+    pub(crate) const IS_SUPERIOR: u64 = 2;
 
     /// Creates a new Comparator for the given ServerKey
     ///
@@ -250,7 +250,7 @@ impl<'a> Comparator<'a> {
     /// Reduces a vec containing shortint blocks that encrypts a sign
     /// (inferior, equal, superior) to one single shortint block containing the
     /// final sign
-    fn reduce_signs_parallelized(
+    pub(crate) fn reduce_signs_parallelized(
         &self,
         mut sign_blocks: Vec<crate::shortint::Ciphertext>,
     ) -> crate::shortint::Ciphertext {
@@ -941,6 +941,15 @@ impl<'a> Comparator<'a> {
         self.unchecked_min_or_max_parallelized(lhs, rhs, selector)
     }
 
+    /// Returns the [Ordering](std::cmp::Ordering) encoded by the decrypted sign of a comparison
+    pub(crate) fn sign_to_ordering(sign: u64) -> std::cmp::Ordering {
+        match sign {
+            Self::IS_INFERIOR => std::cmp::Ordering::Less,
+            Self::IS_EQUAL => std::cmp::Ordering::Equal,
+            _ => std::cmp::Ordering::Greater,
+        }
+    }
+
     /// Takes a block encrypting a sign resulting from
     /// unchecked_sign / unchecked_sign_parallelized.
     ///
@@ -949,7 +958,7 @@ impl<'a> Comparator<'a> {
     /// a boolean value.
     fn map_sign_result<T, F>(
         &self,
-        comparison: crate::shortint::Ciphertext,
+        comparison: &crate::shortint::Ciphertext,
         sign_result_handler_fn: F,
         num_blocks: usize,
     ) -> T
@@ -961,7 +970,7 @@ impl<'a> Comparator<'a> {
             .server_key
             .key
             .generate_lookup_table(|x| u64::from(sign_result_handler_fn(x)));
-        let result_block = self.server_key.key.apply_lookup_table(&comparison, &acc);
+        let result_block = self.server_key.key.apply_lookup_table(comparison, &acc);
 
         let mut blocks = Vec::with_capacity(num_blocks);
         blocks.push(result_block);
@@ -988,7 +997,7 @@ impl<'a> Comparator<'a> {
         F: Fn(u64) -> bool,
     {
         let comparison = comparison_fn(self, lhs, rhs);
-        self.map_sign_result(comparison, sign_result_handler_fn, lhs.blocks().len())
+        self.map_sign_result(&comparison, sign_result_handler_fn, lhs.blocks().len())
     }
 
     /// Helper function to implement smart_lt, smart_ge, etc
@@ -1005,7 +1014,7 @@ impl<'a> Comparator<'a> {
         F: Fn(u64) -> bool,
     {
         let comparison = smart_comparison_fn(self, lhs, rhs);
-        self.map_sign_result(comparison, sign_result_handler_fn, lhs.blocks().len())
+        self.map_sign_result(&comparison, sign_result_handler_fn, lhs.blocks().len())
     }
 
     //======================================
@@ -1140,6 +1149,86 @@ impl<'a> Comparator<'a> {
         self.unchecked_min_or_max_parallelized(lhs, rhs, MinMaxSelector::Min)
     }
 
+    /// Returns a block encrypting the ordering of `lhs` compared to `rhs`
+    ///
+    /// The block can be converted to the result of any comparison with
+    /// [Self::ordering_matches], or used to select between 3 values with
+    /// [Self::ordering_select_parallelized], so that the comparison circuit is only run once.
+    pub fn unchecked_cmp_parallelized<T>(&self, lhs: &T, rhs: &T) -> Ciphertext
+    where
+        T: IntegerRadixCiphertext,
+    {
+        self.unchecked_compare_parallelized(lhs, rhs)
+    }
+
+    /// Returns a radix ciphertext of `num_blocks` blocks encrypting 1 if the ordering encrypted
+    /// by `ordering` satisfies `predicate`, 0 otherwise
+    ///
+    /// This costs a single PBS.
+    pub fn ordering_matches<T, F>(
+        &self,
+        ordering: &Ciphertext,
+        predicate: F,
+        num_blocks: usize,
+    ) -> T
+    where
+        T: IntegerRadixCiphertext,
+        F: Fn(std::cmp::Ordering) -> bool,
+    {
+        self.map_sign_result(
+            ordering,
+            |sign| predicate(Self::sign_to_ordering(sign)),
+            num_blocks,
+        )
+    }
+
+    /// Returns a ciphertext encrypting the same value as `if_less`, `if_equal` or `if_greater`
+    /// depending on the ordering encrypted by `ordering`
+    pub fn ordering_select_parallelized<T>(
+        &self,
+        ordering: &Ciphertext,
+        if_less: &T,
+        if_equal: &T,
+        if_greater: &T,
+    ) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut ct_clones = [None, None, None];
+        let mut ct_refs = [if_less, if_equal, if_greater];
+
+        ct_refs
+            .par_iter_mut()
+            .zip(ct_clones.par_iter_mut())
+            .for_each(|(ct_ref, ct_clone)| {
+                if !ct_ref.block_carries_are_empty() {
+                    let mut cloned = ct_ref.clone();
+                    self.server_key.full_propagate_parallelized(&mut cloned);
+                    *ct_ref = ct_clone.insert(cloned);
+                }
+            });
+
+        let [if_less, if_equal, if_greater] = ct_refs;
+        let do_clean_message = true;
+        let if_not_less = self
+            .server_key
+            .unchecked_programmable_if_then_else_parallelized(
+                ordering,
+                if_greater,
+                if_equal,
+                |sign| sign == Self::IS_SUPERIOR,
+                do_clean_message,
+            );
+        self.server_key
+            .unchecked_programmable_if_then_else_parallelized(
+                ordering,
+                if_less,
+                &if_not_less,
+                |sign| sign == Self::IS_INFERIOR,
+                do_clean_message,
+            )
+    }
+
     //======================================
     // Smart Single-Threaded operations
     //======================================
@@ -1460,6 +1549,42 @@ impl<'a> Comparator<'a> {
         self.unchecked_min_parallelized(lhs, rhs)
     }
 
+    /// Returns a block encrypting the ordering of `lhs` compared to `rhs`
+    ///
+    /// See [Self::unchecked_cmp_parallelized]
+    pub fn cmp_parallelized<T>(&self, lhs: &T, rhs: &T) -> Ciphertext
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp_lhs;
+        let mut tmp_rhs;
+
+        let (lhs, rhs) = match (lhs.block_carries_are_empty(), rhs.block_carries_are_empty()) {
+            (true, true) => (lhs, rhs),
+            (true, false) => {
+                tmp_rhs = rhs.clone();
+                self.server_key.full_propagate_parallelized(&mut tmp_rhs);
+                (lhs, &tmp_rhs)
+            }
+            (false, true) => {
+                tmp_lhs = lhs.clone();
+                self.server_key.full_propagate_parallelized(&mut tmp_lhs);
+                (&tmp_lhs, rhs)
+            }
+            (false, false) => {
+                tmp_lhs = lhs.clone();
+                tmp_rhs = rhs.clone();
+                rayon::join(
+                    || self.server_key.full_propagate_parallelized(&mut tmp_lhs),
+                    || self.server_key.full_propagate_parallelized(&mut tmp_rhs),
+                );
+                (&tmp_lhs, &tmp_rhs)
+            }
+        };
+
+        self.unchecked_cmp_parallelized(lhs, rhs)
+    }
+
     //===========================================
     // Unchecked Scalar Multi-Threaded operations
     //===========================================
@@ -1479,7 +1604,7 @@ impl<'a> Comparator<'a> {
         F: Fn(u64) -> bool + Sync,
     {
         let sign_block = self.unchecked_scalar_compare_parallelized(lhs, rhs);
-        self.map_sign_result(sign_block, sign_result_handler_fn, lhs.blocks().len())
+        self.map_sign_result(&sign_block, sign_result_handler_fn, lhs.blocks().len())
     }
 
     pub fn unchecked_scalar_gt_parallelized<T, Scalar>(&self, lhs: &T, rhs: Scalar) -> T
@@ -1534,6 +1659,17 @@ impl<'a> Comparator<'a> {
         self.unchecked_scalar_min_or_max_parallelized(lhs, rhs, MinMaxSelector::Min)
     }
 
+    /// Returns a block encrypting the ordering of `lhs` compared to the scalar `rhs`
+    ///
+    /// See [Self::unchecked_cmp_parallelized]
+    pub fn unchecked_scalar_cmp_parallelized<T, Scalar>(&self, lhs: &T, rhs: Scalar) -> Ciphertext
+    where
+        T: IntegerRadixCiphertext,
+        Scalar: DecomposableInto<u64>,
+    {
+        self.unchecked_scalar_compare_parallelized(lhs, rhs)
+    }
+
     //=======================================
     // Smart Scalar Multi-Threaded operations
     //=======================================
@@ -1706,4 +1842,23 @@ impl<'a> Comparator<'a> {
         };
         self.unchecked_scalar_min_or_max_parallelized(lhs, rhs, MinMaxSelector::Min)
     }
+
+    /// Returns a block encrypting the ordering of `lhs` compared to the scalar `rhs`
+    ///
+    /// See [Self::unchecked_cmp_parallelized]
+    pub fn scalar_cmp_parallelized<T, Scalar>(&self, lhs: &T, rhs: Scalar) -> Ciphertext
+    where
+        T: IntegerRadixCiphertext,
+        Scalar: DecomposableInto<u64>,
+    {
+        let mut tmp_lhs;
+        let lhs = if !lhs.block_carries_are_empty() {
+            tmp_lhs = lhs.clone();
+            self.server_key.full_propagate_parallelized(&mut tmp_lhs);
+            &tmp_lhs
+        } else {
+            lhs
+        };
+        self.unchecked_scalar_cmp_parallelized(lhs, rhs)
+    }
 }
@@ -2,6 +2,7 @@
 //!
 //! This module implements the generation of the server public key, together with all the
 //! available homomorphic integer operations.
+pub mod backend;
 pub mod comparator;
 mod crt;
 mod crt_parallel;
@@ -9,14 +10,36 @@ mod radix;
 pub(crate) mod radix_parallel;
 
 use crate::integer::client_key::ClientKey;
-use crate::shortint::server_key::MaxDegree;
+use crate::shortint::server_key::{MaxDegree, PbsBackend};
 use serde::{Deserialize, Serialize};
+use std::ops::ControlFlow;
 
 /// Error returned when the carry buffer is full.
 pub use crate::shortint::CheckError;
+pub use backend::{FheAdd, FheCompare, FheMul, FheSelect};
 pub use radix::scalar_mul::ScalarMultiplier;
 pub use radix::scalar_sub::TwosComplementNegation;
-pub use radix_parallel::{MiniUnsignedInteger, Reciprocable};
+pub use radix_parallel::{
+    EncryptedHeap, MiniUnsignedInteger, PartialSum, Reciprocable, RoundingMode,
+};
+
+/// Algorithm used by the radix multiplications of a [`ServerKey`].
+///
+/// The algorithm is a choice of the server and is not part of the key material, it is not
+/// serialized with the key.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum MulAlgorithm {
+    /// All partial products are accumulated without propagating carries in between, carries are
+    /// only fully propagated once at the end.
+    #[default]
+    CarrySave,
+    /// Each partial product is added to the result with a full carry propagation.
+    Schoolbook,
+    /// Operands of 128 bits or more are split in halves, and full products of halves are
+    /// computed with 3 half size products instead of 4. Smaller products use
+    /// [`MulAlgorithm::CarrySave`].
+    Karatsuba,
+}
 
 /// A structure containing the server public key.
 ///
@@ -25,6 +48,8 @@ pub use radix_parallel::{MiniUnsignedInteger, Reciprocable};
 #[derive(Serialize, Deserialize, Clone)]
 pub struct ServerKey {
     pub(crate) key: crate::shortint::ServerKey,
+    #[serde(skip)]
+    pub(crate) mul_algorithm: MulAlgorithm,
 }
 
 impl From<ServerKey> for crate::shortint::ServerKey {
@@ -74,7 +99,72 @@ impl ServerKey {
             max_degree,
         );
 
-        ServerKey { key: sks }
+        ServerKey {
+            key: sks,
+            mul_algorithm: MulAlgorithm::default(),
+        }
+    }
+
+    /// Generates a server key, calling `on_ggsw_encrypted` each time one of the GGSW ciphertexts
+    /// of the bootstrapping key is encrypted.
+    ///
+    /// Returns `None` if `on_ggsw_encrypted` stopped the generation.
+    pub(crate) fn new_with_progress(
+        cks: &ClientKey,
+        on_ggsw_encrypted: &(dyn Fn() -> ControlFlow<()> + Sync),
+    ) -> Option<ServerKey> {
+        let max_degree = integer_server_key_max_degree(cks.key.parameters);
+
+        let sks = crate::shortint::server_key::ServerKey::new_with_max_degree_and_progress(
+            &cks.key,
+            max_degree,
+            on_ggsw_encrypted,
+        )?;
+
+        Some(ServerKey {
+            key: sks,
+            mul_algorithm: MulAlgorithm::default(),
+        })
+    }
+
+    /// Generates a server key whose programmable bootstraps use the chosen [`PbsBackend`], see
+    /// [`crate::shortint::ServerKey::new_with_pbs_backend`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{gen_keys_radix, ServerKey};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::shortint::PbsBackend;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, _) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    /// let sks = ServerKey::new_with_pbs_backend(&cks, PbsBackend::Ntt);
+    ///
+    /// let ct1 = cks.encrypt(27u64);
+    /// let ct2 = cks.encrypt(100u64);
+    /// let ct_res = sks.mul_parallelized(&ct1, &ct2);
+    ///
+    /// let res: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, (27 * 100) % 256);
+    /// ```
+    pub fn new_with_pbs_backend<C>(cks: C, pbs_backend: PbsBackend) -> ServerKey
+    where
+        C: AsRef<ClientKey>,
+    {
+        let client_key = cks.as_ref();
+        let max_degree = integer_server_key_max_degree(client_key.key.parameters);
+
+        let sks = crate::shortint::server_key::ServerKey::new_with_max_degree_and_pbs_backend(
+            &client_key.key,
+            max_degree,
+            pbs_backend,
+        );
+
+        ServerKey {
+            key: sks,
+            mul_algorithm: MulAlgorithm::default(),
+        }
     }
 
     /// Creates a ServerKey from an already generated shortint::ServerKey.
@@ -101,7 +191,31 @@ impl ServerKey {
         let max_degree = integer_server_key_max_degree(cks.key.parameters);
 
         key.max_degree = max_degree;
-        ServerKey { key }
+        ServerKey {
+            key,
+            mul_algorithm: MulAlgorithm::default(),
+        }
+    }
+
+    /// Creates a ServerKey from a shortint::ServerKey assembled from raw parts, see
+    /// [`crate::shortint::ServerKey::from_raw_parts`].
+    ///
+    /// As with [`Self::from_shortint`], the max degree of the key is lowered so that it leaves
+    /// enough space to add a carry to a block.
+    pub fn from_raw_parts(mut key: crate::shortint::ServerKey) -> Self {
+        let full_max_degree = key.message_modulus.0 * key.carry_modulus.0 - 1;
+        let carry_max_degree = key.carry_modulus.0 - 1;
+        // It should remain just enough space add a carry, unless a lower max degree was set
+        key.max_degree = MaxDegree(key.max_degree.0.min(full_max_degree - carry_max_degree));
+
+        Self {
+            key,
+            mul_algorithm: MulAlgorithm::default(),
+        }
+    }
+
+    pub fn into_raw_parts(self) -> crate::shortint::ServerKey {
+        self.key
     }
 
     pub fn deterministic_pbs_execution(&self) -> bool {
@@ -112,6 +226,15 @@ impl ServerKey {
         self.key
             .set_deterministic_pbs_execution(new_deterministic_execution)
     }
+
+    pub fn mul_algorithm(&self) -> MulAlgorithm {
+        self.mul_algorithm
+    }
+
+    /// Sets the algorithm used by the radix multiplications (default, smart and unchecked)
+    pub fn set_mul_algorithm(&mut self, mul_algorithm: MulAlgorithm) {
+        self.mul_algorithm = mul_algorithm;
+    }
 }
 
 #[derive(Clone, Serialize, Deserialize)]
@@ -132,7 +255,10 @@ impl CompressedServerKey {
 impl From<CompressedServerKey> for ServerKey {
     fn from(compressed: CompressedServerKey) -> Self {
         let key = compressed.key.into();
-        Self { key }
+        Self {
+            key,
+            mul_algorithm: MulAlgorithm::default(),
+        }
     }
 }
 
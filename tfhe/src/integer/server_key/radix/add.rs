@@ -106,6 +106,14 @@ impl ServerKey {
     /// assert_eq!(true, res);
     /// ```
     pub fn is_add_possible<T>(&self, ct_left: &T, ct_right: &T) -> bool
+    where
+        T: IntegerRadixCiphertext,
+    {
+        self.check_add_possible(ct_left, ct_right).is_ok()
+    }
+
+    /// Verify if the ciphertexts can be added together, returning the reason otherwise.
+    pub(crate) fn check_add_possible<T>(&self, ct_left: &T, ct_right: &T) -> Result<(), CheckError>
     where
         T: IntegerRadixCiphertext,
     {
@@ -119,11 +127,15 @@ impl ServerKey {
                 >= (left_block.message_modulus.0 * left_block.carry_modulus.0)
             {
                 // We would exceed the block 'capacity'
-                return false;
+                return Err(CarryFull);
             }
             preceding_block_carry = degree_after_add / left_block.message_modulus.0;
+
+            self.key
+                .max_noise_level
+                .validate(left_block.noise_level + right_block.noise_level)?;
         }
-        true
+        Ok(())
     }
 
     /// Computes homomorphically an addition between two ciphertexts encrypting integer values.
@@ -162,11 +174,8 @@ impl ServerKey {
     where
         T: IntegerRadixCiphertext,
     {
-        if self.is_add_possible(ct_left, ct_right) {
-            Ok(self.unchecked_add(ct_left, ct_right))
-        } else {
-            Err(CarryFull)
-        }
+        self.check_add_possible(ct_left, ct_right)?;
+        Ok(self.unchecked_add(ct_left, ct_right))
     }
 
     /// Computes homomorphically an addition between two ciphertexts encrypting integer values.
@@ -202,12 +211,9 @@ impl ServerKey {
     where
         T: IntegerRadixCiphertext,
     {
-        if self.is_add_possible(ct_left, ct_right) {
-            self.unchecked_add_assign(ct_left, ct_right);
-            Ok(())
-        } else {
-            Err(CarryFull)
-        }
+        self.check_add_possible(ct_left, ct_right)?;
+        self.unchecked_add_assign(ct_left, ct_right);
+        Ok(())
     }
 
     /// Computes homomorphically an addition between two ciphertexts encrypting integer values.
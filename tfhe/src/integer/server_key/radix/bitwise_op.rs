@@ -1,7 +1,6 @@
 use crate::integer::ciphertext::IntegerRadixCiphertext;
 use crate::integer::ServerKey;
 use crate::shortint::CheckError;
-use crate::shortint::CheckError::CarryFull;
 
 impl ServerKey {
     /// Computes homomorphically bitand between two ciphertexts encrypting integer values.
@@ -80,18 +79,28 @@ impl ServerKey {
     /// assert_eq!(true, res);
     /// ```
     pub fn is_functional_bivariate_pbs_possible<T>(&self, ct_left: &T, ct_right: &T) -> bool
+    where
+        T: IntegerRadixCiphertext,
+    {
+        self.check_functional_bivariate_pbs_possible(ct_left, ct_right)
+            .is_ok()
+    }
+
+    /// Verify if a bivariate functional PBS can be applied on all the pairs of blocks, returning
+    /// the reason otherwise.
+    pub(crate) fn check_functional_bivariate_pbs_possible<T>(
+        &self,
+        ct_left: &T,
+        ct_right: &T,
+    ) -> Result<(), CheckError>
     where
         T: IntegerRadixCiphertext,
     {
         for (ct_left_i, ct_right_i) in ct_left.blocks().iter().zip(ct_right.blocks().iter()) {
-            if !self
-                .key
-                .is_functional_bivariate_pbs_possible(ct_left_i, ct_right_i)
-            {
-                return false;
-            }
+            self.key
+                .check_functional_bivariate_pbs_possible(ct_left_i, ct_right_i)?;
         }
-        true
+        Ok(())
     }
 
     /// Computes homomorphically a bitand between two ciphertexts encrypting integer values.
@@ -130,11 +139,8 @@ impl ServerKey {
     where
         T: IntegerRadixCiphertext,
     {
-        if self.is_functional_bivariate_pbs_possible(ct_left, ct_right) {
-            Ok(self.unchecked_bitand(ct_left, ct_right))
-        } else {
-            Err(CarryFull)
-        }
+        self.check_functional_bivariate_pbs_possible(ct_left, ct_right)?;
+        Ok(self.unchecked_bitand(ct_left, ct_right))
     }
 
     /// Computes homomorphically a bitand between two ciphertexts encrypting integer values.
@@ -170,12 +176,9 @@ impl ServerKey {
     where
         T: IntegerRadixCiphertext,
     {
-        if self.is_functional_bivariate_pbs_possible(ct_left, ct_right) {
-            self.unchecked_bitand_assign(ct_left, ct_right);
-            Ok(())
-        } else {
-            Err(CarryFull)
-        }
+        self.check_functional_bivariate_pbs_possible(ct_left, ct_right)?;
+        self.unchecked_bitand_assign(ct_left, ct_right);
+        Ok(())
     }
 
     /// Computes homomorphically a bitand between two ciphertexts encrypting integer values.
@@ -316,11 +319,8 @@ impl ServerKey {
     where
         T: IntegerRadixCiphertext,
     {
-        if self.is_functional_bivariate_pbs_possible(ct_left, ct_right) {
-            Ok(self.unchecked_bitor(ct_left, ct_right))
-        } else {
-            Err(CarryFull)
-        }
+        self.check_functional_bivariate_pbs_possible(ct_left, ct_right)?;
+        Ok(self.unchecked_bitor(ct_left, ct_right))
     }
 
     /// Computes homomorphically a bitand between two ciphertexts encrypting integer values.
@@ -357,12 +357,9 @@ impl ServerKey {
     where
         T: IntegerRadixCiphertext,
     {
-        if self.is_functional_bivariate_pbs_possible(ct_left, ct_right) {
-            self.unchecked_bitor_assign(ct_left, ct_right);
-            Ok(())
-        } else {
-            Err(CarryFull)
-        }
+        self.check_functional_bivariate_pbs_possible(ct_left, ct_right)?;
+        self.unchecked_bitor_assign(ct_left, ct_right);
+        Ok(())
     }
 
     /// Computes homomorphically a bitor between two ciphertexts encrypting integer values.
@@ -505,11 +502,8 @@ impl ServerKey {
     where
         T: IntegerRadixCiphertext,
     {
-        if self.is_functional_bivariate_pbs_possible(ct_left, ct_right) {
-            Ok(self.unchecked_bitxor(ct_left, ct_right))
-        } else {
-            Err(CarryFull)
-        }
+        self.check_functional_bivariate_pbs_possible(ct_left, ct_right)?;
+        Ok(self.unchecked_bitxor(ct_left, ct_right))
     }
 
     /// Computes homomorphically a bitxor between two ciphertexts encrypting integer values.
@@ -546,12 +540,9 @@ impl ServerKey {
     where
         T: IntegerRadixCiphertext,
     {
-        if self.is_functional_bivariate_pbs_possible(ct_left, ct_right) {
-            self.unchecked_bitxor_assign(ct_left, ct_right);
-            Ok(())
-        } else {
-            Err(CarryFull)
-        }
+        self.check_functional_bivariate_pbs_possible(ct_left, ct_right)?;
+        self.unchecked_bitxor_assign(ct_left, ct_right);
+        Ok(())
     }
 
     /// Computes homomorphically a bitxor between two ciphertexts encrypting integer values.
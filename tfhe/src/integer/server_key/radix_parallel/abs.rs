@@ -1,4 +1,5 @@
 use crate::integer::ciphertext::IntegerRadixCiphertext;
+use crate::integer::server_key::comparator::ZeroComparisonType;
 use crate::integer::ServerKey;
 
 impl ServerKey {
@@ -45,4 +46,220 @@ impl ServerKey {
             self.unchecked_abs_parallelized(&cloned)
         }
     }
+
+    /// Computes homomorphically the sign of a ciphertext
+    ///
+    /// The result encrypts -1 if the input is negative, 0 if it is zero and 1
+    /// if it is positive. For unsigned ciphertexts, the result is either 0 or 1.
+    ///
+    /// # Requirements
+    ///
+    /// - The input must have its carries empty
+    pub fn unchecked_signum_parallelized<T>(&self, ct: &T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let num_blocks = ct.blocks().len();
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let sign_bit_pos = message_modulus.ilog2() - 1;
+        let all_ones = message_modulus - 1;
+
+        let compute_is_non_zero = || {
+            let comparisons =
+                self.compare_blocks_with_zero(ct.blocks(), ZeroComparisonType::Difference);
+            self.is_at_least_one_comparisons_block_true(comparisons)
+        };
+
+        if !T::IS_SIGNED {
+            let mut blocks = vec![compute_is_non_zero()];
+            blocks.resize_with(num_blocks, || self.key.create_trivial(0));
+            return T::from(blocks);
+        }
+
+        let last_block = &ct.blocks()[num_blocks - 1];
+        let (is_non_zero, upper_block) = rayon::join(compute_is_non_zero, || {
+            // The upper blocks of -1 are all ones, the ones of 0 and 1 are all zeros
+            let lut = self.key.generate_lookup_table(|x| {
+                let is_negative = (x >> sign_bit_pos) & 1 == 1;
+                if is_negative {
+                    all_ones
+                } else {
+                    0
+                }
+            });
+            self.key.apply_lookup_table(last_block, &lut)
+        });
+
+        let lut = self
+            .key
+            .generate_lookup_table_bivariate(|last_block, is_non_zero| {
+                let is_negative = (last_block >> sign_bit_pos) & 1 == 1;
+                if is_negative {
+                    all_ones
+                } else {
+                    is_non_zero
+                }
+            });
+        let first_block =
+            self.key
+                .unchecked_apply_lookup_table_bivariate(last_block, &is_non_zero, &lut);
+
+        let mut blocks = Vec::with_capacity(num_blocks);
+        blocks.push(first_block);
+        blocks.resize(num_blocks, upper_block);
+        T::from(blocks)
+    }
+
+    /// Computes homomorphically the sign of a ciphertext
+    ///
+    /// The result encrypts -1 if the input is negative, 0 if it is zero and 1
+    /// if it is positive. For unsigned ciphertexts, the result is either 0 or 1.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// for msg in [-37i8, 0, 91] {
+    ///     let ct = cks.encrypt_signed(msg);
+    ///
+    ///     let ct_res = sks.signum_parallelized(&ct);
+    ///
+    ///     let dec: i8 = cks.decrypt_signed(&ct_res);
+    ///     assert_eq!(dec, msg.signum());
+    /// }
+    /// ```
+    pub fn signum_parallelized<T>(&self, ct: &T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if ct.block_carries_are_empty() {
+            self.unchecked_signum_parallelized(ct)
+        } else {
+            let mut cloned = ct.clone();
+            self.full_propagate_parallelized(&mut cloned);
+            self.unchecked_signum_parallelized(&cloned)
+        }
+    }
+
+    /// Computes homomorphically a ciphertext with the magnitude of `magnitude`
+    /// and the sign of `sign`
+    ///
+    /// As for [Self::unchecked_abs_parallelized], the magnitude of the minimum value
+    /// cannot be represented, so the minimum value is returned as is.
+    ///
+    /// For unsigned ciphertexts, `magnitude` is returned.
+    ///
+    /// # Requirements
+    ///
+    /// - The inputs must have their carries empty
+    pub fn unchecked_copysign_parallelized<T>(&self, magnitude: &T, sign: &T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        assert_eq!(
+            magnitude.blocks().len(),
+            sign.blocks().len(),
+            "magnitude and sign must have the same number of blocks"
+        );
+
+        if !T::IS_SIGNED {
+            return magnitude.clone();
+        }
+
+        // Same 'bit-hack' as abs, but the mask is all ones only when
+        // the signs of the two inputs differ, which is computed in one bivariate PBS
+        let num_blocks = magnitude.blocks().len();
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let sign_bit_pos = message_modulus.ilog2() - 1;
+        let all_ones = message_modulus - 1;
+
+        let lut = self.key.generate_lookup_table_bivariate(|x, y| {
+            let x_sign = (x >> sign_bit_pos) & 1;
+            let y_sign = (y >> sign_bit_pos) & 1;
+            if x_sign == y_sign {
+                0
+            } else {
+                all_ones
+            }
+        });
+        let mask_block = self.key.unchecked_apply_lookup_table_bivariate(
+            &magnitude.blocks()[num_blocks - 1],
+            &sign.blocks()[num_blocks - 1],
+            &lut,
+        );
+        let mask = T::from(vec![mask_block; num_blocks]);
+
+        let mut result = self.add_parallelized(magnitude, &mask);
+        self.bitxor_assign_parallelized(&mut result, &mask);
+        result
+    }
+
+    /// Computes homomorphically a ciphertext with the magnitude of `magnitude`
+    /// and the sign of `sign`
+    ///
+    /// As for [Self::abs_parallelized], the magnitude of the minimum value
+    /// cannot be represented, so the minimum value is returned as is.
+    ///
+    /// For unsigned ciphertexts, `magnitude` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg = 37i8;
+    /// let ct = cks.encrypt_signed(msg);
+    /// let sign = cks.encrypt_signed(-5i8);
+    ///
+    /// let ct_res = sks.copysign_parallelized(&ct, &sign);
+    ///
+    /// let dec: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec, -msg);
+    /// ```
+    pub fn copysign_parallelized<T>(&self, magnitude: &T, sign: &T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp_magnitude;
+        let mut tmp_sign;
+
+        let (magnitude, sign) = match (
+            magnitude.block_carries_are_empty(),
+            sign.block_carries_are_empty(),
+        ) {
+            (true, true) => (magnitude, sign),
+            (true, false) => {
+                tmp_sign = sign.clone();
+                self.full_propagate_parallelized(&mut tmp_sign);
+                (magnitude, &tmp_sign)
+            }
+            (false, true) => {
+                tmp_magnitude = magnitude.clone();
+                self.full_propagate_parallelized(&mut tmp_magnitude);
+                (&tmp_magnitude, sign)
+            }
+            (false, false) => {
+                tmp_magnitude = magnitude.clone();
+                tmp_sign = sign.clone();
+                rayon::join(
+                    || self.full_propagate_parallelized(&mut tmp_magnitude),
+                    || self.full_propagate_parallelized(&mut tmp_sign),
+                );
+                (&tmp_magnitude, &tmp_sign)
+            }
+        };
+
+        self.unchecked_copysign_parallelized(magnitude, sign)
+    }
 }
@@ -281,6 +281,196 @@ impl ServerKey {
         self.unchecked_add_assign_parallelized_work_efficient(lhs, rhs);
     }
 
+    /// Computes homomorphically the addition of two ciphertexts and returns the carry
+    /// coming out of the most significant block.
+    ///
+    /// The returned carry is a single block encrypting either 0 or 1, which means
+    /// the overflow of the addition can be known without having to extend the inputs
+    /// with an extra block.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg_1 = 200u8;
+    /// let msg_2 = 100u8;
+    ///
+    /// let ctxt_1 = cks.encrypt(msg_1);
+    /// let ctxt_2 = cks.encrypt(msg_2);
+    ///
+    /// // Compute homomorphically an addition:
+    /// let (result, carry) = sks.add_with_carry_out_parallelized(&ctxt_1, &ctxt_2);
+    ///
+    /// // Decrypt:
+    /// let decrypted_result: u8 = cks.decrypt(&result);
+    /// let decrypted_carry = cks.decrypt_one_block(&carry) == 1;
+    ///
+    /// let (expected_result, expected_carry) = msg_1.overflowing_add(msg_2);
+    /// assert_eq!(expected_result, decrypted_result);
+    /// assert_eq!(expected_carry, decrypted_carry);
+    /// ```
+    pub fn add_with_carry_out_parallelized(
+        &self,
+        ctxt_left: &RadixCiphertext,
+        ctxt_right: &RadixCiphertext,
+    ) -> (RadixCiphertext, Ciphertext) {
+        let carry_in = self.key.create_trivial(0);
+        self.add_with_carry_in_parallelized(ctxt_left, ctxt_right, &carry_in)
+    }
+
+    pub fn unchecked_add_with_carry_out_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> (RadixCiphertext, Ciphertext) {
+        let carry_in = self.key.create_trivial(0);
+        self.unchecked_add_with_carry_in_parallelized(lhs, rhs, &carry_in)
+    }
+
+    /// Computes homomorphically `lhs + rhs + carry_in` and returns the carry
+    /// coming out of the most significant block.
+    ///
+    /// `carry_in` must be a block encrypting either 0 or 1, typically the carry
+    /// returned by a previous call to this function or to
+    /// [Self::add_with_carry_out_parallelized].
+    ///
+    /// This allows to chain additions of ciphertexts representing the limbs of
+    /// a bigger integer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Each limb has 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg_1 = 45_000u16;
+    /// let msg_2 = 30_000u16;
+    ///
+    /// // Encrypt the low and high limbs of each message
+    /// let (lhs_lo, lhs_hi) = (cks.encrypt(msg_1 & 0xFF), cks.encrypt(msg_1 >> 8));
+    /// let (rhs_lo, rhs_hi) = (cks.encrypt(msg_2 & 0xFF), cks.encrypt(msg_2 >> 8));
+    ///
+    /// // Compute homomorphically a 16 bits addition from 8 bits limbs:
+    /// let (res_lo, carry) = sks.add_with_carry_out_parallelized(&lhs_lo, &rhs_lo);
+    /// let (res_hi, carry) = sks.add_with_carry_in_parallelized(&lhs_hi, &rhs_hi, &carry);
+    ///
+    /// // Decrypt:
+    /// let dec_lo: u16 = cks.decrypt(&res_lo);
+    /// let dec_hi: u16 = cks.decrypt(&res_hi);
+    /// let decrypted_carry = cks.decrypt_one_block(&carry) == 1;
+    ///
+    /// let (expected_result, expected_carry) = msg_1.overflowing_add(msg_2);
+    /// assert_eq!(expected_result, (dec_hi << 8) | dec_lo);
+    /// assert_eq!(expected_carry, decrypted_carry);
+    /// ```
+    pub fn add_with_carry_in_parallelized(
+        &self,
+        ctxt_left: &RadixCiphertext,
+        ctxt_right: &RadixCiphertext,
+        carry_in: &Ciphertext,
+    ) -> (RadixCiphertext, Ciphertext) {
+        let mut tmp_lhs;
+        let mut tmp_rhs;
+
+        let (lhs, rhs) = match (
+            ctxt_left.block_carries_are_empty(),
+            ctxt_right.block_carries_are_empty(),
+        ) {
+            (true, true) => (ctxt_left, ctxt_right),
+            (true, false) => {
+                tmp_rhs = ctxt_right.clone();
+                self.full_propagate_parallelized(&mut tmp_rhs);
+                (ctxt_left, &tmp_rhs)
+            }
+            (false, true) => {
+                tmp_lhs = ctxt_left.clone();
+                self.full_propagate_parallelized(&mut tmp_lhs);
+                (&tmp_lhs, ctxt_right)
+            }
+            (false, false) => {
+                tmp_lhs = ctxt_left.clone();
+                tmp_rhs = ctxt_right.clone();
+                rayon::join(
+                    || self.full_propagate_parallelized(&mut tmp_lhs),
+                    || self.full_propagate_parallelized(&mut tmp_rhs),
+                );
+                (&tmp_lhs, &tmp_rhs)
+            }
+        };
+
+        self.unchecked_add_with_carry_in_parallelized(lhs, rhs, carry_in)
+    }
+
+    /// # Requirements
+    ///
+    /// - lhs and rhs must have the same number of blocks, with empty carries
+    /// - carry_in must encrypt either 0 or 1
+    pub fn unchecked_add_with_carry_in_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+        carry_in: &Ciphertext,
+    ) -> (RadixCiphertext, Ciphertext) {
+        assert_eq!(
+            lhs.blocks.len(),
+            rhs.blocks.len(),
+            "Left hand side must must have a number of blocks equal \
+            to the number of blocks of the right hand side: lhs {} blocks, rhs {} blocks",
+            lhs.blocks.len(),
+            rhs.blocks.len()
+        );
+        assert!(
+            carry_in.degree.0 <= 1,
+            "The input carry must encrypt a boolean value, got a block of degree {}",
+            carry_in.degree.0
+        );
+
+        let mut ct = self.unchecked_add_parallelized(lhs, rhs);
+        // The first block can hold at most (msg_mod - 1) * 2 + 1,
+        // so adding the input carry still only consumes one bit of carry
+        self.key.unchecked_add_assign(&mut ct.blocks[0], carry_in);
+
+        if self.is_eligible_for_parallel_single_carry_propagation(&ct) {
+            let generates_or_propagates = self.generate_init_carry_array(&ct);
+            let (input_carries, mut output_carry) =
+                self.compute_carry_propagation_parallelized_low_latency(generates_or_propagates);
+
+            ct.blocks
+                .par_iter_mut()
+                .zip(input_carries.par_iter())
+                .for_each(|(block, input_carry)| {
+                    self.key.unchecked_add_assign(block, input_carry);
+                    self.key.message_extract_assign(block);
+                });
+            // The first block never propagates, so we know here that the result
+            // is a boolean value, however the lut used has a degree of 2.
+            output_carry.degree.0 = 1;
+            (ct, output_carry)
+        } else {
+            let mut carry = self.key.create_trivial(0);
+            for block in ct.blocks.iter_mut() {
+                self.key.unchecked_add_assign(block, &carry);
+                let (msg, new_carry) = rayon::join(
+                    || self.key.message_extract(block),
+                    || self.key.carry_extract(block),
+                );
+                *block = msg;
+                carry = new_carry;
+            }
+            (ct, carry)
+        }
+    }
+
     pub(crate) fn is_eligible_for_parallel_single_carry_propagation<T>(&self, ct: &T) -> bool
     where
         T: IntegerRadixCiphertext,
@@ -769,6 +959,10 @@ impl ServerKey {
     ///
     /// - Expects all ciphertexts to have empty carries
     /// - Expects all ciphertexts to have the same size
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(num_ciphertexts = ciphertexts.len()))
+    )]
     pub fn unchecked_sum_ciphertexts_vec_parallelized<T>(
         &self,
         mut ciphertexts: Vec<T>,
@@ -0,0 +1,237 @@
+use super::bit_extractor::BitExtractor;
+use crate::integer::ciphertext::IntegerRadixCiphertext;
+use crate::integer::ServerKey;
+use crate::shortint::Ciphertext;
+use rayon::prelude::*;
+
+impl ServerKey {
+    fn bits_per_block(&self) -> usize {
+        self.key.message_modulus.0.ilog2() as usize
+    }
+
+    /// Returns a block encrypting the bit at position `index` of the ciphertext
+    ///
+    /// The bit 0 is the least significant bit of the first block.
+    ///
+    /// # Requirements
+    ///
+    /// - The input must have its carries empty
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater or equal to the number of bits of the ciphertext
+    pub fn unchecked_get_bit_parallelized<T>(&self, ct: &T, index: usize) -> Ciphertext
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let bits_per_block = self.bits_per_block();
+        let num_bits = ct.blocks().len() * bits_per_block;
+        assert!(
+            index < num_bits,
+            "Bit index {index} is out of range for a ciphertext of {num_bits} bits"
+        );
+
+        let bit_in_block = index % bits_per_block;
+        let lut = self.key.generate_lookup_table(|x| (x >> bit_in_block) & 1);
+        self.key
+            .apply_lookup_table(&ct.blocks()[index / bits_per_block], &lut)
+    }
+
+    /// Returns a block encrypting the bit at position `index` of the ciphertext
+    ///
+    /// The bit 0 is the least significant bit of the first block, for signed ciphertexts the
+    /// last bit is the sign bit.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg = 0b1010_0110u64;
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let bit = sks.get_bit_parallelized(&ct, 5);
+    /// let dec = cks.decrypt_one_block(&bit);
+    /// assert_eq!(dec, 1);
+    /// ```
+    pub fn get_bit_parallelized<T>(&self, ct: &T, index: usize) -> Ciphertext
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if ct.block_carries_are_empty() {
+            self.unchecked_get_bit_parallelized(ct, index)
+        } else {
+            let mut cloned = ct.clone();
+            self.full_propagate_parallelized(&mut cloned);
+            self.unchecked_get_bit_parallelized(&cloned, index)
+        }
+    }
+
+    /// Sets the bit at position `index` of the ciphertext to the value encrypted in `bit`
+    ///
+    /// Only the block holding the bit is updated: the bit is cleared with a lookup table, and the
+    /// new value is added in its place.
+    ///
+    /// # Requirements
+    ///
+    /// - The input must have its carries empty
+    ///
+    /// # Panics
+    ///
+    /// - Panics if `index` is greater or equal to the number of bits of the ciphertext
+    /// - Panics if `bit` may encrypt a value other than 0 or 1 (its degree is greater than 1)
+    pub fn unchecked_set_bit_parallelized<T>(&self, ct: &mut T, index: usize, bit: &Ciphertext)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let bits_per_block = self.bits_per_block();
+        let num_bits = ct.blocks().len() * bits_per_block;
+        assert!(
+            index < num_bits,
+            "Bit index {index} is out of range for a ciphertext of {num_bits} bits"
+        );
+        assert!(
+            bit.degree.0 <= 1,
+            "The bit must encrypt 0 or 1, got a ciphertext of degree {}",
+            bit.degree.0
+        );
+
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let bit_in_block = index % bits_per_block;
+        let mask = !(1u64 << bit_in_block);
+        // Reducing by the message modulus keeps the degree of the cleared block exact, so that
+        // adding the bit back in leaves the carries empty
+        let lut = self
+            .key
+            .generate_lookup_table(|x| (x % message_modulus) & mask);
+
+        let block = &mut ct.blocks_mut()[index / bits_per_block];
+        self.key.apply_lookup_table_assign(block, &lut);
+        let shifted_bit = self.key.unchecked_scalar_mul(bit, 1 << bit_in_block);
+        self.key.unchecked_add_assign(block, &shifted_bit);
+    }
+
+    /// Sets the bit at position `index` of the ciphertext to the value encrypted in `bit`
+    ///
+    /// `bit` must encrypt 0 or 1, e.g. a block returned by [Self::get_bit_parallelized].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg = 0b1010_0110u64;
+    /// let mut ct = cks.encrypt(msg);
+    /// let one = cks.encrypt_one_block(1);
+    /// let zero = cks.encrypt_one_block(0);
+    ///
+    /// sks.set_bit_parallelized(&mut ct, 0, &one);
+    /// sks.set_bit_parallelized(&mut ct, 7, &zero);
+    ///
+    /// let dec: u64 = cks.decrypt(&ct);
+    /// assert_eq!(dec, 0b0010_0111);
+    /// ```
+    pub fn set_bit_parallelized<T>(&self, ct: &mut T, index: usize, bit: &Ciphertext)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if !ct.block_carries_are_empty() {
+            self.full_propagate_parallelized(ct);
+        }
+        if bit.degree.0 <= 1 {
+            self.unchecked_set_bit_parallelized(ct, index, bit);
+        } else {
+            // e.g. a fresh encryption, its degree does not tell that it encrypts 0 or 1
+            let lut = self.key.generate_lookup_table(|x| x & 1);
+            let bit = self.key.apply_lookup_table(bit, &lut);
+            self.unchecked_set_bit_parallelized(ct, index, &bit);
+        }
+    }
+
+    /// Returns blocks encrypting each bit of the ciphertext, least significant bit first
+    ///
+    /// # Requirements
+    ///
+    /// - The input must have its carries empty
+    pub fn unchecked_to_bits_parallelized<T>(&self, ct: &T) -> Vec<Ciphertext>
+    where
+        T: IntegerRadixCiphertext,
+    {
+        BitExtractor::new(self, self.bits_per_block()).extract_all_bits(ct.blocks())
+    }
+
+    /// Returns blocks encrypting each bit of the ciphertext, least significant bit first
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::integer::RadixCiphertext;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg = 0b1010_0110u64;
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let mut bits = sks.to_bits_parallelized(&ct);
+    /// assert_eq!(bits.len(), 8);
+    /// let dec: Vec<u64> = bits.iter().map(|bit| cks.decrypt_one_block(bit)).collect();
+    /// assert_eq!(dec, [0, 1, 1, 0, 0, 1, 0, 1]);
+    ///
+    /// // Reverse the bits
+    /// bits.reverse();
+    /// let ct: RadixCiphertext = sks.from_bits_parallelized(&bits);
+    /// let dec: u64 = cks.decrypt(&ct);
+    /// assert_eq!(dec, 0b0110_0101);
+    /// ```
+    pub fn to_bits_parallelized<T>(&self, ct: &T) -> Vec<Ciphertext>
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if ct.block_carries_are_empty() {
+            self.unchecked_to_bits_parallelized(ct)
+        } else {
+            let mut cloned = ct.clone();
+            self.full_propagate_parallelized(&mut cloned);
+            self.unchecked_to_bits_parallelized(&cloned)
+        }
+    }
+
+    /// Creates a ciphertext from blocks encrypting its bits, least significant bit first
+    ///
+    /// The number of blocks of the result is the number of blocks needed to hold all the bits,
+    /// the missing upper bits of the last block are zeros.
+    ///
+    /// Each block of `bits` must encrypt 0 or 1.
+    pub fn from_bits_parallelized<T>(&self, bits: &[Ciphertext]) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let blocks = bits
+            .par_chunks(self.bits_per_block())
+            .map(|bits_of_block| {
+                let mut block = bits_of_block[0].clone();
+                for (i, bit) in bits_of_block.iter().enumerate().skip(1) {
+                    let shifted_bit = self.key.unchecked_scalar_mul(bit, 1 << i);
+                    self.key.unchecked_add_assign(&mut block, &shifted_bit);
+                }
+                // As the bits encrypt 0 or 1 the sum holds no carries, whatever their degrees.
+                // The bootstrap resets the degree and the noise of the additions
+                self.key.message_extract_assign(&mut block);
+                block
+            })
+            .collect::<Vec<_>>();
+
+        T::from(blocks)
+    }
+}
@@ -2,6 +2,7 @@ use super::ServerKey;
 
 use crate::integer::ciphertext::IntegerRadixCiphertext;
 use crate::integer::server_key::comparator::Comparator;
+use crate::shortint::Ciphertext;
 
 use rayon::prelude::*;
 
@@ -10,23 +11,8 @@ impl ServerKey {
     where
         T: IntegerRadixCiphertext,
     {
-        // Even though the corresponding function
-        // may already exist in self.key
-        // we generate our own lut to do less allocations
-        // one for all the threads as opposed to one per thread
-        let lut = self
-            .key
-            .generate_lookup_table_bivariate(|x, y| u64::from(x == y));
-        let mut block_comparisons = lhs.blocks().to_vec();
-        block_comparisons
-            .par_iter_mut()
-            .zip(rhs.blocks().par_iter())
-            .for_each(|(lhs_block, rhs_block)| {
-                self.key
-                    .unchecked_apply_lookup_table_bivariate_assign(lhs_block, rhs_block, &lut);
-            });
-
-        let is_equal_result = self.are_all_comparisons_block_true(block_comparisons);
+        let block_equalities = self.unchecked_compare_blocks_for_equality(lhs, rhs);
+        let is_equal_result = self.are_all_comparisons_block_true(block_equalities);
 
         let mut blocks = Vec::with_capacity(lhs.blocks().len());
         blocks.push(is_equal_result);
@@ -39,47 +25,50 @@ impl ServerKey {
     where
         T: IntegerRadixCiphertext,
     {
+        let block_equalities = self.unchecked_compare_blocks_for_equality(lhs, rhs);
+        let is_not_equal_result = self.is_any_comparison_block_false(block_equalities);
+
+        let mut blocks = Vec::with_capacity(lhs.blocks().len());
+        blocks.push(is_not_equal_result);
+        blocks.resize_with(lhs.blocks().len(), || self.key.create_trivial(0));
+
+        T::from_blocks(blocks)
+    }
+
+    /// Returns blocks encrypting 1 where the compared blocks of lhs and rhs are equal, 0
+    /// otherwise.
+    ///
+    /// Each pair of blocks is compared with a bivariate PBS: packing two blocks of each operand
+    /// in one ciphertext and comparing their difference would need a noise level of
+    /// `2 * message_modulus + 2`, above the maximum noise level of the parameters with as many
+    /// carry bits as message bits.
+    ///
+    /// Expects the carry buffers to be empty
+    fn unchecked_compare_blocks_for_equality<T>(
+        &self,
+        lhs: &T,
+        rhs: &T,
+    ) -> Vec<crate::shortint::Ciphertext>
+    where
+        T: IntegerRadixCiphertext,
+    {
+        assert_eq!(lhs.blocks().len(), rhs.blocks().len());
+
         // Even though the corresponding function
         // may already exist in self.key
         // we generate our own lut to do less allocations
         // one for all the threads as opposed to one per thread
         let lut = self
             .key
-            .generate_lookup_table_bivariate(|x, y| u64::from(x != y));
-        let mut block_comparisons = lhs.blocks().to_vec();
-        block_comparisons
-            .par_iter_mut()
+            .generate_lookup_table_bivariate(|x, y| u64::from(x == y));
+        lhs.blocks()
+            .par_iter()
             .zip(rhs.blocks().par_iter())
-            .for_each(|(lhs_block, rhs_block)| {
+            .map(|(lhs_block, rhs_block)| {
                 self.key
-                    .unchecked_apply_lookup_table_bivariate_assign(lhs_block, rhs_block, &lut);
-            });
-
-        let message_modulus = self.key.message_modulus.0;
-        let carry_modulus = self.key.carry_modulus.0;
-        let total_modulus = message_modulus * carry_modulus;
-        let max_value = total_modulus - 1;
-
-        let mut block_comparisons_2 = Vec::with_capacity(block_comparisons.len() / 2);
-        let is_non_zero = self.key.generate_lookup_table(|x| u64::from(x != 0));
-
-        while block_comparisons.len() > 1 {
-            block_comparisons
-                .par_chunks(max_value)
-                .map(|blocks| {
-                    let mut sum = blocks[0].clone();
-                    for other_block in &blocks[1..] {
-                        self.key.unchecked_add_assign(&mut sum, other_block);
-                    }
-                    self.key.apply_lookup_table(&sum, &is_non_zero)
-                })
-                .collect_into_vec(&mut block_comparisons_2);
-            std::mem::swap(&mut block_comparisons_2, &mut block_comparisons);
-        }
-
-        block_comparisons.resize_with(lhs.blocks().len(), || self.key.create_trivial(0));
-
-        T::from_blocks(block_comparisons)
+                    .unchecked_apply_lookup_table_bivariate(lhs_block, rhs_block, &lut)
+            })
+            .collect()
     }
 
     pub fn unchecked_gt_parallelized<T>(&self, lhs: &T, rhs: &T) -> T
@@ -124,6 +113,13 @@ impl ServerKey {
         Comparator::new(self).unchecked_min_parallelized(lhs, rhs)
     }
 
+    pub fn unchecked_cmp_parallelized<T>(&self, lhs: &T, rhs: &T) -> Ciphertext
+    where
+        T: IntegerRadixCiphertext,
+    {
+        Comparator::new(self).unchecked_cmp_parallelized(lhs, rhs)
+    }
+
     pub fn smart_eq_parallelized<T>(&self, lhs: &mut T, rhs: &mut T) -> T
     where
         T: IntegerRadixCiphertext,
@@ -309,4 +305,104 @@ impl ServerKey {
     {
         Comparator::new(self).min_parallelized(lhs, rhs)
     }
+
+    /// Compares `lhs` and `rhs`, and returns a block encrypting their ordering.
+    ///
+    /// The comparison circuit is run once, then the ordering can be converted to the result of
+    /// any comparison for a single PBS with [Self::ordering_matches], or used to select between
+    /// three values with [Self::ordering_select_parallelized].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::integer::RadixCiphertext;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let a = 17u8;
+    /// let b = 42u8;
+    ///
+    /// let ct_a = cks.encrypt(a);
+    /// let ct_b = cks.encrypt(b);
+    ///
+    /// let ordering = sks.cmp_parallelized(&ct_a, &ct_b);
+    ///
+    /// let ct_le: RadixCiphertext = sks.ordering_matches(&ordering, |o| o.is_le(), size);
+    /// let ct_eq: RadixCiphertext = sks.ordering_matches(&ordering, |o| o.is_eq(), size);
+    ///
+    /// // Decrypt:
+    /// let dec: u8 = cks.decrypt(&ct_le);
+    /// assert_eq!(dec, u8::from(a <= b));
+    /// let dec: u8 = cks.decrypt(&ct_eq);
+    /// assert_eq!(dec, u8::from(a == b));
+    /// ```
+    pub fn cmp_parallelized<T>(&self, lhs: &T, rhs: &T) -> Ciphertext
+    where
+        T: IntegerRadixCiphertext,
+    {
+        Comparator::new(self).cmp_parallelized(lhs, rhs)
+    }
+
+    /// Returns a radix ciphertext of `num_blocks` blocks encrypting 1 if the ordering encrypted
+    /// by `ordering` satisfies `predicate`, 0 otherwise.
+    ///
+    /// `ordering` is the result of [Self::cmp_parallelized] or of one of its variants.
+    pub fn ordering_matches<T, F>(
+        &self,
+        ordering: &Ciphertext,
+        predicate: F,
+        num_blocks: usize,
+    ) -> T
+    where
+        T: IntegerRadixCiphertext,
+        F: Fn(std::cmp::Ordering) -> bool,
+    {
+        Comparator::new(self).ordering_matches(ordering, predicate, num_blocks)
+    }
+
+    /// Returns a ciphertext encrypting the same value as `if_less`, `if_equal` or `if_greater`
+    /// depending on the ordering encrypted by `ordering`.
+    ///
+    /// `ordering` is the result of [Self::cmp_parallelized] or of one of its variants.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let ct_a = cks.encrypt(200u8);
+    /// let ct_b = cks.encrypt(200u8);
+    ///
+    /// let ordering = sks.cmp_parallelized(&ct_a, &ct_b);
+    ///
+    /// let ct_less = cks.encrypt(1u8);
+    /// let ct_equal = cks.encrypt(2u8);
+    /// let ct_greater = cks.encrypt(3u8);
+    /// let ct_res = sks.ordering_select_parallelized(&ordering, &ct_less, &ct_equal, &ct_greater);
+    ///
+    /// // Decrypt:
+    /// let dec: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 2);
+    /// ```
+    pub fn ordering_select_parallelized<T>(
+        &self,
+        ordering: &Ciphertext,
+        if_less: &T,
+        if_equal: &T,
+        if_greater: &T,
+    ) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        Comparator::new(self).ordering_select_parallelized(ordering, if_less, if_equal, if_greater)
+    }
 }
@@ -0,0 +1,134 @@
+use crate::integer::ciphertext::IntegerRadixCiphertext;
+use crate::integer::ServerKey;
+use rayon::prelude::*;
+use std::time::Instant;
+
+/// The result of a computation over a batch stopped at a deadline, see
+/// [ServerKey::sum_ciphertexts_with_deadline]
+#[derive(Clone)]
+pub struct PartialSum<T> {
+    /// The sum of the first [Self::num_summed] ciphertexts of the batch
+    pub sum: T,
+    /// Number of ciphertexts included in the sum, they are the first ones of the batch
+    pub num_summed: usize,
+    /// Number of ciphertexts of the batch
+    pub num_ciphertexts: usize,
+}
+
+impl<T> PartialSum<T> {
+    /// Returns whether all the ciphertexts of the batch were summed before the deadline
+    pub fn is_complete(&self) -> bool {
+        self.num_summed == self.num_ciphertexts
+    }
+}
+
+impl ServerKey {
+    /// Sums the ciphertexts until `deadline` is reached
+    ///
+    /// The ciphertexts are summed by a tree reduction: each round sums, in parallel, groups of
+    /// adjacent partial sums of as many ciphertexts as the carries can hold, each group ending
+    /// with a carry propagation. The deadline is checked between rounds: once it is reached no new
+    /// PBS is scheduled, and the first partial sum, which covers the first ciphertexts of the
+    /// batch, is returned. As the round in progress is finished, the call may exceed the deadline
+    /// by the duration of one round.
+    ///
+    /// The returned sum has its carries empty. If the deadline is already reached when the call
+    /// starts, it is a trivial encryption of 0.
+    ///
+    /// - Returns None if ciphertexts is empty
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ciphertexts do not all have the same number of blocks
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::{Duration, Instant};
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let msgs = [4u64, 129, 200, 17];
+    /// let cts = msgs.iter().map(|&msg| cks.encrypt(msg)).collect::<Vec<_>>();
+    ///
+    /// let deadline = Instant::now() + Duration::from_secs(60);
+    /// let partial_sum = sks.sum_ciphertexts_with_deadline(&cts, deadline).unwrap();
+    ///
+    /// // Decrypt:
+    /// let expected: u64 = msgs[..partial_sum.num_summed].iter().sum();
+    /// let dec: u64 = cks.decrypt(&partial_sum.sum);
+    /// assert_eq!(dec, expected % 256);
+    /// if partial_sum.is_complete() {
+    ///     assert_eq!(dec, 94);
+    /// }
+    /// ```
+    pub fn sum_ciphertexts_with_deadline<T>(
+        &self,
+        ciphertexts: &[T],
+        deadline: Instant,
+    ) -> Option<PartialSum<T>>
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let first = ciphertexts.first()?;
+        let num_blocks = first.blocks().len();
+        assert!(
+            ciphertexts[1..]
+                .iter()
+                .all(|ct| ct.blocks().len() == num_blocks),
+            "Not all ciphertexts have the same number of blocks"
+        );
+
+        if Instant::now() >= deadline {
+            return Some(PartialSum {
+                sum: self.create_trivial_zero_radix(num_blocks),
+                num_summed: 0,
+                num_ciphertexts: ciphertexts.len(),
+            });
+        }
+
+        let message_modulus = self.key.message_modulus.0;
+        let total_modulus = message_modulus * self.key.carry_modulus.0;
+        let num_elements_to_fill_carry = (total_modulus - 1) / (message_modulus - 1);
+        let group_size = num_elements_to_fill_carry.max(2);
+
+        let mut terms = ciphertexts
+            .par_iter()
+            .map(|ct| {
+                let mut ct = ct.clone();
+                if !ct.block_carries_are_empty() {
+                    self.full_propagate_parallelized(&mut ct);
+                }
+                ct
+            })
+            .collect::<Vec<_>>();
+        // Number of ciphertexts summed in each term, the last one may hold less
+        let mut num_summed_per_term = 1;
+
+        while terms.len() > 1 && Instant::now() < deadline {
+            terms = terms
+                .into_par_iter()
+                .chunks(group_size)
+                .map(|group| {
+                    // Cannot be None, as chunks are not empty
+                    self.unchecked_sum_ciphertexts_vec_parallelized(group)
+                        .unwrap()
+                })
+                .collect();
+            num_summed_per_term = num_summed_per_term.saturating_mul(group_size);
+        }
+
+        let num_summed = num_summed_per_term.min(ciphertexts.len());
+        let sum = terms.swap_remove(0);
+
+        Some(PartialSum {
+            sum,
+            num_summed,
+            num_ciphertexts: ciphertexts.len(),
+        })
+    }
+}
@@ -594,6 +594,14 @@ impl ServerKey {
     /// assert_eq!(q, msg1 / msg2);
     /// assert_eq!(r, msg1 % msg2);
     /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(num_blocks = numerator.blocks().len())
+        )
+    )]
     pub fn div_rem_parallelized<T>(&self, numerator: &T, divisor: &T) -> (T, T)
     where
         T: IntegerRadixCiphertext,
@@ -630,6 +638,113 @@ impl ServerKey {
         self.unchecked_div_rem_parallelized(numerator, divisor)
     }
 
+    /// Computes homomorphically the quotient and remainder of the division between two
+    /// ciphertexts, together with a flag telling whether the divisor is zero.
+    ///
+    /// The flag is a ciphertext encrypting 1 if the divisor is zero, 0 otherwise.
+    ///
+    /// When the divisor is zero, the quotient is 0 and the remainder is the numerator,
+    /// so that `numerator == quotient * divisor + remainder` always holds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = 97;
+    /// let msg2 = 0;
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// let (q_res, r_res, is_zero_res) = sks.checked_div_rem_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let q: u64 = cks.decrypt(&q_res);
+    /// let r: u64 = cks.decrypt(&r_res);
+    /// let is_zero: u64 = cks.decrypt(&is_zero_res);
+    /// assert_eq!(q, 0);
+    /// assert_eq!(r, msg1);
+    /// assert_eq!(is_zero, 1);
+    /// ```
+    pub fn checked_div_rem_parallelized<T>(&self, numerator: &T, divisor: &T) -> (T, T, T)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp_numerator;
+        let mut tmp_divisor;
+
+        let (numerator, divisor) = match (
+            numerator.block_carries_are_empty(),
+            divisor.block_carries_are_empty(),
+        ) {
+            (true, true) => (numerator, divisor),
+            (true, false) => {
+                tmp_divisor = divisor.clone();
+                self.full_propagate_parallelized(&mut tmp_divisor);
+                (numerator, &tmp_divisor)
+            }
+            (false, true) => {
+                tmp_numerator = numerator.clone();
+                self.full_propagate_parallelized(&mut tmp_numerator);
+                (&tmp_numerator, divisor)
+            }
+            (false, false) => {
+                tmp_divisor = divisor.clone();
+                tmp_numerator = numerator.clone();
+                rayon::join(
+                    || self.full_propagate_parallelized(&mut tmp_numerator),
+                    || self.full_propagate_parallelized(&mut tmp_divisor),
+                );
+                (&tmp_numerator, &tmp_divisor)
+            }
+        };
+
+        self.unchecked_checked_div_rem_parallelized(numerator, divisor)
+    }
+
+    /// Computes homomorphically the quotient and remainder of the division between two
+    /// ciphertexts, together with a flag telling whether the divisor is zero.
+    ///
+    /// See [`Self::checked_div_rem_parallelized`] for the values returned when the divisor is
+    /// zero.
+    ///
+    /// The carries of the inputs must be empty.
+    pub fn unchecked_checked_div_rem_parallelized<T>(&self, numerator: &T, divisor: &T) -> (T, T, T)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let ((quotient, remainder), divisor_is_zero) = rayon::join(
+            || self.unchecked_div_rem_parallelized(numerator, divisor),
+            || self.unchecked_scalar_eq_parallelized(divisor, 0u64),
+        );
+
+        let condition_block = &divisor_is_zero.blocks()[0];
+        let (quotient, remainder) = rayon::join(
+            || {
+                let mut quotient = quotient;
+                self.zero_out_if_condition_equals(&mut quotient, condition_block, 1);
+                quotient
+            },
+            || {
+                self.unchecked_programmable_if_then_else_parallelized(
+                    condition_block,
+                    numerator,
+                    &remainder,
+                    |x| x == 1,
+                    true,
+                )
+            },
+        );
+
+        (quotient, remainder, divisor_is_zero)
+    }
+
     pub fn smart_div_rem_parallelized<T>(&self, numerator: &mut T, divisor: &mut T) -> (T, T)
     where
         T: IntegerRadixCiphertext,
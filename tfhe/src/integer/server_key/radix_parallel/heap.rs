@@ -0,0 +1,193 @@
+use crate::integer::ciphertext::IntegerRadixCiphertext;
+use crate::integer::ServerKey;
+use rayon::prelude::*;
+
+/// A min-heap of encrypted integers with a fixed capacity
+///
+/// The values are ordered with homomorphic comparisons, and every push and pop applies the same
+/// sequence of operations whatever the encrypted values are: the compare and swap operations are
+/// all computed, and their result is selected homomorphically. The layout of the heap thus
+/// reveals nothing about the order of the values. Only the number of values is public, as it is
+/// known from the sequence of pushes and pops anyway.
+///
+/// A push costs one comparison per level of the heap, a pop costs a comparison with the children
+/// of every node of the heap, the nodes of a level being processed in parallel.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::integer::{gen_keys_radix, EncryptedHeap};
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+///
+/// // We have 4 * 2 = 8 bits of message
+/// let size = 4;
+/// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+///
+/// let mut heap = EncryptedHeap::new(3);
+/// for msg in [42u64, 7, 128] {
+///     heap.push(&sks, cks.encrypt(msg));
+/// }
+/// assert!(heap.is_full());
+///
+/// let min = heap.pop_min(&sks).unwrap();
+/// let dec: u64 = cks.decrypt(&min);
+/// assert_eq!(dec, 7);
+/// assert_eq!(heap.len(), 2);
+/// ```
+#[derive(Clone)]
+pub struct EncryptedHeap<T> {
+    // Binary tree stored in breadth first order, the children of node i are 2i + 1 and 2i + 2
+    slots: Vec<T>,
+    capacity: usize,
+}
+
+impl<T> EncryptedHeap<T>
+where
+    T: IntegerRadixCiphertext,
+{
+    /// Creates an empty heap that can hold up to `capacity` values
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            slots: Vec::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.slots.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.slots.is_empty()
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.slots.len() == self.capacity
+    }
+
+    /// Returns the minimum value without removing it
+    pub fn peek_min(&self) -> Option<&T> {
+        self.slots.first()
+    }
+
+    /// Inserts a value in the heap
+    ///
+    /// # Panics
+    ///
+    /// Panics if the heap is full, or if the value does not have the same number of blocks as the
+    /// values already in the heap
+    pub fn push(&mut self, sks: &ServerKey, mut ct: T) {
+        assert!(!self.is_full(), "The heap is full");
+        if let Some(first) = self.slots.first() {
+            assert_eq!(
+                ct.blocks().len(),
+                first.blocks().len(),
+                "The value does not have the same number of blocks as the values of the heap"
+            );
+        }
+
+        if !ct.block_carries_are_empty() {
+            sks.full_propagate_parallelized(&mut ct);
+        }
+        self.slots.push(ct);
+
+        // The path from the new leaf to the root only depends on the number of values, it is
+        // always fully processed
+        let mut index = self.slots.len() - 1;
+        while index > 0 {
+            let parent = (index - 1) / 2;
+            let (lhs, rhs) = (&self.slots[parent], &self.slots[index]);
+            let (min, max) = rayon::join(
+                || sks.min_parallelized(lhs, rhs),
+                || sks.max_parallelized(lhs, rhs),
+            );
+            self.slots[parent] = min;
+            self.slots[index] = max;
+            index = parent;
+        }
+    }
+
+    /// Removes the minimum value from the heap and returns it
+    ///
+    /// - Returns None if the heap is empty
+    pub fn pop_min(&mut self, sks: &ServerKey) -> Option<T> {
+        if self.slots.is_empty() {
+            return None;
+        }
+
+        // The last leaf takes the place of the root, and is moved down to its place
+        let min = self.slots.swap_remove(0);
+
+        // The position of the moved value is not known, so every node is ordered with its
+        // children, level by level from the root. Only the nodes on the path of the moved value
+        // are actually changed.
+        let len = self.slots.len();
+        let mut level_start = 0;
+        while 2 * level_start + 1 < len {
+            let level_end = 2 * level_start + 1;
+            let ordered = (level_start..level_end)
+                .into_par_iter()
+                .filter(|&index| 2 * index + 1 < len)
+                .map(|index| (index, self.order_with_children(sks, index)))
+                .collect::<Vec<_>>();
+
+            for (index, (node, left, right)) in ordered {
+                self.slots[index] = node;
+                self.slots[2 * index + 1] = left;
+                if let Some(right) = right {
+                    self.slots[2 * index + 2] = right;
+                }
+            }
+            level_start = level_end;
+        }
+
+        Some(min)
+    }
+
+    /// Returns the node at `index` and its children, with the minimum of the three in the node
+    /// and the node value in place of the smallest child if it was smaller than the node
+    fn order_with_children(&self, sks: &ServerKey, index: usize) -> (T, T, Option<T>) {
+        let node = &self.slots[index];
+        let left = &self.slots[2 * index + 1];
+
+        let Some(right) = self.slots.get(2 * index + 2) else {
+            let (min, max) = rayon::join(
+                || sks.min_parallelized(node, left),
+                || sks.max_parallelized(node, left),
+            );
+            return (min, max, None);
+        };
+
+        let ((right_is_smaller, min_child), (max_node_left, max_node_right)) = rayon::join(
+            || {
+                rayon::join(
+                    || sks.lt_parallelized(right, left),
+                    || sks.min_parallelized(left, right),
+                )
+            },
+            || {
+                rayon::join(
+                    || sks.max_parallelized(node, left),
+                    || sks.max_parallelized(node, right),
+                )
+            },
+        );
+
+        // Only the smallest child can be swapped with the node
+        let (new_node, (new_left, new_right)) = rayon::join(
+            || sks.min_parallelized(node, &min_child),
+            || {
+                rayon::join(
+                    || sks.if_then_else_parallelized(&right_is_smaller, left, &max_node_left),
+                    || sks.if_then_else_parallelized(&right_is_smaller, &max_node_right, right),
+                )
+            },
+        );
+
+        (new_node, new_left, Some(new_right))
+    }
+}
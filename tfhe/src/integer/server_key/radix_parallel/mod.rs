@@ -1,19 +1,26 @@
 mod abs;
 mod add;
 mod bit_extractor;
+mod bit_manipulation;
 mod bitwise_op;
 mod cmux;
 mod comparison;
+mod deadline;
 mod div_mod;
+mod heap;
 mod mul;
 mod neg;
+mod one_hot;
 mod rotate;
+mod saturating;
 mod scalar_add;
+mod scalar_batch;
 mod scalar_bitwise_op;
 mod scalar_comparison;
 mod scalar_div_mod;
 mod scalar_mul;
 mod scalar_rotate;
+mod scalar_rounding;
 mod scalar_shift;
 mod scalar_sub;
 mod shift;
@@ -29,9 +36,13 @@ mod tests_signed;
 pub(crate) mod tests_unsigned;
 
 use crate::integer::ciphertext::IntegerRadixCiphertext;
+use crate::shortint::SanitizationError;
 
 use super::ServerKey;
+pub use deadline::PartialSum;
+pub use heap::EncryptedHeap;
 pub use scalar_div_mod::{MiniUnsignedInteger, Reciprocable};
+pub use scalar_rounding::RoundingMode;
 
 use rayon::prelude::*;
 
@@ -141,10 +152,67 @@ impl ServerKey {
     /// let res: u64 = cks.decrypt(&ct_res);
     /// assert_eq!(msg + msg, res);
     /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(num_blocks = ctxt.blocks().len()))
+    )]
     pub fn full_propagate_parallelized<T>(&self, ctxt: &mut T)
     where
         T: IntegerRadixCiphertext,
     {
         self.partial_propagate_parallelized(ctxt, 0)
     }
+
+    /// Puts all the blocks of a ciphertext in the canonical form of fresh blocks.
+    ///
+    /// Each block is refreshed in parallel, see
+    /// [crate::shortint::ServerKey::sanitize]. Ciphertexts supplied by an untrusted party should
+    /// be sanitized before being used, so that their degrees and noise levels are not chosen by
+    /// the sender.
+    ///
+    /// # Note
+    ///
+    /// The carries of the blocks are dropped, a ciphertext with non-empty carries must be
+    /// propagated first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the LWE dimension or the ciphertext modulus of a block are not the
+    /// ones of the ciphertexts of this key, the blocks may then be partially sanitized.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::integer::{gen_keys_radix, IntegerCiphertext};
+    /// use tfhe::shortint::ciphertext::Degree;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg = 201u64;
+    ///
+    /// // A ciphertext with forged degrees
+    /// let mut ct = cks.encrypt(msg);
+    /// for block in ct.blocks_mut() {
+    ///     block.degree = Degree(0);
+    /// }
+    ///
+    /// sks.sanitize_parallelized(&mut ct).unwrap();
+    ///
+    /// for block in ct.blocks() {
+    ///     assert_eq!(block.degree, Degree(block.message_modulus.0 - 1));
+    /// }
+    /// let res: u64 = cks.decrypt(&ct);
+    /// assert_eq!(msg, res);
+    /// ```
+    pub fn sanitize_parallelized<T>(&self, ctxt: &mut T) -> Result<(), SanitizationError>
+    where
+        T: IntegerRadixCiphertext,
+    {
+        ctxt.blocks_mut()
+            .par_iter_mut()
+            .try_for_each(|block| self.key.sanitize(block))
+    }
 }
@@ -1,7 +1,11 @@
 use crate::integer::ciphertext::IntegerRadixCiphertext;
-use crate::integer::ServerKey;
+use crate::integer::server_key::MulAlgorithm;
+use crate::integer::{RadixCiphertext, ServerKey};
 use rayon::prelude::*;
 
+/// Minimum size, in bits, of the operands of a product to split it with Karatsuba
+const KARATSUBA_MIN_BITS: usize = 128;
+
 impl ServerKey {
     /// Computes homomorphically a multiplication between a ciphertext encrypting an integer value
     /// and another encrypting a shortint value.
@@ -326,6 +330,10 @@ impl ServerKey {
     /// let res: u64 = cks.decrypt(&ct_res);
     /// assert_eq!((clear_1 * clear_2) % 256, res);
     /// ```
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", skip_all, fields(num_blocks = lhs.blocks().len()))
+    )]
     pub fn unchecked_mul_assign_parallelized<T>(&self, lhs: &mut T, rhs: &T)
     where
         T: IntegerRadixCiphertext,
@@ -342,66 +350,10 @@ impl ServerKey {
             return;
         }
 
-        let message_modulus = self.key.message_modulus.0;
-
-        let lsb_block_mul_lut = self
-            .key
-            .generate_lookup_table_bivariate(|x, y| (x * y) % message_modulus as u64);
-
-        let msb_block_mul_lut = self
-            .key
-            .generate_lookup_table_bivariate(|x, y| (x * y) / message_modulus as u64);
-
-        let all_shifted_lhs = rhs
-            .blocks()
-            .par_iter()
-            .enumerate()
-            .filter(|(_, block)| block.degree.0 != 0)
-            .map(|(i, rhs_block)| {
-                let mut result = self.blockshift(lhs, i);
-                result.blocks_mut()[i..]
-                    .par_iter_mut()
-                    .filter(|block| block.degree.0 != 0)
-                    .for_each(|lhs_block| {
-                        self.key.unchecked_apply_lookup_table_bivariate_assign(
-                            lhs_block,
-                            rhs_block,
-                            &lsb_block_mul_lut,
-                        )
-                    });
-
-                result
-            })
-            .chain(
-                rhs.blocks()
-                    .par_iter()
-                    .enumerate()
-                    .filter(|(_, block)| block.degree.0 != 0)
-                    .map(|(i, rhs_block)| {
-                        // Here we are doing (a * b) / modulus
-                        // that is, getting the carry part of the block multiplication
-                        // so the shift is one block longer
-                        let mut result = self.blockshift(lhs, i + 1);
-                        result.blocks_mut()[i + 1..]
-                            .par_iter_mut()
-                            .filter(|block| block.degree.0 != 0)
-                            .for_each(|lhs_block| {
-                                self.key.unchecked_apply_lookup_table_bivariate_assign(
-                                    lhs_block,
-                                    rhs_block,
-                                    &msb_block_mul_lut,
-                                )
-                            });
-
-                        result
-                    }),
-            )
-            .collect::<Vec<_>>();
-
-        if let Some(result) = self.unchecked_sum_ciphertexts_vec_parallelized(all_shifted_lhs) {
-            *lhs = result;
-        } else {
-            self.create_trivial_zero_assign_radix(lhs);
+        match self.mul_algorithm {
+            MulAlgorithm::CarrySave => self.unchecked_carry_save_mul_assign_parallelized(lhs, rhs),
+            MulAlgorithm::Schoolbook => self.unchecked_schoolbook_mul_assign_parallelized(lhs, rhs),
+            MulAlgorithm::Karatsuba => self.unchecked_karatsuba_mul_assign_parallelized(lhs, rhs),
         }
     }
 
@@ -580,4 +532,223 @@ impl ServerKey {
 
         self.unchecked_mul_assign_parallelized(lhs, rhs);
     }
+
+    /// Multiplication where partial products are accumulated without carry propagation, with a
+    /// single final propagation.
+    fn unchecked_carry_save_mul_assign_parallelized<T>(&self, lhs: &mut T, rhs: &T)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let message_modulus = self.key.message_modulus.0;
+
+        let lsb_block_mul_lut = self
+            .key
+            .generate_lookup_table_bivariate(|x, y| (x * y) % message_modulus as u64);
+
+        let msb_block_mul_lut = self
+            .key
+            .generate_lookup_table_bivariate(|x, y| (x * y) / message_modulus as u64);
+
+        let all_shifted_lhs = rhs
+            .blocks()
+            .par_iter()
+            .enumerate()
+            .filter(|(_, block)| block.degree.0 != 0)
+            .map(|(i, rhs_block)| {
+                let mut result = self.blockshift(lhs, i);
+                result.blocks_mut()[i..]
+                    .par_iter_mut()
+                    .filter(|block| block.degree.0 != 0)
+                    .for_each(|lhs_block| {
+                        self.key.unchecked_apply_lookup_table_bivariate_assign(
+                            lhs_block,
+                            rhs_block,
+                            &lsb_block_mul_lut,
+                        )
+                    });
+
+                result
+            })
+            .chain(
+                rhs.blocks()
+                    .par_iter()
+                    .enumerate()
+                    .filter(|(_, block)| block.degree.0 != 0)
+                    .map(|(i, rhs_block)| {
+                        // Here we are doing (a * b) / modulus
+                        // that is, getting the carry part of the block multiplication
+                        // so the shift is one block longer
+                        let mut result = self.blockshift(lhs, i + 1);
+                        result.blocks_mut()[i + 1..]
+                            .par_iter_mut()
+                            .filter(|block| block.degree.0 != 0)
+                            .for_each(|lhs_block| {
+                                self.key.unchecked_apply_lookup_table_bivariate_assign(
+                                    lhs_block,
+                                    rhs_block,
+                                    &msb_block_mul_lut,
+                                )
+                            });
+
+                        result
+                    }),
+            )
+            .collect::<Vec<_>>();
+
+        if let Some(result) = self.unchecked_sum_ciphertexts_vec_parallelized(all_shifted_lhs) {
+            *lhs = result;
+        } else {
+            self.create_trivial_zero_assign_radix(lhs);
+        }
+    }
+
+    /// Multiplication where each partial product is added to the result with a full carry
+    /// propagation.
+    fn unchecked_schoolbook_mul_assign_parallelized<T>(&self, lhs: &mut T, rhs: &T)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut result: T = self.create_trivial_zero_radix(lhs.blocks().len());
+
+        for (i, rhs_block) in rhs.blocks().iter().enumerate() {
+            if rhs_block.degree.0 == 0 {
+                continue;
+            }
+            let partial_product = self.unchecked_block_mul_parallelized(lhs, rhs_block, i);
+            self.add_assign_parallelized(&mut result, &partial_product);
+        }
+
+        *lhs = result;
+    }
+
+    fn unchecked_karatsuba_mul_assign_parallelized<T>(&self, lhs: &mut T, rhs: &T)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let lhs_radix = RadixCiphertext::from(lhs.blocks().to_vec());
+        let rhs_radix = RadixCiphertext::from(rhs.blocks().to_vec());
+
+        // The bits of the truncated product do not depend on the signedness
+        let result = self.karatsuba_truncated_product(&lhs_radix, &rhs_radix);
+        *lhs = T::from(result.blocks);
+    }
+
+    /// Returns whether Karatsuba splitting is used for operands of `num_blocks` blocks
+    fn karatsuba_applies(&self, num_blocks: usize) -> bool {
+        let bits_per_block = self.key.message_modulus.0.ilog2() as usize;
+        num_blocks % 2 == 0 && num_blocks * bits_per_block >= KARATSUBA_MIN_BITS
+    }
+
+    fn split_radix_in_halves(
+        &self,
+        ct: &RadixCiphertext,
+        half: usize,
+    ) -> (RadixCiphertext, RadixCiphertext) {
+        (
+            RadixCiphertext::from(ct.blocks[..half].to_vec()),
+            RadixCiphertext::from(ct.blocks[half..].to_vec()),
+        )
+    }
+
+    /// Computes `lhs * rhs` modulo the modulus of the inputs.
+    ///
+    /// With `B` the modulus of the halves, `lhs * rhs = low_l * low_r + (high_l * low_r + low_l *
+    /// high_r) * B + high_l * high_r * B^2`, the last term vanishes modulo `B^2` and only the low
+    /// half of the cross products is needed.
+    fn karatsuba_truncated_product(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        let num_blocks = lhs.blocks.len();
+        if !self.karatsuba_applies(num_blocks) {
+            let mut result = lhs.clone();
+            self.unchecked_carry_save_mul_assign_parallelized(&mut result, rhs);
+            return result;
+        }
+
+        let half = num_blocks / 2;
+        let (lhs_low, lhs_high) = self.split_radix_in_halves(lhs, half);
+        let (rhs_low, rhs_high) = self.split_radix_in_halves(rhs, half);
+
+        let (low, (cross_0, cross_1)) = rayon::join(
+            || self.karatsuba_full_product(&lhs_low, &rhs_low),
+            || {
+                rayon::join(
+                    || self.karatsuba_truncated_product(&lhs_high, &rhs_low),
+                    || self.karatsuba_truncated_product(&lhs_low, &rhs_high),
+                )
+            },
+        );
+
+        let cross_0 = self.extend_radix_with_trivial_zero_blocks_lsb(&cross_0, half);
+        let cross_1 = self.extend_radix_with_trivial_zero_blocks_lsb(&cross_1, half);
+
+        self.unchecked_sum_ciphertexts_vec_parallelized(vec![low, cross_0, cross_1])
+            .unwrap()
+    }
+
+    /// Computes the full product `lhs * rhs`, which has twice as many blocks as the inputs.
+    ///
+    /// With `B` the modulus of the halves, the Karatsuba identity
+    /// `high_l * low_r + low_l * high_r = (low_l + high_l) * (low_r + high_r) - low_l * low_r -
+    /// high_l * high_r` allows to compute the full product with 3 products of halves.
+    fn karatsuba_full_product(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        let num_blocks = lhs.blocks.len();
+        if !self.karatsuba_applies(num_blocks) {
+            let mut lhs_extended = self.extend_radix_with_trivial_zero_blocks_msb(lhs, num_blocks);
+            let rhs_extended = self.extend_radix_with_trivial_zero_blocks_msb(rhs, num_blocks);
+            self.unchecked_carry_save_mul_assign_parallelized(&mut lhs_extended, &rhs_extended);
+            return lhs_extended;
+        }
+
+        let half = num_blocks / 2;
+        let (lhs_low, lhs_high) = self.split_radix_in_halves(lhs, half);
+        let (rhs_low, rhs_high) = self.split_radix_in_halves(rhs, half);
+
+        // The sum of the halves needs one more block to hold its carry
+        let sum_halves = |low: &RadixCiphertext, high: &RadixCiphertext| {
+            self.add_parallelized(
+                &self.extend_radix_with_trivial_zero_blocks_msb(low, 1),
+                &self.extend_radix_with_trivial_zero_blocks_msb(high, 1),
+            )
+        };
+        let (lhs_sum, rhs_sum) = rayon::join(
+            || sum_halves(&lhs_low, &lhs_high),
+            || sum_halves(&rhs_low, &rhs_high),
+        );
+
+        let ((low, high), mut middle) = rayon::join(
+            || {
+                rayon::join(
+                    || self.karatsuba_full_product(&lhs_low, &rhs_low),
+                    || self.karatsuba_full_product(&lhs_high, &rhs_high),
+                )
+            },
+            || self.karatsuba_full_product(&lhs_sum, &rhs_sum),
+        );
+
+        let output_num_blocks = 2 * num_blocks;
+        let extend_to_output = |ct: RadixCiphertext| -> RadixCiphertext {
+            let num_blocks_to_add = output_num_blocks - ct.blocks.len();
+            self.extend_radix_with_trivial_zero_blocks_msb(&ct, num_blocks_to_add)
+        };
+        let low = extend_to_output(low);
+        let high = extend_to_output(high);
+        middle = extend_to_output(middle);
+
+        // middle = high_l * low_r + low_l * high_r, which is positive
+        self.sub_assign_parallelized(&mut middle, &low);
+        self.sub_assign_parallelized(&mut middle, &high);
+
+        let middle = self.blockshift(&middle, half);
+        let high = self.blockshift(&high, num_blocks);
+
+        self.unchecked_sum_ciphertexts_vec_parallelized(vec![low, middle, high])
+            .unwrap()
+    }
 }
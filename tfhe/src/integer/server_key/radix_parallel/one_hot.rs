@@ -0,0 +1,359 @@
+use crate::integer::block_decomposition::{BlockDecomposer, DecomposableInto};
+use crate::integer::ciphertext::{IntegerCiphertext, OneHotCiphertext};
+use crate::integer::server_key::comparator::Comparator;
+use crate::integer::{RadixCiphertext, ServerKey};
+use crate::shortint::Ciphertext;
+
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Converts a radix ciphertext to the one-hot encoding, see [OneHotCiphertext]
+    ///
+    /// This evaluates `message_modulus` lookup tables per block.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg = 165u64;
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let one_hot = sks.radix_to_one_hot_parallelized(&ct);
+    /// assert_eq!(one_hot.num_digits(), num_blocks);
+    ///
+    /// let dec: u64 = cks.as_ref().decrypt_one_hot(&one_hot);
+    /// assert_eq!(dec, msg);
+    ///
+    /// let ct_res = sks.one_hot_to_radix_parallelized(&one_hot);
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msg);
+    /// ```
+    pub fn radix_to_one_hot_parallelized(&self, ct: &RadixCiphertext) -> OneHotCiphertext {
+        let mut cloned;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            cloned = ct.clone();
+            self.full_propagate_parallelized(&mut cloned);
+            &cloned
+        };
+
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let is_digit_luts = (0..message_modulus)
+            .map(|digit| self.key.generate_lookup_table(|x| u64::from(x == digit)))
+            .collect::<Vec<_>>();
+
+        let blocks = ct
+            .blocks
+            .par_iter()
+            .flat_map(|block| {
+                is_digit_luts
+                    .par_iter()
+                    .map(move |lut| self.key.apply_lookup_table(block, lut))
+            })
+            .collect();
+
+        OneHotCiphertext { blocks }
+    }
+
+    /// Converts a one-hot ciphertext back to the radix encoding
+    ///
+    /// This evaluates `log2(message_modulus)` lookup tables per digit, one per bit of the digit.
+    pub fn one_hot_to_radix_parallelized(&self, ct: &OneHotCiphertext) -> RadixCiphertext {
+        let message_modulus = self.key.message_modulus.0;
+        let num_bits = message_modulus.ilog2();
+        let bit_luts = (0..num_bits)
+            .map(|bit| self.key.generate_lookup_table(|x| (x & 1) << bit))
+            .collect::<Vec<_>>();
+
+        let digits = ct.digits().collect::<Vec<_>>();
+        let blocks = digits
+            .into_par_iter()
+            .map(|digit_blocks| {
+                let bits = bit_luts
+                    .par_iter()
+                    .enumerate()
+                    .map(|(bit, lut)| {
+                        // Only one block encrypts 1, so the sum of the blocks whose index has the
+                        // bit set is the value of the bit
+                        let mut sum = self.key.create_trivial(0);
+                        for (_, block) in digit_blocks
+                            .iter()
+                            .enumerate()
+                            .filter(|(index, _)| (index >> bit) & 1 == 1)
+                        {
+                            self.key.unchecked_add_assign(&mut sum, block);
+                        }
+                        self.key.apply_lookup_table(&sum, lut)
+                    })
+                    .collect::<Vec<_>>();
+
+                let mut bits = bits.into_iter();
+                let mut digit = bits.next().unwrap();
+                for bit in bits {
+                    self.key.unchecked_add_assign(&mut digit, &bit);
+                }
+                digit
+            })
+            .collect::<Vec<_>>();
+
+        RadixCiphertext::from_blocks(blocks)
+    }
+
+    /// Compares a one-hot ciphertext with a clear value for equality
+    ///
+    /// The blocks of the digits of the clear value are selected without evaluating any lookup
+    /// table, then reduced as for [Self::scalar_eq_parallelized]: the result is computed with about
+    /// `num_digits / (message_modulus * carry_modulus - 1)` lookup tables.
+    ///
+    /// The result is a radix ciphertext with as many blocks as the input has digits, encrypting
+    /// 1 if the values are equal and 0 otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_digits = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_digits);
+    ///
+    /// let ct = cks.as_ref().encrypt_one_hot(42u64, num_digits);
+    ///
+    /// let ct_res = sks.one_hot_scalar_eq_parallelized(&ct, 42u64);
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 1);
+    ///
+    /// let ct_res = sks.one_hot_scalar_eq_parallelized(&ct, 43u64);
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 0);
+    /// ```
+    pub fn one_hot_scalar_eq_parallelized<T>(
+        &self,
+        ct: &OneHotCiphertext,
+        scalar: T,
+    ) -> RadixCiphertext
+    where
+        T: DecomposableInto<u64>,
+    {
+        let result = match self.select_one_hot_scalar_digit_blocks(ct, scalar) {
+            Some(blocks) => self.are_all_comparisons_block_true(blocks),
+            None => self.key.create_trivial(0),
+        };
+        self.one_hot_comparison_result(result, ct.num_digits())
+    }
+
+    /// Compares a one-hot ciphertext with a clear value for difference
+    ///
+    /// See [Self::one_hot_scalar_eq_parallelized].
+    pub fn one_hot_scalar_ne_parallelized<T>(
+        &self,
+        ct: &OneHotCiphertext,
+        scalar: T,
+    ) -> RadixCiphertext
+    where
+        T: DecomposableInto<u64>,
+    {
+        let result = match self.select_one_hot_scalar_digit_blocks(ct, scalar) {
+            Some(blocks) => self.is_any_comparison_block_false(blocks),
+            None => self.key.create_trivial(1),
+        };
+        self.one_hot_comparison_result(result, ct.num_digits())
+    }
+
+    /// Computes whether a one-hot ciphertext is greater than a clear value
+    ///
+    /// The comparison of each digit is a sum of its blocks, bootstrapped once: the result is
+    /// computed with one lookup table per digit, then the same reduction as the radix
+    /// comparisons.
+    ///
+    /// The result is a radix ciphertext with as many blocks as the input has digits, encrypting
+    /// 1 if the comparison is true and 0 otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_digits = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_digits);
+    ///
+    /// let ct = cks.as_ref().encrypt_one_hot(42u64, num_digits);
+    ///
+    /// let ct_res = sks.one_hot_scalar_gt_parallelized(&ct, 41u64);
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 1);
+    ///
+    /// let ct_res = sks.one_hot_scalar_le_parallelized(&ct, 41u64);
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 0);
+    /// ```
+    pub fn one_hot_scalar_gt_parallelized<T>(
+        &self,
+        ct: &OneHotCiphertext,
+        scalar: T,
+    ) -> RadixCiphertext
+    where
+        T: DecomposableInto<u64>,
+    {
+        self.one_hot_scalar_compare_parallelized(ct, scalar, |sign| sign == Comparator::IS_SUPERIOR)
+    }
+
+    /// Computes whether a one-hot ciphertext is greater or equal to a clear value
+    ///
+    /// See [Self::one_hot_scalar_gt_parallelized].
+    pub fn one_hot_scalar_ge_parallelized<T>(
+        &self,
+        ct: &OneHotCiphertext,
+        scalar: T,
+    ) -> RadixCiphertext
+    where
+        T: DecomposableInto<u64>,
+    {
+        self.one_hot_scalar_compare_parallelized(ct, scalar, |sign| sign != Comparator::IS_INFERIOR)
+    }
+
+    /// Computes whether a one-hot ciphertext is lower than a clear value
+    ///
+    /// See [Self::one_hot_scalar_gt_parallelized].
+    pub fn one_hot_scalar_lt_parallelized<T>(
+        &self,
+        ct: &OneHotCiphertext,
+        scalar: T,
+    ) -> RadixCiphertext
+    where
+        T: DecomposableInto<u64>,
+    {
+        self.one_hot_scalar_compare_parallelized(ct, scalar, |sign| sign == Comparator::IS_INFERIOR)
+    }
+
+    /// Computes whether a one-hot ciphertext is lower or equal to a clear value
+    ///
+    /// See [Self::one_hot_scalar_gt_parallelized].
+    pub fn one_hot_scalar_le_parallelized<T>(
+        &self,
+        ct: &OneHotCiphertext,
+        scalar: T,
+    ) -> RadixCiphertext
+    where
+        T: DecomposableInto<u64>,
+    {
+        self.one_hot_scalar_compare_parallelized(ct, scalar, |sign| sign != Comparator::IS_SUPERIOR)
+    }
+
+    /// Returns the digits of the scalar, or None if it cannot be represented with the number of
+    /// digits of the ciphertext
+    fn one_hot_scalar_digits<T>(&self, ct: &OneHotCiphertext, scalar: T) -> Option<Vec<u64>>
+    where
+        T: DecomposableInto<u64>,
+    {
+        let mut scalar_digits =
+            BlockDecomposer::with_early_stop_at_zero(scalar, self.key.message_modulus.0.ilog2())
+                .iter_as::<u64>()
+                .collect::<Vec<_>>();
+        if scalar_digits.len() > ct.num_digits() {
+            return None;
+        }
+        scalar_digits.resize(ct.num_digits(), 0);
+        Some(scalar_digits)
+    }
+
+    /// Returns, for each digit, the block which encrypts 1 if the digit is equal to the digit of
+    /// the scalar
+    fn select_one_hot_scalar_digit_blocks<T>(
+        &self,
+        ct: &OneHotCiphertext,
+        scalar: T,
+    ) -> Option<Vec<Ciphertext>>
+    where
+        T: DecomposableInto<u64>,
+    {
+        let scalar_digits = self.one_hot_scalar_digits(ct, scalar)?;
+        Some(
+            ct.digits()
+                .zip(scalar_digits)
+                .map(|(digit_blocks, scalar_digit)| digit_blocks[scalar_digit as usize].clone())
+                .collect(),
+        )
+    }
+
+    fn one_hot_scalar_compare_parallelized<T, F>(
+        &self,
+        ct: &OneHotCiphertext,
+        scalar: T,
+        sign_result_handler_fn: F,
+    ) -> RadixCiphertext
+    where
+        T: DecomposableInto<u64>,
+        F: Fn(u64) -> bool,
+    {
+        let num_digits = ct.num_digits();
+        let Some(scalar_digits) = self.one_hot_scalar_digits(ct, scalar) else {
+            // The scalar is greater than any value of the ciphertext
+            let result = u64::from(sign_result_handler_fn(Comparator::IS_INFERIOR));
+            return self.one_hot_comparison_result(self.key.create_trivial(result), num_digits);
+        };
+        if num_digits == 0 {
+            let result = u64::from(sign_result_handler_fn(Comparator::IS_EQUAL));
+            return self.one_hot_comparison_result(self.key.create_trivial(result), num_digits);
+        }
+
+        // For each digit, `count + 2 * is_equal` is computed, where `count` is the number of
+        // blocks before the digit of the scalar (i.e. the digit is lower), or the number of
+        // blocks after it (i.e. the digit is greater), whichever sums less blocks
+        let sign_from_lower_count_lut = self.key.generate_lookup_table(|x| match x {
+            0 => Comparator::IS_SUPERIOR,
+            1 => Comparator::IS_INFERIOR,
+            _ => Comparator::IS_EQUAL,
+        });
+        let sign_from_greater_count_lut = self.key.generate_lookup_table(|x| match x {
+            0 => Comparator::IS_INFERIOR,
+            1 => Comparator::IS_SUPERIOR,
+            _ => Comparator::IS_EQUAL,
+        });
+
+        let digits = ct.digits().zip(scalar_digits).collect::<Vec<_>>();
+        let sign_blocks = digits
+            .into_par_iter()
+            .map(|(digit_blocks, scalar_digit)| {
+                let scalar_digit = scalar_digit as usize;
+                let (counted_blocks, lut) = if 2 * scalar_digit < digit_blocks.len() {
+                    (&digit_blocks[..scalar_digit], &sign_from_lower_count_lut)
+                } else {
+                    (
+                        &digit_blocks[scalar_digit + 1..],
+                        &sign_from_greater_count_lut,
+                    )
+                };
+
+                let mut sum = self
+                    .key
+                    .unchecked_scalar_mul(&digit_blocks[scalar_digit], 2);
+                for block in counted_blocks {
+                    self.key.unchecked_add_assign(&mut sum, block);
+                }
+                self.key.apply_lookup_table(&sum, lut)
+            })
+            .collect::<Vec<_>>();
+
+        let sign = Comparator::new(self).reduce_signs_parallelized(sign_blocks);
+        let to_result_lut = self
+            .key
+            .generate_lookup_table(|sign| u64::from(sign_result_handler_fn(sign)));
+        let result = self.key.apply_lookup_table(&sign, &to_result_lut);
+        self.one_hot_comparison_result(result, num_digits)
+    }
+
+    fn one_hot_comparison_result(&self, result: Ciphertext, num_digits: usize) -> RadixCiphertext {
+        self.extend_radix_with_trivial_zero_blocks_msb(
+            &RadixCiphertext::from_blocks(vec![result]),
+            num_digits.saturating_sub(1),
+        )
+    }
+}
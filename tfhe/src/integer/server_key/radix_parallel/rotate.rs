@@ -69,6 +69,8 @@ impl ServerKey {
     ///
     /// The result is returned as a new ciphertext.
     ///
+    /// The rotation amount is taken modulo the number of bits of `ct`.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -231,6 +233,8 @@ impl ServerKey {
     ///
     /// The result is returned as a new ciphertext.
     ///
+    /// The rotation amount is taken modulo the number of bits of `ct`.
+    ///
     /// # Example
     ///
     /// ```rust
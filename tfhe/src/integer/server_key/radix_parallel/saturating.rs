@@ -0,0 +1,695 @@
+use crate::integer::ciphertext::IntegerRadixCiphertext;
+use crate::integer::server_key::comparator::ZeroComparisonType;
+use crate::integer::{RadixCiphertext, ServerKey, SignedRadixCiphertext};
+use crate::shortint::Ciphertext;
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Computes homomorphically the saturating addition of two ciphertexts
+    ///
+    /// If the addition overflows, the result is the maximum (or minimum for negative
+    /// signed results) value that can be represented.
+    ///
+    /// # Requirements
+    ///
+    /// - The inputs must have their carries empty
+    pub fn unchecked_saturating_add_parallelized<T>(&self, lhs: &T, rhs: &T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if T::IS_SIGNED {
+            let result = self.add_parallelized(lhs, rhs);
+            let num_blocks = result.blocks().len();
+            let (lhs_last, rhs_last, result_last) = (
+                &lhs.blocks()[num_blocks - 1],
+                &rhs.blocks()[num_blocks - 1],
+                &result.blocks()[num_blocks - 1],
+            );
+
+            // The addition overflowed if the inputs have the same sign
+            // and the result has a different sign
+            let (overflowed, bounds) = rayon::join(
+                || {
+                    let (lhs_sign_changed, rhs_sign_changed) = rayon::join(
+                        || self.sign_bits_differ(lhs_last, result_last),
+                        || self.sign_bits_differ(rhs_last, result_last),
+                    );
+                    self.key.unchecked_apply_lookup_table_bivariate(
+                        &lhs_sign_changed,
+                        &rhs_sign_changed,
+                        &self.key.generate_lookup_table_bivariate(|x, y| x & y),
+                    )
+                },
+                || {
+                    let sign_bit_pos = self.key.message_modulus.0.ilog2() - 1;
+                    self.signed_saturation_bounds(lhs_last, num_blocks, |x| {
+                        (x >> sign_bit_pos) & 1 == 1
+                    })
+                },
+            );
+
+            self.unchecked_programmable_if_then_else_parallelized(
+                &overflowed,
+                &bounds,
+                &result,
+                |x| x == 1,
+                true,
+            )
+        } else {
+            let lhs = RadixCiphertext::from_blocks(lhs.blocks().to_vec());
+            let rhs = RadixCiphertext::from_blocks(rhs.blocks().to_vec());
+            let (mut result, carry) = self.unchecked_add_with_carry_out_parallelized(&lhs, &rhs);
+            let max_block_value = self.key.message_modulus.0 as u64 - 1;
+            self.set_all_blocks_if(&mut result, &carry, max_block_value);
+            T::from_blocks(result.blocks)
+        }
+    }
+
+    /// Computes homomorphically the saturating addition of two ciphertexts
+    ///
+    /// If the addition overflows, the result is the maximum (or minimum for negative
+    /// signed results) value that can be represented.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = 200u8;
+    /// let msg2 = 100u8;
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// let ct_res = sks.saturating_add_parallelized(&ct1, &ct2);
+    ///
+    /// let dec: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msg1.saturating_add(msg2));
+    ///
+    /// let msg1 = -100i8;
+    /// let msg2 = -50i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.saturating_add_parallelized(&ct1, &ct2);
+    ///
+    /// let dec: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec, msg1.saturating_add(msg2));
+    /// ```
+    pub fn saturating_add_parallelized<T>(&self, lhs: &T, rhs: &T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        self.saturating_binary_op(lhs, rhs, Self::unchecked_saturating_add_parallelized)
+    }
+
+    /// Computes homomorphically the saturating subtraction of two ciphertexts
+    ///
+    /// If the subtraction overflows, the result is the maximum (or minimum for negative
+    /// signed results) value that can be represented.
+    ///
+    /// # Requirements
+    ///
+    /// - The inputs must have their carries empty
+    pub fn unchecked_saturating_sub_parallelized<T>(&self, lhs: &T, rhs: &T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if T::IS_SIGNED {
+            let result = self.sub_parallelized(lhs, rhs);
+            let num_blocks = result.blocks().len();
+            let (lhs_last, rhs_last, result_last) = (
+                &lhs.blocks()[num_blocks - 1],
+                &rhs.blocks()[num_blocks - 1],
+                &result.blocks()[num_blocks - 1],
+            );
+
+            // The subtraction overflowed if the inputs have different signs
+            // and the result does not have the sign of lhs
+            let (overflowed, bounds) = rayon::join(
+                || {
+                    let (input_signs_differ, lhs_sign_changed) = rayon::join(
+                        || self.sign_bits_differ(lhs_last, rhs_last),
+                        || self.sign_bits_differ(lhs_last, result_last),
+                    );
+                    self.key.unchecked_apply_lookup_table_bivariate(
+                        &input_signs_differ,
+                        &lhs_sign_changed,
+                        &self.key.generate_lookup_table_bivariate(|x, y| x & y),
+                    )
+                },
+                || {
+                    let sign_bit_pos = self.key.message_modulus.0.ilog2() - 1;
+                    self.signed_saturation_bounds(lhs_last, num_blocks, |x| {
+                        (x >> sign_bit_pos) & 1 == 1
+                    })
+                },
+            );
+
+            self.unchecked_programmable_if_then_else_parallelized(
+                &overflowed,
+                &bounds,
+                &result,
+                |x| x == 1,
+                true,
+            )
+        } else {
+            let lhs = RadixCiphertext::from_blocks(lhs.blocks().to_vec());
+            let rhs = RadixCiphertext::from_blocks(rhs.blocks().to_vec());
+            let (mut result, borrow) =
+                self.unchecked_unsigned_overflowing_sub_parallelized(&lhs, &rhs);
+            self.set_all_blocks_if(&mut result, &borrow, 0);
+            T::from_blocks(result.blocks)
+        }
+    }
+
+    /// Computes homomorphically the saturating subtraction of two ciphertexts
+    ///
+    /// If the subtraction overflows, the result is the maximum (or minimum for negative
+    /// signed results) value that can be represented.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = 37u8;
+    /// let msg2 = 142u8;
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// let ct_res = sks.saturating_sub_parallelized(&ct1, &ct2);
+    ///
+    /// let dec: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msg1.saturating_sub(msg2));
+    ///
+    /// let msg1 = 100i8;
+    /// let msg2 = -50i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.saturating_sub_parallelized(&ct1, &ct2);
+    ///
+    /// let dec: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec, msg1.saturating_sub(msg2));
+    /// ```
+    pub fn saturating_sub_parallelized<T>(&self, lhs: &T, rhs: &T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        self.saturating_binary_op(lhs, rhs, Self::unchecked_saturating_sub_parallelized)
+    }
+
+    /// Computes homomorphically the saturating multiplication of two ciphertexts
+    ///
+    /// If the multiplication overflows, the result is the maximum (or minimum for negative
+    /// signed results) value that can be represented.
+    ///
+    /// The overflow is detected by computing the product on twice the number of blocks.
+    ///
+    /// # Requirements
+    ///
+    /// - The inputs must have their carries empty
+    pub fn unchecked_saturating_mul_parallelized<T>(&self, lhs: &T, rhs: &T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        assert_eq!(
+            lhs.blocks().len(),
+            rhs.blocks().len(),
+            "lhs and rhs must have the same number of blocks"
+        );
+        let num_blocks = lhs.blocks().len();
+
+        if T::IS_SIGNED {
+            let (lhs_ext, rhs_ext) = rayon::join(
+                || {
+                    let lhs = SignedRadixCiphertext::from_blocks(lhs.blocks().to_vec());
+                    self.extend_radix_with_sign_msb(&lhs, num_blocks)
+                },
+                || {
+                    let rhs = SignedRadixCiphertext::from_blocks(rhs.blocks().to_vec());
+                    self.extend_radix_with_sign_msb(&rhs, num_blocks)
+                },
+            );
+            let mut result = self.mul_parallelized(&lhs_ext, &rhs_ext);
+            let high_blocks = result.blocks.split_off(num_blocks);
+
+            // The multiplication did not overflow if all the upper blocks
+            // are the sign extension of the lower blocks
+            let (overflowed, bounds) = rayon::join(
+                || {
+                    let sign_bit_pos = self.key.message_modulus.0.ilog2() - 1;
+                    let max_block_value = self.key.message_modulus.0 as u64 - 1;
+                    let sign_extension_lut = self.key.generate_lookup_table(|x| {
+                        if (x >> sign_bit_pos) & 1 == 1 {
+                            max_block_value
+                        } else {
+                            0
+                        }
+                    });
+                    let sign_extension = self
+                        .key
+                        .apply_lookup_table(&result.blocks[num_blocks - 1], &sign_extension_lut);
+
+                    let is_not_equal_lut = self
+                        .key
+                        .generate_lookup_table_bivariate(|x, y| u64::from(x != y));
+                    let comparisons = high_blocks
+                        .par_iter()
+                        .map(|block| {
+                            self.key.unchecked_apply_lookup_table_bivariate(
+                                block,
+                                &sign_extension,
+                                &is_not_equal_lut,
+                            )
+                        })
+                        .collect::<Vec<_>>();
+                    self.is_at_least_one_comparisons_block_true(comparisons)
+                },
+                || {
+                    let signs_differ = self.sign_bits_differ(
+                        &lhs.blocks()[num_blocks - 1],
+                        &rhs.blocks()[num_blocks - 1],
+                    );
+                    self.signed_saturation_bounds(&signs_differ, num_blocks, |x| x == 1)
+                },
+            );
+
+            let result = T::from_blocks(result.blocks);
+            self.unchecked_programmable_if_then_else_parallelized(
+                &overflowed,
+                &bounds,
+                &result,
+                |x| x == 1,
+                true,
+            )
+        } else {
+            let (lhs_ext, rhs_ext) = rayon::join(
+                || {
+                    let lhs = RadixCiphertext::from_blocks(lhs.blocks().to_vec());
+                    self.extend_radix_with_trivial_zero_blocks_msb(&lhs, num_blocks)
+                },
+                || {
+                    let rhs = RadixCiphertext::from_blocks(rhs.blocks().to_vec());
+                    self.extend_radix_with_trivial_zero_blocks_msb(&rhs, num_blocks)
+                },
+            );
+            let mut result = self.mul_parallelized(&lhs_ext, &rhs_ext);
+            let high_blocks = result.blocks.split_off(num_blocks);
+
+            let comparisons =
+                self.compare_blocks_with_zero(&high_blocks, ZeroComparisonType::Difference);
+            let overflowed = self.is_at_least_one_comparisons_block_true(comparisons);
+
+            let max_block_value = self.key.message_modulus.0 as u64 - 1;
+            self.set_all_blocks_if(&mut result, &overflowed, max_block_value);
+            T::from_blocks(result.blocks)
+        }
+    }
+
+    /// Computes homomorphically the saturating multiplication of two ciphertexts
+    ///
+    /// If the multiplication overflows, the result is the maximum (or minimum for negative
+    /// signed results) value that can be represented.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = 37u8;
+    /// let msg2 = 12u8;
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// let ct_res = sks.saturating_mul_parallelized(&ct1, &ct2);
+    ///
+    /// let dec: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msg1.saturating_mul(msg2));
+    ///
+    /// let msg1 = -37i8;
+    /// let msg2 = 12i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.saturating_mul_parallelized(&ct1, &ct2);
+    ///
+    /// let dec: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec, msg1.saturating_mul(msg2));
+    /// ```
+    pub fn saturating_mul_parallelized<T>(&self, lhs: &T, rhs: &T) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        self.saturating_binary_op(lhs, rhs, Self::unchecked_saturating_mul_parallelized)
+    }
+
+    /// Removes the `num_blocks` most significant blocks of the ciphertext, and returns a block
+    /// encrypting 1 if the value does not fit in the remaining blocks, 0 otherwise
+    ///
+    /// For signed ciphertexts, the value fits if the removed blocks are the sign extension of
+    /// the remaining ones.
+    ///
+    /// # Requirements
+    ///
+    /// - The input must have its carries empty
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_blocks` is not lower than the number of blocks of the ciphertext
+    pub fn unchecked_overflowing_trim_radix_blocks_msb_parallelized<T>(
+        &self,
+        ct: &T,
+        num_blocks: usize,
+    ) -> (T, Ciphertext)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let num_blocks_to_keep = Self::num_blocks_after_trim(ct.blocks().len(), num_blocks);
+
+        let (low_blocks, high_blocks) = ct.blocks().split_at(num_blocks_to_keep);
+        let overflowed = if high_blocks.is_empty() {
+            self.key.create_trivial(0)
+        } else if T::IS_SIGNED {
+            let sign_bit_pos = self.key.message_modulus.0.ilog2() - 1;
+            let max_block_value = self.key.message_modulus.0 as u64 - 1;
+            let sign_extension_lut = self.key.generate_lookup_table(|x| {
+                if (x >> sign_bit_pos) & 1 == 1 {
+                    max_block_value
+                } else {
+                    0
+                }
+            });
+            let sign_extension = self
+                .key
+                .apply_lookup_table(&low_blocks[num_blocks_to_keep - 1], &sign_extension_lut);
+
+            let is_not_equal_lut = self
+                .key
+                .generate_lookup_table_bivariate(|x, y| u64::from(x != y));
+            let comparisons = high_blocks
+                .par_iter()
+                .map(|block| {
+                    self.key.unchecked_apply_lookup_table_bivariate(
+                        block,
+                        &sign_extension,
+                        &is_not_equal_lut,
+                    )
+                })
+                .collect::<Vec<_>>();
+            self.is_at_least_one_comparisons_block_true(comparisons)
+        } else {
+            let comparisons =
+                self.compare_blocks_with_zero(high_blocks, ZeroComparisonType::Difference);
+            self.is_at_least_one_comparisons_block_true(comparisons)
+        };
+
+        (T::from_blocks(low_blocks.to_vec()), overflowed)
+    }
+
+    /// Removes the `num_blocks` most significant blocks of the ciphertext, and returns a block
+    /// encrypting 1 if the value does not fit in the remaining blocks, 0 otherwise
+    ///
+    /// This is the checked version of [Self::trim_radix_blocks_msb], which silently drops the
+    /// removed blocks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_blocks` is not lower than the number of blocks of the ciphertext
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{gen_keys_radix, IntegerCiphertext};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let ct = cks.encrypt(200u8);
+    /// let (ct_res, overflowed) = sks.overflowing_trim_radix_blocks_msb_parallelized(&ct, 2);
+    /// assert_eq!(ct_res.blocks().len(), 2);
+    /// let dec: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 200 % 16);
+    /// assert_eq!(cks.decrypt_one_block(&overflowed), 1);
+    ///
+    /// let ct = cks.encrypt_signed(-7i8);
+    /// let (ct_res, overflowed) = sks.overflowing_trim_radix_blocks_msb_parallelized(&ct, 2);
+    /// let dec: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec, -7);
+    /// assert_eq!(cks.decrypt_one_block(&overflowed), 0);
+    /// ```
+    pub fn overflowing_trim_radix_blocks_msb_parallelized<T>(
+        &self,
+        ct: &T,
+        num_blocks: usize,
+    ) -> (T, Ciphertext)
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp_ct;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp_ct = ct.clone();
+            self.full_propagate_parallelized(&mut tmp_ct);
+            &tmp_ct
+        };
+
+        self.unchecked_overflowing_trim_radix_blocks_msb_parallelized(ct, num_blocks)
+    }
+
+    /// Removes the `num_blocks` most significant blocks of the ciphertext, saturating to the
+    /// maximum (or minimum for negative signed values) value the remaining blocks can represent
+    /// if the value does not fit in them
+    ///
+    /// # Requirements
+    ///
+    /// - The input must have its carries empty
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_blocks` is not lower than the number of blocks of the ciphertext
+    pub fn unchecked_saturating_trim_radix_blocks_msb_parallelized<T>(
+        &self,
+        ct: &T,
+        num_blocks: usize,
+    ) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        if T::IS_SIGNED {
+            let num_blocks_to_keep = Self::num_blocks_after_trim(ct.blocks().len(), num_blocks);
+            let ((result, overflowed), bounds) = rayon::join(
+                || self.unchecked_overflowing_trim_radix_blocks_msb_parallelized(ct, num_blocks),
+                || {
+                    // The sign of the value is the sign of its most significant block
+                    let sign_bit_pos = self.key.message_modulus.0.ilog2() - 1;
+                    let last_block = &ct.blocks()[ct.blocks().len() - 1];
+                    self.signed_saturation_bounds(last_block, num_blocks_to_keep, |x| {
+                        (x >> sign_bit_pos) & 1 == 1
+                    })
+                },
+            );
+
+            self.unchecked_programmable_if_then_else_parallelized(
+                &overflowed,
+                &bounds,
+                &result,
+                |x| x == 1,
+                true,
+            )
+        } else {
+            let (result, overflowed) =
+                self.unchecked_overflowing_trim_radix_blocks_msb_parallelized(ct, num_blocks);
+            let mut result = RadixCiphertext::from_blocks(result.into_blocks());
+            let max_block_value = self.key.message_modulus.0 as u64 - 1;
+            self.set_all_blocks_if(&mut result, &overflowed, max_block_value);
+            T::from_blocks(result.blocks)
+        }
+    }
+
+    /// Removes the `num_blocks` most significant blocks of the ciphertext, saturating to the
+    /// maximum (or minimum for negative signed values) value the remaining blocks can represent
+    /// if the value does not fit in them
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_blocks` is not lower than the number of blocks of the ciphertext
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let ct = cks.encrypt(200u8);
+    /// let ct_res = sks.saturating_trim_radix_blocks_msb_parallelized(&ct, 2);
+    /// let dec: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 15);
+    ///
+    /// let ct = cks.encrypt_signed(-100i8);
+    /// let ct_res = sks.saturating_trim_radix_blocks_msb_parallelized(&ct, 2);
+    /// let dec: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec, -8);
+    /// ```
+    pub fn saturating_trim_radix_blocks_msb_parallelized<T>(&self, ct: &T, num_blocks: usize) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp_ct;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp_ct = ct.clone();
+            self.full_propagate_parallelized(&mut tmp_ct);
+            &tmp_ct
+        };
+
+        self.unchecked_saturating_trim_radix_blocks_msb_parallelized(ct, num_blocks)
+    }
+
+    fn num_blocks_after_trim(ct_num_blocks: usize, num_blocks_to_remove: usize) -> usize {
+        assert!(
+            num_blocks_to_remove < ct_num_blocks,
+            "Cannot remove {num_blocks_to_remove} blocks from a ciphertext \
+            of {ct_num_blocks} blocks"
+        );
+        ct_num_blocks - num_blocks_to_remove
+    }
+
+    fn saturating_binary_op<T, F>(&self, lhs: &T, rhs: &T, unchecked_op: F) -> T
+    where
+        T: IntegerRadixCiphertext,
+        F: Fn(&Self, &T, &T) -> T,
+    {
+        let mut tmp_lhs;
+        let mut tmp_rhs;
+
+        let (lhs, rhs) = match (lhs.block_carries_are_empty(), rhs.block_carries_are_empty()) {
+            (true, true) => (lhs, rhs),
+            (true, false) => {
+                tmp_rhs = rhs.clone();
+                self.full_propagate_parallelized(&mut tmp_rhs);
+                (lhs, &tmp_rhs)
+            }
+            (false, true) => {
+                tmp_lhs = lhs.clone();
+                self.full_propagate_parallelized(&mut tmp_lhs);
+                (&tmp_lhs, rhs)
+            }
+            (false, false) => {
+                tmp_lhs = lhs.clone();
+                tmp_rhs = rhs.clone();
+                rayon::join(
+                    || self.full_propagate_parallelized(&mut tmp_lhs),
+                    || self.full_propagate_parallelized(&mut tmp_rhs),
+                );
+                (&tmp_lhs, &tmp_rhs)
+            }
+        };
+
+        unchecked_op(self, lhs, rhs)
+    }
+
+    /// Returns a block encrypting 1 if the sign bits of the two input
+    /// most significant blocks differ, 0 otherwise
+    fn sign_bits_differ(&self, lhs_last: &Ciphertext, rhs_last: &Ciphertext) -> Ciphertext {
+        let sign_bit_pos = self.key.message_modulus.0.ilog2() - 1;
+        let lut = self.key.generate_lookup_table_bivariate(|x, y| {
+            ((x >> sign_bit_pos) & 1) ^ ((y >> sign_bit_pos) & 1)
+        });
+        self.key
+            .unchecked_apply_lookup_table_bivariate(lhs_last, rhs_last, &lut)
+    }
+
+    /// Returns the value a signed saturating operation saturates to.
+    ///
+    /// The result is the minimum value if `is_negative` is true for the value encrypted
+    /// in `block`, the maximum value otherwise.
+    fn signed_saturation_bounds<T, F>(
+        &self,
+        block: &Ciphertext,
+        num_blocks: usize,
+        is_negative: F,
+    ) -> T
+    where
+        T: IntegerRadixCiphertext,
+        F: Fn(u64) -> bool + Sync,
+    {
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let half_modulus = message_modulus / 2;
+
+        let (low_block, high_block) = rayon::join(
+            || {
+                let lut = self.key.generate_lookup_table(|x| {
+                    if is_negative(x) {
+                        0
+                    } else {
+                        message_modulus - 1
+                    }
+                });
+                self.key.apply_lookup_table(block, &lut)
+            },
+            || {
+                let lut = self.key.generate_lookup_table(|x| {
+                    if is_negative(x) {
+                        half_modulus
+                    } else {
+                        half_modulus - 1
+                    }
+                });
+                self.key.apply_lookup_table(block, &lut)
+            },
+        );
+
+        let mut blocks = vec![low_block; num_blocks - 1];
+        blocks.push(high_block);
+        T::from_blocks(blocks)
+    }
+
+    /// Replaces the value of each block of `ct` by `value` if `condition` encrypts 1
+    fn set_all_blocks_if(&self, ct: &mut RadixCiphertext, condition: &Ciphertext, value: u64) {
+        let lut =
+            self.key.generate_lookup_table_bivariate(
+                |block, condition| {
+                    if condition == 1 {
+                        value
+                    } else {
+                        block
+                    }
+                },
+            );
+
+        ct.blocks.par_iter_mut().for_each(|block| {
+            self.key
+                .unchecked_apply_lookup_table_bivariate_assign(block, condition, &lut);
+        });
+    }
+}
@@ -0,0 +1,109 @@
+use crate::integer::block_decomposition::DecomposableInto;
+use crate::integer::ciphertext::IntegerRadixCiphertext;
+use crate::integer::server_key::radix::scalar_mul::ScalarMultiplier;
+use crate::integer::server_key::radix::scalar_sub::TwosComplementNegation;
+use crate::integer::ServerKey;
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Applies `op` to each ciphertext and its scalar, parallelizing across the elements.
+    ///
+    /// Each rayon worker computes with the buffers of its thread local engine, which are reused
+    /// from one element to the next.
+    fn scalar_op_batch_parallelized<T, Scalar, F>(&self, cts: &mut [T], scalars: &[Scalar], op: F)
+    where
+        T: IntegerRadixCiphertext,
+        Scalar: Copy + Send + Sync,
+        F: Fn(&mut T, Scalar) + Send + Sync,
+    {
+        assert_eq!(
+            cts.len(),
+            scalars.len(),
+            "The batch has {} ciphertexts but {} scalars",
+            cts.len(),
+            scalars.len()
+        );
+
+        cts.par_iter_mut()
+            .zip(scalars.par_iter())
+            .for_each(|(ct, &scalar)| op(ct, scalar));
+    }
+
+    /// Adds to each ciphertext of `cts` the scalar at the same index in `scalars`.
+    ///
+    /// This is equivalent to calling [Self::scalar_add_assign_parallelized] on each element, but
+    /// the elements are computed in parallel, which suits large batches better than parallelizing
+    /// each addition.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cts` and `scalars` do not have the same length.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let msgs = [4u64, 129, 200];
+    /// let offsets = [40u64, 1, 100];
+    ///
+    /// let mut cts = msgs.iter().map(|&msg| cks.encrypt(msg)).collect::<Vec<_>>();
+    ///
+    /// // Compute homomorphically the additions:
+    /// sks.scalar_add_batch_parallelized(&mut cts, &offsets);
+    ///
+    /// // Decrypt:
+    /// for ((ct, msg), offset) in cts.iter().zip(msgs).zip(offsets) {
+    ///     let dec: u64 = cks.decrypt(ct);
+    ///     assert_eq!((msg + offset) % 256, dec);
+    /// }
+    /// ```
+    pub fn scalar_add_batch_parallelized<T, Scalar>(&self, cts: &mut [T], scalars: &[Scalar])
+    where
+        T: IntegerRadixCiphertext,
+        Scalar: DecomposableInto<u8>,
+    {
+        self.scalar_op_batch_parallelized(cts, scalars, |ct, scalar| {
+            self.scalar_add_assign_parallelized(ct, scalar);
+        });
+    }
+
+    /// Subtracts from each ciphertext of `cts` the scalar at the same index in `scalars`.
+    ///
+    /// See [Self::scalar_add_batch_parallelized].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cts` and `scalars` do not have the same length.
+    pub fn scalar_sub_batch_parallelized<T, Scalar>(&self, cts: &mut [T], scalars: &[Scalar])
+    where
+        T: IntegerRadixCiphertext,
+        Scalar: TwosComplementNegation + DecomposableInto<u8>,
+    {
+        self.scalar_op_batch_parallelized(cts, scalars, |ct, scalar| {
+            self.scalar_sub_assign_parallelized(ct, scalar);
+        });
+    }
+
+    /// Multiplies each ciphertext of `cts` by the scalar at the same index in `scalars`.
+    ///
+    /// See [Self::scalar_add_batch_parallelized].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cts` and `scalars` do not have the same length.
+    pub fn scalar_mul_batch_parallelized<T, Scalar>(&self, cts: &mut [T], scalars: &[Scalar])
+    where
+        T: IntegerRadixCiphertext,
+        Scalar: ScalarMultiplier + DecomposableInto<u8>,
+    {
+        self.scalar_op_batch_parallelized(cts, scalars, |ct, scalar| {
+            self.scalar_mul_assign_parallelized(ct, scalar);
+        });
+    }
+}
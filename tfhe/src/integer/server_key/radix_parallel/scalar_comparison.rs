@@ -143,11 +143,35 @@ impl ServerKey {
     ///
     /// if the vec is empty, a trivial 1 is returned
     pub(crate) fn are_all_comparisons_block_true(
+        &self,
+        block_comparisons: Vec<Ciphertext>,
+    ) -> Ciphertext {
+        self.reduce_comparison_blocks(block_comparisons, false)
+    }
+
+    /// This takes a Vec of shortint blocks, where each block is
+    /// either 0 or 1.
+    ///
+    /// It return a shortint block encrypting 1 if at least one input block is 0
+    /// otherwise the block encrypts 0
+    ///
+    /// if the vec is empty, a trivial 0 is returned
+    pub(crate) fn is_any_comparison_block_false(
+        &self,
+        block_comparisons: Vec<Ciphertext>,
+    ) -> Ciphertext {
+        self.reduce_comparison_blocks(block_comparisons, true)
+    }
+
+    // Computes whether all blocks are true, the negation is merged
+    // in the lookup table of the last reduction step when `negate` is set
+    fn reduce_comparison_blocks(
         &self,
         mut block_comparisons: Vec<Ciphertext>,
+        negate: bool,
     ) -> Ciphertext {
         if block_comparisons.is_empty() {
-            return self.key.create_trivial(1);
+            return self.key.create_trivial(u64::from(!negate));
         }
 
         let message_modulus = self.key.message_modulus.0;
@@ -155,11 +179,20 @@ impl ServerKey {
         let total_modulus = message_modulus * carry_modulus;
         let max_value = total_modulus - 1;
 
-        let is_max_value = self
-            .key
-            .generate_lookup_table(|x| u64::from(x == max_value as u64));
+        if block_comparisons.len() == 1 && negate {
+            let not_lut = self.key.generate_lookup_table(|x| u64::from(x == 0));
+            self.key
+                .apply_lookup_table_assign(&mut block_comparisons[0], &not_lut);
+        }
 
         while block_comparisons.len() > 1 {
+            let is_last_step = block_comparisons.len() <= max_value;
+            let negate_output = is_last_step && negate;
+
+            let is_max_value = self
+                .key
+                .generate_lookup_table(|x| u64::from((x == max_value as u64) != negate_output));
+
             // Since all blocks encrypt either 0 or 1, we can sum max_value of them
             // as in the worst case we will be adding `max_value` ones
             block_comparisons = block_comparisons
@@ -173,9 +206,9 @@ impl ServerKey {
                     if blocks.len() == max_value {
                         self.key.apply_lookup_table(&sum, &is_max_value)
                     } else {
-                        let is_equal_to_num_blocks = self
-                            .key
-                            .generate_lookup_table(|x| u64::from(x == blocks.len() as u64));
+                        let is_equal_to_num_blocks = self.key.generate_lookup_table(|x| {
+                            u64::from((x == blocks.len() as u64) != negate_output)
+                        });
                         self.key.apply_lookup_table(&sum, &is_equal_to_num_blocks)
                     }
                 })
@@ -673,6 +706,14 @@ impl ServerKey {
         Comparator::new(self).unchecked_scalar_min_parallelized(lhs, rhs)
     }
 
+    pub fn unchecked_scalar_cmp_parallelized<T, Scalar>(&self, lhs: &T, rhs: Scalar) -> Ciphertext
+    where
+        T: IntegerRadixCiphertext,
+        Scalar: DecomposableInto<u64>,
+    {
+        Comparator::new(self).unchecked_scalar_cmp_parallelized(lhs, rhs)
+    }
+
     //===========================================================
     // Smart <, >, <=, >=, min, max
     //===========================================================
@@ -776,4 +817,15 @@ impl ServerKey {
     {
         Comparator::new(self).scalar_min_parallelized(lhs, rhs)
     }
+
+    /// Compares `lhs` and the scalar `rhs`, and returns a block encrypting their ordering.
+    ///
+    /// See [Self::cmp_parallelized]
+    pub fn scalar_cmp_parallelized<T, Scalar>(&self, lhs: &T, rhs: Scalar) -> Ciphertext
+    where
+        T: IntegerRadixCiphertext,
+        Scalar: DecomposableInto<u64>,
+    {
+        Comparator::new(self).scalar_cmp_parallelized(lhs, rhs)
+    }
 }
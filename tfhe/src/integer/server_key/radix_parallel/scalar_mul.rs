@@ -357,6 +357,33 @@ impl ServerKey {
             return;
         }
 
+        let num_blocks = lhs.blocks().len();
+        let all_shifted_lhs = self.scalar_mul_terms(lhs, scalar);
+
+        if let Some(result) = self.unchecked_sum_ciphertexts_vec_parallelized(all_shifted_lhs) {
+            *lhs = result;
+        } else {
+            self.create_trivial_zero_assign_radix(lhs);
+        }
+    }
+
+    /// Returns the shifted copies of `lhs` whose sum is `lhs * scalar`
+    ///
+    /// The shifts by whole blocks are free, only the shifts within a block need a PBS, so the
+    /// terms have empty carries if `lhs` has. There is no term if the scalar is 0.
+    fn scalar_mul_terms<T, Scalar>(&self, lhs: &T, scalar: Scalar) -> Vec<T>
+    where
+        T: IntegerRadixCiphertext,
+        Scalar: ScalarMultiplier + DecomposableInto<u8>,
+    {
+        if scalar == Scalar::ZERO || lhs.blocks().is_empty() {
+            return vec![];
+        }
+
+        if scalar == Scalar::ONE {
+            return vec![lhs.clone()];
+        }
+
         let num_blocks = lhs.blocks().len();
         let msg_bits = self.key.message_modulus.0.ilog2() as usize;
 
@@ -396,11 +423,7 @@ impl ServerKey {
             .map(|(i, _)| self.blockshift(&preshifted_lhs[i % msg_bits], i / msg_bits))
             .collect::<Vec<_>>();
 
-        if let Some(result) = self.unchecked_sum_ciphertexts_vec_parallelized(all_shifted_lhs) {
-            *lhs = result;
-        } else {
-            self.create_trivial_zero_assign_radix(lhs);
-        }
+        all_shifted_lhs
     }
 
     /// Computes homomorphically a multiplication between a scalar and a ciphertext.
@@ -508,4 +531,169 @@ impl ServerKey {
 
         self.unchecked_scalar_mul_assign_parallelized(lhs, scalar);
     }
+
+    /// Computes homomorphically the dot product between ciphertexts and clear scalars.
+    ///
+    /// The products are not propagated: the shifted copies of the ciphertexts making up all the
+    /// products are summed at once, with a single final carry propagation.
+    ///
+    /// - Returns None if `cts` is empty
+    ///
+    /// - Expects all ciphertexts to have empty carries
+    /// - Expects all ciphertexts to have the same number of blocks
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cts` and `scalars` do not have the same length.
+    pub fn unchecked_scalar_dot_product_parallelized<T, Scalar>(
+        &self,
+        cts: &[T],
+        scalars: &[Scalar],
+    ) -> Option<T>
+    where
+        T: IntegerRadixCiphertext,
+        Scalar: ScalarMultiplier + DecomposableInto<u8>,
+    {
+        assert_eq!(
+            cts.len(),
+            scalars.len(),
+            "Expected as many scalars as ciphertexts, got {} scalars for {} ciphertexts",
+            scalars.len(),
+            cts.len()
+        );
+
+        let (first, _) = cts.split_first()?;
+
+        let terms = cts
+            .par_iter()
+            .zip(scalars.par_iter())
+            .flat_map_iter(|(ct, &scalar)| self.scalar_mul_terms(ct, scalar))
+            .collect::<Vec<_>>();
+
+        Some(
+            self.unchecked_sum_ciphertexts_vec_parallelized(terms)
+                .unwrap_or_else(|| self.create_trivial_zero_radix(first.blocks().len())),
+        )
+    }
+
+    /// Computes homomorphically the dot product between ciphertexts and clear scalars.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case. It outputs a ciphertext whose block carries are always empty.
+    ///
+    /// - Returns None if `cts` is empty
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cts` and `scalars` do not have the same length, or if the ciphertexts do not
+    /// have the same number of blocks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let modulus = 1 << 8;
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let msgs = [23u64, 150, 7];
+    /// let weights = [3u64, 0, 41];
+    ///
+    /// let cts = msgs.iter().map(|&m| cks.encrypt(m)).collect::<Vec<_>>();
+    ///
+    /// // Compute homomorphically the dot product:
+    /// let ct_res = sks.scalar_dot_product_parallelized(&cts, &weights).unwrap();
+    ///
+    /// // Decrypt:
+    /// let clear: u64 = cks.decrypt(&ct_res);
+    /// let expected = msgs.iter().zip(weights.iter()).map(|(m, w)| m * w).sum::<u64>();
+    /// assert_eq!(expected % modulus, clear);
+    /// ```
+    pub fn scalar_dot_product_parallelized<T, Scalar>(
+        &self,
+        cts: &[T],
+        scalars: &[Scalar],
+    ) -> Option<T>
+    where
+        T: IntegerRadixCiphertext,
+        Scalar: ScalarMultiplier + DecomposableInto<u8>,
+    {
+        if cts.iter().all(|ct| ct.block_carries_are_empty()) {
+            return self.unchecked_scalar_dot_product_parallelized(cts, scalars);
+        }
+
+        let cts = cts
+            .par_iter()
+            .map(|ct| {
+                let mut ct = ct.clone();
+                if !ct.block_carries_are_empty() {
+                    self.full_propagate_parallelized(&mut ct);
+                }
+                ct
+            })
+            .collect::<Vec<_>>();
+
+        self.unchecked_scalar_dot_product_parallelized(&cts, scalars)
+    }
+
+    /// Computes homomorphically `ct * mul + add` where `mul` and `add` are scalars.
+    ///
+    /// The product is not propagated before the addition, the result only needs a single carry
+    /// propagation.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertext block carries are empty and clears them if it's not the
+    /// case. It outputs a ciphertext whose block carries are always empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let modulus = 1 << 8;
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let msg = 230;
+    /// let mul = 37;
+    /// let add = 101;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// // Compute homomorphically the fused multiplication and addition:
+    /// let ct_res = sks.scalar_mul_add_parallelized(&ct, mul, add);
+    ///
+    /// // Decrypt:
+    /// let clear: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!((msg * mul + add) % modulus, clear);
+    /// ```
+    pub fn scalar_mul_add_parallelized<T, Scalar>(&self, ct: &T, mul: Scalar, add: Scalar) -> T
+    where
+        T: IntegerRadixCiphertext,
+        Scalar: ScalarMultiplier + DecomposableInto<u8> + DecomposableInto<u64>,
+    {
+        let mut tmp_ct;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp_ct = ct.clone();
+            self.full_propagate_parallelized(&mut tmp_ct);
+            &tmp_ct
+        };
+
+        let num_blocks = ct.blocks().len();
+        let mut terms = self.scalar_mul_terms(ct, mul);
+        if add != Scalar::ZERO {
+            terms.push(self.create_trivial_radix(add, num_blocks));
+        }
+
+        self.unchecked_sum_ciphertexts_vec_parallelized(terms)
+            .unwrap_or_else(|| self.create_trivial_zero_radix(num_blocks))
+    }
 }
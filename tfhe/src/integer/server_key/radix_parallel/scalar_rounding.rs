@@ -0,0 +1,189 @@
+use super::scalar_div_mod::Reciprocable;
+use crate::integer::block_decomposition::DecomposableInto;
+use crate::integer::ciphertext::IntegerRadixCiphertext;
+use crate::integer::server_key::ScalarMultiplier;
+use crate::integer::{RadixCiphertext, ServerKey};
+use crate::shortint::Ciphertext;
+
+/// How the result of a division or of a right shift is rounded to an integer
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum RoundingMode {
+    /// Rounds down, this is the rounding of the `/` and `>>` operators
+    Floor,
+    /// Rounds up
+    Ceil,
+    /// Rounds to the nearest integer, and ties to the even integer (banker's rounding)
+    HalfEven,
+}
+
+impl ServerKey {
+    /// Computes homomorphically the division of a ciphertext by a scalar, rounded with the
+    /// given mode
+    ///
+    /// The result cannot overflow: for a non-zero divisor, the rounded quotient is never
+    /// greater than the numerator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the divisor is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::integer::server_key::RoundingMode;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let ct = cks.encrypt(230u8);
+    ///
+    /// let ct_res = sks.scalar_div_rounded_parallelized(&ct, 20u8, RoundingMode::Ceil);
+    /// let dec: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 12);
+    ///
+    /// // 230 / 20 = 11.5, the tie is rounded to the even quotient
+    /// let ct_res = sks.scalar_div_rounded_parallelized(&ct, 20u8, RoundingMode::HalfEven);
+    /// let dec: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 12);
+    /// ```
+    pub fn scalar_div_rounded_parallelized<T>(
+        &self,
+        numerator: &RadixCiphertext,
+        divisor: T,
+        mode: RoundingMode,
+    ) -> RadixCiphertext
+    where
+        T: Reciprocable + ScalarMultiplier + DecomposableInto<u8> + DecomposableInto<u64>,
+    {
+        if mode == RoundingMode::Floor {
+            return self.scalar_div_parallelized(numerator, divisor);
+        }
+
+        let (mut quotient, remainder) = self.scalar_div_rem_parallelized(numerator, divisor);
+
+        let round_up = if mode == RoundingMode::Ceil {
+            self.scalar_ne_parallelized(&remainder, T::ZERO).blocks[0].clone()
+        } else {
+            let half = divisor >> 1;
+            let ((is_above_half, is_half), is_odd) = rayon::join(
+                || {
+                    rayon::join(
+                        || self.scalar_gt_parallelized(&remainder, half),
+                        || self.scalar_eq_parallelized(&remainder, half),
+                    )
+                },
+                || self.unchecked_get_bit_parallelized(&quotient, 0),
+            );
+            if (divisor & T::ONE) == T::ONE {
+                // With an odd divisor, the remainder cannot be exactly half of it
+                is_above_half.blocks[0].clone()
+            } else {
+                let is_tie_to_odd = self.key.bitand(&is_half.blocks[0], &is_odd);
+                self.key.bitor(&is_above_half.blocks[0], &is_tie_to_odd)
+            }
+        };
+
+        self.add_rounding_bit_assign(&mut quotient, round_up);
+        quotient
+    }
+
+    /// Computes homomorphically a right shift, rounded with the given mode
+    ///
+    /// This is the division by `2^shift`, see [Self::scalar_div_rounded_parallelized].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `shift` is greater or equal to the number of bits of the ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::integer::server_key::RoundingMode;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg = 0b1011_0110u8;
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.scalar_right_shift_rounded_parallelized(&ct, 2, RoundingMode::HalfEven);
+    /// let dec: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, 0b10_1110);
+    /// ```
+    pub fn scalar_right_shift_rounded_parallelized(
+        &self,
+        ct: &RadixCiphertext,
+        shift: u32,
+        mode: RoundingMode,
+    ) -> RadixCiphertext {
+        let num_bits = self.key.message_modulus.0.ilog2() * ct.blocks.len() as u32;
+        assert!(
+            shift < num_bits,
+            "Shift {shift} is out of range for a ciphertext of {num_bits} bits"
+        );
+
+        let mut cloned;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            cloned = ct.clone();
+            self.full_propagate_parallelized(&mut cloned);
+            &cloned
+        };
+
+        if mode == RoundingMode::Floor || shift == 0 {
+            return self.unchecked_scalar_right_shift_parallelized(ct, shift as u64);
+        }
+
+        // The bits shifted out are moved to the most significant bits, so that they are compared
+        // to 0 without needing a scalar as large as the ciphertext
+        let (mut quotient, round_up) = rayon::join(
+            || self.unchecked_scalar_right_shift_parallelized(ct, shift as u64),
+            || match mode {
+                RoundingMode::Floor => unreachable!(),
+                RoundingMode::Ceil => {
+                    let shifted_out = self
+                        .unchecked_scalar_left_shift_parallelized(ct, (num_bits - shift) as u64);
+                    self.scalar_ne_parallelized(&shifted_out, 0u64).blocks[0].clone()
+                }
+                RoundingMode::HalfEven => {
+                    let (round_bit, is_odd) = rayon::join(
+                        || self.unchecked_get_bit_parallelized(ct, shift as usize - 1),
+                        || self.unchecked_get_bit_parallelized(ct, shift as usize),
+                    );
+                    // Rounds up when the shifted out bits are above half, or exactly half with
+                    // an odd quotient
+                    let has_lower_bits = (shift > 1).then(|| {
+                        let below_half = self.unchecked_scalar_left_shift_parallelized(
+                            ct,
+                            (num_bits - shift + 1) as u64,
+                        );
+                        self.scalar_ne_parallelized(&below_half, 0u64).blocks[0].clone()
+                    });
+                    let should_round = match has_lower_bits {
+                        Some(has_lower_bits) => self.key.bitor(&has_lower_bits, &is_odd),
+                        None => is_odd,
+                    };
+                    self.key.bitand(&round_bit, &should_round)
+                }
+            },
+        );
+
+        self.add_rounding_bit_assign(&mut quotient, round_up);
+        quotient
+    }
+
+    fn add_rounding_bit_assign(&self, ct: &mut RadixCiphertext, round_up: Ciphertext) {
+        let round_up = self.extend_radix_with_trivial_zero_blocks_msb(
+            &RadixCiphertext::from_blocks(vec![round_up]),
+            ct.blocks.len() - 1,
+        );
+        self.add_assign_parallelized(ct, &round_up);
+    }
+}
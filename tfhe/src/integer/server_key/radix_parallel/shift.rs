@@ -107,6 +107,8 @@ impl ServerKey {
     ///
     /// The result is returned as a new ciphertext.
     ///
+    /// Like Rust's `wrapping_shl/shr`, the shift is taken modulo the number of bits of `ct`.
+    ///
     /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
     /// check that the input ciphertexts block carries are empty and clears them if it's not the
     /// case and the operation requires it. It outputs a ciphertext whose block carries are always
@@ -255,6 +257,8 @@ impl ServerKey {
     ///
     /// The result is returned as a new ciphertext.
     ///
+    /// Like Rust's `wrapping_shl/shr`, the shift is taken modulo the number of bits of `ct`.
+    ///
     /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
     /// check that the input ciphertexts block carries are empty and clears them if it's not the
     /// case and the operation requires it. It outputs a ciphertext whose block carries are always
@@ -306,15 +310,14 @@ impl ServerKey {
     /// - shift to have clean carries
     /// - the number of bits in the block to be >= 3
     ///
-    /// Similarly to rust `wrapping_shl/shr` functions
-    /// it removes any high-order bits of `shift`
-    /// that would cause the shift to exceed the bitwidth of the type.
+    /// Similarly to rust `wrapping_shl/shr` and `rotate_left/right` functions,
+    /// the `shift` is taken modulo the number of bits of `ct`, so shifting
+    /// by a value greater than or equal to the bitwidth is well defined.
     ///
-    /// **However**, when the total number of bits represented by the
-    /// radix ciphertext is not a power of two (eg a ciphertext with 12 bits)
-    /// then, it removes bit that are higher than the closest higher power of two.
-    /// So for a 12 bits radix ciphertext, its closest higher power of two is 16,
-    /// thus, any bit that are higher than log2(16) will be removed
+    /// When the number of bits of `ct` is a power of two, this simply removes
+    /// the high-order bits of `shift`, otherwise (eg a ciphertext with 12 bits)
+    /// the remainder of the division of `shift` by the number of bits is
+    /// computed first, which costs an encrypted scalar division.
     ///
     /// `ct` will be assigned the result, and it will be in a fresh state
     pub(super) fn barrel_shifter<T>(
@@ -325,7 +328,7 @@ impl ServerKey {
     ) where
         T: IntegerRadixCiphertext,
     {
-        let num_blocks = shift.blocks.len();
+        let num_blocks = ct.blocks().len();
         let message_bits_per_block = self.key.message_modulus.0.ilog2() as u64;
         let carry_bits_per_block = self.key.carry_modulus.0.ilog2() as u64;
         let total_nb_bits = message_bits_per_block * num_blocks as u64;
@@ -335,6 +338,20 @@ impl ServerKey {
             "Blocks must have at least 3 bits"
         );
 
+        // When the number of bits is not a power of two, masking the high-order bits
+        // of the shift is not enough to reduce it modulo the number of bits.
+        // This is only needed if the shift can encrypt a value >= total_nb_bits
+        let shift_nb_bits = message_bits_per_block * shift.blocks.len() as u64;
+        let reduced_shift;
+        let shift = if !total_nb_bits.is_power_of_two()
+            && shift_nb_bits > u64::from(total_nb_bits.ilog2())
+        {
+            reduced_shift = self.unchecked_scalar_rem_parallelized(shift, total_nb_bits);
+            &reduced_shift
+        } else {
+            shift
+        };
+
         let (bits, shift_bits) = rayon::join(
             || {
                 let bit_extractor = BitExtractor::new(self, message_bits_per_block as usize);
@@ -342,11 +359,8 @@ impl ServerKey {
             },
             || {
                 let mut max_num_bits_that_tell_shift = total_nb_bits.ilog2() as u64;
-                // This effectively means, that if the block parameters
-                // give a total_nb_bits that is not a power of two,
-                // then the behaviour of shifting won't be the same
-                // if shift >= total_nb_bits compared to when total_nb_bits
-                // is a power of two, as will 'capture' more bits in `shift_bits`
+                // When total_nb_bits is not a power of two, the shift has been reduced
+                // and is < total_nb_bits, which needs one more bit to be represented
                 if !total_nb_bits.is_power_of_two() {
                     max_num_bits_that_tell_shift += 1;
                 }
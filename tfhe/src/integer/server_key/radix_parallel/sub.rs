@@ -410,6 +410,56 @@ impl ServerKey {
         }
     }
 
+    /// Computes homomorphically the subtraction of two ciphertexts and returns the borrow
+    /// coming out of the most significant block.
+    ///
+    /// The returned borrow is a single block encrypting either 0 or 1, which means
+    /// the overflow of the subtraction can be known without having to extend the inputs
+    /// with an extra block.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg_1 = 37u8;
+    /// let msg_2 = 142u8;
+    ///
+    /// let ctxt_1 = cks.encrypt(msg_1);
+    /// let ctxt_2 = cks.encrypt(msg_2);
+    ///
+    /// // Compute homomorphically a subtraction:
+    /// let (result, borrow) = sks.sub_with_borrow_out_parallelized(&ctxt_1, &ctxt_2);
+    ///
+    /// // Decrypt:
+    /// let decrypted_result: u8 = cks.decrypt(&result);
+    /// let decrypted_borrow = cks.decrypt_one_block(&borrow) == 1;
+    ///
+    /// let (expected_result, expected_borrow) = msg_1.overflowing_sub(msg_2);
+    /// assert_eq!(expected_result, decrypted_result);
+    /// assert_eq!(expected_borrow, decrypted_borrow);
+    /// ```
+    pub fn sub_with_borrow_out_parallelized(
+        &self,
+        ctxt_left: &RadixCiphertext,
+        ctxt_right: &RadixCiphertext,
+    ) -> (RadixCiphertext, Ciphertext) {
+        self.unsigned_overflowing_sub_parallelized(ctxt_left, ctxt_right)
+    }
+
+    pub fn unchecked_sub_with_borrow_out_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> (RadixCiphertext, Ciphertext) {
+        self.unchecked_unsigned_overflowing_sub_parallelized(lhs, rhs)
+    }
+
     pub(super) fn generate_init_borrow_array(&self, sum_ct: &RadixCiphertext) -> Vec<Ciphertext> {
         let modulus = self.key.message_modulus.0 as u64;
 
@@ -1,5 +1,5 @@
 use crate::integer::ciphertext::{RadixCiphertext, SignedRadixCiphertext};
-use crate::integer::{gen_keys, ServerKey, I256, U256};
+use crate::integer::{gen_keys, IntegerCiphertext, ServerKey, I256, U256};
 use crate::shortint::ClassicPBSParameters;
 use rand;
 use rand::prelude::*;
@@ -253,6 +253,59 @@ fn test_max_parallelized_256_bits(params: crate::shortint::ClassicPBSParameters,
     )
 }
 
+fn integer_cmp_parallelized_256_bits(params: crate::shortint::ClassicPBSParameters) {
+    test_default_function(
+        params,
+        1,
+        |sks, lhs, rhs| {
+            let ordering = sks.cmp_parallelized(lhs, rhs);
+            sks.ordering_matches(&ordering, |o| o.is_le(), lhs.blocks().len())
+        },
+        |lhs, rhs| U256::from(u128::from(lhs <= rhs)),
+    );
+    test_default_function(
+        params,
+        1,
+        |sks, lhs, rhs| {
+            let ordering = sks.cmp_parallelized(lhs, rhs);
+            sks.ordering_select_parallelized(&ordering, rhs, lhs, lhs)
+        },
+        std::cmp::max,
+    );
+}
+
+fn integer_scalar_cmp_parallelized_256_bits(params: crate::shortint::ClassicPBSParameters) {
+    test_default_scalar_function(
+        params,
+        1,
+        |sks, lhs, rhs| {
+            let ordering = sks.scalar_cmp_parallelized(lhs, rhs);
+            let trivial = |value: u64| -> RadixCiphertext {
+                sks.create_trivial_radix(value, lhs.blocks().len())
+            };
+            sks.ordering_select_parallelized(&ordering, &trivial(1), &trivial(2), &trivial(3))
+        },
+        |lhs, rhs| match lhs.cmp(&rhs) {
+            std::cmp::Ordering::Less => U256::from(1u128),
+            std::cmp::Ordering::Equal => U256::from(2u128),
+            std::cmp::Ordering::Greater => U256::from(3u128),
+        },
+    )
+}
+
+create_parametrized_test!(integer_cmp_parallelized_256_bits {
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    // We don't use PARAM_MESSAGE_3_CARRY_3_KS_PBS,
+    // as default test might overflow values
+    // and when using 3_3 to represent 256 we actually have more than 256 bits
+    // of message so the overflow behaviour is not the same, leading to false negatives
+    PARAM_MESSAGE_4_CARRY_4_KS_PBS
+});
+create_parametrized_test!(integer_scalar_cmp_parallelized_256_bits {
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MESSAGE_4_CARRY_4_KS_PBS
+});
+
 /// This macro generates the tests for a given comparison fn
 ///
 /// All our comparison function have 5 variants:
@@ -374,6 +427,35 @@ define_comparison_test_functions!(le);
 define_comparison_test_functions!(gt);
 define_comparison_test_functions!(ge);
 
+/// Each pair of blocks is compared on its own, so a single differing block must be detected
+/// whatever its position
+#[test]
+fn test_eq_ne_with_one_differing_block_param_message_2_carry_2_ks_pbs() {
+    let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    let mut rng = rand::thread_rng();
+
+    let num_block = 8;
+    let bits_per_block = PARAM_MESSAGE_2_CARRY_2_KS_PBS.message_modulus.0.ilog2();
+    let clear_a = rng.gen::<u16>();
+    let a: RadixCiphertext = cks.encrypt_radix(clear_a, num_block);
+
+    for block_index in 0..num_block {
+        let clear_b = clear_a ^ (1 << (block_index as u32 * bits_per_block));
+        let b: RadixCiphertext = cks.encrypt_radix(clear_b, num_block);
+
+        let eq = sks.unchecked_eq_parallelized(&a, &b);
+        let ne = sks.unchecked_ne_parallelized(&a, &b);
+        assert_eq!(cks.decrypt_radix::<u16>(&eq), 0);
+        assert_eq!(cks.decrypt_radix::<u16>(&ne), 1);
+    }
+
+    let a_copy: RadixCiphertext = cks.encrypt_radix(clear_a, num_block);
+    let eq = sks.unchecked_eq_parallelized(&a, &a_copy);
+    let ne = sks.unchecked_ne_parallelized(&a, &a_copy);
+    assert_eq!(cks.decrypt_radix::<u16>(&eq), 1);
+    assert_eq!(cks.decrypt_radix::<u16>(&ne), 0);
+}
+
 //================
 // Min
 //================
@@ -376,20 +376,15 @@ where
 
         // case when shift >= nb_bits
         {
-            let clear_shift = clear_shift.saturating_add(nb_bits);
-            let shift = cks.encrypt(clear_shift as u64);
+            // Any amount the shift ciphertext can hold, it has as many blocks as `ct`
+            let clear_shift = rng.gen_range(u64::from(nb_bits)..modulus);
+            let shift = cks.encrypt(clear_shift);
             let encrypted_result = executor.execute((&ct, &shift));
             let decrypted_result: u64 = cks.decrypt(&encrypted_result);
-            // When nb_bits is not a power of two
-            // then the behaviour is not the same
-            let mut nb_bits = modulus.ilog2();
-            if !nb_bits.is_power_of_two() {
-                nb_bits = nb_bits.next_power_of_two();
-            }
             // We mimic wrapping_shl manually as we use a bigger type
             // than the nb_bits we actually simulate in this test
             assert_eq!(
-                (clear << (clear_shift % nb_bits)) % modulus,
+                (clear << (clear_shift % u64::from(nb_bits))) % modulus,
                 decrypted_result
             );
         }
@@ -431,21 +426,16 @@ where
 
         // case when shift >= nb_bits
         {
-            let clear_shift = clear_shift.saturating_add(nb_bits);
-            let shift = cks.encrypt(clear_shift as u64);
+            // Any amount the shift ciphertext can hold, it has as many blocks as `ct`
+            let clear_shift = rng.gen_range(u64::from(nb_bits)..modulus);
+            let shift = cks.encrypt(clear_shift);
             let encrypted_result = executor.execute((&ct, &shift));
             let decrypted_result: u64 = cks.decrypt(&encrypted_result);
 
-            // When nb_bits is not a power of two
-            // then the behaviour is not the same
-            let mut nb_bits = modulus.ilog2();
-            if !nb_bits.is_power_of_two() {
-                nb_bits = nb_bits.next_power_of_two();
-            }
             // We mimic wrapping_shr manually as we use a bigger type
             // than the nb_bits we actually simulate in this test
             assert_eq!(
-                (clear >> (clear_shift % nb_bits)) % modulus,
+                (clear >> (clear_shift % u64::from(nb_bits))) % modulus,
                 decrypted_result
             );
         }
@@ -488,18 +478,13 @@ where
 
         // case when shift >= nb_bits
         {
-            let clear_shift = clear_shift.saturating_add(nb_bits);
-            let shift = cks.encrypt(clear_shift as u64);
+            // Any amount the shift ciphertext can hold, it has as many blocks as `ct`
+            let clear_shift = rng.gen_range(u64::from(nb_bits)..modulus);
+            let shift = cks.encrypt(clear_shift);
             let encrypted_result = executor.execute((&ct, &shift));
             let decrypted_result: u64 = cks.decrypt(&encrypted_result);
-            // When nb_bits is not a power of two
-            // then the behaviour is not the same
-            let true_nb_bits = nb_bits;
-            let mut nb_bits = nb_bits;
-            if !nb_bits.is_power_of_two() {
-                nb_bits = nb_bits.next_power_of_two();
-            }
-            let expected = rotate_left_helper(clear, clear_shift % nb_bits, true_nb_bits);
+            let expected =
+                rotate_left_helper(clear, (clear_shift % u64::from(nb_bits)) as u32, nb_bits);
             assert_eq!(expected, decrypted_result);
         }
     }
@@ -541,18 +526,13 @@ where
 
         // case when shift >= nb_bits
         {
-            let clear_shift = clear_shift.saturating_add(nb_bits);
-            let shift = cks.encrypt(clear_shift as u64);
+            // Any amount the shift ciphertext can hold, it has as many blocks as `ct`
+            let clear_shift = rng.gen_range(u64::from(nb_bits)..modulus);
+            let shift = cks.encrypt(clear_shift);
             let encrypted_result = executor.execute((&ct, &shift));
             let decrypted_result: u64 = cks.decrypt(&encrypted_result);
-            // When nb_bits is not a power of two
-            // then the behaviour is not the same
-            let true_nb_bits = nb_bits;
-            let mut nb_bits = nb_bits;
-            if !nb_bits.is_power_of_two() {
-                nb_bits = nb_bits.next_power_of_two();
-            }
-            let expected = rotate_right_helper(clear, clear_shift % nb_bits, true_nb_bits);
+            let expected =
+                rotate_right_helper(clear, (clear_shift % u64::from(nb_bits)) as u32, nb_bits);
             assert_eq!(expected, decrypted_result);
         }
     }
@@ -1897,6 +1877,237 @@ where
     }
 }
 
+pub(crate) fn default_overflowing_add_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (&'a RadixCiphertext, &'a RadixCiphertext),
+        (RadixCiphertext, Ciphertext),
+    >,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+
+    executor.setup(&cks, sks.clone());
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let (ct_res, result_overflowed) = executor.execute((&ctxt_0, &ctxt_1));
+        let (tmp_ct, tmp_o) = executor.execute((&ctxt_0, &ctxt_1));
+        assert!(ct_res.block_carries_are_empty());
+        assert!(result_overflowed.carry_is_empty());
+        assert_eq!(ct_res, tmp_ct, "Failed determinism check");
+        assert_eq!(tmp_o, result_overflowed, "Failed determinism check");
+
+        let expected_result = (clear_0 + clear_1) % modulus;
+        let expected_overflowed = clear_0 + clear_1 >= modulus;
+
+        let decrypted_result: u64 = cks.decrypt(&ct_res);
+        let decrypted_overflowed = cks.decrypt_one_block(&result_overflowed) == 1;
+        assert_eq!(
+            decrypted_result, expected_result,
+            "Invalid result for add, for ({clear_0} + {clear_1}) % {modulus} \
+             expected {expected_result}, got {decrypted_result}"
+        );
+        assert_eq!(
+            decrypted_overflowed,
+            expected_overflowed,
+            "Invalid overflow flag result for overflowing_add for ({clear_0} + {clear_1}) % {modulus} \
+             expected overflow flag {expected_overflowed}, got {decrypted_overflowed}"
+        );
+
+        for _ in 0..NB_TEST_SMALLER {
+            // Add non zero scalar to have non clean ciphertexts
+            let clear_2 = random_non_zero_value(&mut rng, modulus);
+            let clear_3 = random_non_zero_value(&mut rng, modulus);
+
+            let ctxt_0 = sks.unchecked_scalar_add(&ctxt_0, clear_2);
+            let ctxt_1 = sks.unchecked_scalar_add(&ctxt_1, clear_3);
+
+            let clear_lhs = clear_0.wrapping_add(clear_2) % modulus;
+            let clear_rhs = clear_1.wrapping_add(clear_3) % modulus;
+
+            let (ct_res, result_overflowed) = executor.execute((&ctxt_0, &ctxt_1));
+            assert!(ct_res.block_carries_are_empty());
+            assert!(result_overflowed.carry_is_empty());
+
+            let expected_result = (clear_lhs + clear_rhs) % modulus;
+            let expected_overflowed = clear_lhs + clear_rhs >= modulus;
+
+            let decrypted_result: u64 = cks.decrypt(&ct_res);
+            let decrypted_overflowed = cks.decrypt_one_block(&result_overflowed) == 1;
+            assert_eq!(
+                decrypted_result, expected_result,
+                "Invalid result for add, for ({clear_lhs} + {clear_rhs}) % {modulus} \
+                expected {expected_result}, got {decrypted_result}"
+            );
+            assert_eq!(
+                decrypted_overflowed,
+                expected_overflowed,
+                "Invalid overflow flag result for overflowing_add, for ({clear_lhs} + {clear_rhs}) % {modulus} \
+                expected overflow flag {expected_overflowed}, got {decrypted_overflowed}"
+            );
+        }
+    }
+}
+
+pub(crate) fn default_add_with_carry_in_test<P, T>(param: P, mut executor: T)
+where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<
+        (&'a RadixCiphertext, &'a RadixCiphertext, &'a Ciphertext),
+        (RadixCiphertext, Ciphertext),
+    >,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+
+    executor.setup(&cks, sks.clone());
+
+    for _ in 0..NB_TEST_SMALLER {
+        // Two limbs per operand, the carry of the low limbs
+        // is fed to the addition of the high limbs
+        let clear_lo_0 = rng.gen::<u64>() % modulus;
+        let clear_lo_1 = rng.gen::<u64>() % modulus;
+        let clear_hi_0 = rng.gen::<u64>() % modulus;
+        let clear_hi_1 = rng.gen::<u64>() % modulus;
+
+        let ctxt_lo_0 = cks.encrypt(clear_lo_0);
+        let ctxt_lo_1 = cks.encrypt(clear_lo_1);
+        let ctxt_hi_0 = cks.encrypt(clear_hi_0);
+        let ctxt_hi_1 = cks.encrypt(clear_hi_1);
+
+        let carry_in = sks.key.create_trivial(0);
+        let (ct_lo, carry) = executor.execute((&ctxt_lo_0, &ctxt_lo_1, &carry_in));
+        let (ct_hi, carry_out) = executor.execute((&ctxt_hi_0, &ctxt_hi_1, &carry));
+        assert!(ct_lo.block_carries_are_empty());
+        assert!(ct_hi.block_carries_are_empty());
+        assert!(carry_out.carry_is_empty());
+
+        let clear_lo = clear_lo_0 + clear_lo_1;
+        let expected_carry = clear_lo / modulus;
+        let clear_hi = clear_hi_0 + clear_hi_1 + expected_carry;
+
+        let decrypted_lo: u64 = cks.decrypt(&ct_lo);
+        let decrypted_hi: u64 = cks.decrypt(&ct_hi);
+        let decrypted_carry = cks.decrypt_one_block(&carry);
+        let decrypted_carry_out = cks.decrypt_one_block(&carry_out);
+        assert_eq!(decrypted_lo, clear_lo % modulus);
+        assert_eq!(decrypted_carry, expected_carry);
+        assert_eq!(
+            decrypted_hi,
+            clear_hi % modulus,
+            "Invalid result for add with carry in, for ({clear_hi_0} + {clear_hi_1} + {expected_carry}) % {modulus} \
+             expected {}, got {decrypted_hi}",
+            clear_hi % modulus,
+        );
+        assert_eq!(decrypted_carry_out, clear_hi / modulus);
+    }
+}
+
+/// Tests a saturating operation, `clear_op` is the non saturated clear operation
+/// (its result may be out of the range of representable values)
+pub(crate) fn default_saturating_op_test<P, T>(
+    param: P,
+    mut executor: T,
+    clear_op: impl Fn(u64, u64) -> Option<u64>,
+) where
+    P: Into<PBSParameters>,
+    T: for<'a> FunctionExecutor<(&'a RadixCiphertext, &'a RadixCiphertext), RadixCiphertext>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+    let sks = Arc::new(sks);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+
+    executor.setup(&cks, sks.clone());
+
+    let saturate = |clear_0: u64, clear_1: u64| match clear_op(clear_0, clear_1) {
+        Some(value) if value < modulus => value,
+        Some(_) => modulus - 1,
+        None => 0,
+    };
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let ct_res = executor.execute((&ctxt_0, &ctxt_1));
+        let tmp = executor.execute((&ctxt_0, &ctxt_1));
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp, "Failed determinism check");
+
+        let expected = saturate(clear_0, clear_1);
+        let decrypted: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            decrypted, expected,
+            "Invalid saturating result for ({clear_0}, {clear_1}), \
+             expected {expected}, got {decrypted}"
+        );
+
+        // Add non zero scalar to have non clean ciphertexts
+        let clear_2 = random_non_zero_value(&mut rng, modulus);
+        let ctxt_0 = sks.unchecked_scalar_add(&ctxt_0, clear_2);
+        let clear_0 = (clear_0 + clear_2) % modulus;
+
+        let ct_res = executor.execute((&ctxt_0, &ctxt_1));
+        assert!(ct_res.block_carries_are_empty());
+
+        let expected = saturate(clear_0, clear_1);
+        let decrypted: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            decrypted, expected,
+            "Invalid saturating result for ({clear_0}, {clear_1}), \
+             expected {expected}, got {decrypted}"
+        );
+    }
+
+    // Edge cases
+    for (clear_0, clear_1) in [
+        (0, 0),
+        (modulus - 1, 1),
+        (0, modulus - 1),
+        (modulus - 1, modulus - 1),
+    ] {
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let ct_res = executor.execute((&ctxt_0, &ctxt_1));
+        let decrypted: u64 = cks.decrypt(&ct_res);
+        assert_eq!(decrypted, saturate(clear_0, clear_1));
+    }
+}
+
 pub(crate) fn default_sub_test<P, T>(param: P, mut executor: T)
 where
     P: Into<PBSParameters>,
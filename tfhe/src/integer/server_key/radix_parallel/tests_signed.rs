@@ -1,5 +1,5 @@
 use crate::integer::keycache::KEY_CACHE;
-use crate::integer::RadixClientKey;
+use crate::integer::{RadixClientKey, ServerKey, SignedRadixCiphertext};
 use crate::shortint::parameters::*;
 use itertools::{iproduct, izip};
 use paste::paste;
@@ -690,19 +690,18 @@ where
 
         // case when shift >= nb_bits
         {
-            let clear_shift = clear_shift.saturating_add(nb_bits);
-            let shift = cks.encrypt(clear_shift as u64);
+            // Any amount the shift ciphertext can hold, it has as many blocks as `ct`
+            let clear_shift = rng.gen_range(u64::from(nb_bits)..1 << nb_bits);
+            let shift = cks.encrypt(clear_shift);
             let ct_res = sks.unchecked_left_shift_parallelized(&ct, &shift);
             let dec_res: i64 = cks.decrypt_signed(&ct_res);
-            // When nb_bits is not a power of two
-            // then the behaviour is not the same
-            let mut nb_bits = modulus.ilog2();
-            if !nb_bits.is_power_of_two() {
-                nb_bits = nb_bits.next_power_of_two();
-            }
             // We mimic wrapping_shl manually as we use a bigger type
             // than the nb_bits we actually simulate in this test
-            let clear_res = signed_left_shift_under_modulus(clear, clear_shift % nb_bits, modulus);
+            let clear_res = signed_left_shift_under_modulus(
+                clear,
+                (clear_shift % u64::from(nb_bits)) as u32,
+                modulus,
+            );
             assert_eq!(clear_res, dec_res);
         }
     }
@@ -741,20 +740,19 @@ where
 
         // case when shift >= nb_bits
         {
-            let clear_shift = clear_shift.saturating_add(nb_bits);
-            let shift = cks.encrypt(clear_shift as u64);
+            // Any amount the shift ciphertext can hold, it has as many blocks as `ct`
+            let clear_shift = rng.gen_range(u64::from(nb_bits)..1 << nb_bits);
+            let shift = cks.encrypt(clear_shift);
             let ct_res = sks.unchecked_right_shift_parallelized(&ct, &shift);
             let dec_res: i64 = cks.decrypt_signed(&ct_res);
 
-            // When nb_bits is not a power of two
-            // then the behaviour is not the same
-            let mut nb_bits = modulus.ilog2();
-            if !nb_bits.is_power_of_two() {
-                nb_bits = nb_bits.next_power_of_two();
-            }
             // We mimic wrapping_shr manually as we use a bigger type
             // than the nb_bits we actually simulate in this test
-            let clear_res = signed_right_shift_under_modulus(clear, clear_shift % nb_bits, modulus);
+            let clear_res = signed_right_shift_under_modulus(
+                clear,
+                (clear_shift % u64::from(nb_bits)) as u32,
+                modulus,
+            );
             assert_eq!(clear_res, dec_res);
         }
     }
@@ -794,18 +792,13 @@ where
 
         // case when shift >= nb_bits
         {
-            let clear_shift = clear_shift.saturating_add(nb_bits);
-            let shift = cks.encrypt(clear_shift as u64);
+            // Any amount the shift ciphertext can hold, it has as many blocks as `ct`
+            let clear_shift = rng.gen_range(u64::from(nb_bits)..1 << nb_bits);
+            let shift = cks.encrypt(clear_shift);
             let ct_res = sks.unchecked_rotate_left_parallelized(&ct, &shift);
             let dec_res: i64 = cks.decrypt_signed(&ct_res);
-            // When nb_bits is not a power of two
-            // then the behaviour is not the same
-            let true_nb_bits = nb_bits;
-            let mut nb_bits = nb_bits;
-            if !nb_bits.is_power_of_two() {
-                nb_bits = nb_bits.next_power_of_two();
-            }
-            let expected = rotate_left_helper(clear, clear_shift % nb_bits, true_nb_bits);
+            let expected =
+                rotate_left_helper(clear, (clear_shift % u64::from(nb_bits)) as u32, nb_bits);
             assert_eq!(expected, dec_res);
         }
     }
@@ -844,18 +837,13 @@ where
 
         // case when shift >= nb_bits
         {
-            let clear_shift = clear_shift.saturating_add(nb_bits);
-            let shift = cks.encrypt(clear_shift as u64);
+            // Any amount the shift ciphertext can hold, it has as many blocks as `ct`
+            let clear_shift = rng.gen_range(u64::from(nb_bits)..1 << nb_bits);
+            let shift = cks.encrypt(clear_shift);
             let ct_res = sks.unchecked_rotate_right_parallelized(&ct, &shift);
             let dec_res: i64 = cks.decrypt_signed(&ct_res);
-            // When nb_bits is not a power of two
-            // then the behaviour is not the same
-            let true_nb_bits = nb_bits;
-            let mut nb_bits = nb_bits;
-            if !nb_bits.is_power_of_two() {
-                nb_bits = nb_bits.next_power_of_two();
-            }
-            let expected = rotate_right_helper(clear, clear_shift % nb_bits, true_nb_bits);
+            let expected =
+                rotate_right_helper(clear, (clear_shift % u64::from(nb_bits)) as u32, nb_bits);
             assert_eq!(expected, dec_res);
         }
     }
@@ -1113,6 +1101,12 @@ create_parametrized_test!(integer_signed_default_bitand);
 create_parametrized_test!(integer_signed_default_bitor);
 create_parametrized_test!(integer_signed_default_bitxor);
 create_parametrized_test!(integer_signed_default_absolute_value);
+create_parametrized_test!(integer_signed_default_signum);
+create_parametrized_test!(integer_signed_default_copysign);
+create_parametrized_test!(integer_signed_default_saturating_add);
+create_parametrized_test!(integer_signed_default_saturating_sub);
+create_parametrized_test!(integer_signed_default_saturating_mul);
+create_parametrized_test!(integer_signed_default_overflowing_and_saturating_trim_msb);
 create_parametrized_test!(integer_signed_default_left_shift {
     // Requires 3 bits, so 1_1 parameters are not supported
     // until they get their own version of the algorithm
@@ -1528,6 +1522,215 @@ fn integer_signed_default_absolute_value(param: impl Into<PBSParameters>) {
     }
 }
 
+fn integer_signed_default_signum(param: impl Into<PBSParameters>) {
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param);
+    sks.set_deterministic_pbs_execution(true);
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = (cks.parameters().message_modulus().0.pow(NB_CTXT as u32) / 2) as i64;
+
+    for clear_0 in [-modulus, -1, 0, 1, modulus - 1] {
+        let ctxt_0 = cks.encrypt_signed_radix(clear_0, NB_CTXT);
+        let ct_res = sks.signum_parallelized(&ctxt_0);
+        let dec_res: i64 = cks.decrypt_signed_radix(&ct_res);
+        assert_eq!(dec_res, clear_0.signum());
+    }
+
+    for _ in 0..NB_TEST_SMALLER {
+        let mut clear_0 = rng.gen::<i64>() % modulus;
+        let clear_to_add = rng.gen::<i64>() % modulus;
+
+        let mut ctxt_0 = cks.encrypt_signed_radix(clear_0, NB_CTXT);
+        sks.unchecked_scalar_add_assign(&mut ctxt_0, clear_to_add);
+        clear_0 = signed_add_under_modulus(clear_0, clear_to_add, modulus);
+
+        let ct_res = sks.signum_parallelized(&ctxt_0);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: i64 = cks.decrypt_signed_radix(&ct_res);
+        assert_eq!(clear_0.signum(), dec_res);
+
+        let ct_res2 = sks.signum_parallelized(&ctxt_0);
+        assert_eq!(ct_res2, ct_res);
+    }
+}
+
+fn integer_signed_default_copysign(param: impl Into<PBSParameters>) {
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param);
+    sks.set_deterministic_pbs_execution(true);
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = (cks.parameters().message_modulus().0.pow(NB_CTXT as u32) / 2) as i64;
+
+    let expected_copysign = |magnitude: i64, sign: i64| {
+        let abs = absolute_value_under_modulus(magnitude, modulus);
+        if sign < 0 {
+            signed_neg_under_modulus(abs, modulus)
+        } else {
+            abs
+        }
+    };
+
+    {
+        // The magnitude of -modulus cannot be represented
+        let clear_0 = -modulus;
+        let ctxt_0 = cks.encrypt_signed_radix(clear_0, NB_CTXT);
+        let ctxt_1 = cks.encrypt_signed_radix(1i64, NB_CTXT);
+        let ct_res = sks.copysign_parallelized(&ctxt_0, &ctxt_1);
+        let dec_res: i64 = cks.decrypt_signed_radix(&ct_res);
+        assert_eq!(dec_res, -modulus);
+    }
+
+    for _ in 0..NB_TEST_SMALLER {
+        let mut clear_0 = rng.gen::<i64>() % modulus;
+        let clear_1 = rng.gen::<i64>() % modulus;
+        let clear_to_add = rng.gen::<i64>() % modulus;
+
+        let mut ctxt_0 = cks.encrypt_signed_radix(clear_0, NB_CTXT);
+        let ctxt_1 = cks.encrypt_signed_radix(clear_1, NB_CTXT);
+        sks.unchecked_scalar_add_assign(&mut ctxt_0, clear_to_add);
+        clear_0 = signed_add_under_modulus(clear_0, clear_to_add, modulus);
+
+        let ct_res = sks.copysign_parallelized(&ctxt_0, &ctxt_1);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: i64 = cks.decrypt_signed_radix(&ct_res);
+        assert_eq!(
+            expected_copysign(clear_0, clear_1),
+            dec_res,
+            "Invalid copysign result for copysign({clear_0}, {clear_1})"
+        );
+
+        let ct_res2 = sks.copysign_parallelized(&ctxt_0, &ctxt_1);
+        assert_eq!(ct_res2, ct_res);
+    }
+}
+
+fn integer_signed_default_saturating_add(param: impl Into<PBSParameters>) {
+    signed_default_saturating_op_test(param, ServerKey::saturating_add_parallelized, |a, b| a + b);
+}
+
+fn integer_signed_default_saturating_sub(param: impl Into<PBSParameters>) {
+    signed_default_saturating_op_test(param, ServerKey::saturating_sub_parallelized, |a, b| a - b);
+}
+
+fn integer_signed_default_saturating_mul(param: impl Into<PBSParameters>) {
+    signed_default_saturating_op_test(param, ServerKey::saturating_mul_parallelized, |a, b| a * b);
+}
+
+/// `clear_op` computes the exact result, which is then clamped to the
+/// range of representable values
+fn signed_default_saturating_op_test<F>(
+    param: impl Into<PBSParameters>,
+    op: F,
+    clear_op: impl Fn(i64, i64) -> i64,
+) where
+    F: Fn(&ServerKey, &SignedRadixCiphertext, &SignedRadixCiphertext) -> SignedRadixCiphertext,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param);
+    sks.set_deterministic_pbs_execution(true);
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = (cks.parameters().message_modulus().0.pow(NB_CTXT as u32) / 2) as i64;
+
+    let saturate =
+        |clear_0: i64, clear_1: i64| clear_op(clear_0, clear_1).clamp(-modulus, modulus - 1);
+
+    for (clear_0, clear_1) in [
+        (-modulus, -1),
+        (-modulus, 1),
+        (modulus - 1, 1),
+        (modulus - 1, -modulus),
+        (-modulus, -modulus),
+    ] {
+        let ctxt_0 = cks.encrypt_signed_radix(clear_0, NB_CTXT);
+        let ctxt_1 = cks.encrypt_signed_radix(clear_1, NB_CTXT);
+        let ct_res = op(&sks, &ctxt_0, &ctxt_1);
+        let dec_res: i64 = cks.decrypt_signed_radix(&ct_res);
+        assert_eq!(
+            dec_res,
+            saturate(clear_0, clear_1),
+            "Invalid saturating result for ({clear_0}, {clear_1})"
+        );
+    }
+
+    for _ in 0..NB_TEST_SMALLER {
+        let mut clear_0 = rng.gen::<i64>() % modulus;
+        let clear_1 = rng.gen::<i64>() % modulus;
+        let clear_to_add = rng.gen::<i64>() % modulus;
+
+        let mut ctxt_0 = cks.encrypt_signed_radix(clear_0, NB_CTXT);
+        let ctxt_1 = cks.encrypt_signed_radix(clear_1, NB_CTXT);
+        sks.unchecked_scalar_add_assign(&mut ctxt_0, clear_to_add);
+        clear_0 = signed_add_under_modulus(clear_0, clear_to_add, modulus);
+
+        let ct_res = op(&sks, &ctxt_0, &ctxt_1);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: i64 = cks.decrypt_signed_radix(&ct_res);
+        assert_eq!(
+            dec_res,
+            saturate(clear_0, clear_1),
+            "Invalid saturating result for ({clear_0}, {clear_1})"
+        );
+
+        let ct_res2 = op(&sks, &ctxt_0, &ctxt_1);
+        assert_eq!(ct_res2, ct_res);
+    }
+}
+
+fn integer_signed_default_overflowing_and_saturating_trim_msb(param: impl Into<PBSParameters>) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    let mut rng = rand::thread_rng();
+
+    let num_blocks_to_remove = NB_CTXT / 2;
+    let modulus = (cks.parameters().message_modulus().0.pow(NB_CTXT as u32) / 2) as i64;
+    let trimmed_modulus = (cks
+        .parameters()
+        .message_modulus()
+        .0
+        .pow((NB_CTXT - num_blocks_to_remove) as u32)
+        / 2) as i64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let mut clear = rng.gen::<i64>() % modulus;
+        if rng.gen::<bool>() {
+            // Make sure values that fit are tested too
+            clear %= trimmed_modulus;
+        }
+        let clear_to_add = rng.gen::<i64>() % trimmed_modulus;
+
+        let mut ctxt = cks.encrypt_signed_radix(clear, NB_CTXT);
+        sks.unchecked_scalar_add_assign(&mut ctxt, clear_to_add);
+        let clear = signed_add_under_modulus(clear, clear_to_add, modulus);
+
+        let fits = (-trimmed_modulus..trimmed_modulus).contains(&clear);
+
+        let (ct_res, overflowed) =
+            sks.overflowing_trim_radix_blocks_msb_parallelized(&ctxt, num_blocks_to_remove);
+        assert_eq!(ct_res.blocks.len(), NB_CTXT - num_blocks_to_remove);
+        let dec_res: i64 = cks.decrypt_signed_radix(&ct_res);
+        let dec_overflowed = cks.decrypt_one_block(&overflowed);
+        let wrapped = (clear + trimmed_modulus).rem_euclid(2 * trimmed_modulus) - trimmed_modulus;
+        assert_eq!(dec_res, wrapped, "Invalid trimmed result for {clear}");
+        assert_eq!(
+            dec_overflowed,
+            u64::from(!fits),
+            "Invalid overflow flag for {clear}"
+        );
+
+        let ct_res = sks.saturating_trim_radix_blocks_msb_parallelized(&ctxt, num_blocks_to_remove);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: i64 = cks.decrypt_signed_radix(&ct_res);
+        assert_eq!(
+            dec_res,
+            clear.clamp(-trimmed_modulus, trimmed_modulus - 1),
+            "Invalid saturated result for {clear}"
+        );
+    }
+}
+
 fn integer_signed_default_left_shift<P>(param: P)
 where
     P: Into<PBSParameters>,
@@ -1590,12 +1793,6 @@ where
 
             let ct_res = sks.left_shift_parallelized(&ct, &shift);
             let dec_res: i64 = cks.decrypt_signed(&ct_res);
-            // When nb_bits is not a power of two
-            // then the behaviour is not the same
-            let mut nb_bits = modulus.ilog2();
-            if !nb_bits.is_power_of_two() {
-                nb_bits = nb_bits.next_power_of_two();
-            }
             // We mimic wrapping_shl manually as we use a bigger type
             // than the nb_bits we actually simulate in this test
             let clear_res = signed_left_shift_under_modulus(clear, clear_shift % nb_bits, modulus);
@@ -1673,12 +1870,6 @@ where
 
             let ct_res = sks.right_shift_parallelized(&ct, &shift);
             let dec_res: i64 = cks.decrypt_signed(&ct_res);
-            // When nb_bits is not a power of two
-            // then the behaviour is not the same
-            let mut nb_bits = modulus.ilog2();
-            if !nb_bits.is_power_of_two() {
-                nb_bits = nb_bits.next_power_of_two();
-            }
             // We mimic wrapping_shl manually as we use a bigger type
             // than the nb_bits we actually simulate in this test
             let clear_res = signed_right_shift_under_modulus(clear, clear_shift % nb_bits, modulus);
@@ -1756,16 +1947,9 @@ where
 
             let ct_res = sks.rotate_left_parallelized(&ct, &shift);
             let dec_res: i64 = cks.decrypt_signed(&ct_res);
-            // When nb_bits is not a power of two
-            // then the behaviour is not the same
-            let true_nb_bits = nb_bits;
-            let mut nb_bits = modulus.ilog2();
-            if !nb_bits.is_power_of_two() {
-                nb_bits = nb_bits.next_power_of_two();
-            }
             // We mimic wrapping_shl manually as we use a bigger type
             // than the nb_bits we actually simulate in this test
-            let clear_res = rotate_left_helper(clear, clear_shift % nb_bits, true_nb_bits);
+            let clear_res = rotate_left_helper(clear, clear_shift % nb_bits, nb_bits);
             assert_eq!(
                 clear_res,
                 dec_res,
@@ -1842,16 +2026,9 @@ where
 
             let ct_res = sks.rotate_right_parallelized(&ct, &shift);
             let dec_res: i64 = cks.decrypt_signed(&ct_res);
-            // When nb_bits is not a power of two
-            // then the behaviour is not the same
-            let true_nb_bits = nb_bits;
-            let mut nb_bits = modulus.ilog2();
-            if !nb_bits.is_power_of_two() {
-                nb_bits = nb_bits.next_power_of_two();
-            }
             // We mimic wrapping_shl manually as we use a bigger type
             // than the nb_bits we actually simulate in this test
-            let clear_res = rotate_right_helper(clear, clear_shift % nb_bits, true_nb_bits);
+            let clear_res = rotate_right_helper(clear, clear_shift % nb_bits, nb_bits);
             assert_eq!(
                 clear_res, dec_res,
                 "Invalid rotate right result, for '{clear}.rotate_right({clear_shift})', \
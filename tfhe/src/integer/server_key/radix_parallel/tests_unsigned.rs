@@ -1,9 +1,13 @@
 use crate::integer::keycache::KEY_CACHE;
-use crate::integer::{RadixCiphertext, RadixClientKey, ServerKey};
+use crate::integer::server_key::RoundingMode;
+use crate::integer::{EncryptedHeap, MulAlgorithm, RadixCiphertext, RadixClientKey, ServerKey};
 use crate::shortint::parameters::*;
 use paste::paste;
 use rand::Rng;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use super::tests_cases_unsigned::*;
 
@@ -110,6 +114,8 @@ create_parametrized_test!(integer_smart_add);
 create_parametrized_test!(integer_smart_add_sequence_multi_thread);
 create_parametrized_test!(integer_smart_add_sequence_single_thread);
 create_parametrized_test!(integer_default_add);
+create_parametrized_test!(integer_default_add_with_carry_out);
+create_parametrized_test!(integer_default_add_with_carry_in);
 create_parametrized_test!(integer_default_add_work_efficient {
     // This algorithm requires 3 bits
     PARAM_MESSAGE_2_CARRY_2_KS_PBS,
@@ -211,6 +217,11 @@ create_parametrized_test!(integer_default_neg);
 create_parametrized_test!(integer_smart_sub);
 create_parametrized_test!(integer_default_sub);
 create_parametrized_test!(integer_default_overflowing_sub);
+create_parametrized_test!(integer_default_sub_with_borrow_out);
+create_parametrized_test!(integer_default_saturating_add);
+create_parametrized_test!(integer_default_saturating_sub);
+create_parametrized_test!(integer_default_saturating_mul);
+create_parametrized_test!(integer_default_overflowing_and_saturating_trim_msb);
 create_parametrized_test!(integer_default_sub_work_efficient {
     // This algorithm requires 3 bits
     PARAM_MESSAGE_2_CARRY_2_KS_PBS,
@@ -227,6 +238,9 @@ create_parametrized_test!(integer_smart_block_mul);
 create_parametrized_test!(integer_default_block_mul);
 create_parametrized_test!(integer_smart_mul);
 create_parametrized_test!(integer_default_mul);
+create_parametrized_test!(integer_default_mul_algorithms_u128 {
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS
+});
 create_parametrized_test!(integer_smart_scalar_sub);
 create_parametrized_test!(integer_default_scalar_sub);
 create_parametrized_test!(integer_smart_scalar_add);
@@ -234,6 +248,33 @@ create_parametrized_test!(integer_default_scalar_add);
 create_parametrized_test!(integer_smart_if_then_else);
 create_parametrized_test!(integer_default_if_then_else);
 create_parametrized_test!(integer_trim_radix_msb_blocks_handles_dirty_inputs);
+create_parametrized_test!(integer_default_get_set_bit {
+    PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MESSAGE_3_CARRY_3_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS
+});
+create_parametrized_test!(integer_default_scalar_rounding {
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS
+});
+create_parametrized_test!(integer_default_one_hot_encoding {
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS
+});
+create_parametrized_test!(integer_default_scalar_batch {
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS
+});
+create_parametrized_test!(integer_default_sum_ciphertexts_with_deadline {
+    PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS
+});
+create_parametrized_test!(integer_default_encrypted_heap {
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS
+});
 
 create_parametrized_test!(integer_unchecked_add);
 create_parametrized_test!(integer_unchecked_mul);
@@ -717,6 +758,22 @@ where
     default_add_test(param, executor);
 }
 
+fn integer_default_add_with_carry_out<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::add_with_carry_out_parallelized);
+    default_overflowing_add_test(param, executor);
+}
+
+fn integer_default_add_with_carry_in<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::add_with_carry_in_parallelized);
+    default_add_with_carry_in_test(param, executor);
+}
+
 fn integer_default_sub<P>(param: P)
 where
     P: Into<PBSParameters>,
@@ -733,6 +790,91 @@ where
     default_overflowing_sub_test(param, executor);
 }
 
+fn integer_default_sub_with_borrow_out<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::sub_with_borrow_out_parallelized);
+    default_overflowing_sub_test(param, executor);
+}
+
+fn integer_default_saturating_add<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::saturating_add_parallelized);
+    default_saturating_op_test(param, executor, |a, b| a.checked_add(b));
+}
+
+fn integer_default_saturating_sub<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::saturating_sub_parallelized);
+    default_saturating_op_test(param, executor, |a, b| a.checked_sub(b));
+}
+
+fn integer_default_saturating_mul<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let executor = CpuFunctionExecutor::new(&ServerKey::saturating_mul_parallelized);
+    default_saturating_op_test(param, executor, |a, b| a.checked_mul(b));
+}
+
+fn integer_default_overflowing_and_saturating_trim_msb<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    let num_blocks_to_remove = NB_CTXT / 2;
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+    let trimmed_modulus = cks
+        .parameters()
+        .message_modulus()
+        .0
+        .pow((NB_CTXT - num_blocks_to_remove) as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let mut clear = rng.gen::<u64>() % modulus;
+        if rng.gen::<bool>() {
+            // Make sure values that fit are tested too
+            clear %= trimmed_modulus;
+        }
+        let clear_to_add = rng.gen::<u64>() % trimmed_modulus;
+
+        let ctxt = cks.encrypt(clear);
+        let ctxt = sks.unchecked_scalar_add(&ctxt, clear_to_add);
+        let clear = (clear + clear_to_add) % modulus;
+
+        let (ct_res, overflowed) =
+            sks.overflowing_trim_radix_blocks_msb_parallelized(&ctxt, num_blocks_to_remove);
+        assert_eq!(ct_res.blocks.len(), NB_CTXT - num_blocks_to_remove);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        let dec_overflowed = cks.decrypt_one_block(&overflowed);
+        assert_eq!(dec_res, clear % trimmed_modulus);
+        assert_eq!(
+            dec_overflowed,
+            u64::from(clear >= trimmed_modulus),
+            "Invalid overflow flag for {clear}"
+        );
+
+        let ct_res = sks.saturating_trim_radix_blocks_msb_parallelized(&ctxt, num_blocks_to_remove);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            dec_res,
+            clear.min(trimmed_modulus - 1),
+            "Invalid saturated result for {clear}"
+        );
+    }
+}
+
 // Smaller test for this one
 fn integer_default_add_work_efficient<P>(param: P)
 where
@@ -758,6 +900,45 @@ where
     default_mul_test(param, executor);
 }
 
+fn integer_default_mul_algorithms_u128<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param);
+    // 128 bits, the minimum size for which Karatsuba splits the operands
+    let num_blocks = 128 / cks.parameters().message_modulus().0.ilog2() as usize;
+    let cks = RadixClientKey::from((cks, num_blocks));
+
+    let mut rng = rand::thread_rng();
+
+    for algorithm in [
+        MulAlgorithm::CarrySave,
+        MulAlgorithm::Schoolbook,
+        MulAlgorithm::Karatsuba,
+    ] {
+        sks.set_mul_algorithm(algorithm);
+        assert_eq!(sks.mul_algorithm(), algorithm);
+
+        for _ in 0..2 {
+            let clear_0 = rng.gen::<u128>();
+            let clear_1 = rng.gen::<u128>();
+
+            let ctxt_0 = cks.encrypt(clear_0);
+            let ctxt_1 = cks.encrypt(clear_1);
+
+            let ct_res = sks.mul_parallelized(&ctxt_0, &ctxt_1);
+            assert!(ct_res.block_carries_are_empty());
+
+            let dec_res: u128 = cks.decrypt(&ct_res);
+            assert_eq!(
+                dec_res,
+                clear_0.wrapping_mul(clear_1),
+                "Invalid result for {algorithm:?}"
+            );
+        }
+    }
+}
+
 fn integer_default_neg<P>(param: P)
 where
     P: Into<PBSParameters>,
@@ -1104,6 +1285,325 @@ where
     assert_eq!(output, (msg2 + msg1) % (modulus));
 }
 
+fn integer_default_get_set_bit<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let num_bits = cks.parameters().message_modulus().0.ilog2() as usize * NB_CTXT;
+    let modulus = 1u64 << num_bits;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear = rng.gen::<u64>() % modulus;
+        let index = rng.gen_range(0..num_bits);
+        let new_bit = rng.gen::<u64>() % 2;
+
+        // Dirty input, so that the default functions have to propagate the carries
+        let mut ct = sks.unchecked_add(&cks.encrypt(clear), &cks.encrypt(0u64));
+        let ct_bit = cks.encrypt_one_block(new_bit);
+
+        let bit = sks.get_bit_parallelized(&ct, index);
+        assert_eq!(cks.decrypt_one_block(&bit), (clear >> index) & 1);
+
+        sks.set_bit_parallelized(&mut ct, index, &ct_bit);
+        let expected = (clear & !(1 << index)) | (new_bit << index);
+        let dec: u64 = cks.decrypt(&ct);
+        assert_eq!(
+            dec, expected,
+            "Invalid result when setting bit {index} of {clear}"
+        );
+        assert!(ct.block_carries_are_empty());
+
+        let bits = sks.to_bits_parallelized(&ct);
+        assert_eq!(bits.len(), num_bits);
+        for (i, bit) in bits.iter().enumerate() {
+            assert_eq!(cks.decrypt_one_block(bit), (expected >> i) & 1);
+        }
+
+        let ct: RadixCiphertext = sks.from_bits_parallelized(&bits);
+        assert_eq!(ct.blocks.len(), NB_CTXT);
+        let dec: u64 = cks.decrypt(&ct);
+        assert_eq!(dec, expected);
+    }
+}
+
+fn integer_default_scalar_rounding<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    let num_bits = cks.parameters().message_modulus().0.ilog2() * NB_CTXT as u32;
+    let modulus = 1u64 << num_bits;
+
+    let rounded_div = |numerator: u64, divisor: u64, mode: RoundingMode| {
+        let (quotient, remainder) = (numerator / divisor, numerator % divisor);
+        let round_up = match mode {
+            RoundingMode::Floor => false,
+            RoundingMode::Ceil => remainder != 0,
+            RoundingMode::HalfEven => {
+                2 * remainder > divisor || (2 * remainder == divisor && quotient % 2 == 1)
+            }
+        };
+        quotient + u64::from(round_up)
+    };
+
+    for mode in [
+        RoundingMode::Floor,
+        RoundingMode::Ceil,
+        RoundingMode::HalfEven,
+    ] {
+        for _ in 0..NB_TEST_SMALLER {
+            let clear = rng.gen::<u64>() % modulus;
+            // Small divisors and shifts, so that ties are frequent
+            let divisor = rng.gen_range(1..=8u64);
+            let shift = rng.gen_range(0..num_bits);
+
+            // Dirty input, so that the functions have to propagate the carries
+            let ct = sks.unchecked_add(&cks.encrypt(clear), &cks.encrypt(0u64));
+
+            let ct_res = sks.scalar_div_rounded_parallelized(&ct, divisor, mode);
+            let dec: u64 = cks.decrypt(&ct_res);
+            assert_eq!(
+                dec,
+                rounded_div(clear, divisor, mode),
+                "Invalid result for {clear} / {divisor} with {mode:?}"
+            );
+
+            let ct_res = sks.scalar_right_shift_rounded_parallelized(&ct, shift, mode);
+            assert!(ct_res.block_carries_are_empty());
+            let dec: u64 = cks.decrypt(&ct_res);
+            assert_eq!(
+                dec,
+                rounded_div(clear, 1 << shift, mode),
+                "Invalid result for {clear} >> {shift} with {mode:?}"
+            );
+        }
+    }
+}
+
+fn integer_default_one_hot_encoding<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear = rng.gen::<u64>() % modulus;
+
+        // Dirty input, so that the conversion has to propagate the carries
+        let ct = sks.unchecked_add(&cks.encrypt(clear), &cks.encrypt(0u64));
+        let one_hot = sks.radix_to_one_hot_parallelized(&ct);
+        assert_eq!(one_hot.num_digits(), NB_CTXT);
+        let dec: u64 = cks.as_ref().decrypt_one_hot(&one_hot);
+        assert_eq!(dec, clear);
+
+        let ct_res = sks.one_hot_to_radix_parallelized(&one_hot);
+        assert!(ct_res.block_carries_are_empty());
+        let dec: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec, clear);
+
+        // Scalars equal to the value, close to it, and out of the range of the ciphertext
+        let random_scalar = rng.gen::<u64>() % modulus;
+        for scalar in [
+            clear,
+            clear.saturating_sub(1),
+            clear + 1,
+            random_scalar,
+            modulus,
+        ] {
+            let expected = [
+                clear == scalar,
+                clear != scalar,
+                clear > scalar,
+                clear >= scalar,
+                clear < scalar,
+                clear <= scalar,
+            ];
+            let results = [
+                sks.one_hot_scalar_eq_parallelized(&one_hot, scalar),
+                sks.one_hot_scalar_ne_parallelized(&one_hot, scalar),
+                sks.one_hot_scalar_gt_parallelized(&one_hot, scalar),
+                sks.one_hot_scalar_ge_parallelized(&one_hot, scalar),
+                sks.one_hot_scalar_lt_parallelized(&one_hot, scalar),
+                sks.one_hot_scalar_le_parallelized(&one_hot, scalar),
+            ];
+            for (ct_res, expected) in results.iter().zip(expected) {
+                assert_eq!(ct_res.blocks.len(), NB_CTXT);
+                let dec: u64 = cks.decrypt(ct_res);
+                assert_eq!(
+                    dec,
+                    u64::from(expected),
+                    "Invalid comparison of {clear} with {scalar}"
+                );
+            }
+        }
+    }
+}
+
+fn integer_default_scalar_batch<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+
+    let batch_size = 8;
+    let clears = (0..batch_size)
+        .map(|_| rng.gen::<u64>() % modulus)
+        .collect::<Vec<_>>();
+    let scalars = (0..batch_size)
+        .map(|_| rng.gen::<u64>() % modulus)
+        .collect::<Vec<_>>();
+
+    let mut cts = clears
+        .iter()
+        .map(|&clear| cks.encrypt(clear))
+        .collect::<Vec<_>>();
+
+    sks.scalar_add_batch_parallelized(&mut cts, &scalars);
+    let mut expected = clears
+        .iter()
+        .zip(&scalars)
+        .map(|(clear, scalar)| (clear + scalar) % modulus)
+        .collect::<Vec<_>>();
+    let decrypted = cts.iter().map(|ct| cks.decrypt(ct)).collect::<Vec<u64>>();
+    assert_eq!(decrypted, expected);
+
+    sks.scalar_mul_batch_parallelized(&mut cts, &scalars);
+    for (expected, scalar) in expected.iter_mut().zip(&scalars) {
+        *expected = expected.wrapping_mul(*scalar) % modulus;
+    }
+    let decrypted = cts.iter().map(|ct| cks.decrypt(ct)).collect::<Vec<u64>>();
+    assert_eq!(decrypted, expected);
+
+    sks.scalar_sub_batch_parallelized(&mut cts, &scalars);
+    for (expected, scalar) in expected.iter_mut().zip(&scalars) {
+        *expected = expected.wrapping_sub(*scalar) % modulus;
+    }
+    let decrypted = cts.iter().map(|ct| cks.decrypt(ct)).collect::<Vec<u64>>();
+    assert_eq!(decrypted, expected);
+    assert!(cts.iter().all(|ct| ct.block_carries_are_empty()));
+}
+
+fn integer_default_sum_ciphertexts_with_deadline<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+
+    // Enough ciphertexts for several rounds of the tree reduction
+    let batch_size = 30;
+    let clears = (0..batch_size)
+        .map(|_| rng.gen::<u64>() % modulus)
+        .collect::<Vec<_>>();
+    let cts = clears
+        .iter()
+        .map(|&clear| {
+            // Some inputs have non empty carries
+            let clear_to_add = rng.gen::<u64>() % modulus;
+            let ct = cks.encrypt(clear.wrapping_sub(clear_to_add) % modulus);
+            sks.unchecked_scalar_add(&ct, clear_to_add)
+        })
+        .collect::<Vec<_>>();
+
+    let deadline = Instant::now() + Duration::from_secs(3600);
+    let partial_sum = sks.sum_ciphertexts_with_deadline(&cts, deadline).unwrap();
+    assert!(partial_sum.is_complete());
+    assert_eq!(partial_sum.num_summed, batch_size);
+    assert!(partial_sum.sum.block_carries_are_empty());
+    let decrypted: u64 = cks.decrypt(&partial_sum.sum);
+    assert_eq!(decrypted, clears.iter().sum::<u64>() % modulus);
+
+    // The deadline is already reached, nothing is summed
+    let partial_sum = sks
+        .sum_ciphertexts_with_deadline(&cts, Instant::now())
+        .unwrap();
+    assert!(!partial_sum.is_complete());
+    assert_eq!(partial_sum.num_summed, 0);
+    let decrypted: u64 = cks.decrypt(&partial_sum.sum);
+    assert_eq!(decrypted, 0);
+
+    assert!(sks
+        .sum_ciphertexts_with_deadline::<RadixCiphertext>(&[], deadline)
+        .is_none());
+}
+
+fn integer_default_encrypted_heap<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+
+    let capacity = 6;
+    let mut heap = EncryptedHeap::<RadixCiphertext>::new(capacity);
+    let mut expected = BinaryHeap::new();
+    assert!(heap.pop_min(&sks).is_none());
+
+    let check_pop_min = |heap: &mut EncryptedHeap<RadixCiphertext>,
+                         expected: &mut BinaryHeap<Reverse<u64>>| {
+        let min = heap.pop_min(&sks).unwrap();
+        assert!(min.block_carries_are_empty());
+        let decrypted: u64 = cks.decrypt(&min);
+        assert_eq!(decrypted, expected.pop().unwrap().0);
+        assert_eq!(heap.len(), expected.len());
+    };
+
+    // Interleave pushes and pops, so that pops are done on heaps of several shapes
+    for _ in 0..2 {
+        while !heap.is_full() {
+            let clear = rng.gen::<u64>() % modulus;
+            // Some inputs have non empty carries
+            let clear_to_add = rng.gen::<u64>() % modulus;
+            let ct = cks.encrypt(clear.wrapping_sub(clear_to_add) % modulus);
+            heap.push(&sks, sks.unchecked_scalar_add(&ct, clear_to_add));
+            expected.push(Reverse(clear));
+        }
+
+        for _ in 0..capacity / 2 + 1 {
+            check_pop_min(&mut heap, &mut expected);
+        }
+    }
+
+    while !heap.is_empty() {
+        check_pop_min(&mut heap, &mut expected);
+    }
+    assert!(heap.pop_min(&sks).is_none());
+}
+
 fn integer_full_propagate<P>(param: P)
 where
     P: Into<PBSParameters>,
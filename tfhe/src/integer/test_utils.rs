@@ -0,0 +1,297 @@
+//! Utilities to compare ciphertexts in tests.
+//!
+//! - [semantic_eq] decrypts two ciphertexts and compares the values they encrypt, whatever the
+//!   state of their carries.
+//! - [structural_diff] compares two ciphertexts without decrypting them, and reports which block
+//!   and which field of the block differ.
+//! - [assert_decrypts_eq](crate::assert_decrypts_eq) checks that a ciphertext decrypts to a clear
+//!   value.
+//!
+//! This module is only available in the tests of this crate, and with the `test-utils` feature.
+//!
+//! # Warning
+//!
+//! These utilities decrypt with the client key, they must not be used outside of tests.
+
+use crate::core_crypto::prelude::UnsignedNumeric;
+use crate::integer::block_decomposition::{RecomposableFrom, RecomposableSignedInteger};
+use crate::integer::ciphertext::{IntegerCiphertext, IntegerRadixCiphertext};
+use crate::integer::{ClientKey, RadixCiphertext, SignedRadixCiphertext};
+use std::fmt::Debug;
+
+/// Radix ciphertexts that can be decrypted to a clear value of type `Clear` in tests
+pub trait DecryptForTest<Clear> {
+    fn decrypt_for_test(&self, key: &ClientKey) -> Clear;
+}
+
+impl<Clear> DecryptForTest<Clear> for RadixCiphertext
+where
+    Clear: RecomposableFrom<u64> + UnsignedNumeric,
+{
+    fn decrypt_for_test(&self, key: &ClientKey) -> Clear {
+        key.decrypt_radix(self)
+    }
+}
+
+impl<Clear> DecryptForTest<Clear> for SignedRadixCiphertext
+where
+    Clear: RecomposableSignedInteger,
+{
+    fn decrypt_for_test(&self, key: &ClientKey) -> Clear {
+        key.decrypt_signed_radix(self)
+    }
+}
+
+/// Returns the digits of the value encrypted by the blocks, least significant first
+///
+/// The carries of each block are propagated to the next ones, the carry out of the last block is
+/// dropped as the arithmetic wraps around.
+fn decrypt_digits(key: &ClientKey, blocks: &[crate::shortint::Ciphertext]) -> Vec<u64> {
+    let message_modulus = key.parameters().message_modulus().0 as u64;
+
+    let mut carry = 0u64;
+    blocks
+        .iter()
+        .map(|block| {
+            let value = key.key.decrypt_message_and_carry(block) + carry;
+            carry = value / message_modulus;
+            value % message_modulus
+        })
+        .collect()
+}
+
+/// Returns whether `lhs` and `rhs` encrypt the same value
+///
+/// The values are compared once their carries are propagated, so a ciphertext with non empty
+/// carries is equal to the same value with its carries propagated. Ciphertexts with different
+/// numbers of blocks are never equal.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::integer::gen_keys_radix;
+/// use tfhe::integer::test_utils::semantic_eq;
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+///
+/// let num_blocks = 4;
+/// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+///
+/// let a = cks.encrypt(200u64);
+/// let b = cks.encrypt(100u64);
+/// let c = cks.encrypt(44u64);
+///
+/// // The sum has non empty carries
+/// let sum = sks.unchecked_add(&a, &b);
+/// assert!(semantic_eq(&sum, &c, cks.as_ref()));
+/// assert!(!semantic_eq(&sum, &a, cks.as_ref()));
+/// ```
+pub fn semantic_eq<T>(lhs: &T, rhs: &T, key: &ClientKey) -> bool
+where
+    T: IntegerRadixCiphertext,
+{
+    lhs.blocks().len() == rhs.blocks().len()
+        && decrypt_digits(key, lhs.blocks()) == decrypt_digits(key, rhs.blocks())
+}
+
+/// A difference between two ciphertexts found by [structural_diff]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CiphertextDifference {
+    NumBlocks {
+        lhs: usize,
+        rhs: usize,
+    },
+    /// The blocks at `index` have a different `field`
+    Block {
+        index: usize,
+        field: &'static str,
+        lhs: String,
+        rhs: String,
+    },
+}
+
+impl std::fmt::Display for CiphertextDifference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NumBlocks { lhs, rhs } => {
+                write!(f, "number of blocks: {lhs} != {rhs}")
+            }
+            Self::Block {
+                index,
+                field,
+                lhs,
+                rhs,
+            } => {
+                write!(f, "block {index}, {field}: {lhs} != {rhs}")
+            }
+        }
+    }
+}
+
+/// Returns the differences between the blocks of `lhs` and `rhs`, without decrypting them
+///
+/// The metadata of the blocks (degree, noise level, moduli, PBS order) are reported with their
+/// values, the LWE ciphertexts only by the position of the first differing coefficient. Only the
+/// common blocks are compared when the numbers of blocks differ.
+///
+/// Two encryptions of the same value are different, use [semantic_eq] to compare the values.
+pub fn structural_diff<T>(lhs: &T, rhs: &T) -> Vec<CiphertextDifference>
+where
+    T: IntegerCiphertext,
+{
+    let mut differences = Vec::new();
+    if lhs.blocks().len() != rhs.blocks().len() {
+        differences.push(CiphertextDifference::NumBlocks {
+            lhs: lhs.blocks().len(),
+            rhs: rhs.blocks().len(),
+        });
+    }
+
+    for (index, (lhs, rhs)) in lhs.blocks().iter().zip(rhs.blocks()).enumerate() {
+        let mut push_if_different = |field, lhs_value: &dyn Debug, rhs_value: &dyn Debug| {
+            let (lhs, rhs) = (format!("{lhs_value:?}"), format!("{rhs_value:?}"));
+            if lhs != rhs {
+                differences.push(CiphertextDifference::Block {
+                    index,
+                    field,
+                    lhs,
+                    rhs,
+                });
+            }
+        };
+
+        push_if_different("degree", &lhs.degree.0, &rhs.degree.0);
+        push_if_different(
+            "noise_level",
+            &lhs.noise_level.get(),
+            &rhs.noise_level.get(),
+        );
+        push_if_different(
+            "message_modulus",
+            &lhs.message_modulus.0,
+            &rhs.message_modulus.0,
+        );
+        push_if_different("carry_modulus", &lhs.carry_modulus.0, &rhs.carry_modulus.0);
+        push_if_different("pbs_order", &lhs.pbs_order, &rhs.pbs_order);
+        push_if_different(
+            "ciphertext_modulus",
+            &lhs.ct.ciphertext_modulus(),
+            &rhs.ct.ciphertext_modulus(),
+        );
+
+        let (lhs_data, rhs_data) = (lhs.ct.as_ref(), rhs.ct.as_ref());
+        if lhs_data.len() != rhs_data.len() {
+            push_if_different("lwe_size", &lhs_data.len(), &rhs_data.len());
+        } else if let Some(position) = lhs_data.iter().zip(rhs_data).position(|(l, r)| l != r) {
+            push_if_different(
+                "lwe_ciphertext",
+                &format_args!("coefficient {position} is {}", lhs_data[position]),
+                &format_args!("{}", rhs_data[position]),
+            );
+        }
+    }
+
+    differences
+}
+
+/// Implementation of [assert_decrypts_eq](crate::assert_decrypts_eq)
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_decrypts_eq_impl<T, Clear>(key: &ClientKey, ct: &T, expected: Clear, expression: &str)
+where
+    T: IntegerRadixCiphertext + DecryptForTest<Clear>,
+    Clear: PartialEq + Debug,
+{
+    let decrypted = ct.decrypt_for_test(key);
+    assert!(
+        decrypted == expected,
+        "`{expression}` decrypts to {decrypted:?}, expected {expected:?} \
+        (carries empty: {})",
+        ct.block_carries_are_empty()
+    );
+}
+
+/// Checks that a radix ciphertext decrypts to the expected clear value
+///
+/// The key can be any key giving access to an integer [ClientKey], e.g. a
+/// [RadixClientKey](crate::integer::RadixClientKey). The type of the clear value is the one of
+/// the expected value.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::assert_decrypts_eq;
+/// use tfhe::integer::gen_keys_radix;
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+///
+/// let num_blocks = 4;
+/// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+///
+/// let a = cks.encrypt(27u64);
+/// let b = cks.encrypt_signed(-27i64);
+/// assert_decrypts_eq!(cks, sks.add_parallelized(&a, &a), 54u64);
+/// assert_decrypts_eq!(cks, b, -27i64);
+/// ```
+#[macro_export]
+macro_rules! assert_decrypts_eq {
+    ($key:expr, $ct:expr, $expected:expr $(,)?) => {
+        $crate::integer::test_utils::assert_decrypts_eq_impl(
+            ::core::convert::AsRef::<$crate::integer::ClientKey>::as_ref(&$key),
+            &$ct,
+            $expected,
+            stringify!($ct),
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{semantic_eq, structural_diff, CiphertextDifference};
+    use crate::integer::keycache::KEY_CACHE;
+    use crate::integer::{IntegerCiphertext, RadixCiphertext, RadixClientKey};
+    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+
+    #[test]
+    fn test_semantic_eq_and_structural_diff() {
+        let (cks, sks) = KEY_CACHE.get_from_params(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+        let cks = RadixClientKey::from((cks, 4));
+
+        let a = cks.encrypt(255u64);
+        let b = cks.encrypt(1u64);
+        // 255 + 1 wraps around to 0, the carries are not propagated
+        let sum = sks.unchecked_add(&a, &b);
+        assert!(!sum.block_carries_are_empty());
+        assert!(semantic_eq(&sum, &cks.encrypt(0u64), cks.as_ref()));
+        assert!(!semantic_eq(&sum, &b, cks.as_ref()));
+
+        assert!(structural_diff(&a, &a.clone()).is_empty());
+
+        let differences = structural_diff(&a, &sum);
+        assert!(differences.contains(&CiphertextDifference::Block {
+            index: 0,
+            field: "degree",
+            lhs: "3".to_owned(),
+            rhs: "4".to_owned(),
+        }));
+
+        let mut truncated = a.clone();
+        truncated.blocks_mut()[1] = b.blocks()[1].clone();
+        let truncated = RadixCiphertext::from(truncated.blocks()[..2].to_vec());
+        let differences = structural_diff(&a, &truncated);
+        assert_eq!(
+            differences[0],
+            CiphertextDifference::NumBlocks { lhs: 4, rhs: 2 }
+        );
+        assert_eq!(differences.len(), 2);
+        assert!(matches!(
+            &differences[1],
+            CiphertextDifference::Block {
+                index: 1,
+                field: "lwe_ciphertext",
+                ..
+            }
+        ));
+        assert!(!semantic_eq(&a, &truncated, cks.as_ref()));
+
+        assert_decrypts_eq!(cks, sum, 0u64);
+    }
+}
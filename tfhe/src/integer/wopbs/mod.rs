@@ -11,7 +11,7 @@ pub use crate::core_crypto::commons::parameters::{CiphertextCount, PlaintextCoun
 use crate::core_crypto::prelude::*;
 use crate::integer::client_key::utils::i_crt;
 use crate::integer::{ClientKey, CrtCiphertext, IntegerCiphertext, ServerKey};
-use crate::shortint::ciphertext::Degree;
+use crate::shortint::ciphertext::{Degree, NoiseLevel};
 use crate::shortint::wopbs::WopbsLUTBase;
 use crate::shortint::WopbsParameters;
 use rayon::prelude::*;
@@ -323,6 +323,7 @@ impl WopbsKey {
             ct_vec_out.push(crate::shortint::Ciphertext {
                 ct: block_out,
                 degree: Degree(block.message_modulus.0 - 1),
+                noise_level: NoiseLevel::WOPBS_OUTPUT,
                 message_modulus: block.message_modulus,
                 carry_modulus: block.carry_modulus,
                 pbs_order: block.pbs_order,
@@ -409,6 +410,7 @@ impl WopbsKey {
             ct_vec_out.push(crate::shortint::Ciphertext {
                 ct: block_out,
                 degree: Degree(block.message_modulus.0 - 1),
+                noise_level: NoiseLevel::WOPBS_OUTPUT,
                 message_modulus: block.message_modulus,
                 carry_modulus: block.carry_modulus,
                 pbs_order: block.pbs_order,
@@ -1114,6 +1116,7 @@ impl WopbsKey {
             ct_vec_out.push(crate::shortint::Ciphertext {
                 ct: block_out,
                 degree: Degree(block.message_modulus.0 - 1),
+                noise_level: NoiseLevel::WOPBS_OUTPUT,
                 message_modulus: block.message_modulus,
                 carry_modulus: block.carry_modulus,
                 pbs_order: block.pbs_order,
@@ -1,6 +1,7 @@
 pub use crate::core_crypto::commons::math::random::Seed;
 use wasm_bindgen::prelude::*;
 
+use crate::boolean::server_key::BinaryBooleanGates;
 use crate::core_crypto::commons::generators::DeterministicSeeder;
 use crate::core_crypto::prelude::ActivatedRandomGenerator;
 
@@ -9,6 +10,39 @@ use std::panic::set_hook;
 #[wasm_bindgen]
 pub struct BooleanCiphertext(pub(crate) crate::boolean::ciphertext::Ciphertext);
 
+/// A list of ciphertexts, used as input and output of batched gate evaluation
+#[wasm_bindgen]
+pub struct BooleanCiphertextBatch(pub(crate) Vec<crate::boolean::ciphertext::Ciphertext>);
+
+#[wasm_bindgen]
+impl BooleanCiphertextBatch {
+    #[wasm_bindgen(constructor)]
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    #[wasm_bindgen]
+    pub fn push(&mut self, ciphertext: &BooleanCiphertext) {
+        self.0.push(ciphertext.0.clone());
+    }
+
+    #[wasm_bindgen]
+    pub fn get(&self, index: usize) -> Option<BooleanCiphertext> {
+        self.0.get(index).cloned().map(BooleanCiphertext)
+    }
+
+    #[wasm_bindgen]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[wasm_bindgen]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
 #[wasm_bindgen]
 pub struct BooleanCompressedCiphertext(pub(crate) crate::boolean::ciphertext::CompressedCiphertext);
 
@@ -21,6 +55,9 @@ pub struct BooleanPublicKey(pub(crate) crate::boolean::public_key::PublicKey);
 #[wasm_bindgen]
 pub struct BooleanCompressedServerKey(pub(crate) crate::boolean::server_key::CompressedServerKey);
 
+#[wasm_bindgen]
+pub struct BooleanServerKey(pub(crate) crate::boolean::server_key::ServerKey);
+
 #[wasm_bindgen]
 pub struct Boolean {}
 
@@ -73,6 +110,29 @@ impl From<BooleanEncryptionKeyChoice>
     }
 }
 
+#[wasm_bindgen]
+pub enum BooleanGate {
+    And,
+    Nand,
+    Nor,
+    Or,
+    Xor,
+    Xnor,
+}
+
+impl From<BooleanGate> for crate::boolean::server_key::BooleanGate {
+    fn from(value: BooleanGate) -> Self {
+        match value {
+            BooleanGate::And => Self::And,
+            BooleanGate::Nand => Self::Nand,
+            BooleanGate::Nor => Self::Nor,
+            BooleanGate::Or => Self::Or,
+            BooleanGate::Xor => Self::Xor,
+            BooleanGate::Xnor => Self::Xnor,
+        }
+    }
+}
+
 #[wasm_bindgen]
 impl Boolean {
     #[wasm_bindgen]
@@ -165,6 +225,22 @@ impl Boolean {
         ))
     }
 
+    #[wasm_bindgen]
+    pub fn new_server_key(client_key: &BooleanClientKey) -> BooleanServerKey {
+        set_hook(Box::new(console_error_panic_hook::hook));
+
+        BooleanServerKey(crate::boolean::server_key::ServerKey::new(&client_key.0))
+    }
+
+    #[wasm_bindgen]
+    pub fn decompress_server_key(
+        compressed_server_key: &BooleanCompressedServerKey,
+    ) -> BooleanServerKey {
+        set_hook(Box::new(console_error_panic_hook::hook));
+
+        BooleanServerKey(compressed_server_key.0.clone().into())
+    }
+
     #[wasm_bindgen]
     pub fn encrypt(client_key: &BooleanClientKey, message: bool) -> BooleanCiphertext {
         set_hook(Box::new(console_error_panic_hook::hook));
@@ -210,6 +286,122 @@ impl Boolean {
         client_key.0.decrypt(&ct.0)
     }
 
+    #[wasm_bindgen]
+    pub fn and(
+        server_key: &BooleanServerKey,
+        ct_left: &BooleanCiphertext,
+        ct_right: &BooleanCiphertext,
+    ) -> BooleanCiphertext {
+        set_hook(Box::new(console_error_panic_hook::hook));
+        BooleanCiphertext(server_key.0.and(&ct_left.0, &ct_right.0))
+    }
+
+    #[wasm_bindgen]
+    pub fn nand(
+        server_key: &BooleanServerKey,
+        ct_left: &BooleanCiphertext,
+        ct_right: &BooleanCiphertext,
+    ) -> BooleanCiphertext {
+        set_hook(Box::new(console_error_panic_hook::hook));
+        BooleanCiphertext(server_key.0.nand(&ct_left.0, &ct_right.0))
+    }
+
+    #[wasm_bindgen]
+    pub fn nor(
+        server_key: &BooleanServerKey,
+        ct_left: &BooleanCiphertext,
+        ct_right: &BooleanCiphertext,
+    ) -> BooleanCiphertext {
+        set_hook(Box::new(console_error_panic_hook::hook));
+        BooleanCiphertext(server_key.0.nor(&ct_left.0, &ct_right.0))
+    }
+
+    #[wasm_bindgen]
+    pub fn or(
+        server_key: &BooleanServerKey,
+        ct_left: &BooleanCiphertext,
+        ct_right: &BooleanCiphertext,
+    ) -> BooleanCiphertext {
+        set_hook(Box::new(console_error_panic_hook::hook));
+        BooleanCiphertext(server_key.0.or(&ct_left.0, &ct_right.0))
+    }
+
+    #[wasm_bindgen]
+    pub fn xor(
+        server_key: &BooleanServerKey,
+        ct_left: &BooleanCiphertext,
+        ct_right: &BooleanCiphertext,
+    ) -> BooleanCiphertext {
+        set_hook(Box::new(console_error_panic_hook::hook));
+        BooleanCiphertext(server_key.0.xor(&ct_left.0, &ct_right.0))
+    }
+
+    #[wasm_bindgen]
+    pub fn xnor(
+        server_key: &BooleanServerKey,
+        ct_left: &BooleanCiphertext,
+        ct_right: &BooleanCiphertext,
+    ) -> BooleanCiphertext {
+        set_hook(Box::new(console_error_panic_hook::hook));
+        BooleanCiphertext(server_key.0.xnor(&ct_left.0, &ct_right.0))
+    }
+
+    #[wasm_bindgen]
+    pub fn not(server_key: &BooleanServerKey, ct: &BooleanCiphertext) -> BooleanCiphertext {
+        set_hook(Box::new(console_error_panic_hook::hook));
+        BooleanCiphertext(server_key.0.not(&ct.0))
+    }
+
+    #[wasm_bindgen]
+    pub fn mux(
+        server_key: &BooleanServerKey,
+        ct_condition: &BooleanCiphertext,
+        ct_then: &BooleanCiphertext,
+        ct_else: &BooleanCiphertext,
+    ) -> BooleanCiphertext {
+        set_hook(Box::new(console_error_panic_hook::hook));
+        BooleanCiphertext(server_key.0.mux(&ct_condition.0, &ct_then.0, &ct_else.0))
+    }
+
+    /// Evaluates `gate` on each pair of ciphertexts of `lhs` and `rhs`
+    ///
+    /// The gates are evaluated one after the other, unless the package is built with the
+    /// `parallel-wasm-api` feature, in which case they are evaluated in parallel on the thread
+    /// pool, which must have been initialized with `initThreadPool`.
+    #[wasm_bindgen]
+    pub fn batch_gates(
+        server_key: &BooleanServerKey,
+        gate: BooleanGate,
+        lhs: &BooleanCiphertextBatch,
+        rhs: &BooleanCiphertextBatch,
+    ) -> Result<BooleanCiphertextBatch, JsError> {
+        set_hook(Box::new(console_error_panic_hook::hook));
+        if lhs.0.len() != rhs.0.len() {
+            return Err(JsError::new(&format!(
+                "lhs and rhs must have the same number of ciphertexts, got {} and {}",
+                lhs.0.len(),
+                rhs.0.len()
+            )));
+        }
+
+        Ok(BooleanCiphertextBatch(server_key.0.batch_gates(
+            gate.into(),
+            &lhs.0,
+            &rhs.0,
+        )))
+    }
+
+    /// Evaluates the NOT gate on each of the ciphertexts, with the same parallelism as
+    /// [Self::batch_gates]
+    #[wasm_bindgen]
+    pub fn batch_not(
+        server_key: &BooleanServerKey,
+        cts: &BooleanCiphertextBatch,
+    ) -> BooleanCiphertextBatch {
+        set_hook(Box::new(console_error_panic_hook::hook));
+        BooleanCiphertextBatch(server_key.0.batch_not(&cts.0))
+    }
+
     #[wasm_bindgen]
     pub fn serialize_ciphertext(ciphertext: &BooleanCiphertext) -> Result<Vec<u8>, JsError> {
         set_hook(Box::new(console_error_panic_hook::hook));
@@ -292,4 +484,19 @@ impl Boolean {
             .map_err(|e| wasm_bindgen::JsError::new(format!("{e:?}").as_str()))
             .map(BooleanCompressedServerKey)
     }
+
+    #[wasm_bindgen]
+    pub fn serialize_server_key(server_key: &BooleanServerKey) -> Result<Vec<u8>, JsError> {
+        set_hook(Box::new(console_error_panic_hook::hook));
+        bincode::serialize(&server_key.0)
+            .map_err(|e| wasm_bindgen::JsError::new(format!("{e:?}").as_str()))
+    }
+
+    #[wasm_bindgen]
+    pub fn deserialize_server_key(buffer: &[u8]) -> Result<BooleanServerKey, JsError> {
+        set_hook(Box::new(console_error_panic_hook::hook));
+        bincode::deserialize(buffer)
+            .map_err(|e| wasm_bindgen::JsError::new(format!("{e:?}").as_str()))
+            .map(BooleanServerKey)
+    }
 }
@@ -1,6 +1,6 @@
 pub use utils::{
-    FileStorage, KeyCache as ImplKeyCache, NamedParam, PersistentStorage,
-    SharedKey as GenericSharedKey,
+    EvictionPolicy, FileStorage, KeyCache as ImplKeyCache, MemoryStorage, NamedParam, ObjectStore,
+    PersistentStorage, SharedKey as GenericSharedKey,
 };
 
 #[macro_use]
@@ -8,11 +8,12 @@ pub mod utils {
     use fs2::FileExt;
     use serde::de::DeserializeOwned;
     use serde::Serialize;
-    use std::fs::File;
-    use std::io::{BufReader, BufWriter};
+    use std::collections::HashMap;
+    use std::fs::{File, OpenOptions};
+    use std::io::{BufWriter, Read, Write};
     use std::ops::Deref;
     use std::path::PathBuf;
-    use std::sync::{Arc, OnceLock, RwLock};
+    use std::sync::{Arc, Mutex, OnceLock, RwLock};
 
     pub trait PersistentStorage<P, K> {
         fn load(&self, param: P) -> Option<K>;
@@ -55,6 +56,59 @@ pub mod utils {
         }
     );
 
+    /// A store of named binary objects
+    ///
+    /// Any object store implements [PersistentStorage]: the keys are serialized along with their
+    /// parameters and a checksum, and an object that is corrupted or that was generated for other
+    /// parameters is ignored, so the key is generated again and the object overwritten. This
+    /// allows to share the keys between machines, e.g. with a bucket of an object storage service.
+    pub trait ObjectStore {
+        fn get(&self, name: &str) -> Option<Vec<u8>>;
+        fn put(&self, name: &str, data: &[u8]);
+    }
+
+    /// FNV-1a, used to detect corrupted or truncated objects
+    fn checksum(data: &[u8]) -> u64 {
+        data.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        })
+    }
+
+    fn object_name<P: NamedParam>(param: &P) -> String {
+        format!("{}.bin", param.name())
+    }
+
+    impl<P, K, O> PersistentStorage<P, K> for O
+    where
+        O: ObjectStore,
+        P: NamedParam + DeserializeOwned + Serialize + PartialEq,
+        K: DeserializeOwned + Serialize,
+    {
+        fn load(&self, param: P) -> Option<K> {
+            let data = self.get(&object_name(&param))?;
+            let payload_len = data.len().checked_sub(std::mem::size_of::<u64>())?;
+            let (payload, stored_checksum) = data.split_at(payload_len);
+            if u64::from_le_bytes(stored_checksum.try_into().unwrap()) != checksum(payload) {
+                return None;
+            }
+
+            bincode::deserialize::<(P, K)>(payload)
+                .ok()
+                .and_then(|(p, k)| if p == param { Some(k) } else { None })
+        }
+
+        fn store(&self, param: P, key: &K) {
+            let mut data = bincode::serialize(&(&param, key)).unwrap();
+            let payload_checksum = checksum(&data);
+            data.extend_from_slice(&payload_checksum.to_le_bytes());
+            self.put(&object_name(&param), &data);
+        }
+    }
+
+    /// Stores the keys in files of a directory
+    ///
+    /// The files are locked while they are read or written, so several processes can share the
+    /// same directory.
     pub struct FileStorage {
         prefix: String,
     }
@@ -65,46 +119,81 @@ pub mod utils {
         }
     }
 
-    impl<P, K> PersistentStorage<P, K> for FileStorage
-    where
-        P: NamedParam + DeserializeOwned + Serialize + PartialEq,
-        K: DeserializeOwned + Serialize,
-    {
-        fn load(&self, param: P) -> Option<K> {
+    impl ObjectStore for FileStorage {
+        fn get(&self, name: &str) -> Option<Vec<u8>> {
             let mut path_buf = PathBuf::with_capacity(256);
             path_buf.push(&self.prefix);
-            path_buf.push(param.name());
-            path_buf.set_extension("bin");
-
-            if path_buf.exists() {
-                let file = File::open(&path_buf).unwrap();
-                // Lock for reading
-                file.lock_shared().unwrap();
-                let file_reader = BufReader::new(file);
-                bincode::deserialize_from::<_, (P, K)>(file_reader)
-                    .ok()
-                    .and_then(|(p, k)| if p == param { Some(k) } else { None })
-            } else {
-                None
-            }
+            path_buf.push(name);
+
+            let mut file = File::open(&path_buf).ok()?;
+            // Lock for reading
+            file.lock_shared().unwrap();
+            let mut data = Vec::new();
+            file.read_to_end(&mut data).ok()?;
+            Some(data)
         }
 
-        fn store(&self, param: P, key: &K) {
+        fn put(&self, name: &str, data: &[u8]) {
             let mut path_buf = PathBuf::with_capacity(256);
             path_buf.push(&self.prefix);
             std::fs::create_dir_all(&path_buf).unwrap();
-            path_buf.push(param.name());
-            path_buf.set_extension("bin");
+            path_buf.push(name);
 
-            let file = File::create(&path_buf).unwrap();
-            // Lock for writing
+            let mut file = OpenOptions::new()
+                .write(true)
+                .create(true)
+                .truncate(false)
+                .open(&path_buf)
+                .unwrap();
+            // Lock for writing, the file is only truncated once the lock is acquired so readers
+            // never see a partially written key
             file.lock_exclusive().unwrap();
+            file.set_len(0).unwrap();
+
+            let mut file_writer = BufWriter::new(file);
+            file_writer.write_all(data).unwrap();
+            file_writer.flush().unwrap();
+        }
+    }
+
+    /// Stores the keys in memory, it is mostly useful for tests or as a stand-in for a remote
+    /// [ObjectStore]
+    #[derive(Default)]
+    pub struct MemoryStorage {
+        objects: Mutex<HashMap<String, Vec<u8>>>,
+    }
+
+    impl MemoryStorage {
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
 
-            let file_writer = BufWriter::new(file);
-            bincode::serialize_into(file_writer, &(param, key)).unwrap();
+    impl ObjectStore for MemoryStorage {
+        fn get(&self, name: &str) -> Option<Vec<u8>> {
+            self.objects.lock().unwrap().get(name).cloned()
+        }
+
+        fn put(&self, name: &str, data: &[u8]) {
+            self.objects
+                .lock()
+                .unwrap()
+                .insert(name.to_owned(), data.to_vec());
         }
     }
 
+    /// How the keys are released from the memory of a [KeyCache]
+    ///
+    /// A released key stays valid for the [SharedKey] that are still alive, and it is loaded
+    /// again from the persistent storage the next time it is requested.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum EvictionPolicy {
+        /// The keys are kept until [KeyCache::clear_in_memory_cache] is called
+        Never,
+        /// At most `max_entries` keys are kept, the least recently used ones are released first
+        LeastRecentlyUsed { max_entries: usize },
+    }
+
     pub struct SharedKey<K> {
         inner: Arc<OnceLock<K>>,
     }
@@ -131,14 +220,21 @@ pub mod utils {
         persistent_storage: S,
         // Temporary memory storage to avoid querying the persistent storage each time
         // the outer Arc makes it so that we don't clone the OnceLock contents when initializing it
+        // The entries are ordered from the least to the most recently used
         memory_storage: RwLock<Vec<(P, SharedKey<K>)>>,
+        eviction_policy: EvictionPolicy,
     }
 
     impl<P, K, S> KeyCache<P, K, S> {
         pub fn new(storage: S) -> Self {
+            Self::with_eviction_policy(storage, EvictionPolicy::Never)
+        }
+
+        pub fn with_eviction_policy(storage: S, eviction_policy: EvictionPolicy) -> Self {
             Self {
                 persistent_storage: storage,
                 memory_storage: RwLock::new(vec![]),
+                eviction_policy,
             }
         }
 
@@ -146,6 +242,11 @@ pub mod utils {
             let mut memory_storage = self.memory_storage.write().unwrap();
             memory_storage.clear();
         }
+
+        /// Returns the number of keys held in memory
+        pub fn in_memory_len(&self) -> usize {
+            self.memory_storage.read().unwrap().len()
+        }
     }
 
     impl<P, K, S> KeyCache<P, K, S>
@@ -176,43 +277,95 @@ pub mod utils {
                 }
             };
 
-            let try_load_from_memory_and_init = || {
-                // we only hold a read lock for a short duration to find the key
-                let maybe_shared_cell = {
-                    let memory_storage = self.memory_storage.read().unwrap();
-                    memory_storage
-                        .iter()
-                        .find(|(p, _)| *p == param)
-                        .map(|param_key| param_key.1.clone())
-                };
-
-                if let Some(shared_cell) = maybe_shared_cell {
-                    shared_cell.inner.get_or_init(load_from_persistent_storage);
-                    Ok(shared_cell)
-                } else {
-                    Err(())
-                }
+            let find_in_memory = |memory_storage: &[(P, SharedKey<K>)]| {
+                memory_storage
+                    .iter()
+                    .find(|(p, _)| *p == param)
+                    .map(|param_key| param_key.1.clone())
             };
 
-            match try_load_from_memory_and_init() {
-                Ok(result) => f(&result),
-                Err(()) => {
-                    {
-                        // we only hold a write lock for a short duration to push the lazily
-                        // evaluated key without actually evaluating the key
-                        let mut memory_storage = self.memory_storage.write().unwrap();
-                        if !memory_storage.iter().any(|(p, _)| *p == param) {
-                            memory_storage.push((
-                                param,
-                                SharedKey {
-                                    inner: Arc::new(OnceLock::new()),
-                                },
-                            ));
-                        }
-                    }
-                    f(&try_load_from_memory_and_init().ok().unwrap())
-                }
+            // we only hold a read lock for a short duration to find the key
+            let maybe_shared_key = find_in_memory(&self.memory_storage.read().unwrap());
+            let shared_key = maybe_shared_key.unwrap_or_else(|| {
+                // we only hold a write lock for a short duration to push the lazily
+                // evaluated key without actually evaluating the key
+                let mut memory_storage = self.memory_storage.write().unwrap();
+                // another thread may have pushed the key in the meantime
+                find_in_memory(&memory_storage).unwrap_or_else(|| {
+                    let shared_key = SharedKey {
+                        inner: Arc::new(OnceLock::new()),
+                    };
+                    memory_storage.push((param, shared_key.clone()));
+                    shared_key
+                })
+            });
+
+            shared_key.inner.get_or_init(load_from_persistent_storage);
+            self.apply_eviction_policy(param);
+            f(&shared_key)
+        }
+
+        fn apply_eviction_policy(&self, used_param: P) {
+            let EvictionPolicy::LeastRecentlyUsed { max_entries } = self.eviction_policy else {
+                return;
+            };
+
+            let mut memory_storage = self.memory_storage.write().unwrap();
+            if let Some(index) = memory_storage.iter().position(|(p, _)| *p == used_param) {
+                let entry = memory_storage.remove(index);
+                memory_storage.push(entry);
+            }
+            let num_evicted = memory_storage.len().saturating_sub(max_entries);
+            memory_storage.drain(..num_evicted);
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{EvictionPolicy, KeyCache, MemoryStorage, NamedParam, ObjectStore};
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+        struct Param(u64);
+
+        impl NamedParam for Param {
+            fn name(&self) -> String {
+                format!("PARAM_{}", self.0)
             }
         }
+
+        #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+        struct Key(Vec<u64>);
+
+        impl From<Param> for Key {
+            fn from(param: Param) -> Self {
+                Self(vec![param.0; 4])
+            }
+        }
+
+        #[test]
+        fn test_key_cache_eviction_and_integrity() {
+            let cache = KeyCache::<Param, Key, _>::with_eviction_policy(
+                MemoryStorage::new(),
+                EvictionPolicy::LeastRecentlyUsed { max_entries: 2 },
+            );
+
+            let first = cache.get(Param(1));
+            assert_eq!(*first, Key(vec![1; 4]));
+            let _ = cache.get(Param(2));
+            let _ = cache.get(Param(1));
+            let _ = cache.get(Param(3));
+            // Param(2) was the least recently used
+            assert_eq!(cache.in_memory_len(), 2);
+            // Evicted keys stay valid for their holders
+            assert_eq!(*first, Key(vec![1; 4]));
+
+            // A corrupted object is ignored and overwritten
+            let mut data = cache.persistent_storage.get("PARAM_2.bin").unwrap();
+            data[0] ^= 1;
+            cache.persistent_storage.put("PARAM_2.bin", &data);
+            assert_eq!(*cache.get(Param(2)), Key(vec![2; 4]));
+            assert_ne!(cache.persistent_storage.get("PARAM_2.bin").unwrap(), data);
+        }
     }
 }
@@ -66,6 +66,17 @@ pub mod keycache;
 #[cfg(feature = "safe-deserialization")]
 pub mod safe_deserialization;
 
+#[cfg(feature = "ciphertext-store")]
+pub mod ciphertext_store;
+
+#[cfg(feature = "serialized-size")]
+pub mod serialized_size;
+
+#[cfg(feature = "block-serialization")]
+pub mod block_serialization;
+
 pub mod conformance;
 
+pub mod content_hash;
+
 pub mod named;
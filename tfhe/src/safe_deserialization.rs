@@ -153,6 +153,64 @@ mod test_shortint {
     }
 }
 
+#[cfg(all(test, feature = "boolean"))]
+mod test_boolean {
+    use crate::boolean::gen_keys;
+    use crate::boolean::parameters::{DEFAULT_PARAMETERS, TFHE_LIB_PARAMETERS};
+    use crate::boolean::prelude::{Ciphertext, ServerKey};
+    use crate::safe_deserialization::{safe_deserialize_conformant, safe_serialize};
+
+    #[test]
+    fn safe_desererialization_ct() {
+        let (ck, _sk) = gen_keys();
+
+        let ct = ck.encrypt(true);
+
+        let mut buffer = vec![];
+
+        safe_serialize(&ct, &mut buffer, 1 << 40).unwrap();
+
+        assert!(safe_deserialize_conformant::<Ciphertext>(
+            buffer.as_slice(),
+            1 << 20,
+            &TFHE_LIB_PARAMETERS.to_ciphertext_conformance_params(),
+        )
+        .is_err());
+
+        let ct2 = safe_deserialize_conformant(
+            buffer.as_slice(),
+            1 << 20,
+            &DEFAULT_PARAMETERS.to_ciphertext_conformance_params(),
+        )
+        .unwrap();
+
+        assert!(ck.decrypt(&ct2));
+    }
+
+    #[test]
+    fn safe_desererialization_server_key() {
+        let (_ck, sk) = gen_keys();
+
+        let mut buffer = vec![];
+
+        safe_serialize(&sk, &mut buffer, 1 << 40).unwrap();
+
+        assert!(safe_deserialize_conformant::<ServerKey>(
+            buffer.as_slice(),
+            1 << 30,
+            &TFHE_LIB_PARAMETERS.to_server_key_conformance_params(),
+        )
+        .is_err());
+
+        safe_deserialize_conformant::<ServerKey>(
+            buffer.as_slice(),
+            1 << 30,
+            &DEFAULT_PARAMETERS.to_server_key_conformance_params(),
+        )
+        .unwrap();
+    }
+}
+
 #[cfg(all(test, feature = "integer"))]
 mod test_integer {
     use crate::conformance::{ListSizeConstraint, ParameterSetConformant};
@@ -0,0 +1,99 @@
+//! Serialized size estimation.
+//!
+//! The [SerializedSizeHint] trait gives the number of bytes an object takes once serialized with
+//! bincode, without serializing it. This allows to budget storage and bandwidth for keys and
+//! ciphertexts before sending or storing them.
+
+use bincode::Options;
+use serde::Serialize;
+
+/// Gives the size of an object once serialized.
+///
+/// This trait is implemented for all serializable types of this crate (keys, ciphertexts,
+/// compressed and compact variants).
+///
+/// The size is the one of the default bincode serialization (`bincode::serialize`), it does not
+/// include the header written by
+/// [safe_serialize](crate::safe_deserialization::safe_serialize).
+///
+/// It is implemented for all serializable types, so it is not part of the prelude: it must be
+/// imported explicitly from this module.
+pub trait SerializedSizeHint {
+    /// Returns the number of bytes of the serialized object
+    ///
+    /// The object is not serialized, its size is computed by walking through it, so this is
+    /// cheap even for big keys.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::serialized_size::SerializedSizeHint;
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let (cks, _sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let ct = cks.encrypt(3);
+    /// let compressed_ct = cks.encrypt_compressed(3);
+    ///
+    /// let size = ct.serialized_size_hint();
+    /// assert_eq!(size, bincode::serialize(&ct).unwrap().len() as u64);
+    /// assert!(compressed_ct.serialized_size_hint() < size);
+    /// ```
+    fn serialized_size_hint(&self) -> u64;
+}
+
+impl<T> SerializedSizeHint for T
+where
+    T: Serialize + ?Sized,
+{
+    fn serialized_size_hint(&self) -> u64 {
+        bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .serialized_size(self)
+            .expect("Failed to compute the serialized size of the object")
+    }
+}
+
+#[cfg(all(test, feature = "shortint"))]
+mod test_shortint {
+    use super::SerializedSizeHint;
+    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    use crate::shortint::{gen_keys, CompressedServerKey};
+
+    #[test]
+    fn serialized_size_hint_matches_serialization() {
+        let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+        let compressed_sks = CompressedServerKey::new(&cks);
+
+        let ct = cks.encrypt(1);
+        let compressed_ct = cks.encrypt_compressed(1);
+
+        assert_eq!(
+            ct.serialized_size_hint(),
+            bincode::serialize(&ct).unwrap().len() as u64
+        );
+        assert_eq!(
+            compressed_ct.serialized_size_hint(),
+            bincode::serialize(&compressed_ct).unwrap().len() as u64
+        );
+        assert_eq!(
+            sks.serialized_size_hint(),
+            bincode::serialize(&sks).unwrap().len() as u64
+        );
+        assert_eq!(
+            compressed_sks.serialized_size_hint(),
+            bincode::serialize(&compressed_sks).unwrap().len() as u64
+        );
+
+        let cts = vec![ct; 4];
+        assert_eq!(
+            cts.serialized_size_hint(),
+            bincode::serialize(&cts).unwrap().len() as u64
+        );
+        assert_eq!(
+            cts.as_slice().serialized_size_hint(),
+            cts.serialized_size_hint()
+        );
+    }
+}
@@ -1,11 +1,17 @@
 //! Module with the definition of the Ciphertext.
 use crate::conformance::ParameterSetConformant;
+use crate::content_hash::{ContentHash, ContentHasher};
+use crate::core_crypto::algorithms::expand_lwe_compact_ciphertext_list_element;
 pub use crate::core_crypto::commons::parameters::PBSOrder;
+use crate::core_crypto::commons::parameters::{CiphertextModulus, LweDimension, LweSize};
 use crate::core_crypto::entities::*;
-use crate::shortint::parameters::{CarryModulus, MessageModulus};
+use crate::shortint::parameters::{CarryModulus, MessageModulus, PBSParameters};
+use crate::shortint::server_key::MaxNoiseLevel;
+use crate::shortint::{KeySwitchingKey, ServerKey};
 use serde::{Deserialize, Serialize};
 use std::cmp;
-use std::fmt::Debug;
+use std::fmt::{Debug, Display, Formatter};
+use std::ops::Range;
 
 use super::parameters::{CiphertextConformanceParams, CiphertextListConformanceParams};
 
@@ -78,11 +84,91 @@ impl Degree {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+/// This tracks the noise of a ciphertext, in multiples of the noise of a fresh encryption.
+///
+/// Linear operations add or multiply the noise levels of their inputs, a PBS resets the noise
+/// level of its output to [NoiseLevel::NOMINAL].
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Serialize, Deserialize)]
+pub struct NoiseLevel(usize);
+
+impl NoiseLevel {
+    /// The noise level of a trivial encryption
+    pub const ZERO: Self = Self(0);
+    /// The noise level of a fresh encryption, or of the output of a PBS
+    pub const NOMINAL: Self = Self(1);
+    // The noise of an encryption with a public key, or of a re-randomized one, is bigger than the
+    // nominal one: it must never be taken for the output of a PBS
+    pub(crate) const PUBLIC_KEY_ENCRYPTION: Self = Self(2);
+    // Same for the outputs of a circuit bootstrapping and of the keyswitches around a wopbs
+    pub(crate) const WOPBS_OUTPUT: Self = Self(2);
+
+    pub fn get(&self) -> usize {
+        self.0
+    }
+}
+
+impl std::ops::Add for NoiseLevel {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0.saturating_add(rhs.0))
+    }
+}
+
+impl std::ops::AddAssign for NoiseLevel {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl std::ops::Mul<usize> for NoiseLevel {
+    type Output = Self;
+
+    fn mul(self, rhs: usize) -> Self::Output {
+        Self(self.0.saturating_mul(rhs))
+    }
+}
+
+/// The capacity left in a ciphertext for leveled operations, before its noise level exceeds the
+/// maximum and a PBS is required, see [Ciphertext::remaining_noise_budget].
+///
+/// Only the noise is accounted for: the result of an operation must also fit in the message and
+/// carry space, which is checked with the degree of the ciphertexts.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct NoiseBudget {
+    noise_level: NoiseLevel,
+    max_noise_level: MaxNoiseLevel,
+}
+
+impl NoiseBudget {
+    /// Returns the number of ciphertexts with the [nominal](NoiseLevel::NOMINAL) noise level,
+    /// e.g. fresh encryptions or outputs of a PBS, that can still be added
+    pub fn remaining_additions(&self) -> usize {
+        self.max_noise_level
+            .get()
+            .saturating_sub(self.noise_level.get())
+    }
+
+    /// Returns the largest scalar the ciphertext can be multiplied by, without a PBS
+    pub fn max_scalar_multiplier(&self) -> usize {
+        match self.noise_level.get() {
+            0 => usize::MAX,
+            noise_level => self.max_noise_level.get() / noise_level,
+        }
+    }
+
+    /// Returns whether a PBS is required before adding a ciphertext with the nominal noise level
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining_additions() == 0
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
 #[must_use]
 pub struct Ciphertext {
     pub ct: LweCiphertextOwned<u64>,
     pub degree: Degree,
+    pub noise_level: NoiseLevel,
     pub message_modulus: MessageModulus,
     pub carry_modulus: CarryModulus,
     pub pbs_order: PBSOrder,
@@ -92,6 +178,115 @@ impl crate::named::Named for Ciphertext {
     const NAME: &'static str = "shortint::Ciphertext";
 }
 
+// The ciphertext is serialized through a versioned enum so that the format can evolve while old
+// ciphertexts stay readable, the borrowed variant avoids cloning the ciphertext to serialize it.
+#[derive(Serialize)]
+struct CiphertextV0Ref<'a> {
+    ct: &'a LweCiphertextOwned<u64>,
+    degree: Degree,
+    noise_level: NoiseLevel,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+    pbs_order: PBSOrder,
+}
+
+#[derive(Serialize)]
+enum SerializableCiphertext<'a> {
+    V0(CiphertextV0Ref<'a>),
+}
+
+#[derive(Deserialize)]
+struct CiphertextV0 {
+    ct: LweCiphertextOwned<u64>,
+    degree: Degree,
+    noise_level: NoiseLevel,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+    pbs_order: PBSOrder,
+}
+
+#[derive(Deserialize)]
+enum DeserializableCiphertext {
+    V0(CiphertextV0),
+}
+
+impl Serialize for Ciphertext {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        SerializableCiphertext::V0(CiphertextV0Ref {
+            ct: &self.ct,
+            degree: self.degree,
+            noise_level: self.noise_level,
+            message_modulus: self.message_modulus,
+            carry_modulus: self.carry_modulus,
+            pbs_order: self.pbs_order,
+        })
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Ciphertext {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        DeserializableCiphertext::deserialize(deserializer).map(|versioned| match versioned {
+            DeserializableCiphertext::V0(ct) => Self {
+                ct: ct.ct,
+                degree: ct.degree,
+                noise_level: ct.noise_level,
+                message_modulus: ct.message_modulus,
+                carry_modulus: ct.carry_modulus,
+                pbs_order: ct.pbs_order,
+            },
+        })
+    }
+}
+
+/// A ciphertext serialized before the ciphertexts were versioned, without a noise level.
+///
+/// These ciphertexts cannot be deserialized as a [Ciphertext], deserialize them as a
+/// `LegacyCiphertext` and convert them: their noise is unknown, so they get the maximum noise
+/// level of their moduli and are bootstrapped before being used in a leveled operation.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::shortint::ciphertext::LegacyCiphertext;
+/// use tfhe::shortint::Ciphertext;
+///
+/// # fn load(legacy_bytes: &[u8]) -> bincode::Result<Ciphertext> {
+/// let ct: Ciphertext = bincode::deserialize::<LegacyCiphertext>(legacy_bytes)?.into();
+/// # Ok(ct)
+/// # }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct LegacyCiphertext {
+    ct: LweCiphertextOwned<u64>,
+    degree: Degree,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+    pbs_order: PBSOrder,
+}
+
+impl From<LegacyCiphertext> for Ciphertext {
+    fn from(legacy: LegacyCiphertext) -> Self {
+        let max_noise_level =
+            MaxNoiseLevel::from_msg_carry_modulus(legacy.message_modulus, legacy.carry_modulus);
+
+        Self {
+            ct: legacy.ct,
+            degree: legacy.degree,
+            noise_level: NoiseLevel(max_noise_level.get()),
+            message_modulus: legacy.message_modulus,
+            carry_modulus: legacy.carry_modulus,
+            pbs_order: legacy.pbs_order,
+        }
+    }
+}
+
 impl ParameterSetConformant for Ciphertext {
     type ParameterSet = CiphertextConformanceParams;
 
@@ -104,6 +299,28 @@ impl ParameterSetConformant for Ciphertext {
     }
 }
 
+impl ContentHash for Ciphertext {
+    fn hash_content(&self, hasher: &mut ContentHasher) {
+        // Destructure so that new members cannot be forgotten
+        let Self {
+            ct,
+            degree,
+            noise_level,
+            message_modulus,
+            carry_modulus,
+            pbs_order,
+        } = self;
+
+        hasher.write_str(<Self as crate::named::Named>::NAME);
+        ct.hash_content(hasher);
+        hasher.write_usize(degree.0);
+        hasher.write_usize(noise_level.get());
+        hasher.write_usize(message_modulus.0);
+        hasher.write_usize(carry_modulus.0);
+        hasher.write_u64(*pbs_order as u64);
+    }
+}
+
 // Use destructuring to also have a compile error
 // if ever a new member is added to Ciphertext
 // and is not handled here.
@@ -114,6 +331,7 @@ impl Clone for Ciphertext {
         let Ciphertext {
             ct: src_ct,
             degree: src_degree,
+            noise_level: src_noise_level,
             message_modulus: src_message_modulus,
             carry_modulus: src_carry_modulus,
             pbs_order: src_pbs_order,
@@ -122,6 +340,7 @@ impl Clone for Ciphertext {
         Self {
             ct: src_ct.clone(),
             degree: *src_degree,
+            noise_level: *src_noise_level,
             message_modulus: *src_message_modulus,
             carry_modulus: *src_carry_modulus,
             pbs_order: *src_pbs_order,
@@ -132,6 +351,7 @@ impl Clone for Ciphertext {
         let Ciphertext {
             ct: dst_ct,
             degree: dst_degree,
+            noise_level: dst_noise_level,
             message_modulus: dst_message_modulus,
             carry_modulus: dst_carry_modulus,
             pbs_order: dst_pbs_order,
@@ -140,6 +360,7 @@ impl Clone for Ciphertext {
         let Ciphertext {
             ct: src_ct,
             degree: src_degree,
+            noise_level: src_noise_level,
             message_modulus: src_message_modulus,
             carry_modulus: src_carry_modulus,
             pbs_order: src_pbs_order,
@@ -153,6 +374,7 @@ impl Clone for Ciphertext {
             dst_ct.as_mut().copy_from_slice(src_ct.as_ref());
         }
         *dst_degree = *src_degree;
+        *dst_noise_level = *src_noise_level;
         *dst_message_modulus = *src_message_modulus;
         *dst_carry_modulus = *src_carry_modulus;
         *dst_pbs_order = *src_pbs_order;
@@ -160,11 +382,272 @@ impl Clone for Ciphertext {
 }
 
 impl Ciphertext {
+    pub fn noise_level(&self) -> NoiseLevel {
+        self.noise_level
+    }
+
+    /// Returns how many more leveled operations can be applied to the ciphertext before a PBS is
+    /// required to reduce its noise.
+    ///
+    /// `max_noise_level` is the one of the [ServerKey] used for the computations, or
+    /// [MaxNoiseLevel::from_msg_carry_modulus] for the keys generated from parameters.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let mut ct = cks.encrypt(1);
+    /// let budget = ct.remaining_noise_budget(sks.max_noise_level);
+    /// let remaining_additions = budget.remaining_additions();
+    ///
+    /// sks.unchecked_add_assign(&mut ct, &cks.encrypt(1));
+    /// let budget = ct.remaining_noise_budget(sks.max_noise_level);
+    /// assert_eq!(budget.remaining_additions(), remaining_additions - 1);
+    ///
+    /// // A scheduler can force a PBS when the budget is too low for the next operations
+    /// if budget.max_scalar_multiplier() < 3 {
+    ///     sks.message_extract_assign(&mut ct);
+    /// }
+    /// let ct = sks.unchecked_scalar_mul(&ct, 3);
+    /// assert_eq!(cks.decrypt(&ct), 2);
+    /// ```
+    pub fn remaining_noise_budget(&self, max_noise_level: MaxNoiseLevel) -> NoiseBudget {
+        NoiseBudget {
+            noise_level: self.noise_level,
+            max_noise_level,
+        }
+    }
+
     pub fn carry_is_empty(&self) -> bool {
         self.degree.0 < self.message_modulus.0
     }
+
+    /// Returns whether the ciphertext has empty carries and the noise level of a fresh
+    /// ciphertext, i.e. if it can be used as an input of the default operations as is
+    pub fn is_clean(&self) -> bool {
+        self.carry_is_empty() && self.noise_level <= NoiseLevel::NOMINAL
+    }
+
+    /// Returns whether the ciphertext is a trivial encryption, i.e. its mask is zero and its body
+    /// holds the encoded message in the clear, e.g. the ciphertexts created by
+    /// [ServerKey::create_trivial].
+    pub fn is_trivial(&self) -> bool {
+        self.ct.get_mask().as_ref().iter().all(|&x| x == 0)
+    }
+
+    /// Decodes the message and carry of a trivial ciphertext, which does not require the client
+    /// key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let mut ct = sks.create_trivial(3);
+    /// sks.unchecked_add_assign(&mut ct, &sks.create_trivial(2));
+    /// assert!(ct.is_trivial());
+    /// assert_eq!(ct.decrypt_trivial_message_and_carry(), Ok(5));
+    ///
+    /// let ct = cks.encrypt(3);
+    /// assert!(!ct.is_trivial());
+    /// assert!(ct.decrypt_trivial_message_and_carry().is_err());
+    /// ```
+    pub fn decrypt_trivial_message_and_carry(&self) -> Result<u64, NotTrivialCiphertextError> {
+        if !self.is_trivial() {
+            return Err(NotTrivialCiphertextError);
+        }
+
+        let decrypted_u64 = *self.ct.get_body().data;
+
+        let delta = (1_u64 << 63) / (self.message_modulus.0 * self.carry_modulus.0) as u64;
+
+        //The bit before the message
+        let rounding_bit = delta >> 1;
+
+        //compute the rounding bit
+        let rounding = (decrypted_u64 & rounding_bit) << 1;
+
+        Ok((decrypted_u64.wrapping_add(rounding)) / delta)
+    }
+
+    /// Creates a ciphertext from a raw LWE ciphertext encrypting a value of degree `degree` with
+    /// the parameters `parameters`.
+    ///
+    /// Contrary to building the struct directly, the LWE dimension and the ciphertext modulus are
+    /// checked against the parameters, the degree must fit in the message and carry space and the
+    /// noise level must not exceed the maximum noise level of the parameters.
+    ///
+    /// The degree and the noise level are claims made by the caller: they cannot be checked
+    /// without the secret key, and underestimating them leads to wrong results.
+    ///
+    /// If `noise_level` is `None`, the noise of the LWE ciphertext is unknown, so the noise level
+    /// is set to the maximum of the parameters: no leveled operation is allowed before a PBS
+    /// refreshes the ciphertext, and the lookup tables applied to it are never skipped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::ciphertext::{CiphertextCreationError, Degree, NoiseLevel};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::shortint::{gen_keys, Ciphertext};
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let ct = cks.encrypt(3);
+    /// let lwe = ct.ct.clone();
+    ///
+    /// let params = PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // The ciphertext is a fresh encryption, it has the nominal noise level
+    /// let rebuilt = Ciphertext::try_from_raw_parts(
+    ///     lwe.clone(),
+    ///     Degree(3),
+    ///     Some(NoiseLevel::NOMINAL),
+    ///     params,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(cks.decrypt(&rebuilt), 3);
+    /// assert_eq!(rebuilt.noise_level(), NoiseLevel::NOMINAL);
+    ///
+    /// // Without a noise level, the maximum one is assumed
+    /// let rebuilt = Ciphertext::try_from_raw_parts(lwe.clone(), Degree(3), None, params).unwrap();
+    /// assert!(rebuilt.noise_level() > NoiseLevel::NOMINAL);
+    ///
+    /// // 16 does not fit in the 4 bits of message and carry
+    /// let result = Ciphertext::try_from_raw_parts(lwe, Degree(16), None, params);
+    /// assert!(matches!(result, Err(CiphertextCreationError::DegreeTooHigh { .. })));
+    /// # let _ = sks;
+    /// ```
+    pub fn try_from_raw_parts<P>(
+        ct: LweCiphertextOwned<u64>,
+        degree: Degree,
+        noise_level: Option<NoiseLevel>,
+        parameters: P,
+    ) -> Result<Self, CiphertextCreationError>
+    where
+        P: Into<PBSParameters>,
+    {
+        let conformance_params = parameters.into().to_shortint_conformance_param();
+
+        let expected_lwe_dimension = conformance_params.ct_params.lwe_dim;
+        if ct.lwe_size().to_lwe_dimension() != expected_lwe_dimension {
+            return Err(CiphertextCreationError::InvalidLweDimension {
+                expected: expected_lwe_dimension,
+                got: ct.lwe_size().to_lwe_dimension(),
+            });
+        }
+
+        if ct.ciphertext_modulus() != conformance_params.ct_params.ct_modulus {
+            return Err(CiphertextCreationError::InvalidCiphertextModulus);
+        }
+
+        let max_degree =
+            Degree(conformance_params.message_modulus.0 * conformance_params.carry_modulus.0 - 1);
+        if degree.0 > max_degree.0 {
+            return Err(CiphertextCreationError::DegreeTooHigh {
+                degree,
+                max: max_degree,
+            });
+        }
+
+        let max_noise_level = MaxNoiseLevel::from_msg_carry_modulus(
+            conformance_params.message_modulus,
+            conformance_params.carry_modulus,
+        );
+        let noise_level = noise_level.unwrap_or(NoiseLevel(max_noise_level.get()));
+        if max_noise_level.validate(noise_level).is_err() {
+            return Err(CiphertextCreationError::NoiseLevelTooHigh {
+                noise_level,
+                max: max_noise_level,
+            });
+        }
+
+        Ok(Self {
+            ct,
+            degree,
+            noise_level,
+            message_modulus: conformance_params.message_modulus,
+            carry_modulus: conformance_params.carry_modulus,
+            pbs_order: conformance_params.pbs_order,
+        })
+    }
+}
+
+/// Error returned by [`Ciphertext::try_from_raw_parts`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CiphertextCreationError {
+    InvalidLweDimension {
+        expected: LweDimension,
+        got: LweDimension,
+    },
+    InvalidCiphertextModulus,
+    DegreeTooHigh {
+        degree: Degree,
+        max: Degree,
+    },
+    NoiseLevelTooHigh {
+        noise_level: NoiseLevel,
+        max: MaxNoiseLevel,
+    },
+}
+
+impl Display for CiphertextCreationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidLweDimension { expected, got } => {
+                write!(
+                    f,
+                    "The LWE dimension of the ciphertext is {}, the parameters expect {}",
+                    got.0, expected.0
+                )
+            }
+            Self::InvalidCiphertextModulus => {
+                write!(
+                    f,
+                    "The ciphertext modulus does not match the one of the parameters"
+                )
+            }
+            Self::DegreeTooHigh { degree, max } => {
+                write!(
+                    f,
+                    "The degree {} does not fit in the message and carry space, max is {}",
+                    degree.0, max.0
+                )
+            }
+            Self::NoiseLevelTooHigh { noise_level, max } => {
+                write!(
+                    f,
+                    "The noise level {} exceeds the maximum noise level {}",
+                    noise_level.get(),
+                    max.get()
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for CiphertextCreationError {}
+
+/// Error returned when decoding a ciphertext which is not a trivial encryption, see
+/// [`Ciphertext::decrypt_trivial_message_and_carry`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotTrivialCiphertextError;
+
+impl Display for NotTrivialCiphertextError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "The ciphertext is not a trivial encryption")
+    }
 }
 
+impl std::error::Error for NotTrivialCiphertextError {}
+
 /// A structure representing a compressed shortint ciphertext.
 /// It is used to homomorphically evaluate a shortint circuits.
 /// Internally, it uses a LWE ciphertext.
@@ -177,6 +660,10 @@ pub struct CompressedCiphertext {
     pub pbs_order: PBSOrder,
 }
 
+impl crate::named::Named for CompressedCiphertext {
+    const NAME: &'static str = "shortint::CompressedCiphertext";
+}
+
 impl ParameterSetConformant for CompressedCiphertext {
     type ParameterSet = CiphertextConformanceParams;
 
@@ -202,6 +689,7 @@ impl CompressedCiphertext {
         Ciphertext {
             ct: ct.decompress_into_lwe_ciphertext(),
             degree,
+            noise_level: NoiseLevel::NOMINAL,
             message_modulus,
             carry_modulus,
             pbs_order,
@@ -215,28 +703,172 @@ impl From<CompressedCiphertext> for Ciphertext {
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct CompactCiphertextList {
     pub ct_list: LweCompactCiphertextListOwned<u64>,
     pub degree: Degree,
     pub message_modulus: MessageModulus,
     pub carry_modulus: CarryModulus,
     pub pbs_order: PBSOrder,
+    /// The noise level of the ciphertexts of the list, it grows with each re-randomization
+    ///
+    /// The expanded ciphertexts never get a noise level below the one of a fresh encryption with
+    /// a compact public key.
+    pub noise_level: NoiseLevel,
+    /// The metadata of each ciphertext of the list, when they do not all share the degree and
+    /// message modulus of the list
+    ///
+    /// The carry modulus of a ciphertext is what remains of the plaintext space of the list, the
+    /// product of its message and carry moduli being the same for all the ciphertexts.
+    pub element_metadata: Option<Vec<ElementMetadata>>,
+}
+
+// The list is serialized through a versioned enum so that the format can evolve while old lists
+// stay readable, the borrowed variant avoids cloning the list to serialize it.
+#[derive(Serialize)]
+struct CompactCiphertextListV0Ref<'a> {
+    ct_list: &'a LweCompactCiphertextListOwned<u64>,
+    degree: Degree,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+    pbs_order: PBSOrder,
+    noise_level: NoiseLevel,
+    element_metadata: &'a Option<Vec<ElementMetadata>>,
+}
+
+#[derive(Serialize)]
+enum SerializableCompactCiphertextList<'a> {
+    V0(CompactCiphertextListV0Ref<'a>),
+}
+
+#[derive(Deserialize)]
+struct CompactCiphertextListV0 {
+    ct_list: LweCompactCiphertextListOwned<u64>,
+    degree: Degree,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+    pbs_order: PBSOrder,
+    noise_level: NoiseLevel,
+    element_metadata: Option<Vec<ElementMetadata>>,
+}
+
+#[derive(Deserialize)]
+enum DeserializableCompactCiphertextList {
+    V0(CompactCiphertextListV0),
+}
+
+impl Serialize for CompactCiphertextList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        SerializableCompactCiphertextList::V0(CompactCiphertextListV0Ref {
+            ct_list: &self.ct_list,
+            degree: self.degree,
+            message_modulus: self.message_modulus,
+            carry_modulus: self.carry_modulus,
+            pbs_order: self.pbs_order,
+            noise_level: self.noise_level,
+            element_metadata: &self.element_metadata,
+        })
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for CompactCiphertextList {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        DeserializableCompactCiphertextList::deserialize(deserializer).map(|versioned| {
+            match versioned {
+                DeserializableCompactCiphertextList::V0(list) => Self {
+                    ct_list: list.ct_list,
+                    degree: list.degree,
+                    message_modulus: list.message_modulus,
+                    carry_modulus: list.carry_modulus,
+                    pbs_order: list.pbs_order,
+                    noise_level: list.noise_level,
+                    element_metadata: list.element_metadata,
+                },
+            }
+        })
+    }
+}
+
+/// A list serialized before the lists were versioned, see [LegacyCiphertext].
+///
+/// These lists could not be re-randomized, their ciphertexts have the noise of a fresh
+/// encryption with a compact public key.
+#[derive(Debug, Deserialize)]
+pub struct LegacyCompactCiphertextList {
+    ct_list: LweCompactCiphertextListOwned<u64>,
+    degree: Degree,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+    pbs_order: PBSOrder,
+}
+
+impl From<LegacyCompactCiphertextList> for CompactCiphertextList {
+    fn from(legacy: LegacyCompactCiphertextList) -> Self {
+        Self {
+            ct_list: legacy.ct_list,
+            degree: legacy.degree,
+            message_modulus: legacy.message_modulus,
+            carry_modulus: legacy.carry_modulus,
+            pbs_order: legacy.pbs_order,
+            noise_level: NoiseLevel::PUBLIC_KEY_ENCRYPTION,
+            element_metadata: None,
+        }
+    }
+}
+
+/// The degree and message modulus of a ciphertext of a [`CompactCiphertextList`]
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
+pub struct ElementMetadata {
+    pub degree: Degree,
+    pub message_modulus: MessageModulus,
 }
 
 impl ParameterSetConformant for CompactCiphertextList {
     type ParameterSet = CiphertextListConformanceParams;
 
     fn is_conformant(&self, param: &CiphertextListConformanceParams) -> bool {
+        self.header_is_conformant(param)
+            && (0..self.ct_list.lwe_ciphertext_count().0)
+                .all(|index| self.element_is_conformant(index))
+    }
+}
+
+impl CompactCiphertextList {
+    /// Conformance checks of the list which do not depend on its ciphertexts
+    fn header_is_conformant(&self, param: &CiphertextListConformanceParams) -> bool {
+        let element_metadata_len_is_valid =
+            self.element_metadata.as_ref().map_or(true, |metadata| {
+                metadata.len() == self.ct_list.lwe_ciphertext_count().0
+            });
+
         self.ct_list.is_conformant(&param.ct_list_params)
             && self.message_modulus == param.message_modulus
             && self.carry_modulus == param.carry_modulus
             && self.pbs_order == param.pbs_order
             && self.degree == param.degree
+            && self.noise_level >= NoiseLevel::PUBLIC_KEY_ENCRYPTION
+            && element_metadata_len_is_valid
+    }
+
+    /// Conformance checks of the ciphertext at `index`, the header of the list must be
+    /// conformant
+    fn element_is_conformant(&self, index: usize) -> bool {
+        let total_modulus = self.message_modulus.0 * self.carry_modulus.0;
+        self.element_metadata.as_ref().map_or(true, |metadata| {
+            let metadata = metadata[index];
+            metadata.message_modulus.0.is_power_of_two()
+                && metadata.message_modulus.0 <= total_modulus
+                && metadata.degree.0 < metadata.message_modulus.0
+        })
     }
-}
 
-impl CompactCiphertextList {
     pub fn expand(&self) -> Vec<Ciphertext> {
         let mut output_lwe_ciphertext_list = LweCiphertextList::new(
             0u64,
@@ -262,22 +894,567 @@ impl CompactCiphertextList {
         output_lwe_ciphertext_list
             .as_ref()
             .chunks_exact(self.ct_list.lwe_size().0)
-            .map(|lwe_data| {
+            .enumerate()
+            .map(|(index, lwe_data)| {
                 let ct = LweCiphertext::from_container(
                     lwe_data.to_vec(),
                     self.ct_list.ciphertext_modulus(),
                 );
-                Ciphertext {
-                    ct,
-                    degree: self.degree,
-                    message_modulus: self.message_modulus,
-                    carry_modulus: self.carry_modulus,
-                    pbs_order: self.pbs_order,
-                }
+                self.element_ciphertext(index, ct)
             })
             .collect::<Vec<_>>()
     }
 
+    /// Expand the list, applying the operations configured in `config` to each expanded
+    /// ciphertext.
+    ///
+    /// The parts of the conformance check (if any) that do not depend on the ciphertexts are done
+    /// on the list before expanding it. Then, in a single parallel pass over the list, each
+    /// ciphertext is checked for conformance, expanded, keyswitched using the casting key (if any)
+    /// and finally sanitized with a PBS using the sanitizing key (if any).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::ciphertext::ExpandConfig;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS;
+    /// use tfhe::shortint::{ClientKey, CompactPublicKey, ServerKey};
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS);
+    /// let sks = ServerKey::new(&cks);
+    /// let pk = CompactPublicKey::new(&cks);
+    ///
+    /// let msgs = vec![3u64, 2, 1];
+    /// let list = pk.encrypt_slice(&msgs);
+    ///
+    /// let conformance_params = sks.conformance_params().to_ct_list_conformance_parameters(
+    ///     tfhe::conformance::ListSizeConstraint::exact_size(msgs.len()),
+    /// );
+    ///
+    /// let config = ExpandConfig::new()
+    ///     .with_conformance_params(&conformance_params)
+    ///     .with_sanitizing_key(&sks);
+    ///
+    /// let expanded = list.expand_with(config).unwrap();
+    /// for (ct, msg) in expanded.iter().zip(msgs.iter()) {
+    ///     assert_eq!(cks.decrypt(ct), *msg);
+    /// }
+    /// ```
+    pub fn expand_with(&self, config: ExpandConfig<'_>) -> Result<Vec<Ciphertext>, ExpandError> {
+        self.expand_with_sanitizer(config, |sanitizing_key, ct| {
+            sanitizing_key.message_extract(ct)
+        })
+    }
+
+    /// Expand the list as a list of encrypted booleans.
+    ///
+    /// A sanitizing key is required: each expanded ciphertext goes through a PBS mapping 0 to 0
+    /// and any other value to 1, so that the resulting ciphertexts have a degree of 1, whatever
+    /// the value that was encrypted by the client.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::ciphertext::{Degree, ExpandConfig};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS;
+    /// use tfhe::shortint::{ClientKey, CompactPublicKey, ServerKey};
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS);
+    /// let sks = ServerKey::new(&cks);
+    /// let pk = CompactPublicKey::new(&cks);
+    ///
+    /// let msgs = vec![1u64, 0, 3];
+    /// let list = pk.encrypt_slice(&msgs);
+    ///
+    /// let config = ExpandConfig::new().with_sanitizing_key(&sks);
+    ///
+    /// let expanded = list.expand_booleans_with(config).unwrap();
+    /// for (ct, msg) in expanded.iter().zip(msgs.iter()) {
+    ///     assert_eq!(ct.degree, Degree(1));
+    ///     assert_eq!(cks.decrypt(ct), u64::from(*msg != 0));
+    /// }
+    /// ```
+    pub fn expand_booleans_with(
+        &self,
+        config: ExpandConfig<'_>,
+    ) -> Result<Vec<Ciphertext>, ExpandError> {
+        let Some(sanitizing_key) = config.sanitizing_key else {
+            return Err(ExpandError::MissingSanitizingKey);
+        };
+
+        let lut = sanitizing_key.generate_lookup_table(|x| u64::from(x != 0));
+        self.expand_with_sanitizer(config, |sanitizing_key, ct| {
+            sanitizing_key.apply_lookup_table(ct, &lut)
+        })
+    }
+
+    fn expand_with_sanitizer<F>(
+        &self,
+        config: ExpandConfig<'_>,
+        sanitize: F,
+    ) -> Result<Vec<Ciphertext>, ExpandError>
+    where
+        F: Fn(&ServerKey, &Ciphertext) -> Ciphertext + Sync,
+    {
+        self.check_expand_config(&config)?;
+
+        let process = |index: usize| {
+            if config.conformance_params.is_some() && !self.element_is_conformant(index) {
+                return Err(ExpandError::NonConformantList);
+            }
+
+            let ct = self.expand_element(index);
+            let ct = match config.casting_key {
+                Some(casting_key) => casting_key.cast(&ct),
+                None => ct,
+            };
+            let ct = match config.sanitizing_key {
+                Some(sanitizing_key) => sanitize(sanitizing_key, &ct),
+                None => ct,
+            };
+            Ok(ct)
+        };
+
+        let lwe_ciphertext_count = self.ct_list.lwe_ciphertext_count().0;
+
+        // No parallelism allowed
+        #[cfg(all(feature = "__wasm_api", not(feature = "parallel-wasm-api")))]
+        let result = (0..lwe_ciphertext_count)
+            .map(process)
+            .collect::<Result<Vec<_>, _>>();
+
+        // Parallelism allowed
+        #[cfg(any(not(feature = "__wasm_api"), feature = "parallel-wasm-api"))]
+        let result = {
+            use rayon::prelude::*;
+            (0..lwe_ciphertext_count)
+                .into_par_iter()
+                .map(process)
+                .collect::<Result<Vec<_>, _>>()
+        };
+
+        result
+    }
+
+    /// Checks the header of the list and the compatibility of the keys of `config`, the
+    /// conformance of each ciphertext is left to the caller
+    fn check_expand_config(&self, config: &ExpandConfig<'_>) -> Result<(), ExpandError> {
+        if let Some(params) = config.conformance_params {
+            if !self.header_is_conformant(params) {
+                return Err(ExpandError::NonConformantList);
+            }
+        }
+
+        let mut current_lwe_dimension = self.ct_list.lwe_size().to_lwe_dimension();
+        if let Some(casting_key) = config.casting_key {
+            // The casting key output has the moduli of its destination parameters, which would
+            // not match the metadata of each ciphertext
+            if casting_key.key_switching_key.input_key_lwe_dimension() != current_lwe_dimension
+                || self.element_metadata.is_some()
+            {
+                return Err(ExpandError::IncompatibleCastingKey);
+            }
+            current_lwe_dimension = casting_key
+                .dest_server_key
+                .conformance_params()
+                .ct_params
+                .lwe_dim;
+        }
+
+        if let Some(sanitizing_key) = config.sanitizing_key {
+            if sanitizing_key.conformance_params().ct_params.lwe_dim != current_lwe_dimension {
+                return Err(ExpandError::IncompatibleSanitizingKey);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns an iterator expanding the ciphertexts of the list one at a time.
+    ///
+    /// Contrary to [`Self::expand`], only the ciphertext being yielded is in memory, which keeps
+    /// the memory usage bounded for lists with many ciphertexts, at the cost of the parallelism.
+    pub fn iter_expand(&self) -> CompactCiphertextListExpandIter<'_, '_> {
+        CompactCiphertextListExpandIter {
+            list: self,
+            config: ExpandConfig::new(),
+            index: 0,
+        }
+    }
+
+    /// Returns an iterator expanding the ciphertexts of the list one at a time, applying the
+    /// operations configured in `config` to each of them, see [`Self::expand_with`] and
+    /// [`Self::iter_expand`].
+    ///
+    /// The conformance of the list and the compatibility of the keys are checked before
+    /// returning the iterator.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::ciphertext::ExpandConfig;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS;
+    /// use tfhe::shortint::{ClientKey, CompactPublicKey, ServerKey};
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS);
+    /// let sks = ServerKey::new(&cks);
+    /// let pk = CompactPublicKey::new(&cks);
+    ///
+    /// let msgs = vec![3u64, 2, 1];
+    /// let list = pk.encrypt_slice(&msgs);
+    ///
+    /// let conformance_params = sks.conformance_params().to_ct_list_conformance_parameters(
+    ///     tfhe::conformance::ListSizeConstraint::exact_size(msgs.len()),
+    /// );
+    ///
+    /// let config = ExpandConfig::new()
+    ///     .with_conformance_params(&conformance_params)
+    ///     .with_sanitizing_key(&sks);
+    ///
+    /// let mut sum = 0;
+    /// for ct in list.iter_expand_with(config).unwrap() {
+    ///     sum += cks.decrypt(&ct);
+    /// }
+    /// assert_eq!(sum, 6);
+    /// ```
+    pub fn iter_expand_with<'list, 'key>(
+        &'list self,
+        config: ExpandConfig<'key>,
+    ) -> Result<CompactCiphertextListExpandIter<'list, 'key>, ExpandError> {
+        self.check_expand_config(&config)?;
+
+        // The iterator cannot report errors, so all the ciphertexts are checked beforehand
+        if config.conformance_params.is_some()
+            && !(0..self.ct_list.lwe_ciphertext_count().0)
+                .all(|index| self.element_is_conformant(index))
+        {
+            return Err(ExpandError::NonConformantList);
+        }
+
+        Ok(CompactCiphertextListExpandIter {
+            list: self,
+            config,
+            index: 0,
+        })
+    }
+
+    /// Expands the ciphertexts of the list whose indices are in `range`.
+    ///
+    /// Only the ciphertexts of the range are allocated, so that a list with many ciphertexts can
+    /// be expanded in chunks of bounded size, while each chunk is still expanded in parallel.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of the bounds of the list.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS;
+    /// use tfhe::shortint::{ClientKey, CompactPublicKey};
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS);
+    /// let pk = CompactPublicKey::new(&cks);
+    ///
+    /// let msgs = vec![3u64, 2, 1, 0, 3];
+    /// let list = pk.encrypt_slice(&msgs);
+    ///
+    /// for start in (0..msgs.len()).step_by(2) {
+    ///     let end = (start + 2).min(msgs.len());
+    ///     let expanded = list.expand_range(start..end);
+    ///     for (ct, msg) in expanded.iter().zip(msgs[start..end].iter()) {
+    ///         assert_eq!(cks.decrypt(ct), *msg);
+    ///     }
+    /// }
+    /// ```
+    pub fn expand_range(&self, range: Range<usize>) -> Vec<Ciphertext> {
+        let lwe_ciphertext_count = self.ct_list.lwe_ciphertext_count().0;
+        assert!(
+            range.start <= range.end && range.end <= lwe_ciphertext_count,
+            "The range {range:?} is out of the bounds of a list of {lwe_ciphertext_count} \
+            ciphertexts"
+        );
+
+        // No parallelism allowed
+        #[cfg(all(feature = "__wasm_api", not(feature = "parallel-wasm-api")))]
+        let result = range
+            .map(|index| self.expand_element(index))
+            .collect::<Vec<_>>();
+
+        // Parallelism allowed
+        #[cfg(any(not(feature = "__wasm_api"), feature = "parallel-wasm-api"))]
+        let result = {
+            use rayon::prelude::*;
+            range
+                .into_par_iter()
+                .map(|index| self.expand_element(index))
+                .collect::<Vec<_>>()
+        };
+
+        result
+    }
+
+    fn expand_element(&self, index: usize) -> Ciphertext {
+        let mut lwe = LweCiphertextOwned::new(
+            0u64,
+            self.ct_list.lwe_size(),
+            self.ct_list.ciphertext_modulus(),
+        );
+        expand_lwe_compact_ciphertext_list_element(&mut lwe, &self.ct_list, index);
+
+        self.element_ciphertext(index, lwe)
+    }
+
+    /// Wraps the expanded LWE at `index` with its metadata
+    fn element_ciphertext(&self, index: usize, ct: LweCiphertextOwned<u64>) -> Ciphertext {
+        let (degree, message_modulus, carry_modulus) = match &self.element_metadata {
+            Some(element_metadata) => {
+                let ElementMetadata {
+                    degree,
+                    message_modulus,
+                } = element_metadata[index];
+                let total_modulus = self.message_modulus.0 * self.carry_modulus.0;
+                (
+                    degree,
+                    message_modulus,
+                    CarryModulus(total_modulus / message_modulus.0),
+                )
+            }
+            None => (self.degree, self.message_modulus, self.carry_modulus),
+        };
+
+        Ciphertext {
+            ct,
+            degree,
+            // A forged noise level must not make the sanitization skip its PBS
+            noise_level: self.noise_level.max(NoiseLevel::PUBLIC_KEY_ENCRYPTION),
+            message_modulus,
+            carry_modulus,
+            pbs_order: self.pbs_order,
+        }
+    }
+
+    /// Returns an iterator expanding a stream of lists one list at a time, applying the
+    /// operations configured in `config` to each expanded ciphertext, see [`Self::expand_with`].
+    ///
+    /// This is meant for lists produced by
+    /// [`CompactPublicKey::encrypt_stream`](crate::shortint::CompactPublicKey::encrypt_stream):
+    /// all the lists must have the same parameters as the first one, a list that does not is
+    /// reported as [`ExpandError::InconsistentChunk`]. Only one list is expanded at a time, so
+    /// the lists can be processed as they are received.
+    ///
+    /// As the last list is usually smaller, the conformance parameters should constrain the size
+    /// of the lists with a maximum rather than an exact size.
+    pub fn expand_stream<'key, I>(
+        lists: I,
+        config: ExpandConfig<'key>,
+    ) -> CompactCiphertextListStreamExpandIter<'key, I::IntoIter>
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        CompactCiphertextListStreamExpandIter {
+            lists: lists.into_iter(),
+            config,
+            header: None,
+            index: 0,
+        }
+    }
+
+    /// Splits the list in two at `index` without expanding it, the first list contains the
+    /// ciphertexts before `index`, the second one the others.
+    ///
+    /// The ciphertexts of a compact list are stored in bins of `lwe_dimension` ciphertexts
+    /// sharing a mask, see [`LweCompactCiphertextList::split_at`]: splitting inside a bin is only
+    /// possible when the ciphertexts after `index` are all in this bin, which is always the case
+    /// for lists with less ciphertexts than the LWE dimension.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS;
+    /// use tfhe::shortint::{ClientKey, CompactPublicKey};
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS);
+    /// let pk = CompactPublicKey::new(&cks);
+    ///
+    /// let list = pk.encrypt_slice(&[3u64, 2, 1]);
+    ///
+    /// let (first, second) = list.split_at(1).unwrap();
+    /// let expanded = second.expand();
+    /// assert_eq!(expanded.len(), 2);
+    /// assert_eq!(cks.decrypt(&expanded[0]), 2);
+    /// assert_eq!(cks.decrypt(&expanded[1]), 1);
+    /// # let _ = first;
+    /// ```
+    pub fn split_at(&self, index: usize) -> Result<(Self, Self), CompactListError> {
+        if index > self.ct_list.lwe_ciphertext_count().0 {
+            return Err(CompactListError::IndexOutOfBounds);
+        }
+
+        let (first, second) = self
+            .ct_list
+            .split_at(index)
+            .ok_or(CompactListError::SplitBin)?;
+        let (first_metadata, second_metadata) = match &self.element_metadata {
+            Some(element_metadata) => {
+                let (first, second) = element_metadata.split_at(index);
+                (Some(first.to_vec()), Some(second.to_vec()))
+            }
+            None => (None, None),
+        };
+        let with_ct_list = |ct_list, element_metadata| Self {
+            ct_list,
+            degree: self.degree,
+            message_modulus: self.message_modulus,
+            carry_modulus: self.carry_modulus,
+            pbs_order: self.pbs_order,
+            noise_level: self.noise_level,
+            element_metadata,
+        };
+        Ok((
+            with_ct_list(first, first_metadata),
+            with_ct_list(second, second_metadata),
+        ))
+    }
+
+    /// Concatenates lists without expanding them.
+    ///
+    /// The lists must have the same parameters, and as the ciphertexts of a bin share a mask,
+    /// every list but the last must have a ciphertext count that is a multiple of the LWE
+    /// dimension, see [`LweCompactCiphertextListOwned::concat`]. Lists which do not satisfy this
+    /// can still be forwarded together and expanded with [`Self::expand_stream`].
+    pub fn concat(lists: &[Self]) -> Result<Self, CompactListError> {
+        let Some(first) = lists.first() else {
+            return Err(CompactListError::EmptyConcat);
+        };
+        let header = first.header();
+        if lists.iter().any(|list| list.header() != header) {
+            return Err(CompactListError::IncompatibleLists);
+        }
+
+        let ct_lists = lists
+            .iter()
+            .map(|list| {
+                LweCompactCiphertextList::from_container(
+                    list.ct_list.as_ref(),
+                    list.ct_list.lwe_size(),
+                    list.ct_list.lwe_ciphertext_count(),
+                    list.ct_list.ciphertext_modulus(),
+                )
+            })
+            .collect::<Vec<_>>();
+        let ct_list =
+            LweCompactCiphertextListOwned::concat(&ct_lists).ok_or(CompactListError::SplitBin)?;
+
+        // The metadata of the lists sharing it is repeated for each of their ciphertexts
+        let element_metadata = lists
+            .iter()
+            .any(|list| list.element_metadata.is_some())
+            .then(|| {
+                lists
+                    .iter()
+                    .flat_map(|list| match &list.element_metadata {
+                        Some(element_metadata) => element_metadata.clone(),
+                        None => vec![
+                            ElementMetadata {
+                                degree: list.degree,
+                                message_modulus: list.message_modulus,
+                            };
+                            list.ct_list.lwe_ciphertext_count().0
+                        ],
+                    })
+                    .collect()
+            });
+
+        // The ciphertexts share the noise level of the list, the noisiest one is kept
+        let noise_level = lists
+            .iter()
+            .map(|list| list.noise_level)
+            .max()
+            .unwrap_or(first.noise_level);
+
+        Ok(Self {
+            ct_list,
+            degree: first.degree,
+            message_modulus: first.message_modulus,
+            carry_modulus: first.carry_modulus,
+            pbs_order: first.pbs_order,
+            noise_level,
+            element_metadata,
+        })
+    }
+
+    fn header(&self) -> CompactCiphertextListHeader {
+        CompactCiphertextListHeader {
+            lwe_size: self.ct_list.lwe_size(),
+            ciphertext_modulus: self.ct_list.ciphertext_modulus(),
+            degree: self.degree,
+            message_modulus: self.message_modulus,
+            carry_modulus: self.carry_modulus,
+            pbs_order: self.pbs_order,
+        }
+    }
+
+    pub fn size_elements(&self) -> usize {
+        self.ct_list.size_elements()
+    }
+
+    pub fn size_bytes(&self) -> usize {
+        self.ct_list.size_bytes()
+    }
+}
+
+/// A structure representing a compressed [`CompactCiphertextList`].
+///
+/// Only the bodies of the ciphertexts are stored, the masks being generated from a seed when the
+/// list is decompressed, which roughly halves the size of the list. As the masks are derived from
+/// a seed, such a list can only be encrypted with a [`ClientKey`](crate::shortint::ClientKey).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CompressedCompactCiphertextList {
+    pub ct_list: SeededLweCompactCiphertextListOwned<u64>,
+    pub degree: Degree,
+    pub message_modulus: MessageModulus,
+    pub carry_modulus: CarryModulus,
+    pub pbs_order: PBSOrder,
+}
+
+impl crate::named::Named for CompressedCompactCiphertextList {
+    const NAME: &'static str = "shortint::CompressedCompactCiphertextList";
+}
+
+impl ParameterSetConformant for CompressedCompactCiphertextList {
+    type ParameterSet = CiphertextListConformanceParams;
+
+    fn is_conformant(&self, param: &CiphertextListConformanceParams) -> bool {
+        self.ct_list.is_conformant(&param.ct_list_params)
+            && self.message_modulus == param.message_modulus
+            && self.carry_modulus == param.carry_modulus
+            && self.pbs_order == param.pbs_order
+            && self.degree == param.degree
+    }
+}
+
+impl CompressedCompactCiphertextList {
+    pub fn decompress(self) -> CompactCiphertextList {
+        let CompressedCompactCiphertextList {
+            ct_list,
+            degree,
+            message_modulus,
+            carry_modulus,
+            pbs_order,
+        } = self;
+
+        CompactCiphertextList {
+            ct_list: ct_list.decompress_into_lwe_compact_ciphertext_list(),
+            degree,
+            message_modulus,
+            carry_modulus,
+            pbs_order,
+            noise_level: NoiseLevel::PUBLIC_KEY_ENCRYPTION,
+            element_metadata: None,
+        }
+    }
+
     pub fn size_elements(&self) -> usize {
         self.ct_list.size_elements()
     }
@@ -287,6 +1464,213 @@ impl CompactCiphertextList {
     }
 }
 
+impl From<CompressedCompactCiphertextList> for CompactCiphertextList {
+    fn from(value: CompressedCompactCiphertextList) -> Self {
+        value.decompress()
+    }
+}
+
+/// The parameters that must be shared by all the lists of a stream
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct CompactCiphertextListHeader {
+    lwe_size: LweSize,
+    ciphertext_modulus: CiphertextModulus<u64>,
+    degree: Degree,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+    pbs_order: PBSOrder,
+}
+
+/// Iterator expanding a stream of [`CompactCiphertextList`] one list at a time, see
+/// [`CompactCiphertextList::expand_stream`].
+pub struct CompactCiphertextListStreamExpandIter<'key, I> {
+    lists: I,
+    config: ExpandConfig<'key>,
+    header: Option<CompactCiphertextListHeader>,
+    index: usize,
+}
+
+impl<'key, I> Iterator for CompactCiphertextListStreamExpandIter<'key, I>
+where
+    I: Iterator<Item = CompactCiphertextList>,
+{
+    type Item = Result<Vec<Ciphertext>, ExpandError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let list = self.lists.next()?;
+        let index = self.index;
+        self.index += 1;
+
+        let header = list.header();
+        if *self.header.get_or_insert(header) != header {
+            return Some(Err(ExpandError::InconsistentChunk(index)));
+        }
+
+        Some(list.expand_with(self.config))
+    }
+}
+
+/// Iterator expanding the ciphertexts of a [`CompactCiphertextList`] one at a time, see
+/// [`CompactCiphertextList::iter_expand`].
+pub struct CompactCiphertextListExpandIter<'list, 'key> {
+    list: &'list CompactCiphertextList,
+    config: ExpandConfig<'key>,
+    index: usize,
+}
+
+impl<'list, 'key> Iterator for CompactCiphertextListExpandIter<'list, 'key> {
+    type Item = Ciphertext;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let ct_list = &self.list.ct_list;
+        if self.index >= ct_list.lwe_ciphertext_count().0 {
+            return None;
+        }
+
+        let ct = self.list.expand_element(self.index);
+        self.index += 1;
+
+        let ct = match self.config.casting_key {
+            Some(casting_key) => casting_key.cast(&ct),
+            None => ct,
+        };
+        let ct = match self.config.sanitizing_key {
+            Some(sanitizing_key) => sanitizing_key.message_extract(&ct),
+            None => ct,
+        };
+        Some(ct)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.list.ct_list.lwe_ciphertext_count().0 - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'list, 'key> ExactSizeIterator for CompactCiphertextListExpandIter<'list, 'key> {}
+
+/// Operations to apply to each ciphertext when expanding a [`CompactCiphertextList`] with
+/// [`CompactCiphertextList::expand_with`].
+///
+/// By default no operation is applied, which is equivalent to calling
+/// [`CompactCiphertextList::expand`].
+#[derive(Copy, Clone, Default)]
+pub struct ExpandConfig<'key> {
+    pub conformance_params: Option<&'key CiphertextListConformanceParams>,
+    pub casting_key: Option<&'key KeySwitchingKey>,
+    pub sanitizing_key: Option<&'key ServerKey>,
+}
+
+impl<'key> ExpandConfig<'key> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check that the list is conformant to the given parameters before expanding it.
+    pub fn with_conformance_params(
+        mut self,
+        params: &'key CiphertextListConformanceParams,
+    ) -> Self {
+        self.conformance_params = Some(params);
+        self
+    }
+
+    /// Keyswitch each expanded ciphertext to the destination parameters of the casting key.
+    pub fn with_casting_key(mut self, casting_key: &'key KeySwitchingKey) -> Self {
+        self.casting_key = Some(casting_key);
+        self
+    }
+
+    /// Apply a PBS to each expanded ciphertext, so that it has a canonical noise and degree.
+    pub fn with_sanitizing_key(mut self, sanitizing_key: &'key ServerKey) -> Self {
+        self.sanitizing_key = Some(sanitizing_key);
+        self
+    }
+}
+
+/// Error returned by [`CompactCiphertextList::expand_with`] and
+/// [`CompactCiphertextList::expand_stream`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExpandError {
+    NonConformantList,
+    IncompatibleCastingKey,
+    IncompatibleSanitizingKey,
+    MissingSanitizingKey,
+    /// The list at this index of a stream does not have the same parameters as the first one
+    InconsistentChunk(usize),
+}
+
+impl Display for ExpandError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NonConformantList => {
+                write!(
+                    f,
+                    "The compact list is not conformant to the expected parameters"
+                )
+            }
+            Self::IncompatibleCastingKey => {
+                write!(f, "The casting key input does not match the compact list")
+            }
+            Self::IncompatibleSanitizingKey => {
+                write!(
+                    f,
+                    "The sanitizing key input does not match the expanded ciphertexts"
+                )
+            }
+            Self::MissingSanitizingKey => {
+                write!(f, "A sanitizing key is required to expand this list")
+            }
+            Self::InconsistentChunk(index) => {
+                write!(
+                    f,
+                    "The list {index} of the stream does not have the same parameters as the \
+                    first one"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ExpandError {}
+
+/// Error returned by [`CompactCiphertextList::split_at`] and [`CompactCiphertextList::concat`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompactListError {
+    IndexOutOfBounds,
+    /// The ciphertexts sharing a mask would not be in a single bin of the resulting lists
+    SplitBin,
+    IncompatibleLists,
+    EmptyConcat,
+}
+
+impl Display for CompactListError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::IndexOutOfBounds => {
+                write!(f, "The split index is greater than the list length")
+            }
+            Self::SplitBin => {
+                write!(
+                    f,
+                    "The ciphertexts sharing a mask cannot be split across several bins"
+                )
+            }
+            Self::IncompatibleLists => {
+                write!(
+                    f,
+                    "The lists to concatenate do not have the same parameters"
+                )
+            }
+            Self::EmptyConcat => {
+                write!(f, "At least one list is required to concatenate lists")
+            }
+        }
+    }
+}
+
+impl std::error::Error for CompactListError {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -300,6 +1684,7 @@ mod tests {
                 CiphertextModulus::new_native(),
             ),
             degree: Degree(1),
+            noise_level: NoiseLevel::NOMINAL,
             message_modulus: MessageModulus(1),
             carry_modulus: CarryModulus(1),
             pbs_order: PBSOrder::KeyswitchBootstrap,
@@ -311,6 +1696,7 @@ mod tests {
                 CiphertextModulus::new_native(),
             ),
             degree: Degree(42),
+            noise_level: NoiseLevel::ZERO,
             message_modulus: MessageModulus(2),
             carry_modulus: CarryModulus(2),
             pbs_order: PBSOrder::BootstrapKeyswitch,
@@ -330,6 +1716,7 @@ mod tests {
                 CiphertextModulus::try_new_power_of_2(32).unwrap(),
             ),
             degree: Degree(1),
+            noise_level: NoiseLevel::NOMINAL,
             message_modulus: MessageModulus(1),
             carry_modulus: CarryModulus(1),
             pbs_order: PBSOrder::KeyswitchBootstrap,
@@ -341,6 +1728,7 @@ mod tests {
                 CiphertextModulus::new_native(),
             ),
             degree: Degree(42),
+            noise_level: NoiseLevel::ZERO,
             message_modulus: MessageModulus(2),
             carry_modulus: CarryModulus(2),
             pbs_order: PBSOrder::BootstrapKeyswitch,
@@ -360,6 +1748,7 @@ mod tests {
                 CiphertextModulus::new_native(),
             ),
             degree: Degree(1),
+            noise_level: NoiseLevel::NOMINAL,
             message_modulus: MessageModulus(1),
             carry_modulus: CarryModulus(1),
             pbs_order: PBSOrder::KeyswitchBootstrap,
@@ -371,6 +1760,7 @@ mod tests {
                 CiphertextModulus::new_native(),
             ),
             degree: Degree(42),
+            noise_level: NoiseLevel::ZERO,
             message_modulus: MessageModulus(2),
             carry_modulus: CarryModulus(2),
             pbs_order: PBSOrder::BootstrapKeyswitch,
@@ -381,4 +1771,130 @@ mod tests {
         c1.clone_from(&c2);
         assert_eq!(c1, c2);
     }
+
+    #[test]
+    fn test_try_from_raw_parts() {
+        use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+
+        let params = PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+        // KS_PBS parameters encrypt under the big key
+        let lwe_size = params
+            .glwe_dimension
+            .to_equivalent_lwe_dimension(params.polynomial_size)
+            .to_lwe_size();
+
+        let lwe = LweCiphertextOwned::new(0u64, lwe_size, params.ciphertext_modulus);
+        let ct = Ciphertext::try_from_raw_parts(lwe.clone(), Degree(15), None, params).unwrap();
+        assert_eq!(ct.message_modulus, params.message_modulus);
+        assert_eq!(ct.carry_modulus, params.carry_modulus);
+        assert_eq!(ct.pbs_order, PBSOrder::KeyswitchBootstrap);
+        // The noise of the raw ciphertext is unknown, it must be refreshed before being used
+        assert_eq!(
+            ct.remaining_noise_budget(MaxNoiseLevel::from_msg_carry_modulus(
+                params.message_modulus,
+                params.carry_modulus
+            ))
+            .remaining_additions(),
+            0
+        );
+        assert!(ct.noise_level() > NoiseLevel::NOMINAL);
+
+        let max_noise_level =
+            MaxNoiseLevel::from_msg_carry_modulus(params.message_modulus, params.carry_modulus);
+        let ct = Ciphertext::try_from_raw_parts(
+            lwe.clone(),
+            Degree(15),
+            Some(NoiseLevel::NOMINAL),
+            params,
+        )
+        .unwrap();
+        assert_eq!(ct.noise_level(), NoiseLevel::NOMINAL);
+
+        let too_noisy = NoiseLevel(max_noise_level.get() + 1);
+        assert_eq!(
+            Ciphertext::try_from_raw_parts(lwe.clone(), Degree(15), Some(too_noisy), params),
+            Err(CiphertextCreationError::NoiseLevelTooHigh {
+                noise_level: too_noisy,
+                max: max_noise_level,
+            })
+        );
+
+        assert_eq!(
+            Ciphertext::try_from_raw_parts(lwe, Degree(16), None, params),
+            Err(CiphertextCreationError::DegreeTooHigh {
+                degree: Degree(16),
+                max: Degree(15),
+            })
+        );
+
+        let small_lwe = LweCiphertextOwned::new(
+            0u64,
+            params.lwe_dimension.to_lwe_size(),
+            params.ciphertext_modulus,
+        );
+        assert_eq!(
+            Ciphertext::try_from_raw_parts(small_lwe, Degree(0), None, params),
+            Err(CiphertextCreationError::InvalidLweDimension {
+                expected: lwe_size.to_lwe_dimension(),
+                got: params.lwe_dimension,
+            })
+        );
+
+        let non_native_lwe = LweCiphertextOwned::new(
+            0u64,
+            lwe_size,
+            CiphertextModulus::try_new_power_of_2(62).unwrap(),
+        );
+        assert_eq!(
+            Ciphertext::try_from_raw_parts(non_native_lwe, Degree(0), None, params),
+            Err(CiphertextCreationError::InvalidCiphertextModulus)
+        );
+    }
+
+    #[test]
+    fn test_deserialize_legacy_ciphertext() {
+        // A ciphertext serialized with bincode before the ciphertexts were versioned
+        let legacy_serialized: &[u8] = &[
+            // ct.data: length then elements
+            3, 0, 0, 0, 0, 0, 0, 0, //
+            1, 0, 0, 0, 0, 0, 0, 0, //
+            2, 0, 0, 0, 0, 0, 0, 0, //
+            0, 0, 0, 0, 0, 0, 0, 24, //
+            // ct.ciphertext_modulus: native modulus then scalar bits
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, //
+            64, 0, 0, 0, 0, 0, 0, 0, //
+            // degree
+            3, 0, 0, 0, 0, 0, 0, 0, //
+            // message_modulus
+            4, 0, 0, 0, 0, 0, 0, 0, //
+            // carry_modulus
+            4, 0, 0, 0, 0, 0, 0, 0, //
+            // pbs_order: KeyswitchBootstrap
+            0, 0, 0, 0,
+        ];
+
+        assert!(bincode::deserialize::<Ciphertext>(legacy_serialized).is_err());
+
+        let ct: Ciphertext = bincode::deserialize::<LegacyCiphertext>(legacy_serialized)
+            .unwrap()
+            .into();
+        assert_eq!(ct.ct.as_ref(), &[1u64, 2, 3 << 59]);
+        assert_eq!(ct.ct.ciphertext_modulus(), CiphertextModulus::new_native());
+        assert_eq!(ct.degree, Degree(3));
+        assert_eq!(ct.message_modulus, MessageModulus(4));
+        assert_eq!(ct.carry_modulus, CarryModulus(4));
+        assert_eq!(ct.pbs_order, PBSOrder::KeyswitchBootstrap);
+        // The noise of legacy ciphertexts is unknown
+        assert_eq!(
+            ct.noise_level,
+            NoiseLevel(
+                MaxNoiseLevel::from_msg_carry_modulus(MessageModulus(4), CarryModulus(4)).get()
+            )
+        );
+
+        // Current ciphertexts round trip through their versioned format
+        let serialized = bincode::serialize(&ct).unwrap();
+        let deserialized: Ciphertext = bincode::deserialize(&serialized).unwrap();
+        assert_eq!(deserialized, ct);
+    }
 }
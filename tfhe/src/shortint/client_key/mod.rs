@@ -1,10 +1,14 @@
 //! Module with the definition of the ClientKey.
 
+use crate::core_crypto::algorithms::decrypt_lwe_ciphertext;
 use crate::core_crypto::entities::*;
-use crate::shortint::ciphertext::{Ciphertext, CompressedCiphertext};
+use crate::shortint::ciphertext::{
+    Ciphertext, CompressedCiphertext, CompressedCompactCiphertextList, Degree, NoiseLevel,
+};
 use crate::shortint::engine::ShortintEngine;
 use crate::shortint::parameters::{MessageModulus, ShortintParameterSet};
-use crate::shortint::CarryModulus;
+use crate::shortint::{CarryModulus, PBSOrder};
+use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 
@@ -16,6 +20,8 @@ use std::fmt::Debug;
 /// * `glwe_secret_key` - a GLWE secret key, used to generate the bootstrapping keys and key
 /// switching keys.
 /// * `parameters` - the cryptographic parameter set.
+///
+/// The secret keys are zeroized when the client key is dropped.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ClientKey {
     /// The LWE secret key equivalent to the GLWE secret key
@@ -50,6 +56,128 @@ impl ClientKey {
         })
     }
 
+    /// Construct a client key from its secret keys, e.g. keys generated outside of this crate by
+    /// a threshold key generation.
+    ///
+    /// The LWE secret key equivalent to the GLWE secret key is derived from it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// - the dimension or polynomial size of `glwe_secret_key` do not match the parameters,
+    /// - the dimension of `lwe_secret_key` does not match the parameters,
+    /// - one of the secret keys is not binary.
+    ///
+    /// The secret keys are zeroized before panicking.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::shortint::ClientKey;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// let ct = cks.encrypt(3);
+    ///
+    /// let (glwe_secret_key, lwe_secret_key, parameters) = cks.into_raw_parts();
+    /// let cks = ClientKey::from_raw_parts(glwe_secret_key, lwe_secret_key, parameters);
+    ///
+    /// assert_eq!(cks.decrypt(&ct), 3);
+    /// ```
+    #[cfg(feature = "secret-key-raw-parts")]
+    pub fn from_raw_parts<P>(
+        mut glwe_secret_key: GlweSecretKeyOwned<u64>,
+        mut lwe_secret_key: LweSecretKeyOwned<u64>,
+        parameters: P,
+    ) -> ClientKey
+    where
+        P: TryInto<ShortintParameterSet>,
+        <P as TryInto<ShortintParameterSet>>::Error: Debug,
+    {
+        // The secret keys are zeroized before panicking, as they would otherwise be dropped as is
+        let parameters: Result<ShortintParameterSet, _> = parameters.try_into();
+        let check = parameters
+            .as_ref()
+            .map_err(|err| format!("Invalid parameters: {err:?}"))
+            .and_then(|parameters| {
+                Self::check_raw_parts(&glwe_secret_key, &lwe_secret_key, parameters)
+            });
+        if let Err(message) = check {
+            zeroize::Zeroize::zeroize(&mut glwe_secret_key);
+            zeroize::Zeroize::zeroize(&mut lwe_secret_key);
+            panic!("{message}");
+        }
+        let parameters = parameters.unwrap();
+
+        let large_lwe_secret_key = glwe_secret_key.clone().into_lwe_secret_key();
+
+        ClientKey {
+            large_lwe_secret_key,
+            glwe_secret_key,
+            small_lwe_secret_key: lwe_secret_key,
+            parameters,
+        }
+    }
+
+    #[cfg(feature = "secret-key-raw-parts")]
+    fn check_raw_parts(
+        glwe_secret_key: &GlweSecretKeyOwned<u64>,
+        lwe_secret_key: &LweSecretKeyOwned<u64>,
+        parameters: &ShortintParameterSet,
+    ) -> Result<(), String> {
+        if glwe_secret_key.glwe_dimension() != parameters.glwe_dimension() {
+            return Err(format!(
+                "Mismatch between the GlweDimension of the GLWE secret key and the parameters: \
+                {:?} != {:?}",
+                glwe_secret_key.glwe_dimension(),
+                parameters.glwe_dimension()
+            ));
+        }
+        if glwe_secret_key.polynomial_size() != parameters.polynomial_size() {
+            return Err(format!(
+                "Mismatch between the PolynomialSize of the GLWE secret key and the parameters: \
+                {:?} != {:?}",
+                glwe_secret_key.polynomial_size(),
+                parameters.polynomial_size()
+            ));
+        }
+        if lwe_secret_key.lwe_dimension() != parameters.lwe_dimension() {
+            return Err(format!(
+                "Mismatch between the LweDimension of the LWE secret key and the parameters: \
+                {:?} != {:?}",
+                lwe_secret_key.lwe_dimension(),
+                parameters.lwe_dimension()
+            ));
+        }
+        if !glwe_secret_key.as_ref().iter().all(|&bit| bit <= 1) {
+            return Err("The GLWE secret key is not binary".to_owned());
+        }
+        if !lwe_secret_key.as_ref().iter().all(|&bit| bit <= 1) {
+            return Err("The LWE secret key is not binary".to_owned());
+        }
+        Ok(())
+    }
+
+    /// Deconstruct a client key into its GLWE secret key, its LWE secret key and its parameters,
+    /// see [`Self::from_raw_parts`].
+    ///
+    /// The secret keys kept by the client key are zeroized.
+    #[cfg(feature = "secret-key-raw-parts")]
+    pub fn into_raw_parts(
+        self,
+    ) -> (
+        GlweSecretKeyOwned<u64>,
+        LweSecretKeyOwned<u64>,
+        ShortintParameterSet,
+    ) {
+        // The client key zeroizes its own copies of the secret keys when dropped
+        let glwe_secret_key = self.glwe_secret_key.clone();
+        let small_lwe_secret_key = self.small_lwe_secret_key.clone();
+        let parameters = self.parameters;
+
+        (glwe_secret_key, small_lwe_secret_key, parameters)
+    }
+
     /// Encrypt a small integer message using the client key.
     ///
     /// The input message is reduced to the encrypted message space modulus
@@ -118,6 +246,45 @@ impl ClientKey {
         })
     }
 
+    /// Encrypt a list of small integer messages in a compressed compact list, using the client
+    /// key.
+    ///
+    /// The list is about half the size of a compact list encrypted with a
+    /// [`CompactPublicKey`](crate::shortint::CompactPublicKey), and gives a
+    /// [`CompactCiphertextList`](crate::shortint::ciphertext::CompactCiphertextList) once
+    /// decompressed.
+    ///
+    /// The messages are reduced modulo the message modulus of the parameters.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS;
+    /// use tfhe::shortint::ClientKey;
+    ///
+    /// // Generate the client key
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS);
+    ///
+    /// let msgs = [0, 1, 2, 3];
+    /// let compressed_list = cks.encrypt_compressed_compact_list(&msgs);
+    ///
+    /// let list = compressed_list.decompress();
+    ///
+    /// for (ct, msg) in list.expand().iter().zip(msgs) {
+    ///     assert_eq!(cks.decrypt(ct), msg);
+    /// }
+    /// ```
+    pub fn encrypt_compressed_compact_list(
+        &self,
+        messages: &[u64],
+    ) -> CompressedCompactCiphertextList {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .encrypt_compressed_compact_list(self, messages)
+                .unwrap()
+        })
+    }
+
     /// Encrypt a small integer message using the client key with a specific message modulus
     ///
     /// # Example
@@ -328,6 +495,135 @@ impl ClientKey {
         ShortintEngine::with_thread_local_mut(|engine| engine.decrypt(self, ct).unwrap())
     }
 
+    /// Decrypt a batch of ciphertexts encrypting messages and carries using the client key.
+    ///
+    /// The ciphertexts are decrypted in parallel and the decoding constants are computed once for
+    /// the whole batch.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::shortint::ClientKey;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let msgs = [3, 5, 10, 15];
+    /// let cts: Vec<_> = msgs.iter().map(|msg| cks.encrypt(*msg)).collect();
+    ///
+    /// let decs = cks.decrypt_message_and_carry_batch(&cts);
+    /// assert_eq!(decs, msgs);
+    /// ```
+    pub fn decrypt_message_and_carry_batch(&self, cts: &[Ciphertext]) -> Vec<u64> {
+        let delta = (1_u64 << 63)
+            / (self.parameters.message_modulus().0 * self.parameters.carry_modulus().0) as u64;
+
+        //The bit before the message
+        let rounding_bit = delta >> 1;
+
+        cts.par_iter()
+            .map(|ct| {
+                let lwe_decryption_key = match ct.pbs_order {
+                    PBSOrder::KeyswitchBootstrap => &self.large_lwe_secret_key,
+                    PBSOrder::BootstrapKeyswitch => &self.small_lwe_secret_key,
+                };
+
+                let decrypted_u64 = decrypt_lwe_ciphertext(lwe_decryption_key, &ct.ct).0;
+
+                let rounding = (decrypted_u64 & rounding_bit) << 1;
+
+                decrypted_u64.wrapping_add(rounding) / delta
+            })
+            .collect()
+    }
+
+    /// Decrypt a batch of ciphertexts encrypting messages using the client key.
+    ///
+    /// The ciphertexts are decrypted in parallel and the decoding constants are computed once for
+    /// the whole batch.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::shortint::ClientKey;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let msgs = [0, 1, 2, 3];
+    /// let cts: Vec<_> = msgs.iter().map(|msg| cks.encrypt(*msg)).collect();
+    ///
+    /// let decs = cks.decrypt_batch(&cts);
+    /// assert_eq!(decs, msgs);
+    /// ```
+    pub fn decrypt_batch(&self, cts: &[Ciphertext]) -> Vec<u64> {
+        let mut decrypted = self.decrypt_message_and_carry_batch(cts);
+        for (message, ct) in decrypted.iter_mut().zip(cts.iter()) {
+            *message %= ct.message_modulus.0 as u64;
+        }
+        decrypted
+    }
+
+    /// Measures the noise of a ciphertext, to find which operations bring it close to a
+    /// decryption failure.
+    ///
+    /// The noise is the distance between the phase of the ciphertext and the closest encoded
+    /// value, it gives a wrong decryption once it reaches the
+    /// [decision boundary](BlockNoiseReport::decision_boundary). As opposed to the
+    /// [noise level](Ciphertext::noise_level) tracked by the server key, this is the actual noise
+    /// of this ciphertext.
+    ///
+    /// This is a development tool, the side doing the computations must not have the client key
+    /// in production.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let ct = cks.encrypt(1);
+    /// let fresh = cks.analyze_noise(&ct);
+    /// assert_eq!(fresh.message, 1);
+    /// assert!(fresh.margin_bits() > 0.0);
+    ///
+    /// // Linear operations add up the noises
+    /// let sum = sks.unchecked_add(&ct, &sks.unchecked_add(&ct, &ct));
+    /// let report = cks.analyze_noise(&sum);
+    /// assert_eq!(report.message, 3);
+    /// assert!(report.margin_bits() > 0.0);
+    ///
+    /// // Trivial encryptions have no noise
+    /// let trivial = cks.analyze_noise(&sks.create_trivial(2));
+    /// assert_eq!(trivial.noise, 0);
+    /// ```
+    pub fn analyze_noise(&self, ct: &Ciphertext) -> BlockNoiseReport {
+        let lwe_decryption_key = match ct.pbs_order {
+            PBSOrder::KeyswitchBootstrap => &self.large_lwe_secret_key,
+            PBSOrder::BootstrapKeyswitch => &self.small_lwe_secret_key,
+        };
+
+        let message_modulus = ct.message_modulus.0 as u64;
+        let carry_modulus = ct.carry_modulus.0 as u64;
+        let delta = (1_u64 << 63) / (message_modulus * carry_modulus);
+
+        let phase = decrypt_lwe_ciphertext(lwe_decryption_key, &ct.ct).0;
+        let rounded = phase.wrapping_add(delta / 2) / delta;
+        let noise = phase.wrapping_sub(rounded.wrapping_mul(delta)) as i64;
+        let message_and_carry = rounded % (message_modulus * carry_modulus);
+
+        BlockNoiseReport {
+            message: message_and_carry % message_modulus,
+            carry: message_and_carry / message_modulus,
+            degree: ct.degree,
+            noise_level: ct.noise_level,
+            noise,
+            decision_boundary: delta / 2,
+        }
+    }
+
     /// Encrypt a small integer message using the client key without padding bit.
     ///
     /// The input message is reduced to the encrypted message space modulus
@@ -573,3 +869,118 @@ impl ClientKey {
         })
     }
 }
+
+/// Noise of a ciphertext measured by [ClientKey::analyze_noise]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BlockNoiseReport {
+    /// The decrypted message
+    pub message: u64,
+    /// The decrypted carry
+    pub carry: u64,
+    pub degree: Degree,
+    /// Noise level tracked by the server key operations
+    pub noise_level: NoiseLevel,
+    /// Signed distance between the phase of the ciphertext and the closest encoded value
+    pub noise: i64,
+    /// Half the distance between two encoded values, the decryption is wrong when the absolute
+    /// value of the noise reaches it
+    pub decision_boundary: u64,
+}
+
+impl BlockNoiseReport {
+    /// Base 2 logarithm of the absolute value of the noise, negative infinity if there is no noise
+    pub fn log2_noise(&self) -> f64 {
+        (self.noise.unsigned_abs() as f64).log2()
+    }
+
+    /// Number of bits between the noise and the decision boundary
+    ///
+    /// The decryption fails when the margin reaches 0. The margin of a trivial encryption is
+    /// infinite.
+    pub fn margin_bits(&self) -> f64 {
+        (self.decision_boundary as f64).log2() - self.log2_noise()
+    }
+}
+
+impl zeroize::Zeroize for ClientKey {
+    fn zeroize(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.large_lwe_secret_key);
+        zeroize::Zeroize::zeroize(&mut self.glwe_secret_key);
+        zeroize::Zeroize::zeroize(&mut self.small_lwe_secret_key);
+    }
+}
+
+impl Drop for ClientKey {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+impl zeroize::ZeroizeOnDrop for ClientKey {}
+
+#[cfg(all(test, feature = "secret-key-raw-parts"))]
+mod test {
+    use super::ClientKey;
+    use crate::core_crypto::entities::LweSecretKey;
+    use crate::shortint::parameters::{
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    };
+
+    #[test]
+    fn test_client_key_raw_parts_round_trip() {
+        let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+        let ct = cks.encrypt(2);
+
+        let (glwe_secret_key, lwe_secret_key, parameters) = cks.clone().into_raw_parts();
+        let rebuilt = ClientKey::from_raw_parts(glwe_secret_key, lwe_secret_key, parameters);
+
+        assert_eq!(rebuilt, cks);
+        assert_eq!(rebuilt.decrypt(&ct), 2);
+    }
+
+    #[test]
+    fn test_client_key_zeroize_on_drop() {
+        fn assert_zeroize_on_drop<T: zeroize::ZeroizeOnDrop>() {}
+        assert_zeroize_on_drop::<ClientKey>();
+
+        let mut cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+        zeroize::Zeroize::zeroize(&mut cks);
+
+        for key in [
+            cks.large_lwe_secret_key.as_ref(),
+            cks.glwe_secret_key.as_ref(),
+            cks.small_lwe_secret_key.as_ref(),
+        ] {
+            assert!(key.iter().all(|&bit| bit == 0));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Mismatch between the GlweDimension of the GLWE secret key")]
+    fn test_client_key_raw_parts_mismatched_parameters() {
+        let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+        let (glwe_secret_key, lwe_secret_key, _) = cks.into_raw_parts();
+
+        let _ = ClientKey::from_raw_parts(
+            glwe_secret_key,
+            lwe_secret_key,
+            PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "The LWE secret key is not binary")]
+    fn test_client_key_raw_parts_non_binary_key() {
+        let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+        let (glwe_secret_key, lwe_secret_key, parameters) = cks.into_raw_parts();
+
+        let mut data = lwe_secret_key.into_container();
+        data[0] = 2;
+
+        let _ = ClientKey::from_raw_parts(
+            glwe_secret_key,
+            LweSecretKey::from_container(data),
+            parameters,
+        );
+    }
+}
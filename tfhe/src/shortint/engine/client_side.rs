@@ -2,14 +2,27 @@
 use super::{EngineResult, ShortintEngine};
 use crate::core_crypto::algorithms::*;
 use crate::core_crypto::commons::dispersion::DispersionParameter;
+use crate::core_crypto::commons::math::random::Seeder;
+use crate::core_crypto::commons::parameters::LweCiphertextCount;
 use crate::core_crypto::entities::*;
-use crate::shortint::ciphertext::Degree;
+use crate::shortint::ciphertext::{CompressedCompactCiphertextList, Degree, NoiseLevel};
 use crate::shortint::parameters::{CarryModulus, MessageModulus};
 use crate::shortint::{
     Ciphertext, ClientKey, CompressedCiphertext, PBSOrder, ShortintParameterSet,
 };
 
 impl ShortintEngine {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(
+                message_modulus = parameters.message_modulus().0,
+                carry_modulus = parameters.carry_modulus().0,
+            )
+        )
+    )]
     pub fn new_client_key(&mut self, parameters: ShortintParameterSet) -> EngineResult<ClientKey> {
         // generate the lwe secret key
         let small_lwe_secret_key = allocate_and_generate_new_binary_lwe_secret_key(
@@ -55,7 +68,7 @@ impl ShortintEngine {
         )
     }
 
-    fn encrypt_inner_ct(
+    pub(crate) fn encrypt_inner_ct(
         &mut self,
         client_key_parameters: &ShortintParameterSet,
         client_lwe_sk: &LweSecretKeyOwned<u64>,
@@ -120,6 +133,7 @@ impl ShortintEngine {
         Ok(Ciphertext {
             ct,
             degree: Degree(message_modulus.0 - 1),
+            noise_level: NoiseLevel::NOMINAL,
             message_modulus,
             carry_modulus: CarryModulus(carry_modulus),
             pbs_order: params_op_order,
@@ -165,6 +179,7 @@ impl ShortintEngine {
         Ok(Ciphertext {
             ct,
             degree: Degree(message_modulus.0 - 1),
+            noise_level: NoiseLevel::NOMINAL,
             message_modulus,
             carry_modulus,
             pbs_order: params_op_order,
@@ -225,6 +240,63 @@ impl ShortintEngine {
         })
     }
 
+    pub(crate) fn encrypt_compressed_compact_list(
+        &mut self,
+        client_key: &ClientKey,
+        messages: &[u64],
+    ) -> EngineResult<CompressedCompactCiphertextList> {
+        let message_modulus = client_key.parameters.message_modulus();
+        let carry_modulus = client_key.parameters.carry_modulus();
+
+        //The delta is the one defined by the parameters
+        let delta = (1_u64 << 63) / (message_modulus.0 * carry_modulus.0) as u64;
+
+        //The inputs are reduced modulus the message_modulus
+        let encoded = PlaintextList::from_container(
+            messages
+                .iter()
+                .map(|message| (message % message_modulus.0 as u64) * delta)
+                .collect::<Vec<_>>(),
+        );
+
+        let params_op_order: PBSOrder = client_key.parameters.encryption_key_choice().into();
+
+        let (encryption_lwe_sk, encryption_noise) = match params_op_order {
+            PBSOrder::KeyswitchBootstrap => (
+                &client_key.large_lwe_secret_key,
+                client_key.parameters.glwe_modular_std_dev(),
+            ),
+            PBSOrder::BootstrapKeyswitch => (
+                &client_key.small_lwe_secret_key,
+                client_key.parameters.lwe_modular_std_dev(),
+            ),
+        };
+
+        let mut ct_list = SeededLweCompactCiphertextListOwned::new(
+            0u64,
+            encryption_lwe_sk.lwe_dimension().to_lwe_size(),
+            LweCiphertextCount(messages.len()),
+            self.seeder.seed().into(),
+            client_key.parameters.ciphertext_modulus(),
+        );
+
+        encrypt_seeded_lwe_compact_ciphertext_list(
+            encryption_lwe_sk,
+            &mut ct_list,
+            &encoded,
+            encryption_noise,
+            &mut self.seeder,
+        );
+
+        Ok(CompressedCompactCiphertextList {
+            ct_list,
+            degree: Degree(message_modulus.0 - 1),
+            message_modulus,
+            carry_modulus,
+            pbs_order: params_op_order,
+        })
+    }
+
     pub(crate) fn unchecked_encrypt(
         &mut self,
         client_key: &ClientKey,
@@ -264,6 +336,7 @@ impl ShortintEngine {
                 client_key.parameters.message_modulus().0 * client_key.parameters.carry_modulus().0
                     - 1,
             ),
+            noise_level: NoiseLevel::NOMINAL,
             message_modulus: client_key.parameters.message_modulus(),
             carry_modulus: client_key.parameters.carry_modulus(),
             pbs_order: params_op_order,
@@ -342,6 +415,7 @@ impl ShortintEngine {
         Ok(Ciphertext {
             ct,
             degree: Degree(client_key.parameters.message_modulus().0 - 1),
+            noise_level: NoiseLevel::NOMINAL,
             message_modulus: client_key.parameters.message_modulus(),
             carry_modulus: client_key.parameters.carry_modulus(),
             pbs_order: params_op_order,
@@ -467,6 +541,7 @@ impl ShortintEngine {
         Ok(Ciphertext {
             ct,
             degree: Degree(message_modulus as usize - 1),
+            noise_level: NoiseLevel::NOMINAL,
             message_modulus: MessageModulus(message_modulus as usize),
             carry_modulus: CarryModulus(carry_modulus),
             pbs_order: params_op_order,
@@ -11,11 +11,14 @@ use crate::core_crypto::commons::math::random::{ActivatedRandomGenerator, Seeder
 use crate::core_crypto::entities::*;
 use crate::core_crypto::prelude::ContainerMut;
 use crate::core_crypto::seeders::new_seeder;
-use crate::shortint::ciphertext::Degree;
-use crate::shortint::server_key::{BivariateLookupTableOwned, LookupTableOwned};
+use crate::shortint::ciphertext::{Ciphertext, Degree, NoiseLevel};
+use crate::shortint::server_key::{
+    BivariateLookupTableOwned, LookupTableOwned, PbsBackend, ShortintBootstrappingKey,
+};
 use crate::shortint::ServerKey;
 use std::cell::RefCell;
 use std::fmt::Debug;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 
 use super::parameters::MessageModulus;
 use super::server_key::BivariateLookupTable;
@@ -29,6 +32,65 @@ thread_local! {
     static LOCAL_ENGINE: RefCell<ShortintEngine> = RefCell::new(ShortintEngine::new());
 }
 
+// Shared by the engines of all threads, as operations are spread over the rayon threads
+static PBS_COUNT: AtomicU64 = AtomicU64::new(0);
+
+/// Returns the number of programmable bootstraps computed by the engines of all threads since the
+/// start of the program, or the last call to [reset_programmable_bootstrap_count].
+///
+/// The programmable bootstrap dominates the cost of the computations, this count can be used to
+/// compare the cost of several implementations of an application, e.g. with the
+/// [simulation parameters](crate::shortint::parameters::parameters_simulator).
+pub fn programmable_bootstrap_count() -> u64 {
+    PBS_COUNT.load(Ordering::Relaxed)
+}
+
+/// Resets the count of [programmable_bootstrap_count], returning its value before the reset.
+pub fn reset_programmable_bootstrap_count() -> u64 {
+    PBS_COUNT.swap(0, Ordering::Relaxed)
+}
+
+fn count_programmable_bootstrap() {
+    PBS_COUNT.fetch_add(1, Ordering::Relaxed);
+}
+
+// Shared by the engines of all threads, for the same reason as PBS_COUNT
+static SKIPPED_PBS_COUNT: AtomicU64 = AtomicU64::new(0);
+
+/// Returns the number of programmable bootstraps skipped by the engines of all threads since the
+/// start of the program, or the last call to [reset_skipped_programmable_bootstrap_count].
+///
+/// A lookup table applied to a ciphertext with a nominal noise level is skipped when it is the
+/// identity on all the values the degree of the ciphertext allows, e.g. a message extraction of a
+/// ciphertext with empty carries. The skipped bootstraps are not part of
+/// [programmable_bootstrap_count].
+pub fn skipped_programmable_bootstrap_count() -> u64 {
+    SKIPPED_PBS_COUNT.load(Ordering::Relaxed)
+}
+
+/// Resets the count of [skipped_programmable_bootstrap_count], returning its value before the
+/// reset.
+pub fn reset_skipped_programmable_bootstrap_count() -> u64 {
+    SKIPPED_PBS_COUNT.swap(0, Ordering::Relaxed)
+}
+
+fn count_skipped_programmable_bootstrap() {
+    SKIPPED_PBS_COUNT.fetch_add(1, Ordering::Relaxed);
+}
+
+// Shared by the engines of all threads, for the same reason as PBS_COUNT
+static DETERMINISTIC: AtomicBool = AtomicBool::new(false);
+
+// Called before the PBS, a key in the Fourier domain would silently break the bit-exact results
+fn check_deterministic_pbs(bootstrapping_key: &ShortintBootstrappingKey) {
+    assert!(
+        matches!(bootstrapping_key, ShortintBootstrappingKey::Ntt(_))
+            || !ShortintEngine::is_deterministic(),
+        "The engines are in deterministic mode but the server key was generated for the Fourier \
+        PBS, generate or decompress the server key after ShortintEngine::set_deterministic(true)"
+    );
+}
+
 pub struct BuffersRef<'a> {
     // For the intermediate keyswitch result in the case of a big ciphertext
     pub(crate) buffer_lwe_after_ks: LweCiphertextMutView<'a, u64>,
@@ -36,6 +98,8 @@ pub struct BuffersRef<'a> {
     pub(crate) buffer_lwe_after_pbs: LweCiphertextMutView<'a, u64>,
 }
 
+// Unlike the boolean bootstrapper, the buffers are not generic over the scalar: the shortint
+// ciphertexts are always 64 bits
 #[derive(Default)]
 struct Memory {
     buffer: Vec<u64>,
@@ -132,6 +196,56 @@ where
     max_value
 }
 
+/// Returns whether the programmable bootstrap of `ct` with `acc` would output the same message
+/// with the same noise level as `ct`, so that it can be skipped.
+///
+/// This relies on the nominal noise level being the noise of a PBS output: the producers of
+/// ciphertexts with more noise, e.g. the public key encryptions, tag them above it.
+///
+/// The coefficients of the accumulator that the blind rotation can read for each value allowed by
+/// the degree of `ct` are checked directly, so the lookup table does not have to come from
+/// [fill_accumulator].
+fn is_no_op_lookup_table(server_key: &ServerKey, ct: &Ciphertext, acc: &LookupTableOwned) -> bool {
+    if ct.noise_level() != NoiseLevel::NOMINAL
+        || ct.degree.0 >= server_key.message_modulus.0
+        || ct.message_modulus != server_key.message_modulus
+        || ct.carry_modulus != server_key.carry_modulus
+        || ct.pbs_order != server_key.pbs_order
+    {
+        return false;
+    }
+
+    // The accumulator must be a trivial encryption, otherwise its values are unknown
+    let mask = acc.acc.get_mask();
+    if mask.as_ref().iter().any(|&coefficient| coefficient != 0) {
+        return false;
+    }
+
+    let body = acc.acc.get_body();
+    let body = body.as_ref();
+
+    let modulus_sup = server_key.message_modulus.0 * server_key.carry_modulus.0;
+    let polynomial_size = body.len();
+    let box_size = polynomial_size / modulus_sup;
+    let half_box_size = box_size / 2;
+    let delta = (1_u64 << 63) / modulus_sup as u64;
+
+    (0..=ct.degree.0).all(|value| {
+        // The noise of an encryption of value moves the rotation of the accumulator within the
+        // box centered on value * box_size, the coefficients before the start of the accumulator
+        // are read negated
+        let center = value * box_size;
+        (center..center + box_size).all(|index| {
+            let coefficient = if index < half_box_size {
+                body[polynomial_size + index - half_box_size].wrapping_neg()
+            } else {
+                body[index - half_box_size]
+            };
+            coefficient == value as u64 * delta
+        })
+    })
+}
+
 /// Simple wrapper around [`std::error::Error`] to be able to
 /// forward all the possible `EngineError` type from [`core_crypto`](crate::core_crypto)
 #[allow(dead_code)]
@@ -193,6 +307,17 @@ impl ShortintEngine {
         LOCAL_ENGINE.with(|engine_cell| func(&mut engine_cell.borrow_mut()))
     }
 
+    /// Replace the `thread_local` shortint engine
+    ///
+    /// `new_engine` will replace the already existing `thread_local` engine, e.g. with an engine
+    /// created from a fork of a
+    /// [RandomnessService](crate::core_crypto::commons::generators::RandomnessService).
+    pub fn replace_thread_local(new_engine: Self) {
+        Self::with_thread_local_mut(|local_engine| {
+            let _ = std::mem::replace(local_engine, new_engine);
+        })
+    }
+
     /// Create a new shortint engine
     ///
     /// Creating a `ShortintEngine` should not be needed, as each
@@ -209,6 +334,60 @@ impl ShortintEngine {
         Self::new_from_seeder(root_seeder.as_mut())
     }
 
+    /// Enable or disable the deterministic mode of the engines of all threads.
+    ///
+    /// The FFT used by the programmable bootstrap computes in floating point, its results (and
+    /// hence the noise of the output ciphertexts) can differ in the last bits between x86 and ARM
+    /// CPUs. In deterministic mode the server keys are instead generated in the NTT domain, the
+    /// programmable bootstrap only uses integer arithmetic and its results are bit-exact on all
+    /// platforms.
+    ///
+    /// The mode applies to the server keys generated (or decompressed) afterwards, a server key
+    /// in the Fourier domain panics on its first programmable bootstrap while the mode is
+    /// enabled, so that a bit-exact result is never silently replaced by a platform-dependent
+    /// one. The NTT is slower than the FFT and only supports the classic PBS parameters.
+    ///
+    /// To choose the transform of a single server key instead, see
+    /// [ServerKey::new_with_pbs_backend](crate::shortint::ServerKey::new_with_pbs_backend).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::engine::ShortintEngine;
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// ShortintEngine::set_deterministic(true);
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let ct = cks.encrypt(3);
+    /// let lut = sks.generate_lookup_table(|x| (x * 3) % 4);
+    /// let res = sks.apply_lookup_table(&ct, &lut);
+    ///
+    /// assert_eq!(cks.decrypt(&res), 1);
+    ///
+    /// ShortintEngine::set_deterministic(false);
+    /// ```
+    pub fn set_deterministic(deterministic: bool) {
+        DETERMINISTIC.store(deterministic, Ordering::Relaxed);
+    }
+
+    /// Returns whether the deterministic mode is enabled, see
+    /// [ShortintEngine::set_deterministic].
+    pub fn is_deterministic() -> bool {
+        DETERMINISTIC.load(Ordering::Relaxed)
+    }
+
+    /// The [PbsBackend] of the server keys generated without an explicit choice
+    pub(crate) fn default_pbs_backend() -> PbsBackend {
+        if Self::is_deterministic() {
+            PbsBackend::Ntt
+        } else {
+            PbsBackend::Fft
+        }
+    }
+
     pub fn new_from_seeder(root_seeder: &mut dyn Seeder) -> Self {
         let mut deterministic_seeder =
             DeterministicSeeder::<ActivatedRandomGenerator>::new(root_seeder.seed());
@@ -3,7 +3,7 @@ use super::{EngineResult, ShortintEngine};
 use crate::core_crypto::algorithms::*;
 use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::entities::*;
-use crate::shortint::ciphertext::Degree;
+use crate::shortint::ciphertext::{Degree, NoiseLevel};
 use crate::shortint::parameters::{CarryModulus, MessageModulus};
 use crate::shortint::{Ciphertext, ClientKey, CompressedPublicKey, PublicKey};
 
@@ -20,6 +20,17 @@ pub fn shortint_public_key_zero_encryption_count(
 }
 
 impl ShortintEngine {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(
+                message_modulus = client_key.parameters.message_modulus().0,
+                carry_modulus = client_key.parameters.carry_modulus().0,
+            )
+        )
+    )]
     pub(crate) fn new_public_key(&mut self, client_key: &ClientKey) -> EngineResult<PublicKey> {
         let client_parameters = client_key.parameters;
 
@@ -64,6 +75,17 @@ impl ShortintEngine {
         })
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(
+                message_modulus = client_key.parameters.message_modulus().0,
+                carry_modulus = client_key.parameters.carry_modulus().0,
+            )
+        )
+    )]
     pub(crate) fn new_compressed_public_key(
         &mut self,
         client_key: &ClientKey,
@@ -180,12 +202,48 @@ impl ShortintEngine {
         Ok(Ciphertext {
             ct: encrypted_ct,
             degree: Degree(message_modulus.0 - 1),
+            noise_level: NoiseLevel::PUBLIC_KEY_ENCRYPTION,
             message_modulus,
             carry_modulus: CarryModulus(carry_modulus),
             pbs_order: public_key.pbs_order,
         })
     }
 
+    pub(crate) fn rerandomize_with_public_key(
+        &mut self,
+        public_key: &PublicKey,
+        ct: &Ciphertext,
+    ) -> EngineResult<Ciphertext> {
+        assert_eq!(
+            ct.pbs_order, public_key.pbs_order,
+            "The ciphertext is not encrypted under the key of the public key"
+        );
+
+        // This allocates the required ct
+        let mut rerandomized_ct = LweCiphertextOwned::new(
+            0u64,
+            public_key.lwe_public_key.lwe_size(),
+            public_key.lwe_public_key.ciphertext_modulus(),
+        );
+
+        encrypt_lwe_ciphertext_with_public_key(
+            &public_key.lwe_public_key,
+            &mut rerandomized_ct,
+            Plaintext(0),
+            &mut self.secret_generator,
+        );
+        lwe_ciphertext_add_assign(&mut rerandomized_ct, &ct.ct);
+
+        Ok(Ciphertext {
+            ct: rerandomized_ct,
+            degree: ct.degree,
+            noise_level: ct.noise_level + NoiseLevel::NOMINAL,
+            message_modulus: ct.message_modulus,
+            carry_modulus: ct.carry_modulus,
+            pbs_order: ct.pbs_order,
+        })
+    }
+
     pub(crate) fn encrypt_with_message_modulus_and_compressed_public_key(
         &mut self,
         public_key: &CompressedPublicKey,
@@ -228,6 +286,7 @@ impl ShortintEngine {
         Ok(Ciphertext {
             ct: encrypted_ct,
             degree: Degree(message_modulus.0 - 1),
+            noise_level: NoiseLevel::PUBLIC_KEY_ENCRYPTION,
             message_modulus,
             carry_modulus: CarryModulus(carry_modulus),
             pbs_order: public_key.pbs_order,
@@ -267,6 +326,7 @@ impl ShortintEngine {
         Ok(Ciphertext {
             ct: encrypted_ct,
             degree: Degree(public_key.parameters.message_modulus().0 - 1),
+            noise_level: NoiseLevel::PUBLIC_KEY_ENCRYPTION,
             message_modulus: public_key.parameters.message_modulus(),
             carry_modulus: public_key.parameters.carry_modulus(),
             pbs_order: public_key.pbs_order,
@@ -306,6 +366,7 @@ impl ShortintEngine {
         Ok(Ciphertext {
             ct: encrypted_ct,
             degree: Degree(public_key.parameters.message_modulus().0 - 1),
+            noise_level: NoiseLevel::PUBLIC_KEY_ENCRYPTION,
             message_modulus: public_key.parameters.message_modulus(),
             carry_modulus: public_key.parameters.carry_modulus(),
             pbs_order: public_key.pbs_order,
@@ -342,6 +403,7 @@ impl ShortintEngine {
         Ok(Ciphertext {
             ct: encrypted_ct,
             degree: Degree(message_modulus as usize - 1),
+            noise_level: NoiseLevel::PUBLIC_KEY_ENCRYPTION,
             message_modulus: MessageModulus(message_modulus as usize),
             carry_modulus: CarryModulus(carry_modulus),
             pbs_order: public_key.pbs_order,
@@ -378,6 +440,7 @@ impl ShortintEngine {
         Ok(Ciphertext {
             ct: encrypted_ct,
             degree: Degree(message_modulus as usize - 1),
+            noise_level: NoiseLevel::PUBLIC_KEY_ENCRYPTION,
             message_modulus: MessageModulus(message_modulus as usize),
             carry_modulus: CarryModulus(carry_modulus),
             pbs_order: public_key.pbs_order,
@@ -416,6 +479,7 @@ impl ShortintEngine {
                 public_key.parameters.message_modulus().0 * public_key.parameters.carry_modulus().0
                     - 1,
             ),
+            noise_level: NoiseLevel::PUBLIC_KEY_ENCRYPTION,
             message_modulus: public_key.parameters.message_modulus(),
             carry_modulus: public_key.parameters.carry_modulus(),
             pbs_order: public_key.pbs_order,
@@ -454,6 +518,7 @@ impl ShortintEngine {
                 public_key.parameters.message_modulus().0 * public_key.parameters.carry_modulus().0
                     - 1,
             ),
+            noise_level: NoiseLevel::PUBLIC_KEY_ENCRYPTION,
             message_modulus: public_key.parameters.message_modulus(),
             carry_modulus: public_key.parameters.carry_modulus(),
             pbs_order: public_key.pbs_order,
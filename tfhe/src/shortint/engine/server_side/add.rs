@@ -1,5 +1,5 @@
 use crate::core_crypto::algorithms::*;
-use crate::shortint::ciphertext::Degree;
+use crate::shortint::ciphertext::{Degree, NoiseLevel};
 use crate::shortint::engine::{EngineResult, ShortintEngine};
 use crate::shortint::{Ciphertext, ServerKey};
 
@@ -21,9 +21,24 @@ impl ShortintEngine {
     ) -> EngineResult<()> {
         lwe_ciphertext_add_assign(&mut ct_left.ct, &ct_right.ct);
         ct_left.degree = Degree(ct_left.degree.0 + ct_right.degree.0);
+        ct_left.noise_level += ct_right.noise_level;
         Ok(())
     }
 
+    /// Returns whether `extracted` and `other` can be added once the message of `extracted` is
+    /// extracted
+    fn is_add_possible_after_extraction(
+        server_key: &ServerKey,
+        extracted: &Ciphertext,
+        other: &Ciphertext,
+    ) -> bool {
+        extracted.message_modulus.0 - 1 + other.degree.0 <= server_key.max_degree.0
+            && server_key
+                .max_noise_level
+                .validate(NoiseLevel::NOMINAL + other.noise_level)
+                .is_ok()
+    }
+
     pub(crate) fn smart_add(
         &mut self,
         server_key: &ServerKey,
@@ -32,9 +47,9 @@ impl ShortintEngine {
     ) -> EngineResult<Ciphertext> {
         //If the ciphertext cannot be added together without exceeding the capacity of a ciphertext
         if !server_key.is_add_possible(ct_left, ct_right) {
-            if ct_left.message_modulus.0 - 1 + ct_right.degree.0 <= server_key.max_degree.0 {
+            if Self::is_add_possible_after_extraction(server_key, ct_left, ct_right) {
                 self.message_extract_assign(server_key, ct_left)?;
-            } else if ct_right.message_modulus.0 - 1 + ct_left.degree.0 <= server_key.max_degree.0 {
+            } else if Self::is_add_possible_after_extraction(server_key, ct_right, ct_left) {
                 self.message_extract_assign(server_key, ct_right)?;
             } else {
                 self.message_extract_assign(server_key, ct_left)?;
@@ -55,9 +70,9 @@ impl ShortintEngine {
     ) -> EngineResult<()> {
         //If the ciphertext cannot be added together without exceeding the capacity of a ciphertext
         if !server_key.is_add_possible(ct_left, ct_right) {
-            if ct_left.message_modulus.0 - 1 + ct_right.degree.0 <= server_key.max_degree.0 {
+            if Self::is_add_possible_after_extraction(server_key, ct_left, ct_right) {
                 self.message_extract_assign(server_key, ct_left)?;
-            } else if ct_right.message_modulus.0 - 1 + ct_left.degree.0 <= server_key.max_degree.0 {
+            } else if Self::is_add_possible_after_extraction(server_key, ct_right, ct_left) {
                 self.message_extract_assign(server_key, ct_right)?;
             } else {
                 self.message_extract_assign(server_key, ct_left)?;
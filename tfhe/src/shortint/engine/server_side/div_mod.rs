@@ -11,6 +11,15 @@ pub(crate) fn safe_division(x: u64, y: u64, value_on_div_by_zero: u64) -> u64 {
     }
 }
 
+// Specific remainder function returning the numerator in case of a division by 0
+pub(crate) fn safe_remainder(x: u64, y: u64) -> u64 {
+    if y == 0 {
+        x
+    } else {
+        x % y
+    }
+}
+
 impl ShortintEngine {
     pub(crate) fn unchecked_div(
         &mut self,
@@ -39,6 +48,62 @@ impl ShortintEngine {
         Ok(())
     }
 
+    pub(crate) fn unchecked_div_rem(
+        &mut self,
+        server_key: &ServerKey,
+        ct_left: &Ciphertext,
+        ct_right: &Ciphertext,
+    ) -> EngineResult<(Ciphertext, Ciphertext)> {
+        let value_on_div_by_zero = (ct_left.message_modulus.0 - 1) as u64;
+        let message_modulus = server_key.message_modulus.0 as u64;
+        let modulus_sup = server_key.message_modulus.0 * server_key.carry_modulus.0;
+
+        // Both outputs read the same packed input ct_left * factor + ct_right
+        let factor = ct_right.degree.0 + 1;
+        let factor_u64 = factor as u64;
+        let unpack = |input: u64| {
+            (
+                (input / factor_u64) % message_modulus,
+                (input % factor_u64) % message_modulus,
+            )
+        };
+        let quotient = |input: u64| {
+            let (x, y) = unpack(input);
+            safe_division(x, y, value_on_div_by_zero)
+        };
+        let remainder = |input: u64| {
+            let (x, y) = unpack(input);
+            safe_remainder(x, y)
+        };
+
+        let mut packed = ct_left.clone();
+        self.unchecked_scalar_mul_assign(&mut packed, factor as u8)?;
+        self.unchecked_add_assign(&mut packed, ct_right)?;
+
+        let (quotient, mut remainder) = if packed.degree.0 < modulus_sup / 2 {
+            // The packed input fits in half of the message space, both functions are evaluated
+            // by a single blind rotation
+            let acc = self.generate_many_lookup_table(server_key, &[&quotient, &remainder])?;
+            let mut outputs = self.apply_lookup_table_multi_output(server_key, &packed, &acc)?;
+            let remainder = outputs.pop().unwrap();
+            let quotient = outputs.pop().unwrap();
+            (quotient, remainder)
+        } else {
+            let quotient_acc = self.generate_lookup_table(server_key, quotient)?;
+            let remainder_acc = self.generate_lookup_table(server_key, remainder)?;
+            (
+                self.apply_lookup_table(server_key, &packed, &quotient_acc)?,
+                self.apply_lookup_table(server_key, &packed, &remainder_acc)?,
+            )
+        };
+
+        // The remainder is never greater than the numerator, which the lookup table cannot
+        // know as it is also evaluated on inputs the packing does not produce
+        remainder.degree = Degree(remainder.degree.0.min(ct_left.degree.0));
+
+        Ok((quotient, remainder))
+    }
+
     pub(crate) fn smart_div(
         &mut self,
         server_key: &ServerKey,
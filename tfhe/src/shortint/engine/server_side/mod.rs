@@ -1,21 +1,26 @@
-use super::ShortintEngine;
+use super::{
+    check_deterministic_pbs, count_programmable_bootstrap, count_skipped_programmable_bootstrap,
+    fill_accumulator, is_no_op_lookup_table, ShortintEngine,
+};
 use crate::core_crypto::algorithms::*;
 use crate::core_crypto::commons::ciphertext_modulus::CiphertextModulus;
 use crate::core_crypto::commons::parameters::{
     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweBskGroupingFactor,
-    LweDimension, PolynomialSize, ThreadCount,
+    LweDimension, MonomialDegree, PolynomialSize, ThreadCount,
 };
 use crate::core_crypto::entities::*;
 use crate::core_crypto::fft_impl::fft64::crypto::bootstrap::FourierLweBootstrapKey;
 use crate::core_crypto::fft_impl::fft64::math::fft::Fft;
-use crate::shortint::ciphertext::Degree;
+use crate::core_crypto::fft_impl::ntt64::math::ntt::Ntt;
+use crate::shortint::ciphertext::{Degree, NoiseLevel};
 use crate::shortint::engine::EngineResult;
 use crate::shortint::parameters::{MessageModulus, ShortintKeySwitchingParameters};
 use crate::shortint::server_key::{
-    BivariateLookupTableOwned, LookupTableOwned, MaxDegree, ShortintBootstrappingKey,
-    ShortintCompressedBootstrappingKey,
+    BivariateLookupTableOwned, LookupTableOwned, ManyLutAccumulator, MaxDegree,
+    MaxNoiseLevelPolicy, PbsBackend, ShortintBootstrappingKey, ShortintCompressedBootstrappingKey,
 };
 use crate::shortint::{Ciphertext, ClientKey, CompressedServerKey, PBSOrder, ServerKey};
+use std::ops::ControlFlow;
 
 mod add;
 mod bitwise_op;
@@ -30,6 +35,27 @@ mod scalar_sub;
 mod shift;
 mod sub;
 
+#[derive(Debug)]
+pub enum ServerKeyCreationError {
+    UnsupportedDeterministicMultiBit,
+}
+
+impl std::error::Error for ServerKeyCreationError {}
+
+impl std::fmt::Display for ServerKeyCreationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ServerKeyCreationError::UnsupportedDeterministicMultiBit => {
+                write!(
+                    f,
+                    "The NTT backend of the PBS, used by the deterministic mode of the engines, \
+                    does not support multi bit PBS"
+                )
+            }
+        }
+    }
+}
+
 impl ShortintEngine {
     pub(crate) fn new_server_key(&mut self, cks: &ClientKey) -> EngineResult<ServerKey> {
         // Plaintext Max Value
@@ -40,6 +66,45 @@ impl ShortintEngine {
         self.new_server_key_with_max_degree(cks, max)
     }
 
+    /// Convert a classic bootstrapping key to the domain of the transform of `pbs_backend`.
+    pub(crate) fn convert_classic_bootstrapping_key(
+        bootstrap_key: &LweBootstrapKeyOwned<u64>,
+        pbs_backend: PbsBackend,
+    ) -> ShortintBootstrappingKey {
+        match pbs_backend {
+            PbsBackend::Ntt => {
+                // Creation of the bootstrapping key in the NTT domain
+                let mut ntt_bsk = NttLweBootstrapKeyOwned::new(
+                    bootstrap_key.input_lwe_dimension(),
+                    bootstrap_key.glwe_size(),
+                    bootstrap_key.polynomial_size(),
+                    bootstrap_key.decomposition_base_log(),
+                    bootstrap_key.decomposition_level_count(),
+                );
+
+                // Conversion to NTT domain
+                par_convert_standard_lwe_bootstrap_key_to_ntt64(bootstrap_key, &mut ntt_bsk);
+
+                ShortintBootstrappingKey::Ntt(ntt_bsk)
+            }
+            PbsBackend::Fft => {
+                // Creation of the bootstrapping key in the Fourier domain
+                let mut fourier_bsk = FourierLweBootstrapKey::new(
+                    bootstrap_key.input_lwe_dimension(),
+                    bootstrap_key.glwe_size(),
+                    bootstrap_key.polynomial_size(),
+                    bootstrap_key.decomposition_base_log(),
+                    bootstrap_key.decomposition_level_count(),
+                );
+
+                // Conversion to fourier domain
+                par_convert_standard_lwe_bootstrap_key_to_fourier(bootstrap_key, &mut fourier_bsk);
+
+                ShortintBootstrappingKey::Classic(fourier_bsk)
+            }
+        }
+    }
+
     pub(crate) fn get_thread_count_for_multi_bit_pbs(
         &self,
         lwe_dimension: LweDimension,
@@ -49,18 +114,20 @@ impl ShortintEngine {
         pbs_level: DecompositionLevelCount,
         grouping_factor: LweBskGroupingFactor,
     ) -> ThreadCount {
-        // TODO
-        // Will be used later when we dynamically compute thread counts, put them in the public
-        // signature of the function for now
-        let _ = (
-            lwe_dimension,
+        // A thread count pinned by the user or found by the autotuner takes precedence
+        let key = MultiBitPbsThreadCountKey {
+            input_lwe_dimension: lwe_dimension,
             glwe_dimension,
             polynomial_size,
-            pbs_base_log,
-            pbs_level,
-        );
+            decomposition_base_log: pbs_base_log,
+            decomposition_level_count: pbs_level,
+            grouping_factor,
+        };
+        if let Some(thread_count) = cached_multi_bit_pbs_thread_count(key) {
+            return thread_count;
+        }
 
-        // For now optimal threads for m6i.metal across 1_1, 2_2, 3_3 and 4_4 params
+        // Otherwise use the optimal threads for m6i.metal across 1_1, 2_2, 3_3 and 4_4 params
         match grouping_factor.0 {
             2 => ThreadCount(5),
             3 => ThreadCount(7),
@@ -70,52 +137,112 @@ impl ShortintEngine {
         }
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(
+                message_modulus = cks.parameters.message_modulus().0,
+                carry_modulus = cks.parameters.carry_modulus().0,
+            )
+        )
+    )]
     pub(crate) fn new_server_key_with_max_degree(
         &mut self,
         cks: &ClientKey,
         max_degree: MaxDegree,
     ) -> EngineResult<ServerKey> {
+        self.new_server_key_with_max_degree_and_pbs_backend(
+            cks,
+            max_degree,
+            Self::default_pbs_backend(),
+        )
+    }
+
+    /// Generate the server key with a bootstrapping key for `pbs_backend`, whatever the mode of
+    /// the engines, see [ShortintEngine::set_deterministic].
+    pub(crate) fn new_server_key_with_max_degree_and_pbs_backend(
+        &mut self,
+        cks: &ClientKey,
+        max_degree: MaxDegree,
+        pbs_backend: PbsBackend,
+    ) -> EngineResult<ServerKey> {
+        let server_key =
+            self.new_server_key_with_progress(cks, max_degree, pbs_backend, &|| {
+                ControlFlow::Continue(())
+            })?;
+        // The progress callback never stops the generation
+        Ok(server_key.unwrap())
+    }
+
+    /// Generate the server key, calling `on_ggsw_encrypted` each time one of the GGSW ciphertexts
+    /// of the bootstrapping key is encrypted.
+    ///
+    /// Returns `None` if `on_ggsw_encrypted` stopped the generation of the bootstrapping key.
+    pub(crate) fn new_server_key_with_progress(
+        &mut self,
+        cks: &ClientKey,
+        max_degree: MaxDegree,
+        pbs_backend: PbsBackend,
+        on_ggsw_encrypted: &(dyn Fn() -> ControlFlow<()> + Sync),
+    ) -> EngineResult<Option<ServerKey>> {
         let params = &cks.parameters;
         let pbs_params_base = params.pbs_parameters().unwrap();
         let bootstrapping_key_base = match pbs_params_base {
             crate::shortint::PBSParameters::PBS(pbs_params) => {
-                let bootstrap_key: LweBootstrapKeyOwned<u64> =
-                    par_allocate_and_generate_new_lwe_bootstrap_key(
-                        &cks.small_lwe_secret_key,
-                        &cks.glwe_secret_key,
-                        pbs_params.pbs_base_log,
-                        pbs_params.pbs_level,
-                        pbs_params.glwe_modular_std_dev,
-                        pbs_params.ciphertext_modulus,
-                        &mut self.encryption_generator,
-                    );
-
-                // Creation of the bootstrapping key in the Fourier domain
-                let mut fourier_bsk = FourierLweBootstrapKey::new(
-                    bootstrap_key.input_lwe_dimension(),
-                    bootstrap_key.glwe_size(),
-                    bootstrap_key.polynomial_size(),
-                    bootstrap_key.decomposition_base_log(),
-                    bootstrap_key.decomposition_level_count(),
+                let mut bootstrap_key = LweBootstrapKeyOwned::new(
+                    0u64,
+                    pbs_params.glwe_dimension.to_glwe_size(),
+                    pbs_params.polynomial_size,
+                    pbs_params.pbs_base_log,
+                    pbs_params.pbs_level,
+                    pbs_params.lwe_dimension,
+                    pbs_params.ciphertext_modulus,
                 );
 
-                // Conversion to fourier domain
-                par_convert_standard_lwe_bootstrap_key_to_fourier(&bootstrap_key, &mut fourier_bsk);
+                if par_generate_lwe_bootstrap_key_with_progress(
+                    &cks.small_lwe_secret_key,
+                    &cks.glwe_secret_key,
+                    &mut bootstrap_key,
+                    pbs_params.glwe_modular_std_dev,
+                    &mut self.encryption_generator,
+                    on_ggsw_encrypted,
+                )
+                .is_break()
+                {
+                    return Ok(None);
+                }
 
-                ShortintBootstrappingKey::Classic(fourier_bsk)
+                Self::convert_classic_bootstrapping_key(&bootstrap_key, pbs_backend)
+            }
+            crate::shortint::PBSParameters::MultiBitPBS(_) if pbs_backend == PbsBackend::Ntt => {
+                return Err(ServerKeyCreationError::UnsupportedDeterministicMultiBit.into());
             }
             crate::shortint::PBSParameters::MultiBitPBS(pbs_params) => {
-                let bootstrap_key: LweMultiBitBootstrapKeyOwned<u64> =
-                    par_allocate_and_generate_new_lwe_multi_bit_bootstrap_key(
-                        &cks.small_lwe_secret_key,
-                        &cks.glwe_secret_key,
-                        pbs_params.pbs_base_log,
-                        pbs_params.pbs_level,
-                        pbs_params.grouping_factor,
-                        pbs_params.glwe_modular_std_dev,
-                        pbs_params.ciphertext_modulus,
-                        &mut self.encryption_generator,
-                    );
+                let mut bootstrap_key = LweMultiBitBootstrapKeyOwned::new(
+                    0u64,
+                    pbs_params.glwe_dimension.to_glwe_size(),
+                    pbs_params.polynomial_size,
+                    pbs_params.pbs_base_log,
+                    pbs_params.pbs_level,
+                    pbs_params.lwe_dimension,
+                    pbs_params.grouping_factor,
+                    pbs_params.ciphertext_modulus,
+                );
+
+                if par_generate_lwe_multi_bit_bootstrap_key_with_progress(
+                    &cks.small_lwe_secret_key,
+                    &cks.glwe_secret_key,
+                    &mut bootstrap_key,
+                    pbs_params.glwe_modular_std_dev,
+                    &mut self.encryption_generator,
+                    on_ggsw_encrypted,
+                )
+                .is_break()
+                {
+                    return Ok(None);
+                }
 
                 // Creation of the bootstrapping key in the Fourier domain
                 let mut fourier_bsk = FourierLweMultiBitBootstrapKey::new(
@@ -161,15 +288,17 @@ impl ShortintEngine {
         );
 
         // Pack the keys in the server key set:
-        Ok(ServerKey {
+        Ok(Some(ServerKey {
             key_switching_key,
             bootstrapping_key: bootstrapping_key_base,
             message_modulus: cks.parameters.message_modulus(),
             carry_modulus: cks.parameters.carry_modulus(),
             max_degree,
+            max_noise_level: cks.parameters.max_noise_level(),
+            max_noise_level_policy: MaxNoiseLevelPolicy::default(),
             ciphertext_modulus: cks.parameters.ciphertext_modulus(),
             pbs_order: cks.parameters.encryption_key_choice().into(),
-        })
+        }))
     }
 
     pub(crate) fn new_key_switching_key(
@@ -190,6 +319,24 @@ impl ShortintEngine {
         ))
     }
 
+    pub(crate) fn new_seeded_key_switching_key(
+        &mut self,
+        cks1: &ClientKey,
+        cks2: &ClientKey,
+        params: ShortintKeySwitchingParameters,
+    ) -> EngineResult<SeededLweKeyswitchKeyOwned<u64>> {
+        // Creation of the key switching key
+        Ok(allocate_and_generate_new_seeded_lwe_keyswitch_key(
+            &cks1.large_lwe_secret_key,
+            &cks2.large_lwe_secret_key,
+            params.ks_base_log,
+            params.ks_level,
+            cks2.parameters.lwe_modular_std_dev(),
+            cks2.parameters.ciphertext_modulus(),
+            &mut self.seeder,
+        ))
+    }
+
     pub(crate) fn new_compressed_server_key(
         &mut self,
         cks: &ClientKey,
@@ -202,6 +349,17 @@ impl ShortintEngine {
         self.new_compressed_server_key_with_max_degree(cks, max)
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "debug",
+            skip_all,
+            fields(
+                message_modulus = cks.parameters.message_modulus().0,
+                carry_modulus = cks.parameters.carry_modulus().0,
+            )
+        )
+    )]
     pub(crate) fn new_compressed_server_key_with_max_degree(
         &mut self,
         cks: &ClientKey,
@@ -285,6 +443,7 @@ impl ShortintEngine {
             message_modulus: cks.parameters.message_modulus(),
             carry_modulus: cks.parameters.carry_modulus(),
             max_degree,
+            max_noise_level: cks.parameters.max_noise_level(),
             ciphertext_modulus: cks.parameters.ciphertext_modulus(),
             pbs_order: cks.parameters.encryption_key_choice().into(),
         })
@@ -301,73 +460,139 @@ impl ShortintEngine {
         Self::generate_lookup_table_with_engine(server_key, f)
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "trace",
+            skip_all,
+            fields(
+                lwe_dimension = ct.ct.lwe_size().to_lwe_dimension().0,
+                polynomial_size = server_key.bootstrapping_key.polynomial_size().0,
+            )
+        )
+    )]
     pub(crate) fn keyswitch_programmable_bootstrap_assign(
         &mut self,
         server_key: &ServerKey,
         ct: &mut Ciphertext,
         acc: &LookupTableOwned,
+    ) -> EngineResult<()> {
+        self.keyswitch_programmable_bootstrap_slice_assign(
+            server_key,
+            std::slice::from_mut(ct),
+            acc,
+        )
+    }
+
+    /// Keyswitches then bootstraps each ciphertext of `cts` with the same lookup table, the
+    /// buffers and the FFT plan being prepared once for all of them
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "trace",
+            skip_all,
+            fields(
+                num_ciphertexts = cts.len(),
+                polynomial_size = server_key.bootstrapping_key.polynomial_size().0,
+            )
+        )
+    )]
+    pub(crate) fn keyswitch_programmable_bootstrap_slice_assign(
+        &mut self,
+        server_key: &ServerKey,
+        cts: &mut [Ciphertext],
+        acc: &LookupTableOwned,
     ) -> EngineResult<()> {
         // Compute the programmable bootstrapping with fixed test polynomial
         let (mut ciphertext_buffers, buffers) = self.get_buffers(server_key);
 
-        // Compute a key switch
-        keyswitch_lwe_ciphertext(
-            &server_key.key_switching_key,
-            &ct.ct,
-            &mut ciphertext_buffers.buffer_lwe_after_ks,
-        );
+        check_deterministic_pbs(&server_key.bootstrapping_key);
 
+        let fft = Fft::new(server_key.bootstrapping_key.polynomial_size());
+        let fft = fft.as_view();
         match &server_key.bootstrapping_key {
-            ShortintBootstrappingKey::Classic(fourier_bsk) => {
-                let fft = Fft::new(fourier_bsk.polynomial_size());
-                let fft = fft.as_view();
-                buffers.resize(
-                    programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement::<u64>(
-                        fourier_bsk.glwe_size(),
-                        fourier_bsk.polynomial_size(),
-                        fft,
-                    )
-                    .unwrap()
-                    .unaligned_bytes_required(),
-                );
-                let stack = buffers.stack();
-
-                // Compute a bootstrap
-                programmable_bootstrap_lwe_ciphertext_mem_optimized(
-                    &ciphertext_buffers.buffer_lwe_after_ks,
-                    &mut ct.ct,
-                    &acc.acc,
-                    fourier_bsk,
+            ShortintBootstrappingKey::Classic(fourier_bsk) => buffers.resize(
+                programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement::<u64>(
+                    fourier_bsk.glwe_size(),
+                    fourier_bsk.polynomial_size(),
                     fft,
-                    stack,
-                );
-            }
-            ShortintBootstrappingKey::MultiBit {
-                fourier_bsk,
-                thread_count,
-                deterministic_execution,
-            } => {
-                if *deterministic_execution {
-                    multi_bit_deterministic_programmable_bootstrap_lwe_ciphertext(
+                )
+                .unwrap()
+                .unaligned_bytes_required(),
+            ),
+            ShortintBootstrappingKey::Ntt(ntt_bsk) => buffers.resize(
+                programmable_bootstrap_ntt64_lwe_ciphertext_mem_optimized_requirement(
+                    ntt_bsk.glwe_size(),
+                    ntt_bsk.polynomial_size(),
+                )
+                .unwrap()
+                .unaligned_bytes_required(),
+            ),
+            ShortintBootstrappingKey::MultiBit { .. } => (),
+        }
+
+        for ct in cts.iter_mut() {
+            count_programmable_bootstrap();
+
+            // Compute a key switch
+            keyswitch_lwe_ciphertext(
+                &server_key.key_switching_key,
+                &ct.ct,
+                &mut ciphertext_buffers.buffer_lwe_after_ks,
+            );
+
+            match &server_key.bootstrapping_key {
+                ShortintBootstrappingKey::Classic(fourier_bsk) => {
+                    // Compute a bootstrap
+                    programmable_bootstrap_lwe_ciphertext_mem_optimized(
                         &ciphertext_buffers.buffer_lwe_after_ks,
                         &mut ct.ct,
                         &acc.acc,
                         fourier_bsk,
-                        *thread_count,
+                        fft,
+                        buffers.stack(),
                     );
-                } else {
-                    multi_bit_programmable_bootstrap_lwe_ciphertext(
+                }
+                ShortintBootstrappingKey::Ntt(ntt_bsk) => {
+                    let ntt = Ntt::new(ntt_bsk.polynomial_size());
+
+                    programmable_bootstrap_ntt64_lwe_ciphertext_mem_optimized(
                         &ciphertext_buffers.buffer_lwe_after_ks,
                         &mut ct.ct,
                         &acc.acc,
-                        fourier_bsk,
-                        *thread_count,
+                        ntt_bsk,
+                        ntt.as_view(),
+                        buffers.stack(),
                     );
                 }
-            }
-        };
+                ShortintBootstrappingKey::MultiBit {
+                    fourier_bsk,
+                    thread_count,
+                    deterministic_execution,
+                } => {
+                    if *deterministic_execution {
+                        multi_bit_deterministic_programmable_bootstrap_lwe_ciphertext(
+                            &ciphertext_buffers.buffer_lwe_after_ks,
+                            &mut ct.ct,
+                            &acc.acc,
+                            fourier_bsk,
+                            *thread_count,
+                        );
+                    } else {
+                        multi_bit_programmable_bootstrap_lwe_ciphertext(
+                            &ciphertext_buffers.buffer_lwe_after_ks,
+                            &mut ct.ct,
+                            &acc.acc,
+                            fourier_bsk,
+                            *thread_count,
+                        );
+                    }
+                }
+            };
 
-        ct.degree = acc.degree;
+            ct.degree = acc.degree;
+            ct.noise_level = NoiseLevel::NOMINAL;
+        }
 
         Ok(())
     }
@@ -568,72 +793,258 @@ impl ShortintEngine {
         self.unchecked_apply_lookup_table_bivariate_assign(server_key, ct_left, ct_right, acc)
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "trace",
+            skip_all,
+            fields(
+                lwe_dimension = ct.ct.lwe_size().to_lwe_dimension().0,
+                polynomial_size = server_key.bootstrapping_key.polynomial_size().0,
+            )
+        )
+    )]
     pub(crate) fn programmable_bootstrap_keyswitch_assign(
         &mut self,
         server_key: &ServerKey,
         ct: &mut Ciphertext,
         acc: &LookupTableOwned,
+    ) -> EngineResult<()> {
+        self.programmable_bootstrap_keyswitch_slice_assign(
+            server_key,
+            std::slice::from_mut(ct),
+            acc,
+        )
+    }
+
+    /// Bootstraps then keyswitches each ciphertext of `cts` with the same lookup table, the
+    /// buffers and the FFT plan being prepared once for all of them
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            level = "trace",
+            skip_all,
+            fields(
+                num_ciphertexts = cts.len(),
+                polynomial_size = server_key.bootstrapping_key.polynomial_size().0,
+            )
+        )
+    )]
+    pub(crate) fn programmable_bootstrap_keyswitch_slice_assign(
+        &mut self,
+        server_key: &ServerKey,
+        cts: &mut [Ciphertext],
+        acc: &LookupTableOwned,
     ) -> EngineResult<()> {
         let (mut ciphertext_buffers, buffers) = self.get_buffers(server_key);
 
-        match &server_key.bootstrapping_key {
-            ShortintBootstrappingKey::Classic(fourier_bsk) => {
-                let fft = Fft::new(fourier_bsk.polynomial_size());
-                let fft = fft.as_view();
-                buffers.resize(
-                    programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement::<u64>(
-                        fourier_bsk.glwe_size(),
-                        fourier_bsk.polynomial_size(),
-                        fft,
-                    )
-                    .unwrap()
-                    .unaligned_bytes_required(),
-                );
-                let stack = buffers.stack();
+        check_deterministic_pbs(&server_key.bootstrapping_key);
 
-                // Compute a bootstrap
-                programmable_bootstrap_lwe_ciphertext_mem_optimized(
-                    &ct.ct,
-                    &mut ciphertext_buffers.buffer_lwe_after_pbs,
-                    &acc.acc,
-                    fourier_bsk,
+        let fft = Fft::new(server_key.bootstrapping_key.polynomial_size());
+        let fft = fft.as_view();
+        match &server_key.bootstrapping_key {
+            ShortintBootstrappingKey::Classic(fourier_bsk) => buffers.resize(
+                programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement::<u64>(
+                    fourier_bsk.glwe_size(),
+                    fourier_bsk.polynomial_size(),
                     fft,
-                    stack,
-                );
-            }
-            ShortintBootstrappingKey::MultiBit {
-                fourier_bsk,
-                thread_count,
-                deterministic_execution,
-            } => {
-                if *deterministic_execution {
-                    multi_bit_deterministic_programmable_bootstrap_lwe_ciphertext(
+                )
+                .unwrap()
+                .unaligned_bytes_required(),
+            ),
+            ShortintBootstrappingKey::Ntt(ntt_bsk) => buffers.resize(
+                programmable_bootstrap_ntt64_lwe_ciphertext_mem_optimized_requirement(
+                    ntt_bsk.glwe_size(),
+                    ntt_bsk.polynomial_size(),
+                )
+                .unwrap()
+                .unaligned_bytes_required(),
+            ),
+            ShortintBootstrappingKey::MultiBit { .. } => (),
+        }
+
+        for ct in cts.iter_mut() {
+            count_programmable_bootstrap();
+
+            match &server_key.bootstrapping_key {
+                ShortintBootstrappingKey::Classic(fourier_bsk) => {
+                    // Compute a bootstrap
+                    programmable_bootstrap_lwe_ciphertext_mem_optimized(
                         &ct.ct,
                         &mut ciphertext_buffers.buffer_lwe_after_pbs,
                         &acc.acc,
                         fourier_bsk,
-                        *thread_count,
+                        fft,
+                        buffers.stack(),
                     );
-                } else {
-                    multi_bit_programmable_bootstrap_lwe_ciphertext(
+                }
+                ShortintBootstrappingKey::Ntt(ntt_bsk) => {
+                    let ntt = Ntt::new(ntt_bsk.polynomial_size());
+
+                    programmable_bootstrap_ntt64_lwe_ciphertext_mem_optimized(
                         &ct.ct,
                         &mut ciphertext_buffers.buffer_lwe_after_pbs,
                         &acc.acc,
-                        fourier_bsk,
-                        *thread_count,
+                        ntt_bsk,
+                        ntt.as_view(),
+                        buffers.stack(),
                     );
                 }
+                ShortintBootstrappingKey::MultiBit {
+                    fourier_bsk,
+                    thread_count,
+                    deterministic_execution,
+                } => {
+                    if *deterministic_execution {
+                        multi_bit_deterministic_programmable_bootstrap_lwe_ciphertext(
+                            &ct.ct,
+                            &mut ciphertext_buffers.buffer_lwe_after_pbs,
+                            &acc.acc,
+                            fourier_bsk,
+                            *thread_count,
+                        );
+                    } else {
+                        multi_bit_programmable_bootstrap_lwe_ciphertext(
+                            &ct.ct,
+                            &mut ciphertext_buffers.buffer_lwe_after_pbs,
+                            &acc.acc,
+                            fourier_bsk,
+                            *thread_count,
+                        );
+                    }
+                }
+            };
+
+            // Compute a key switch
+            keyswitch_lwe_ciphertext(
+                &server_key.key_switching_key,
+                &ciphertext_buffers.buffer_lwe_after_pbs,
+                &mut ct.ct,
+            );
+
+            ct.degree = acc.degree;
+            ct.noise_level = NoiseLevel::NOMINAL;
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn convert_pbs_order_assign(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &mut Ciphertext,
+        pbs_order: PBSOrder,
+    ) -> EngineResult<()> {
+        if ct.pbs_order == pbs_order {
+            return Ok(());
+        }
+
+        match pbs_order {
+            PBSOrder::BootstrapKeyswitch => {
+                // The ciphertext is under the big key, a key switch brings it under the small key
+                let mut ct_small = LweCiphertext::new(
+                    0,
+                    server_key.key_switching_key.output_lwe_size(),
+                    server_key.ciphertext_modulus,
+                );
+                keyswitch_lwe_ciphertext(&server_key.key_switching_key, &ct.ct, &mut ct_small);
+                ct.ct = ct_small;
+                // The key switch adds its own noise on top of the one of the input
+                ct.noise_level += NoiseLevel::NOMINAL;
             }
-        };
+            PBSOrder::KeyswitchBootstrap => {
+                // The ciphertext is under the small key, a bootstrap with the identity brings it
+                // under the big key
+                let acc = self.generate_lookup_table(server_key, |x| x)?;
+                let mut ct_big = LweCiphertext::new(
+                    0,
+                    server_key
+                        .bootstrapping_key
+                        .output_lwe_dimension()
+                        .to_lwe_size(),
+                    server_key.ciphertext_modulus,
+                );
 
-        // Compute a key switch
-        keyswitch_lwe_ciphertext(
-            &server_key.key_switching_key,
-            &ciphertext_buffers.buffer_lwe_after_pbs,
-            &mut ct.ct,
-        );
+                check_deterministic_pbs(&server_key.bootstrapping_key);
+
+                match &server_key.bootstrapping_key {
+                    ShortintBootstrappingKey::Classic(fourier_bsk) => {
+                        let fft = Fft::new(fourier_bsk.polynomial_size());
+                        let fft = fft.as_view();
+                        self.computation_buffers.resize(
+                            programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement::<u64>(
+                                fourier_bsk.glwe_size(),
+                                fourier_bsk.polynomial_size(),
+                                fft,
+                            )
+                            .unwrap()
+                            .unaligned_bytes_required(),
+                        );
+                        let stack = self.computation_buffers.stack();
+
+                        programmable_bootstrap_lwe_ciphertext_mem_optimized(
+                            &ct.ct,
+                            &mut ct_big,
+                            &acc.acc,
+                            fourier_bsk,
+                            fft,
+                            stack,
+                        );
+                    }
+                    ShortintBootstrappingKey::Ntt(ntt_bsk) => {
+                        let ntt = Ntt::new(ntt_bsk.polynomial_size());
+                        let ntt = ntt.as_view();
+                        self.computation_buffers.resize(
+                            programmable_bootstrap_ntt64_lwe_ciphertext_mem_optimized_requirement(
+                                ntt_bsk.glwe_size(),
+                                ntt_bsk.polynomial_size(),
+                            )
+                            .unwrap()
+                            .unaligned_bytes_required(),
+                        );
+                        let stack = self.computation_buffers.stack();
+
+                        programmable_bootstrap_ntt64_lwe_ciphertext_mem_optimized(
+                            &ct.ct,
+                            &mut ct_big,
+                            &acc.acc,
+                            ntt_bsk,
+                            ntt,
+                            stack,
+                        );
+                    }
+                    ShortintBootstrappingKey::MultiBit {
+                        fourier_bsk,
+                        thread_count,
+                        deterministic_execution,
+                    } => {
+                        if *deterministic_execution {
+                            multi_bit_deterministic_programmable_bootstrap_lwe_ciphertext(
+                                &ct.ct,
+                                &mut ct_big,
+                                &acc.acc,
+                                fourier_bsk,
+                                *thread_count,
+                            );
+                        } else {
+                            multi_bit_programmable_bootstrap_lwe_ciphertext(
+                                &ct.ct,
+                                &mut ct_big,
+                                &acc.acc,
+                                fourier_bsk,
+                                *thread_count,
+                            );
+                        }
+                    }
+                };
+
+                // The identity keeps the degree of the input
+                ct.ct = ct_big;
+                ct.noise_level = NoiseLevel::NOMINAL;
+            }
+        }
 
-        ct.degree = acc.degree;
+        ct.pbs_order = pbs_order;
 
         Ok(())
     }
@@ -643,6 +1054,22 @@ impl ShortintEngine {
         server_key: &ServerKey,
         ct: &mut Ciphertext,
         acc: &LookupTableOwned,
+    ) -> EngineResult<()> {
+        if is_no_op_lookup_table(server_key, ct, acc) {
+            count_skipped_programmable_bootstrap();
+            return Ok(());
+        }
+
+        self.force_apply_lookup_table_assign(server_key, ct, acc)
+    }
+
+    /// Same as [Self::apply_lookup_table_assign], but the programmable bootstrap is never skipped,
+    /// for the callers which cannot trust the metadata of the input
+    pub(crate) fn force_apply_lookup_table_assign(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &mut Ciphertext,
+        acc: &LookupTableOwned,
     ) -> EngineResult<()> {
         match server_key.pbs_order {
             PBSOrder::KeyswitchBootstrap => {
@@ -658,6 +1085,26 @@ impl ShortintEngine {
         Ok(())
     }
 
+    pub(crate) fn apply_lookup_table_slice_assign(
+        &mut self,
+        server_key: &ServerKey,
+        cts: &mut [Ciphertext],
+        acc: &LookupTableOwned,
+    ) -> EngineResult<()> {
+        match server_key.pbs_order {
+            PBSOrder::KeyswitchBootstrap => {
+                // This updates the ciphertexts degree
+                self.keyswitch_programmable_bootstrap_slice_assign(server_key, cts, acc)?;
+            }
+            PBSOrder::BootstrapKeyswitch => {
+                // This updates the ciphertexts degree
+                self.programmable_bootstrap_keyswitch_slice_assign(server_key, cts, acc)?;
+            }
+        };
+
+        Ok(())
+    }
+
     pub(crate) fn apply_lookup_table(
         &mut self,
         server_key: &ServerKey,
@@ -671,6 +1118,269 @@ impl ShortintEngine {
         Ok(ct_res)
     }
 
+    pub(crate) fn generate_many_lookup_table(
+        &mut self,
+        server_key: &ServerKey,
+        functions: &[&dyn Fn(u64) -> u64],
+    ) -> EngineResult<ManyLutAccumulator> {
+        let modulus_sup = server_key.message_modulus.0 * server_key.carry_modulus.0;
+        let function_count = functions.len();
+        assert!(
+            function_count > 0 && function_count <= modulus_sup,
+            "Expected between 1 and {modulus_sup} functions, got {function_count}"
+        );
+
+        // Each function gets the same number of consecutive boxes, one per input value
+        let input_modulus = modulus_sup / function_count;
+        let box_size = server_key.bootstrapping_key.polynomial_size().0 / modulus_sup;
+
+        let mut acc = GlweCiphertext::new(
+            0,
+            server_key.bootstrapping_key.glwe_size(),
+            server_key.bootstrapping_key.polynomial_size(),
+            server_key.ciphertext_modulus,
+        );
+        fill_accumulator(&mut acc, server_key, |value| {
+            let function_index = value as usize / input_modulus;
+            let input = value % input_modulus as u64;
+            functions
+                .get(function_index)
+                .map_or(0, |function| function(input))
+        });
+
+        let per_function_output_degree = functions
+            .iter()
+            .map(|function| {
+                let max_value = (0..input_modulus as u64).map(function).max().unwrap();
+                Degree(max_value as usize)
+            })
+            .collect();
+
+        Ok(ManyLutAccumulator {
+            acc,
+            input_max_degree: Degree(input_modulus - 1),
+            sample_extraction_stride: input_modulus * box_size,
+            per_function_output_degree,
+        })
+    }
+
+    pub(crate) fn generate_many_lookup_table_from_lookup_tables(
+        &mut self,
+        server_key: &ServerKey,
+        luts: &[LookupTableOwned],
+    ) -> EngineResult<ManyLutAccumulator> {
+        let modulus_sup = server_key.message_modulus.0 * server_key.carry_modulus.0;
+        let function_count = luts.len();
+        assert!(
+            function_count > 0 && function_count <= modulus_sup,
+            "Expected between 1 and {modulus_sup} lookup tables, got {function_count}"
+        );
+
+        let input_modulus = modulus_sup / function_count;
+        let polynomial_size = server_key.bootstrapping_key.polynomial_size();
+        let box_size = polynomial_size.0 / modulus_sup;
+        let half_box_size = box_size / 2;
+        let function_boxes_size = input_modulus * box_size;
+
+        let mut acc = GlweCiphertext::new(
+            0,
+            server_key.bootstrapping_key.glwe_size(),
+            polynomial_size,
+            server_key.ciphertext_modulus,
+        );
+        let mut body = acc.get_mut_body();
+        let accumulator_u64 = body.as_mut();
+
+        let mut lut_body = vec![0u64; polynomial_size.0];
+        for (function_index, lut) in luts.iter().enumerate() {
+            assert_eq!(lut.acc.polynomial_size(), polynomial_size);
+
+            // Undo the rotation of the lookup table, so the boxes of the first inputs are the
+            // first coefficients, and copy them at the place of the function
+            lut_body.copy_from_slice(lut.acc.get_body().as_ref());
+            lut_body.rotate_right(half_box_size);
+            for a_i in lut_body[0..half_box_size].iter_mut() {
+                *a_i = (*a_i).wrapping_neg();
+            }
+
+            let start = function_index * function_boxes_size;
+            accumulator_u64[start..start + function_boxes_size]
+                .copy_from_slice(&lut_body[..function_boxes_size]);
+        }
+
+        // Same rotation as the one done when filling an accumulator
+        for a_i in accumulator_u64[0..half_box_size].iter_mut() {
+            *a_i = (*a_i).wrapping_neg();
+        }
+        accumulator_u64.rotate_left(half_box_size);
+
+        Ok(ManyLutAccumulator {
+            acc,
+            input_max_degree: Degree(input_modulus - 1),
+            sample_extraction_stride: function_boxes_size,
+            per_function_output_degree: luts.iter().map(|lut| lut.degree).collect(),
+        })
+    }
+
+    pub(crate) fn apply_many_lookup_tables(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &Ciphertext,
+        luts: &[LookupTableOwned],
+    ) -> EngineResult<Vec<Ciphertext>> {
+        let acc = self.generate_many_lookup_table_from_lookup_tables(server_key, luts)?;
+        self.apply_lookup_table_multi_output(server_key, ct, &acc)
+    }
+
+    pub(crate) fn apply_lookup_table_multi_output(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &Ciphertext,
+        acc: &ManyLutAccumulator,
+    ) -> EngineResult<Vec<Ciphertext>> {
+        assert!(
+            ct.degree.0 <= acc.input_max_degree.0,
+            "The input degree ({}) exceeds the input space of the lookup table (max degree {})",
+            ct.degree.0,
+            acc.input_max_degree.0
+        );
+
+        count_programmable_bootstrap();
+        check_deterministic_pbs(&server_key.bootstrapping_key);
+
+        let (mut ciphertext_buffers, buffers) = self.get_buffers(server_key);
+
+        let input = match server_key.pbs_order {
+            PBSOrder::KeyswitchBootstrap => {
+                keyswitch_lwe_ciphertext(
+                    &server_key.key_switching_key,
+                    &ct.ct,
+                    &mut ciphertext_buffers.buffer_lwe_after_ks,
+                );
+                ciphertext_buffers.buffer_lwe_after_ks.as_view()
+            }
+            PBSOrder::BootstrapKeyswitch => ct.ct.as_view(),
+        };
+
+        // All the functions are evaluated by a single blind rotation
+        let mut rotated_acc = acc.acc.clone();
+        match &server_key.bootstrapping_key {
+            ShortintBootstrappingKey::Classic(fourier_bsk) => {
+                let fft = Fft::new(fourier_bsk.polynomial_size());
+                let fft = fft.as_view();
+                buffers.resize(
+                    blind_rotate_assign_mem_optimized_requirement::<u64>(
+                        fourier_bsk.glwe_size(),
+                        fourier_bsk.polynomial_size(),
+                        fft,
+                    )
+                    .unwrap()
+                    .unaligned_bytes_required(),
+                );
+                let stack = buffers.stack();
+
+                blind_rotate_assign_mem_optimized(
+                    &input,
+                    &mut rotated_acc,
+                    fourier_bsk,
+                    fft,
+                    stack,
+                );
+            }
+            ShortintBootstrappingKey::Ntt(ntt_bsk) => {
+                let ntt = Ntt::new(ntt_bsk.polynomial_size());
+                let ntt = ntt.as_view();
+                buffers.resize(
+                    blind_rotate_ntt64_assign_mem_optimized_requirement(
+                        ntt_bsk.glwe_size(),
+                        ntt_bsk.polynomial_size(),
+                    )
+                    .unwrap()
+                    .unaligned_bytes_required(),
+                );
+                let stack = buffers.stack();
+
+                blind_rotate_ntt64_assign_mem_optimized(
+                    &input,
+                    &mut rotated_acc,
+                    ntt_bsk,
+                    ntt,
+                    stack,
+                );
+            }
+            ShortintBootstrappingKey::MultiBit {
+                fourier_bsk,
+                thread_count,
+                deterministic_execution,
+            } => {
+                if *deterministic_execution {
+                    multi_bit_deterministic_blind_rotate_assign(
+                        &input,
+                        &mut rotated_acc,
+                        fourier_bsk,
+                        *thread_count,
+                    );
+                } else {
+                    multi_bit_blind_rotate_assign(
+                        &input,
+                        &mut rotated_acc,
+                        fourier_bsk,
+                        *thread_count,
+                    );
+                }
+            }
+        };
+
+        let outputs = acc
+            .per_function_output_degree
+            .iter()
+            .enumerate()
+            .map(|(function_index, degree)| {
+                let mut ct_after_pbs = LweCiphertext::new(
+                    0,
+                    server_key
+                        .bootstrapping_key
+                        .output_lwe_dimension()
+                        .to_lwe_size(),
+                    server_key.ciphertext_modulus,
+                );
+                extract_lwe_sample_from_glwe_ciphertext(
+                    &rotated_acc,
+                    &mut ct_after_pbs,
+                    MonomialDegree(function_index * acc.sample_extraction_stride),
+                );
+
+                let output = match server_key.pbs_order {
+                    PBSOrder::KeyswitchBootstrap => ct_after_pbs,
+                    PBSOrder::BootstrapKeyswitch => {
+                        let mut ct_after_ks = LweCiphertext::new(
+                            0,
+                            server_key.key_switching_key.output_lwe_size(),
+                            server_key.ciphertext_modulus,
+                        );
+                        keyswitch_lwe_ciphertext(
+                            &server_key.key_switching_key,
+                            &ct_after_pbs,
+                            &mut ct_after_ks,
+                        );
+                        ct_after_ks
+                    }
+                };
+
+                Ciphertext {
+                    ct: output,
+                    degree: *degree,
+                    noise_level: NoiseLevel::NOMINAL,
+                    message_modulus: ct.message_modulus,
+                    carry_modulus: ct.carry_modulus,
+                    pbs_order: ct.pbs_order,
+                }
+            })
+            .collect();
+
+        Ok(outputs)
+    }
+
     pub(crate) fn carry_extract_assign(
         &mut self,
         server_key: &ServerKey,
@@ -719,6 +1429,41 @@ impl ShortintEngine {
         Ok(result)
     }
 
+    pub(crate) fn sanitize_assign(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &mut Ciphertext,
+    ) -> EngineResult<()> {
+        // The metadata of the input cannot be trusted, the lookup table is built from the moduli
+        // of the key
+        ct.message_modulus = server_key.message_modulus;
+        ct.carry_modulus = server_key.carry_modulus;
+        ct.pbs_order = server_key.pbs_order;
+
+        // The padding bit of the input may be set, the PBS would then compute the opposite of the
+        // identity. A first PBS, with a constant lookup table, gives a quarter of the torus when
+        // the padding bit is not set, and its opposite when it is: the difference with a quarter
+        // is half of the torus exactly when the padding bit is set, it is subtracted to clear it.
+        let full_modulus = (server_key.message_modulus.0 * server_key.carry_modulus.0) as u64;
+        let delta = (1_u64 << 63) / full_modulus;
+        let quarter = Plaintext(delta * (full_modulus / 2));
+
+        let padding_acc = self.generate_lookup_table(server_key, |_| full_modulus / 2)?;
+        let mut padding = ct.clone();
+        self.force_apply_lookup_table_assign(server_key, &mut padding, &padding_acc)?;
+        lwe_ciphertext_sub_assign(&mut ct.ct, &padding.ct);
+        lwe_ciphertext_plaintext_add_assign(&mut ct.ct, quarter);
+
+        let modulus = server_key.message_modulus.0 as u64;
+
+        let acc = self.generate_lookup_table(server_key, |x| x % modulus)?;
+
+        // This sets the degree and the noise level of a fresh ciphertext
+        self.force_apply_lookup_table_assign(server_key, ct, &acc)?;
+
+        Ok(())
+    }
+
     // Impossible to call the assign function in this case
     pub(crate) fn create_trivial(
         &mut self,
@@ -757,6 +1502,7 @@ impl ShortintEngine {
         Ok(Ciphertext {
             ct,
             degree,
+            noise_level: NoiseLevel::ZERO,
             message_modulus: server_key.message_modulus,
             carry_modulus: server_key.carry_modulus,
             pbs_order: server_key.pbs_order,
@@ -781,6 +1527,7 @@ impl ShortintEngine {
         trivially_encrypt_lwe_ciphertext(&mut ct.ct, encoded);
 
         ct.degree = Degree(modular_value);
+        ct.noise_level = NoiseLevel::ZERO;
         Ok(())
     }
 }
@@ -1,9 +1,68 @@
 use crate::core_crypto::algorithms::*;
 use crate::core_crypto::entities::*;
-use crate::shortint::ciphertext::Degree;
+use crate::shortint::ciphertext::{Degree, NoiseLevel};
 use crate::shortint::engine::{EngineResult, ShortintEngine};
 use crate::shortint::{Ciphertext, ServerKey};
 
+/// How [ShortintEngine::scalar_mul_refreshed_assign] computes `ct * scalar`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum ScalarMulSchedule {
+    /// `ct * scalar`, a single cleartext multiplication
+    Direct,
+    /// `refresh(ct * factor) * multiplier + ct * remainder`, where the refresh is an identity PBS
+    /// bringing the noise back to the nominal level
+    Refreshed {
+        factor: u8,
+        multiplier: u8,
+        remainder: u8,
+    },
+}
+
+impl ScalarMulSchedule {
+    /// Returns the schedule giving the smallest output noise level, with at most one refresh
+    ///
+    /// The noise of `ct * scalar` is `scalar` times the noise of `ct` whatever the sequence of
+    /// doublings and additions used to compute it, only a PBS can reduce it. So the direct
+    /// multiplication is kept as long as its noise stays below the maximum noise level, and a
+    /// refresh is only inserted otherwise.
+    pub(crate) fn new(server_key: &ServerKey, ct: &Ciphertext, scalar: u8) -> Self {
+        let noise_level = ct.noise_level.get();
+        let max_noise_level = server_key.max_noise_level.get();
+
+        let mut best_noise_level = noise_level * scalar as usize;
+        let mut best_schedule = Self::Direct;
+        if best_noise_level <= max_noise_level {
+            return best_schedule;
+        }
+
+        for factor in 1..=scalar {
+            // The refreshed value must be decryptable by the PBS: its noise must be below the
+            // maximum and it must not reach the padding bit
+            if noise_level * factor as usize > max_noise_level
+                || ct.degree.0 * factor as usize > server_key.max_degree.0
+            {
+                break;
+            }
+
+            let multiplier = scalar / factor;
+            let remainder = scalar % factor;
+            let output_noise_level =
+                multiplier as usize * NoiseLevel::NOMINAL.get() + remainder as usize * noise_level;
+
+            if output_noise_level < best_noise_level {
+                best_noise_level = output_noise_level;
+                best_schedule = Self::Refreshed {
+                    factor,
+                    multiplier,
+                    remainder,
+                };
+            }
+        }
+
+        best_schedule
+    }
+}
+
 impl ShortintEngine {
     pub(crate) fn unchecked_scalar_mul(
         &mut self,
@@ -25,6 +84,7 @@ impl ShortintEngine {
             0 => {
                 trivially_encrypt_lwe_ciphertext(&mut ct.ct, Plaintext(0));
                 ct.degree = Degree(0);
+                ct.noise_level = NoiseLevel::ZERO;
             }
             1 => {
                 // Multiplication by one is the identidy
@@ -35,6 +95,54 @@ impl ShortintEngine {
                 lwe_ciphertext_cleartext_mul_assign(&mut ct.ct, cleartext_scalar);
 
                 ct.degree = Degree(ct.degree.0 * scalar as usize);
+                ct.noise_level = ct.noise_level * scalar as usize;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn scalar_mul_refreshed(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &Ciphertext,
+        scalar: u8,
+    ) -> EngineResult<Ciphertext> {
+        let mut ct_result = ct.clone();
+        self.scalar_mul_refreshed_assign(server_key, &mut ct_result, scalar)?;
+
+        Ok(ct_result)
+    }
+
+    /// Multiplies by a scalar following [ScalarMulSchedule], so that the output noise level is as
+    /// small as possible with at most one PBS
+    pub(crate) fn scalar_mul_refreshed_assign(
+        &mut self,
+        server_key: &ServerKey,
+        ct: &mut Ciphertext,
+        scalar: u8,
+    ) -> EngineResult<()> {
+        match ScalarMulSchedule::new(server_key, ct, scalar) {
+            ScalarMulSchedule::Direct => self.unchecked_scalar_mul_assign(ct, scalar)?,
+            ScalarMulSchedule::Refreshed {
+                factor,
+                multiplier,
+                remainder,
+            } => {
+                let mut refreshed = self.unchecked_scalar_mul(ct, factor)?;
+                // The identity is applied on the whole plaintext space, so the degree is kept
+                let degree = refreshed.degree;
+                let acc = self.generate_lookup_table(server_key, |x| x)?;
+                self.apply_lookup_table_assign(server_key, &mut refreshed, &acc)?;
+                refreshed.degree = degree;
+
+                self.unchecked_scalar_mul_assign(&mut refreshed, multiplier)?;
+                if remainder == 0 {
+                    *ct = refreshed;
+                } else {
+                    self.unchecked_scalar_mul_assign(ct, remainder)?;
+                    self.unchecked_add_assign(ct, &refreshed)?;
+                }
             }
         }
 
@@ -50,6 +50,7 @@ impl ShortintEngine {
         lwe_ciphertext_add_assign(&mut ct_left.ct, &neg_right.ct);
 
         ct_left.degree = Degree(ct_left.degree.0 + z as usize);
+        ct_left.noise_level += neg_right.noise_level;
 
         Ok(z)
     }
@@ -5,15 +5,18 @@ use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
 use crate::core_crypto::fft_impl::fft64::crypto::bootstrap::FourierLweBootstrapKey;
 use crate::core_crypto::fft_impl::fft64::math::fft::Fft;
-use crate::shortint::ciphertext::Degree;
-use crate::shortint::engine::{EngineResult, ShortintEngine};
-use crate::shortint::server_key::{MaxDegree, ShortintBootstrappingKey};
+use crate::shortint::ciphertext::{Degree, NoiseLevel};
+use crate::shortint::engine::{check_deterministic_pbs, EngineResult, ShortintEngine};
+use crate::shortint::server_key::{
+    MaxDegree, MaxNoiseLevel, MaxNoiseLevelPolicy, ShortintBootstrappingKey,
+};
 use crate::shortint::wopbs::{WopbsKey, WopbsLUTBase};
 use crate::shortint::{Ciphertext, ClientKey, ServerKey, WopbsParameters};
 
 #[derive(Debug)]
 pub enum WopbsKeyCreationError {
     UnsupportedMultiBit,
+    UnsupportedDeterministic,
 }
 
 impl std::error::Error for WopbsKeyCreationError {}
@@ -24,6 +27,12 @@ impl std::fmt::Display for WopbsKeyCreationError {
             WopbsKeyCreationError::UnsupportedMultiBit => {
                 write!(f, "WopbsKey does not yet support using multi bit PBS")
             }
+            WopbsKeyCreationError::UnsupportedDeterministic => {
+                write!(
+                    f,
+                    "WopbsKey does not yet support the deterministic mode of the engines"
+                )
+            }
         }
     }
 }
@@ -41,6 +50,11 @@ impl ShortintEngine {
         ) {
             return Err(WopbsKeyCreationError::UnsupportedMultiBit.into());
         }
+        if Self::is_deterministic()
+            || matches!(sks.bootstrapping_key, ShortintBootstrappingKey::Ntt(_))
+        {
+            return Err(WopbsKeyCreationError::UnsupportedDeterministic.into());
+        }
 
         let wop_params = cks.parameters.wopbs_parameters().unwrap();
 
@@ -73,6 +87,12 @@ impl ShortintEngine {
         sks: &ServerKey,
         parameters: &WopbsParameters,
     ) -> EngineResult<WopbsKey> {
+        if Self::is_deterministic()
+            || matches!(sks.bootstrapping_key, ShortintBootstrappingKey::Ntt(_))
+        {
+            return Err(WopbsKeyCreationError::UnsupportedDeterministic.into());
+        }
+
         //Independent client key generation dedicated to the WoPBS
         let small_lwe_secret_key = allocate_and_generate_new_binary_lwe_secret_key(
             parameters.lwe_dimension,
@@ -161,6 +181,11 @@ impl ShortintEngine {
             message_modulus: parameters.message_modulus,
             carry_modulus: parameters.carry_modulus,
             max_degree: MaxDegree(parameters.message_modulus.0 * parameters.carry_modulus.0 - 1),
+            max_noise_level: MaxNoiseLevel::from_msg_carry_modulus(
+                parameters.message_modulus,
+                parameters.carry_modulus,
+            ),
+            max_noise_level_policy: MaxNoiseLevelPolicy::default(),
             ciphertext_modulus: parameters.ciphertext_modulus,
             pbs_order: cks.parameters.encryption_key_choice().into(),
         };
@@ -173,6 +198,8 @@ impl ShortintEngine {
             max_degree: MaxDegree(
                 cks.parameters.message_modulus().0 * cks.parameters.carry_modulus().0 - 1,
             ),
+            max_noise_level: sks.max_noise_level,
+            max_noise_level_policy: sks.max_noise_level_policy,
             ciphertext_modulus: cks.parameters.ciphertext_modulus(),
             pbs_order: cks.parameters.encryption_key_choice().into(),
         };
@@ -214,7 +241,7 @@ impl ShortintEngine {
             wopbs_key,
             extracted_bit_count,
             &mut output,
-        );
+        )?;
 
         Ok(output)
     }
@@ -226,7 +253,8 @@ impl ShortintEngine {
         wopbs_key: &WopbsKey,
         extracted_bit_count: ExtractedBitsCount,
         output: &mut LweCiphertextList<OutputCont>,
-    ) where
+    ) -> EngineResult<()>
+    where
         OutputCont: ContainerMut<Element = u64>,
     {
         let server_key = &wopbs_key.wopbs_server_key;
@@ -265,9 +293,14 @@ impl ShortintEngine {
                 )
             }
             ShortintBootstrappingKey::MultiBit { .. } => {
-                todo!("extract_bits_assign currently does not support multi-bit PBS")
+                return Err(WopbsKeyCreationError::UnsupportedMultiBit.into());
+            }
+            ShortintBootstrappingKey::Ntt(_) => {
+                return Err(WopbsKeyCreationError::UnsupportedDeterministic.into());
             }
         }
+
+        Ok(())
     }
 
     pub(crate) fn circuit_bootstrap_with_bits<InputCont, LutCont>(
@@ -331,6 +364,9 @@ impl ShortintEngine {
             ShortintBootstrappingKey::MultiBit { .. } => {
                 return Err(WopbsKeyCreationError::UnsupportedMultiBit.into());
             }
+            ShortintBootstrappingKey::Ntt(_) => {
+                return Err(WopbsKeyCreationError::UnsupportedDeterministic.into());
+            }
         };
 
         Ok(output_cbs_vp_ct)
@@ -365,6 +401,7 @@ impl ShortintEngine {
         let ct_out = Ciphertext {
             ct: ciphertext,
             degree: Degree(sks.message_modulus.0 - 1),
+            noise_level: NoiseLevel::WOPBS_OUTPUT,
             message_modulus: sks.message_modulus,
             carry_modulus: sks.carry_modulus,
             pbs_order: ct_in.pbs_order,
@@ -431,6 +468,7 @@ impl ShortintEngine {
         Ok(Ciphertext {
             ct: buffer_lwe_after_ks,
             degree: ct_in.degree,
+            noise_level: NoiseLevel::WOPBS_OUTPUT,
             message_modulus: ct_clean.message_modulus,
             carry_modulus: ct_clean.carry_modulus,
             pbs_order: ct_in.pbs_order,
@@ -456,6 +494,8 @@ impl ShortintEngine {
             &mut ciphertext_buffers.buffer_lwe_after_ks,
         );
 
+        check_deterministic_pbs(&wopbs_key.pbs_server_key.bootstrapping_key);
+
         let ct_out = match &wopbs_key.pbs_server_key.bootstrapping_key {
             ShortintBootstrappingKey::Classic(fourier_bsk) => {
                 let out_lwe_size = fourier_bsk.output_lwe_dimension().to_lwe_size();
@@ -490,11 +530,15 @@ impl ShortintEngine {
             ShortintBootstrappingKey::MultiBit { .. } => {
                 return Err(WopbsKeyCreationError::UnsupportedMultiBit.into());
             }
+            ShortintBootstrappingKey::Ntt(_) => {
+                return Err(WopbsKeyCreationError::UnsupportedDeterministic.into());
+            }
         };
 
         Ok(Ciphertext {
             ct: ct_out,
             degree: ct_in.degree,
+            noise_level: NoiseLevel::NOMINAL,
             message_modulus: ct_in.message_modulus,
             carry_modulus: ct_in.carry_modulus,
             pbs_order: ct_in.pbs_order,
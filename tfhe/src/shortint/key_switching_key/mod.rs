@@ -2,17 +2,37 @@
 //!
 //! - [KeySwitchingKey] allows switching the keys of a ciphertext, from a cleitn key to another.
 
+use crate::shortint::ciphertext::NoiseLevel;
 use crate::shortint::engine::ShortintEngine;
 use crate::shortint::parameters::ShortintKeySwitchingParameters;
-use crate::shortint::{Ciphertext, ClientKey, ServerKey};
+use crate::shortint::{Ciphertext, ClientKey, CompressedServerKey, ServerKey};
 
-use crate::core_crypto::prelude::{keyswitch_lwe_ciphertext, LweKeyswitchKeyOwned};
+use crate::core_crypto::prelude::{
+    keyswitch_lwe_ciphertext, LweKeyswitchKeyOwned, SeededLweKeyswitchKeyOwned,
+};
 
 use serde::{Deserialize, Serialize};
 
 #[cfg(test)]
 mod test;
 
+/// Returns the shift needed to go from the full message modulus of the first key to the full
+/// message modulus of the second one.
+fn cast_rshift(client_key_1: &ClientKey, client_key_2: &ClientKey) -> i8 {
+    let full_message_modulus_1 =
+        client_key_1.parameters.carry_modulus().0 * client_key_1.parameters.message_modulus().0;
+    let full_message_modulus_2 =
+        client_key_2.parameters.carry_modulus().0 * client_key_2.parameters.message_modulus().0;
+    if !full_message_modulus_1.is_power_of_two() || !full_message_modulus_2.is_power_of_two() {
+        panic!("Cannot create casting key if the full messages moduli are not a power of 2");
+    }
+
+    let nb_bits_1: i8 = full_message_modulus_1.ilog2().try_into().unwrap();
+    let nb_bits_2: i8 = full_message_modulus_2.ilog2().try_into().unwrap();
+
+    nb_bits_2 - nb_bits_1
+}
+
 /// A structure containing the casting public key.
 ///
 /// The casting key is generated by the client and is meant to be published: the client
@@ -59,23 +79,12 @@ impl KeySwitchingKey {
             engine.new_key_switching_key(key_pair_1.0, key_pair_2.0, params)
         });
 
-        let full_message_modulus_1 =
-            key_pair_1.0.parameters.carry_modulus().0 * key_pair_1.0.parameters.message_modulus().0;
-        let full_message_modulus_2 =
-            key_pair_2.0.parameters.carry_modulus().0 * key_pair_2.0.parameters.message_modulus().0;
-        if !full_message_modulus_1.is_power_of_two() || !full_message_modulus_2.is_power_of_two() {
-            panic!("Cannot create casting key if the full messages moduli are not a power of 2");
-        }
-
-        let nb_bits_1: i8 = full_message_modulus_1.ilog2().try_into().unwrap();
-        let nb_bits_2: i8 = full_message_modulus_2.ilog2().try_into().unwrap();
-
         // Pack the keys in the casting key set:
         Self {
             key_switching_key: key_switching_key.unwrap(),
             dest_server_key: key_pair_2.1.clone(),
             src_server_key: key_pair_1.1.clone(),
-            cast_rshift: nb_bits_2 - nb_bits_1,
+            cast_rshift: cast_rshift(key_pair_1.0, key_pair_2.0),
         }
     }
 
@@ -108,12 +117,17 @@ impl KeySwitchingKey {
     /// ```
     pub fn cast_into(&self, ct: &Ciphertext, ct_dest: &mut Ciphertext) {
         match self.cast_rshift {
-            // Same bit size: only key switch
-            0 => keyswitch_lwe_ciphertext(&self.key_switching_key, &ct.ct, &mut ct_dest.ct),
+            // Same bit size: only key switch, which adds its noise to the one of the input
+            0 => {
+                keyswitch_lwe_ciphertext(&self.key_switching_key, &ct.ct, &mut ct_dest.ct);
+                ct_dest.degree = ct.degree;
+                ct_dest.noise_level = ct.noise_level() + NoiseLevel::NOMINAL;
+            }
 
             // Cast to bigger bit length: keyswitch, then right shift
             i if i > 0 => {
                 keyswitch_lwe_ciphertext(&self.key_switching_key, &ct.ct, &mut ct_dest.ct);
+                ct_dest.noise_level = ct.noise_level() + NoiseLevel::NOMINAL;
 
                 let acc = self.dest_server_key.generate_lookup_table(|n| n >> i);
                 self.dest_server_key
@@ -130,6 +144,7 @@ impl KeySwitchingKey {
                     &shifted_cipher.ct,
                     &mut ct_dest.ct,
                 );
+                ct_dest.noise_level = shifted_cipher.noise_level() + NoiseLevel::NOMINAL;
             }
 
             _ => unreachable!(),
@@ -168,3 +183,97 @@ impl KeySwitchingKey {
         ret
     }
 }
+
+/// A structure containing a compressed casting public key.
+///
+/// The key switching key is seeded, and the source and destination server keys are stored
+/// compressed, the whole key can be decompressed into a [KeySwitchingKey].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CompressedKeySwitchingKey {
+    pub(crate) key_switching_key: SeededLweKeyswitchKeyOwned<u64>,
+    pub(crate) dest_server_key: CompressedServerKey,
+    pub(crate) src_server_key: CompressedServerKey,
+    pub cast_rshift: i8,
+}
+
+impl CompressedKeySwitchingKey {
+    /// Generate a compressed casting key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{
+    ///     PARAM_MESSAGE_1_CARRY_1_KS_PBS, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    /// use tfhe::shortint::prelude::*;
+    /// use tfhe::shortint::{CompressedKeySwitchingKey, CompressedServerKey};
+    ///
+    /// // Generate the client keys and compressed server keys:
+    /// let ck1 = ClientKey::new(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+    /// let sk1 = CompressedServerKey::new(&ck1);
+    /// let ck2 = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// let sk2 = CompressedServerKey::new(&ck2);
+    ///
+    /// // Generate the compressed casting key:
+    /// let compressed_ksk = CompressedKeySwitchingKey::new(
+    ///     (&ck1, &sk1),
+    ///     (&ck2, &sk2),
+    ///     PARAM_KEYSWITCH_1_1_KS_PBS_TO_2_2_KS_PBS,
+    /// );
+    ///
+    /// let ksk = compressed_ksk.decompress();
+    ///
+    /// let cipher = ck1.encrypt(1);
+    /// let cipher_2 = ksk.cast(&cipher);
+    /// assert_eq!(ck2.decrypt(&cipher_2), 1);
+    /// ```
+    pub fn new(
+        key_pair_1: (&ClientKey, &CompressedServerKey),
+        key_pair_2: (&ClientKey, &CompressedServerKey),
+        params: ShortintKeySwitchingParameters,
+    ) -> Self {
+        // Creation of the seeded key switching key
+        let key_switching_key = ShortintEngine::with_thread_local_mut(|engine| {
+            engine.new_seeded_key_switching_key(key_pair_1.0, key_pair_2.0, params)
+        });
+
+        Self {
+            key_switching_key: key_switching_key.unwrap(),
+            dest_server_key: key_pair_2.1.clone(),
+            src_server_key: key_pair_1.1.clone(),
+            cast_rshift: cast_rshift(key_pair_1.0, key_pair_2.0),
+        }
+    }
+
+    pub fn decompress(self) -> KeySwitchingKey {
+        let Self {
+            key_switching_key,
+            dest_server_key,
+            src_server_key,
+            cast_rshift,
+        } = self;
+
+        let (key_switching_key, (dest_server_key, src_server_key)) = rayon::join(
+            || key_switching_key.par_decompress_into_lwe_keyswitch_key(),
+            || {
+                rayon::join(
+                    || ServerKey::from(dest_server_key),
+                    || ServerKey::from(src_server_key),
+                )
+            },
+        );
+
+        KeySwitchingKey {
+            key_switching_key,
+            dest_server_key,
+            src_server_key,
+            cast_rshift,
+        }
+    }
+}
+
+impl From<CompressedKeySwitchingKey> for KeySwitchingKey {
+    fn from(value: CompressedKeySwitchingKey) -> Self {
+        value.decompress()
+    }
+}
@@ -1,3 +1,4 @@
+use crate::shortint::ciphertext::NoiseLevel;
 use crate::shortint::keycache::{KEY_CACHE, KEY_CACHE_KSK};
 use crate::shortint::parameters::ShortintKeySwitchingParameters;
 use crate::shortint::prelude::*;
@@ -150,9 +151,20 @@ fn gen_multi_keys_test_no_shift() {
         PARAM_MESSAGE_1_CARRY_1_KS_PBS,
         ksk_params,
     ));
+    let ck1 = keys.client_key_1();
     let ksk = keys.key_switching_key();
 
     assert_eq!(ksk.cast_rshift, 0);
+
+    // The ciphertext is only key switched, its noise grows
+    let cipher = ck1.encrypt(1);
+    let output_of_cast = ksk.cast(&cipher);
+    assert_eq!(ck2.decrypt(&output_of_cast), 1);
+    assert_eq!(output_of_cast.degree, cipher.degree);
+    assert_eq!(
+        output_of_cast.noise_level(),
+        cipher.noise_level() + NoiseLevel::NOMINAL
+    );
 }
 
 #[test]
@@ -12,7 +12,29 @@ use crate::shortint::{ClientKey, KeySwitchingKey, ServerKey};
 use lazy_static::*;
 use serde::{Deserialize, Serialize};
 
-named_params_impl!( ShortintParameterSet =>
+/// The parameter sets named in the keycache, without a custom maximum noise level
+#[derive(Copy, Clone, PartialEq)]
+struct NamedParameterSet(ShortintParameterSet);
+
+impl From<ClassicPBSParameters> for NamedParameterSet {
+    fn from(value: ClassicPBSParameters) -> Self {
+        Self(value.into())
+    }
+}
+
+impl From<MultiBitPBSParameters> for NamedParameterSet {
+    fn from(value: MultiBitPBSParameters) -> Self {
+        Self(value.into())
+    }
+}
+
+impl From<WopbsParameters> for NamedParameterSet {
+    fn from(value: WopbsParameters) -> Self {
+        Self(value.into())
+    }
+}
+
+named_params_impl!( NamedParameterSet =>
     PARAM_MESSAGE_1_CARRY_0_KS_PBS,
     PARAM_MESSAGE_1_CARRY_1_KS_PBS,
     PARAM_MESSAGE_1_CARRY_2_KS_PBS,
@@ -241,6 +263,19 @@ named_params_impl!( ShortintParameterSet =>
     PARAM_4_BITS_5_BLOCKS,
 );
 
+impl NamedParam for ShortintParameterSet {
+    fn name(&self) -> String {
+        let name = NamedParameterSet(self.without_custom_max_noise_level()).name();
+
+        // The maximum noise level is part of the server keys, keys generated with a custom one
+        // must not be mistaken for the keys of the base parameters
+        match self.custom_max_noise_level() {
+            Some(max_noise_level) => format!("{name}_MAX_NOISE_LEVEL_{}", max_noise_level.get()),
+            None => name,
+        }
+    }
+}
+
 impl NamedParam for ClassicPBSParameters {
     fn name(&self) -> String {
         PBSParameters::from(*self).name()
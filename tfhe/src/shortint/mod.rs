@@ -52,23 +52,29 @@ pub mod engine;
 pub mod key_switching_key;
 #[cfg(any(test, doctest, feature = "internal-keycache"))]
 pub mod keycache;
+#[cfg(any(test, feature = "__noise_injection"))]
+pub mod noise_injection;
 pub mod parameters;
 pub mod prelude;
 pub mod public_key;
 pub mod server_key;
 pub mod wopbs;
 
-pub use ciphertext::{Ciphertext, CompressedCiphertext, PBSOrder};
-pub use client_key::ClientKey;
-pub use key_switching_key::KeySwitchingKey;
+pub use ciphertext::{Ciphertext, CompressedCiphertext, CompressedCompactCiphertextList, PBSOrder};
+pub use client_key::{BlockNoiseReport, ClientKey};
+pub use key_switching_key::{CompressedKeySwitchingKey, KeySwitchingKey};
 pub use parameters::{
     CarryModulus, CiphertextModulus, ClassicPBSParameters, EncryptionKeyChoice, MessageModulus,
     MultiBitPBSParameters, PBSParameters, ShortintParameterSet, WopbsParameters,
 };
 pub use public_key::{
-    CompactPublicKey, CompressedCompactPublicKey, CompressedPublicKey, PublicKey,
+    CompactCiphertextListStream, CompactPublicKey, CompressedCompactPublicKey, CompressedPublicKey,
+    PublicKey,
+};
+pub use server_key::{
+    CheckError, CompressedServerKey, LookupTableCreationError, PbsBackend, SanitizationError,
+    ServerKey,
 };
-pub use server_key::{CheckError, CompressedServerKey, ServerKey};
 
 /// Generate a couple of client and server keys.
 ///
@@ -112,7 +118,10 @@ where
             encryption_key_choice: wopbs_params.encryption_key_choice,
         };
 
-        ShortintParameterSet::try_new_pbs_and_wopbs_param_set((pbs_params, wopbs_params)).unwrap()
+        ShortintParameterSet::try_new_pbs_and_wopbs_param_set((pbs_params, wopbs_params))
+            .unwrap()
+            .with_max_noise_level(shortint_parameters_set.max_noise_level())
+            .unwrap()
     } else {
         shortint_parameters_set
     };
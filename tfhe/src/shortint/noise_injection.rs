@@ -0,0 +1,207 @@
+//! Utilities to test the behavior of operations near the decryption failure boundary.
+//!
+//! Ciphertexts can be encrypted with an arbitrary amount of noise using
+//! [`ClientKey::encrypt_with_noise_level`], and the rate at which a computation fails can be
+//! measured with [`measure_failure_rate`].
+//!
+//! This module is only meant for tests, it is available in `cfg(test)` or with the
+//! `__noise_injection` feature.
+
+use crate::core_crypto::commons::dispersion::StandardDev;
+use crate::shortint::ciphertext::{Degree, NoiseLevel};
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::parameters::ShortintParameterSet;
+use crate::shortint::{Ciphertext, ClientKey, PBSOrder};
+use rayon::prelude::*;
+
+/// Standard deviation of the encryption noise, relative to the decryption failure bound.
+///
+/// The decryption of a ciphertext fails when the absolute value of its noise is bigger than
+/// half of the encoding scaling factor (delta / 2). An `InjectedNoiseStdDev(1.0)` gives a noise
+/// whose standard deviation is exactly this bound, so roughly 32% of decryptions fail, while an
+/// `InjectedNoiseStdDev(0.25)` only fails when the noise exceeds 4 standard deviations, i.e. with
+/// a probability of about 6e-5.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct InjectedNoiseStdDev(pub f64);
+
+impl InjectedNoiseStdDev {
+    /// Returns the modular standard deviation corresponding to this relative one for the given
+    /// parameters
+    pub fn to_std_dev(self, parameters: ShortintParameterSet) -> StandardDev {
+        // delta is 1 / (2 * message_modulus * carry_modulus) on the torus, the bound is half
+        // of it
+        let total_modulus = (parameters.message_modulus().0 * parameters.carry_modulus().0) as f64;
+        StandardDev(self.0 / (4.0 * total_modulus))
+    }
+
+    /// Returns the [`NoiseLevel`] of a ciphertext encrypted with this noise, the ratio of its
+    /// standard deviation to the one of a fresh encryption rounded up.
+    ///
+    /// The noise level is always above [`NoiseLevel::NOMINAL`], so that the ciphertext is never
+    /// taken for the output of a PBS and a PBS is never skipped on it.
+    fn to_noise_level(self, parameters: ShortintParameterSet, pbs_order: PBSOrder) -> NoiseLevel {
+        let encryption_std_dev = match pbs_order {
+            PBSOrder::KeyswitchBootstrap => parameters.glwe_modular_std_dev(),
+            PBSOrder::BootstrapKeyswitch => parameters.lwe_modular_std_dev(),
+        };
+        let ratio = (self.to_std_dev(parameters).0 / encryption_std_dev.0).ceil() as usize;
+        NoiseLevel::NOMINAL * ratio.max(NoiseLevel::NOMINAL.get() + 1)
+    }
+}
+
+impl ClientKey {
+    /// Encrypt a message with a noise of the given [`InjectedNoiseStdDev`] instead of the noise
+    /// of the parameters.
+    ///
+    /// The requested noise level is a standard deviation relative to the decryption failure
+    /// bound, the [`NoiseLevel`] of the returned ciphertext is derived from it.
+    pub fn encrypt_with_noise_level(&self, message: u64, noise: InjectedNoiseStdDev) -> Ciphertext {
+        let pbs_order: PBSOrder = self.parameters.encryption_key_choice().into();
+        let encryption_lwe_sk = match pbs_order {
+            PBSOrder::KeyswitchBootstrap => &self.large_lwe_secret_key,
+            PBSOrder::BootstrapKeyswitch => &self.small_lwe_secret_key,
+        };
+        let message_modulus = self.parameters.message_modulus();
+
+        let ct = ShortintEngine::with_thread_local_mut(|engine| {
+            engine.encrypt_inner_ct(
+                &self.parameters,
+                encryption_lwe_sk,
+                noise.to_std_dev(self.parameters),
+                message,
+                message_modulus,
+            )
+        });
+
+        Ciphertext {
+            ct,
+            degree: Degree(message_modulus.0 - 1),
+            noise_level: noise.to_noise_level(self.parameters, pbs_order),
+            message_modulus,
+            carry_modulus: self.parameters.carry_modulus(),
+            pbs_order,
+        }
+    }
+}
+
+/// Result of [`measure_failure_rate`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FailureRate {
+    pub failures: usize,
+    pub samples: usize,
+}
+
+impl FailureRate {
+    pub fn rate(&self) -> f64 {
+        self.failures as f64 / self.samples as f64
+    }
+}
+
+/// Runs `trial` `samples` times (in parallel) and counts the number of failures.
+///
+/// `trial` receives the index of the sample and returns whether the trial was successful,
+/// typically by comparing the decryption of a computation on noisy inputs to the expected
+/// result.
+pub fn measure_failure_rate<F>(samples: usize, trial: F) -> FailureRate
+where
+    F: Fn(usize) -> bool + Sync,
+{
+    let failures = (0..samples)
+        .into_par_iter()
+        .filter(|index| !trial(*index))
+        .count();
+
+    FailureRate { failures, samples }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{measure_failure_rate, InjectedNoiseStdDev};
+    use crate::shortint::ciphertext::NoiseLevel;
+    use crate::shortint::keycache::KEY_CACHE;
+    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+
+    const NB_SAMPLES: usize = 4000;
+
+    #[test]
+    fn test_encrypt_with_noise_level_failure_rate() {
+        let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+        let cks = keys.client_key();
+        let modulus = cks.parameters.message_modulus().0 as u64;
+
+        // Noise with a std dev equal to the bound: ~31.7% failures
+        let failure_rate = measure_failure_rate(NB_SAMPLES, |i| {
+            let msg = i as u64 % modulus;
+            let ct = cks.encrypt_with_noise_level(msg, InjectedNoiseStdDev(1.0));
+            cks.decrypt_message_and_carry(&ct) == msg
+        });
+        assert!(
+            (0.25..0.39).contains(&failure_rate.rate()),
+            "Unexpected failure rate: {failure_rate:?}"
+        );
+
+        // Bound at 8 std dev: failures are not expected to be observed
+        let failure_rate = measure_failure_rate(NB_SAMPLES, |i| {
+            let msg = i as u64 % modulus;
+            let ct = cks.encrypt_with_noise_level(msg, InjectedNoiseStdDev(0.125));
+            cks.decrypt_message_and_carry(&ct) == msg
+        });
+        assert_eq!(failure_rate.failures, 0, "{failure_rate:?}");
+    }
+
+    #[test]
+    fn test_injected_noise_add_failure_rate() {
+        let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+        let (cks, sks) = (keys.client_key(), keys.server_key());
+        let modulus = cks.parameters.message_modulus().0 as u64;
+
+        let noise = InjectedNoiseStdDev(0.5);
+
+        // Bound at 2 std dev: ~4.6% failures
+        let fresh_failure_rate = measure_failure_rate(NB_SAMPLES, |i| {
+            let msg = i as u64 % modulus;
+            let ct = cks.encrypt_with_noise_level(msg, noise);
+            cks.decrypt_message_and_carry(&ct) == msg
+        });
+
+        // Adding two ciphertexts multiplies the std dev by sqrt(2),
+        // the bound is at ~1.41 std dev: ~15.7% failures
+        let add_failure_rate = measure_failure_rate(NB_SAMPLES, |i| {
+            let msg = i as u64 % modulus;
+            let ct_0 = cks.encrypt_with_noise_level(msg, noise);
+            let ct_1 = cks.encrypt_with_noise_level(msg, noise);
+            let ct_res = sks.unchecked_add(&ct_0, &ct_1);
+            cks.decrypt_message_and_carry(&ct_res) == 2 * msg
+        });
+
+        assert!(
+            (0.02..0.08).contains(&fresh_failure_rate.rate()),
+            "Unexpected failure rate: {fresh_failure_rate:?}"
+        );
+        assert!(
+            (0.11..0.21).contains(&add_failure_rate.rate()),
+            "Unexpected failure rate: {add_failure_rate:?}"
+        );
+    }
+
+    #[test]
+    fn test_injected_noise_pbs_resets_noise() {
+        let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+        let (cks, sks) = (keys.client_key(), keys.server_key());
+        let modulus = cks.parameters.message_modulus().0 as u64;
+
+        // Inputs are close to, but still within the bound, the PBS output has the nominal noise
+        // so adding several of them stays correct
+        let failure_rate = measure_failure_rate(100, |i| {
+            let msg = i as u64 % modulus;
+            let ct = cks.encrypt_with_noise_level(msg, InjectedNoiseStdDev(0.125));
+            assert!(ct.noise_level() > NoiseLevel::NOMINAL);
+            let ct_res = sks.message_extract(&ct);
+            assert_eq!(ct_res.noise_level(), NoiseLevel::NOMINAL);
+            let ct_res = sks.unchecked_add(&ct_res, &ct_res);
+            let ct_res = sks.unchecked_add(&ct_res, &ct_res);
+            cks.decrypt_message_and_carry(&ct_res) == 4 * msg
+        });
+        assert_eq!(failure_rate.failures, 0, "{failure_rate:?}");
+    }
+}
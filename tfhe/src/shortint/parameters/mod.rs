@@ -17,17 +17,21 @@ use serde::{Deserialize, Serialize};
 pub mod key_switching;
 pub mod multi_bit;
 pub mod parameters_compact_pk;
+pub mod parameters_simulator;
 pub mod parameters_wopbs;
 pub mod parameters_wopbs_message_carry;
 pub(crate) mod parameters_wopbs_prime_moduli;
+pub mod selection;
 
 pub use crate::core_crypto::commons::parameters::EncryptionKeyChoice;
 pub use key_switching::ShortintKeySwitchingParameters;
 pub use multi_bit::*;
 pub use parameters_compact_pk::*;
+pub use parameters_simulator::*;
 pub use parameters_wopbs::WopbsParameters;
 
 use super::ciphertext::Degree;
+use super::server_key::MaxNoiseLevel;
 use super::PBSOrder;
 
 /// The number of bits on which the message will be encoded.
@@ -333,18 +337,25 @@ impl ShortintParameterSetInner {
 #[derive(Serialize, Copy, Clone, Deserialize, Debug, PartialEq)]
 pub struct ShortintParameterSet {
     inner: ShortintParameterSetInner,
+    // Not serialized so that the keys embedding a parameter set keep the layout they had before
+    // it was added (bincode cannot skip a missing field), the server keys store their own
+    // maximum noise level
+    #[serde(skip)]
+    max_noise_level: Option<MaxNoiseLevel>,
 }
 
 impl ShortintParameterSet {
     pub const fn new_pbs_param_set(params: PBSParameters) -> Self {
         Self {
             inner: ShortintParameterSetInner::PBSOnly(params),
+            max_noise_level: None,
         }
     }
 
     pub const fn new_wopbs_param_set(params: WopbsParameters) -> Self {
         Self {
             inner: ShortintParameterSetInner::WopbsOnly(params),
+            max_noise_level: None,
         }
     }
 
@@ -368,6 +379,7 @@ impl ShortintParameterSet {
         }
         Ok(Self {
             inner: ShortintParameterSetInner::PBSAndWopbs(pbs_params, wopbs_params),
+            max_noise_level: None,
         })
     }
 
@@ -491,6 +503,85 @@ impl ShortintParameterSet {
         }
     }
 
+    /// Returns the maximum noise level enforced by the server keys generated from these parameters
+    ///
+    /// Unless it was set with [Self::with_max_noise_level], this is
+    /// [MaxNoiseLevel::from_msg_carry_modulus].
+    pub fn max_noise_level(&self) -> MaxNoiseLevel {
+        self.max_noise_level.unwrap_or_else(|| {
+            MaxNoiseLevel::from_msg_carry_modulus(self.message_modulus(), self.carry_modulus())
+        })
+    }
+
+    /// Sets the maximum noise level enforced by the server keys generated from these parameters
+    ///
+    /// The checked operations of these server keys refuse to produce a ciphertext whose noise
+    /// level exceeds it, see [CheckError::NoiseTooBig](super::CheckError::NoiseTooBig). A
+    /// maximum lower than the default leaves a safety margin to the noise budget of the
+    /// parameters.
+    ///
+    /// Returns an error if the maximum is 0 or exceeds the default one
+    /// ([MaxNoiseLevel::from_msg_carry_modulus]), which is what the parameters support.
+    ///
+    /// The maximum is not serialized with the parameters: it is stored in the server keys, a
+    /// deserialized parameter set has the default maximum.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{ShortintParameterSet, PARAM_MESSAGE_2_CARRY_2_KS_PBS};
+    /// use tfhe::shortint::server_key::MaxNoiseLevel;
+    /// use tfhe::shortint::{gen_keys, CheckError};
+    ///
+    /// let params = ShortintParameterSet::from(PARAM_MESSAGE_2_CARRY_2_KS_PBS)
+    ///     .with_max_noise_level(MaxNoiseLevel::new(2))
+    ///     .unwrap();
+    /// let (cks, sks) = gen_keys(params);
+    /// assert_eq!(sks.max_noise_level, MaxNoiseLevel::new(2));
+    ///
+    /// let ct = cks.encrypt(1);
+    /// let ct = sks.checked_add(&ct, &ct).unwrap();
+    /// // A third fresh ciphertext would exceed the maximum noise level
+    /// let res = sks.checked_add(&ct, &cks.encrypt(1));
+    /// assert!(matches!(res, Err(CheckError::NoiseTooBig { .. })));
+    ///
+    /// // The parameters do not support a maximum above the default one
+    /// let params = ShortintParameterSet::from(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// let max_noise_level = MaxNoiseLevel::new(params.max_noise_level().get() + 1);
+    /// assert!(params.with_max_noise_level(max_noise_level).is_err());
+    /// ```
+    pub fn with_max_noise_level(
+        mut self,
+        max_noise_level: MaxNoiseLevel,
+    ) -> Result<Self, &'static str> {
+        let default_max_noise_level =
+            MaxNoiseLevel::from_msg_carry_modulus(self.message_modulus(), self.carry_modulus());
+
+        if max_noise_level.get() == 0 {
+            return Err("The maximum noise level must allow at least a fresh ciphertext");
+        }
+        if max_noise_level.get() > default_max_noise_level.get() {
+            return Err(
+                "The maximum noise level exceeds the one supported by the message and carry moduli",
+            );
+        }
+
+        self.max_noise_level = Some(max_noise_level);
+        Ok(self)
+    }
+
+    /// Returns the maximum noise level set with [Self::with_max_noise_level], if any
+    pub(crate) const fn custom_max_noise_level(&self) -> Option<MaxNoiseLevel> {
+        self.max_noise_level
+    }
+
+    /// Returns the parameters without the maximum noise level set with
+    /// [Self::with_max_noise_level]
+    pub(crate) const fn without_custom_max_noise_level(mut self) -> Self {
+        self.max_noise_level = None;
+        self
+    }
+
     pub const fn pbs_only(&self) -> bool {
         self.inner.is_pbs_only()
     }
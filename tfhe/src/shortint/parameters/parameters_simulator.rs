@@ -0,0 +1,36 @@
+//! Parameters to simulate computations, they are **not secure**.
+//!
+//! These parameters run the same algorithms as the secure ones, with LWE and GLWE dimensions
+//! small enough for a programmable bootstrap to take a fraction of a millisecond and no encryption
+//! noise. Computations go through the same code paths, with the same degree bookkeeping and
+//! carry propagations, so they can be used to test the logic of an application quickly.
+//!
+//! # Warning
+//!
+//! Ciphertexts encrypted with these parameters do not protect the messages at all, they must
+//! never be used outside of tests.
+
+use crate::shortint::parameters::{
+    CarryModulus, CiphertextModulus, ClassicPBSParameters, DecompositionBaseLog,
+    DecompositionLevelCount, EncryptionKeyChoice, GlweDimension, LweDimension, MessageModulus,
+    PolynomialSize, StandardDev,
+};
+
+/// Insecure parameters with the message and carry moduli of
+/// [PARAM_MESSAGE_2_CARRY_2_KS_PBS](super::PARAM_MESSAGE_2_CARRY_2_KS_PBS), see the
+/// [module documentation](self).
+pub const PARAM_SIMULATOR_MESSAGE_2_CARRY_2_KS_PBS: ClassicPBSParameters = ClassicPBSParameters {
+    lwe_dimension: LweDimension(8),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(512),
+    lwe_modular_std_dev: StandardDev(0.0),
+    glwe_modular_std_dev: StandardDev(0.0),
+    pbs_base_log: DecompositionBaseLog(23),
+    pbs_level: DecompositionLevelCount(1),
+    ks_level: DecompositionLevelCount(4),
+    ks_base_log: DecompositionBaseLog(8),
+    message_modulus: MessageModulus(4),
+    carry_modulus: CarryModulus(4),
+    ciphertext_modulus: CiphertextModulus::new_native(),
+    encryption_key_choice: EncryptionKeyChoice::Big,
+};
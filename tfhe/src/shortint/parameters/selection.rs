@@ -0,0 +1,183 @@
+//! Selection of a parameter set from a description of the workload.
+//!
+//! [choose_parameters] goes through the built-in parameter sets and ranks them with a simple cost
+//! model: the number of programmable bootstraps (PBS) needed by the workload, multiplied by the
+//! cost of a keyswitch and a PBS with the parameters. The predictions are meant to compare the
+//! parameter sets, they are not benchmarks: the latencies are those of a single core of a recent
+//! x86 CPU, and they do not account for the parallelism of the integer operations.
+
+use super::{ClassicPBSParameters, ALL_PARAMETER_VEC};
+use std::time::Duration;
+
+/// Approximate number of cost units computed per second by a single core, see
+/// [pbs_cost_units]
+const COST_UNITS_PER_SECOND: f64 = 6.5e9;
+
+/// The probability of failure of a single PBS guaranteed by all the built-in parameter sets
+const LOG2_PBS_P_FAIL: f64 = -40.0;
+
+/// The security level of the parameters
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SecurityLevel {
+    /// 128 bits of security, guaranteed by all the built-in parameter sets
+    Bits128,
+}
+
+/// Number of operations of each kind done on the encrypted integers in one evaluation
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct OpsProfile {
+    pub additions: usize,
+    pub multiplications: usize,
+    pub comparisons: usize,
+    pub bitwise_operations: usize,
+}
+
+/// Description of a computation on encrypted integers
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WorkloadSpec {
+    /// Number of bits of the integers
+    pub precision_bits: usize,
+    /// Number of multiplications on the longest path of the computation, the workload is
+    /// assumed to do at least this many multiplications
+    pub mul_depth: usize,
+    pub ops_profile: OpsProfile,
+    /// Maximum latency of one evaluation, the parameter sets reaching it are ranked first
+    pub latency_target: Option<Duration>,
+    pub security: SecurityLevel,
+}
+
+/// A parameter set suitable for a [WorkloadSpec], with the predictions of the cost model
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ParameterCandidate {
+    pub parameters: ClassicPBSParameters,
+    /// Number of shortint blocks of each integer
+    pub num_blocks: usize,
+    /// Predicted number of PBS of one evaluation
+    pub predicted_pbs_count: usize,
+    /// Predicted single core latency of one evaluation
+    pub predicted_latency: Duration,
+    /// Base 2 logarithm of the predicted probability that one evaluation gives a wrong result
+    pub log2_p_fail: f64,
+    pub meets_latency_target: bool,
+}
+
+/// Cost of a keyswitch followed by a PBS, in number of multiply-add operations
+///
+/// The PBS does `lwe_dimension` external products, each one computing the forward FFTs of the
+/// decomposed GLWE and their products with the key. The keyswitch does one product per
+/// coefficient of the input LWE and decomposition level.
+fn pbs_cost_units(parameters: &ClassicPBSParameters) -> f64 {
+    let lwe_dimension = parameters.lwe_dimension.0 as f64;
+    let glwe_size = (parameters.glwe_dimension.0 + 1) as f64;
+    let polynomial_size = parameters.polynomial_size.0 as f64;
+    let big_lwe_dimension = parameters.glwe_dimension.0 as f64 * polynomial_size;
+
+    let external_product = parameters.pbs_level.0 as f64
+        * glwe_size
+        * glwe_size
+        * polynomial_size
+        * polynomial_size.log2();
+    let keyswitch = parameters.ks_level.0 as f64 * big_lwe_dimension * (lwe_dimension + 1.0);
+
+    lwe_dimension * external_product + keyswitch
+}
+
+/// Number of PBS of the integer operations of the profile, on integers of `num_blocks` blocks
+fn pbs_count(ops: &OpsProfile, num_blocks: usize) -> usize {
+    // Carry propagation: one PBS to extract the message and one for the carry of each block
+    let addition = 2 * num_blocks;
+    // Schoolbook multiplication of the blocks, two PBS per block product and a final propagation
+    let multiplication = 2 * num_blocks * num_blocks + addition;
+    // One PBS per block comparison, and a tree reduction of the block comparisons
+    let comparison = 2 * num_blocks;
+    let bitwise_operation = num_blocks;
+
+    ops.additions * addition
+        + ops.multiplications * multiplication
+        + ops.comparisons * comparison
+        + ops.bitwise_operations * bitwise_operation
+}
+
+/// Returns the built-in parameter sets suitable for the workload, from the most to the least
+/// suitable
+///
+/// The parameter sets reaching the latency target are ranked first, the parameter sets are then
+/// ranked by predicted latency. Only the parameter sets with a carry space at least as large as
+/// the message space are considered, as it is required by the integer operations.
+///
+/// The predictions come from a cost model, see the [module documentation](self).
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use tfhe::shortint::parameters::selection::{
+///     choose_parameters, OpsProfile, SecurityLevel, WorkloadSpec,
+/// };
+///
+/// let spec = WorkloadSpec {
+///     precision_bits: 32,
+///     mul_depth: 2,
+///     ops_profile: OpsProfile {
+///         additions: 10,
+///         multiplications: 4,
+///         comparisons: 2,
+///         bitwise_operations: 0,
+///     },
+///     latency_target: Some(Duration::from_secs(60)),
+///     security: SecurityLevel::Bits128,
+/// };
+///
+/// let candidates = choose_parameters(&spec);
+/// let best = &candidates[0];
+/// assert!(best.meets_latency_target);
+/// assert!(best.parameters.carry_modulus.0 >= best.parameters.message_modulus.0);
+/// assert!(best.log2_p_fail < -20.0);
+/// ```
+pub fn choose_parameters(spec: &WorkloadSpec) -> Vec<ParameterCandidate> {
+    match spec.security {
+        // All the built-in parameter sets provide this level
+        SecurityLevel::Bits128 => {}
+    }
+
+    let ops = OpsProfile {
+        multiplications: spec.ops_profile.multiplications.max(spec.mul_depth),
+        ..spec.ops_profile
+    };
+
+    let mut candidates = ALL_PARAMETER_VEC
+        .into_iter()
+        .filter(|parameters| parameters.carry_modulus.0 >= parameters.message_modulus.0)
+        .map(|parameters| {
+            let bits_per_block = parameters.message_modulus.0.ilog2() as usize;
+            let num_blocks = ((spec.precision_bits + bits_per_block - 1) / bits_per_block).max(1);
+            let predicted_pbs_count = pbs_count(&ops, num_blocks);
+
+            let predicted_latency = Duration::from_secs_f64(
+                predicted_pbs_count as f64 * pbs_cost_units(&parameters) / COST_UNITS_PER_SECOND,
+            );
+            // Union bound over the PBS of the evaluation
+            let log2_p_fail =
+                (LOG2_PBS_P_FAIL + (predicted_pbs_count.max(1) as f64).log2()).min(0.0);
+            let meets_latency_target = spec
+                .latency_target
+                .map_or(true, |target| predicted_latency <= target);
+
+            ParameterCandidate {
+                parameters,
+                num_blocks,
+                predicted_pbs_count,
+                predicted_latency,
+                log2_p_fail,
+                meets_latency_target,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    candidates.sort_by(|a, b| {
+        b.meets_latency_target
+            .cmp(&a.meets_latency_target)
+            .then(a.predicted_latency.cmp(&b.predicted_latency))
+    });
+    candidates
+}
@@ -6,9 +6,11 @@ use crate::core_crypto::prelude::{
     LweCompactPublicKeyOwned, Plaintext, PlaintextList, SeededLweCompactPublicKeyOwned,
 };
 
+use crate::core_crypto::algorithms::slice_algorithms::slice_wrapping_add_assign;
 use crate::core_crypto::prelude::encrypt_lwe_ciphertext_with_compact_public_key;
 
-use crate::shortint::ciphertext::{CompactCiphertextList, Degree};
+use crate::shortint::ciphertext::{CompactCiphertextList, Degree, ElementMetadata, NoiseLevel};
+use crate::shortint::parameters::MessageModulus;
 use crate::shortint::{Ciphertext, ClientKey, PBSOrder, ShortintParameterSet};
 
 use crate::shortint::engine::ShortintEngine;
@@ -118,6 +120,7 @@ impl CompactPublicKey {
         Ciphertext {
             ct: encrypted_ct,
             degree: Degree(message_modulus.0 - 1),
+            noise_level: NoiseLevel::PUBLIC_KEY_ENCRYPTION,
             message_modulus,
             carry_modulus: self.parameters.carry_modulus(),
             pbs_order: self.pbs_order,
@@ -133,7 +136,94 @@ impl CompactPublicKey {
             .map(|plaintext| plaintext.0)
             .collect::<Vec<_>>();
 
-        let plaintext_list = PlaintextList::from_container(plaintext_container);
+        let ct_list =
+            self.encrypt_plaintext_list(PlaintextList::from_container(plaintext_container));
+
+        let message_modulus = self.parameters.message_modulus();
+        CompactCiphertextList {
+            ct_list,
+            degree: Degree(message_modulus.0 - 1),
+            message_modulus,
+            carry_modulus: self.parameters.carry_modulus(),
+            pbs_order: self.pbs_order,
+            noise_level: NoiseLevel::PUBLIC_KEY_ENCRYPTION,
+            element_metadata: None,
+        }
+    }
+
+    /// Encrypts messages with different message moduli in a single compact list
+    ///
+    /// Each message is reduced modulo its own message modulus, the remaining space of the
+    /// plaintext being its carry space, so that a list can hold booleans next to messages larger
+    /// than the message modulus of the parameters. Each ciphertext of the list is expanded with
+    /// its own message modulus and degree, see [`CompactCiphertextList::element_metadata`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if a message modulus is not a power of two, or is greater than the product of the
+    /// message and carry moduli of the parameters.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{MessageModulus, PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS};
+    /// use tfhe::shortint::{ClientKey, CompactPublicKey};
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS);
+    /// let pk = CompactPublicKey::new(&cks);
+    ///
+    /// // A boolean, a 2 bits message and a 4 bits message
+    /// let msgs = [(1u64, MessageModulus(2)), (3, MessageModulus(4)), (13, MessageModulus(16))];
+    /// let list = pk.encrypt_slice_with_message_moduli(&msgs);
+    ///
+    /// for (ct, (msg, message_modulus)) in list.expand().iter().zip(msgs) {
+    ///     assert_eq!(ct.message_modulus, message_modulus);
+    ///     assert_eq!(ct.degree.0, message_modulus.0 - 1);
+    ///     assert_eq!(cks.decrypt_message_and_carry(ct), msg);
+    /// }
+    /// ```
+    pub fn encrypt_slice_with_message_moduli(
+        &self,
+        messages: &[(u64, MessageModulus)],
+    ) -> CompactCiphertextList {
+        let total_modulus = self.parameters.message_modulus().0 * self.parameters.carry_modulus().0;
+        //The delta is the one defined by the parameters
+        let delta = (1_u64 << 63) / total_modulus as u64;
+
+        let mut plaintext_container = Vec::with_capacity(messages.len());
+        let mut element_metadata = Vec::with_capacity(messages.len());
+        for &(message, message_modulus) in messages {
+            assert!(
+                message_modulus.0.is_power_of_two() && message_modulus.0 <= total_modulus,
+                "The message modulus {} does not fit in a plaintext of modulus {total_modulus}",
+                message_modulus.0
+            );
+            plaintext_container.push((message % message_modulus.0 as u64) * delta);
+            element_metadata.push(ElementMetadata {
+                degree: Degree(message_modulus.0 - 1),
+                message_modulus,
+            });
+        }
+
+        let ct_list =
+            self.encrypt_plaintext_list(PlaintextList::from_container(plaintext_container));
+
+        let message_modulus = self.parameters.message_modulus();
+        CompactCiphertextList {
+            ct_list,
+            degree: Degree(message_modulus.0 - 1),
+            message_modulus,
+            carry_modulus: self.parameters.carry_modulus(),
+            pbs_order: self.pbs_order,
+            noise_level: NoiseLevel::PUBLIC_KEY_ENCRYPTION,
+            element_metadata: Some(element_metadata),
+        }
+    }
+
+    fn encrypt_plaintext_list(
+        &self,
+        plaintext_list: PlaintextList<Vec<u64>>,
+    ) -> LweCompactCiphertextListOwned<u64> {
         let mut ct_list = LweCompactCiphertextListOwned::new(
             0u64,
             self.key.lwe_dimension().to_lwe_size(),
@@ -175,13 +265,123 @@ impl CompactPublicKey {
             });
         }
 
-        let message_modulus = self.parameters.message_modulus();
-        CompactCiphertextList {
-            ct_list,
-            degree: Degree(message_modulus.0 - 1),
-            message_modulus,
-            carry_modulus: self.parameters.carry_modulus(),
-            pbs_order: self.pbs_order,
+        ct_list
+    }
+
+    /// Re-randomizes a list encrypted under this key, by adding a fresh encryption of 0 to each of
+    /// its ciphertexts
+    ///
+    /// The returned list encrypts the same messages, but it cannot be linked to the input list
+    /// without the secret key, which allows a relayer to re-post encrypted inputs (e.g. ballots)
+    /// without revealing which ones they are.
+    ///
+    /// Each call adds the noise of a fresh encryption, which is tracked in the noise level of the
+    /// list so that its ciphertexts are sanitized with a PBS when it is expanded (see
+    /// [`CompactCiphertextList::expand_with`]).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the list was not encrypted with the parameters of this key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS;
+    /// use tfhe::shortint::{ClientKey, CompactPublicKey};
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS);
+    /// let pk = CompactPublicKey::new(&cks);
+    ///
+    /// let msgs = [3u64, 0, 1];
+    /// let list = pk.encrypt_slice(&msgs);
+    ///
+    /// let rerandomized = pk.rerandomize_compact_list(&list);
+    /// assert_ne!(rerandomized.ct_list, list.ct_list);
+    ///
+    /// let decrypted = rerandomized
+    ///     .expand()
+    ///     .iter()
+    ///     .map(|ct| cks.decrypt(ct))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(decrypted, msgs);
+    /// ```
+    pub fn rerandomize_compact_list(&self, list: &CompactCiphertextList) -> CompactCiphertextList {
+        assert_eq!(
+            list.ct_list.lwe_size(),
+            self.key.lwe_dimension().to_lwe_size(),
+            "The list does not have the LWE size of the key"
+        );
+        assert_eq!(
+            list.ct_list.ciphertext_modulus(),
+            self.parameters.ciphertext_modulus(),
+            "The list does not have the ciphertext modulus of the key"
+        );
+        assert!(
+            list.message_modulus == self.parameters.message_modulus()
+                && list.carry_modulus == self.parameters.carry_modulus()
+                && list.pbs_order == self.pbs_order,
+            "The list was not encrypted with the parameters of the key"
+        );
+
+        let mut rerandomized = list.clone();
+        let count = list.ct_list.lwe_ciphertext_count().0;
+        if count == 0 {
+            return rerandomized;
+        }
+
+        // The degree is unchanged as the added ciphertexts encrypt 0, the noise of their
+        // encryption is added
+        let zeros = self.encrypt_iter(std::iter::repeat(0).take(count));
+        slice_wrapping_add_assign(rerandomized.ct_list.as_mut(), zeros.ct_list.as_ref());
+        rerandomized.noise_level += zeros.noise_level;
+
+        rerandomized
+    }
+
+    /// Returns an iterator encrypting the messages into lists of at most `chunk_size` ciphertexts
+    ///
+    /// The messages are only consumed when the next list is requested, so a stream that does not
+    /// fit in memory can be encrypted and sent one list at a time. All the lists share the same
+    /// parameters, they can be expanded as they arrive with
+    /// [`CompactCiphertextList::expand_stream`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is 0
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::ciphertext::{CompactCiphertextList, ExpandConfig};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS;
+    /// use tfhe::shortint::{ClientKey, CompactPublicKey};
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS);
+    /// let pk = CompactPublicKey::new(&cks);
+    ///
+    /// let lists = pk.encrypt_stream((0..10u64).map(|i| i % 4), 4);
+    ///
+    /// let mut decrypted = Vec::new();
+    /// for chunk in CompactCiphertextList::expand_stream(lists, ExpandConfig::new()) {
+    ///     let chunk = chunk.unwrap();
+    ///     assert!(chunk.len() <= 4);
+    ///     decrypted.extend(chunk.iter().map(|ct| cks.decrypt(ct)));
+    /// }
+    /// assert_eq!(decrypted, [0, 1, 2, 3, 0, 1, 2, 3, 0, 1]);
+    /// ```
+    pub fn encrypt_stream<I>(
+        &self,
+        messages: I,
+        chunk_size: usize,
+    ) -> CompactCiphertextListStream<'_, I::IntoIter>
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        assert!(chunk_size > 0, "The chunk size must not be 0");
+        CompactCiphertextListStream {
+            key: self,
+            messages: messages.into_iter(),
+            chunk_size,
         }
     }
 
@@ -194,6 +394,26 @@ impl CompactPublicKey {
     }
 }
 
+/// Iterator returned by [`CompactPublicKey::encrypt_stream`]
+pub struct CompactCiphertextListStream<'key, I> {
+    key: &'key CompactPublicKey,
+    messages: I,
+    chunk_size: usize,
+}
+
+impl<'key, I> Iterator for CompactCiphertextListStream<'key, I>
+where
+    I: Iterator<Item = u64>,
+{
+    type Item = CompactCiphertextList;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut chunk = self.messages.by_ref().take(self.chunk_size).peekable();
+        chunk.peek()?;
+        Some(self.key.encrypt_iter(chunk))
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CompressedCompactPublicKey {
     pub(crate) key: SeededLweCompactPublicKeyOwned<u64>,
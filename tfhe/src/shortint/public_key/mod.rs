@@ -4,6 +4,6 @@ pub mod compact;
 pub mod compressed;
 pub mod standard;
 
-pub use compact::{CompactPublicKey, CompressedCompactPublicKey};
+pub use compact::{CompactCiphertextListStream, CompactPublicKey, CompressedCompactPublicKey};
 pub use compressed::CompressedPublicKey;
 pub use standard::PublicKey;
@@ -3,6 +3,17 @@ use crate::shortint::engine::ShortintEngine;
 use crate::shortint::server_key::CheckError;
 use crate::shortint::server_key::CheckError::CarryFull;
 use crate::shortint::Ciphertext;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// Statistics about the work done by [ServerKey::sum_many]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct SumManyStats {
+    /// Number of additions performed
+    pub additions: usize,
+    /// Number of bootstraps performed to clean carries
+    pub bootstraps: usize,
+}
 
 impl ServerKey {
     /// Compute homomorphically an addition between two ciphertexts encrypting integer values.
@@ -117,11 +128,11 @@ impl ServerKey {
     pub fn add_assign(&self, ct_left: &mut Ciphertext, ct_right: &Ciphertext) {
         let tmp_rhs: Ciphertext;
 
-        if !ct_left.carry_is_empty() {
+        if !ct_left.is_clean() {
             self.message_extract_assign(ct_left);
         }
 
-        let rhs = if ct_right.carry_is_empty() {
+        let rhs = if ct_right.is_clean() {
             ct_right
         } else {
             tmp_rhs = self.message_extract(ct_right);
@@ -270,8 +281,22 @@ impl ServerKey {
     /// assert_eq!(can_be_added, true);
     /// ```
     pub fn is_add_possible(&self, ct_left: &Ciphertext, ct_right: &Ciphertext) -> bool {
+        self.check_add_possible(ct_left, ct_right).is_ok()
+    }
+
+    /// Verify if the ciphertexts can be added together, returning the reason otherwise.
+    pub(crate) fn check_add_possible(
+        &self,
+        ct_left: &Ciphertext,
+        ct_right: &Ciphertext,
+    ) -> Result<(), CheckError> {
         let final_operation_count = ct_left.degree.0 + ct_right.degree.0;
-        final_operation_count <= self.max_degree.0
+        if final_operation_count > self.max_degree.0 {
+            return Err(CarryFull);
+        }
+
+        self.max_noise_level
+            .validate(ct_left.noise_level + ct_right.noise_level)
     }
 
     /// Compute homomorphically an addition between two ciphertexts encrypting integer values.
@@ -325,12 +350,15 @@ impl ServerKey {
         ct_left: &Ciphertext,
         ct_right: &Ciphertext,
     ) -> Result<Ciphertext, CheckError> {
-        if self.is_add_possible(ct_left, ct_right) {
-            let ct_result = self.unchecked_add(ct_left, ct_right);
-            Ok(ct_result)
-        } else {
-            Err(CarryFull)
-        }
+        let (lhs, rhs) =
+            self.check_bivariate_with_noise_policy(ct_left, ct_right, |lhs, rhs| {
+                self.check_add_possible(lhs, rhs)
+            })?;
+        let ct_result = self.unchecked_add(
+            lhs.as_ref().unwrap_or(ct_left),
+            rhs.as_ref().unwrap_or(ct_right),
+        );
+        Ok(ct_result)
     }
 
     /// Compute homomorphically an addition between two ciphertexts encrypting integer values.
@@ -382,12 +410,15 @@ impl ServerKey {
         ct_left: &mut Ciphertext,
         ct_right: &Ciphertext,
     ) -> Result<(), CheckError> {
-        if self.is_add_possible(ct_left, ct_right) {
-            self.unchecked_add_assign(ct_left, ct_right);
-            Ok(())
-        } else {
-            Err(CarryFull)
+        let (lhs, rhs) =
+            self.check_bivariate_with_noise_policy(ct_left, ct_right, |lhs, rhs| {
+                self.check_add_possible(lhs, rhs)
+            })?;
+        if let Some(lhs) = lhs {
+            *ct_left = lhs;
         }
+        self.unchecked_add_assign(ct_left, rhs.as_ref().unwrap_or(ct_right));
+        Ok(())
     }
 
     /// Compute homomorphically an addition between two ciphertexts encrypting integer values.
@@ -490,4 +521,93 @@ impl ServerKey {
             engine.smart_add_assign(self, ct_left, ct_right).unwrap()
         })
     }
+
+    /// Compute homomorphically the sum of many ciphertexts encrypting integer values.
+    ///
+    /// Rather than folding the additions from left to right, operands are always added
+    /// starting from the two with the smallest degrees, which builds a balanced addition tree
+    /// and uses the carry space as much as possible before a bootstrap is required.
+    ///
+    /// When no pair of operands can be added anymore, the operand with the highest degree
+    /// gets its carry cleaned.
+    ///
+    /// The result has its carry empty and encrypts the sum modulo the message modulus.
+    /// The returned [SumManyStats] give the number of additions and bootstraps performed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let msgs = [3, 2, 1, 3, 0, 2];
+    ///
+    /// let cts = msgs.iter().map(|msg| cks.encrypt(*msg)).collect::<Vec<_>>();
+    /// let refs = cts.iter().collect::<Vec<_>>();
+    ///
+    /// // Compute homomorphically the sum:
+    /// let (ct_res, stats) = sks.sum_many(&refs);
+    ///
+    /// // Decrypt:
+    /// let res = cks.decrypt(&ct_res);
+    /// let modulus = cks.parameters.message_modulus().0 as u64;
+    /// assert_eq!(msgs.iter().sum::<u64>() % modulus, res);
+    /// assert_eq!(stats.additions, msgs.len() - 1);
+    /// ```
+    pub fn sum_many(&self, cts: &[&Ciphertext]) -> (Ciphertext, SumManyStats) {
+        let mut stats = SumManyStats::default();
+
+        if cts.is_empty() {
+            return (self.create_trivial(0), stats);
+        }
+
+        let mut slots = cts.iter().map(|ct| Some((*ct).clone())).collect::<Vec<_>>();
+        // Min heap of (degree, slot index)
+        let mut heap = slots
+            .iter()
+            .enumerate()
+            .map(|(i, ct)| Reverse((ct.as_ref().unwrap().degree.0, i)))
+            .collect::<BinaryHeap<_>>();
+
+        while heap.len() > 1 {
+            let Reverse((_, i)) = heap.pop().unwrap();
+            let Reverse((_, j)) = heap.pop().unwrap();
+            let mut smallest = slots[i].take().unwrap();
+            let mut second = slots[j].take().unwrap();
+
+            if !self.is_add_possible(&smallest, &second) {
+                // No other pair can be added, so clean the highest degree first
+                // and only clean the other one if still needed
+                self.message_extract_assign(&mut second);
+                stats.bootstraps += 1;
+
+                if !self.is_add_possible(&smallest, &second) {
+                    self.message_extract_assign(&mut smallest);
+                    stats.bootstraps += 1;
+                }
+                assert!(
+                    self.is_add_possible(&smallest, &second),
+                    "The parameters do not allow to add two ciphertexts with empty carries"
+                );
+            }
+
+            self.unchecked_add_assign(&mut smallest, &second);
+            stats.additions += 1;
+
+            heap.push(Reverse((smallest.degree.0, i)));
+            slots[i] = Some(smallest);
+        }
+
+        let Reverse((_, i)) = heap.pop().unwrap();
+        let mut result = slots[i].take().unwrap();
+        if !result.is_clean() {
+            self.message_extract_assign(&mut result);
+            stats.bootstraps += 1;
+        }
+
+        (result, stats)
+    }
 }
@@ -1,6 +1,5 @@
 use super::ServerKey;
 use crate::shortint::engine::ShortintEngine;
-use crate::shortint::CheckError::CarryFull;
 use crate::shortint::{CheckError, Ciphertext};
 
 impl ServerKey {
@@ -113,11 +112,11 @@ impl ServerKey {
     pub fn bitand_assign(&self, ct_left: &mut Ciphertext, ct_right: &Ciphertext) {
         let tmp_rhs: Ciphertext;
 
-        if !ct_left.carry_is_empty() {
+        if !ct_left.is_clean() {
             self.message_extract_assign(ct_left);
         }
 
-        let rhs = if ct_right.carry_is_empty() {
+        let rhs = if ct_right.is_clean() {
             ct_right
         } else {
             tmp_rhs = self.message_extract(ct_right);
@@ -262,12 +261,15 @@ impl ServerKey {
         ct_left: &Ciphertext,
         ct_right: &Ciphertext,
     ) -> Result<Ciphertext, CheckError> {
-        if self.is_functional_bivariate_pbs_possible(ct_left, ct_right) {
-            let ct_result = self.unchecked_bitand(ct_left, ct_right);
-            Ok(ct_result)
-        } else {
-            Err(CarryFull)
-        }
+        let (lhs, rhs) =
+            self.check_bivariate_with_noise_policy(ct_left, ct_right, |lhs, rhs| {
+                self.check_functional_bivariate_pbs_possible(lhs, rhs)
+            })?;
+        let ct_result = self.unchecked_bitand(
+            lhs.as_ref().unwrap_or(ct_left),
+            rhs.as_ref().unwrap_or(ct_right),
+        );
+        Ok(ct_result)
     }
 
     /// Compute bitwise AND between two ciphertexts without checks.
@@ -319,12 +321,15 @@ impl ServerKey {
         ct_left: &mut Ciphertext,
         ct_right: &Ciphertext,
     ) -> Result<(), CheckError> {
-        if self.is_functional_bivariate_pbs_possible(ct_left, ct_right) {
-            self.unchecked_bitand_assign(ct_left, ct_right);
-            Ok(())
-        } else {
-            Err(CarryFull)
+        let (lhs, rhs) =
+            self.check_bivariate_with_noise_policy(ct_left, ct_right, |lhs, rhs| {
+                self.check_functional_bivariate_pbs_possible(lhs, rhs)
+            })?;
+        if let Some(lhs) = lhs {
+            *ct_left = lhs;
         }
+        self.unchecked_bitand_assign(ct_left, rhs.as_ref().unwrap_or(ct_right));
+        Ok(())
     }
 
     /// Compute homomorphically an AND between two ciphertexts encrypting integer values.
@@ -537,11 +542,11 @@ impl ServerKey {
     pub fn bitxor_assign(&self, ct_left: &mut Ciphertext, ct_right: &Ciphertext) {
         let tmp_rhs: Ciphertext;
 
-        if !ct_left.carry_is_empty() {
+        if !ct_left.is_clean() {
             self.message_extract_assign(ct_left);
         }
 
-        let rhs = if ct_right.carry_is_empty() {
+        let rhs = if ct_right.is_clean() {
             ct_right
         } else {
             tmp_rhs = self.message_extract(ct_right);
@@ -690,12 +695,15 @@ impl ServerKey {
         ct_left: &Ciphertext,
         ct_right: &Ciphertext,
     ) -> Result<Ciphertext, CheckError> {
-        if self.is_functional_bivariate_pbs_possible(ct_left, ct_right) {
-            let ct_result = self.unchecked_bitxor(ct_left, ct_right);
-            Ok(ct_result)
-        } else {
-            Err(CarryFull)
-        }
+        let (lhs, rhs) =
+            self.check_bivariate_with_noise_policy(ct_left, ct_right, |lhs, rhs| {
+                self.check_functional_bivariate_pbs_possible(lhs, rhs)
+            })?;
+        let ct_result = self.unchecked_bitxor(
+            lhs.as_ref().unwrap_or(ct_left),
+            rhs.as_ref().unwrap_or(ct_right),
+        );
+        Ok(ct_result)
     }
 
     /// Compute bitwise XOR between two ciphertexts without checks.
@@ -747,12 +755,15 @@ impl ServerKey {
         ct_left: &mut Ciphertext,
         ct_right: &Ciphertext,
     ) -> Result<(), CheckError> {
-        if self.is_functional_bivariate_pbs_possible(ct_left, ct_right) {
-            self.unchecked_bitxor_assign(ct_left, ct_right);
-            Ok(())
-        } else {
-            Err(CarryFull)
+        let (lhs, rhs) =
+            self.check_bivariate_with_noise_policy(ct_left, ct_right, |lhs, rhs| {
+                self.check_functional_bivariate_pbs_possible(lhs, rhs)
+            })?;
+        if let Some(lhs) = lhs {
+            *ct_left = lhs;
         }
+        self.unchecked_bitxor_assign(ct_left, rhs.as_ref().unwrap_or(ct_right));
+        Ok(())
     }
 
     /// Compute homomorphically an XOR between two ciphertexts encrypting integer values.
@@ -965,11 +976,11 @@ impl ServerKey {
     pub fn bitor_assign(&self, ct_left: &mut Ciphertext, ct_right: &Ciphertext) {
         let tmp_rhs: Ciphertext;
 
-        if !ct_left.carry_is_empty() {
+        if !ct_left.is_clean() {
             self.message_extract_assign(ct_left);
         }
 
-        let rhs = if ct_right.carry_is_empty() {
+        let rhs = if ct_right.is_clean() {
             ct_right
         } else {
             tmp_rhs = self.message_extract(ct_right);
@@ -1120,12 +1131,15 @@ impl ServerKey {
         ct_left: &Ciphertext,
         ct_right: &Ciphertext,
     ) -> Result<Ciphertext, CheckError> {
-        if self.is_functional_bivariate_pbs_possible(ct_left, ct_right) {
-            let ct_result = self.unchecked_bitor(ct_left, ct_right);
-            Ok(ct_result)
-        } else {
-            Err(CarryFull)
-        }
+        let (lhs, rhs) =
+            self.check_bivariate_with_noise_policy(ct_left, ct_right, |lhs, rhs| {
+                self.check_functional_bivariate_pbs_possible(lhs, rhs)
+            })?;
+        let ct_result = self.unchecked_bitor(
+            lhs.as_ref().unwrap_or(ct_left),
+            rhs.as_ref().unwrap_or(ct_right),
+        );
+        Ok(ct_result)
     }
 
     /// Compute bitwise OR between two ciphertexts without checks.
@@ -1177,12 +1191,15 @@ impl ServerKey {
         ct_left: &mut Ciphertext,
         ct_right: &Ciphertext,
     ) -> Result<(), CheckError> {
-        if self.is_functional_bivariate_pbs_possible(ct_left, ct_right) {
-            self.unchecked_bitor_assign(ct_left, ct_right);
-            Ok(())
-        } else {
-            Err(CarryFull)
+        let (lhs, rhs) =
+            self.check_bivariate_with_noise_policy(ct_left, ct_right, |lhs, rhs| {
+                self.check_functional_bivariate_pbs_possible(lhs, rhs)
+            })?;
+        if let Some(lhs) = lhs {
+            *ct_left = lhs;
         }
+        self.unchecked_bitor_assign(ct_left, rhs.as_ref().unwrap_or(ct_right));
+        Ok(())
     }
 
     /// Compute homomorphically an OR between two ciphertexts encrypting integer values.
@@ -1,7 +1,6 @@
 use super::ServerKey;
 use crate::shortint::engine::ShortintEngine;
 use crate::shortint::server_key::CheckError;
-use crate::shortint::server_key::CheckError::CarryFull;
 use crate::shortint::Ciphertext;
 
 // # Note:
@@ -62,14 +61,14 @@ impl ServerKey {
         let tmp_lhs: Ciphertext;
         let tmp_rhs: Ciphertext;
 
-        let lhs = if ct_left.carry_is_empty() {
+        let lhs = if ct_left.is_clean() {
             ct_left
         } else {
             tmp_lhs = self.message_extract(ct_left);
             &tmp_lhs
         };
 
-        let rhs = if ct_right.carry_is_empty() {
+        let rhs = if ct_right.is_clean() {
             ct_right
         } else {
             tmp_rhs = self.message_extract(ct_right);
@@ -171,11 +170,14 @@ impl ServerKey {
         ct_left: &Ciphertext,
         ct_right: &Ciphertext,
     ) -> Result<Ciphertext, CheckError> {
-        if self.is_functional_bivariate_pbs_possible(ct_left, ct_right) {
-            Ok(self.unchecked_greater(ct_left, ct_right))
-        } else {
-            Err(CarryFull)
-        }
+        let (lhs, rhs) =
+            self.check_bivariate_with_noise_policy(ct_left, ct_right, |lhs, rhs| {
+                self.check_functional_bivariate_pbs_possible(lhs, rhs)
+            })?;
+        Ok(self.unchecked_greater(
+            lhs.as_ref().unwrap_or(ct_left),
+            rhs.as_ref().unwrap_or(ct_right),
+        ))
     }
 
     /// Compute homomorphically a `>` between two ciphertexts encrypting integer values.
@@ -276,14 +278,14 @@ impl ServerKey {
         let tmp_lhs: Ciphertext;
         let tmp_rhs: Ciphertext;
 
-        let lhs = if ct_left.carry_is_empty() {
+        let lhs = if ct_left.is_clean() {
             ct_left
         } else {
             tmp_lhs = self.message_extract(ct_left);
             &tmp_lhs
         };
 
-        let rhs = if ct_right.carry_is_empty() {
+        let rhs = if ct_right.is_clean() {
             ct_right
         } else {
             tmp_rhs = self.message_extract(ct_right);
@@ -444,11 +446,14 @@ impl ServerKey {
         ct_left: &Ciphertext,
         ct_right: &Ciphertext,
     ) -> Result<Ciphertext, CheckError> {
-        if self.is_functional_bivariate_pbs_possible(ct_left, ct_right) {
-            Ok(self.unchecked_greater_or_equal(ct_left, ct_right))
-        } else {
-            Err(CarryFull)
-        }
+        let (lhs, rhs) =
+            self.check_bivariate_with_noise_policy(ct_left, ct_right, |lhs, rhs| {
+                self.check_functional_bivariate_pbs_possible(lhs, rhs)
+            })?;
+        Ok(self.unchecked_greater_or_equal(
+            lhs.as_ref().unwrap_or(ct_left),
+            rhs.as_ref().unwrap_or(ct_right),
+        ))
     }
 
     /// Compute homomorphically a `<` between two ciphertexts encrypting integer values.
@@ -502,14 +507,14 @@ impl ServerKey {
         let tmp_lhs: Ciphertext;
         let tmp_rhs: Ciphertext;
 
-        let lhs = if ct_left.carry_is_empty() {
+        let lhs = if ct_left.is_clean() {
             ct_left
         } else {
             tmp_lhs = self.message_extract(ct_left);
             &tmp_lhs
         };
 
-        let rhs = if ct_right.carry_is_empty() {
+        let rhs = if ct_right.is_clean() {
             ct_right
         } else {
             tmp_rhs = self.message_extract(ct_right);
@@ -613,11 +618,14 @@ impl ServerKey {
         ct_left: &Ciphertext,
         ct_right: &Ciphertext,
     ) -> Result<Ciphertext, CheckError> {
-        if self.is_functional_bivariate_pbs_possible(ct_left, ct_right) {
-            Ok(self.unchecked_less(ct_left, ct_right))
-        } else {
-            Err(CarryFull)
-        }
+        let (lhs, rhs) =
+            self.check_bivariate_with_noise_policy(ct_left, ct_right, |lhs, rhs| {
+                self.check_functional_bivariate_pbs_possible(lhs, rhs)
+            })?;
+        Ok(self.unchecked_less(
+            lhs.as_ref().unwrap_or(ct_left),
+            rhs.as_ref().unwrap_or(ct_right),
+        ))
     }
 
     /// Compute homomorphically a `<` between two ciphertexts encrypting integer values.
@@ -718,14 +726,14 @@ impl ServerKey {
         let tmp_lhs: Ciphertext;
         let tmp_rhs: Ciphertext;
 
-        let lhs = if ct_left.carry_is_empty() {
+        let lhs = if ct_left.is_clean() {
             ct_left
         } else {
             tmp_lhs = self.message_extract(ct_left);
             &tmp_lhs
         };
 
-        let rhs = if ct_right.carry_is_empty() {
+        let rhs = if ct_right.is_clean() {
             ct_right
         } else {
             tmp_rhs = self.message_extract(ct_right);
@@ -833,11 +841,14 @@ impl ServerKey {
         ct_left: &Ciphertext,
         ct_right: &Ciphertext,
     ) -> Result<Ciphertext, CheckError> {
-        if self.is_functional_bivariate_pbs_possible(ct_left, ct_right) {
-            Ok(self.unchecked_less(ct_left, ct_right))
-        } else {
-            Err(CarryFull)
-        }
+        let (lhs, rhs) =
+            self.check_bivariate_with_noise_policy(ct_left, ct_right, |lhs, rhs| {
+                self.check_functional_bivariate_pbs_possible(lhs, rhs)
+            })?;
+        Ok(self.unchecked_less(
+            lhs.as_ref().unwrap_or(ct_left),
+            rhs.as_ref().unwrap_or(ct_right),
+        ))
     }
 
     /// Compute homomorphically a `<=` between two ciphertexts encrypting integer values.
@@ -942,14 +953,14 @@ impl ServerKey {
         let tmp_lhs: Ciphertext;
         let tmp_rhs: Ciphertext;
 
-        let lhs = if ct_left.carry_is_empty() {
+        let lhs = if ct_left.is_clean() {
             ct_left
         } else {
             tmp_lhs = self.message_extract(ct_left);
             &tmp_lhs
         };
 
-        let rhs = if ct_right.carry_is_empty() {
+        let rhs = if ct_right.is_clean() {
             ct_right
         } else {
             tmp_rhs = self.message_extract(ct_right);
@@ -1051,11 +1062,14 @@ impl ServerKey {
         ct_left: &Ciphertext,
         ct_right: &Ciphertext,
     ) -> Result<Ciphertext, CheckError> {
-        if self.is_functional_bivariate_pbs_possible(ct_left, ct_right) {
-            Ok(self.unchecked_equal(ct_left, ct_right))
-        } else {
-            Err(CarryFull)
-        }
+        let (lhs, rhs) =
+            self.check_bivariate_with_noise_policy(ct_left, ct_right, |lhs, rhs| {
+                self.check_functional_bivariate_pbs_possible(lhs, rhs)
+            })?;
+        Ok(self.unchecked_equal(
+            lhs.as_ref().unwrap_or(ct_left),
+            rhs.as_ref().unwrap_or(ct_right),
+        ))
     }
 
     /// Compute homomorphically a `==` between two ciphertexts encrypting integer values.
@@ -1156,14 +1170,14 @@ impl ServerKey {
         let tmp_lhs: Ciphertext;
         let tmp_rhs: Ciphertext;
 
-        let lhs = if ct_left.carry_is_empty() {
+        let lhs = if ct_left.is_clean() {
             ct_left
         } else {
             tmp_lhs = self.message_extract(ct_left);
             &tmp_lhs
         };
 
-        let rhs = if ct_right.carry_is_empty() {
+        let rhs = if ct_right.is_clean() {
             ct_right
         } else {
             tmp_rhs = self.message_extract(ct_right);
@@ -1265,11 +1279,14 @@ impl ServerKey {
         ct_left: &Ciphertext,
         ct_right: &Ciphertext,
     ) -> Result<Ciphertext, CheckError> {
-        if self.is_functional_bivariate_pbs_possible(ct_left, ct_right) {
-            Ok(self.unchecked_not_equal(ct_left, ct_right))
-        } else {
-            Err(CarryFull)
-        }
+        let (lhs, rhs) =
+            self.check_bivariate_with_noise_policy(ct_left, ct_right, |lhs, rhs| {
+                self.check_functional_bivariate_pbs_possible(lhs, rhs)
+            })?;
+        Ok(self.unchecked_not_equal(
+            lhs.as_ref().unwrap_or(ct_left),
+            rhs.as_ref().unwrap_or(ct_right),
+        ))
     }
 
     /// Compute homomorphically a `!=` between two ciphertexts encrypting integer values.
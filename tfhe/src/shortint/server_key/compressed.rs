@@ -1,6 +1,6 @@
 //! Module with the definition of the CompressedServerKey.
 
-use super::MaxDegree;
+use super::{MaxDegree, MaxNoiseLevel, PbsBackend, ServerKey};
 use crate::core_crypto::prelude::*;
 use crate::shortint::engine::ShortintEngine;
 use crate::shortint::parameters::{CarryModulus, CiphertextModulus, MessageModulus};
@@ -44,7 +44,7 @@ impl ShortintCompressedBootstrappingKey {
 ///
 /// The server key is generated by the client and is meant to be published: the client
 /// sends it to the server so it can compute homomorphic circuits.
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct CompressedServerKey {
     pub key_switching_key: SeededLweKeyswitchKeyOwned<u64>,
     pub bootstrapping_key: ShortintCompressedBootstrappingKey,
@@ -54,10 +54,120 @@ pub struct CompressedServerKey {
     pub carry_modulus: CarryModulus,
     // Maximum number of operations that can be done before emptying the operation buffer
     pub max_degree: MaxDegree,
+    // Maximum noise level the ciphertexts can reach before a PBS is required
+    pub max_noise_level: MaxNoiseLevel,
     pub ciphertext_modulus: CiphertextModulus,
     pub pbs_order: PBSOrder,
 }
 
+// The compressed server key is serialized through a versioned enum so that the format can evolve
+// while old keys stay readable, the borrowed variant avoids cloning the key to serialize it.
+#[derive(Serialize)]
+struct CompressedServerKeyV0Ref<'a> {
+    key_switching_key: &'a SeededLweKeyswitchKeyOwned<u64>,
+    bootstrapping_key: &'a ShortintCompressedBootstrappingKey,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+    max_degree: MaxDegree,
+    max_noise_level: MaxNoiseLevel,
+    ciphertext_modulus: CiphertextModulus,
+    pbs_order: PBSOrder,
+}
+
+#[derive(Serialize)]
+enum SerializableCompressedServerKey<'a> {
+    V0(CompressedServerKeyV0Ref<'a>),
+}
+
+#[derive(Deserialize)]
+struct CompressedServerKeyV0 {
+    key_switching_key: SeededLweKeyswitchKeyOwned<u64>,
+    bootstrapping_key: ShortintCompressedBootstrappingKey,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+    max_degree: MaxDegree,
+    max_noise_level: MaxNoiseLevel,
+    ciphertext_modulus: CiphertextModulus,
+    pbs_order: PBSOrder,
+}
+
+#[derive(Deserialize)]
+enum DeserializableCompressedServerKey {
+    V0(CompressedServerKeyV0),
+}
+
+impl Serialize for CompressedServerKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        SerializableCompressedServerKey::V0(CompressedServerKeyV0Ref {
+            key_switching_key: &self.key_switching_key,
+            bootstrapping_key: &self.bootstrapping_key,
+            message_modulus: self.message_modulus,
+            carry_modulus: self.carry_modulus,
+            max_degree: self.max_degree,
+            max_noise_level: self.max_noise_level,
+            ciphertext_modulus: self.ciphertext_modulus,
+            pbs_order: self.pbs_order,
+        })
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for CompressedServerKey {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        DeserializableCompressedServerKey::deserialize(deserializer).map(
+            |versioned| match versioned {
+                DeserializableCompressedServerKey::V0(key) => Self {
+                    key_switching_key: key.key_switching_key,
+                    bootstrapping_key: key.bootstrapping_key,
+                    message_modulus: key.message_modulus,
+                    carry_modulus: key.carry_modulus,
+                    max_degree: key.max_degree,
+                    max_noise_level: key.max_noise_level,
+                    ciphertext_modulus: key.ciphertext_modulus,
+                    pbs_order: key.pbs_order,
+                },
+            },
+        )
+    }
+}
+
+/// A compressed server key serialized before the server keys were versioned, without a maximum
+/// noise level, see [LegacyServerKey](super::LegacyServerKey).
+#[derive(Debug, Deserialize)]
+pub struct LegacyCompressedServerKey {
+    key_switching_key: SeededLweKeyswitchKeyOwned<u64>,
+    bootstrapping_key: ShortintCompressedBootstrappingKey,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+    max_degree: MaxDegree,
+    ciphertext_modulus: CiphertextModulus,
+    pbs_order: PBSOrder,
+}
+
+impl From<LegacyCompressedServerKey> for CompressedServerKey {
+    fn from(legacy: LegacyCompressedServerKey) -> Self {
+        Self {
+            key_switching_key: legacy.key_switching_key,
+            bootstrapping_key: legacy.bootstrapping_key,
+            message_modulus: legacy.message_modulus,
+            carry_modulus: legacy.carry_modulus,
+            max_degree: legacy.max_degree,
+            max_noise_level: MaxNoiseLevel::from_msg_carry_modulus(
+                legacy.message_modulus,
+                legacy.carry_modulus,
+            ),
+            ciphertext_modulus: legacy.ciphertext_modulus,
+            pbs_order: legacy.pbs_order,
+        }
+    }
+}
+
 impl CompressedServerKey {
     /// Generate a compressed server key.
     ///
@@ -87,4 +197,15 @@ impl CompressedServerKey {
                 .unwrap()
         })
     }
+
+    /// Decompress the key into a [`ServerKey`] whose programmable bootstraps use the chosen
+    /// [`PbsBackend`], whatever the [deterministic mode](ShortintEngine::set_deterministic) of
+    /// the engines.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pbs_backend` is [`PbsBackend::Ntt`] and the key is a multi bit PBS key.
+    pub fn decompress_with_pbs_backend(self, pbs_backend: PbsBackend) -> ServerKey {
+        ServerKey::from_compressed(self, pbs_backend)
+    }
 }
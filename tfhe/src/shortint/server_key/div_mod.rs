@@ -122,11 +122,11 @@ impl ServerKey {
     pub fn div_assign(&self, ct_left: &mut Ciphertext, ct_right: &Ciphertext) {
         let tmp_rhs: Ciphertext;
 
-        if !ct_left.carry_is_empty() {
+        if !ct_left.is_clean() {
             self.message_extract_assign(ct_left);
         }
 
-        let rhs = if ct_right.carry_is_empty() {
+        let rhs = if ct_right.is_clean() {
             ct_right
         } else {
             tmp_rhs = self.message_extract(ct_right);
@@ -244,6 +244,126 @@ impl ServerKey {
         })
     }
 
+    /// Compute the quotient and the remainder of a division between two ciphertexts.
+    ///
+    /// The results are returned in _new_ ciphertexts, as `(quotient, remainder)`.
+    ///
+    /// # Warning
+    ///
+    /// /!\ A division by zero returns the input ciphertext maximum message value as quotient and
+    /// the numerator as remainder.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertext carries are empty and clears them if it's not the case and
+    /// the operation requires it. It outputs ciphertexts whose carries are always empty.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let clear_1 = 3;
+    /// let clear_2 = 2;
+    ///
+    /// // Encrypt two messages
+    /// let ct_1 = cks.encrypt(clear_1);
+    /// let ct_2 = cks.encrypt(clear_2);
+    ///
+    /// // Compute homomorphically a division
+    /// let (ct_q, ct_r) = sks.div_rem(&ct_1, &ct_2);
+    ///
+    /// // Decrypt
+    /// assert_eq!(cks.decrypt(&ct_q), clear_1 / clear_2);
+    /// assert_eq!(cks.decrypt(&ct_r), clear_1 % clear_2);
+    /// ```
+    pub fn div_rem(&self, ct_left: &Ciphertext, ct_right: &Ciphertext) -> (Ciphertext, Ciphertext) {
+        let tmp_lhs: Ciphertext;
+        let tmp_rhs: Ciphertext;
+
+        let lhs = if ct_left.is_clean() {
+            ct_left
+        } else {
+            tmp_lhs = self.message_extract(ct_left);
+            &tmp_lhs
+        };
+
+        let rhs = if ct_right.is_clean() {
+            ct_right
+        } else {
+            tmp_rhs = self.message_extract(ct_right);
+            &tmp_rhs
+        };
+
+        self.unchecked_div_rem(lhs, rhs)
+    }
+
+    /// Compute the quotient and the remainder of a division between two ciphertexts without
+    /// checks.
+    ///
+    /// The results are returned in _new_ ciphertexts, as `(quotient, remainder)`.
+    ///
+    /// Both results are computed from a single packing of the inputs. When the packed inputs fit
+    /// in half of the message and carry space, both results come out of a single blind rotation,
+    /// otherwise two lookup tables are applied.
+    ///
+    /// # Warning
+    ///
+    /// /!\ A division by zero returns the input ciphertext maximum message value as quotient and
+    /// the numerator as remainder.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::{
+    ///     PARAM_MESSAGE_2_CARRY_2_KS_PBS, PARAM_MESSAGE_2_CARRY_3_KS_PBS,
+    /// };
+    ///
+    /// // Generate the client key and the server key
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let clear_1 = 3;
+    /// let clear_2 = 2;
+    ///
+    /// // Encrypt two messages
+    /// let ct_1 = cks.encrypt(clear_1);
+    /// let ct_2 = cks.encrypt(clear_2);
+    ///
+    /// // Compute homomorphically a division
+    /// let (ct_q, ct_r) = sks.unchecked_div_rem(&ct_1, &ct_2);
+    ///
+    /// // Decrypt
+    /// assert_eq!(cks.decrypt(&ct_q), clear_1 / clear_2);
+    /// assert_eq!(cks.decrypt(&ct_r), clear_1 % clear_2);
+    ///
+    /// // With a larger carry space, a single blind rotation computes both results
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_3_KS_PBS);
+    ///
+    /// // Encrypt two messages
+    /// let ct_1 = cks.encrypt(clear_1);
+    /// let ct_2 = cks.encrypt(clear_2);
+    ///
+    /// // Compute homomorphically a division
+    /// let (ct_q, ct_r) = sks.unchecked_div_rem(&ct_1, &ct_2);
+    ///
+    /// // Decrypt
+    /// assert_eq!(cks.decrypt(&ct_q), clear_1 / clear_2);
+    /// assert_eq!(cks.decrypt(&ct_r), clear_1 % clear_2);
+    /// ```
+    pub fn unchecked_div_rem(
+        &self,
+        ct_left: &Ciphertext,
+        ct_right: &Ciphertext,
+    ) -> (Ciphertext, Ciphertext) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.unchecked_div_rem(self, ct_left, ct_right).unwrap()
+        })
+    }
+
     /// Compute a division between two ciphertexts.
     ///
     /// The result is returned in a _new_ ciphertext.
@@ -0,0 +1,77 @@
+use super::ServerKey;
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::{Ciphertext, PublicKey};
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Returns a copy of the ciphertext which cannot be linked to it.
+    ///
+    /// A fresh encryption of zero made with the public key is added to the ciphertext, so the copy
+    /// encrypts the same value with the same degree, but looks like an unrelated ciphertext to
+    /// anyone without the secret key. A server returning several outputs derived from the same
+    /// value can use it to hide that the outputs are equal.
+    ///
+    /// The noise of the copy is the noise of the input plus the noise of a public key encryption.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the public key does not encrypt under the same key as the ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_1_CARRY_1_KS_PBS;
+    /// use tfhe::shortint::{gen_keys, PublicKey};
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+    /// let pk = PublicKey::new(&cks);
+    ///
+    /// let ct = cks.encrypt(1);
+    /// let copy = sks.duplicate(&ct, &pk);
+    ///
+    /// assert_ne!(copy.ct, ct.ct);
+    /// assert_eq!(copy.degree, ct.degree);
+    /// assert_eq!(cks.decrypt(&copy), 1);
+    /// ```
+    pub fn duplicate(&self, ct: &Ciphertext, public_key: &PublicKey) -> Ciphertext {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.rerandomize_with_public_key(public_key, ct).unwrap()
+        })
+    }
+
+    /// Returns `count` copies of the ciphertext, which cannot be linked to it nor to each other.
+    ///
+    /// The copies are computed in parallel, see [`Self::duplicate`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_1_CARRY_1_KS_PBS;
+    /// use tfhe::shortint::{gen_keys, PublicKey};
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+    /// let pk = PublicKey::new(&cks);
+    ///
+    /// let ct = cks.encrypt(1);
+    /// let copies = sks.duplicate_many(&ct, 3, &pk);
+    ///
+    /// assert_eq!(copies.len(), 3);
+    /// assert_ne!(copies[0].ct, copies[1].ct);
+    /// for copy in &copies {
+    ///     assert_eq!(cks.decrypt(copy), 1);
+    /// }
+    /// ```
+    pub fn duplicate_many(
+        &self,
+        ct: &Ciphertext,
+        count: usize,
+        public_key: &PublicKey,
+    ) -> Vec<Ciphertext> {
+        (0..count)
+            .into_par_iter()
+            .map(|_| self.duplicate(ct, public_key))
+            .collect()
+    }
+}
@@ -0,0 +1,241 @@
+use super::{LookupTableCreationError, LookupTableOwned, ServerKey};
+use crate::core_crypto::commons::traits::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+impl ServerKey {
+    /// Returns the outputs of a lookup table, the output for the input `x` being at index `x`.
+    ///
+    /// There is one output per value of the message and carry space.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let acc = sks.generate_lookup_table(|x| x / 2);
+    /// let outputs = sks.lookup_table_outputs(&acc);
+    ///
+    /// assert_eq!(outputs, (0..16).map(|x| x / 2).collect::<Vec<_>>());
+    /// ```
+    pub fn lookup_table_outputs(&self, acc: &LookupTableOwned) -> Vec<u64> {
+        assert_eq!(
+            acc.acc.polynomial_size(),
+            self.bootstrapping_key.polynomial_size(),
+            "The lookup table was not generated for the parameters of this server key"
+        );
+
+        let modulus_sup = self.message_modulus.0 * self.carry_modulus.0;
+        let box_size = self.bootstrapping_key.polynomial_size().0 / modulus_sup;
+        let delta = (1_u64 << 63) / modulus_sup as u64;
+
+        // The accumulator is rotated by half a box, so the first coefficient of each box is the
+        // middle of the box of the input
+        let body = acc.acc.get_body();
+        let body = body.as_ref();
+        (0..modulus_sup)
+            .map(|input| body[input * box_size] / delta)
+            .collect()
+    }
+
+    /// Constructs the lookup table of `outer(inner(x))`, evaluated with a single programmable
+    /// bootstrapping instead of two.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let msg = 3;
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let square = sks.generate_lookup_table(|x| (x * x) % 16);
+    /// let message = sks.generate_lookup_table(|x| x % 4);
+    ///
+    /// let acc = sks.compose_lookup_tables(&message, &square).unwrap();
+    /// let ct_res = sks.apply_lookup_table(&ct, &acc);
+    ///
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, (msg * msg) % 4);
+    /// ```
+    pub fn compose_lookup_tables(
+        &self,
+        outer: &LookupTableOwned,
+        inner: &LookupTableOwned,
+    ) -> Result<LookupTableOwned, LookupTableCreationError> {
+        let outer_outputs = self.lookup_table_outputs(outer);
+        let inner_outputs = self.lookup_table_outputs(inner);
+        // The outputs of inner are the inputs of outer, they must be in the message and carry
+        // space
+        self.check_table_outputs(&inner_outputs)?;
+
+        let table = inner_outputs
+            .iter()
+            .map(|&output| outer_outputs[output as usize])
+            .collect::<Vec<_>>();
+        self.generate_lookup_table_from_vec(&table)
+    }
+
+    /// Constructs the lookup table of `f(x + offset)` from the lookup table of `f`, the addition
+    /// wrapping around the message and carry space.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let msg = 1;
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let double = sks.generate_lookup_table(|x| (2 * x) % 4);
+    ///
+    /// let acc = sks.lookup_table_with_input_offset(&double, 1).unwrap();
+    /// let ct_res = sks.apply_lookup_table(&ct, &acc);
+    ///
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, (2 * (msg + 1)) % 4);
+    /// ```
+    pub fn lookup_table_with_input_offset(
+        &self,
+        acc: &LookupTableOwned,
+        offset: u64,
+    ) -> Result<LookupTableOwned, LookupTableCreationError> {
+        let outputs = self.lookup_table_outputs(acc);
+        let modulus_sup = outputs.len() as u64;
+
+        let table = (0..modulus_sup)
+            .map(|input| outputs[((input + offset % modulus_sup) % modulus_sup) as usize])
+            .collect::<Vec<_>>();
+        self.generate_lookup_table_from_vec(&table)
+    }
+
+    /// Constructs the lookup table of `f(x) + offset` from the lookup table of `f`.
+    ///
+    /// Returns an error if an output would not fit in the message and carry space.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let msg = 2;
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let half = sks.generate_lookup_table(|x| x / 2);
+    ///
+    /// let acc = sks.lookup_table_with_output_offset(&half, 2).unwrap();
+    /// let ct_res = sks.apply_lookup_table(&ct, &acc);
+    ///
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, msg / 2 + 2);
+    ///
+    /// // 15 / 2 + 10 does not fit in the 4 bits of message and carry
+    /// assert!(sks.lookup_table_with_output_offset(&half, 10).is_err());
+    /// ```
+    pub fn lookup_table_with_output_offset(
+        &self,
+        acc: &LookupTableOwned,
+        offset: u64,
+    ) -> Result<LookupTableOwned, LookupTableCreationError> {
+        let table = self
+            .lookup_table_outputs(acc)
+            .iter()
+            .map(|output| output.saturating_add(offset))
+            .collect::<Vec<_>>();
+        self.generate_lookup_table_from_vec(&table)
+    }
+}
+
+/// A cache of lookup tables, keyed by an identifier of the function they evaluate.
+///
+/// The identifiers are chosen by the application. The cache can be serialized, so that the lookup
+/// tables of the frequently used functions are generated once and shipped to the workers instead
+/// of the functions themselves. All the lookup tables of a cache must be generated for the
+/// parameters of the [ServerKey] they are used with.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::shortint::gen_keys;
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+/// use tfhe::shortint::server_key::LookupTableCache;
+///
+/// // Generate the client key and the server key:
+/// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+///
+/// const CLAMP_ID: u64 = 0;
+///
+/// let mut cache = LookupTableCache::new();
+/// cache.get_or_insert_with(CLAMP_ID, || sks.generate_lookup_table(|x| x.min(3)));
+///
+/// // The cache is sent to a worker
+/// let serialized = bincode::serialize(&cache).unwrap();
+/// let cache: LookupTableCache = bincode::deserialize(&serialized).unwrap();
+///
+/// let msg = 2;
+/// let ct = cks.encrypt(msg);
+/// let ct_res = sks.apply_lookup_table(&ct, cache.get(CLAMP_ID).unwrap());
+///
+/// let dec = cks.decrypt(&ct_res);
+/// assert_eq!(dec, msg.min(3));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct LookupTableCache {
+    tables: HashMap<u64, LookupTableOwned>,
+}
+
+impl crate::named::Named for LookupTableCache {
+    const NAME: &'static str = "shortint::LookupTableCache";
+}
+
+impl LookupTableCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, function_id: u64) -> Option<&LookupTableOwned> {
+        self.tables.get(&function_id)
+    }
+
+    /// Inserts the lookup table of a function, returning the previous one if any
+    pub fn insert(&mut self, function_id: u64, acc: LookupTableOwned) -> Option<LookupTableOwned> {
+        self.tables.insert(function_id, acc)
+    }
+
+    /// Returns the lookup table of a function, generating it with `generate` if it is not
+    /// in the cache yet
+    pub fn get_or_insert_with<F>(&mut self, function_id: u64, generate: F) -> &LookupTableOwned
+    where
+        F: FnOnce() -> LookupTableOwned,
+    {
+        self.tables.entry(function_id).or_insert_with(generate)
+    }
+
+    pub fn remove(&mut self, function_id: u64) -> Option<LookupTableOwned> {
+        self.tables.remove(&function_id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.tables.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tables.is_empty()
+    }
+}
@@ -6,8 +6,11 @@ mod add;
 mod bitwise_op;
 mod comp_op;
 mod div_mod;
+mod duplicate;
+mod lookup_table;
 mod mul;
 mod neg;
+mod pbs_order;
 mod scalar_add;
 mod scalar_bitwise_op;
 mod scalar_mul;
@@ -15,8 +18,13 @@ mod scalar_sub;
 mod shift;
 mod sub;
 
+pub use add::SumManyStats;
+pub use lookup_table::LookupTableCache;
+
 pub mod compressed;
-pub use compressed::{CompressedServerKey, ShortintCompressedBootstrappingKey};
+pub use compressed::{
+    CompressedServerKey, LegacyCompressedServerKey, ShortintCompressedBootstrappingKey,
+};
 
 #[cfg(test)]
 mod tests;
@@ -30,21 +38,82 @@ use crate::core_crypto::commons::parameters::{
 };
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
-use crate::shortint::ciphertext::{Ciphertext, Degree};
+use crate::shortint::ciphertext::{Ciphertext, Degree, NoiseLevel};
 use crate::shortint::client_key::ClientKey;
 use crate::shortint::engine::ShortintEngine;
-use crate::shortint::parameters::{CarryModulus, CiphertextModulus, MessageModulus};
+use crate::shortint::parameters::{
+    CarryModulus, CiphertextModulus, MessageModulus, PBSParameters, ShortintParameterSet,
+};
+use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 use std::fmt::{Debug, Display, Formatter};
+use std::ops::ControlFlow;
 
 /// Maximum value that the degree can reach.
 #[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
 pub struct MaxDegree(pub usize);
 
-/// Error returned when the carry buffer is full.
+/// Maximum value that the [NoiseLevel] of a ciphertext can reach.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
+pub struct MaxNoiseLevel(usize);
+
+impl MaxNoiseLevel {
+    pub const fn new(value: usize) -> Self {
+        Self(value)
+    }
+
+    pub fn get(&self) -> usize {
+        self.0
+    }
+
+    /// Returns the maximum noise level supported by parameters with the given moduli
+    ///
+    /// It allows to fill the carry space by summing fresh ciphertexts, and to pack two fresh
+    /// ciphertexts for a bivariate PBS.
+    pub fn from_msg_carry_modulus(
+        message_modulus: MessageModulus,
+        carry_modulus: CarryModulus,
+    ) -> Self {
+        let max_sum_count =
+            (message_modulus.0 * carry_modulus.0 - 1) / (message_modulus.0 - 1).max(1);
+        Self(max_sum_count.max(carry_modulus.0 + 1))
+    }
+
+    /// Returns an error if `noise_level` exceeds the maximum
+    pub fn validate(&self, noise_level: NoiseLevel) -> Result<(), CheckError> {
+        if noise_level.get() > self.0 {
+            Err(CheckError::NoiseTooBig {
+                noise_level,
+                max_noise_level: *self,
+            })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// What the checked operations of a [ServerKey] do when their result would exceed its
+/// [MaxNoiseLevel].
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Default)]
+pub enum MaxNoiseLevelPolicy {
+    /// Return [CheckError::NoiseTooBig], the operands are left untouched
+    #[default]
+    ReturnError,
+    /// Bootstrap the operands which are above the [nominal](NoiseLevel::NOMINAL) noise level,
+    /// then do the operation if it is now possible
+    Bootstrap,
+}
+
+/// Error returned when an operation cannot be performed without exceeding the capacity of the
+/// ciphertexts.
 #[derive(Debug)]
 pub enum CheckError {
     CarryFull,
+    NoiseTooBig {
+        noise_level: NoiseLevel,
+        max_noise_level: MaxNoiseLevel,
+    },
 }
 
 impl Display for CheckError {
@@ -53,12 +122,94 @@ impl Display for CheckError {
             CheckError::CarryFull => {
                 write!(f, "The carry buffer is full")
             }
+            CheckError::NoiseTooBig {
+                noise_level,
+                max_noise_level,
+            } => {
+                write!(
+                    f,
+                    "The noise level {} would exceed the maximum noise level {}",
+                    noise_level.get(),
+                    max_noise_level.get()
+                )
+            }
         }
     }
 }
 
 impl std::error::Error for CheckError {}
 
+/// Error returned when a lookup table cannot be created from a table of outputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LookupTableCreationError {
+    InvalidTableLength { expected: usize, got: usize },
+    OutputTooLarge { input: usize, output: u64, max: u64 },
+}
+
+impl Display for LookupTableCreationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidTableLength { expected, got } => {
+                write!(f, "The table has {got} entries, {expected} were expected")
+            }
+            Self::OutputTooLarge { input, output, max } => {
+                write!(
+                    f,
+                    "The output {output} for the input {input} does not fit in the message \
+                    and carry space, max is {max}"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for LookupTableCreationError {}
+
+/// Error returned when a ciphertext cannot be sanitized by a server key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SanitizationError {
+    InvalidLweDimension {
+        expected: LweDimension,
+        got: LweDimension,
+    },
+    InvalidCiphertextModulus,
+}
+
+impl Display for SanitizationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidLweDimension { expected, got } => {
+                write!(
+                    f,
+                    "The LWE dimension of the ciphertext is {}, the server key expects {}",
+                    got.0, expected.0
+                )
+            }
+            Self::InvalidCiphertextModulus => {
+                write!(
+                    f,
+                    "The ciphertext modulus does not match the one of the server key"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for SanitizationError {}
+
+/// The transform used by the polynomial products of the programmable bootstrap of a
+/// [`ServerKey`], chosen when the key is generated or decompressed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PbsBackend {
+    /// Floating point FFT, the fastest
+    #[default]
+    Fft,
+    /// Integer NTT, slower but exact: its results are bit-exact on all platforms, and the non
+    /// native power of two ciphertext moduli do not lose precision. Only the classic PBS is
+    /// supported.
+    Ntt,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum ShortintBootstrappingKey {
     Classic(FourierLweBootstrapKeyOwned),
@@ -67,16 +218,23 @@ pub enum ShortintBootstrappingKey {
         thread_count: ThreadCount,
         deterministic_execution: bool,
     },
+    /// A classic PBS key in the NTT domain, generated for the [`PbsBackend::Ntt`] or in the
+    /// [deterministic mode](ShortintEngine::set_deterministic) of the engines
+    Ntt(NttLweBootstrapKeyOwned),
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-#[serde(bound(deserialize = "C: IntoContainerOwned"))]
-enum SerializableShortintBootstrappingKey<C: Container<Element = concrete_fft::c64>> {
+#[serde(bound(deserialize = "C: IntoContainerOwned, N: Deserialize<'de>"))]
+enum SerializableShortintBootstrappingKey<
+    C: Container<Element = concrete_fft::c64>,
+    N: Container<Element = u64>,
+> {
     Classic(FourierLweBootstrapKey<C>),
     MultiBit {
         fourier_bsk: FourierLweMultiBitBootstrapKey<C>,
         deterministic_execution: bool,
     },
+    Ntt(NttLweBootstrapKey<N>),
 }
 
 impl Serialize for ShortintBootstrappingKey {
@@ -96,6 +254,9 @@ impl Serialize for ShortintBootstrappingKey {
                 fourier_bsk: bsk.as_view(),
                 deterministic_execution: *deterministic_execution,
             },
+            ShortintBootstrappingKey::Ntt(bsk) => {
+                SerializableShortintBootstrappingKey::Ntt(bsk.as_view())
+            }
         }
         .serialize(serializer)
     }
@@ -132,6 +293,9 @@ impl<'de> Deserialize<'de> for ShortintBootstrappingKey {
                     deterministic_execution,
                 })
             }
+            SerializableShortintBootstrappingKey::Ntt(bsk) => {
+                Ok(ShortintBootstrappingKey::Ntt(bsk))
+            }
         }
     }
 }
@@ -143,6 +307,7 @@ impl ShortintBootstrappingKey {
             ShortintBootstrappingKey::MultiBit {
                 fourier_bsk: inner, ..
             } => inner.input_lwe_dimension(),
+            ShortintBootstrappingKey::Ntt(inner) => inner.input_lwe_dimension(),
         }
     }
 
@@ -152,6 +317,7 @@ impl ShortintBootstrappingKey {
             ShortintBootstrappingKey::MultiBit {
                 fourier_bsk: inner, ..
             } => inner.polynomial_size(),
+            ShortintBootstrappingKey::Ntt(inner) => inner.polynomial_size(),
         }
     }
 
@@ -161,6 +327,7 @@ impl ShortintBootstrappingKey {
             ShortintBootstrappingKey::MultiBit {
                 fourier_bsk: inner, ..
             } => inner.glwe_size(),
+            ShortintBootstrappingKey::Ntt(inner) => inner.glwe_size(),
         }
     }
 
@@ -170,6 +337,7 @@ impl ShortintBootstrappingKey {
             ShortintBootstrappingKey::MultiBit {
                 fourier_bsk: inner, ..
             } => inner.decomposition_base_log(),
+            ShortintBootstrappingKey::Ntt(inner) => inner.decomposition_base_log(),
         }
     }
 
@@ -179,6 +347,7 @@ impl ShortintBootstrappingKey {
             ShortintBootstrappingKey::MultiBit {
                 fourier_bsk: inner, ..
             } => inner.decomposition_level_count(),
+            ShortintBootstrappingKey::Ntt(inner) => inner.decomposition_level_count(),
         }
     }
 
@@ -188,6 +357,7 @@ impl ShortintBootstrappingKey {
             ShortintBootstrappingKey::MultiBit {
                 fourier_bsk: inner, ..
             } => inner.output_lwe_dimension(),
+            ShortintBootstrappingKey::Ntt(inner) => inner.output_lwe_dimension(),
         }
     }
 
@@ -197,6 +367,7 @@ impl ShortintBootstrappingKey {
             ShortintBootstrappingKey::MultiBit {
                 fourier_bsk: bsk, ..
             } => bsk.as_view().data().len(),
+            ShortintBootstrappingKey::Ntt(bsk) => bsk.as_view().data().len(),
         }
     }
 
@@ -206,6 +377,17 @@ impl ShortintBootstrappingKey {
             ShortintBootstrappingKey::MultiBit {
                 fourier_bsk: bsk, ..
             } => std::mem::size_of_val(bsk.as_view().data()),
+            ShortintBootstrappingKey::Ntt(bsk) => std::mem::size_of_val(bsk.as_view().data()),
+        }
+    }
+
+    /// Return the transform used by the PBS computed with the key.
+    pub fn pbs_backend(&self) -> PbsBackend {
+        match self {
+            ShortintBootstrappingKey::Classic(_) | ShortintBootstrappingKey::MultiBit { .. } => {
+                PbsBackend::Fft
+            }
+            ShortintBootstrappingKey::Ntt(_) => PbsBackend::Ntt,
         }
     }
 
@@ -215,7 +397,7 @@ impl ShortintBootstrappingKey {
     /// Note: the classic PBS algorithm is always deterministic.
     pub fn deterministic_pbs_execution(&self) -> bool {
         match self {
-            ShortintBootstrappingKey::Classic(_) => true,
+            ShortintBootstrappingKey::Classic(_) | ShortintBootstrappingKey::Ntt(_) => true,
             ShortintBootstrappingKey::MultiBit {
                 deterministic_execution,
                 ..
@@ -230,20 +412,62 @@ impl ShortintBootstrappingKey {
     pub fn set_deterministic_pbs_execution(&mut self, new_deterministic_execution: bool) {
         match self {
             // Classic PBS is already deterministic no matter what
-            ShortintBootstrappingKey::Classic(_) => (),
+            ShortintBootstrappingKey::Classic(_) | ShortintBootstrappingKey::Ntt(_) => (),
             ShortintBootstrappingKey::MultiBit {
                 deterministic_execution,
                 ..
             } => *deterministic_execution = new_deterministic_execution,
         }
     }
+
+    /// Return the number of threads used by the multi bit PBS, `None` for the classic PBS.
+    pub fn multi_bit_pbs_thread_count(&self) -> Option<ThreadCount> {
+        match self {
+            ShortintBootstrappingKey::Classic(_) | ShortintBootstrappingKey::Ntt(_) => None,
+            ShortintBootstrappingKey::MultiBit { thread_count, .. } => Some(*thread_count),
+        }
+    }
+
+    /// Set the number of threads used by the multi bit PBS.
+    ///
+    /// Note: calling this function on a [`ServerKey`] made from
+    /// [`super::ClassicPBSParameters`] is a no-op.
+    pub fn set_multi_bit_pbs_thread_count(&mut self, new_thread_count: ThreadCount) {
+        assert!(new_thread_count.0 > 0, "ThreadCount must be greater than 0");
+        match self {
+            ShortintBootstrappingKey::Classic(_) | ShortintBootstrappingKey::Ntt(_) => (),
+            ShortintBootstrappingKey::MultiBit { thread_count, .. } => {
+                *thread_count = new_thread_count;
+            }
+        }
+    }
+
+    /// Use the fastest number of threads for the multi bit PBS on this machine, see
+    /// [`autotune_multi_bit_pbs_thread_count`].
+    ///
+    /// Note: calling this function on a [`ServerKey`] made from
+    /// [`super::ClassicPBSParameters`] is a no-op.
+    pub fn autotune_multi_bit_pbs_thread_count(&mut self) {
+        match self {
+            ShortintBootstrappingKey::Classic(_) | ShortintBootstrappingKey::Ntt(_) => (),
+            ShortintBootstrappingKey::MultiBit {
+                fourier_bsk,
+                thread_count,
+                ..
+            } => {
+                *thread_count = autotune_multi_bit_pbs_thread_count(
+                    MultiBitPbsThreadCountKey::from_bootstrap_key(fourier_bsk),
+                );
+            }
+        }
+    }
 }
 
 /// A structure containing the server public key.
 ///
 /// The server key is generated by the client and is meant to be published: the client
 /// sends it to the server so it can compute homomorphic circuits.
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct ServerKey {
     pub key_switching_key: LweKeyswitchKeyOwned<u64>,
     pub bootstrapping_key: ShortintBootstrappingKey,
@@ -253,14 +477,139 @@ pub struct ServerKey {
     pub carry_modulus: CarryModulus,
     // Maximum number of operations that can be done before emptying the operation buffer
     pub max_degree: MaxDegree,
+    // Maximum noise level the ciphertexts can reach before a PBS is required
+    pub max_noise_level: MaxNoiseLevel,
+    // What the checked operations do when the maximum noise level would be exceeded
+    pub(crate) max_noise_level_policy: MaxNoiseLevelPolicy,
     // Modulus use for computations on the ciphertext
     pub ciphertext_modulus: CiphertextModulus,
     pub pbs_order: PBSOrder,
 }
 
+// The server key is serialized through a versioned enum so that the format can evolve while old
+// keys stay readable, the borrowed variant avoids cloning the key to serialize it. The
+// MaxNoiseLevelPolicy is a choice of the server, it is not serialized.
+#[derive(Serialize)]
+struct ServerKeyV0Ref<'a> {
+    key_switching_key: &'a LweKeyswitchKeyOwned<u64>,
+    bootstrapping_key: &'a ShortintBootstrappingKey,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+    max_degree: MaxDegree,
+    max_noise_level: MaxNoiseLevel,
+    ciphertext_modulus: CiphertextModulus,
+    pbs_order: PBSOrder,
+}
+
+#[derive(Serialize)]
+enum SerializableServerKey<'a> {
+    V0(ServerKeyV0Ref<'a>),
+}
+
+#[derive(Deserialize)]
+struct ServerKeyV0 {
+    key_switching_key: LweKeyswitchKeyOwned<u64>,
+    bootstrapping_key: ShortintBootstrappingKey,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+    max_degree: MaxDegree,
+    max_noise_level: MaxNoiseLevel,
+    ciphertext_modulus: CiphertextModulus,
+    pbs_order: PBSOrder,
+}
+
+#[derive(Deserialize)]
+enum DeserializableServerKey {
+    V0(ServerKeyV0),
+}
+
+impl Serialize for ServerKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        SerializableServerKey::V0(ServerKeyV0Ref {
+            key_switching_key: &self.key_switching_key,
+            bootstrapping_key: &self.bootstrapping_key,
+            message_modulus: self.message_modulus,
+            carry_modulus: self.carry_modulus,
+            max_degree: self.max_degree,
+            max_noise_level: self.max_noise_level,
+            ciphertext_modulus: self.ciphertext_modulus,
+            pbs_order: self.pbs_order,
+        })
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ServerKey {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        DeserializableServerKey::deserialize(deserializer).map(|versioned| match versioned {
+            DeserializableServerKey::V0(key) => Self {
+                key_switching_key: key.key_switching_key,
+                bootstrapping_key: key.bootstrapping_key,
+                message_modulus: key.message_modulus,
+                carry_modulus: key.carry_modulus,
+                max_degree: key.max_degree,
+                max_noise_level: key.max_noise_level,
+                max_noise_level_policy: MaxNoiseLevelPolicy::default(),
+                ciphertext_modulus: key.ciphertext_modulus,
+                pbs_order: key.pbs_order,
+            },
+        })
+    }
+}
+
+/// A server key serialized before the server keys were versioned, without a maximum noise level.
+///
+/// These keys cannot be deserialized as a [ServerKey], deserialize them as a `LegacyServerKey`
+/// and convert them: they get the maximum noise level derived from their message and carry
+/// moduli.
+#[derive(Debug, Deserialize)]
+pub struct LegacyServerKey {
+    key_switching_key: LweKeyswitchKeyOwned<u64>,
+    bootstrapping_key: ShortintBootstrappingKey,
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+    max_degree: MaxDegree,
+    ciphertext_modulus: CiphertextModulus,
+    pbs_order: PBSOrder,
+}
+
+impl From<LegacyServerKey> for ServerKey {
+    fn from(legacy: LegacyServerKey) -> Self {
+        Self {
+            key_switching_key: legacy.key_switching_key,
+            bootstrapping_key: legacy.bootstrapping_key,
+            message_modulus: legacy.message_modulus,
+            carry_modulus: legacy.carry_modulus,
+            max_degree: legacy.max_degree,
+            max_noise_level: MaxNoiseLevel::from_msg_carry_modulus(
+                legacy.message_modulus,
+                legacy.carry_modulus,
+            ),
+            max_noise_level_policy: MaxNoiseLevelPolicy::default(),
+            ciphertext_modulus: legacy.ciphertext_modulus,
+            pbs_order: legacy.pbs_order,
+        }
+    }
+}
+
 impl ServerKey {
     pub fn conformance_params(&self) -> CiphertextConformanceParams {
-        let lwe_dim = match self.pbs_order {
+        self.conformance_params_for_pbs_order(self.pbs_order)
+    }
+
+    /// Returns the conformance parameters of ciphertexts using the given [`PBSOrder`] with this
+    /// server key, e.g. the ones obtained with [`Self::convert_pbs_order`]
+    pub fn conformance_params_for_pbs_order(
+        &self,
+        pbs_order: PBSOrder,
+    ) -> CiphertextConformanceParams {
+        let lwe_dim = match pbs_order {
             PBSOrder::KeyswitchBootstrap => self.key_switching_key.input_key_lwe_dimension(),
             PBSOrder::BootstrapKeyswitch => self.key_switching_key.output_key_lwe_dimension(),
         };
@@ -275,7 +624,7 @@ impl ServerKey {
             message_modulus: self.message_modulus,
             carry_modulus: self.carry_modulus,
             degree: Degree(self.message_modulus.0 - 1),
-            pbs_order: self.pbs_order,
+            pbs_order,
         }
     }
 }
@@ -292,17 +641,47 @@ fn ciphertexts_can_be_packed_without_exceeding_space(
     final_degree < lhs.carry_modulus.0 * lhs.message_modulus.0
 }
 
-#[derive(Clone, Debug, PartialEq)]
+/// The accumulator of a univariate function, evaluated by a programmable bootstrapping.
+///
+/// A lookup table only depends on the parameters of the [ServerKey] used to generate it, it can
+/// be serialized and sent to the workers sharing these parameters, see also
+/// [LookupTableCache].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[must_use]
 pub struct LookupTable<C: Container<Element = u64>> {
     pub acc: GlweCiphertext<C>,
     pub degree: Degree,
 }
 
+impl crate::named::Named for LookupTableOwned {
+    const NAME: &'static str = "shortint::LookupTable";
+}
+
 pub type LookupTableOwned = LookupTable<Vec<u64>>;
 pub type LookupTableMutView<'a> = LookupTable<&'a mut [u64]>;
 pub type LookupTableView<'a> = LookupTable<&'a [u64]>;
 
+/// A lookup table packing several univariate functions in a single accumulator.
+///
+/// The functions are stored one after the other in the accumulator, a single blind rotation
+/// evaluates all of them and each output is sample extracted at its own position. The price is a
+/// smaller input space: the inputs must have a degree at most `input_max_degree`.
+#[must_use]
+pub struct ManyLutAccumulator {
+    pub acc: GlweCiphertextOwned<u64>,
+    // Maximum degree of the input ciphertext
+    pub input_max_degree: Degree,
+    // Distance, in polynomial coefficients, between the outputs of two consecutive functions
+    pub sample_extraction_stride: usize,
+    pub per_function_output_degree: Vec<Degree>,
+}
+
+impl ManyLutAccumulator {
+    pub fn function_count(&self) -> usize {
+        self.per_function_output_degree.len()
+    }
+}
+
 #[must_use]
 pub struct BivariateLookupTable<C: Container<Element = u64>> {
     // A bivariate lookup table is an univariate loolookup table
@@ -351,6 +730,365 @@ impl ServerKey {
         })
     }
 
+    /// Generate a server key with a chosen maximum degree, calling `on_ggsw_encrypted` each time
+    /// one of the GGSW ciphertexts of the bootstrapping key is encrypted.
+    ///
+    /// Returns `None` if `on_ggsw_encrypted` stopped the generation, see
+    /// [`par_generate_lwe_bootstrap_key_with_progress`].
+    pub(crate) fn new_with_max_degree_and_progress(
+        cks: &ClientKey,
+        max_degree: MaxDegree,
+        on_ggsw_encrypted: &(dyn Fn() -> ControlFlow<()> + Sync),
+    ) -> Option<ServerKey> {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .new_server_key_with_progress(
+                    cks,
+                    max_degree,
+                    ShortintEngine::default_pbs_backend(),
+                    on_ggsw_encrypted,
+                )
+                .unwrap()
+        })
+    }
+
+    /// Generate a server key whose programmable bootstraps use the chosen [`PbsBackend`],
+    /// whatever the [deterministic mode](ShortintEngine::set_deterministic) of the engines.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pbs_backend` is [`PbsBackend::Ntt`] and the parameters are multi bit PBS
+    /// parameters.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::shortint::{ClientKey, PbsBackend, ServerKey};
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// let sks = ServerKey::new_with_pbs_backend(&cks, PbsBackend::Ntt);
+    /// assert_eq!(sks.pbs_backend(), PbsBackend::Ntt);
+    ///
+    /// let ct = cks.encrypt(3);
+    /// let lut = sks.generate_lookup_table(|x| (x * 3) % 4);
+    /// let res = sks.apply_lookup_table(&ct, &lut);
+    ///
+    /// assert_eq!(cks.decrypt(&res), 1);
+    /// ```
+    pub fn new_with_pbs_backend(cks: &ClientKey, pbs_backend: PbsBackend) -> ServerKey {
+        let max_value = cks.parameters.message_modulus().0 * cks.parameters.carry_modulus().0 - 1;
+        Self::new_with_max_degree_and_pbs_backend(cks, MaxDegree(max_value), pbs_backend)
+    }
+
+    /// Generate a server key with a chosen maximum degree, whose programmable bootstraps use the
+    /// chosen [`PbsBackend`], see [`Self::new_with_pbs_backend`].
+    pub fn new_with_max_degree_and_pbs_backend(
+        cks: &ClientKey,
+        max_degree: MaxDegree,
+        pbs_backend: PbsBackend,
+    ) -> ServerKey {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .new_server_key_with_max_degree_and_pbs_backend(cks, max_degree, pbs_backend)
+                .unwrap()
+        })
+    }
+
+    /// Return the transform used by the programmable bootstraps of the key.
+    pub fn pbs_backend(&self) -> PbsBackend {
+        self.bootstrapping_key.pbs_backend()
+    }
+
+    /// Construct a server key from its raw parts, e.g. keys generated outside of this crate by a
+    /// distributed key generation.
+    ///
+    /// The moduli, the maximum noise level, the ciphertext modulus and the [`PBSOrder`] of the
+    /// key are the ones of `parameters`, the max degree is the full message and carry space.
+    ///
+    /// # Panics
+    ///
+    /// Panics if:
+    /// - the dimensions or decomposition parameters of the bootstrapping key do not match the
+    ///   parameters,
+    /// - the bootstrapping key is a multi bit one and the parameters are not, or conversely,
+    /// - the dimensions or decomposition parameters of the keyswitch key do not match the
+    ///   parameters,
+    /// - the ciphertext modulus of the keyswitch key is not the one of the parameters.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::shortint::ServerKey;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let (bootstrapping_key, key_switching_key) = sks.into_raw_parts();
+    ///
+    /// let sks = ServerKey::from_raw_parts(
+    ///     bootstrapping_key,
+    ///     key_switching_key,
+    ///     PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// );
+    ///
+    /// let ct = cks.encrypt(3);
+    /// let ct_res = sks.scalar_mul(&ct, 3);
+    /// assert_eq!(cks.decrypt(&ct_res), 1);
+    /// ```
+    pub fn from_raw_parts<P>(
+        bootstrapping_key: ShortintBootstrappingKey,
+        key_switching_key: LweKeyswitchKeyOwned<u64>,
+        parameters: P,
+    ) -> Self
+    where
+        P: TryInto<ShortintParameterSet>,
+        <P as TryInto<ShortintParameterSet>>::Error: Debug,
+    {
+        let parameters: ShortintParameterSet = parameters
+            .try_into()
+            .unwrap_or_else(|err| panic!("Invalid parameters: {err:?}"));
+
+        if let Err(message) =
+            Self::check_raw_parts(&bootstrapping_key, &key_switching_key, &parameters)
+        {
+            panic!("{message}");
+        }
+
+        let message_modulus = parameters.message_modulus();
+        let carry_modulus = parameters.carry_modulus();
+
+        Self {
+            key_switching_key,
+            bootstrapping_key,
+            message_modulus,
+            carry_modulus,
+            max_degree: MaxDegree(message_modulus.0 * carry_modulus.0 - 1),
+            max_noise_level: parameters.max_noise_level(),
+            max_noise_level_policy: MaxNoiseLevelPolicy::default(),
+            ciphertext_modulus: parameters.ciphertext_modulus(),
+            pbs_order: parameters.encryption_key_choice().into(),
+        }
+    }
+
+    fn check_raw_parts(
+        bootstrapping_key: &ShortintBootstrappingKey,
+        key_switching_key: &LweKeyswitchKeyOwned<u64>,
+        parameters: &ShortintParameterSet,
+    ) -> Result<(), String> {
+        let big_lwe_dimension = parameters
+            .glwe_dimension()
+            .to_equivalent_lwe_dimension(parameters.polynomial_size());
+
+        let mismatch = |what: &str, key: &dyn Debug, param: &dyn Debug| {
+            Err(format!(
+                "Mismatch between the {what} and the parameters: {key:?} != {param:?}"
+            ))
+        };
+
+        if bootstrapping_key.input_lwe_dimension() != parameters.lwe_dimension() {
+            return mismatch(
+                "input LweDimension of the bootstrapping key",
+                &bootstrapping_key.input_lwe_dimension(),
+                &parameters.lwe_dimension(),
+            );
+        }
+        if bootstrapping_key.glwe_size() != parameters.glwe_dimension().to_glwe_size() {
+            return mismatch(
+                "GlweSize of the bootstrapping key",
+                &bootstrapping_key.glwe_size(),
+                &parameters.glwe_dimension().to_glwe_size(),
+            );
+        }
+        if bootstrapping_key.polynomial_size() != parameters.polynomial_size() {
+            return mismatch(
+                "PolynomialSize of the bootstrapping key",
+                &bootstrapping_key.polynomial_size(),
+                &parameters.polynomial_size(),
+            );
+        }
+        if bootstrapping_key.decomposition_base_log() != parameters.pbs_base_log() {
+            return mismatch(
+                "DecompositionBaseLog of the bootstrapping key",
+                &bootstrapping_key.decomposition_base_log(),
+                &parameters.pbs_base_log(),
+            );
+        }
+        if bootstrapping_key.decomposition_level_count() != parameters.pbs_level() {
+            return mismatch(
+                "DecompositionLevelCount of the bootstrapping key",
+                &bootstrapping_key.decomposition_level_count(),
+                &parameters.pbs_level(),
+            );
+        }
+
+        let key_grouping_factor = match bootstrapping_key {
+            ShortintBootstrappingKey::MultiBit { fourier_bsk, .. } => {
+                Some(fourier_bsk.grouping_factor())
+            }
+            ShortintBootstrappingKey::Classic(_) | ShortintBootstrappingKey::Ntt(_) => None,
+        };
+        let param_grouping_factor = match parameters.pbs_parameters() {
+            Some(PBSParameters::MultiBitPBS(multi_bit)) => Some(multi_bit.grouping_factor),
+            Some(PBSParameters::PBS(_)) | None => None,
+        };
+        if key_grouping_factor != param_grouping_factor {
+            return mismatch(
+                "LweBskGroupingFactor of the bootstrapping key",
+                &key_grouping_factor,
+                &param_grouping_factor,
+            );
+        }
+
+        if key_switching_key.input_key_lwe_dimension() != big_lwe_dimension {
+            return mismatch(
+                "input LweDimension of the keyswitch key",
+                &key_switching_key.input_key_lwe_dimension(),
+                &big_lwe_dimension,
+            );
+        }
+        if key_switching_key.output_key_lwe_dimension() != parameters.lwe_dimension() {
+            return mismatch(
+                "output LweDimension of the keyswitch key",
+                &key_switching_key.output_key_lwe_dimension(),
+                &parameters.lwe_dimension(),
+            );
+        }
+        if key_switching_key.decomposition_base_log() != parameters.ks_base_log() {
+            return mismatch(
+                "DecompositionBaseLog of the keyswitch key",
+                &key_switching_key.decomposition_base_log(),
+                &parameters.ks_base_log(),
+            );
+        }
+        if key_switching_key.decomposition_level_count() != parameters.ks_level() {
+            return mismatch(
+                "DecompositionLevelCount of the keyswitch key",
+                &key_switching_key.decomposition_level_count(),
+                &parameters.ks_level(),
+            );
+        }
+        if key_switching_key.ciphertext_modulus() != parameters.ciphertext_modulus() {
+            return mismatch(
+                "CiphertextModulus of the keyswitch key",
+                &key_switching_key.ciphertext_modulus(),
+                &parameters.ciphertext_modulus(),
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Deconstruct a server key into its bootstrapping key and its keyswitch key, see
+    /// [`Self::from_raw_parts`].
+    pub fn into_raw_parts(self) -> (ShortintBootstrappingKey, LweKeyswitchKeyOwned<u64>) {
+        let Self {
+            key_switching_key,
+            bootstrapping_key,
+            ..
+        } = self;
+
+        (bootstrapping_key, key_switching_key)
+    }
+
+    /// Returns what the checked operations do when their result would exceed the maximum noise
+    /// level.
+    pub fn max_noise_level_policy(&self) -> MaxNoiseLevelPolicy {
+        self.max_noise_level_policy
+    }
+
+    /// Sets what the checked operations do when their result would exceed the maximum noise
+    /// level.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::ciphertext::Degree;
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::shortint::server_key::MaxNoiseLevelPolicy;
+    /// use tfhe::shortint::Ciphertext;
+    ///
+    /// let (cks, mut sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// // The noise of a ciphertext built from raw parts is unknown, it has the maximum noise level
+    /// let ct = cks.encrypt(1);
+    /// let params = PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// let ct = Ciphertext::try_from_raw_parts(ct.ct, Degree(3), None, params).unwrap();
+    ///
+    /// // By default, the checked operations refuse to exceed the maximum noise level
+    /// assert!(sks.checked_add(&ct, &ct).is_err());
+    ///
+    /// // They can bootstrap the noisy operands instead
+    /// sks.set_max_noise_level_policy(MaxNoiseLevelPolicy::Bootstrap);
+    /// let res = sks.checked_add(&ct, &ct).unwrap();
+    /// assert_eq!(cks.decrypt_message_and_carry(&res), 2);
+    /// ```
+    pub fn set_max_noise_level_policy(&mut self, policy: MaxNoiseLevelPolicy) {
+        self.max_noise_level_policy = policy;
+    }
+
+    /// Bootstraps `ct` with the identity over the message and carry space if its noise level is
+    /// above the nominal one: its value and degree are kept.
+    fn refresh_noisy(&self, ct: &Ciphertext) -> Option<Ciphertext> {
+        if ct.noise_level() <= NoiseLevel::NOMINAL {
+            return None;
+        }
+
+        let acc = self.generate_lookup_table(|x| x);
+        let mut refreshed = self.apply_lookup_table(ct, &acc);
+        refreshed.degree = ct.degree;
+        Some(refreshed)
+    }
+
+    /// Runs the `check` of a checked operation on `ct`, applying the [MaxNoiseLevelPolicy].
+    ///
+    /// With [MaxNoiseLevelPolicy::Bootstrap], when the noise prevents the operation, a noisy
+    /// ciphertext is bootstrapped and checked again. The bootstrapped ciphertext is returned,
+    /// the operation must use it instead of `ct`.
+    pub(crate) fn check_with_noise_policy(
+        &self,
+        ct: &Ciphertext,
+        check: impl Fn(&Ciphertext) -> Result<(), CheckError>,
+    ) -> Result<Option<Ciphertext>, CheckError> {
+        match check(ct) {
+            Err(CheckError::NoiseTooBig { .. })
+                if self.max_noise_level_policy == MaxNoiseLevelPolicy::Bootstrap =>
+            {
+                let refreshed = self.refresh_noisy(ct);
+                check(refreshed.as_ref().unwrap_or(ct))?;
+                Ok(refreshed)
+            }
+            result => result.map(|()| None),
+        }
+    }
+
+    /// Same as [Self::check_with_noise_policy] for the checked operations with two operands.
+    pub(crate) fn check_bivariate_with_noise_policy(
+        &self,
+        ct_left: &Ciphertext,
+        ct_right: &Ciphertext,
+        check: impl Fn(&Ciphertext, &Ciphertext) -> Result<(), CheckError>,
+    ) -> Result<(Option<Ciphertext>, Option<Ciphertext>), CheckError> {
+        match check(ct_left, ct_right) {
+            Err(CheckError::NoiseTooBig { .. })
+                if self.max_noise_level_policy == MaxNoiseLevelPolicy::Bootstrap =>
+            {
+                let (lhs, rhs) = rayon::join(
+                    || self.refresh_noisy(ct_left),
+                    || self.refresh_noisy(ct_right),
+                );
+                check(
+                    lhs.as_ref().unwrap_or(ct_left),
+                    rhs.as_ref().unwrap_or(ct_right),
+                )?;
+                Ok((lhs, rhs))
+            }
+            result => result.map(|()| (None, None)),
+        }
+    }
+
     /// Constructs the lookup table given a function as input.
     ///
     /// # Example
@@ -385,6 +1123,24 @@ impl ServerKey {
         })
     }
 
+    /// Constructs a lookup table evaluating several univariate functions with a single
+    /// programmable bootstrapping, see [`ServerKey::apply_lookup_table_multi_output`].
+    ///
+    /// With `n` functions, the inputs must be strictly smaller than
+    /// `message_modulus * carry_modulus / n`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no function, or more functions than `message_modulus * carry_modulus`.
+    pub fn generate_many_lookup_table(
+        &self,
+        functions: &[&dyn Fn(u64) -> u64],
+    ) -> ManyLutAccumulator {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.generate_many_lookup_table(self, functions).unwrap()
+        })
+    }
+
     pub fn generate_lookup_table_bivariate_with_factor<F>(
         &self,
         f: F,
@@ -435,6 +1191,125 @@ impl ServerKey {
         })
     }
 
+    /// Constructs the lookup table of the function whose outputs are given by `table`, the output
+    /// for the input `x` being `table[x]`.
+    ///
+    /// The table either covers the whole message and carry space, with
+    /// `message_modulus * carry_modulus` entries, or only the message space, with
+    /// `message_modulus` entries, in which case the carries of the input are ignored.
+    ///
+    /// The degree of the lookup table is the largest output of the table.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let msg = 3;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// // e.g. a substitution box loaded from a file
+    /// let table = [2, 0, 3, 1];
+    ///
+    /// let acc = sks.generate_lookup_table_from_vec(&table).unwrap();
+    /// assert_eq!(acc.degree.0, 3);
+    /// let ct_res = sks.apply_lookup_table(&ct, &acc);
+    ///
+    /// let dec = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, table[msg as usize]);
+    /// ```
+    pub fn generate_lookup_table_from_vec(
+        &self,
+        table: &[u64],
+    ) -> Result<LookupTableOwned, LookupTableCreationError> {
+        let message_modulus = self.message_modulus.0;
+        let full_modulus = message_modulus * self.carry_modulus.0;
+        if table.len() != message_modulus && table.len() != full_modulus {
+            return Err(LookupTableCreationError::InvalidTableLength {
+                expected: full_modulus,
+                got: table.len(),
+            });
+        }
+        let degree = self.check_table_outputs(table)?;
+
+        // The message modulus divides the full modulus, so the carries are ignored for tables of
+        // the message space only
+        let mut acc = self.generate_lookup_table(|x| table[x as usize % table.len()]);
+        acc.degree = degree;
+        Ok(acc)
+    }
+
+    /// Constructs the lookup table of the bivariate function whose outputs are given by `table`,
+    /// the output for the inputs `(x, y)` being `table[x * message_modulus + y]`.
+    ///
+    /// The table must have `message_modulus * message_modulus` entries.
+    ///
+    /// The degree of the lookup table is the largest output of the table.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let msg_1 = 3;
+    /// let msg_2 = 2;
+    ///
+    /// let ct1 = cks.encrypt(msg_1);
+    /// let ct2 = cks.encrypt(msg_2);
+    ///
+    /// // The table of x * y, rows are indexed by x
+    /// let modulus = cks.parameters.message_modulus().0 as u64;
+    /// let table = (0..modulus)
+    ///     .flat_map(|x| (0..modulus).map(move |y| x * y))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let acc = sks.generate_lookup_table_bivariate_from_vec(&table).unwrap();
+    /// assert_eq!(acc.acc.degree.0, 9);
+    /// let ct_res = sks.unchecked_apply_lookup_table_bivariate(&ct1, &ct2, &acc);
+    ///
+    /// let dec = cks.decrypt_message_and_carry(&ct_res);
+    /// assert_eq!(dec, msg_1 * msg_2);
+    /// ```
+    pub fn generate_lookup_table_bivariate_from_vec(
+        &self,
+        table: &[u64],
+    ) -> Result<BivariateLookupTableOwned, LookupTableCreationError> {
+        let message_modulus = self.message_modulus.0;
+        if table.len() != message_modulus * message_modulus {
+            return Err(LookupTableCreationError::InvalidTableLength {
+                expected: message_modulus * message_modulus,
+                got: table.len(),
+            });
+        }
+        let degree = self.check_table_outputs(table)?;
+
+        let mut acc = self.generate_lookup_table_bivariate(|x, y| {
+            table[x as usize * message_modulus + y as usize]
+        });
+        acc.acc.degree = degree;
+        Ok(acc)
+    }
+
+    /// Returns the degree of the outputs of a table, checking they fit in the message and carry
+    /// space
+    fn check_table_outputs(&self, table: &[u64]) -> Result<Degree, LookupTableCreationError> {
+        let max = (self.message_modulus.0 * self.carry_modulus.0) as u64 - 1;
+        if let Some((input, &output)) = table.iter().enumerate().find(|(_, &output)| output > max) {
+            return Err(LookupTableCreationError::OutputTooLarge { input, output, max });
+        }
+
+        Ok(Degree(table.iter().copied().max().unwrap_or(0) as usize))
+    }
+
     /// Compute a keyswitch and programmable bootstrap.
     ///
     /// # Example
@@ -537,6 +1412,11 @@ impl ServerKey {
 
     /// Compute a keyswitch and programmable bootstrap.
     ///
+    /// The programmable bootstrap is skipped when the input has a nominal noise level, empty
+    /// carries, and the lookup table is the identity on the values its degree allows: the input
+    /// is then returned unchanged, see
+    /// [crate::shortint::engine::skipped_programmable_bootstrap_count].
+    ///
     /// # Example
     ///
     /// ```rust
@@ -570,6 +1450,134 @@ impl ServerKey {
         })
     }
 
+    /// Applies the same lookup table to all the ciphertexts of a slice.
+    ///
+    /// The ciphertexts are split in one chunk per rayon thread, and the buffers and the FFT plan
+    /// of a thread are prepared once for its whole chunk, instead of once per ciphertext as when
+    /// calling [`Self::apply_lookup_table_assign`] in a loop.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let msgs = [0, 1, 2, 3, 2, 1];
+    /// let modulus = cks.parameters.message_modulus().0 as u64;
+    ///
+    /// let mut cts = msgs.iter().map(|&msg| cks.encrypt(msg)).collect::<Vec<_>>();
+    ///
+    /// let f = |x: u64| (x * x) % modulus;
+    /// let acc = sks.generate_lookup_table(f);
+    /// sks.apply_lookup_table_many(&mut cts, &acc);
+    ///
+    /// for (ct, msg) in cts.iter().zip(msgs) {
+    ///     assert_eq!(cks.decrypt(ct), f(msg));
+    /// }
+    /// ```
+    pub fn apply_lookup_table_many(&self, cts: &mut [Ciphertext], acc: &LookupTableOwned) {
+        if cts.is_empty() {
+            return;
+        }
+
+        let chunk_size = cts.len().div_ceil(rayon::current_num_threads());
+        cts.par_chunks_mut(chunk_size).for_each(|chunk| {
+            ShortintEngine::with_thread_local_mut(|engine| {
+                engine
+                    .apply_lookup_table_slice_assign(self, chunk, acc)
+                    .unwrap()
+            })
+        });
+    }
+
+    /// Evaluates all the functions of a [`ManyLutAccumulator`] on the input with a single blind
+    /// rotation, returning one ciphertext per function, in the order the functions were given.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the degree of the input exceeds the input space of the lookup table.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let msg = 7;
+    /// let modulus = cks.parameters.message_modulus().0 as u64;
+    ///
+    /// // Message and carry of the input, in one bootstrap
+    /// let message = |x: u64| x % modulus;
+    /// let carry = |x: u64| x / modulus;
+    /// let acc = sks.generate_many_lookup_table(&[&message, &carry]);
+    ///
+    /// let ct = cks.unchecked_encrypt(msg);
+    /// let res = sks.apply_lookup_table_multi_output(&ct, &acc);
+    ///
+    /// assert_eq!(res.len(), 2);
+    /// assert_eq!(cks.decrypt(&res[0]), msg % modulus);
+    /// assert_eq!(cks.decrypt(&res[1]), msg / modulus);
+    /// ```
+    pub fn apply_lookup_table_multi_output(
+        &self,
+        ct: &Ciphertext,
+        acc: &ManyLutAccumulator,
+    ) -> Vec<Ciphertext> {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .apply_lookup_table_multi_output(self, ct, acc)
+                .unwrap()
+        })
+    }
+
+    /// Evaluates several lookup tables on the input with a single blind rotation, returning one
+    /// ciphertext per lookup table, in the order the lookup tables were given.
+    ///
+    /// The lookup tables are packed into a [`ManyLutAccumulator`], see
+    /// [`ServerKey::generate_many_lookup_table`]: with `n` lookup tables, the degree of the input
+    /// must be strictly smaller than `message_modulus * carry_modulus / n`. The degree of each
+    /// output is the one of its lookup table.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no lookup table, or if the degree of the input exceeds the input space
+    /// of the packed lookup tables.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let msg = 7;
+    ///
+    /// // Quotient and remainder of the division by 3, in one bootstrap
+    /// let quotient = sks.generate_lookup_table(|x| x / 3);
+    /// let remainder = sks.generate_lookup_table(|x| x % 3);
+    ///
+    /// let ct = cks.unchecked_encrypt(msg);
+    /// let res = sks.apply_many_lookup_tables(&ct, &[quotient, remainder]);
+    ///
+    /// assert_eq!(res.len(), 2);
+    /// assert_eq!(cks.decrypt(&res[0]), msg / 3);
+    /// assert_eq!(cks.decrypt(&res[1]), msg % 3);
+    /// ```
+    pub fn apply_many_lookup_tables(
+        &self,
+        ct: &Ciphertext,
+        luts: &[LookupTableOwned],
+    ) -> Vec<Ciphertext> {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.apply_many_lookup_tables(self, ct, luts).unwrap()
+        })
+    }
+
     /// Generic programmable bootstrap where messages are concatenated into one ciphertext to
     /// evaluate a bivariate function. This is used to apply many binary operations (comparisons,
     /// multiplications, division).
@@ -606,7 +1614,27 @@ impl ServerKey {
 
     /// Verify if a functional bivariate pbs can be applied on ct_left and ct_right.
     pub fn is_functional_bivariate_pbs_possible(&self, ct1: &Ciphertext, ct2: &Ciphertext) -> bool {
-        ciphertexts_can_be_packed_without_exceeding_space(ct1, ct2, ct2.degree.0 + 1)
+        self.check_functional_bivariate_pbs_possible(ct1, ct2)
+            .is_ok()
+    }
+
+    /// Verify if a bivariate functional PBS can be applied on ct1 and ct2, returning the reason
+    /// otherwise.
+    ///
+    /// The ciphertexts are packed as `ct1 * (ct2.degree + 1) + ct2` before the PBS, the noise
+    /// level of the packing must not exceed the maximum noise level.
+    pub(crate) fn check_functional_bivariate_pbs_possible(
+        &self,
+        ct1: &Ciphertext,
+        ct2: &Ciphertext,
+    ) -> Result<(), CheckError> {
+        let factor = ct2.degree.0 + 1;
+        if !ciphertexts_can_be_packed_without_exceeding_space(ct1, ct2, factor) {
+            return Err(CheckError::CarryFull);
+        }
+
+        self.max_noise_level
+            .validate(ct1.noise_level * factor + ct2.noise_level)
     }
 
     pub fn smart_evaluate_bivariate_function_assign<F>(
@@ -639,6 +1667,127 @@ impl ServerKey {
                 .unwrap()
         })
     }
+
+    /// Evaluates a bivariate function on two ciphertexts, whatever their degrees.
+    ///
+    /// As for [`Self::unchecked_evaluate_bivariate_function`], `f` is evaluated on the messages of
+    /// the inputs, i.e. their values modulo the message modulus. When the degrees of the inputs
+    /// do not allow to pack them in a single ciphertext, the carries of one or both inputs are
+    /// first cleared with a message extraction, the inputs are not modified.
+    ///
+    /// The number of programmable bootstrappings this function computes is given by
+    /// [`Self::bivariate_pbs_unconstrained_pbs_count`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if two messages cannot be packed in a single ciphertext with the parameters of the
+    /// inputs, which is the case when the carry modulus is smaller than the message modulus.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let modulus = cks.parameters.message_modulus().0 as u64;
+    ///
+    /// // Both inputs have full carries
+    /// let ct_left = cks.unchecked_encrypt(14);
+    /// let ct_right = cks.unchecked_encrypt(11);
+    /// assert!(!sks.is_functional_bivariate_pbs_possible(&ct_left, &ct_right));
+    ///
+    /// // Both carries need to be cleared before the bivariate PBS
+    /// assert_eq!(sks.bivariate_pbs_unconstrained_pbs_count(&ct_left, &ct_right), 3);
+    ///
+    /// let f = |x: u64, y: u64| (x * y) % modulus;
+    /// let ct_res = sks.bivariate_pbs_unconstrained(&ct_left, &ct_right, f);
+    ///
+    /// let res = cks.decrypt(&ct_res);
+    /// assert_eq!(res, f(14 % modulus, 11 % modulus));
+    /// ```
+    pub fn bivariate_pbs_unconstrained<F>(
+        &self,
+        ct_left: &Ciphertext,
+        ct_right: &Ciphertext,
+        f: F,
+    ) -> Ciphertext
+    where
+        F: Fn(u64, u64) -> u64,
+    {
+        let (extract_left, extract_right) = self.bivariate_pbs_renormalization(ct_left, ct_right);
+
+        let renormalize = |ct: &Ciphertext, extract: bool| {
+            if extract {
+                Cow::Owned(self.message_extract(ct))
+            } else {
+                Cow::Borrowed(ct)
+            }
+        };
+        let (ct_left, ct_right) = rayon::join(
+            || renormalize(ct_left, extract_left),
+            || renormalize(ct_right, extract_right),
+        );
+
+        self.unchecked_evaluate_bivariate_function(&ct_left, &ct_right, f)
+    }
+
+    /// Returns the number of programmable bootstrappings computed by
+    /// [`Self::bivariate_pbs_unconstrained`] for the given inputs, this includes the bivariate
+    /// PBS itself.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same cases as [`Self::bivariate_pbs_unconstrained`].
+    pub fn bivariate_pbs_unconstrained_pbs_count(
+        &self,
+        ct_left: &Ciphertext,
+        ct_right: &Ciphertext,
+    ) -> usize {
+        let (extract_left, extract_right) = self.bivariate_pbs_renormalization(ct_left, ct_right);
+        1 + usize::from(extract_left) + usize::from(extract_right)
+    }
+
+    /// Returns which of the left and right inputs need their carries cleared so that they can be
+    /// packed for a bivariate PBS, using as few message extractions as possible.
+    fn bivariate_pbs_renormalization(
+        &self,
+        ct_left: &Ciphertext,
+        ct_right: &Ciphertext,
+    ) -> (bool, bool) {
+        let message_max_degree = ct_left.message_modulus.0 - 1;
+        let full_modulus = ct_left.message_modulus.0 * ct_left.carry_modulus.0;
+
+        // A message extraction gives a ciphertext of degree message_max_degree,
+        // it is only useful on inputs with a bigger degree
+        let degree_after = |ct: &Ciphertext, extract: bool| {
+            if extract {
+                message_max_degree
+            } else {
+                ct.degree.0
+            }
+        };
+
+        [(false, false), (false, true), (true, false), (true, true)]
+            .into_iter()
+            .filter(|&(extract_left, extract_right)| {
+                (!extract_left || ct_left.degree.0 > message_max_degree)
+                    && (!extract_right || ct_right.degree.0 > message_max_degree)
+            })
+            .find(|&(extract_left, extract_right)| {
+                let left_degree = degree_after(ct_left, extract_left);
+                let right_degree = degree_after(ct_right, extract_right);
+                left_degree * (right_degree + 1) + right_degree < full_modulus
+            })
+            .unwrap_or_else(|| {
+                panic!(
+                    "Two messages cannot be packed for a bivariate PBS with \
+                    message modulus {} and carry modulus {}",
+                    ct_left.message_modulus.0, ct_left.carry_modulus.0
+                )
+            })
+    }
     /// Replace the input encrypted message by the value of its carry buffer.
     ///
     /// # Example
@@ -791,6 +1940,72 @@ impl ServerKey {
         ShortintEngine::with_thread_local_mut(|engine| engine.message_extract(self, ct).unwrap())
     }
 
+    /// Puts a ciphertext in the canonical form of a fresh ciphertext.
+    ///
+    /// The message is refreshed with a PBS computing the identity on the message space, and the
+    /// degree, noise level, moduli and PBS order of the ciphertext are reset to the ones of a
+    /// fresh ciphertext of this key, whatever their values in the input.
+    ///
+    /// The metadata of a ciphertext received from an untrusted party cannot be checked without
+    /// the secret key: an underestimated degree or noise level would make the following
+    /// operations skip required PBS and give wrong results. Such ciphertexts should be sanitized
+    /// before being used, after checking their
+    /// [conformance](crate::conformance::ParameterSetConformant).
+    ///
+    /// The padding bit of the input may be set too: it is cleared by a first PBS, so the
+    /// sanitization costs two PBS.
+    ///
+    /// # Note
+    ///
+    /// The carries of the input are dropped, as a fresh ciphertext has empty carries.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, leaving the ciphertext unchanged, if its LWE dimension or its ciphertext
+    /// modulus are not the ones of the ciphertexts of this key.
+    ///
+    /// # Example
+    ///
+    ///```rust
+    /// use tfhe::shortint::ciphertext::{Degree, NoiseLevel};
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let msg = 3;
+    ///
+    /// // A ciphertext with forged metadata
+    /// let mut ct = cks.encrypt(msg);
+    /// ct.degree = Degree(0);
+    /// ct.noise_level = NoiseLevel::ZERO;
+    ///
+    /// sks.sanitize(&mut ct).unwrap();
+    ///
+    /// assert_eq!(ct.degree, Degree(sks.message_modulus.0 - 1));
+    /// assert_eq!(ct.noise_level(), NoiseLevel::NOMINAL);
+    /// assert_eq!(cks.decrypt(&ct), msg);
+    /// ```
+    pub fn sanitize(&self, ct: &mut Ciphertext) -> Result<(), SanitizationError> {
+        let expected = self
+            .conformance_params_for_pbs_order(self.pbs_order)
+            .ct_params;
+        let lwe_dimension = ct.ct.lwe_size().to_lwe_dimension();
+        if lwe_dimension != expected.lwe_dim {
+            return Err(SanitizationError::InvalidLweDimension {
+                expected: expected.lwe_dim,
+                got: lwe_dimension,
+            });
+        }
+        if ct.ct.ciphertext_modulus() != expected.ct_modulus {
+            return Err(SanitizationError::InvalidCiphertextModulus);
+        }
+
+        ShortintEngine::with_thread_local_mut(|engine| engine.sanitize_assign(self, ct).unwrap());
+        Ok(())
+    }
+
     /// Compute a trivial shortint ciphertext with the dimension of the big LWE secret key from a
     /// given value.
     ///
@@ -849,16 +2064,57 @@ impl ServerKey {
         self.bootstrapping_key
             .set_deterministic_pbs_execution(new_deterministic_execution)
     }
+
+    pub fn multi_bit_pbs_thread_count(&self) -> Option<ThreadCount> {
+        self.bootstrapping_key.multi_bit_pbs_thread_count()
+    }
+
+    pub fn set_multi_bit_pbs_thread_count(&mut self, new_thread_count: ThreadCount) {
+        self.bootstrapping_key
+            .set_multi_bit_pbs_thread_count(new_thread_count)
+    }
+
+    /// Benchmark the multi bit PBS with these parameters on this machine, the first time they
+    /// are used in the process, and use the fastest number of threads.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS;
+    ///
+    /// let (cks, mut sks) = gen_keys(PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS);
+    ///
+    /// sks.autotune_multi_bit_pbs_thread_count();
+    /// assert!(sks.multi_bit_pbs_thread_count().unwrap().0 >= 1);
+    ///
+    /// let ct = cks.encrypt(3);
+    /// let ct_res = sks.scalar_add(&ct, 1);
+    /// assert_eq!(cks.decrypt(&ct_res), 0);
+    /// ```
+    pub fn autotune_multi_bit_pbs_thread_count(&mut self) {
+        self.bootstrapping_key.autotune_multi_bit_pbs_thread_count()
+    }
 }
 
 impl From<CompressedServerKey> for ServerKey {
     fn from(compressed_server_key: CompressedServerKey) -> Self {
+        Self::from_compressed(compressed_server_key, ShortintEngine::default_pbs_backend())
+    }
+}
+
+impl ServerKey {
+    pub(crate) fn from_compressed(
+        compressed_server_key: CompressedServerKey,
+        pbs_backend: PbsBackend,
+    ) -> Self {
         let CompressedServerKey {
             key_switching_key,
             bootstrapping_key,
             message_modulus,
             carry_modulus,
             max_degree,
+            max_noise_level,
             ciphertext_modulus,
             pbs_order,
         } = compressed_server_key;
@@ -870,25 +2126,20 @@ impl From<CompressedServerKey> for ServerKey {
                     let standard_bootstrapping_key =
                         bootstrapping_key.par_decompress_into_lwe_bootstrap_key();
 
-                    let mut bootstrapping_key = FourierLweBootstrapKeyOwned::new(
-                        standard_bootstrapping_key.input_lwe_dimension(),
-                        standard_bootstrapping_key.glwe_size(),
-                        standard_bootstrapping_key.polynomial_size(),
-                        standard_bootstrapping_key.decomposition_base_log(),
-                        standard_bootstrapping_key.decomposition_level_count(),
-                    );
-
-                    par_convert_standard_lwe_bootstrap_key_to_fourier(
+                    ShortintEngine::convert_classic_bootstrapping_key(
                         &standard_bootstrapping_key,
-                        &mut bootstrapping_key,
-                    );
-
-                    ShortintBootstrappingKey::Classic(bootstrapping_key)
+                        pbs_backend,
+                    )
                 }
                 ShortintCompressedBootstrappingKey::MultiBit {
                     seeded_bsk: bootstrapping_key,
                     deterministic_execution,
                 } => {
+                    assert!(
+                        pbs_backend == PbsBackend::Fft,
+                        "The NTT backend of the PBS does not support multi bit PBS"
+                    );
+
                     let standard_bootstrapping_key =
                         bootstrapping_key.par_decompress_into_lwe_multi_bit_bootstrap_key();
 
@@ -932,6 +2183,8 @@ impl From<CompressedServerKey> for ServerKey {
             message_modulus,
             carry_modulus,
             max_degree,
+            max_noise_level,
+            max_noise_level_policy: MaxNoiseLevelPolicy::default(),
             ciphertext_modulus,
             pbs_order,
         }
@@ -2,7 +2,6 @@ use super::ServerKey;
 use crate::shortint::ciphertext::Degree;
 use crate::shortint::engine::ShortintEngine;
 use crate::shortint::server_key::CheckError;
-use crate::shortint::server_key::CheckError::CarryFull;
 use crate::shortint::Ciphertext;
 
 impl ServerKey {
@@ -240,6 +239,15 @@ impl ServerKey {
         self.is_functional_bivariate_pbs_possible(ct1, ct2)
     }
 
+    /// Verify if two ciphertexts can be multiplied together, returning the reason otherwise.
+    pub(crate) fn check_mul_possible(
+        &self,
+        ct1: &Ciphertext,
+        ct2: &Ciphertext,
+    ) -> Result<(), CheckError> {
+        self.check_functional_bivariate_pbs_possible(ct1, ct2)
+    }
+
     /// Multiply two ciphertexts together with checks.
     ///
     /// Return the "least significant bits" of the multiplication, i.e., the result modulus the
@@ -294,12 +302,15 @@ impl ServerKey {
         ct_left: &Ciphertext,
         ct_right: &Ciphertext,
     ) -> Result<Ciphertext, CheckError> {
-        if self.is_mul_possible(ct_left, ct_right) {
-            let ct_result = self.unchecked_mul_lsb(ct_left, ct_right);
-            Ok(ct_result)
-        } else {
-            Err(CarryFull)
-        }
+        let (lhs, rhs) =
+            self.check_bivariate_with_noise_policy(ct_left, ct_right, |lhs, rhs| {
+                self.check_mul_possible(lhs, rhs)
+            })?;
+        let ct_result = self.unchecked_mul_lsb(
+            lhs.as_ref().unwrap_or(ct_left),
+            rhs.as_ref().unwrap_or(ct_right),
+        );
+        Ok(ct_result)
     }
 
     /// Multiply two ciphertexts together with checks.
@@ -355,12 +366,15 @@ impl ServerKey {
         ct_left: &mut Ciphertext,
         ct_right: &Ciphertext,
     ) -> Result<(), CheckError> {
-        if self.is_mul_possible(ct_left, ct_right) {
-            self.unchecked_mul_lsb_assign(ct_left, ct_right);
-            Ok(())
-        } else {
-            Err(CarryFull)
+        let (lhs, rhs) =
+            self.check_bivariate_with_noise_policy(ct_left, ct_right, |lhs, rhs| {
+                self.check_mul_possible(lhs, rhs)
+            })?;
+        if let Some(lhs) = lhs {
+            *ct_left = lhs;
         }
+        self.unchecked_mul_lsb_assign(ct_left, rhs.as_ref().unwrap_or(ct_right));
+        Ok(())
     }
 
     /// Multiply two ciphertexts together without checks.
@@ -438,12 +452,15 @@ impl ServerKey {
         ct_left: &Ciphertext,
         ct_right: &Ciphertext,
     ) -> Result<Ciphertext, CheckError> {
-        if self.is_mul_possible(ct_left, ct_right) {
-            let ct_result = self.unchecked_mul_msb(ct_left, ct_right);
-            Ok(ct_result)
-        } else {
-            Err(CarryFull)
-        }
+        let (lhs, rhs) =
+            self.check_bivariate_with_noise_policy(ct_left, ct_right, |lhs, rhs| {
+                self.check_mul_possible(lhs, rhs)
+            })?;
+        let ct_result = self.unchecked_mul_msb(
+            lhs.as_ref().unwrap_or(ct_left),
+            rhs.as_ref().unwrap_or(ct_right),
+        );
+        Ok(ct_result)
     }
 
     /// Multiply two ciphertexts together using one bit of carry only.
@@ -568,10 +585,20 @@ impl ServerKey {
     /// assert_eq!(false, res);
     /// ```
     pub fn is_mul_small_carry_possible(&self, ct_left: &Ciphertext, ct_right: &Ciphertext) -> bool {
-        // Check if an addition is possible
-        let b1 = self.is_add_possible(ct_left, ct_right);
-        let b2 = self.is_sub_possible(ct_left, ct_right);
-        b1 & b2
+        self.check_mul_small_carry_possible(ct_left, ct_right)
+            .is_ok()
+    }
+
+    /// Verify if the multiplication using a small carry buffer is possible, returning the reason
+    /// otherwise.
+    pub(crate) fn check_mul_small_carry_possible(
+        &self,
+        ct_left: &Ciphertext,
+        ct_right: &Ciphertext,
+    ) -> Result<(), CheckError> {
+        // Check if an addition and a subtraction are possible
+        self.check_add_possible(ct_left, ct_right)?;
+        self.check_sub_possible(ct_left, ct_right)
     }
 
     /// Compute homomorphically a multiplication between two ciphertexts encrypting integer values.
@@ -630,13 +657,16 @@ impl ServerKey {
         ct_left: &Ciphertext,
         ct_right: &Ciphertext,
     ) -> Result<Ciphertext, CheckError> {
-        if self.is_mul_small_carry_possible(ct_left, ct_right) {
-            let mut ct_result = self.unchecked_mul_lsb_small_carry(ct_left, ct_right);
-            ct_result.degree = Degree(ct_left.degree.0 * 2);
-            Ok(ct_result)
-        } else {
-            Err(CarryFull)
-        }
+        let (lhs, rhs) =
+            self.check_bivariate_with_noise_policy(ct_left, ct_right, |lhs, rhs| {
+                self.check_mul_small_carry_possible(lhs, rhs)
+            })?;
+        let mut ct_result = self.unchecked_mul_lsb_small_carry(
+            lhs.as_ref().unwrap_or(ct_left),
+            rhs.as_ref().unwrap_or(ct_right),
+        );
+        ct_result.degree = Degree(ct_left.degree.0 * 2);
+        Ok(ct_result)
     }
 
     /// Multiply two ciphertexts together
@@ -856,11 +886,11 @@ impl ServerKey {
     pub fn mul_lsb_assign(&self, ct_left: &mut Ciphertext, ct_right: &Ciphertext) {
         let tmp_rhs: Ciphertext;
 
-        if !ct_left.carry_is_empty() {
+        if !ct_left.is_clean() {
             self.message_extract_assign(ct_left);
         }
 
-        let rhs = if ct_right.carry_is_empty() {
+        let rhs = if ct_right.is_clean() {
             ct_right
         } else {
             tmp_rhs = self.message_extract(ct_right);
@@ -995,11 +1025,11 @@ impl ServerKey {
     pub fn mul_msb_assign(&self, ct_left: &mut Ciphertext, ct_right: &Ciphertext) {
         let tmp_rhs: Ciphertext;
 
-        if !ct_left.carry_is_empty() {
+        if !ct_left.is_clean() {
             self.message_extract_assign(ct_left);
         }
 
-        let rhs = if ct_right.carry_is_empty() {
+        let rhs = if ct_right.is_clean() {
             ct_right
         } else {
             tmp_rhs = self.message_extract(ct_right);
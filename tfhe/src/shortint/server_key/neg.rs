@@ -112,7 +112,7 @@ impl ServerKey {
     /// assert_eq!(clear_res, modulus - msg);
     /// ```
     pub fn neg_assign(&self, ct: &mut Ciphertext) {
-        if !ct.carry_is_empty() {
+        if !ct.is_clean() {
             self.message_extract_assign(ct);
         }
         self.unchecked_neg_assign(ct);
@@ -260,12 +260,20 @@ impl ServerKey {
     /// assert_eq!(can_be_negated, true);
     /// ```
     pub fn is_neg_possible(&self, ct: &Ciphertext) -> bool {
+        self.check_neg_possible(ct).is_ok()
+    }
+
+    /// Verify if the ciphertext can be negated, returning the reason otherwise.
+    pub(crate) fn check_neg_possible(&self, ct: &Ciphertext) -> Result<(), CheckError> {
         // z = ceil( degree / 2^p ) x 2^p
         let msg_mod = self.message_modulus.0;
         let mut z = (ct.degree.0 + msg_mod - 1) / msg_mod;
         z = z.wrapping_mul(msg_mod);
+        if z > self.max_degree.0 {
+            return Err(CarryFull);
+        }
 
-        z <= self.max_degree.0
+        self.max_noise_level.validate(ct.noise_level)
     }
 
     /// Compute homomorphically a negation of a ciphertext.
@@ -314,12 +322,9 @@ impl ServerKey {
     /// ```
     pub fn checked_neg(&self, ct: &Ciphertext) -> Result<Ciphertext, CheckError> {
         // If the ciphertext cannot be negated without exceeding the capacity of a ciphertext
-        if self.is_neg_possible(ct) {
-            let ct_result = self.unchecked_neg(ct);
-            Ok(ct_result)
-        } else {
-            Err(CarryFull)
-        }
+        let refreshed = self.check_with_noise_policy(ct, |ct| self.check_neg_possible(ct))?;
+        let ct_result = self.unchecked_neg(refreshed.as_ref().unwrap_or(ct));
+        Ok(ct_result)
     }
 
     /// Compute homomorphically a negation of a ciphertext.
@@ -369,12 +374,13 @@ impl ServerKey {
     /// assert_eq!(clear_res, modulus - msg);
     /// ```
     pub fn checked_neg_assign(&self, ct: &mut Ciphertext) -> Result<(), CheckError> {
-        if self.is_neg_possible(ct) {
-            self.unchecked_neg_assign(ct);
-            Ok(())
-        } else {
-            Err(CarryFull)
+        if let Some(refreshed) =
+            self.check_with_noise_policy(ct, |ct| self.check_neg_possible(ct))?
+        {
+            *ct = refreshed;
         }
+        self.unchecked_neg_assign(ct);
+        Ok(())
     }
 
     /// Compute homomorphically a negation of a ciphertext.
@@ -0,0 +1,64 @@
+use super::ServerKey;
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::{Ciphertext, PBSOrder};
+
+impl ServerKey {
+    /// Convert a ciphertext so that it uses the given [`PBSOrder`].
+    ///
+    /// Ciphertexts using [`PBSOrder::KeyswitchBootstrap`] are encrypted under the big LWE key,
+    /// while ciphertexts using [`PBSOrder::BootstrapKeyswitch`] are encrypted under the small LWE
+    /// key. Both keys are linked by the key switching and bootstrapping keys of the server key,
+    /// so:
+    ///
+    /// - converting to [`PBSOrder::BootstrapKeyswitch`] computes a keyswitch,
+    /// - converting to [`PBSOrder::KeyswitchBootstrap`] computes a PBS with the identity function.
+    ///
+    /// The message, carries and degree of the ciphertext are preserved. The keyswitch adds
+    /// [`NoiseLevel::NOMINAL`](crate::shortint::ciphertext::NoiseLevel::NOMINAL) to the noise
+    /// level of the ciphertext, while the PBS resets it to the nominal level. If the ciphertext
+    /// already uses the given order, it is returned unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::shortint::PBSOrder;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let msg = 3;
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_small = sks.convert_pbs_order(&ct, PBSOrder::BootstrapKeyswitch);
+    /// assert_eq!(ct_small.pbs_order, PBSOrder::BootstrapKeyswitch);
+    /// assert_eq!(cks.decrypt(&ct_small), msg);
+    ///
+    /// let ct_big = sks.convert_pbs_order(&ct_small, PBSOrder::KeyswitchBootstrap);
+    /// assert_eq!(ct_big.pbs_order, PBSOrder::KeyswitchBootstrap);
+    /// assert_eq!(cks.decrypt(&ct_big), msg);
+    /// ```
+    pub fn convert_pbs_order(&self, ct: &Ciphertext, pbs_order: PBSOrder) -> Ciphertext {
+        let mut ct_res = ct.clone();
+        self.convert_pbs_order_assign(&mut ct_res, pbs_order);
+        ct_res
+    }
+
+    /// Convert a ciphertext so that it uses the given [`PBSOrder`].
+    ///
+    /// See [`Self::convert_pbs_order`] for details.
+    pub fn convert_pbs_order_assign(&self, ct: &mut Ciphertext, pbs_order: PBSOrder) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .convert_pbs_order_assign(self, ct, pbs_order)
+                .unwrap();
+        });
+    }
+
+    /// Convert a ciphertext to the [`PBSOrder`] of this server key, so that it can be used in
+    /// operations with ciphertexts produced by this server key.
+    pub fn convert_to_key_pbs_order_assign(&self, ct: &mut Ciphertext) {
+        self.convert_pbs_order_assign(ct, self.pbs_order);
+    }
+}
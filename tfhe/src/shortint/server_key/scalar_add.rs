@@ -247,9 +247,23 @@ impl ServerKey {
     /// assert_eq!(can_be_computed, true);
     /// ```
     pub fn is_scalar_add_possible(&self, ct: &Ciphertext, scalar: u8) -> bool {
+        self.check_scalar_add_possible(ct, scalar).is_ok()
+    }
+
+    /// Verify if a scalar can be added to the ciphertext, returning the reason otherwise.
+    ///
+    /// Adding a scalar does not change the noise level.
+    pub(crate) fn check_scalar_add_possible(
+        &self,
+        ct: &Ciphertext,
+        scalar: u8,
+    ) -> Result<(), CheckError> {
         let final_degree = scalar as usize + ct.degree.0;
+        if final_degree > self.max_degree.0 {
+            return Err(CarryFull);
+        }
 
-        final_degree <= self.max_degree.0
+        Ok(())
     }
 
     /// Compute homomorphically an addition between a ciphertext and a scalar.
@@ -300,12 +314,10 @@ impl ServerKey {
         scalar: u8,
     ) -> Result<Ciphertext, CheckError> {
         //If the ciphertext cannot be multiplied without exceeding the max degree
-        if self.is_scalar_add_possible(ct, scalar) {
-            let ct_result = self.unchecked_scalar_add(ct, scalar);
-            Ok(ct_result)
-        } else {
-            Err(CarryFull)
-        }
+        let refreshed =
+            self.check_with_noise_policy(ct, |ct| self.check_scalar_add_possible(ct, scalar))?;
+        let ct_result = self.unchecked_scalar_add(refreshed.as_ref().unwrap_or(ct), scalar);
+        Ok(ct_result)
     }
 
     /// Compute homomorphically an addition between a ciphertext and a scalar.
@@ -353,12 +365,13 @@ impl ServerKey {
         ct: &mut Ciphertext,
         scalar: u8,
     ) -> Result<(), CheckError> {
-        if self.is_scalar_add_possible(ct, scalar) {
-            self.unchecked_scalar_add_assign(ct, scalar);
-            Ok(())
-        } else {
-            Err(CarryFull)
+        if let Some(refreshed) =
+            self.check_with_noise_policy(ct, |ct| self.check_scalar_add_possible(ct, scalar))?
+        {
+            *ct = refreshed;
         }
+        self.unchecked_scalar_add_assign(ct, scalar);
+        Ok(())
     }
 
     /// Compute homomorphically an addition between a ciphertext and a scalar.
@@ -35,7 +35,7 @@ impl ServerKey {
     }
 
     pub fn scalar_bitand_assign(&self, lhs: &mut Ciphertext, rhs: u8) {
-        if !lhs.carry_is_empty() {
+        if !lhs.is_clean() {
             self.message_extract_assign(lhs);
         }
 
@@ -102,7 +102,7 @@ impl ServerKey {
     }
 
     pub fn scalar_bitxor_assign(&self, lhs: &mut Ciphertext, rhs: u8) {
-        if !lhs.carry_is_empty() {
+        if !lhs.is_clean() {
             self.message_extract_assign(lhs);
         }
 
@@ -168,7 +168,7 @@ impl ServerKey {
     }
 
     pub fn scalar_bitor_assign(&self, lhs: &mut Ciphertext, rhs: u8) {
-        if !lhs.carry_is_empty() {
+        if !lhs.is_clean() {
             self.message_extract_assign(lhs);
         }
 
@@ -125,6 +125,9 @@ impl ServerKey {
     ///
     /// This function does _not_ check whether the capacity of the ciphertext is exceeded.
     ///
+    /// The noise level of the product is the noise level of the input times the scalar, see
+    /// [scalar_mul_refreshed](Self::scalar_mul_refreshed) to keep it under the maximum noise level.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -170,6 +173,10 @@ impl ServerKey {
     ///
     /// This function does not check whether the capacity of the ciphertext is exceeded.
     ///
+    /// The noise level of the product is the noise level of the input times the scalar, see
+    /// [scalar_mul_refreshed_assign](Self::scalar_mul_refreshed_assign) to keep it under the
+    /// maximum noise level.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -207,6 +214,82 @@ impl ServerKey {
         })
     }
 
+    /// Compute homomorphically a multiplication of a ciphertext by a scalar, refreshing the input
+    /// with a PBS when the product would exceed the maximum noise level.
+    ///
+    /// The result is returned in a _new_ ciphertext.
+    ///
+    /// Like [unchecked_scalar_mul](Self::unchecked_scalar_mul), this function does _not_ check
+    /// whether the capacity of the ciphertext is exceeded, and the carries of the product are
+    /// kept.
+    ///
+    /// The noise level of `ct * scalar` is the noise level of `ct` times the scalar, whatever the
+    /// sequence of doublings and additions used to compute it. When this exceeds the maximum
+    /// noise level, the product is computed as `refresh(ct * a) * b + ct * r`, with `a`, `b` and
+    /// `r` chosen to minimize the noise level of the output. At most one PBS is computed, none
+    /// when the direct product is under the maximum noise level.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// // A ciphertext with twice the noise of a fresh encryption
+    /// let ct = sks.unchecked_add(&cks.encrypt(1), &cks.encrypt(0));
+    ///
+    /// // The direct product would exceed the maximum noise level
+    /// let ct_res = sks.unchecked_scalar_mul(&ct, 5);
+    /// assert!(sks.max_noise_level.validate(ct_res.noise_level()).is_err());
+    ///
+    /// let ct_res = sks.scalar_mul_refreshed(&ct, 5);
+    /// assert!(sks.max_noise_level.validate(ct_res.noise_level()).is_ok());
+    ///
+    /// let clear = cks.decrypt_message_and_carry(&ct_res);
+    /// assert_eq!(5, clear);
+    /// ```
+    pub fn scalar_mul_refreshed(&self, ct: &Ciphertext, scalar: u8) -> Ciphertext {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.scalar_mul_refreshed(self, ct, scalar).unwrap()
+        })
+    }
+
+    /// Compute homomorphically a multiplication of a ciphertext by a scalar, refreshing the input
+    /// with a PBS when the product would exceed the maximum noise level.
+    ///
+    /// The result it stored in the given ciphertext.
+    ///
+    /// See [scalar_mul_refreshed](Self::scalar_mul_refreshed) for the details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// // A ciphertext with twice the noise of a fresh encryption
+    /// let mut ct = sks.unchecked_add(&cks.encrypt(1), &cks.encrypt(0));
+    ///
+    /// sks.scalar_mul_refreshed_assign(&mut ct, 5);
+    /// assert!(sks.max_noise_level.validate(ct.noise_level()).is_ok());
+    ///
+    /// let clear = cks.decrypt_message_and_carry(&ct);
+    /// assert_eq!(5, clear);
+    /// ```
+    pub fn scalar_mul_refreshed_assign(&self, ct: &mut Ciphertext, scalar: u8) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .scalar_mul_refreshed_assign(self, ct, scalar)
+                .unwrap()
+        })
+    }
+
     /// Multiply one ciphertext with a scalar in the case the carry space cannot fit the product
     /// applying the message space modulus in the process.
     ///
@@ -292,10 +375,23 @@ impl ServerKey {
     /// assert_eq!(can_be_computed, true);
     /// ```
     pub fn is_scalar_mul_possible(&self, ct: &Ciphertext, scalar: u8) -> bool {
+        self.check_scalar_mul_possible(ct, scalar).is_ok()
+    }
+
+    /// Verify if the ciphertext can be multiplied by a scalar, returning the reason otherwise.
+    pub(crate) fn check_scalar_mul_possible(
+        &self,
+        ct: &Ciphertext,
+        scalar: u8,
+    ) -> Result<(), CheckError> {
         //scalar * ct.counter
         let final_degree = scalar as usize * ct.degree.0;
+        if final_degree > self.max_degree.0 {
+            return Err(CarryFull);
+        }
 
-        final_degree <= self.max_degree.0
+        self.max_noise_level
+            .validate(ct.noise_level * scalar as usize)
     }
 
     /// Compute homomorphically a multiplication of a ciphertext by a scalar.
@@ -349,12 +445,10 @@ impl ServerKey {
         scalar: u8,
     ) -> Result<Ciphertext, CheckError> {
         //If the ciphertext cannot be multiplied without exceeding the degree max
-        if self.is_scalar_mul_possible(ct, scalar) {
-            let ct_result = self.unchecked_scalar_mul(ct, scalar);
-            Ok(ct_result)
-        } else {
-            Err(CarryFull)
-        }
+        let refreshed =
+            self.check_with_noise_policy(ct, |ct| self.check_scalar_mul_possible(ct, scalar))?;
+        let ct_result = self.unchecked_scalar_mul(refreshed.as_ref().unwrap_or(ct), scalar);
+        Ok(ct_result)
     }
 
     /// Compute homomorphically a multiplication of a ciphertext by a scalar.
@@ -404,12 +498,13 @@ impl ServerKey {
         ct: &mut Ciphertext,
         scalar: u8,
     ) -> Result<(), CheckError> {
-        if self.is_scalar_mul_possible(ct, scalar) {
-            self.unchecked_scalar_mul_assign(ct, scalar);
-            Ok(())
-        } else {
-            Err(CarryFull)
+        if let Some(refreshed) =
+            self.check_with_noise_policy(ct, |ct| self.check_scalar_mul_possible(ct, scalar))?
+        {
+            *ct = refreshed;
         }
+        self.unchecked_scalar_mul_assign(ct, scalar);
+        Ok(())
     }
 
     /// Compute homomorphically a multiplication of a ciphertext by a scalar.
@@ -243,9 +243,24 @@ impl ServerKey {
     /// assert_eq!(can_be_computed, true);
     /// ```
     pub fn is_scalar_sub_possible(&self, ct: &Ciphertext, scalar: u8) -> bool {
+        self.check_scalar_sub_possible(ct, scalar).is_ok()
+    }
+
+    /// Verify if a scalar can be subtracted to the ciphertext, returning the reason otherwise.
+    ///
+    /// Subtracting a scalar does not change the noise level.
+    pub(crate) fn check_scalar_sub_possible(
+        &self,
+        ct: &Ciphertext,
+        scalar: u8,
+    ) -> Result<(), CheckError> {
         let neg_scalar = u64::from(scalar.wrapping_neg()) % self.message_modulus.0 as u64;
         let final_degree = neg_scalar as usize + ct.degree.0;
-        final_degree <= self.max_degree.0
+        if final_degree > self.max_degree.0 {
+            return Err(CarryFull);
+        }
+
+        Ok(())
     }
 
     /// Compute homomorphically a subtraction of a ciphertext by a scalar.
@@ -296,12 +311,10 @@ impl ServerKey {
         scalar: u8,
     ) -> Result<Ciphertext, CheckError> {
         //If the scalar subtraction cannot be done without exceeding the max degree
-        if self.is_scalar_sub_possible(ct, scalar) {
-            let ct_result = self.unchecked_scalar_sub(ct, scalar);
-            Ok(ct_result)
-        } else {
-            Err(CarryFull)
-        }
+        let refreshed =
+            self.check_with_noise_policy(ct, |ct| self.check_scalar_sub_possible(ct, scalar))?;
+        let ct_result = self.unchecked_scalar_sub(refreshed.as_ref().unwrap_or(ct), scalar);
+        Ok(ct_result)
     }
 
     /// Compute homomorphically a subtraction of a ciphertext by a scalar.
@@ -349,12 +362,13 @@ impl ServerKey {
         ct: &mut Ciphertext,
         scalar: u8,
     ) -> Result<(), CheckError> {
-        if self.is_scalar_sub_possible(ct, scalar) {
-            self.unchecked_scalar_sub_assign(ct, scalar);
-            Ok(())
-        } else {
-            Err(CarryFull)
+        if let Some(refreshed) =
+            self.check_with_noise_policy(ct, |ct| self.check_scalar_sub_possible(ct, scalar))?
+        {
+            *ct = refreshed;
         }
+        self.unchecked_scalar_sub_assign(ct, scalar);
+        Ok(())
     }
 
     /// Compute homomorphically a subtraction of a ciphertext by a scalar.
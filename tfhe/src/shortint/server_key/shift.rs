@@ -591,8 +591,22 @@ impl ServerKey {
     /// assert_eq!(false, res);
     /// ```
     pub fn is_scalar_left_shift_possible(&self, ct1: &Ciphertext, shift: u8) -> bool {
+        self.check_scalar_left_shift_possible(ct1, shift).is_ok()
+    }
+
+    /// Verify if the ciphertext can be shifted to the left, returning the reason otherwise.
+    pub(crate) fn check_scalar_left_shift_possible(
+        &self,
+        ct1: &Ciphertext,
+        shift: u8,
+    ) -> Result<(), CheckError> {
         let final_operation_count = ct1.degree.0 << shift as usize;
-        final_operation_count <= self.max_degree.0
+        if final_operation_count > self.max_degree.0 {
+            return Err(CarryFull);
+        }
+
+        self.max_noise_level
+            .validate(ct1.noise_level * (1 << shift as usize))
     }
 
     /// Compute homomorphically a left shift of the bits.
@@ -678,12 +692,10 @@ impl ServerKey {
         ct: &Ciphertext,
         shift: u8,
     ) -> Result<Ciphertext, CheckError> {
-        if self.is_scalar_left_shift_possible(ct, shift) {
-            let ct_result = self.unchecked_scalar_left_shift(ct, shift);
-            Ok(ct_result)
-        } else {
-            Err(CarryFull)
-        }
+        let refreshed = self
+            .check_with_noise_policy(ct, |ct| self.check_scalar_left_shift_possible(ct, shift))?;
+        let ct_result = self.unchecked_scalar_left_shift(refreshed.as_ref().unwrap_or(ct), shift);
+        Ok(ct_result)
     }
 
     pub fn checked_scalar_left_shift_assign(
@@ -691,12 +703,13 @@ impl ServerKey {
         ct: &mut Ciphertext,
         shift: u8,
     ) -> Result<(), CheckError> {
-        if self.is_scalar_left_shift_possible(ct, shift) {
-            self.unchecked_scalar_left_shift_assign(ct, shift);
-            Ok(())
-        } else {
-            Err(CarryFull)
+        if let Some(refreshed) =
+            self.check_with_noise_policy(ct, |ct| self.check_scalar_left_shift_possible(ct, shift))?
+        {
+            *ct = refreshed;
         }
+        self.unchecked_scalar_left_shift_assign(ct, shift);
+        Ok(())
     }
 
     /// Compute homomorphically a left shift of the bits
@@ -76,11 +76,11 @@ impl ServerKey {
     pub fn sub_assign(&self, ct_left: &mut Ciphertext, ct_right: &Ciphertext) {
         let tmp_rhs: Ciphertext;
 
-        if !ct_left.carry_is_empty() {
+        if !ct_left.is_clean() {
             self.message_extract_assign(ct_left);
         }
 
-        let rhs = if ct_right.carry_is_empty() {
+        let rhs = if ct_right.is_clean() {
             ct_right
         } else {
             tmp_rhs = self.message_extract(ct_right);
@@ -182,14 +182,27 @@ impl ServerKey {
     /// assert_eq!(true, can_be_subtracted);
     /// ```
     pub fn is_sub_possible(&self, ct_left: &Ciphertext, ct_right: &Ciphertext) -> bool {
+        self.check_sub_possible(ct_left, ct_right).is_ok()
+    }
+
+    /// Verify if `ct_right` can be subtracted to `ct_left`, returning the reason otherwise.
+    pub(crate) fn check_sub_possible(
+        &self,
+        ct_left: &Ciphertext,
+        ct_right: &Ciphertext,
+    ) -> Result<(), CheckError> {
         // z = ceil( degree / 2^p ) x 2^p
         let msg_mod = self.message_modulus.0;
         let mut z = (ct_right.degree.0 + msg_mod - 1) / msg_mod;
         z = z.wrapping_mul(msg_mod);
 
         let final_operation_count = ct_left.degree.0 + z;
+        if final_operation_count > self.max_degree.0 {
+            return Err(CarryFull);
+        }
 
-        final_operation_count <= self.max_degree.0
+        self.max_noise_level
+            .validate(ct_left.noise_level + ct_right.noise_level)
     }
 
     /// Compute homomorphically a subtraction between two ciphertexts encrypting integer values.
@@ -224,12 +237,15 @@ impl ServerKey {
         ct_right: &Ciphertext,
     ) -> Result<Ciphertext, CheckError> {
         // If the ciphertexts cannot be subtracted without exceeding the degree max
-        if self.is_sub_possible(ct_left, ct_right) {
-            let ct_result = self.unchecked_sub(ct_left, ct_right);
-            Ok(ct_result)
-        } else {
-            Err(CarryFull)
-        }
+        let (lhs, rhs) =
+            self.check_bivariate_with_noise_policy(ct_left, ct_right, |lhs, rhs| {
+                self.check_sub_possible(lhs, rhs)
+            })?;
+        let ct_result = self.unchecked_sub(
+            lhs.as_ref().unwrap_or(ct_left),
+            rhs.as_ref().unwrap_or(ct_right),
+        );
+        Ok(ct_result)
     }
 
     /// Compute homomorphically a subtraction between two ciphertexts.
@@ -264,12 +280,15 @@ impl ServerKey {
         ct_right: &Ciphertext,
     ) -> Result<(), CheckError> {
         // If the ciphertexts cannot be subtracted without exceeding the degree max
-        if self.is_sub_possible(ct_left, ct_right) {
-            self.unchecked_sub_assign(ct_left, ct_right);
-            Ok(())
-        } else {
-            Err(CarryFull)
+        let (lhs, rhs) =
+            self.check_bivariate_with_noise_policy(ct_left, ct_right, |lhs, rhs| {
+                self.check_sub_possible(lhs, rhs)
+            })?;
+        if let Some(lhs) = lhs {
+            *ct_left = lhs;
         }
+        self.unchecked_sub_assign(ct_left, rhs.as_ref().unwrap_or(ct_right));
+        Ok(())
     }
 
     /// Compute homomorphically a subtraction between two ciphertexts.
@@ -1,5 +1,7 @@
+use crate::conformance::ParameterSetConformant;
 use crate::shortint::keycache::KEY_CACHE;
 use crate::shortint::parameters::*;
+use crate::shortint::{LookupTableCreationError, PBSOrder};
 use paste::paste;
 use rand::Rng;
 
@@ -175,10 +177,18 @@ create_parametrized_test!(shortint_keyswitch_bootstrap);
 create_parametrized_test!(shortint_keyswitch_programmable_bootstrap);
 create_parametrized_test!(shortint_carry_extract);
 create_parametrized_test!(shortint_message_extract);
+create_parametrized_test!(shortint_sanitize);
 create_parametrized_test!(shortint_generate_lookup_table);
+create_parametrized_test!(shortint_apply_lookup_table_multi_output);
+create_parametrized_test!(shortint_apply_many_lookup_tables);
+create_parametrized_test!(shortint_apply_lookup_table_many);
+create_parametrized_test!(shortint_generate_lookup_table_from_vec);
+create_parametrized_test!(shortint_compose_lookup_tables);
 create_parametrized_test!(shortint_unchecked_add);
 create_parametrized_test!(shortint_smart_add);
 create_parametrized_test!(shortint_default_add);
+create_parametrized_test!(shortint_sum_many);
+create_parametrized_test!(shortint_convert_pbs_order);
 create_parametrized_test!(shortint_smart_mul_lsb);
 create_parametrized_test!(shortint_default_mul_lsb);
 create_parametrized_test!(shortint_unchecked_neg);
@@ -202,6 +212,14 @@ create_parametrized_test!(shortint_smart_sub);
 create_parametrized_test!(shortint_default_sub);
 create_parametrized_test!(shortint_mul_small_carry);
 create_parametrized_test!(shortint_mux);
+create_parametrized_test!(shortint_bivariate_pbs_unconstrained {
+    PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+    PARAM_MESSAGE_1_CARRY_2_KS_PBS,
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MESSAGE_2_CARRY_3_KS_PBS,
+    PARAM_MESSAGE_3_CARRY_3_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS
+});
 create_parametrized_test!(shortint_unchecked_scalar_bitand);
 create_parametrized_test!(shortint_unchecked_scalar_bitor);
 create_parametrized_test!(shortint_unchecked_scalar_bitxor);
@@ -233,6 +251,18 @@ fn test_shortint_public_key_smart_add_param_message_2_carry_2_ks_pbs() {
     shortint_public_key_smart_add(PARAM_MESSAGE_2_CARRY_2_KS_PBS)
 }
 
+#[cfg(not(feature = "__coverage"))]
+#[test]
+fn test_shortint_public_key_duplicate_param_message_1_carry_1_ks_pbs() {
+    shortint_public_key_duplicate(PARAM_MESSAGE_1_CARRY_1_KS_PBS)
+}
+
+#[cfg(not(feature = "__coverage"))]
+#[test]
+fn test_shortint_public_key_noise_level_param_message_1_carry_1_ks_pbs() {
+    shortint_public_key_noise_level(PARAM_MESSAGE_1_CARRY_1_KS_PBS)
+}
+
 //These functions are compatible with some parameter sets where the carry modulus is larger than
 // the message modulus.
 create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_bitand);
@@ -265,6 +295,7 @@ create_parametrized_test_bivariate_pbs_compliant!(shortint_smart_scalar_less_or_
 create_parametrized_test_bivariate_pbs_compliant!(shortint_smart_scalar_greater);
 create_parametrized_test_bivariate_pbs_compliant!(shortint_smart_scalar_greater_or_equal);
 create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_div);
+create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_div_rem);
 create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_scalar_div);
 create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_mod);
 create_parametrized_test_bivariate_pbs_compliant!(shortint_unchecked_mul_lsb);
@@ -493,6 +524,255 @@ where
     }
 }
 
+/// test the evaluation of several functions packed in one lookup table
+fn shortint_apply_lookup_table_multi_output<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let msg_modulus = cks.parameters.message_modulus().0 as u64;
+    let full_modulus = msg_modulus * cks.parameters.carry_modulus().0 as u64;
+
+    let message = |x: u64| x % msg_modulus;
+    let carry = |x: u64| x / msg_modulus;
+    let double = |x: u64| (2 * x) % full_modulus;
+    let functions: [&dyn Fn(u64) -> u64; 3] = [&message, &carry, &double];
+
+    for function_count in 1..=functions.len() {
+        let functions = &functions[..function_count];
+        let acc = sks.generate_many_lookup_table(functions);
+        assert_eq!(acc.function_count(), function_count);
+
+        let input_modulus = acc.input_max_degree.0 as u64 + 1;
+        assert_eq!(input_modulus, full_modulus / function_count as u64);
+
+        for clear in 0..input_modulus {
+            let ctxt = cks.unchecked_encrypt(clear);
+
+            let outputs = sks.apply_lookup_table_multi_output(&ctxt, &acc);
+            assert_eq!(outputs.len(), function_count);
+
+            for (output, function) in outputs.iter().zip(functions.iter()) {
+                let expected = function(clear);
+                assert!(output.degree.0 as u64 >= expected);
+                assert_eq!(cks.decrypt_message_and_carry(output), expected);
+            }
+        }
+    }
+}
+
+/// test the evaluation of several lookup tables with one bootstrap
+fn shortint_apply_many_lookup_tables<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let msg_modulus = cks.parameters.message_modulus().0 as u64;
+    let full_modulus = msg_modulus * cks.parameters.carry_modulus().0 as u64;
+
+    let message = |x: u64| x % msg_modulus;
+    let carry = |x: u64| x / msg_modulus;
+    let double = |x: u64| (2 * x) % full_modulus;
+    let functions: [&dyn Fn(u64) -> u64; 3] = [&message, &carry, &double];
+    let luts = functions
+        .iter()
+        .map(|function| sks.generate_lookup_table(function))
+        .collect::<Vec<_>>();
+
+    for function_count in 1..=functions.len() {
+        let input_modulus = full_modulus / function_count as u64;
+
+        for clear in 0..input_modulus {
+            let ctxt = cks.unchecked_encrypt(clear);
+
+            let outputs = sks.apply_many_lookup_tables(&ctxt, &luts[..function_count]);
+            assert_eq!(outputs.len(), function_count);
+
+            for (i, output) in outputs.iter().enumerate() {
+                assert_eq!(output.degree, luts[i].degree);
+                assert_eq!(cks.decrypt_message_and_carry(output), functions[i](clear));
+            }
+        }
+    }
+}
+
+/// test the batched application of a lookup table
+fn shortint_apply_lookup_table_many<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    use crate::shortint::ciphertext::NoiseLevel;
+
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus_sup =
+        (cks.parameters.message_modulus().0 * cks.parameters.carry_modulus().0) as u64;
+
+    let modulus = cks.parameters.message_modulus().0 as u64;
+
+    let f = |x: u64| (x * 3 + 1) % modulus;
+    let acc = sks.generate_lookup_table(f);
+
+    // Fewer ciphertexts than threads, and more
+    for num_ct in [0, 1, 3, 2 * rayon::current_num_threads() + 1] {
+        let clears = (0..num_ct)
+            .map(|_| rng.gen::<u64>() % modulus_sup)
+            .collect::<Vec<_>>();
+
+        let mut ctxts = clears
+            .iter()
+            .map(|&clear| cks.unchecked_encrypt(clear))
+            .collect::<Vec<_>>();
+
+        sks.apply_lookup_table_many(&mut ctxts, &acc);
+
+        for (ctxt, &clear) in ctxts.iter().zip(clears.iter()) {
+            assert_eq!(ctxt.degree, acc.degree);
+            assert_eq!(ctxt.noise_level(), NoiseLevel::NOMINAL);
+            assert_eq!(cks.decrypt(ctxt), f(clear));
+        }
+    }
+}
+
+fn shortint_generate_lookup_table_from_vec<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let mut rng = rand::thread_rng();
+
+    let msg_modulus = cks.parameters.message_modulus().0 as u64;
+    let full_modulus = msg_modulus * cks.parameters.carry_modulus().0 as u64;
+
+    // Tables of the whole space and of the message space only
+    for table_len in [full_modulus, msg_modulus] {
+        let table = (0..table_len)
+            .map(|_| rng.gen::<u64>() % full_modulus)
+            .collect::<Vec<_>>();
+        let acc = sks.generate_lookup_table_from_vec(&table).unwrap();
+        assert_eq!(acc.degree.0 as u64, *table.iter().max().unwrap());
+
+        for clear in 0..full_modulus {
+            let ctxt = cks.unchecked_encrypt(clear);
+            let ct_res = sks.apply_lookup_table(&ctxt, &acc);
+            assert_eq!(
+                cks.decrypt_message_and_carry(&ct_res),
+                table[(clear % table_len) as usize]
+            );
+        }
+    }
+
+    let table = (0..msg_modulus * msg_modulus)
+        .map(|_| rng.gen::<u64>() % full_modulus)
+        .collect::<Vec<_>>();
+    let acc = sks
+        .generate_lookup_table_bivariate_from_vec(&table)
+        .unwrap();
+    assert_eq!(acc.acc.degree.0 as u64, *table.iter().max().unwrap());
+    for _ in 0..NB_TEST_SMART {
+        let clear_left = rng.gen::<u64>() % msg_modulus;
+        let clear_right = rng.gen::<u64>() % msg_modulus;
+
+        let ct_left = cks.encrypt(clear_left);
+        let ct_right = cks.encrypt(clear_right);
+
+        let ct_res = sks.unchecked_apply_lookup_table_bivariate(&ct_left, &ct_right, &acc);
+        assert_eq!(
+            cks.decrypt_message_and_carry(&ct_res),
+            table[(clear_left * msg_modulus + clear_right) as usize]
+        );
+    }
+
+    // Invalid tables
+    assert_eq!(
+        sks.generate_lookup_table_from_vec(&vec![0; full_modulus as usize + 1])
+            .unwrap_err(),
+        LookupTableCreationError::InvalidTableLength {
+            expected: full_modulus as usize,
+            got: full_modulus as usize + 1,
+        }
+    );
+    let mut table = vec![0; full_modulus as usize];
+    table[1] = full_modulus;
+    assert_eq!(
+        sks.generate_lookup_table_from_vec(&table).unwrap_err(),
+        LookupTableCreationError::OutputTooLarge {
+            input: 1,
+            output: full_modulus,
+            max: full_modulus - 1,
+        }
+    );
+    assert!(sks
+        .generate_lookup_table_bivariate_from_vec(&vec![0; msg_modulus as usize])
+        .is_err());
+}
+
+fn shortint_compose_lookup_tables<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    use crate::shortint::server_key::LookupTableOwned;
+
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let mut rng = rand::thread_rng();
+
+    let msg_modulus = cks.parameters.message_modulus().0 as u64;
+    let full_modulus = msg_modulus * cks.parameters.carry_modulus().0 as u64;
+
+    let outer_table = (0..full_modulus)
+        .map(|_| rng.gen::<u64>() % full_modulus)
+        .collect::<Vec<_>>();
+    let inner_table = (0..full_modulus)
+        .map(|_| rng.gen::<u64>() % full_modulus)
+        .collect::<Vec<_>>();
+    let outer = sks.generate_lookup_table_from_vec(&outer_table).unwrap();
+    let inner = sks.generate_lookup_table_from_vec(&inner_table).unwrap();
+    assert_eq!(sks.lookup_table_outputs(&outer), outer_table);
+
+    let composed = sks.compose_lookup_tables(&outer, &inner).unwrap();
+    let offset = rng.gen::<u64>() % full_modulus;
+    let input_offset = sks.lookup_table_with_input_offset(&inner, offset).unwrap();
+    let half = sks.generate_lookup_table(|x| x / 2);
+    let output_offset = sks
+        .lookup_table_with_output_offset(&half, full_modulus / 2)
+        .unwrap();
+    assert!(sks
+        .lookup_table_with_output_offset(&half, full_modulus / 2 + 1)
+        .is_err());
+
+    // The lookup tables are unchanged by a serialization round trip
+    let serialized = bincode::serialize(&composed).unwrap();
+    let deserialized: LookupTableOwned = bincode::deserialize(&serialized).unwrap();
+    assert_eq!(deserialized, composed);
+
+    for clear in 0..full_modulus {
+        let ctxt = cks.unchecked_encrypt(clear);
+
+        let ct_res = sks.apply_lookup_table(&ctxt, &composed);
+        let expected = outer_table[inner_table[clear as usize] as usize];
+        assert_eq!(cks.decrypt_message_and_carry(&ct_res), expected);
+
+        let ct_res = sks.apply_lookup_table(&ctxt, &input_offset);
+        let expected = inner_table[((clear + offset) % full_modulus) as usize];
+        assert_eq!(cks.decrypt_message_and_carry(&ct_res), expected);
+
+        let ct_res = sks.apply_lookup_table(&ctxt, &output_offset);
+        let expected = clear / 2 + full_modulus / 2;
+        assert_eq!(cks.decrypt_message_and_carry(&ct_res), expected);
+    }
+}
+
 /// test extraction of a message
 fn shortint_message_extract<P>(param: P)
 where
@@ -525,6 +805,90 @@ where
     }
 }
 
+/// test sanitization of ciphertexts with forged metadata
+fn shortint_sanitize<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    use crate::core_crypto::prelude::{
+        lwe_ciphertext_plaintext_add_assign, LweCiphertextOwned, LweSize, Plaintext,
+    };
+    use crate::shortint::ciphertext::{Degree, NoiseLevel};
+    use crate::shortint::SanitizationError;
+
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus_sup =
+        (cks.parameters.message_modulus().0 * cks.parameters.carry_modulus().0) as u64;
+
+    let modulus = cks.parameters.message_modulus().0 as u64;
+
+    for _ in 0..NB_TEST {
+        let clear = rng.gen::<u64>() % modulus_sup;
+
+        // encryption of an integer, with metadata claiming it is an empty trivial ciphertext
+        let mut ctxt = cks.unchecked_encrypt(clear);
+        ctxt.degree = Degree(0);
+        ctxt.noise_level = NoiseLevel::ZERO;
+        ctxt.message_modulus = MessageModulus(modulus_sup as usize);
+        ctxt.carry_modulus = CarryModulus(1);
+
+        sks.sanitize(&mut ctxt).unwrap();
+
+        assert_eq!(ctxt.degree, Degree(modulus as usize - 1));
+        assert_eq!(ctxt.noise_level(), NoiseLevel::NOMINAL);
+        assert_eq!(ctxt.message_modulus, sks.message_modulus);
+        assert_eq!(ctxt.carry_modulus, sks.carry_modulus);
+        assert_eq!(ctxt.pbs_order, sks.pbs_order);
+        assert!(ctxt.is_clean());
+
+        // decryption of the sanitized ciphertext
+        let dec = cks.decrypt(&ctxt);
+
+        // assert
+        assert_eq!(clear % modulus, dec);
+    }
+
+    for _ in 0..NB_TEST {
+        let clear = rng.gen::<u64>() % modulus;
+
+        // encryption of an integer, with the padding bit set
+        let mut ctxt = cks.encrypt(clear);
+        lwe_ciphertext_plaintext_add_assign(&mut ctxt.ct, Plaintext(1 << 63));
+
+        sks.sanitize(&mut ctxt).unwrap();
+
+        assert_eq!(ctxt.degree, Degree(modulus as usize - 1));
+        assert_eq!(ctxt.noise_level(), NoiseLevel::NOMINAL);
+        assert_eq!(cks.decrypt(&ctxt), clear);
+    }
+
+    // Ciphertexts which are not ciphertexts of the key are rejected, and left unchanged
+    let mut ctxt = cks.encrypt(0);
+    let lwe_size = ctxt.ct.lwe_size();
+    let ciphertext_modulus = ctxt.ct.ciphertext_modulus();
+
+    ctxt.ct = LweCiphertextOwned::new(0u64, LweSize(lwe_size.0 + 1), ciphertext_modulus);
+    assert_eq!(
+        sks.sanitize(&mut ctxt),
+        Err(SanitizationError::InvalidLweDimension {
+            expected: lwe_size.to_lwe_dimension(),
+            got: LweDimension(lwe_size.0),
+        })
+    );
+    assert_eq!(ctxt.ct.lwe_size(), LweSize(lwe_size.0 + 1));
+
+    let other_modulus = CiphertextModulus::try_new_power_of_2(62).unwrap();
+    ctxt.ct = LweCiphertextOwned::new(0u64, lwe_size, other_modulus);
+    assert_eq!(
+        sks.sanitize(&mut ctxt),
+        Err(SanitizationError::InvalidCiphertextModulus)
+    );
+}
+
 /// test multiplication with the LWE server key
 fn shortint_generate_lookup_table<P>(param: P)
 where
@@ -672,6 +1036,115 @@ where
     }
 }
 
+fn shortint_sum_many<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus().0 as u64;
+
+    for _ in 0..NB_TEST_SMART {
+        let num_cts = rng.gen_range(0..=NB_SUB_TEST_SMART);
+        let clears = (0..num_cts)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+
+        let ctxts = clears
+            .iter()
+            .map(|clear| cks.encrypt(*clear))
+            .collect::<Vec<_>>();
+        let ctxt_refs = ctxts.iter().collect::<Vec<_>>();
+
+        let (ct_res, stats) = sks.sum_many(&ctxt_refs);
+        assert!(ct_res.carry_is_empty());
+        assert_eq!(stats.additions, num_cts.saturating_sub(1));
+
+        let dec_res = cks.decrypt(&ct_res);
+        let clear_res = clears.iter().sum::<u64>();
+
+        assert_eq!(clear_res % modulus, dec_res);
+    }
+}
+
+fn shortint_convert_pbs_order<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    use crate::shortint::ciphertext::NoiseLevel;
+
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus().0 as u64;
+
+    let other_order = match sks.pbs_order {
+        PBSOrder::KeyswitchBootstrap => PBSOrder::BootstrapKeyswitch,
+        PBSOrder::BootstrapKeyswitch => PBSOrder::KeyswitchBootstrap,
+    };
+    // The key material is the same for both orders, only the order of operations differs
+    let mut other_sks = sks.clone();
+    other_sks.pbs_order = other_order;
+
+    for _ in 0..NB_TEST_SMART {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        // Converting to the same order is a no-op
+        let ct_same = sks.convert_pbs_order(&ctxt_0, sks.pbs_order);
+        assert_eq!(ct_same.ct, ctxt_0.ct);
+
+        // Carries are preserved by the conversion
+        let ct_sum = sks.unchecked_add(&ctxt_0, &ctxt_1);
+        let ct_sum_converted = sks.convert_pbs_order(&ct_sum, other_order);
+        assert_eq!(ct_sum_converted.pbs_order, other_order);
+        assert_eq!(ct_sum_converted.degree, ct_sum.degree);
+        // A key switch adds noise, a bootstrap resets it
+        let expected_noise_level = match other_order {
+            PBSOrder::BootstrapKeyswitch => ct_sum.noise_level() + NoiseLevel::NOMINAL,
+            PBSOrder::KeyswitchBootstrap => NoiseLevel::NOMINAL,
+        };
+        assert_eq!(ct_sum_converted.noise_level(), expected_noise_level);
+        assert!(
+            ct_sum_converted.is_conformant(&CiphertextConformanceParams {
+                degree: ct_sum.degree,
+                ..sks.conformance_params_for_pbs_order(other_order)
+            })
+        );
+        assert!(
+            !ct_sum_converted.is_conformant(&CiphertextConformanceParams {
+                degree: ct_sum.degree,
+                ..sks.conformance_params()
+            })
+        );
+        assert_eq!(
+            cks.decrypt_message_and_carry(&ct_sum_converted),
+            clear_0 + clear_1
+        );
+
+        // Converted ciphertexts can be used with a key using the other order
+        let ct_res = other_sks.message_extract(&ct_sum_converted);
+        assert_eq!(ct_res.pbs_order, other_order);
+        assert_eq!(cks.decrypt(&ct_res), (clear_0 + clear_1) % modulus);
+
+        let mut ct_back = ct_res;
+        sks.convert_to_key_pbs_order_assign(&mut ct_back);
+        assert_eq!(ct_back.pbs_order, sks.pbs_order);
+        assert!(ct_back.is_conformant(&sks.conformance_params()));
+        assert_eq!(cks.decrypt(&ct_back), (clear_0 + clear_1) % modulus);
+    }
+}
+
 /// test addition with the LWE server key using the a public key for encryption
 fn shortint_compressed_public_key_smart_add<P>(param: P)
 where
@@ -715,6 +1188,64 @@ where
     }
 }
 
+/// test the re-randomized copies of a ciphertext made with a public key
+fn shortint_public_key_duplicate<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    let pk = crate::shortint::PublicKey::new(cks);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus().0 as u64;
+
+    for _ in 0..NB_TEST_SMART {
+        let clear = rng.gen::<u64>() % modulus;
+
+        // A dirty ciphertext, the copies must keep its degree
+        let ct = sks.unchecked_add(&cks.encrypt(clear), &cks.encrypt(0));
+
+        let copies = sks.duplicate_many(&ct, 2, &pk);
+        assert_ne!(copies[0].ct, ct.ct);
+        assert_ne!(copies[0].ct, copies[1].ct);
+
+        for copy in &copies {
+            assert_eq!(copy.degree, ct.degree);
+            assert_eq!(cks.decrypt(copy), clear);
+        }
+    }
+}
+
+/// The ciphertexts encrypted with a public key are never taken for PBS outputs, so the message
+/// extraction cleaning them is not skipped
+fn shortint_public_key_noise_level<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    use crate::shortint::ciphertext::NoiseLevel;
+
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    let pk = crate::shortint::PublicKey::new(cks);
+    let compressed_pk = crate::shortint::CompressedPublicKey::new(cks);
+
+    let modulus = cks.parameters.message_modulus().0 as u64;
+
+    for clear in 0..modulus {
+        for ct in [pk.encrypt(clear), compressed_pk.encrypt(clear)] {
+            assert!(ct.noise_level() > NoiseLevel::NOMINAL);
+
+            let res = sks.message_extract(&ct);
+            assert_eq!(res.noise_level(), NoiseLevel::NOMINAL);
+            assert_ne!(res.ct, ct.ct);
+            assert_eq!(cks.decrypt(&res), clear);
+        }
+    }
+}
+
 /// test addition with the LWE server key using the a public key for encryption
 fn shortint_public_key_smart_add<P>(param: P)
 where
@@ -2170,6 +2701,45 @@ where
     }
 }
 
+fn shortint_unchecked_div_rem<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus().0 as u64;
+
+    // check div by 0 result
+    {
+        let numerator = rng.gen::<u64>() % modulus;
+
+        let ct_num = cks.encrypt(numerator);
+        let ct_denom = cks.encrypt(0);
+        let (ct_q, ct_r) = sks.unchecked_div_rem(&ct_num, &ct_denom);
+
+        assert_eq!(cks.decrypt(&ct_q), modulus - 1);
+        assert_eq!(cks.decrypt(&ct_r), numerator);
+    }
+
+    for _ in 0..NB_TEST {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = (rng.gen::<u64>() % (modulus - 1)) + 1;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let (ct_q, ct_r) = sks.unchecked_div_rem(&ctxt_0, &ctxt_1);
+
+        assert!(ct_q.degree.0 < modulus as usize);
+        assert!(ct_r.degree.0 <= ctxt_0.degree.0);
+        assert_eq!(cks.decrypt(&ct_q), clear_0 / clear_1);
+        assert_eq!(cks.decrypt(&ct_r), clear_0 % clear_1);
+    }
+}
+
 /// test scalar division with the LWE server key
 fn shortint_unchecked_scalar_div<P>(param: P)
 where
@@ -3197,6 +3767,51 @@ where
     }
 }
 
+fn shortint_bivariate_pbs_unconstrained<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let mut rng = rand::thread_rng();
+
+    let msg_modulus = cks.parameters.message_modulus().0 as u64;
+    let full_modulus = msg_modulus * cks.parameters.carry_modulus().0 as u64;
+
+    let f = |x: u64, y: u64| (x * 3 + y) % msg_modulus;
+
+    for _ in 0..NB_TEST_SMART {
+        let clear_left = rng.gen::<u64>() % full_modulus;
+        let clear_right = rng.gen::<u64>() % full_modulus;
+
+        // Encrypt with full degrees to force the renormalization
+        let ct_left = cks.unchecked_encrypt(clear_left);
+        let ct_right = cks.unchecked_encrypt(clear_right);
+
+        let ct_res = sks.bivariate_pbs_unconstrained(&ct_left, &ct_right, f);
+        assert_eq!(
+            cks.decrypt(&ct_res),
+            f(clear_left % msg_modulus, clear_right % msg_modulus)
+        );
+
+        // Fresh ciphertexts only need the bivariate PBS
+        let ct_left = cks.encrypt(clear_left);
+        let ct_right = cks.encrypt(clear_right);
+        assert!(sks.is_functional_bivariate_pbs_possible(&ct_left, &ct_right));
+        assert_eq!(
+            sks.bivariate_pbs_unconstrained_pbs_count(&ct_left, &ct_right),
+            1
+        );
+
+        let ct_res = sks.bivariate_pbs_unconstrained(&ct_left, &ct_right, f);
+        assert_eq!(
+            cks.decrypt(&ct_res),
+            f(clear_left % msg_modulus, clear_right % msg_modulus)
+        );
+    }
+}
+
 /// test simulating a MUX
 fn shortint_mux<P>(param: P)
 where
@@ -3226,3 +3841,431 @@ where
     println!("(msg_true - msg_false) * control_bit  + msg_false = {clear_mux}, res = {dec_res}");
     assert_eq!(clear_mux, dec_res);
 }
+
+#[test]
+fn test_server_key_from_raw_parts() {
+    let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2_PBS_KS);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let (bootstrapping_key, key_switching_key) = sks.clone().into_raw_parts();
+    let sks_raw = crate::shortint::ServerKey::from_raw_parts(
+        bootstrapping_key,
+        key_switching_key,
+        PARAM_MESSAGE_2_CARRY_2_PBS_KS,
+    );
+    assert_eq!(sks_raw.pbs_order, PBSOrder::BootstrapKeyswitch);
+    assert_eq!(sks_raw.max_degree, sks.max_degree);
+    assert_eq!(sks_raw.max_noise_level, sks.max_noise_level);
+
+    let ct = cks.encrypt(3);
+    let ct_res = sks_raw.scalar_mul(&ct, 3);
+    assert_eq!(cks.decrypt(&ct_res), 1);
+}
+
+#[test]
+#[should_panic(expected = "Mismatch between the input LweDimension of the keyswitch key")]
+fn test_server_key_from_raw_parts_mismatched_keys() {
+    let keys_1_1 = KEY_CACHE.get_from_param(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+    let keys_2_2 = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+
+    let (_, key_switching_key) = keys_1_1.server_key().clone().into_raw_parts();
+    let (bootstrapping_key, _) = keys_2_2.server_key().clone().into_raw_parts();
+
+    let _ = crate::shortint::ServerKey::from_raw_parts(
+        bootstrapping_key,
+        key_switching_key,
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Mismatch between the DecompositionLevelCount of the bootstrapping key")]
+fn test_server_key_from_raw_parts_mismatched_parameters() {
+    let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+
+    let (bootstrapping_key, key_switching_key) = keys.server_key().clone().into_raw_parts();
+
+    let mut parameters = PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    parameters.pbs_level.0 += 1;
+
+    let _ = crate::shortint::ServerKey::from_raw_parts(
+        bootstrapping_key,
+        key_switching_key,
+        parameters,
+    );
+}
+
+#[test]
+fn test_max_noise_level_is_enforced() {
+    use crate::shortint::ciphertext::NoiseLevel;
+    use crate::shortint::CheckError;
+
+    let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let msg = 2;
+    let mut ct = cks.encrypt(msg);
+
+    // A PBS output encrypting a constant has a degree of 0 but a nominal noise level, summing it
+    // increases the noise level without filling the carries
+    let zero_lut = sks.generate_lookup_table(|_| 0);
+    let zero = sks.apply_lookup_table(&cks.encrypt(0), &zero_lut);
+    assert_eq!(zero.degree.0, 0);
+    assert_eq!(zero.noise_level, NoiseLevel::NOMINAL);
+
+    while sks.max_noise_level.validate(ct.noise_level).is_ok() {
+        sks.unchecked_add_assign(&mut ct, &zero);
+    }
+    assert!(ct.carry_is_empty());
+    assert!(!ct.is_clean());
+    assert!(!sks.is_add_possible(&ct, &zero));
+
+    let res = sks.checked_add(&ct, &zero);
+    assert!(matches!(res, Err(CheckError::NoiseTooBig { .. })));
+    let res = sks.checked_scalar_mul(&ct, 1);
+    assert!(matches!(res, Err(CheckError::NoiseTooBig { .. })));
+    let res = sks.checked_bitand(&ct, &zero);
+    assert!(matches!(res, Err(CheckError::NoiseTooBig { .. })));
+
+    // The default and smart operations bootstrap the ciphertext
+    let res = sks.add(&ct, &zero);
+    assert_eq!(res.noise_level, NoiseLevel::NOMINAL);
+    assert_eq!(cks.decrypt(&res), msg);
+
+    let mut zero = zero;
+    let res = sks.smart_add(&mut ct, &mut zero);
+    assert!(sks.max_noise_level.validate(res.noise_level).is_ok());
+    assert_eq!(cks.decrypt(&res), msg);
+}
+
+#[test]
+fn test_max_noise_level_bootstrap_policy() {
+    use crate::shortint::ciphertext::NoiseLevel;
+    use crate::shortint::server_key::MaxNoiseLevelPolicy;
+    use crate::shortint::CheckError;
+
+    let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    let (cks, mut sks) = (keys.client_key(), keys.server_key().clone());
+    sks.set_max_noise_level_policy(MaxNoiseLevelPolicy::Bootstrap);
+
+    let msg = 2;
+    let mut ct = cks.encrypt(msg);
+
+    let zero_lut = sks.generate_lookup_table(|_| 0);
+    let zero = sks.apply_lookup_table(&cks.encrypt(0), &zero_lut);
+    while sks.max_noise_level.validate(ct.noise_level).is_ok() {
+        sks.unchecked_add_assign(&mut ct, &zero);
+    }
+    assert!(!sks.is_add_possible(&ct, &zero));
+
+    // The noisy operand is bootstrapped, its carries are kept
+    let res = sks.checked_add(&ct, &ct).unwrap();
+    assert_eq!(res.noise_level, NoiseLevel::NOMINAL + NoiseLevel::NOMINAL);
+    assert_eq!(cks.decrypt_message_and_carry(&res), 2 * msg);
+
+    let res = sks.checked_scalar_mul(&ct, 3).unwrap();
+    assert_eq!(cks.decrypt_message_and_carry(&res), 3 * msg);
+
+    let res = sks.checked_bitand(&ct, &zero).unwrap();
+    assert_eq!(cks.decrypt(&res), 0);
+
+    let mut res = ct.clone();
+    sks.checked_add_assign(&mut res, &zero).unwrap();
+    assert_eq!(res.noise_level, NoiseLevel::NOMINAL + NoiseLevel::NOMINAL);
+    assert_eq!(cks.decrypt(&res), msg);
+
+    // The policy does not apply when the carries are full
+    let res = sks.checked_add(&ct, &sks.unchecked_scalar_mul(&cks.encrypt(0), 5));
+    assert!(matches!(res, Err(CheckError::CarryFull)));
+}
+
+#[test]
+fn test_deserialize_ciphertext_without_noise_level() {
+    use crate::shortint::ciphertext::{LegacyCiphertext, NoiseLevel};
+    use crate::shortint::Ciphertext;
+
+    let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let ct = cks.encrypt(3);
+    let sum = sks.unchecked_add(&ct, &ct);
+
+    let serialized = bincode::serialize(&sum).unwrap();
+    let deserialized: Ciphertext = bincode::deserialize(&serialized).unwrap();
+    assert_eq!(deserialized, sum);
+
+    // The ciphertexts serialized before the noise level was tracked only had these fields, in
+    // this order
+    let legacy_serialized = bincode::serialize(&(
+        &sum.ct,
+        sum.degree,
+        sum.message_modulus,
+        sum.carry_modulus,
+        sum.pbs_order,
+    ))
+    .unwrap();
+    assert!(bincode::deserialize::<Ciphertext>(&legacy_serialized).is_err());
+
+    // Their noise is unknown, they get the maximum noise level
+    let deserialized: Ciphertext = bincode::deserialize::<LegacyCiphertext>(&legacy_serialized)
+        .unwrap()
+        .into();
+    assert_eq!(
+        deserialized.noise_level,
+        NoiseLevel::NOMINAL * sks.max_noise_level.get()
+    );
+    assert_eq!(deserialized.ct, sum.ct);
+    assert_eq!(cks.decrypt(&deserialized), cks.decrypt(&sum));
+
+    // Nothing can be added to them before they are bootstrapped
+    assert!(sks.checked_add(&deserialized, &ct).is_err());
+    let refreshed = sks.add(&deserialized, &ct);
+    assert_eq!(cks.decrypt(&refreshed), (3 + 3 + 3) % 4);
+}
+
+#[test]
+fn test_configured_max_noise_level() {
+    use crate::shortint::server_key::MaxNoiseLevel;
+    use crate::shortint::CheckError;
+
+    let params = ShortintParameterSet::from(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    let default_max_noise_level = params.max_noise_level();
+    assert_eq!(
+        default_max_noise_level,
+        MaxNoiseLevel::from_msg_carry_modulus(params.message_modulus(), params.carry_modulus())
+    );
+
+    // The parameters cannot support more noise than the default maximum, nor no noise at all
+    assert!(params
+        .with_max_noise_level(MaxNoiseLevel::new(default_max_noise_level.get() + 1))
+        .is_err());
+    assert!(params.with_max_noise_level(MaxNoiseLevel::new(0)).is_err());
+    assert!(params.with_max_noise_level(default_max_noise_level).is_ok());
+
+    let params = params.with_max_noise_level(MaxNoiseLevel::new(2)).unwrap();
+    let (cks, sks) = crate::shortint::gen_keys(params);
+    assert_eq!(cks.parameters.max_noise_level(), MaxNoiseLevel::new(2));
+    assert_eq!(sks.max_noise_level, MaxNoiseLevel::new(2));
+
+    let ct = cks.encrypt(1);
+    let sum = sks.checked_add(&ct, &ct).unwrap();
+    assert_eq!(cks.decrypt(&sum), 2);
+
+    // The degree allows more additions, the noise level does not
+    assert!(sks.max_degree.0 >= sum.degree.0 + ct.degree.0);
+    let res = sks.checked_add(&sum, &ct);
+    assert!(matches!(res, Err(CheckError::NoiseTooBig { .. })));
+    let res = sks.checked_scalar_mul(&ct, 3);
+    assert!(matches!(res, Err(CheckError::NoiseTooBig { .. })));
+    let res = sks.checked_mul_lsb(&sum, &ct);
+    assert!(matches!(res, Err(CheckError::NoiseTooBig { .. })));
+
+    // The configured maximum is kept by the server key, the serialized parameters keep the
+    // layout of the ones without a maximum
+    let serialized = bincode::serialize(&sks).unwrap();
+    let deserialized: crate::shortint::ServerKey = bincode::deserialize(&serialized).unwrap();
+    assert_eq!(deserialized.max_noise_level, MaxNoiseLevel::new(2));
+
+    let base_params = ShortintParameterSet::from(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    let serialized = bincode::serialize(&params).unwrap();
+    assert_eq!(serialized, bincode::serialize(&base_params).unwrap());
+    let deserialized: ShortintParameterSet = bincode::deserialize(&serialized).unwrap();
+    assert_eq!(deserialized, base_params);
+}
+
+#[test]
+fn test_configured_max_noise_level_name() {
+    use crate::keycache::NamedParam;
+    use crate::shortint::server_key::MaxNoiseLevel;
+
+    let params = ShortintParameterSet::from(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    assert_eq!(params.name(), "PARAM_MESSAGE_2_CARRY_2_KS_PBS");
+
+    // Keys generated with a custom maximum noise level must not be cached as the base ones
+    let custom_params = params.with_max_noise_level(MaxNoiseLevel::new(2)).unwrap();
+    assert_eq!(
+        custom_params.name(),
+        "PARAM_MESSAGE_2_CARRY_2_KS_PBS_MAX_NOISE_LEVEL_2"
+    );
+}
+
+#[test]
+fn test_remaining_noise_budget() {
+    use crate::shortint::ciphertext::NoiseLevel;
+
+    let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    let max_noise_level = sks.max_noise_level.get();
+
+    let trivial = sks.create_trivial(1);
+    let budget = trivial.remaining_noise_budget(sks.max_noise_level);
+    assert_eq!(budget.remaining_additions(), max_noise_level);
+    assert_eq!(budget.max_scalar_multiplier(), usize::MAX);
+
+    let mut ct = cks.encrypt(0);
+    for i in 1..=max_noise_level {
+        let budget = ct.remaining_noise_budget(sks.max_noise_level);
+        assert_eq!(budget.remaining_additions(), max_noise_level - i);
+        assert_eq!(budget.max_scalar_multiplier(), max_noise_level / i);
+        assert_eq!(
+            budget.is_exhausted(),
+            sks.max_noise_level
+                .validate(ct.noise_level() + NoiseLevel::NOMINAL)
+                .is_err()
+        );
+        if !budget.is_exhausted() {
+            sks.unchecked_add_assign(&mut ct, &cks.encrypt(0));
+        }
+    }
+    assert!(ct
+        .remaining_noise_budget(sks.max_noise_level)
+        .is_exhausted());
+}
+
+#[test]
+fn test_scalar_mul_refreshed() {
+    use crate::shortint::ciphertext::NoiseLevel;
+
+    let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    let modulus = cks.parameters.message_modulus().0 as u64;
+
+    let zero_lut = sks.generate_lookup_table(|_| 0);
+    let zero = sks.apply_lookup_table(&cks.encrypt(0), &zero_lut);
+
+    for msg in 0..modulus {
+        // Fresh ciphertexts are multiplied directly
+        let ct = cks.encrypt(msg);
+        let res = sks.scalar_mul_refreshed(&ct, 3);
+        assert_eq!(res, sks.unchecked_scalar_mul(&ct, 3));
+        assert_eq!(res.noise_level, ct.noise_level * 3);
+        assert_eq!(cks.decrypt(&res), (msg * 3) % modulus);
+
+        // Summing a ciphertext to itself gives the same noise as the direct multiplication, so a
+        // refresh is the only way to keep it under the maximum
+        let mut ct = cks.encrypt(msg);
+        sks.unchecked_add_assign(&mut ct, &zero);
+
+        // The unchecked multiplication stays linear, whatever its noise
+        let res = sks.unchecked_scalar_mul(&ct, 5);
+        assert_eq!(res.noise_level, ct.noise_level * 5);
+        assert!(sks.max_noise_level.validate(res.noise_level).is_err());
+
+        // 5 * ct is computed as refresh(2 * ct) * 2 + ct
+        let res = sks.scalar_mul_refreshed(&ct, 5);
+        assert_eq!(res.noise_level, NoiseLevel::NOMINAL * 2 + ct.noise_level);
+        assert!(sks.max_noise_level.validate(res.noise_level).is_ok());
+        assert_eq!(res.degree.0, ct.degree.0 * 5);
+        assert_eq!(cks.decrypt(&res), (msg * 5) % modulus);
+
+        while sks
+            .max_noise_level
+            .validate(ct.noise_level + NoiseLevel::NOMINAL)
+            .is_ok()
+        {
+            sks.unchecked_add_assign(&mut ct, &zero);
+        }
+        let mut res = ct.clone();
+        sks.scalar_mul_refreshed_assign(&mut res, 3);
+        assert_eq!(res.noise_level, NoiseLevel::NOMINAL * 3);
+        assert_eq!(cks.decrypt(&res), (msg * 3) % modulus);
+    }
+}
+
+#[test]
+fn test_deterministic_server_key() {
+    use crate::shortint::server_key::{MaxDegree, PbsBackend, ShortintBootstrappingKey};
+    use crate::shortint::{CompressedServerKey, ServerKey};
+
+    let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    let cks = keys.client_key();
+    let modulus = cks.parameters.message_modulus().0 as u64;
+    let max_degree =
+        MaxDegree(cks.parameters.message_modulus().0 * cks.parameters.carry_modulus().0 - 1);
+
+    // The engines are left in their default mode, as the tests run concurrently
+    let sks = ServerKey::new_with_max_degree_and_pbs_backend(cks, max_degree, PbsBackend::Ntt);
+    assert!(matches!(
+        sks.bootstrapping_key,
+        ShortintBootstrappingKey::Ntt(_)
+    ));
+    assert_eq!(sks.pbs_backend(), PbsBackend::Ntt);
+    assert_eq!(keys.server_key().pbs_backend(), PbsBackend::Fft);
+
+    let decompressed = CompressedServerKey::new(cks).decompress_with_pbs_backend(PbsBackend::Ntt);
+    assert_eq!(decompressed.pbs_backend(), PbsBackend::Ntt);
+
+    let serialized = bincode::serialize(&sks).unwrap();
+    let deserialized: ServerKey = bincode::deserialize(&serialized).unwrap();
+    assert_eq!(deserialized, sks);
+
+    let lut = sks.generate_lookup_table(|x| (x * 3 + 1) % modulus);
+    let luts = [
+        sks.generate_lookup_table(|x| x / 2),
+        sks.generate_lookup_table(|x| x % 2),
+    ];
+    for msg in 0..modulus {
+        let ct = cks.encrypt(msg);
+
+        let res = sks.apply_lookup_table(&ct, &lut);
+        assert_eq!(cks.decrypt(&res), (msg * 3 + 1) % modulus);
+
+        let res = sks.apply_many_lookup_tables(&ct, &luts);
+        assert_eq!(cks.decrypt(&res[0]), msg / 2);
+        assert_eq!(cks.decrypt(&res[1]), msg % 2);
+
+        let res = decompressed.apply_lookup_table(&ct, &lut);
+        assert_eq!(cks.decrypt(&res), (msg * 3 + 1) % modulus);
+    }
+}
+
+#[test]
+fn test_no_op_lookup_table_is_skipped() {
+    use crate::shortint::ciphertext::{Degree, NoiseLevel};
+    use crate::shortint::engine::skipped_programmable_bootstrap_count;
+
+    let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    let modulus = cks.parameters.message_modulus().0 as u64;
+
+    let message_lut = sks.generate_lookup_table(|x| x % modulus);
+    let carry_lut = sks.generate_lookup_table(|x| x / modulus);
+
+    for msg in 0..modulus {
+        let ct = cks.encrypt(msg);
+
+        // Other tests may run in parallel, the count can only be checked to increase
+        let count_before = skipped_programmable_bootstrap_count();
+        let res = sks.apply_lookup_table(&ct, &message_lut);
+        assert!(skipped_programmable_bootstrap_count() > count_before);
+        assert_eq!(res, ct);
+
+        let res = sks.message_extract(&ct);
+        assert_eq!(res, ct);
+
+        // The carry extraction is only the identity on 0
+        let res = sks.apply_lookup_table(&ct, &carry_lut);
+        assert_ne!(res.ct, ct.ct);
+        assert_eq!(cks.decrypt(&res), 0);
+
+        // The carries may not be empty
+        let mut with_carries = ct.clone();
+        sks.unchecked_add_assign(&mut with_carries, &cks.encrypt(0));
+        sks.message_extract_assign(&mut with_carries);
+        assert_eq!(with_carries.noise_level(), NoiseLevel::NOMINAL);
+        assert_ne!(with_carries.ct, ct.ct);
+        assert_eq!(cks.decrypt(&with_carries), msg);
+
+        // The metadata of the input is not trusted by the sanitization
+        let mut res = ct.clone();
+        sks.sanitize(&mut res).unwrap();
+        assert_ne!(res.ct, ct.ct);
+        assert_eq!(cks.decrypt(&res), msg);
+    }
+
+    // A ciphertext known to encrypt 0 is left unchanged by the carry extraction
+    let mut ct = cks.encrypt(0);
+    ct.degree = Degree(0);
+    let res = sks.carry_extract(&ct);
+    assert_eq!(res, ct);
+}
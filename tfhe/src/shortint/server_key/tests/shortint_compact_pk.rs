@@ -219,3 +219,421 @@ fn shortint_compact_public_key_base_list_smart_sub(params: ClassicPBSParameters)
         }
     }
 }
+
+create_parametrized_test!(shortint_compact_public_key_base_list_expand_with {
+    PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS,
+    PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_PBS_KS
+});
+
+fn shortint_compact_public_key_base_list_expand_with(params: ClassicPBSParameters) {
+    use crate::conformance::{ListSizeConstraint, ParameterSetConformant};
+    use crate::shortint::ciphertext::{ExpandConfig, ExpandError};
+
+    let keys = KEY_CACHE.get_from_param(params);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    let pk = crate::shortint::CompactPublicKey::new(cks);
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus().0 as u64;
+
+    const MAX_CT: usize = 5;
+
+    for _ in 0..(NB_TEST / 2).min(5) {
+        let num_ct_for_this_iter = rng.gen_range(1..=MAX_CT);
+        let clear_vec = (0..num_ct_for_this_iter)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+
+        let compact_list = pk.encrypt_slice(&clear_vec);
+
+        let conformance_params = sks.conformance_params().to_ct_list_conformance_parameters(
+            ListSizeConstraint::exact_size(num_ct_for_this_iter),
+        );
+        let config = ExpandConfig::new()
+            .with_conformance_params(&conformance_params)
+            .with_sanitizing_key(sks);
+
+        let expanded_vec = compact_list.expand_with(config).unwrap();
+        assert_eq!(expanded_vec.len(), num_ct_for_this_iter);
+
+        for (ct, clear) in expanded_vec.iter().zip(clear_vec.iter()) {
+            assert!(ct.is_conformant(&sks.conformance_params()));
+            assert_eq!(cks.decrypt(ct), *clear);
+        }
+
+        let wrong_conformance_params = sks.conformance_params().to_ct_list_conformance_parameters(
+            ListSizeConstraint::exact_size(num_ct_for_this_iter + 1),
+        );
+        let config = ExpandConfig::new().with_conformance_params(&wrong_conformance_params);
+        assert_eq!(
+            compact_list.expand_with(config).unwrap_err(),
+            ExpandError::NonConformantList
+        );
+    }
+}
+
+create_parametrized_test!(shortint_compact_public_key_noise_level {
+    PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS,
+    PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_PBS_KS
+});
+
+/// The ciphertexts encrypted with a compact public key are never taken for PBS outputs, so the
+/// PBS sanitizing them is not skipped, even when the list was re-randomized
+fn shortint_compact_public_key_noise_level(params: ClassicPBSParameters) {
+    use crate::shortint::ciphertext::{ExpandConfig, NoiseLevel};
+
+    let keys = KEY_CACHE.get_from_param(params);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    let pk = crate::shortint::CompactPublicKey::new(cks);
+
+    let ct = pk.encrypt(1);
+    assert!(ct.noise_level() > NoiseLevel::NOMINAL);
+
+    let modulus = cks.parameters.message_modulus().0 as u64;
+    let clear_vec = (0..modulus).collect::<Vec<_>>();
+
+    let compact_list = pk.encrypt_slice(&clear_vec);
+    let rerandomized_list = pk.rerandomize_compact_list(&compact_list);
+    let twice_rerandomized_list = pk.rerandomize_compact_list(&rerandomized_list);
+
+    // Each re-randomization adds the noise of a fresh encryption
+    assert!(rerandomized_list.noise_level > compact_list.noise_level);
+    assert!(twice_rerandomized_list.noise_level > rerandomized_list.noise_level);
+    assert!(rerandomized_list.expand()[0].noise_level() > compact_list.expand()[0].noise_level());
+
+    let serialized = bincode::serialize(&twice_rerandomized_list).unwrap();
+    let deserialized: crate::shortint::ciphertext::CompactCiphertextList =
+        bincode::deserialize(&serialized).unwrap();
+    assert_eq!(deserialized, twice_rerandomized_list);
+
+    for list in [compact_list, rerandomized_list, twice_rerandomized_list] {
+        let expanded_vec = list.expand();
+        assert!(expanded_vec
+            .iter()
+            .all(|ct| ct.noise_level() > NoiseLevel::NOMINAL));
+
+        let config = ExpandConfig::new().with_sanitizing_key(sks);
+        let sanitized_vec = list.expand_with(config).unwrap();
+
+        for ((ct, sanitized), clear) in expanded_vec.iter().zip(&sanitized_vec).zip(&clear_vec) {
+            assert_eq!(sanitized.noise_level(), NoiseLevel::NOMINAL);
+            assert_ne!(sanitized.ct, ct.ct);
+            assert_eq!(cks.decrypt(sanitized), *clear);
+        }
+    }
+}
+
+create_parametrized_test!(shortint_compact_public_key_base_list_iter_expand {
+    PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS,
+    PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_PBS_KS
+});
+
+fn shortint_compact_public_key_base_list_iter_expand(params: ClassicPBSParameters) {
+    use crate::conformance::ListSizeConstraint;
+    use crate::shortint::ciphertext::{ExpandConfig, ExpandError};
+
+    let keys = KEY_CACHE.get_from_param(params);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    let pk = crate::shortint::CompactPublicKey::new(cks);
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus().0 as u64;
+
+    // More ciphertexts than the LWE dimension, so that the list holds several masks
+    let num_ct = pk.key.lwe_dimension().0 + 3;
+    let clear_vec = (0..num_ct)
+        .map(|_| rng.gen::<u64>() % modulus)
+        .collect::<Vec<_>>();
+
+    let compact_list = pk.encrypt_slice(&clear_vec);
+
+    let iter = compact_list.iter_expand();
+    assert_eq!(iter.len(), num_ct);
+    let streamed = iter.collect::<Vec<_>>();
+    assert_eq!(streamed, compact_list.expand());
+
+    let conformance_params = sks
+        .conformance_params()
+        .to_ct_list_conformance_parameters(ListSizeConstraint::exact_size(num_ct));
+    let config = ExpandConfig::new()
+        .with_conformance_params(&conformance_params)
+        .with_sanitizing_key(sks);
+
+    // Sanitizing every ciphertext would be slow, the first ones cover the first mask
+    for (ct, clear) in compact_list
+        .iter_expand_with(config)
+        .unwrap()
+        .zip(clear_vec.iter())
+        .take(5)
+    {
+        assert_eq!(cks.decrypt(&ct), *clear);
+    }
+
+    let wrong_conformance_params = sks
+        .conformance_params()
+        .to_ct_list_conformance_parameters(ListSizeConstraint::exact_size(num_ct + 1));
+    let config = ExpandConfig::new().with_conformance_params(&wrong_conformance_params);
+    assert!(matches!(
+        compact_list.iter_expand_with(config),
+        Err(ExpandError::NonConformantList)
+    ));
+}
+
+create_parametrized_test!(shortint_compact_public_key_base_list_expand_booleans_with {
+    PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS,
+    PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_PBS_KS
+});
+
+fn shortint_compact_public_key_base_list_expand_booleans_with(params: ClassicPBSParameters) {
+    use crate::shortint::ciphertext::{Degree, ExpandConfig, ExpandError};
+
+    let keys = KEY_CACHE.get_from_param(params);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    let pk = crate::shortint::CompactPublicKey::new(cks);
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus().0 as u64;
+
+    const MAX_CT: usize = 5;
+
+    for _ in 0..(NB_TEST / 2).min(5) {
+        let num_ct_for_this_iter = rng.gen_range(1..=MAX_CT);
+        // Values outside of the boolean range must be sanitized too
+        let clear_vec = (0..num_ct_for_this_iter)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+
+        let compact_list = pk.encrypt_slice(&clear_vec);
+
+        let config = ExpandConfig::new().with_sanitizing_key(sks);
+        let expanded_vec = compact_list.expand_booleans_with(config).unwrap();
+        assert_eq!(expanded_vec.len(), num_ct_for_this_iter);
+
+        for (ct, clear) in expanded_vec.iter().zip(clear_vec.iter()) {
+            assert_eq!(ct.degree, Degree(1));
+            assert_eq!(cks.decrypt(ct), u64::from(*clear != 0));
+        }
+
+        assert_eq!(
+            compact_list
+                .expand_booleans_with(ExpandConfig::new())
+                .unwrap_err(),
+            ExpandError::MissingSanitizingKey
+        );
+    }
+}
+
+create_parametrized_test!(shortint_compact_public_key_base_list_encrypt_stream {
+    PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS,
+    PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_PBS_KS
+});
+
+fn shortint_compact_public_key_base_list_encrypt_stream(params: ClassicPBSParameters) {
+    use crate::conformance::ListSizeConstraint;
+    use crate::shortint::ciphertext::{CompactCiphertextList, Degree, ExpandConfig, ExpandError};
+
+    let keys = KEY_CACHE.get_from_param(params);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    let pk = crate::shortint::CompactPublicKey::new(cks);
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus().0 as u64;
+
+    const CHUNK_SIZE: usize = 4;
+
+    let num_ct = 2 * CHUNK_SIZE + 1;
+    let clear_vec = (0..num_ct)
+        .map(|_| rng.gen::<u64>() % modulus)
+        .collect::<Vec<_>>();
+
+    let lists = pk
+        .encrypt_stream(clear_vec.iter().copied(), CHUNK_SIZE)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        lists
+            .iter()
+            .map(|list| list.ct_list.lwe_ciphertext_count().0)
+            .collect::<Vec<_>>(),
+        [CHUNK_SIZE, CHUNK_SIZE, 1]
+    );
+    assert_eq!(pk.encrypt_stream(std::iter::empty(), CHUNK_SIZE).count(), 0);
+
+    let conformance_params = sks.conformance_params().to_ct_list_conformance_parameters(
+        ListSizeConstraint::try_size_in_range(1, CHUNK_SIZE).unwrap(),
+    );
+    let config = ExpandConfig::new()
+        .with_conformance_params(&conformance_params)
+        .with_sanitizing_key(sks);
+
+    let mut decrypted = Vec::with_capacity(num_ct);
+    for chunk in CompactCiphertextList::expand_stream(lists.clone(), config) {
+        decrypted.extend(chunk.unwrap().iter().map(|ct| cks.decrypt(ct)));
+    }
+    assert_eq!(decrypted, clear_vec);
+
+    let mut inconsistent_lists = lists;
+    inconsistent_lists[1].degree = Degree(1);
+    let results = CompactCiphertextList::expand_stream(inconsistent_lists, ExpandConfig::new())
+        .collect::<Vec<_>>();
+    assert_eq!(results.len(), 3);
+    assert!(results[0].is_ok());
+    assert_eq!(
+        results[1].as_ref().unwrap_err(),
+        &ExpandError::InconsistentChunk(1)
+    );
+    assert!(results[2].is_ok());
+}
+
+create_parametrized_test!(shortint_compact_public_key_base_list_element_metadata {
+    PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS,
+    PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_PBS_KS
+});
+
+fn shortint_compact_public_key_base_list_element_metadata(params: ClassicPBSParameters) {
+    use crate::conformance::{ListSizeConstraint, ParameterSetConformant};
+    use crate::shortint::ciphertext::{CompactCiphertextList, Degree, ExpandConfig, ExpandError};
+
+    let keys = KEY_CACHE.get_from_param(params);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    let pk = crate::shortint::CompactPublicKey::new(cks);
+
+    let mut rng = rand::thread_rng();
+
+    let total_modulus = cks.parameters.message_modulus().0 * cks.parameters.carry_modulus().0;
+
+    const NUM_CT: usize = 8;
+
+    let messages = (0..NUM_CT)
+        .map(|_| {
+            let message_modulus = 1 << rng.gen_range(1..=total_modulus.ilog2());
+            (
+                rng.gen::<u64>() % message_modulus as u64,
+                MessageModulus(message_modulus),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let compact_list = pk.encrypt_slice_with_message_moduli(&messages);
+
+    let conformance_params = sks
+        .conformance_params()
+        .to_ct_list_conformance_parameters(ListSizeConstraint::exact_size(NUM_CT));
+    assert!(compact_list.is_conformant(&conformance_params));
+
+    let check_expanded = |expanded: &[crate::shortint::Ciphertext], messages: &[(u64, _)]| {
+        assert_eq!(expanded.len(), messages.len());
+        for (ct, &(clear, message_modulus)) in expanded.iter().zip(messages.iter()) {
+            assert_eq!(ct.message_modulus, message_modulus);
+            assert_eq!(
+                ct.message_modulus.0 * ct.carry_modulus.0,
+                total_modulus,
+                "The carry modulus must complete the plaintext space"
+            );
+            assert!(ct.degree.0 < message_modulus.0);
+            assert_eq!(cks.decrypt_message_and_carry(ct), clear);
+        }
+    };
+
+    check_expanded(&compact_list.expand(), &messages);
+
+    let config = ExpandConfig::new()
+        .with_conformance_params(&conformance_params)
+        .with_sanitizing_key(sks);
+    check_expanded(&compact_list.expand_with(config).unwrap(), &messages);
+
+    // The metadata follows the ciphertexts when the list is split and merged
+    let (first, second) = compact_list.split_at(NUM_CT / 2).unwrap();
+    check_expanded(&first.expand(), &messages[..NUM_CT / 2]);
+    check_expanded(&second.expand(), &messages[NUM_CT / 2..]);
+
+    // A list filling its bin can be followed by another one
+    let bin_size = pk.key.lwe_dimension().0;
+    let shared = pk.encrypt_slice(&vec![1; bin_size]);
+    let merged = CompactCiphertextList::concat(&[shared, second]).unwrap();
+    let expanded = merged.expand();
+    assert_eq!(
+        expanded[0].message_modulus,
+        cks.parameters.message_modulus()
+    );
+    assert_eq!(cks.decrypt(&expanded[0]), 1);
+    check_expanded(&expanded[bin_size..], &messages[NUM_CT / 2..]);
+
+    let mut invalid_list = compact_list;
+    invalid_list.element_metadata.as_mut().unwrap()[NUM_CT - 1].degree = Degree(total_modulus);
+    assert!(!invalid_list.is_conformant(&conformance_params));
+
+    // The ciphertexts are checked while being expanded
+    let config = ExpandConfig::new()
+        .with_conformance_params(&conformance_params)
+        .with_sanitizing_key(sks);
+    assert_eq!(
+        invalid_list.expand_with(config).unwrap_err(),
+        ExpandError::NonConformantList
+    );
+    assert!(matches!(
+        invalid_list.iter_expand_with(config),
+        Err(ExpandError::NonConformantList)
+    ));
+}
+
+create_parametrized_test!(shortint_compressed_compact_list {
+    PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS,
+    PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_PBS_KS
+});
+
+fn shortint_compressed_compact_list(params: ClassicPBSParameters) {
+    use crate::conformance::{ListSizeConstraint, ParameterSetConformant};
+    use crate::shortint::ciphertext::CompactCiphertextList;
+
+    let keys = KEY_CACHE.get_from_param(params);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    let pk = crate::shortint::CompactPublicKey::new(cks);
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus().0 as u64;
+
+    // More than one bin, the last one being partially filled
+    let num_ct = pk.key.lwe_dimension().0 + 3;
+
+    let messages = (0..num_ct)
+        .map(|_| rng.gen::<u64>() % modulus)
+        .collect::<Vec<_>>();
+
+    let compressed_list = cks.encrypt_compressed_compact_list(&messages);
+
+    let conformance_params = sks
+        .conformance_params()
+        .to_ct_list_conformance_parameters(ListSizeConstraint::exact_size(num_ct));
+    assert!(compressed_list.is_conformant(&conformance_params));
+
+    let wrong_conformance_params = sks
+        .conformance_params()
+        .to_ct_list_conformance_parameters(ListSizeConstraint::exact_size(num_ct - 1));
+    assert!(!compressed_list.is_conformant(&wrong_conformance_params));
+
+    // Only the bodies are sent, instead of the bodies and one mask per bin
+    let uncompressed_list = pk.encrypt_slice(&messages);
+    assert!(2 * compressed_list.size_bytes() < uncompressed_list.size_bytes());
+
+    let compact_list: CompactCiphertextList = compressed_list.decompress();
+    assert!(compact_list.is_conformant(&conformance_params));
+    assert_eq!(compact_list.size_bytes(), uncompressed_list.size_bytes());
+
+    let expanded = compact_list.expand();
+    assert_eq!(expanded.len(), num_ct);
+    for (ct, &clear) in expanded.iter().zip(messages.iter()) {
+        assert_eq!(cks.decrypt(ct), clear);
+    }
+
+    // The expanded ciphertexts can be used in computations
+    let ct_res = sks.unchecked_add(&expanded[0], &expanded[num_ct - 1]);
+    assert_eq!(
+        cks.decrypt(&ct_res),
+        (messages[0] + messages[num_ct - 1]) % modulus
+    );
+}
@@ -503,13 +503,15 @@ impl WopbsKey {
         OutputCont: ContainerMut<Element = u64>,
     {
         ShortintEngine::with_thread_local_mut(|engine| {
-            engine.extract_bits_assign(
-                delta_log,
-                &ciphertext.ct,
-                self,
-                ExtractedBitsCount(num_bits_to_extract),
-                output,
-            )
+            engine
+                .extract_bits_assign(
+                    delta_log,
+                    &ciphertext.ct,
+                    self,
+                    ExtractedBitsCount(num_bits_to_extract),
+                    output,
+                )
+                .unwrap();
         })
     }
 
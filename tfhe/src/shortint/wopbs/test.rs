@@ -1,3 +1,4 @@
+use crate::shortint::ciphertext::NoiseLevel;
 use crate::shortint::keycache::KEY_CACHE_WOPBS;
 use crate::shortint::parameters::parameters_wopbs_message_carry::*;
 use crate::shortint::parameters::{
@@ -141,9 +142,12 @@ fn generate_lut_modulus(params: (ClassicPBSParameters, WopbsParameters)) {
         let ct = cks.encrypt_with_message_modulus(m as u64, message_modulus);
 
         let ct = wopbs_key.keyswitch_to_wopbs_params(sks, &ct);
+        assert!(ct.noise_level() > NoiseLevel::NOMINAL);
         let lut = wopbs_key.generate_lut(&ct, |x| (x * x) % message_modulus.0 as u64);
         let ct_res = wopbs_key.wopbs(&ct, &lut);
+        assert!(ct_res.noise_level() > NoiseLevel::NOMINAL);
         let ct_res = wopbs_key.keyswitch_to_pbs_params(&ct_res);
+        assert_eq!(ct_res.noise_level(), NoiseLevel::NOMINAL);
 
         let res = cks.decrypt(&ct_res);
         assert_eq!(res as usize, (m * m) % message_modulus.0);
@@ -171,3 +175,28 @@ fn generate_lut_modulus_not_power_of_two(params: WopbsParameters) {
         assert_eq!(res as usize, (m * m) % message_modulus.0);
     }
 }
+
+#[test]
+fn extract_bits_with_ntt_server_key_is_rejected() {
+    use crate::core_crypto::commons::parameters::{DeltaLog, ExtractedBitsCount};
+    use crate::shortint::engine::ShortintEngine;
+    use crate::shortint::server_key::PbsBackend;
+    use crate::shortint::ServerKey;
+
+    let keys = KEY_CACHE_WOPBS.get_from_param((
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        WOPBS_PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    ));
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    // The fields of the key are public, an NTT server key can be put in it after its creation
+    let mut wopbs_key = keys.wopbs_key().clone();
+    wopbs_key.wopbs_server_key =
+        ServerKey::new_with_max_degree_and_pbs_backend(cks, sks.max_degree, PbsBackend::Ntt);
+
+    let ct = cks.encrypt(1);
+    let res = ShortintEngine::with_thread_local_mut(|engine| {
+        engine.extract_bits(DeltaLog(60), &ct.ct, &wopbs_key, ExtractedBitsCount(2))
+    });
+    assert!(res.is_err());
+}